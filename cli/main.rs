@@ -106,9 +106,12 @@ enum Commands {
         /// Clear the compile cache before checking
         #[arg(long)]
         clear_cache: bool,
+
+        #[command(flatten)]
+        filter: FilterArgs,
     },
 
-    /// Manage the compile cache
+    /// Manage the compile cache and build-artifact cache
     Cache {
         #[command(subcommand)]
         action: CacheAction,
@@ -128,9 +131,10 @@ enum Commands {
         #[arg(long, value_name = "N")]
         iterations: Option<u64>,
 
-        /// Output format for reports (console, markdown, json)
-        #[arg(long, default_value = "console")]
-        report: String,
+        /// Output formats for reports (console, markdown, json, junit, github; repeatable or
+        /// comma-separated). Defaults to `report_formats` in polybench.toml, else console
+        #[arg(long, value_name = "FORMAT", value_delimiter = ',')]
+        report: Vec<String>,
 
         /// Output directory for reports
         #[arg(long, short, value_name = "DIR")]
@@ -140,6 +144,71 @@ enum Commands {
         /// csharp:./src)
         #[arg(long, value_name = "LANG:DIR")]
         project_dir: Vec<String>,
+
+        #[command(flatten)]
+        filter: FilterArgs,
+
+        #[command(flatten)]
+        parallel: ParallelArgs,
+
+        #[command(flatten)]
+        isolation: IsolationArgs,
+
+        /// Don't record this run in .polybench/history/
+        #[arg(long)]
+        no_history: bool,
+    },
+
+    /// Watch benchmark sources and re-run the benchmarks whose inputs changed
+    Watch {
+        /// Path to the .bench file (optional if in a poly-bench project)
+        #[arg(value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        /// Run only benchmarks for a specific language (go, ts, rust, python, c, csharp)
+        #[arg(long, value_name = "LANG")]
+        lang: Option<String>,
+
+        /// Override the number of iterations
+        #[arg(long, value_name = "N")]
+        iterations: Option<u64>,
+
+        /// Project root for a language (repeatable). Format: LANG:DIR
+        #[arg(long, value_name = "LANG:DIR")]
+        project_dir: Vec<String>,
+
+        /// How often to check watched files for changes, in milliseconds
+        #[arg(long, value_name = "MS", default_value_t = 500)]
+        poll_interval: u64,
+
+        #[command(flatten)]
+        filter: FilterArgs,
+
+        #[command(flatten)]
+        parallel: ParallelArgs,
+    },
+
+    /// Compare two result sets and flag statistically significant regressions
+    Compare {
+        /// Baseline: results JSON path, `latest`, `previous`, history id, commit, or git revision
+        #[arg(value_name = "BASELINE")]
+        baseline: String,
+
+        /// Candidate (same forms as BASELINE)
+        #[arg(value_name = "CANDIDATE", default_value = "latest")]
+        candidate: String,
+
+        /// Minimum relative change (percent) reported as a regression or improvement
+        #[arg(
+            long,
+            value_name = "PCT",
+            default_value_t = executor::regression::DEFAULT_REGRESSION_THRESHOLD_PERCENT
+        )]
+        threshold: f64,
+
+        /// Output format for the comparison (console, json)
+        #[arg(long, default_value = "console")]
+        report: String,
     },
 
     /// Generate code from a DSL file without running
@@ -337,6 +406,74 @@ enum PlotSubcommand {
     },
 }
 
+/// Benchmark selectors shared by `run` and `compile`
+#[derive(Args)]
+struct FilterArgs {
+    /// Only include benchmarks with any of these tags (repeatable or comma-separated)
+    #[arg(long, value_name = "TAG", value_delimiter = ',', help_heading = "Filtering")]
+    tag: Vec<String>,
+
+    /// Exclude benchmarks with any of these tags (repeatable or comma-separated)
+    #[arg(long, value_name = "TAG", value_delimiter = ',', help_heading = "Filtering")]
+    exclude_tag: Vec<String>,
+
+    /// Only include these suites (glob, repeatable)
+    #[arg(long, value_name = "GLOB", help_heading = "Filtering")]
+    suite: Vec<String>,
+
+    /// Only include benchmarks whose name matches (glob, repeatable; `suite.bench` to qualify)
+    #[arg(long, value_name = "GLOB", help_heading = "Filtering")]
+    bench: Vec<String>,
+}
+
+impl FilterArgs {
+    fn to_filter(&self) -> ir::BenchFilter {
+        ir::BenchFilter {
+            tags: self.tag.clone(),
+            exclude_tags: self.exclude_tag.clone(),
+            suites: self.suite.clone(),
+            benches: self.bench.clone(),
+        }
+    }
+}
+
+/// Worker settings for suites declared with `order: parallel`
+#[derive(Args)]
+struct ParallelArgs {
+    /// Worker threads for parallel suites (default: available CPUs, capped at the language count)
+    #[arg(long, value_name = "N", help_heading = "Parallel suites")]
+    workers: Option<usize>,
+
+    /// Do not pin parallel workers to dedicated CPUs
+    #[arg(long, help_heading = "Parallel suites")]
+    no_pin: bool,
+}
+
+/// System noise control (Linux); also configurable under `[isolation]` in polybench.toml
+#[derive(Args)]
+struct IsolationArgs {
+    /// Pin benchmarks to a dedicated CPU, raise priority and check system stability
+    #[arg(long, help_heading = "Noise control")]
+    isolate: bool,
+
+    /// CPUs to pin benchmark processes to, e.g. 2-3 or 2,4 (implies --isolate)
+    #[arg(long, value_name = "LIST", help_heading = "Noise control")]
+    cpus: Option<String>,
+
+    /// Nice value to run at; negative values need CAP_SYS_NICE (implies --isolate)
+    #[arg(long, value_name = "N", allow_hyphen_values = true, help_heading = "Noise control")]
+    nice: Option<i32>,
+
+    /// Refuse to run when the stability checks find a noisy system (implies --isolate)
+    #[arg(long, help_heading = "Noise control")]
+    strict_env: bool,
+
+    /// Also fail --strict-env on these machine-wide checks, which otherwise only warn:
+    /// aslr, turbo (implies --strict-env)
+    #[arg(long, value_name = "LIST", value_delimiter = ',', help_heading = "Noise control")]
+    strict_checks: Vec<String>,
+}
+
 /// Shared parameters for direct chart subcommands (bar-chart, line-chart, etc.)
 #[derive(Args)]
 struct ChartDirectParams {
@@ -441,7 +578,7 @@ struct ChartDirectParams {
 enum CacheAction {
     /// Show cache statistics
     Stats,
-    /// Clear all cached compile results
+    /// Clear all cached compile results and build artifacts
     Clear,
     /// Clean the entire .polybench workspace
     Clean,
@@ -450,6 +587,7 @@ enum CacheAction {
 #[tokio::main]
 async fn main() -> Result<()> {
     runtime::init_import_extractors();
+    runtime::init_helper_function_extractors();
 
     let cli = Cli::parse();
 
@@ -482,14 +620,62 @@ async fn main() -> Result<()> {
         Commands::Check { file, show_ast } => {
             cmd_check(&file, show_ast).await?;
         }
-        Commands::Compile { file, lang, no_cache, clear_cache } => {
-            cmd_compile(file, lang, no_cache, clear_cache, cli.verbose).await?;
+        Commands::Compile { file, lang, no_cache, clear_cache, filter } => {
+            cmd_compile(file, lang, no_cache, clear_cache, &filter.to_filter(), cli.verbose)
+                .await?;
         }
         Commands::Cache { action } => {
             cmd_cache(action).await?;
         }
-        Commands::Run { file, lang, iterations, report, output, project_dir } => {
-            cmd_run(file, lang, iterations, &report, output, project_dir, cli.verbose).await?;
+        Commands::Run {
+            file,
+            lang,
+            iterations,
+            report,
+            output,
+            project_dir,
+            filter,
+            parallel,
+            isolation,
+            no_history,
+        } => {
+            let args = RunArgs {
+                file,
+                lang,
+                iterations,
+                report_formats: report,
+                output,
+                project_dir,
+                filter: filter.to_filter(),
+                parallel,
+                isolation,
+                no_history,
+            };
+            cmd_run(args, cli.verbose).await?;
+        }
+        Commands::Watch {
+            file,
+            lang,
+            iterations,
+            project_dir,
+            poll_interval,
+            filter,
+            parallel,
+        } => {
+            cmd_watch(
+                file,
+                lang,
+                iterations,
+                project_dir,
+                poll_interval,
+                &filter.to_filter(),
+                &parallel,
+                cli.verbose,
+            )
+            .await?;
+        }
+        Commands::Compare { baseline, candidate, threshold, report } => {
+            cmd_compare(&baseline, &candidate, threshold, &report)?;
         }
         Commands::Codegen { file, lang, output } => {
             cmd_codegen(&file, &lang, &output).await?;
@@ -552,6 +738,15 @@ async fn cmd_check(file: &PathBuf, show_ast: bool) -> Result<()> {
 
     let filename = file.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
 
+    let mut manifest = None;
+    if let Some(root) = project::find_project_root(file.parent().unwrap_or(file)) {
+        register_project_runtimes(&root)?;
+        if root.join(project::MANIFEST_FILENAME).exists() {
+            manifest = Some(project::load_manifest(&root)?);
+        }
+    }
+    let lint_config = manifest.as_ref().map(|m| m.lint_config()).unwrap_or_default();
+
     match dsl::parse(&source, filename) {
         Ok(ast) => {
             ui::success(format!("Parsed {}", file.display()));
@@ -571,17 +766,92 @@ async fn cmd_check(file: &PathBuf, show_ast: bool) -> Result<()> {
                 println!("{:#?}", ast);
             }
 
+            for id in lint_config.unknown_rules() {
+                ui::info(format!("[lint] {} is not a known rule", id));
+            }
+            let result = match &manifest {
+                Some(manifest) => {
+                    dsl::validate_project_file(&ast, |lang| manifest.has_runtime(lang))
+                }
+                None => dsl::validate_file(&ast),
+            };
+            let warnings = report_lints(file, &source, result, &lint_config)?;
+            ui::success(format!("No lint errors ({} warning(s))", warnings));
             Ok(())
         }
         Err(e) => Err(e),
     }
 }
 
+/// Apply the project's `[lint]` overrides and the file's inline suppressions to `result` and
+/// print every remaining finding. Fails if an error-level finding remains; otherwise returns the
+/// number of warnings.
+fn report_lints(
+    file: &std::path::Path,
+    source: &str,
+    result: dsl::ValidationResult,
+    lint_config: &dsl::lint::LintConfig,
+) -> Result<usize> {
+    use colored::Colorize;
+
+    let filename = file.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+    let suppressions = dsl::lint::Suppressions::parse(source);
+    let result = result.apply_lints(lint_config, &suppressions);
+    for warning in &result.warnings {
+        println!(
+            "  {} {}",
+            "⚠".yellow().bold(),
+            format_finding(filename, &warning.message, warning.rule, warning.line)
+        );
+        print_rule_help(warning.rule);
+    }
+    for error in &result.errors {
+        ui::failure(format_finding(filename, &error.message, error.rule, error.line));
+        print_rule_help(error.rule);
+    }
+
+    if !result.is_ok() {
+        return Err(miette::miette!(
+            "{} failed validation with {} error(s)",
+            file.display(),
+            result.errors.len()
+        ));
+    }
+    Ok(result.warnings.len())
+}
+
+fn format_finding(
+    filename: &str,
+    message: &str,
+    rule: Option<&str>,
+    line: Option<usize>,
+) -> String {
+    let location = match line {
+        Some(line) => format!("{}:{}", filename, line),
+        None => filename.to_string(),
+    };
+    match rule {
+        Some(id) => format!("{}: {} [{}]", location, message, id),
+        None => format!("{}: {}", location, message),
+    }
+}
+
+fn print_rule_help(rule: Option<&str>) {
+    use colored::Colorize;
+
+    let Some(rule) = rule.and_then(dsl::lint::rule) else { return };
+    if let Some(fix) = rule.fix {
+        ui::indented_line(format!("{} {}", "fix:".dimmed(), fix));
+    }
+    ui::indented_line(format!("{} {}", "docs:".dimmed(), rule.docs_url()));
+}
+
 async fn cmd_compile(
     file: Option<PathBuf>,
     lang: Option<String>,
     no_cache: bool,
     clear_cache: bool,
+    filter: &ir::BenchFilter,
     verbose: bool,
 ) -> Result<()> {
     use colored::Colorize;
@@ -617,6 +887,7 @@ async fn cmd_compile(
             (bench_files, true, project_root)
         }
     };
+    register_project_runtimes(&project_root)?;
 
     // Initialize compile cache
     let cache_dir = project_root.join(".polybench").join("cache");
@@ -643,9 +914,9 @@ async fn cmd_compile(
 
     let lang_filter = lang.as_deref();
     if run_parallel && files.len() > 1 {
-        compile_files_parallel_cached(&files, lang_filter, &cache, verbose).await
+        compile_files_parallel_cached(&files, lang_filter, filter, &cache, verbose).await
     } else {
-        compile_files_sequential_cached(&files, lang_filter, &cache, verbose).await
+        compile_files_sequential_cached(&files, lang_filter, filter, &cache, verbose).await
     }
 }
 
@@ -659,6 +930,7 @@ struct CompileResultCached {
 async fn compile_single_file_cached(
     bench_file: PathBuf,
     lang_filter: Option<&str>,
+    filter: &ir::BenchFilter,
     cache: &executor::CompileCache,
 ) -> Result<CompileResultCached> {
     let source = std::fs::read_to_string(&bench_file)
@@ -667,7 +939,16 @@ async fn compile_single_file_cached(
     let filename = bench_file.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
 
     let ast = dsl::parse(&source, filename)?;
-    let ir_result = ir::lower(&ast, bench_file.parent())?;
+    let mut ir_result = ir::lower(&ast, bench_file.parent())?;
+    filter.apply(&mut ir_result);
+    if ir_result.suites.is_empty() {
+        return Ok(CompileResultCached {
+            file: bench_file,
+            bench_count: 0,
+            errors: Vec::new(),
+            stats: executor::ValidationStats::default(),
+        });
+    }
 
     let langs = resolve_languages_for_run(lang_filter, &ir_result)?;
 
@@ -683,6 +964,7 @@ async fn compile_single_file_cached(
 async fn compile_files_parallel_cached(
     files: &[PathBuf],
     lang_filter: Option<&str>,
+    filter: &ir::BenchFilter,
     cache: &executor::CompileCache,
     verbose: bool,
 ) -> Result<()> {
@@ -700,8 +982,10 @@ async fn compile_files_parallel_cached(
 
     let spinner = create_compiling_spinner();
 
-    let futures: Vec<_> =
-        files.iter().map(|f| compile_single_file_cached(f.clone(), lang_filter, cache)).collect();
+    let futures: Vec<_> = files
+        .iter()
+        .map(|f| compile_single_file_cached(f.clone(), lang_filter, filter, cache))
+        .collect();
 
     let results = join_all(futures).await;
     spinner.finish_and_clear();
@@ -733,6 +1017,10 @@ async fn compile_files_parallel_cached(
     }
 
     for result in &success_results {
+        if result.bench_count == 0 {
+            ui::info(format!("{} - no benchmarks match the filters", result.file.display()));
+            continue;
+        }
         let cache_info = if result.stats.cache_hits > 0 {
             format!(" ({} cached)", result.stats.cache_hits)
         } else {
@@ -775,6 +1063,7 @@ async fn compile_files_parallel_cached(
 async fn compile_files_sequential_cached(
     files: &[PathBuf],
     lang_filter: Option<&str>,
+    filter: &ir::BenchFilter,
     cache: &executor::CompileCache,
     verbose: bool,
 ) -> Result<()> {
@@ -792,7 +1081,12 @@ async fn compile_files_sequential_cached(
         let filename = bench_file.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
 
         let ast = dsl::parse(&source, filename)?;
-        let ir_result = ir::lower(&ast, bench_file.parent())?;
+        let mut ir_result = ir::lower(&ast, bench_file.parent())?;
+        filter.apply(&mut ir_result);
+        if ir_result.suites.is_empty() {
+            ui::info(format!("{} - no benchmarks match the filters", bench_file.display()));
+            continue;
+        }
 
         let langs = resolve_languages_for_run(lang_filter, &ir_result)?;
 
@@ -871,6 +1165,28 @@ async fn cmd_cache(action: CacheAction) -> Result<()> {
             println!("  Location: {}", cache_dir.display());
             println!("  {}", stats);
 
+            let artifacts = runtime::ArtifactCache::from_env();
+            let artifact_stats = artifacts.stats();
+            println!("\n{}", "Artifact Cache Statistics".bold());
+            println!("  Location: {}", artifacts.root().display());
+            if !artifacts.is_enabled() {
+                println!("  {}", "disabled (POLYBENCH_NO_ARTIFACT_CACHE)".dimmed());
+            }
+            println!(
+                "  {} entries, {} of {}",
+                artifact_stats.entries,
+                executor::format_size(artifact_stats.total_bytes),
+                executor::format_size(artifact_stats.max_bytes)
+            );
+            for (lang, (entries, bytes)) in &artifact_stats.by_lang {
+                println!(
+                    "    {:<8} {:>4} entries  {}",
+                    lang,
+                    entries,
+                    executor::format_size(*bytes)
+                );
+            }
+
             // Also show workspace size
             let workspace = executor::CompileWorkspace::new(&project_root)?;
             let size = workspace.size();
@@ -881,7 +1197,16 @@ async fn cmd_cache(action: CacheAction) -> Result<()> {
             let cache_dir = project_root.join(".polybench").join("cache");
             let cache = executor::CompileCache::new(&cache_dir, true);
             cache.clear().await;
+            cache.save().await?;
             println!("{} Cleared compile cache", "✓".green());
+
+            let artifacts = runtime::ArtifactCache::from_env();
+            let freed = artifacts.clear()?;
+            println!(
+                "{} Cleared artifact cache ({} freed)",
+                "✓".green(),
+                executor::format_size(freed)
+            );
         }
         CacheAction::Clean => {
             let workspace = executor::CompileWorkspace::new(&project_root)?;
@@ -893,50 +1218,48 @@ async fn cmd_cache(action: CacheAction) -> Result<()> {
     Ok(())
 }
 
-async fn cmd_run(
+/// Options of `poly-bench run`
+struct RunArgs {
     file: Option<PathBuf>,
     lang: Option<String>,
     iterations: Option<u64>,
-    report_format: &str,
+    report_formats: Vec<String>,
     output: Option<PathBuf>,
     project_dir: Vec<String>,
-    verbose: bool,
-) -> Result<()> {
-    // Get benchmark files and project root (project_root is None when file specified but not in a
-    // project)
-    let (files, project_root) = match file {
-        Some(f) => {
-            let root = project::find_project_root(f.parent().unwrap_or(&f));
-            (vec![f], root)
-        }
-        None => {
-            let current_dir = std::env::current_dir()
-                .map_err(|e| miette::miette!("Failed to get current directory: {}", e))?;
-
-            let project_root = project::find_project_root(&current_dir).ok_or_else(|| {
-                miette::miette!(
-                    "No .bench file specified and not in a poly-bench project.\n\
-                    Either specify a file: poly-bench run <file.bench>\n\
-                    Or initialize a project: poly-bench init <name>"
-                )
-            })?;
-
-            let bench_files = project::find_bench_files(&project_root)?;
-
-            if bench_files.is_empty() {
-                return Err(miette::miette!(
-                    "No .bench files found in {}/",
-                    project::BENCHMARKS_DIR
-                ));
-            }
+    filter: ir::BenchFilter,
+    parallel: ParallelArgs,
+    isolation: IsolationArgs,
+    no_history: bool,
+}
 
-            (bench_files, Some(project_root))
-        }
-    };
+async fn cmd_run(args: RunArgs, verbose: bool) -> Result<()> {
+    let RunArgs {
+        file,
+        lang,
+        iterations,
+        report_formats,
+        output,
+        project_dir,
+        filter,
+        parallel,
+        isolation,
+        no_history,
+    } = args;
+    let (files, project_root) = resolve_run_files(file, "run")?;
+    if let Some(ref root) = project_root {
+        register_project_runtimes(root)?;
+    }
+    let report_formats = resolve_report_formats(report_formats, project_root.as_deref())?;
+    let isolation_report = resolve_isolation(&isolation, project_root.as_deref())?
+        .map(|settings| apply_isolation(&settings))
+        .transpose()?;
 
     // Run each benchmark file
     let mut all_results = Vec::new();
     let mut all_chart_directives = Vec::new();
+    let mut all_skipped = Vec::new();
+    let mut run_langs: Vec<dsl::Lang> = Vec::new();
+    let mut run_files = Vec::new();
 
     for bench_file in &files {
         // Parse the DSL file
@@ -948,7 +1271,14 @@ async fn cmd_run(
         let ast = dsl::parse(&source, filename)?;
 
         // Lower to IR
-        let ir = ir::lower(&ast, bench_file.parent())?;
+        let mut ir = ir::lower(&ast, bench_file.parent())?;
+
+        // Drop benchmarks not selected by --tag/--exclude-tag/--suite/--bench
+        all_skipped.extend(filter.apply(&mut ir));
+        if ir.suites.is_empty() {
+            println!("▸ Skipping {} (no benchmarks match the filters)", bench_file.display());
+            continue;
+        }
 
         // Validate runtime configuration (languages used vs polybench.toml)
         if let Some(ref root) = project_root {
@@ -972,6 +1302,10 @@ async fn cmd_run(
 
         if !compile_errors.is_empty() {
             print_compile_errors_for_file(bench_file, &compile_errors, verbose);
+            if report_formats.iter().any(|f| f == "junit") {
+                let xml = reporter::junit::report_compile_errors(&compile_errors)?;
+                write_report(output.as_ref().unwrap_or(&PathBuf::from("out")), "junit.xml", &xml)?;
+            }
             std::process::exit(1);
         }
         println!("  ✓ Compile validation passed");
@@ -982,9 +1316,27 @@ async fn cmd_run(
         println!();
 
         // Execute benchmarks
-        let run_opts = executor::RunOptions { verbose };
+        let run_opts = executor::RunOptions {
+            verbose,
+            workers: parallel.workers,
+            pin_cpus: !parallel.no_pin,
+            ..Default::default()
+        };
         let results = executor::run(&ir, &langs, iterations, &project_roots, &run_opts).await?;
         all_results.push(results);
+        for lang in langs {
+            if !run_langs.contains(&lang) {
+                run_langs.push(lang);
+            }
+        }
+        run_files.push(bench_file.clone());
+    }
+
+    if all_results.is_empty() {
+        return Err(miette::miette!(
+            "No benchmarks match the filters ({} skipped)",
+            all_skipped.len()
+        ));
     }
 
     // Merge results if multiple files
@@ -994,6 +1346,10 @@ async fn cmd_run(
         // Merge multiple results into one
         merge_results(all_results)
     };
+    let results = results.with_skipped(all_skipped).with_environment(
+        executor::RunEnvironment::capture(&run_langs, project_root.as_deref(), &run_files)
+            .with_isolation(isolation_report),
+    );
 
     // Default output directory for auto-saved results
     let default_output_dir = PathBuf::from("out");
@@ -1006,6 +1362,16 @@ async fn cmd_run(
     std::fs::write(&results_path, &json)
         .map_err(|e| miette::miette!("Failed to save results: {}", e))?;
 
+    // Record the run in .polybench/history/ so it can be used with `poly-bench compare`
+    if let Some(root) = project_root.as_ref().filter(|_| !no_history) {
+        let limit = project::load_manifest(root)?.output.history_limit;
+        if limit > 0 {
+            let store = executor::HistoryStore::new(root)?;
+            store.record(&results)?;
+            store.prune(limit)?;
+        }
+    }
+
     // Execute chart directives if any
     if !all_chart_directives.is_empty() {
         let chart_output_dir = output.clone().unwrap_or_else(|| default_output_dir.clone());
@@ -1014,48 +1380,427 @@ async fn cmd_run(
     }
 
     // Generate reports
-    match report_format {
-        "console" => {
-            reporter::console::report(&results)?;
-        }
-        "markdown" => {
-            let md = reporter::markdown::report(&results)?;
-            if let Some(ref out_dir) = output {
-                std::fs::create_dir_all(out_dir)
-                    .map_err(|e| miette::miette!("Failed to create output directory: {}", e))?;
-                let out_path = out_dir.join("benchmark-report.md");
-                std::fs::write(&out_path, &md)
-                    .map_err(|e| miette::miette!("Failed to write report: {}", e))?;
-                println!("Report written to {}", out_path.display());
-            } else {
-                println!("{}", md);
+    for format in &report_formats {
+        let (content, file_name) = match format.as_str() {
+            "console" => {
+                reporter::console::report(&results)?;
+                continue;
             }
+            "markdown" => (reporter::markdown::report(&results)?, "benchmark-report.md"),
+            "json" => (reporter::json::report(&results)?, "benchmark-results.json"),
+            "junit" => (reporter::junit::report(&results)?, "junit.xml"),
+            "github" => (reporter::github::report(&results)?, "check-run.json"),
+            _ => unreachable!("report formats are validated before running"),
+        };
+        if let Some(ref out_dir) = output {
+            write_report(out_dir, file_name, &content)?;
+        } else {
+            println!("{}", content);
         }
-        "json" => {
-            let json = reporter::json::report(&results)?;
-            if let Some(ref out_dir) = output {
-                std::fs::create_dir_all(out_dir)
-                    .map_err(|e| miette::miette!("Failed to create output directory: {}", e))?;
-                let out_path = out_dir.join("benchmark-results.json");
-                std::fs::write(&out_path, &json)
-                    .map_err(|e| miette::miette!("Failed to write report: {}", e))?;
-                println!("Report written to {}", out_path.display());
-            } else {
-                println!("{}", json);
+    }
+
+    // Non-zero exit so CI can gate on implementations that disagree (`equivalent: true`)
+    let equivalence_failures = results.equivalence_failures();
+    if !equivalence_failures.is_empty() {
+        return Err(miette::miette!(
+            "{} benchmark(s) produced different outputs across languages. Results saved to {}",
+            equivalence_failures.len(),
+            results_path.display()
+        ));
+    }
+
+    let validation_failures = results.validation_failures();
+    if !validation_failures.is_empty() {
+        return Err(miette::miette!(
+            "{} benchmark(s) failed validation. Results saved to {}",
+            validation_failures.len(),
+            results_path.display()
+        ));
+    }
+
+    println!("Benchmark successful. Results saved to {}", results_path.display());
+
+    Ok(())
+}
+
+/// Get benchmark files and project root for `run`/`watch` (project_root is None when a file is
+/// specified but not in a project)
+fn resolve_run_files(
+    file: Option<PathBuf>,
+    command: &str,
+) -> Result<(Vec<PathBuf>, Option<PathBuf>)> {
+    match file {
+        Some(f) => {
+            let root = project::find_project_root(f.parent().unwrap_or(&f));
+            Ok((vec![f], root))
+        }
+        None => {
+            let current_dir = std::env::current_dir()
+                .map_err(|e| miette::miette!("Failed to get current directory: {}", e))?;
+
+            let project_root = project::find_project_root(&current_dir).ok_or_else(|| {
+                miette::miette!(
+                    "No .bench file specified and not in a poly-bench project.\n\
+                    Either specify a file: poly-bench {} <file.bench>\n\
+                    Or initialize a project: poly-bench init <name>",
+                    command
+                )
+            })?;
+
+            let bench_files = project::find_bench_files(&project_root)?;
+
+            if bench_files.is_empty() {
+                return Err(miette::miette!(
+                    "No .bench files found in {}/",
+                    project::BENCHMARKS_DIR
+                ));
             }
+
+            Ok((bench_files, Some(project_root)))
         }
-        _ => {
-            return Err(miette::miette!("Unknown report format: {}", report_format));
+    }
+}
+
+/// Per-file state carried between `watch` iterations
+#[derive(Default)]
+struct WatchedFile {
+    /// Fingerprints of the last successful run
+    fingerprints: executor::watch::Fingerprints,
+    /// Fixture files, manifests and project sources this file depends on
+    inputs: Vec<PathBuf>,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn cmd_watch(
+    file: Option<PathBuf>,
+    lang: Option<String>,
+    iterations: Option<u64>,
+    project_dir: Vec<String>,
+    poll_interval: u64,
+    filter: &ir::BenchFilter,
+    parallel: &ParallelArgs,
+    verbose: bool,
+) -> Result<()> {
+    use colored::Colorize;
+
+    let single_file = file.is_some();
+    let (mut files, project_root) = resolve_run_files(file, "watch")?;
+    if let Some(ref root) = project_root {
+        register_project_runtimes(root)?;
+    }
+    let explicit_roots = parse_project_dirs(&project_dir)?;
+    let cache_root = project_root
+        .clone()
+        .unwrap_or_else(|| files[0].parent().unwrap_or(&files[0]).to_path_buf());
+    let cache = executor::CompileCache::new(&cache_root.join(".polybench").join("cache"), true);
+    let run_opts = executor::RunOptions {
+        verbose,
+        workers: parallel.workers,
+        pin_cpus: !parallel.no_pin,
+        ..Default::default()
+    };
+    let interval = std::time::Duration::from_millis(poll_interval.max(50));
+
+    let mut state: std::collections::HashMap<PathBuf, WatchedFile> =
+        std::collections::HashMap::new();
+    loop {
+        // Stat what is known to be watched before running, so that files saved while the
+        // benchmarks run are still seen as changed afterwards
+        let before = executor::watch::FileSnapshot::capture(&watched_paths(
+            &files,
+            &state,
+            project_root.as_deref(),
+            single_file,
+        ));
+        for bench_file in &files {
+            let watched = state.entry(bench_file.clone()).or_default();
+            if let Err(e) = watch_iteration(
+                bench_file,
+                watched,
+                lang.as_deref(),
+                iterations,
+                &explicit_roots,
+                project_root.as_deref(),
+                filter,
+                &cache,
+                &run_opts,
+                verbose,
+            )
+            .await
+            {
+                eprintln!("{:?}", e);
+            }
+        }
+
+        let watched_paths = watched_paths(&files, &state, project_root.as_deref(), single_file);
+        println!();
+        ui::info(format!(
+            "Watching {} file(s) for changes {}",
+            watched_paths.len(),
+            "(Ctrl+C to stop)".dimmed()
+        ));
+
+        let snapshot = before.with_paths(&watched_paths);
+        let changed = loop {
+            tokio::time::sleep(interval).await;
+            let current = executor::watch::FileSnapshot::capture(&watched_paths);
+            let changed = snapshot.changed_paths(&current);
+            if changed.is_empty() {
+                continue;
+            }
+            // Let editors finish writing before re-reading anything
+            tokio::time::sleep(interval).await;
+            break changed;
+        };
+
+        println!();
+        for path in &changed {
+            ui::info(format!("Changed: {}", path.display()));
+        }
+
+        if let Some(ref root) = project_root {
+            if !single_file {
+                files = project::find_bench_files(root)?;
+                state.retain(|path, _| files.contains(path));
+            }
         }
     }
+}
 
-    println!("Benchmark successful. Results saved to {}", results_path.display());
+/// Everything the current set of files depends on, plus the manifest and the benchmarks
+/// directory so that added or removed .bench files are noticed
+fn watched_paths(
+    files: &[PathBuf],
+    state: &std::collections::HashMap<PathBuf, WatchedFile>,
+    project_root: Option<&std::path::Path>,
+    single_file: bool,
+) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = files.to_vec();
+    for bench_file in files {
+        if let Some(watched) = state.get(bench_file) {
+            paths.extend(watched.inputs.iter().cloned());
+        }
+    }
+    if let Some(root) = project_root {
+        paths.push(root.join(project::MANIFEST_FILENAME));
+        if !single_file {
+            paths.push(root.join(project::BENCHMARKS_DIR));
+        }
+    }
+    paths
+}
+
+/// Re-lower one .bench file and run the benchmarks whose inputs changed since the last
+/// successful run
+#[allow(clippy::too_many_arguments)]
+async fn watch_iteration(
+    bench_file: &PathBuf,
+    watched: &mut WatchedFile,
+    lang: Option<&str>,
+    iterations: Option<u64>,
+    explicit_roots: &std::collections::HashMap<dsl::Lang, PathBuf>,
+    project_root: Option<&std::path::Path>,
+    filter: &ir::BenchFilter,
+    cache: &executor::CompileCache,
+    run_opts: &executor::RunOptions,
+    verbose: bool,
+) -> Result<()> {
+    use executor::watch;
+
+    let source = std::fs::read_to_string(bench_file)
+        .map_err(|e| miette::miette!("Failed to read file {}: {}", bench_file.display(), e))?;
+    let filename = bench_file.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+
+    let project_roots = resolve_project_roots(explicit_roots, bench_file)?;
+    watched.inputs = watch::project_paths(&project_roots);
+
+    let ast = dsl::parse(&source, filename)?;
+    watched.inputs.extend(watch::fixture_files(&ast, bench_file.parent()));
+    let mut ir = ir::lower(&ast, bench_file.parent())?;
+    filter.apply(&mut ir);
+    if ir.suites.is_empty() {
+        return Ok(());
+    }
+    if let Some(root) = project_root {
+        validate_runtime_configuration(root, &ir)?;
+    }
+
+    let fingerprints = watch::Fingerprints::of(&ir, &watch::EnvFingerprints::scan(&project_roots));
+    let changed = fingerprints.changed_since(&watched.fingerprints);
+    if changed.is_empty() {
+        ui::info(format!("{} - no benchmark inputs changed", bench_file.display()));
+        return Ok(());
+    }
+
+    ui::section(&format!("Watch ▸ {}", bench_file.display()));
+    ui::kv("benchmarks", format!("{} of {}", changed.len(), fingerprints.len()));
+    if changed.len() < fingerprints.len() {
+        for key in &changed {
+            ui::indented_line(key.to_string());
+        }
+    }
+    watch::retain_benchmarks(&mut ir, &changed.into_iter().collect());
+
+    let langs = resolve_languages_for_run(lang, &ir)?;
+    let spinner = create_compiling_spinner();
+    let (compile_errors, _) =
+        executor::validate_benchmarks_with_cache(&ir, &langs, &project_roots, cache).await?;
+    spinner.finish_and_clear();
+    if !compile_errors.is_empty() {
+        print_compile_errors_for_file(bench_file, &compile_errors, verbose);
+        return Ok(());
+    }
 
+    let results = executor::run(&ir, &langs, iterations, &project_roots, run_opts).await?;
+    reporter::console::report(&results)?;
+    watched.fingerprints = fingerprints;
     Ok(())
 }
 
 use executor::{BenchmarkResults, ProjectRoots};
 
+/// Report formats understood by `poly-bench run --report`
+const REPORT_FORMATS: &[&str] = &["console", "markdown", "json", "junit", "github"];
+
+/// Combine `--isolate`/`--cpus`/`--nice`/`--strict-env` with `[isolation]` from polybench.toml;
+/// flags take precedence. `None` when neither asks for isolation.
+fn resolve_isolation(
+    args: &IsolationArgs,
+    project_root: Option<&std::path::Path>,
+) -> Result<Option<executor::IsolationSettings>> {
+    let config = match project_root {
+        Some(root) => project::load_manifest(root)?.isolation,
+        None => None,
+    };
+    let requested = args.isolate ||
+        args.cpus.is_some() ||
+        args.nice.is_some() ||
+        args.strict_env ||
+        !args.strict_checks.is_empty();
+    if !requested && config.is_none() {
+        return Ok(None);
+    }
+    let config = config.unwrap_or_default();
+    executor::IsolationSettings::new(
+        args.cpus.as_deref().or(config.cpus.as_deref()),
+        args.nice.or(config.nice),
+        args.strict_env || config.strict,
+        if args.strict_checks.is_empty() {
+            config.strict_checks
+        } else {
+            args.strict_checks.clone()
+        },
+    )
+    .map(Some)
+}
+
+/// Apply isolation and print what was applied and any stability warnings
+fn apply_isolation(settings: &executor::IsolationSettings) -> Result<executor::IsolationReport> {
+    use colored::Colorize;
+
+    println!("▸ Noise control");
+    let report = executor::isolation::apply(settings)?;
+    println!("  {}", report.summary());
+    for warning in &report.warnings {
+        println!("  {} {}", "⚠".yellow(), warning);
+    }
+    println!();
+    Ok(report)
+}
+
+/// Use `--report` if given, else `[output] report_formats` from polybench.toml, else console
+fn resolve_report_formats(
+    cli_formats: Vec<String>,
+    project_root: Option<&std::path::Path>,
+) -> Result<Vec<String>> {
+    let mut formats = cli_formats;
+    if formats.is_empty() {
+        if let Some(root) = project_root {
+            formats = project::load_manifest(root)?.output.report_formats;
+        }
+    }
+    if formats.is_empty() {
+        formats.push("console".to_string());
+    }
+    for format in &formats {
+        if !REPORT_FORMATS.contains(&format.as_str()) {
+            return Err(miette::miette!(
+                "Unknown report format: {} (expected one of: {})",
+                format,
+                REPORT_FORMATS.join(", ")
+            ));
+        }
+    }
+    let mut seen = std::collections::HashSet::new();
+    formats.retain(|f| seen.insert(f.clone()));
+    Ok(formats)
+}
+
+/// Write a report file into the output directory
+fn write_report(out_dir: &std::path::Path, file_name: &str, content: &str) -> Result<()> {
+    std::fs::create_dir_all(out_dir)
+        .map_err(|e| miette::miette!("Failed to create output directory: {}", e))?;
+    let out_path = out_dir.join(file_name);
+    std::fs::write(&out_path, content)
+        .map_err(|e| miette::miette!("Failed to write report: {}", e))?;
+    println!("Report written to {}", out_path.display());
+    Ok(())
+}
+
+fn cmd_compare(baseline: &str, candidate: &str, threshold: f64, report_format: &str) -> Result<()> {
+    let current_dir = std::env::current_dir()
+        .map_err(|e| miette::miette!("Failed to get current directory: {}", e))?;
+    // Results name the project's external runtimes and variants, which must be known to load
+    if let Some(root) = project::find_project_root(&current_dir) {
+        register_project_runtimes(&root)?;
+    }
+
+    // Outside a project only explicit results files can be compared
+    let resolve = |reference: &str| -> Result<(String, BenchmarkResults)> {
+        let path = std::path::Path::new(reference);
+        if path.is_file() {
+            return reporter::results_file::load_file(path).map(|r| (reference.to_string(), r));
+        }
+        match project::find_project_root(&current_dir) {
+            Some(root) => executor::HistoryStore::new(&root)?.resolve(reference),
+            None => Err(miette::miette!("Results file {} does not exist", reference)),
+        }
+    };
+    let (baseline_label, baseline_results) = resolve(baseline)?;
+    let (candidate_label, candidate_results) = resolve(candidate)?;
+
+    let report = executor::compare_results(
+        &baseline_results,
+        &candidate_results,
+        &baseline_label,
+        &candidate_label,
+        threshold,
+    );
+
+    match report_format {
+        "console" => reporter::console::report_regressions(&report)?,
+        "json" => {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|e| miette::miette!("Failed to serialize comparison: {}", e))?;
+            println!("{}", json);
+        }
+        _ => {
+            return Err(miette::miette!("Unknown report format: {}", report_format));
+        }
+    }
+
+    // Fail the command so CI can gate on regressions
+    if report.has_regressions() {
+        return Err(miette::miette!(
+            "{} benchmark(s) regressed beyond the {}% threshold",
+            report.regressions().count(),
+            report.threshold_percent
+        ));
+    }
+
+    Ok(())
+}
+
 /// Merge multiple benchmark results into one
 fn merge_results(mut results: Vec<BenchmarkResults>) -> BenchmarkResults {
     if results.is_empty() {
@@ -1074,6 +1819,12 @@ const DEFAULT_RESULTS_PATH: &str = "out/results.json";
 const DEFAULT_OUTPUT_DIR: &str = "out";
 
 async fn cmd_plot(subcommand: PlotSubcommand) -> Result<()> {
+    // Benchmarks and results may name the project's external runtimes and variants
+    let current_dir = std::env::current_dir()
+        .map_err(|e| miette::miette!("Failed to get current directory: {}", e))?;
+    if let Some(root) = project::find_project_root(&current_dir) {
+        register_project_runtimes(&root)?;
+    }
     match subcommand {
         PlotSubcommand::FromFile { file, results, output } => {
             cmd_plot_from_file(file, results, output).await
@@ -1143,21 +1894,7 @@ async fn cmd_plot_from_file(
     }
 
     let results_path = results.unwrap_or_else(|| PathBuf::from(DEFAULT_RESULTS_PATH));
-    let json = std::fs::read_to_string(&results_path).map_err(|e| {
-        miette::miette!(
-            "Failed to read results file {}: {}.\n\
-            Run benchmarks first: poly-bench run",
-            results_path.display(),
-            e
-        )
-    })?;
-
-    let benchmark_results: BenchmarkResults = serde_json::from_str(&json).map_err(|e| {
-        miette::miette!(
-            "Failed to parse results JSON: {}. File may be from an incompatible version.",
-            e
-        )
-    })?;
+    let benchmark_results = load_results_for_plot(&results_path)?;
 
     let output_dir = output.unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT_DIR));
     let generated =
@@ -1171,6 +1908,18 @@ async fn cmd_plot_from_file(
     Ok(())
 }
 
+/// Load a results file, upgrading results written by older poly-bench versions
+fn load_results_for_plot(results_path: &std::path::Path) -> Result<BenchmarkResults> {
+    if !results_path.exists() {
+        return Err(miette::miette!(
+            "Results file {} not found.\n\
+            Run benchmarks first: poly-bench run",
+            results_path.display()
+        ));
+    }
+    reporter::results_file::load_file(results_path)
+}
+
 async fn cmd_plot_direct(chart_type: dsl::ChartType, params: ChartDirectParams) -> Result<()> {
     let output_file = params.output_file.clone().ok_or_else(|| {
         miette::miette!(
@@ -1181,21 +1930,7 @@ async fn cmd_plot_direct(chart_type: dsl::ChartType, params: ChartDirectParams)
 
     let results_path =
         params.results.clone().unwrap_or_else(|| PathBuf::from(DEFAULT_RESULTS_PATH));
-    let json = std::fs::read_to_string(&results_path).map_err(|e| {
-        miette::miette!(
-            "Failed to read results file {}: {}.\n\
-            Run benchmarks first: poly-bench run",
-            results_path.display(),
-            e
-        )
-    })?;
-
-    let benchmark_results: BenchmarkResults = serde_json::from_str(&json).map_err(|e| {
-        miette::miette!(
-            "Failed to parse results JSON: {}. File may be from an incompatible version.",
-            e
-        )
-    })?;
+    let benchmark_results = load_results_for_plot(&results_path)?;
 
     let directive = chart_params_to_directive(chart_type, &output_file, &params);
 
@@ -1312,7 +2047,7 @@ fn resolve_project_roots(
     while let Some(dir) = current {
         // Inside a poly-bench project: prefer .polybench/runtime-env/{lang}
         if dir.join(project::MANIFEST_FILENAME).exists() {
-            for lang in runtime::supported_languages() {
+            for lang in &runtime::supported_languages() {
                 if roots.get_root(*lang).is_none() {
                     let env = project::runtime_env(&dir, *lang);
                     if project::is_valid_project_root_for_lang(&env, *lang) {
@@ -1322,7 +2057,7 @@ fn resolve_project_roots(
             }
         }
         // Fallback: classic layout via detectors
-        for lang in runtime::supported_languages() {
+        for lang in &runtime::supported_languages() {
             if roots.get_root(*lang).is_none() {
                 if let Some(det) = project::get_detector(*lang) {
                     roots.set_root(*lang, det.detect(&dir));
@@ -1339,7 +2074,7 @@ fn resolve_project_roots(
 }
 
 fn parse_lang_arg(raw: &str, arg_name: &str) -> Result<dsl::Lang> {
-    dsl::Lang::from_str(raw).ok_or_else(|| {
+    dsl::Lang::from_str_with_variants(raw).ok_or_else(|| {
         miette::miette!(
             "Unknown language '{}' for {}. Supported: {}",
             raw,
@@ -1363,7 +2098,7 @@ fn resolve_languages_for_run(lang: Option<&str>, ir: &ir::BenchmarkIR) -> Result
         Some(raw) => {
             let l = parse_lang_arg(raw, "--lang")?;
             let used = ir.languages_used();
-            if !used.contains(&l) {
+            if !used.contains(&l.base()) {
                 return Err(miette::miette!(
                     "Language '{}' specified with --lang is not used in this benchmark file. Languages used: {}",
                     raw,
@@ -1376,7 +2111,12 @@ fn resolve_languages_for_run(lang: Option<&str>, ir: &ir::BenchmarkIR) -> Result
             let used = ir.languages_used();
             // Deterministic order: sort by supported_languages order
             let supported = runtime::supported_languages();
-            let mut langs: Vec<_> = used.into_iter().collect();
+            // Runtime variants run alongside their base language as separate contestants
+            let variants = runtime::variant::variant_languages()
+                .into_iter()
+                .filter(|v| used.contains(&v.base()))
+                .collect::<Vec<_>>();
+            let mut langs: Vec<_> = used.into_iter().chain(variants).collect();
             langs.sort_by_key(|l| supported.iter().position(|s| s == l).unwrap_or(usize::MAX));
             Ok(langs)
         }
@@ -1391,6 +2131,19 @@ fn supported_languages_help() -> String {
         .join(", ")
 }
 
+/// Register the external runtimes (`[runtimes.*]`) and runtime variants (`[variants.*]`)
+/// declared in a project's polybench.toml so their names parse in `.bench` files and `--lang`.
+/// No-op outside a project.
+fn register_project_runtimes(project_root: &std::path::Path) -> Result<()> {
+    if !project_root.join(project::MANIFEST_FILENAME).exists() {
+        return Ok(());
+    }
+    let manifest = project::load_manifest(project_root)?;
+    runtime::register_external_runtimes(project_root, &manifest.runtimes)?;
+    runtime::register_runtime_variants(project_root, &manifest.variants)?;
+    Ok(())
+}
+
 /// Validate that all languages used in the IR are configured in polybench.toml.
 /// Returns Err if any language is used but not configured.
 fn validate_runtime_configuration(
@@ -1425,6 +2178,13 @@ async fn cmd_codegen(file: &PathBuf, lang: &str, output: &PathBuf) -> Result<()>
     // Lower to IR
     let ir = ir::lower(&ast, file.parent())?;
 
+    // The generated code loads large fixtures from their shared files
+    for fixture in ir.suites.iter().flat_map(|suite| &suite.fixtures) {
+        fixture.write_external_file().map_err(|e| {
+            miette::miette!("Failed to write data file for fixture '{}': {}", fixture.name, e)
+        })?;
+    }
+
     // Create output directory
     std::fs::create_dir_all(output)
         .map_err(|e| miette::miette!("Failed to create output directory: {}", e))?;
@@ -1733,7 +2493,7 @@ fn init_interactive() -> Result<(String, Vec<String>, bool)> {
             .join(" + ")
     );
     let mut lang_choices = vec![all_label.as_str()];
-    for lang in supported {
+    for lang in &supported {
         lang_choices.push(poly_bench_runtime::lang_label(*lang));
     }
     let mut defaults = vec![false; lang_choices.len()];
@@ -2503,6 +3263,20 @@ fn cmd_add_runtime(runtime: &str) -> Result<()> {
                 dependencies: std::collections::HashMap::new(),
             });
         }
+        Lang::External(_) => {
+            return Err(miette::miette!(
+                "{} is an external runtime; declare it under [runtimes.{}] in polybench.toml",
+                lang_str,
+                lang_str
+            ));
+        }
+        Lang::Variant(_) => {
+            return Err(miette::miette!(
+                "{} is a runtime variant; it is configured under [variants.{}] in polybench.toml",
+                lang_str,
+                lang_str
+            ));
+        }
     }
 
     project::save_manifest(&project_root, &manifest)?;
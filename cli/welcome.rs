@@ -69,6 +69,7 @@ pub fn show_welcome_and_maybe_mark_seen() {
         &[
             ("init", "Create a new poly-bench project"),
             ("run", "Run benchmarks from a DSL file or project"),
+            ("watch", "Re-run changed benchmarks when sources change"),
             ("compile", "Compile-check benchmarks without running"),
             ("check", "Parse and validate a benchmark DSL file"),
             ("codegen", "Generate code from a DSL file without running"),
@@ -263,7 +263,7 @@ const runtimeDetails = [
   {
     lang: 'TypeScript',
     runtime: 'Node.js subprocess',
-    memory: 'process.memoryUsage()',
+    memory: 'V8 heap statistics + forced GC',
     accent: '#3178C6',
   },
   {
@@ -275,13 +275,13 @@ const runtimeDetails = [
   {
     lang: 'Python',
     runtime: 'venv + subprocess',
-    memory: 'tracemalloc / sys.getsizeof',
+    memory: 'tracemalloc',
     accent: '#3776AB',
   },
   {
     lang: 'Zig',
     runtime: 'zig build + subprocess',
-    memory: 'Zig allocator tracking',
+    memory: 'Counting allocator',
     accent: '#F7A41D',
   },
   {
@@ -293,7 +293,7 @@ const runtimeDetails = [
   {
     lang: 'C#',
     runtime: 'dotnet build + subprocess',
-    memory: 'GC.GetTotalMemory',
+    memory: 'GC.GetAllocatedBytesForCurrentThread',
     accent: '#239120',
   },
 ]
@@ -234,6 +234,10 @@ export async function activate(context: ExtensionContext): Promise<void> {
         workspace.createFileSystemWatcher('**/Cargo.lock'),
         workspace.createFileSystemWatcher('**/go.sum'),
         workspace.createFileSystemWatcher('**/requirements.txt'),
+        // External runtimes and variants declared by the project
+        workspace.createFileSystemWatcher('**/polybench.toml'),
+        // Results of the last `poly-bench run`, shown as inlay hints
+        workspace.createFileSystemWatcher('**/out/results.json'),
       ],
     },
     outputChannel,
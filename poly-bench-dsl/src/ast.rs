@@ -1,7 +1,12 @@
 //! AST types for the poly-bench DSL
 
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Schema, SchemaObject},
+    JsonSchema,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::HashMap, sync::RwLock};
 
 /// Source location span for error reporting
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -33,7 +38,7 @@ impl Default for Span {
 }
 
 /// Supported programming languages
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Lang {
     Go,
     TypeScript,
@@ -42,9 +47,14 @@ pub enum Lang {
     C,
     CSharp,
     Zig,
+    /// A language provided by an external runtime declared in `polybench.toml`
+    External(ExternalLang),
+    /// A named toolchain/flags variant of another language declared in `polybench.toml`
+    Variant(RuntimeVariant),
 }
 
 impl Lang {
+    /// Built-in languages (external runtimes are not included)
     pub const ALL: [Lang; 7] =
         [Lang::Go, Lang::TypeScript, Lang::Rust, Lang::Python, Lang::C, Lang::CSharp, Lang::Zig];
 
@@ -61,10 +71,18 @@ impl Lang {
             "c" => Some(Lang::C),
             "csharp" | "cs" => Some(Lang::CSharp),
             "zig" | "z" => Some(Lang::Zig),
-            _ => None,
+            name => ExternalLang::lookup(name).map(Lang::External),
         }
     }
 
+    /// Parse a language or a registered runtime variant name (`--lang`, `baseline:`).
+    ///
+    /// Code blocks are always written for a base language, so the parser's language positions
+    /// use [`Lang::from_str`] instead.
+    pub fn from_str_with_variants(s: &str) -> Option<Self> {
+        Self::from_str(s).or_else(|| RuntimeVariant::lookup(s).map(Lang::Variant))
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Lang::Go => "go",
@@ -74,6 +92,26 @@ impl Lang {
             Lang::C => "c",
             Lang::CSharp => "csharp",
             Lang::Zig => "zig",
+            Lang::External(ext) => ext.name(),
+            Lang::Variant(variant) => variant.name(),
+        }
+    }
+
+    /// Whether this language is provided by an external runtime
+    pub fn is_external(&self) -> bool {
+        matches!(self, Lang::External(_))
+    }
+
+    /// Whether this is a runtime variant of another language
+    pub fn is_variant(&self) -> bool {
+        matches!(self, Lang::Variant(_))
+    }
+
+    /// The language whose code this runs: the base language for variants, otherwise itself
+    pub fn base(&self) -> Lang {
+        match self {
+            Lang::Variant(variant) => variant.base(),
+            other => *other,
         }
     }
 
@@ -87,6 +125,8 @@ impl Lang {
             Lang::C => &["c"],
             Lang::CSharp => &["csharp", "cs"],
             Lang::Zig => &["zig", "z"],
+            Lang::External(ext) => ext.aliases(),
+            Lang::Variant(variant) => variant.aliases(),
         }
     }
 
@@ -100,6 +140,8 @@ impl Lang {
             Lang::C => "C",
             Lang::CSharp => "C#",
             Lang::Zig => "Zig",
+            Lang::External(ext) => ext.name(),
+            Lang::Variant(variant) => variant.name(),
         }
     }
 
@@ -107,13 +149,14 @@ impl Lang {
     pub fn tree_sitter_injection_name(&self) -> &'static str {
         match self {
             Lang::CSharp => "c_sharp",
+            Lang::Variant(variant) => variant.base().tree_sitter_injection_name(),
             _ => self.as_str(),
         }
     }
 
     /// Import sections use paren blocks for Go only.
     pub fn uses_paren_import_block(&self) -> bool {
-        matches!(self, Lang::Go)
+        matches!(self.base(), Lang::Go)
     }
 }
 
@@ -123,6 +166,226 @@ impl std::fmt::Display for Lang {
     }
 }
 
+/// Built-in languages serialize by variant name (`"Go"`, `"TypeScript"`, ...); external
+/// languages and runtime variants by their registered name.
+impl Serialize for Lang {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = match self {
+            Lang::Go => "Go",
+            Lang::TypeScript => "TypeScript",
+            Lang::Rust => "Rust",
+            Lang::Python => "Python",
+            Lang::C => "C",
+            Lang::CSharp => "CSharp",
+            Lang::Zig => "Zig",
+            Lang::External(ext) => ext.name(),
+            Lang::Variant(variant) => variant.name(),
+        };
+        serializer.serialize_str(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lang {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(match name.as_str() {
+            "Go" => Lang::Go,
+            "TypeScript" => Lang::TypeScript,
+            "Rust" => Lang::Rust,
+            "Python" => Lang::Python,
+            "C" => Lang::C,
+            "CSharp" => Lang::CSharp,
+            "Zig" => Lang::Zig,
+            // Same order as `Lang::from_str_with_variants`: external runtimes, then variants
+            other => {
+                if let Some(ext) = ExternalLang::lookup(other) {
+                    Lang::External(ext)
+                } else if let Some(variant) = RuntimeVariant::lookup(other) {
+                    Lang::Variant(variant)
+                } else {
+                    return Err(serde::de::Error::custom(format!(
+                        "unknown language '{}' (external runtimes and variants must be \
+                         declared in polybench.toml)",
+                        other
+                    )));
+                }
+            }
+        })
+    }
+}
+
+impl JsonSchema for Lang {
+    fn schema_name() -> String {
+        "Lang".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        let mut schema =
+            SchemaObject { instance_type: Some(InstanceType::String.into()), ..Default::default() };
+        schema.metadata().description = Some(
+            "Go, TypeScript, Rust, Python, C, CSharp, Zig, or the name of an external runtime or \
+             runtime variant"
+                .to_string(),
+        );
+        schema.into()
+    }
+}
+
+/// Registered external language names, indexed by [`ExternalLang`]
+static EXTERNAL_LANGS: RwLock<Vec<ExternalLangEntry>> = RwLock::new(Vec::new());
+
+struct ExternalLangEntry {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    /// Cleared by [`ExternalLang::clear`]; inactive names no longer resolve
+    active: bool,
+}
+
+/// Handle to a language provided by an external runtime.
+///
+/// Names are interned process-wide so `Lang` stays `Copy`; register them (from the
+/// `[runtimes.*]` tables in `polybench.toml`) before parsing `.bench` files so that
+/// `Lang::from_str` recognizes them. Switching projects calls [`ExternalLang::clear`] first, so
+/// only the current project's names resolve; each distinct name is interned once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExternalLang(u16);
+
+impl ExternalLang {
+    /// Register an external language name (lowercased), returning the existing entry if the
+    /// name is already known.
+    pub fn register(name: &str) -> Lang {
+        let name = name.to_lowercase();
+        let mut langs = EXTERNAL_LANGS.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(idx) = langs.iter().position(|e| e.name == name) {
+            langs[idx].active = true;
+            return Lang::External(ExternalLang(idx as u16));
+        }
+        let name: &'static str = Box::leak(name.into_boxed_str());
+        let aliases: &'static [&'static str] = Box::leak(vec![name].into_boxed_slice());
+        langs.push(ExternalLangEntry { name, aliases, active: true });
+        Lang::External(ExternalLang((langs.len() - 1) as u16))
+    }
+
+    /// Unregister all external language names. Existing handles stay valid but their names no
+    /// longer resolve until registered again.
+    pub fn clear() {
+        let mut langs = EXTERNAL_LANGS.write().unwrap_or_else(|e| e.into_inner());
+        for entry in langs.iter_mut() {
+            entry.active = false;
+        }
+    }
+
+    /// Look up a registered external language by name
+    pub fn lookup(name: &str) -> Option<ExternalLang> {
+        let langs = EXTERNAL_LANGS.read().unwrap_or_else(|e| e.into_inner());
+        langs
+            .iter()
+            .position(|e| e.active && e.name.eq_ignore_ascii_case(name))
+            .map(|i| ExternalLang(i as u16))
+    }
+
+    /// All registered external languages, in registration order
+    pub fn registered() -> Vec<Lang> {
+        let langs = EXTERNAL_LANGS.read().unwrap_or_else(|e| e.into_inner());
+        (0..langs.len())
+            .filter(|&i| langs[i].active)
+            .map(|i| Lang::External(ExternalLang(i as u16)))
+            .collect()
+    }
+
+    /// The registered name
+    pub fn name(self) -> &'static str {
+        EXTERNAL_LANGS.read().unwrap_or_else(|e| e.into_inner())[self.0 as usize].name
+    }
+
+    fn aliases(self) -> &'static [&'static str] {
+        EXTERNAL_LANGS.read().unwrap_or_else(|e| e.into_inner())[self.0 as usize].aliases
+    }
+}
+
+/// Registered runtime variants, indexed by [`RuntimeVariant`]
+static VARIANT_LANGS: RwLock<Vec<VariantLangEntry>> = RwLock::new(Vec::new());
+
+struct VariantLangEntry {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    base: Lang,
+    /// Cleared by [`RuntimeVariant::clear`]; inactive names no longer resolve
+    active: bool,
+}
+
+/// Handle to a runtime variant: a named contestant that runs a base language's implementations
+/// with its own toolchain, flags and environment (`[variants.*]` in `polybench.toml`).
+///
+/// Interned process-wide and cleared on project switches like [`ExternalLang`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuntimeVariant(u16);
+
+impl RuntimeVariant {
+    /// Register a variant name (lowercased) of `base`, returning the existing entry if the name
+    /// is already known. Returns `None` if the name is registered with a different base; a name
+    /// left over from a cleared project takes the new base.
+    pub fn register(name: &str, base: Lang) -> Option<Lang> {
+        let name = name.to_lowercase();
+        let base = base.base();
+        let mut variants = VARIANT_LANGS.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(idx) = variants.iter().position(|e| e.name == name) {
+            let entry = &mut variants[idx];
+            if entry.active && entry.base != base {
+                return None;
+            }
+            entry.base = base;
+            entry.active = true;
+            return Some(Lang::Variant(RuntimeVariant(idx as u16)));
+        }
+        let name: &'static str = Box::leak(name.into_boxed_str());
+        let aliases: &'static [&'static str] = Box::leak(vec![name].into_boxed_slice());
+        variants.push(VariantLangEntry { name, aliases, base, active: true });
+        Some(Lang::Variant(RuntimeVariant((variants.len() - 1) as u16)))
+    }
+
+    /// Unregister all variant names. Existing handles stay valid but their names no longer
+    /// resolve until registered again.
+    pub fn clear() {
+        let mut variants = VARIANT_LANGS.write().unwrap_or_else(|e| e.into_inner());
+        for entry in variants.iter_mut() {
+            entry.active = false;
+        }
+    }
+
+    /// Look up a registered variant by name
+    pub fn lookup(name: &str) -> Option<RuntimeVariant> {
+        let variants = VARIANT_LANGS.read().unwrap_or_else(|e| e.into_inner());
+        variants
+            .iter()
+            .position(|e| e.active && e.name.eq_ignore_ascii_case(name))
+            .map(|i| RuntimeVariant(i as u16))
+    }
+
+    /// All registered variants, in registration order
+    pub fn registered() -> Vec<Lang> {
+        let variants = VARIANT_LANGS.read().unwrap_or_else(|e| e.into_inner());
+        (0..variants.len())
+            .filter(|&i| variants[i].active)
+            .map(|i| Lang::Variant(RuntimeVariant(i as u16)))
+            .collect()
+    }
+
+    /// The registered name
+    pub fn name(self) -> &'static str {
+        VARIANT_LANGS.read().unwrap_or_else(|e| e.into_inner())[self.0 as usize].name
+    }
+
+    /// The language whose implementations the variant runs
+    pub fn base(self) -> Lang {
+        VARIANT_LANGS.read().unwrap_or_else(|e| e.into_inner())[self.0 as usize].base
+    }
+
+    fn aliases(self) -> &'static [&'static str] {
+        VARIANT_LANGS.read().unwrap_or_else(|e| e.into_inner())[self.0 as usize].aliases
+    }
+}
+
 /// A code block with source information
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CodeBlock {
@@ -265,7 +528,7 @@ impl Default for BenchMode {
 }
 
 /// High-level suite category
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum SuiteType {
     /// Memory-focused benchmarking suites
     Memory,
@@ -324,7 +587,7 @@ impl RunMode {
 }
 
 /// Benchmark kind (sync vs async sequential)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
 pub enum BenchmarkKind {
     /// Traditional synchronous benchmark
     #[default]
@@ -411,6 +674,23 @@ impl FixtureParam {
     }
 }
 
+/// A parameter axis on a benchmark: `bench sort(n in [100, 1000])`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchParam {
+    /// Parameter name (bound in every language implementation)
+    pub name: String,
+    /// Values the benchmark is expanded over, in source order
+    pub values: Vec<Value>,
+    /// Source location of the parameter name
+    pub span: Span,
+}
+
+impl BenchParam {
+    pub fn new(name: String, values: Vec<Value>, span: Span) -> Self {
+        Self { name, values, span }
+    }
+}
+
 /// A property value in the DSL
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Value {
@@ -439,6 +719,41 @@ impl Value {
     }
 }
 
+/// A fixture data generator call such as `@random(bytes: 1024, seed: 42)`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureGenerator {
+    /// Generator name without the `@` (`random`, `sequence`, `repeat`)
+    pub name: String,
+    /// Named arguments in source order
+    pub args: Vec<(String, Value)>,
+    pub span: Span,
+}
+
+impl FixtureGenerator {
+    /// Look up an argument by name
+    pub fn arg(&self, name: &str) -> Option<&Value> {
+        self.args.iter().find(|(key, _)| key == name).map(|(_, value)| value)
+    }
+}
+
+impl std::fmt::Display for FixtureGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|(key, value)| match value {
+                Value::String(s) => format!("{}: {:?}", key, s),
+                Value::Number(n) => format!("{}: {}", key, n),
+                Value::Float(x) => format!("{}: {}", key, x),
+                Value::Bool(b) => format!("{}: {}", key, b),
+                Value::Identifier(id) => format!("{}: {}", key, id),
+                Value::FileRef(path) => format!("{}: @file({:?})", key, path),
+            })
+            .collect();
+        write!(f, "@{}({})", self.name, args.join(", "))
+    }
+}
+
 /// A standard library import: use std::module
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UseStd {
@@ -734,12 +1049,27 @@ pub struct Suite {
     pub cv_threshold: Option<f64>,
     /// Number of times to run each benchmark for statistical consistency (default: 1)
     pub count: Option<u64>,
+    /// Keep adding runs until the 95% CI half-width is below this percentage of the median
+    #[serde(default)]
+    pub ci_target: Option<f64>,
+    /// Run budget for adaptive runs (default: 30)
+    #[serde(default)]
+    pub max_runs: Option<u64>,
+    /// Time budget for adaptive runs in milliseconds
+    #[serde(default)]
+    pub max_time_ms: Option<u64>,
     /// Fairness mode for execution and comparison (default: strict)
     pub fairness_mode: Option<FairnessMode>,
     /// Optional deterministic seed for fairness randomization
     pub fairness_seed: Option<u64>,
 
     // Observability settings (Phase 2B)
+    /// Collect hardware performance counters around the timed region (default: false)
+    #[serde(default)]
+    pub counters: bool,
+    /// Check that all languages return equivalent output (default: false)
+    #[serde(default)]
+    pub equivalent: bool,
     /// Async sampling policy (default: timeBudgeted)
     pub async_sampling_policy: Option<AsyncSamplingPolicy>,
     /// Async warmup cap override
@@ -782,8 +1112,13 @@ impl Suite {
             outlier_detection: true, // Enabled by default for statistical accuracy
             cv_threshold: None,      // Uses default (5.0%) when None
             count: None,             // Uses default (1) when None - single run
-            fairness_mode: None,     // Uses default (strict) when None
+            ci_target: None,         // Adaptive runs disabled when None
+            max_runs: None,
+            max_time_ms: None,
+            fairness_mode: None, // Uses default (strict) when None
             fairness_seed: None,
+            counters: false,
+            equivalent: false,
             async_sampling_policy: None, // Uses default (timeBudgeted) when None
             async_warmup_cap: None,
             async_sample_cap: None,
@@ -809,12 +1144,17 @@ pub struct Fixture {
     pub data: Option<String>,
     /// Generic fixture data source file reference
     pub data_file: Option<String>,
+    /// Generated fixture data source (`data: @random(...)`)
+    pub generator: Option<FixtureGenerator>,
     /// Data encoding (hex/raw/utf8/base64)
     pub encoding: Option<String>,
     /// Structured data format (json/csv)
     pub format: Option<String>,
     /// Optional selector for structured data extraction
     pub selector: Option<String>,
+    /// Load the data from a file at startup instead of inlining it (default: automatic for
+    /// large fixtures)
+    pub external: Option<bool>,
     /// Compatibility: inline hex fixture data
     pub hex_data: Option<String>,
     /// Compatibility: file-based hex fixture data
@@ -839,9 +1179,11 @@ impl Fixture {
             description: None,
             data: None,
             data_file: None,
+            generator: None,
             encoding: None,
             format: None,
             selector: None,
+            external: None,
             hex_data: None,
             hex_file: None,
             implementations: HashMap::new(),
@@ -899,6 +1241,21 @@ pub struct Benchmark {
     pub cv_threshold: Option<f64>,
     /// Override count setting (None = inherit from suite)
     pub count: Option<u64>,
+    /// Override adaptive CI target (None = inherit from suite)
+    #[serde(default)]
+    pub ci_target: Option<f64>,
+    /// Override adaptive run budget (None = inherit from suite)
+    #[serde(default)]
+    pub max_runs: Option<u64>,
+    /// Override adaptive time budget (None = inherit from suite)
+    #[serde(default)]
+    pub max_time_ms: Option<u64>,
+    /// Override hardware counter collection (None = inherit from suite)
+    #[serde(default)]
+    pub counters: Option<bool>,
+    /// Override cross-language output equivalence checking (None = inherit from suite)
+    #[serde(default)]
+    pub equivalent: Option<bool>,
 
     // Phase 3: Lifecycle hooks
     /// Pre-benchmark hook (runs once before iterations)
@@ -914,6 +1271,10 @@ pub struct Benchmark {
     pub implementations: HashMap<Lang, CodeBlock>,
     /// Order of language implementations as defined in source
     pub impl_order: Vec<Lang>,
+
+    /// Parameter axes; the benchmark is expanded over their cartesian product
+    #[serde(default)]
+    pub params: Vec<BenchParam>,
 }
 
 impl Benchmark {
@@ -936,15 +1297,31 @@ impl Benchmark {
             outlier_detection: None,
             cv_threshold: None,
             count: None,
+            ci_target: None,
+            max_runs: None,
+            max_time_ms: None,
+            counters: None,
+            equivalent: None,
             before: HashMap::new(),
             after: HashMap::new(),
             each: HashMap::new(),
             hook_style: HookStyle::default(),
             implementations: HashMap::new(),
             impl_order: Vec::new(),
+            params: Vec::new(),
         }
     }
 
+    /// Check if this is a parameterized benchmark
+    pub fn is_parameterized(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    /// Number of concrete benchmarks this definition expands into
+    pub fn expansion_count(&self) -> usize {
+        self.params.iter().map(|p| p.values.len()).product()
+    }
+
     /// Check if this benchmark has any lifecycle hooks for a language
     pub fn has_hooks(&self, lang: Lang) -> bool {
         self.before.contains_key(&lang) ||
@@ -99,6 +99,20 @@ pub enum ParseError {
     },
 }
 
+/// A parse failure that is also a registered lint rule (see [`crate::lint::RULES`]), so editors
+/// can report it under the rule's ID
+#[derive(Error, Debug, Diagnostic)]
+#[error("{message}")]
+#[diagnostic(code(poly_bench::parse::rule_violation))]
+pub struct RuleViolation {
+    pub rule: &'static str,
+    pub message: String,
+    #[label("{rule}")]
+    pub span: Span,
+    #[source_code]
+    pub(crate) source_code: NamedSource,
+}
+
 // Implement From<Span> for SourceSpan to work with miette
 impl From<Span> for SourceSpan {
     fn from(span: Span) -> Self {
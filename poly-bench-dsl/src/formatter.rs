@@ -132,7 +132,7 @@ pub fn format_file(file: &File) -> String {
 }
 
 /// Format an AST with optional language order for setup/benchmark sections.
-/// Pass `Some(supported_languages())` from poly-bench-runtime when available.
+/// Pass `Some(&supported_languages())` from poly-bench-runtime when available.
 pub fn format_file_with_options(file: &File, lang_order: Option<&[Lang]>) -> String {
     let mut out = String::new();
     let lang_order: &[Lang] = match lang_order {
@@ -192,7 +192,7 @@ pub fn format_file_with_source(file: &File, original_source: &str) -> String {
 }
 
 /// Format with comment preservation and optional language order.
-/// Pass `Some(supported_languages())` from poly-bench-runtime when available.
+/// Pass `Some(&supported_languages())` from poly-bench-runtime when available.
 pub fn format_file_with_source_and_options(
     file: &File,
     original_source: &str,
@@ -335,6 +335,21 @@ fn format_suite_with_source(
     if let Some(count) = suite.count {
         write!(out, "{}count: {}\n", inner, count).unwrap();
     }
+    if let Some(ci_target) = suite.ci_target {
+        writeln!(out, "{}ciTarget: {}", inner, ci_target).unwrap();
+    }
+    if let Some(max_runs) = suite.max_runs {
+        writeln!(out, "{}maxRuns: {}", inner, max_runs).unwrap();
+    }
+    if let Some(max_time) = suite.max_time_ms {
+        writeln!(out, "{}maxTime: {}ms", inner, max_time).unwrap();
+    }
+    if suite.counters {
+        write!(out, "{}counters: true\n", inner).unwrap();
+    }
+    if suite.equivalent {
+        write!(out, "{}equivalent: true\n", inner).unwrap();
+    }
     if let Some(policy) = suite.async_sampling_policy {
         let policy_name = match policy {
             AsyncSamplingPolicy::FixedCap => "fixedCap",
@@ -562,6 +577,21 @@ fn format_suite(out: &mut String, suite: &Suite, indent_level: usize, lang_order
     if let Some(count) = suite.count {
         write!(out, "{}count: {}\n", inner, count).unwrap();
     }
+    if let Some(ci_target) = suite.ci_target {
+        writeln!(out, "{}ciTarget: {}", inner, ci_target).unwrap();
+    }
+    if let Some(max_runs) = suite.max_runs {
+        writeln!(out, "{}maxRuns: {}", inner, max_runs).unwrap();
+    }
+    if let Some(max_time) = suite.max_time_ms {
+        writeln!(out, "{}maxTime: {}ms", inner, max_time).unwrap();
+    }
+    if suite.counters {
+        write!(out, "{}counters: true\n", inner).unwrap();
+    }
+    if suite.equivalent {
+        write!(out, "{}equivalent: true\n", inner).unwrap();
+    }
 
     // Observability settings (Phase 2B) - only output non-default values
     if let Some(policy) = suite.async_sampling_policy {
@@ -742,6 +772,9 @@ fn format_fixture(out: &mut String, fixture: &Fixture, indent_level: usize) {
     if let Some(ref path) = fixture.data_file {
         write!(out, "{}data: @file(\"{}\")\n", inner, escape_string(path)).unwrap();
     }
+    if let Some(ref generator) = fixture.generator {
+        writeln!(out, "{}data: {}", inner, generator).unwrap();
+    }
     if let Some(ref encoding) = fixture.encoding {
         write!(out, "{}encoding: {}\n", inner, encoding).unwrap();
     }
@@ -751,6 +784,9 @@ fn format_fixture(out: &mut String, fixture: &Fixture, indent_level: usize) {
     if let Some(ref selector) = fixture.selector {
         write!(out, "{}selector: \"{}\"\n", inner, escape_string(selector)).unwrap();
     }
+    if let Some(external) = fixture.external {
+        writeln!(out, "{}external: {}", inner, external).unwrap();
+    }
     if let Some(ref hex) = fixture.hex_data {
         write!(out, "{}hex: \"{}\"\n", inner, escape_string(hex)).unwrap();
     }
@@ -786,7 +822,15 @@ fn format_benchmark(out: &mut String, bench: &Benchmark, indent_level: usize, la
     let pad = INDENT.repeat(indent_level);
     let inner = INDENT.repeat(indent_level + 1);
 
-    write!(out, "{}{} {} {{\n", pad, bench.kind.as_keyword(), bench.name).unwrap();
+    write!(
+        out,
+        "{}{} {}{} {{\n",
+        pad,
+        bench.kind.as_keyword(),
+        bench.name,
+        format_bench_params(&bench.params)
+    )
+    .unwrap();
 
     if let Some(ref desc) = bench.description {
         write!(out, "{}description: \"{}\"\n", inner, escape_string(desc)).unwrap();
@@ -835,6 +879,22 @@ fn format_benchmark(out: &mut String, bench: &Benchmark, indent_level: usize, la
     if let Some(count) = bench.count {
         write!(out, "{}count: {}\n", inner, count).unwrap();
     }
+    if let Some(ci_target) = bench.ci_target {
+        writeln!(out, "{}ciTarget: {}", inner, ci_target).unwrap();
+    }
+    if let Some(max_runs) = bench.max_runs {
+        writeln!(out, "{}maxRuns: {}", inner, max_runs).unwrap();
+    }
+    if let Some(max_time) = bench.max_time_ms {
+        writeln!(out, "{}maxTime: {}ms", inner, max_time).unwrap();
+    }
+    if let Some(counters) = bench.counters {
+        write!(out, "{}counters: {}\n", inner, if counters { "true" } else { "false" }).unwrap();
+    }
+    if let Some(equivalent) = bench.equivalent {
+        write!(out, "{}equivalent: {}\n", inner, if equivalent { "true" } else { "false" })
+            .unwrap();
+    }
 
     // Skip and validate hooks (always flat syntax)
     for lang in lang_order {
@@ -1207,6 +1267,32 @@ fn format_params_default_order(directive: &ChartDirective, inner2: &str) -> Vec<
     params
 }
 
+/// Format benchmark parameter axes as `(n in [100, 1000], ...)`, or nothing when empty
+fn format_bench_params(params: &[BenchParam]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let axes: Vec<String> = params
+        .iter()
+        .map(|p| {
+            let values: Vec<String> = p
+                .values
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => format!("\"{}\"", escape_string(s)),
+                    Value::Number(n) => n.to_string(),
+                    Value::Float(f) => format!("{:?}", f),
+                    Value::Bool(b) => b.to_string(),
+                    Value::Identifier(s) => s.clone(),
+                    Value::FileRef(path) => format!("@file(\"{}\")", escape_string(path)),
+                })
+                .collect();
+            format!("{} in [{}]", p.name, values.join(", "))
+        })
+        .collect();
+    format!("({})", axes.join(", "))
+}
+
 fn escape_string(s: &str) -> String {
     s.replace('\\', "\\\\")
         .replace('"', "\\\"")
@@ -1254,6 +1340,31 @@ suite example {
         assert!(formatted.contains("theme: \"light\""), "Expected theme parameter");
     }
 
+    #[test]
+    fn test_format_bench_params_roundtrip() {
+        let input = r#"declare suite sorting performance timeBased sameDataset: false {
+    bench sort(n in [100, 1000], ratio in [0.5], label in ["a"]) {
+        go: sortInts(n)
+    }
+}"#;
+        let ast = parse(input, "test.bench").unwrap();
+        let formatted = format_file(&ast);
+        assert!(
+            formatted.contains("bench sort(n in [100, 1000], ratio in [0.5], label in [\"a\"]) {"),
+            "Expected parameter axes in output: {}",
+            formatted
+        );
+        let roundtrip = parse(&formatted, "test.bench").unwrap();
+        let values = |file: &crate::File| -> Vec<(String, Vec<crate::Value>)> {
+            file.suites[0].benchmarks[0]
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), p.values.clone()))
+                .collect()
+        };
+        assert_eq!(values(&ast), values(&roundtrip));
+    }
+
     #[test]
     fn test_format_roundtrip() {
         let input = r#"declare suite example performance iterationBased sameDataset: true {
@@ -1380,4 +1491,45 @@ suite example {
         assert!(formatted.contains("asyncWarmupCap: 8"));
         assert!(formatted.contains("asyncSampleCap: 64"));
     }
+
+    #[test]
+    fn test_format_adaptive_settings_roundtrip() {
+        let input = r#"declare suite perf performance timeBased sameDataset: false {
+    ciTarget: 2.5
+    maxRuns: 40
+    bench tight {
+        ciTarget: 1
+        maxTime: 30s
+        go: work()
+    }
+}"#;
+        let ast = parse(input, "test.bench").unwrap();
+        let formatted = format_file(&ast);
+        assert!(formatted.contains("    ciTarget: 2.5\n    maxRuns: 40\n"));
+        assert!(formatted.contains("        ciTarget: 1\n        maxTime: 30000ms\n"));
+        let reparsed = parse(&formatted, "test.bench").unwrap();
+        assert_eq!(reparsed.suites[0].ci_target, Some(2.5));
+        assert_eq!(reparsed.suites[0].benchmarks[0].max_time_ms, Some(30_000));
+    }
+
+    #[test]
+    fn test_format_counters_roundtrip() {
+        let input = r#"declare suite perf performance timeBased sameDataset: false {
+    counters: true
+    bench inherited {
+        go: work()
+    }
+    bench disabled {
+        counters: false
+        go: work()
+    }
+}"#;
+        let ast = parse(input, "test.bench").unwrap();
+        let formatted = format_file(&ast);
+        assert!(formatted.contains("    counters: true\n"));
+        assert!(formatted.contains("        counters: false\n"));
+        let reparsed = parse(&formatted, "test.bench").unwrap();
+        assert!(reparsed.suites[0].counters);
+        assert_eq!(reparsed.suites[0].benchmarks[1].counters, Some(false));
+    }
 }
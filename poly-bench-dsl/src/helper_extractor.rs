@@ -0,0 +1,142 @@
+//! Helper function extraction for undefined-function checks
+//!
+//! Runtimes implement [`HelperFunctionExtractor`] to provide language-specific logic for
+//! extracting function names from helpers blocks and builtin identifiers to ignore.
+//!
+//! Extractors are registered at startup via `set_helper_function_extractors()` (called by
+//! poly-bench-runtime). If unset, undefined-function checks are skipped.
+
+use crate::Lang;
+use std::{collections::HashSet, sync::Mutex};
+
+/// Trait for extracting function names from helpers code and builtin identifiers.
+/// Used by validation for "undefined function" checks.
+pub trait HelperFunctionExtractor: Send + Sync {
+    /// Extract function names defined in a helpers block
+    fn extract_functions(&self, code: &str) -> HashSet<String>;
+
+    /// Builtin identifiers to ignore when checking for undefined function calls
+    fn builtins(&self) -> &'static [&'static str];
+}
+
+static EXTRACTORS: Mutex<Vec<(Lang, &'static dyn HelperFunctionExtractor)>> =
+    Mutex::new(Vec::new());
+
+/// Register helper function extractors from runtime crates.
+/// Called by poly-bench-runtime at application startup.
+pub fn set_helper_function_extractors(
+    extractors: Vec<(Lang, &'static dyn HelperFunctionExtractor)>,
+) {
+    *EXTRACTORS.lock().unwrap() = extractors;
+}
+
+/// The registered extractor for `lang`; variants use their base language's
+pub fn helper_function_extractor(lang: Lang) -> Option<&'static dyn HelperFunctionExtractor> {
+    let base = lang.base();
+    EXTRACTORS.lock().unwrap().iter().find(|(l, _)| *l == base).map(|(_, e)| *e)
+}
+
+/// A function call found in code, with its position
+#[derive(Debug, Clone)]
+pub(crate) struct FunctionCall {
+    pub name: String,
+    /// Byte offset within the code string where the function name starts
+    pub start_offset: usize,
+    /// Byte offset where the function name ends
+    pub end_offset: usize,
+}
+
+/// Extract function calls from benchmark implementation code
+/// Returns function names that look like custom helper calls (not builtins or method calls)
+pub(crate) fn extract_function_calls(
+    code: &str,
+    lang: Lang,
+    extractor: &dyn HelperFunctionExtractor,
+) -> Vec<FunctionCall> {
+    let mut calls = Vec::new();
+    let builtins: HashSet<&str> = extractor.builtins().iter().copied().collect();
+
+    // Find function call patterns: identifier followed by (
+    // We need to be careful to:
+    // 1. Skip method calls (preceded by .)
+    // 2. Skip type casts/constructors in some cases
+    // 3. Handle Rust's macro calls (name!)
+
+    let chars: Vec<char> = code.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        // Skip if we're inside a string literal
+        if chars[i] == '"' || chars[i] == '\'' || chars[i] == '`' {
+            let quote = chars[i];
+            i += 1;
+            while i < chars.len() {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    i += 2; // Skip escaped character
+                } else if chars[i] == quote {
+                    i += 1;
+                    break;
+                } else {
+                    i += 1;
+                }
+            }
+            continue;
+        }
+
+        // Look for identifier start
+        if chars[i].is_alphabetic() || chars[i] == '_' {
+            let start = i;
+
+            // Collect the identifier
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let name_end = i;
+
+            let name: String = chars[start..i].iter().collect();
+
+            // Skip whitespace
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+
+            // Check if followed by ( - this is a function call
+            if i < chars.len() && chars[i] == '(' {
+                // Check if this is NOT a method call (preceded by .)
+                let is_method_call = start > 0 && {
+                    let mut j = start - 1;
+                    while j > 0 && chars[j].is_whitespace() {
+                        j -= 1;
+                    }
+                    chars[j] == '.'
+                };
+
+                // Check if this is a Rust reference (&name)
+                let is_rust_ref = lang.base() == Lang::Rust && start > 0 && chars[start - 1] == '&';
+
+                // Check if this is a Zig builtin (@name)
+                let is_zig_builtin = start > 0 && chars[start - 1] == '@';
+
+                if !is_method_call &&
+                    !is_rust_ref &&
+                    !is_zig_builtin &&
+                    !builtins.contains(name.as_str())
+                {
+                    // Calculate byte offsets
+                    let byte_start: usize = chars[..start].iter().map(|c| c.len_utf8()).sum();
+                    let byte_end: usize = chars[..name_end].iter().map(|c| c.len_utf8()).sum();
+
+                    calls.push(FunctionCall {
+                        name,
+                        start_offset: byte_start,
+                        end_offset: byte_end,
+                    });
+                }
+            }
+        } else {
+            i += 1;
+        }
+    }
+
+    calls
+}
@@ -6,19 +6,22 @@ mod ast;
 pub mod chart_params;
 mod error;
 mod formatter;
+pub mod helper_extractor;
 mod lexer;
+pub mod lint;
 mod parser;
 mod tokens;
 pub mod validate;
 
 pub use ast::*;
-pub use error::{NamedSource, ParseError};
+pub use error::{NamedSource, ParseError, RuleViolation};
 pub use formatter::{
     format_file, format_file_with_options, format_file_with_source,
     format_file_with_source_and_options,
 };
 pub use validate::{
-    validate_file, validate_suite, ValidationError, ValidationResult, ValidationWarning,
+    validate_file, validate_project_file, validate_suite, ValidationError, ValidationResult,
+    ValidationWarning,
 };
 
 use miette::Result;
@@ -0,0 +1,439 @@
+//! Lint rule registry shared by `poly-bench check` and the language server
+//!
+//! Every semantic rule has a stable ID, a default severity, a docs link and an optional fix
+//! hint. Severities can be overridden per project in `polybench.toml`:
+//!
+//! ```toml
+//! [lint]
+//! unused-fixture = "allow"
+//! same-dataset-inconsistent-fixtures = "error"
+//! ```
+//!
+//! and individual findings silenced with a comment on the same or the preceding line:
+//!
+//! ```text
+//! # polybench-ignore: hook-without-impl, unused-param
+//! bench sort(n in [10, 100]) { ... }
+//! ```
+//!
+//! `# polybench-ignore-file: <rules>` silences rules for the whole file.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+};
+
+/// Base URL of the per-rule documentation
+pub const DOCS_URL: &str = "https://polybench.evm-tooling.tools/docs/core/dsl-reference";
+
+const IGNORE_DIRECTIVE: &str = "polybench-ignore:";
+const IGNORE_FILE_DIRECTIVE: &str = "polybench-ignore-file:";
+
+/// Severity of a lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// Rule is disabled
+    Allow,
+    /// Informational; shown as a hint in editors and a warning by `check`
+    Hint,
+    Warn,
+    /// Fails `poly-bench check`
+    Error,
+}
+
+impl LintLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Hint => "hint",
+            LintLevel::Warn => "warn",
+            LintLevel::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A registered lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LintRule {
+    /// Stable ID used in diagnostics, `[lint]` overrides and suppressions
+    pub id: &'static str,
+    pub default_level: LintLevel,
+    pub summary: &'static str,
+    /// How to fix a finding, when there is a mechanical fix
+    pub fix: Option<&'static str>,
+}
+
+impl LintRule {
+    /// Link to the rule's section of the DSL reference
+    pub fn docs_url(&self) -> String {
+        format!("{}#{}", DOCS_URL, self.id)
+    }
+}
+
+const fn rule_def(
+    id: &'static str,
+    default_level: LintLevel,
+    summary: &'static str,
+    fix: Option<&'static str>,
+) -> LintRule {
+    LintRule { id, default_level, summary, fix }
+}
+
+use LintLevel::{Error, Hint, Warn};
+
+/// All lint rules, grouped by the construct they check
+pub const RULES: &[LintRule] = &[
+    // Syntax
+    rule_def("syntax-error", Error, "The file does not parse", None),
+    rule_def("missing-token", Error, "A required token is missing", None),
+    rule_def("parse-error", Error, "A declaration could not be parsed", None),
+    // Imports and runtimes
+    rule_def(
+        "duplicate-import",
+        Warn,
+        "A `use std::` module is imported twice",
+        Some("Remove the duplicate `use` statement"),
+    ),
+    rule_def(
+        "missing-charting-import",
+        Error,
+        "Chart directives are used without `use std::charting`",
+        Some("Add `use std::charting`"),
+    ),
+    rule_def(
+        "runtime-not-configured",
+        Error,
+        "A language is used but not enabled in polybench.toml",
+        Some("Run `poly-bench add-runtime <lang>`"),
+    ),
+    // Suites
+    rule_def("empty-suite", Warn, "A suite has no benchmarks", None),
+    rule_def(
+        "suite-semantics-in-header",
+        Error,
+        "suiteType, runMode or sameDataset is set as a property instead of in the suite header",
+        Some("Move the value into the `declare suite` header"),
+    ),
+    rule_def(
+        "missing-suite-type",
+        Error,
+        "The suite header has no suite type",
+        Some("Declare `performance` or `memory` in the suite header"),
+    ),
+    rule_def(
+        "missing-run-mode",
+        Error,
+        "The suite header has no run mode",
+        Some("Declare `timeBased` or `iterationBased` in the suite header"),
+    ),
+    rule_def(
+        "missing-same-dataset",
+        Error,
+        "The suite header has no sameDataset flag",
+        Some("Add `sameDataset: true` or `sameDataset: false` to the suite header"),
+    ),
+    rule_def(
+        "legacy-mode-removed",
+        Error,
+        "The removed `mode` property is used",
+        Some("Remove `mode` and set the run mode in the suite header"),
+    ),
+    rule_def(
+        "suite-iterations-invalid",
+        Error,
+        "`iterations` is set on a timeBased suite",
+        Some("Use `targetTime` instead"),
+    ),
+    rule_def(
+        "suite-target-time-invalid",
+        Error,
+        "`targetTime` is set on an iterationBased suite",
+        Some("Use `iterations` instead"),
+    ),
+    rule_def("invalid-baseline", Error, "`baseline` names an unknown language", None),
+    rule_def(
+        "baseline-missing-in-benchmark",
+        Error,
+        "A benchmark does not implement the baseline language",
+        None,
+    ),
+    rule_def("invalid-fairness-mode", Error, "`fairness` has an unknown value", None),
+    rule_def(
+        "invalid-async-sampling-policy",
+        Error,
+        "`asyncSamplingPolicy` has an unknown value",
+        None,
+    ),
+    rule_def("negative-suite-property", Error, "A numeric suite property is negative", None),
+    rule_def(
+        "missing-required-language",
+        Error,
+        "A benchmark lacks a language listed in `requires`",
+        None,
+    ),
+    rule_def(
+        "spawn-anvil-outside-global-setup",
+        Error,
+        "`spawnAnvil()` is called outside `globalSetup`",
+        Some("Move the call into `globalSetup`"),
+    ),
+    // Charts
+    rule_def(
+        "chart-requires-same-dataset",
+        Error,
+        "Line and bar charts need `sameDataset: true`",
+        None,
+    ),
+    rule_def(
+        "chart-requires-multiple-benchmarks",
+        Error,
+        "Line and bar charts need at least 2 benchmarks",
+        None,
+    ),
+    rule_def("chart-requires-description", Error, "Line and bar charts need a `description`", None),
+    rule_def(
+        "invalid-chart-y-scale",
+        Error,
+        "`yScale` is not linear, log10, symlog or split",
+        None,
+    ),
+    // Setups
+    rule_def("setup-without-init", Warn, "A Go or Rust setup has no `init` section", None),
+    rule_def(
+        "async-without-init",
+        Warn,
+        "A setup is marked async but has no `init` code",
+        Some("Remove the `async` modifier"),
+    ),
+    rule_def("undefined-function", Error, "A benchmark calls a function no setup defines", None),
+    // Fixtures
+    rule_def("empty-fixture", Error, "A fixture has no data, implementations or parameters", None),
+    rule_def(
+        "fixture-multiple-sources",
+        Error,
+        "A fixture defines more than one data source",
+        None,
+    ),
+    rule_def(
+        "fixture-encoding-without-data",
+        Error,
+        "A fixture sets `encoding` without `data`",
+        Some("Add `data: ...` or remove `encoding`"),
+    ),
+    rule_def(
+        "fixture-selector-without-format",
+        Error,
+        "A fixture sets `selector` without `format`",
+        Some("Add `format: json|csv`"),
+    ),
+    rule_def("unused-fixture", Hint, "No benchmark references the fixture", None),
+    rule_def(
+        "same-dataset-inconsistent-fixtures",
+        Warn,
+        "Benchmarks of a `sameDataset: true` suite reference different fixtures",
+        None,
+    ),
+    // Benchmarks
+    rule_def("empty-benchmark", Error, "A benchmark has no language implementations", None),
+    rule_def(
+        "benchmark-iterations-invalid",
+        Error,
+        "`iterations` is set on a benchmark of a timeBased suite",
+        Some("Remove `iterations` or use `targetTime`"),
+    ),
+    rule_def(
+        "benchmark-target-time-invalid",
+        Error,
+        "`targetTime` is set on a benchmark of an iterationBased suite",
+        Some("Remove `targetTime` or use `iterations`"),
+    ),
+    rule_def(
+        "hook-without-impl",
+        Warn,
+        "A hook, skip or validate block targets a language with no implementation",
+        None,
+    ),
+    rule_def(
+        "param-shadows-fixture",
+        Error,
+        "A benchmark parameter has the same name as a fixture",
+        Some("Rename the parameter"),
+    ),
+    rule_def("unused-param", Warn, "No implementation references a benchmark parameter", None),
+    rule_def(
+        "equivalent-without-sink",
+        Warn,
+        "`equivalent: true` has no effect for Go, TypeScript, Python or C# without `sink: true`",
+        Some("Set `sink: true`"),
+    ),
+    rule_def(
+        "equivalent-unsupported-lang",
+        Error,
+        "`equivalent: true` on a benchmark with C or Zig implementations, whose outputs are not reported",
+        Some("Remove `equivalent: true` or the C/Zig implementation"),
+    ),
+    rule_def(
+        "adaptive-settings-invalid",
+        Error,
+        "`ciTarget` is not positive, or `maxRuns` is lower than `count`",
+        None,
+    ),
+    rule_def(
+        "adaptive-without-effect",
+        Warn,
+        "`ciTarget`, `maxRuns` or `maxTime` is set where adaptive runs do not apply",
+        None,
+    ),
+];
+
+/// Look up a rule by ID
+pub fn rule(id: &str) -> Option<&'static LintRule> {
+    RULES.iter().find(|r| r.id == id)
+}
+
+/// Project-level severity overrides from the `[lint]` table of `polybench.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    overrides: BTreeMap<String, LintLevel>,
+}
+
+impl LintConfig {
+    pub fn new(overrides: BTreeMap<String, LintLevel>) -> Self {
+        Self { overrides }
+    }
+
+    /// Effective level of a rule; unknown IDs default to `warn`
+    pub fn level(&self, id: &str) -> LintLevel {
+        self.overrides
+            .get(id)
+            .copied()
+            .or_else(|| rule(id).map(|r| r.default_level))
+            .unwrap_or(LintLevel::Warn)
+    }
+
+    /// Override keys that do not name a registered rule
+    pub fn unknown_rules(&self) -> Vec<&str> {
+        self.overrides.keys().map(String::as_str).filter(|id| rule(id).is_none()).collect()
+    }
+}
+
+/// Inline `# polybench-ignore:` suppressions of a source file
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    /// Rules ignored per 1-indexed line
+    lines: HashMap<usize, HashSet<String>>,
+    file: HashSet<String>,
+}
+
+impl Suppressions {
+    /// Collect suppression comments from source text
+    pub fn parse(source: &str) -> Self {
+        let mut suppressions = Self::default();
+        for (idx, line) in source.lines().enumerate() {
+            if let Some(rules) = comment_directive(line, IGNORE_FILE_DIRECTIVE) {
+                suppressions.file.extend(split_rules(rules));
+            } else if let Some(rules) = comment_directive(line, IGNORE_DIRECTIVE) {
+                suppressions.lines.entry(idx + 1).or_default().extend(split_rules(rules));
+            }
+        }
+        suppressions
+    }
+
+    /// Whether a finding of `id` reported on 1-indexed `line` is suppressed by a comment on that
+    /// line, the line above, or a file-level directive
+    pub fn is_suppressed(&self, id: &str, line: Option<usize>) -> bool {
+        if self.file.contains(id) {
+            return true;
+        }
+        let Some(line) = line else { return false };
+        [line, line.saturating_sub(1)]
+            .iter()
+            .any(|l| self.lines.get(l).is_some_and(|rules| rules.contains(id)))
+    }
+}
+
+/// Text after `directive` when it opens a `#` comment on `line`. Looks for the directive itself
+/// rather than the first `#`, which may belong to inline code.
+fn comment_directive<'a>(line: &'a str, directive: &str) -> Option<&'a str> {
+    let pos = line.find(directive)?;
+    line[..pos].trim_end().ends_with('#').then(|| &line[pos + directive.len()..])
+}
+
+fn split_rules(list: &str) -> impl Iterator<Item = String> + '_ {
+    list.split(',').map(str::trim).filter(|r| !r.is_empty()).map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_ids_are_unique_and_linkable() {
+        let mut seen = HashSet::new();
+        for r in RULES {
+            assert!(seen.insert(r.id), "duplicate rule {}", r.id);
+            assert!(r.id.chars().all(|c| c.is_ascii_lowercase() || c == '-'), "{}", r.id);
+        }
+        assert_eq!(
+            rule("unused-fixture").unwrap().docs_url(),
+            format!("{}#unused-fixture", DOCS_URL)
+        );
+    }
+
+    #[test]
+    fn test_every_rule_has_a_docs_anchor() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../docs/src/content/docs/core/dsl-reference.mdx");
+        let docs = std::fs::read_to_string(&path).unwrap();
+        for r in RULES {
+            assert!(docs.contains(&format!("<a id=\"{}\">", r.id)), "{} has no section", r.id);
+        }
+    }
+
+    #[test]
+    fn test_config_overrides_and_unknown_rules() {
+        let config = LintConfig::new(BTreeMap::from([
+            ("unused-fixture".to_string(), LintLevel::Error),
+            ("no-such-rule".to_string(), LintLevel::Allow),
+        ]));
+        assert_eq!(config.level("unused-fixture"), LintLevel::Error);
+        assert_eq!(config.level("empty-suite"), LintLevel::Warn);
+        assert_eq!(config.level("empty-benchmark"), LintLevel::Error);
+        assert_eq!(config.unknown_rules(), vec!["no-such-rule"]);
+    }
+
+    #[test]
+    fn test_suppressions_cover_same_and_next_line() {
+        let source = "# polybench-ignore-file: empty-suite\n\
+                      # polybench-ignore: unused-param, hook-without-impl\n\
+                      bench foo(n in [1]) {\n\
+                      }\n\
+                      fixture x { hex: \"00\" } # polybench-ignore: unused-fixture\n";
+        let s = Suppressions::parse(source);
+        assert!(s.is_suppressed("empty-suite", None));
+        assert!(s.is_suppressed("unused-param", Some(3)));
+        assert!(s.is_suppressed("hook-without-impl", Some(2)));
+        assert!(!s.is_suppressed("unused-param", Some(4)));
+        assert!(s.is_suppressed("unused-fixture", Some(5)));
+        assert!(!s.is_suppressed("empty-benchmark", Some(3)));
+    }
+
+    #[test]
+    fn test_suppression_after_code_containing_hash() {
+        let source = "bench fmt {\n\
+                      go: fmt.Sprintf(\"#%d\", n) # polybench-ignore: unused-param\n\
+                      ts: `#${n}` # not a directive: polybench-ignore: hook-without-impl\n\
+                      }\n";
+        let s = Suppressions::parse(source);
+        assert!(s.is_suppressed("unused-param", Some(2)));
+        assert!(!s.is_suppressed("hook-without-impl", Some(3)));
+    }
+}
@@ -5,7 +5,7 @@
 use crate::{
     ast::{HookStyle, *},
     chart_params::validate_param,
-    error::{NamedSource, ParseError},
+    error::{NamedSource, ParseError, RuleViolation},
     lexer::Lexer,
     tokens::{Token, TokenKind},
 };
@@ -345,26 +345,26 @@ impl Parser {
             TokenKind::Baseline => {
                 self.advance();
                 self.expect(TokenKind::Colon)?;
-                let lang = self.expect_lang_from_string()?;
+                let name = self.expect_string()?;
+                // Runtime variants are accepted as baselines
+                let lang = Lang::from_str_with_variants(&name).ok_or_else(|| {
+                    self.rule_error(
+                        "invalid-baseline",
+                        format!("Unknown baseline language: '{}'", name),
+                        self.previous().span.clone(),
+                    )
+                })?;
                 suite.baseline = Some(lang);
             }
-            TokenKind::SuiteType => {
-                return Err(self.make_error(ParseError::InvalidProperty {
-                    name: "suiteType must be declared in the suite header".to_string(),
-                    span: token.span.clone(),
-                }));
-            }
-            TokenKind::RunMode => {
-                return Err(self.make_error(ParseError::InvalidProperty {
-                    name: "runMode must be declared in the suite header".to_string(),
-                    span: token.span.clone(),
-                }));
-            }
-            TokenKind::SameDataset => {
-                return Err(self.make_error(ParseError::InvalidProperty {
-                    name: "sameDataset must be declared in the suite header".to_string(),
-                    span: token.span.clone(),
-                }));
+            TokenKind::SuiteType | TokenKind::RunMode | TokenKind::SameDataset => {
+                return Err(self.rule_error(
+                    "suite-semantics-in-header",
+                    format!(
+                        "{} must be declared in the suite header: declare suite <name> <performance|memory> <timeBased|iterationBased> sameDataset: <true|false>",
+                        token.lexeme
+                    ),
+                    token.span.clone(),
+                ));
             }
             // Benchmark accuracy settings
             TokenKind::Mode => {
@@ -382,7 +382,7 @@ impl Parser {
             TokenKind::FairnessSeed => {
                 self.advance();
                 self.expect(TokenKind::Colon)?;
-                let seed = self.expect_number()?;
+                let seed = self.expect_count(&token.lexeme)?;
                 suite.fairness_seed = Some(seed);
             }
             TokenKind::Sink => {
@@ -415,6 +415,24 @@ impl Parser {
                 let value = self.expect_number()?;
                 suite.count = Some(value);
             }
+            TokenKind::CiTarget => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_float()?;
+                suite.ci_target = Some(value);
+            }
+            TokenKind::MaxRuns => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_number()?;
+                suite.max_runs = Some(value);
+            }
+            TokenKind::MaxTime => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_duration()?;
+                suite.max_time_ms = Some(value);
+            }
             TokenKind::Memory => {
                 return Err(self.make_error(ParseError::InvalidProperty {
                     name: "memory: use suiteType: memory in the suite declaration instead"
@@ -422,6 +440,18 @@ impl Parser {
                     span: token.span.clone(),
                 }));
             }
+            TokenKind::Counters => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_bool()?;
+                suite.counters = value;
+            }
+            TokenKind::Equivalent => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_bool()?;
+                suite.equivalent = value;
+            }
             TokenKind::AsyncSamplingPolicy => {
                 self.advance();
                 self.expect(TokenKind::Colon)?;
@@ -431,13 +461,13 @@ impl Parser {
             TokenKind::AsyncWarmupCap => {
                 self.advance();
                 self.expect(TokenKind::Colon)?;
-                let value = self.expect_number()?;
+                let value = self.expect_count(&token.lexeme)?;
                 suite.async_warmup_cap = Some(value);
             }
             TokenKind::AsyncSampleCap => {
                 self.advance();
                 self.expect(TokenKind::Colon)?;
-                let value = self.expect_number()?;
+                let value = self.expect_count(&token.lexeme)?;
                 suite.async_sample_cap = Some(value);
             }
             // globalSetup can now be inside suite
@@ -672,6 +702,22 @@ impl Parser {
         }
     }
 
+    /// Expect a non-negative number for `property`; negative literals are a lint finding rather
+    /// than a generic parse error
+    fn expect_count(&mut self, property: &str) -> Result<u64> {
+        let token = self.peek().clone();
+        if let TokenKind::Identifier(s) = &token.kind {
+            if s.strip_prefix('-').is_some_and(|n| n.trim().parse::<u64>().is_ok()) {
+                return Err(self.rule_error(
+                    "negative-suite-property",
+                    format!("Property '{}' must be >= 0", property),
+                    token.span,
+                ));
+            }
+        }
+        self.expect_number()
+    }
+
     /// Expect and parse a float (or integer as float)
     fn expect_float(&mut self) -> Result<f64> {
         let token = self.advance().clone();
@@ -978,6 +1024,8 @@ impl Parser {
                         let path = self.expect_string()?;
                         self.expect(TokenKind::RParen)?;
                         fixture.data_file = Some(path);
+                    } else if self.check(TokenKind::At) {
+                        fixture.generator = Some(self.parse_fixture_generator()?);
                     } else {
                         fixture.data = Some(self.expect_string()?);
                     }
@@ -993,6 +1041,10 @@ impl Parser {
                     self.advance();
                     self.expect(TokenKind::Colon)?;
                     fixture.selector = Some(self.expect_string()?);
+                } else if s == "external" {
+                    self.advance();
+                    self.expect(TokenKind::Colon)?;
+                    fixture.external = Some(self.expect_bool()?);
                 } else {
                     return Err(self.make_error(ParseError::InvalidProperty {
                         name: s.clone(),
@@ -1003,7 +1055,7 @@ impl Parser {
             _ => {
                 return Err(self.make_error(ParseError::ExpectedToken {
                     expected:
-                        "fixture property (hex, data, encoding, format, selector, description, shape) or language"
+                        "fixture property (hex, data, encoding, format, selector, external, description, shape) or language"
                             .to_string(),
                     found: format!("{:?}", token.kind),
                     span: token.span.clone(),
@@ -1031,10 +1083,17 @@ impl Parser {
             _ => unreachable!(),
         };
 
-        self.expect(TokenKind::LBrace)?;
-
         let mut benchmark = Benchmark::new(name, kind, name_token.span.clone());
 
+        // Check for parameterized benchmark: bench name(n in [100, 1000], ...)
+        if self.check(TokenKind::LParen) {
+            self.advance();
+            benchmark.params = self.parse_bench_params()?;
+            self.expect(TokenKind::RParen)?;
+        }
+
+        self.expect(TokenKind::LBrace)?;
+
         while !self.check(TokenKind::RBrace) && !self.is_at_end() {
             self.parse_benchmark_item(&mut benchmark)?;
         }
@@ -1044,6 +1103,88 @@ impl Parser {
         Ok(benchmark)
     }
 
+    /// Parse benchmark parameter axes: (n in [100, 1000], algo in ["a", "b"])
+    fn parse_bench_params(&mut self) -> Result<Vec<BenchParam>> {
+        let mut params: Vec<BenchParam> = Vec::new();
+
+        while !self.check(TokenKind::RParen) && !self.is_at_end() {
+            let name_token = self.expect_identifier()?;
+            let name = match &name_token.kind {
+                TokenKind::Identifier(s) => s.clone(),
+                _ => unreachable!(),
+            };
+
+            if params.iter().any(|p| p.name == name) {
+                return Err(self.make_error(ParseError::Duplicate {
+                    item: "benchmark parameter".to_string(),
+                    name,
+                    span: name_token.span.clone(),
+                }));
+            }
+
+            if !self.check_identifier("in") {
+                let token = self.peek().clone();
+                return Err(self.make_error(ParseError::ExpectedToken {
+                    expected: "'in'".to_string(),
+                    found: format!("{:?}", token.kind),
+                    span: token.span,
+                }));
+            }
+            self.advance();
+
+            let values = self.parse_param_value_array()?;
+            if values.is_empty() {
+                return Err(self.make_error(ParseError::ExpectedToken {
+                    expected: format!("at least one value for parameter '{}'", name),
+                    found: "[]".to_string(),
+                    span: self.previous().span.clone(),
+                }));
+            }
+
+            params.push(BenchParam::new(name, values, name_token.span.clone()));
+
+            // Check for comma
+            if !self.check(TokenKind::RParen) {
+                self.expect(TokenKind::Comma)?;
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// Parse a parameter value array: [100, 2.5, "label", true]
+    fn parse_param_value_array(&mut self) -> Result<Vec<Value>> {
+        self.expect(TokenKind::LBracket)?;
+        let mut items = Vec::new();
+
+        while !self.check(TokenKind::RBracket) && !self.is_at_end() {
+            let token = self.advance().clone();
+            let value = match token.kind {
+                TokenKind::Number(n) => Value::Number(n),
+                TokenKind::Float(f) => Value::Float(f),
+                TokenKind::String(s) => Value::String(s),
+                TokenKind::True => Value::Bool(true),
+                TokenKind::False => Value::Bool(false),
+                _ => {
+                    return Err(self.make_error(ParseError::ExpectedToken {
+                        expected: "number, float, string or boolean".to_string(),
+                        found: format!("{:?}", token.kind),
+                        span: token.span,
+                    }));
+                }
+            };
+            items.push(value);
+
+            // Optional comma
+            if self.check(TokenKind::Comma) {
+                self.advance();
+            }
+        }
+
+        self.expect(TokenKind::RBracket)?;
+        Ok(items)
+    }
+
     /// Parse a single item within a benchmark
     fn parse_benchmark_item(&mut self, benchmark: &mut Benchmark) -> Result<()> {
         let token = self.peek().clone();
@@ -1135,6 +1276,24 @@ impl Parser {
                 let value = self.expect_number()?;
                 benchmark.count = Some(value);
             }
+            TokenKind::CiTarget => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_float()?;
+                benchmark.ci_target = Some(value);
+            }
+            TokenKind::MaxRuns => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_number()?;
+                benchmark.max_runs = Some(value);
+            }
+            TokenKind::MaxTime => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_duration()?;
+                benchmark.max_time_ms = Some(value);
+            }
             TokenKind::Memory => {
                 return Err(self.make_error(ParseError::InvalidProperty {
                     name: "memory: use suiteType: memory in the suite declaration instead"
@@ -1142,6 +1301,18 @@ impl Parser {
                     span: token.span.clone(),
                 }));
             }
+            TokenKind::Counters => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_bool()?;
+                benchmark.counters = Some(value);
+            }
+            TokenKind::Equivalent => {
+                self.advance();
+                self.expect(TokenKind::Colon)?;
+                let value = self.expect_bool()?;
+                benchmark.equivalent = Some(value);
+            }
             // Phase 3: Lifecycle hooks - support both grouped and flat syntax
             TokenKind::Before => {
                 self.advance();
@@ -1226,7 +1397,7 @@ impl Parser {
             }
             _ => {
                 return Err(self.make_error(ParseError::ExpectedToken {
-                    expected: "benchmark property (iterations, warmup, timeout, tags, skip, validate, mode, sink, targetTime, before, after, each) or language implementation".to_string(),
+                    expected: "benchmark property (iterations, warmup, timeout, tags, skip, validate, mode, sink, targetTime, count, ciTarget, maxRuns, maxTime, counters, equivalent, before, after, each) or language implementation".to_string(),
                     found: format!("{:?}", token.kind),
                     span: token.span.clone(),
                 }));
@@ -1381,7 +1552,12 @@ impl Parser {
                         TokenKind::OutlierDetection |
                         TokenKind::CvThreshold |
                         TokenKind::Count |
+                        TokenKind::CiTarget |
+                        TokenKind::MaxRuns |
+                        TokenKind::MaxTime |
                         TokenKind::Memory |
+                        TokenKind::Counters |
+                        TokenKind::Equivalent |
                         TokenKind::AsyncSamplingPolicy |
                         TokenKind::AsyncWarmupCap |
                         TokenKind::AsyncSampleCap |
@@ -1519,6 +1695,45 @@ impl Parser {
         self.expect(expected)
     }
 
+    /// Parse `@name(key: value, ...)`; argument values are numbers or strings
+    fn parse_fixture_generator(&mut self) -> Result<FixtureGenerator> {
+        let start = self.advance().span.clone();
+        let name = self.expect_identifier()?.lexeme;
+        self.expect(TokenKind::LParen)?;
+        let mut args = Vec::new();
+        while !self.check(TokenKind::RParen) {
+            // Keys are words, some of which (`hex`, `count`) are keywords elsewhere
+            let key = self.advance().clone();
+            if !key.lexeme.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(self.make_error(ParseError::ExpectedIdentifier { span: key.span }));
+            }
+            self.expect(TokenKind::Colon)?;
+            let token = self.advance().clone();
+            let value = match token.kind {
+                TokenKind::Number(n) => Value::Number(n),
+                TokenKind::String(s) => Value::String(s),
+                _ => {
+                    return Err(self.make_error(ParseError::ExpectedToken {
+                        expected: "number or string".to_string(),
+                        found: format!("{:?}", token.kind),
+                        span: token.span,
+                    }))
+                }
+            };
+            args.push((key.lexeme, value));
+            if !self.check(TokenKind::Comma) {
+                break;
+            }
+            self.advance();
+        }
+        let end = self.expect(TokenKind::RParen)?.span.clone();
+        Ok(FixtureGenerator {
+            name,
+            args,
+            span: Span::new(start.start, end.end, start.line, start.col),
+        })
+    }
+
     fn expect_identifier(&mut self) -> Result<Token> {
         let token = self.peek().clone();
         if matches!(token.kind, TokenKind::Identifier(_)) {
@@ -1581,17 +1796,6 @@ impl Parser {
         }
     }
 
-    /// Parse a lang from a string literal (for baseline: "go")
-    fn expect_lang_from_string(&mut self) -> Result<Lang> {
-        let s = self.expect_string()?;
-        Lang::from_str(&s).ok_or_else(|| {
-            self.make_error(ParseError::UnknownLang {
-                lang: s.clone(),
-                span: self.previous().span.clone(),
-            })
-        })
-    }
-
     /// Expect a duration token and return milliseconds
     fn expect_duration(&mut self) -> Result<u64> {
         let token = self.peek().clone();
@@ -1758,25 +1962,13 @@ impl Parser {
                     self.advance();
                     mode
                 })
-                .ok_or_else(|| {
-                    self.make_error(ParseError::ExpectedToken {
-                        expected: "fairness mode (\"legacy\" or \"strict\")".to_string(),
-                        found: s.clone(),
-                        span: token.span.clone(),
-                    })
-                }),
+                .ok_or_else(|| self.invalid_fairness_mode(s, &token.span)),
             TokenKind::Identifier(s) => FairnessMode::from_str(s)
                 .map(|mode| {
                     self.advance();
                     mode
                 })
-                .ok_or_else(|| {
-                    self.make_error(ParseError::ExpectedToken {
-                        expected: "fairness mode (legacy or strict)".to_string(),
-                        found: s.clone(),
-                        span: token.span.clone(),
-                    })
-                }),
+                .ok_or_else(|| self.invalid_fairness_mode(s, &token.span)),
             _ => Err(self.make_error(ParseError::ExpectedToken {
                 expected: "fairness mode (legacy or strict)".to_string(),
                 found: format!("{:?}", token.kind),
@@ -1794,26 +1986,13 @@ impl Parser {
                     self.advance();
                     policy
                 })
-                .ok_or_else(|| {
-                    self.make_error(ParseError::ExpectedToken {
-                        expected: "async sampling policy (\"fixedCap\" or \"timeBudgeted\")"
-                            .to_string(),
-                        found: s.clone(),
-                        span: token.span.clone(),
-                    })
-                }),
+                .ok_or_else(|| self.invalid_async_sampling_policy(s, &token.span)),
             TokenKind::Identifier(s) => AsyncSamplingPolicy::from_str(s)
                 .map(|policy| {
                     self.advance();
                     policy
                 })
-                .ok_or_else(|| {
-                    self.make_error(ParseError::ExpectedToken {
-                        expected: "async sampling policy (fixedCap or timeBudgeted)".to_string(),
-                        found: s.clone(),
-                        span: token.span.clone(),
-                    })
-                }),
+                .ok_or_else(|| self.invalid_async_sampling_policy(s, &token.span)),
             _ => Err(self.make_error(ParseError::ExpectedToken {
                 expected: "async sampling policy (fixedCap or timeBudgeted)".to_string(),
                 found: format!("{:?}", token.kind),
@@ -1873,6 +2052,35 @@ impl Parser {
         Report::new(error)
             .with_source_code(NamedSource::new(self.filename.clone(), self.source.clone()))
     }
+
+    /// A parse failure reported under lint rule `rule`
+    fn rule_error(&self, rule: &'static str, message: String, span: Span) -> Report {
+        Report::new(RuleViolation {
+            rule,
+            message,
+            span,
+            source_code: NamedSource::new(self.filename.clone(), self.source.clone()),
+        })
+    }
+
+    fn invalid_fairness_mode(&self, mode: &str, span: &Span) -> Report {
+        self.rule_error(
+            "invalid-fairness-mode",
+            format!("Invalid fairness mode '{}'. Expected \"legacy\" or \"strict\"", mode),
+            span.clone(),
+        )
+    }
+
+    fn invalid_async_sampling_policy(&self, policy: &str, span: &Span) -> Report {
+        self.rule_error(
+            "invalid-async-sampling-policy",
+            format!(
+                "Invalid asyncSamplingPolicy '{}'. Expected \"timeBudgeted\" or \"fixedCap\"",
+                policy
+            ),
+            span.clone(),
+        )
+    }
 }
 
 /// Parse source code into an AST
@@ -1941,6 +2149,47 @@ suite asyncSuite {
         assert_eq!(bench.kind, BenchmarkKind::Async);
     }
 
+    #[test]
+    fn test_parse_bench_params() {
+        let source = r#"
+suite sorting {
+    bench sort(n in [100, 1000, 10000], dir in ["asc", "desc"]) {
+        go: sortInts(n)
+    }
+}
+"#;
+        let result = parse(source, "test.bench");
+        assert!(result.is_ok(), "Parse failed: {:?}", result.err());
+
+        let file = result.unwrap();
+        let bench = &file.suites[0].benchmarks[0];
+        assert_eq!(bench.name, "sort");
+        assert!(bench.is_parameterized());
+        assert_eq!(bench.params.len(), 2);
+        assert_eq!(bench.params[0].name, "n");
+        assert_eq!(
+            bench.params[0].values,
+            vec![Value::Number(100), Value::Number(1000), Value::Number(10000)]
+        );
+        assert_eq!(bench.params[1].name, "dir");
+        assert_eq!(
+            bench.params[1].values,
+            vec![Value::String("asc".to_string()), Value::String("desc".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_params_rejects_duplicates() {
+        let source = r#"
+suite sorting {
+    bench sort(n in [1], n in [2]) {
+        go: sortInts(n)
+    }
+}
+"#;
+        assert!(parse(source, "test.bench").is_err());
+    }
+
     #[test]
     fn test_parse_fixture() {
         let source = r#"
@@ -2009,6 +2258,52 @@ suite test {
         let fixture = &file.suites[0].fixtures[0];
         assert_eq!(fixture.format, Some("json".to_string()));
         assert_eq!(fixture.selector, Some("$.items[0].id".to_string()));
+        assert_eq!(fixture.external, None);
+    }
+
+    #[test]
+    fn test_parse_fixture_external() {
+        let source = r#"
+suite test {
+    fixture blob {
+        data: @file("fixtures/blob.bin")
+        encoding: raw
+        external: true
+    }
+
+    bench foo {
+        go: process(blob)
+    }
+}
+"#;
+        let file = parse(source, "test.bench").unwrap();
+        assert_eq!(file.suites[0].fixtures[0].external, Some(true));
+    }
+
+    #[test]
+    fn test_parse_fixture_generator() {
+        let source = r#"
+suite test {
+    fixture noise {
+        data: @random(bytes: 1048576, seed: 42)
+    }
+
+    fixture pattern {
+        data: @repeat(hex: "00ff", times: 8)
+    }
+
+    bench foo {
+        go: process(noise, pattern)
+    }
+}
+"#;
+        let file = parse(source, "test.bench").unwrap();
+        let generator = file.suites[0].fixtures[0].generator.as_ref().unwrap();
+        assert_eq!(generator.name, "random");
+        assert_eq!(generator.arg("bytes"), Some(&Value::Number(1048576)));
+        assert_eq!(generator.arg("seed"), Some(&Value::Number(42)));
+        let pattern = file.suites[0].fixtures[1].generator.as_ref().unwrap();
+        assert_eq!(pattern.to_string(), "@repeat(hex: \"00ff\", times: 8)");
     }
 
     #[test]
@@ -2558,4 +2853,46 @@ suite fairnessTest {
         assert_eq!(suite.async_warmup_cap, Some(9));
         assert_eq!(suite.async_sample_cap, Some(77));
     }
+
+    #[test]
+    fn test_parse_rule_violations_carry_rule_ids() {
+        let cases = [
+            ("baseline: \"cobol\"", "invalid-baseline"),
+            ("suiteType: memory", "suite-semantics-in-header"),
+            ("fairness: \"loose\"", "invalid-fairness-mode"),
+            ("asyncSamplingPolicy: \"sometimes\"", "invalid-async-sampling-policy"),
+            ("asyncSampleCap: -3", "negative-suite-property"),
+        ];
+        for (property, rule) in cases {
+            let source = format!(
+                "declare suite s performance timeBased sameDataset: false {{\n    {}\n    bench a {{\n        go: work()\n    }}\n}}\n",
+                property
+            );
+            let report = parse(&source, "test.bench").unwrap_err();
+            let violation = report.downcast_ref::<RuleViolation>().unwrap();
+            assert_eq!(violation.rule, rule, "{}", property);
+            assert_eq!(violation.span.line, 2, "{}", property);
+        }
+    }
+
+    #[test]
+    fn test_parse_registered_external_lang() {
+        let source = r#"
+suite ext {
+    bench work {
+        go: doWork()
+        jrubytest: do_work
+    }
+}
+"#;
+        assert_eq!(Lang::from_str("jrubytest"), None);
+        let jruby = ExternalLang::register("JRubyTest");
+        assert_eq!(Lang::from_str("jrubytest"), Some(jruby));
+        assert_eq!(jruby.as_str(), "jrubytest");
+
+        let ast = parse(source, "test.bench").unwrap();
+        let bench = &ast.suites[0].benchmarks[0];
+        assert!(bench.implementations.contains_key(&Lang::Go));
+        assert!(bench.implementations.contains_key(&jruby));
+    }
 }
@@ -54,9 +54,14 @@ pub enum TokenKind {
     OutlierDetection, // outlierDetection (true/false)
     CvThreshold,      // cvThreshold (percentage)
     Count,            // count (number) - run benchmark N times for statistical consistency
+    CiTarget,         // ciTarget (percentage) - adaptive runs until the 95% CI is this tight
+    MaxRuns,          // maxRuns (number) - run budget for adaptive runs
+    MaxTime,          // maxTime (duration) - time budget for adaptive runs
 
     // Observability keywords (Phase 2B)
     Memory,              // memory (true/false) - enable memory profiling
+    Counters,            // counters (true/false) - hardware performance counters
+    Equivalent,          // equivalent (true/false) - cross-language output equivalence
     AsyncSamplingPolicy, // asyncSamplingPolicy (fixedCap/timeBudgeted)
     AsyncWarmupCap,      // asyncWarmupCap
     AsyncSampleCap,      // asyncSampleCap
@@ -155,7 +160,12 @@ impl TokenKind {
                 TokenKind::OutlierDetection |
                 TokenKind::CvThreshold |
                 TokenKind::Count |
+                TokenKind::CiTarget |
+                TokenKind::MaxRuns |
+                TokenKind::MaxTime |
                 TokenKind::Memory |
+                TokenKind::Counters |
+                TokenKind::Equivalent |
                 TokenKind::AsyncSamplingPolicy |
                 TokenKind::AsyncWarmupCap |
                 TokenKind::AsyncSampleCap |
@@ -263,9 +273,14 @@ pub fn keyword_from_str(s: &str) -> Option<TokenKind> {
         "outlierDetection" => Some(TokenKind::OutlierDetection),
         "cvThreshold" => Some(TokenKind::CvThreshold),
         "count" => Some(TokenKind::Count),
+        "ciTarget" => Some(TokenKind::CiTarget),
+        "maxRuns" => Some(TokenKind::MaxRuns),
+        "maxTime" => Some(TokenKind::MaxTime),
 
         // Observability keywords (Phase 2B)
         "memory" => Some(TokenKind::Memory),
+        "counters" => Some(TokenKind::Counters),
+        "equivalent" => Some(TokenKind::Equivalent),
         "asyncSamplingPolicy" => Some(TokenKind::AsyncSamplingPolicy),
         "asyncWarmupCap" => Some(TokenKind::AsyncWarmupCap),
         "asyncSampleCap" => Some(TokenKind::AsyncSampleCap),
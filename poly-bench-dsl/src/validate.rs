@@ -6,7 +6,12 @@
 //! Note: Stdlib-specific validation (e.g., validating use std::module names)
 //! is handled by higher-level crates that depend on both dsl and stdlib.
 
-use crate::{Benchmark, ChartType, CodeBlock, File, Lang, RunMode, StructuredSetup, Suite, UseStd};
+use crate::{
+    helper_extractor::{extract_function_calls, helper_function_extractor},
+    lint::{LintConfig, LintLevel, Suppressions},
+    Benchmark, ChartType, CodeBlock, ExecutionOrder, File, Lang, RunMode, Span, StructuredSetup,
+    Suite, SuiteType, UseStd,
+};
 use std::collections::HashSet;
 
 /// A validation warning (non-fatal issue)
@@ -14,17 +19,46 @@ use std::collections::HashSet;
 pub struct ValidationWarning {
     pub message: String,
     pub location: Option<String>,
+    /// ID of the lint rule that produced the warning (see [`crate::lint::RULES`])
+    pub rule: Option<&'static str>,
+    /// 1-indexed source line the warning is reported on
+    pub line: Option<usize>,
+    /// Source range the warning is reported on
+    pub span: Option<Span>,
 }
 
 impl ValidationWarning {
     pub fn new(message: impl Into<String>) -> Self {
-        Self { message: message.into(), location: None }
+        Self { message: message.into(), location: None, rule: None, line: None, span: None }
     }
 
     pub fn with_location(mut self, location: impl Into<String>) -> Self {
         self.location = Some(location.into());
         self
     }
+
+    pub fn with_rule(mut self, rule: &'static str) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Report on `span`, which also sets the line
+    pub fn with_span(mut self, span: &Span) -> Self {
+        self.line = Some(span.line);
+        self.span = Some(span.clone());
+        self
+    }
+}
+
+impl From<ValidationError> for ValidationWarning {
+    fn from(e: ValidationError) -> Self {
+        Self { message: e.message, location: e.location, rule: e.rule, line: e.line, span: e.span }
+    }
 }
 
 /// A validation error (fatal issue)
@@ -32,17 +66,46 @@ impl ValidationWarning {
 pub struct ValidationError {
     pub message: String,
     pub location: Option<String>,
+    /// ID of the lint rule that produced the error (see [`crate::lint::RULES`])
+    pub rule: Option<&'static str>,
+    /// 1-indexed source line the error is reported on
+    pub line: Option<usize>,
+    /// Source range the error is reported on
+    pub span: Option<Span>,
 }
 
 impl ValidationError {
     pub fn new(message: impl Into<String>) -> Self {
-        Self { message: message.into(), location: None }
+        Self { message: message.into(), location: None, rule: None, line: None, span: None }
     }
 
     pub fn with_location(mut self, location: impl Into<String>) -> Self {
         self.location = Some(location.into());
         self
     }
+
+    pub fn with_rule(mut self, rule: &'static str) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Report on `span`, which also sets the line
+    pub fn with_span(mut self, span: &Span) -> Self {
+        self.line = Some(span.line);
+        self.span = Some(span.clone());
+        self
+    }
+}
+
+impl From<ValidationWarning> for ValidationError {
+    fn from(w: ValidationWarning) -> Self {
+        Self { message: w.message, location: w.location, rule: w.rule, line: w.line, span: w.span }
+    }
 }
 
 /// Result of validating a suite
@@ -78,6 +141,30 @@ impl ValidationResult {
         self.errors.extend(other.errors);
         self.warnings.extend(other.warnings);
     }
+    /// Re-level findings according to project lint overrides and drop allowed or suppressed ones.
+    ///
+    /// Findings without a rule ID are kept as reported.
+    pub fn apply_lints(self, config: &LintConfig, suppressions: &Suppressions) -> ValidationResult {
+        let mut result = ValidationResult::new();
+        let findings = self
+            .errors
+            .into_iter()
+            .map(|e| (e.rule, e.line, LintLevel::Error, ValidationWarning::from(e)))
+            .chain(self.warnings.into_iter().map(|w| (w.rule, w.line, LintLevel::Warn, w)));
+        for (rule, line, reported, finding) in findings {
+            let level = match rule {
+                Some(id) if suppressions.is_suppressed(id, line) => LintLevel::Allow,
+                Some(id) => config.level(id),
+                None => reported,
+            };
+            match level {
+                LintLevel::Allow => {}
+                LintLevel::Error => result.add_error(finding.into()),
+                LintLevel::Hint | LintLevel::Warn => result.add_warning(finding),
+            }
+        }
+        result
+    }
 }
 
 /// Validate a complete file and return any errors or warnings.
@@ -103,6 +190,20 @@ pub fn validate_file(file: &File) -> ValidationResult {
     result
 }
 
+/// Validate a file that belongs to a project: [`validate_file`], plus a finding for every
+/// language the project has not configured (`is_configured` is typically
+/// `Manifest::has_runtime`)
+pub fn validate_project_file(
+    file: &File,
+    is_configured: impl Fn(Lang) -> bool,
+) -> ValidationResult {
+    let mut result = validate_file(file);
+    for suite in &file.suites {
+        validate_runtime_configuration(suite, &is_configured, &mut result);
+    }
+    result
+}
+
 /// Check for duplicate use statements (core validation, no stdlib check)
 fn validate_use_stds_duplicates(use_stds: &[UseStd], result: &mut ValidationResult) {
     let mut seen = HashSet::new();
@@ -110,6 +211,8 @@ fn validate_use_stds_duplicates(use_stds: &[UseStd], result: &mut ValidationResu
         if !seen.insert(&use_std.module) {
             result.add_warning(
                 ValidationWarning::new(format!("Duplicate import of std::{}", use_std.module))
+                    .with_rule("duplicate-import")
+                    .with_span(&use_std.span)
                     .with_location(format!("line {}", use_std.span.line)),
             );
         }
@@ -120,6 +223,15 @@ fn validate_use_stds_duplicates(use_stds: &[UseStd], result: &mut ValidationResu
 pub fn validate_suite(suite: &Suite) -> ValidationResult {
     let mut result = ValidationResult::new();
 
+    if suite.benchmarks.is_empty() && suite.fixtures.is_empty() {
+        result.add_warning(
+            ValidationWarning::new(format!("Suite '{}' has no benchmarks or fixtures", suite.name))
+                .with_rule("empty-suite")
+                .with_span(&suite.span)
+                .with_location(format!("suite.{}", suite.name)),
+        );
+    }
+
     // Validate suite-level semantic contract
     validate_suite_semantics(suite, &mut result);
     validate_chart_dataset_constraints(suite, &mut result);
@@ -162,6 +274,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
                 "Suite '{}' is missing required property 'suiteType' (expected \"memory\" or \"performance\")",
                 suite.name
             ))
+            .with_rule("missing-suite-type")
+            .with_span(&suite.span)
             .with_location(suite_location.clone()),
         );
     }
@@ -172,6 +286,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
                 "Suite '{}' is missing required run mode in suite declaration (expected \"timeBased\" or \"iterationBased\")",
                 suite.name
             ))
+            .with_rule("missing-run-mode")
+            .with_span(&suite.span)
             .with_location(suite_location.clone()),
         );
     }
@@ -182,6 +298,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
                 "Suite '{}' is missing required property 'sameDataset' (expected true or false)",
                 suite.name
             ))
+            .with_rule("missing-same-dataset")
+            .with_span(&suite.span)
             .with_location(suite_location.clone()),
         );
     }
@@ -191,6 +309,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
             ValidationError::new(
                 "Property 'mode' is no longer supported. Use suite declaration run mode: timeBased | iterationBased",
             )
+            .with_rule("legacy-mode-removed")
+            .with_span(&suite.span)
             .with_location(suite_location.clone()),
         );
     }
@@ -203,6 +323,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
                         ValidationError::new(
                             "Property 'iterations' is invalid when run mode is timeBased. Use 'targetTime' instead",
                         )
+                        .with_rule("suite-iterations-invalid")
+                        .with_span(&suite.span)
                         .with_location(suite_location.clone()),
                     );
                 }
@@ -213,6 +335,8 @@ fn validate_suite_semantics(suite: &Suite, result: &mut ValidationResult) {
                         ValidationError::new(
                             "Property 'targetTime' is invalid when run mode is iterationBased. Use 'iterations' instead",
                         )
+                        .with_rule("suite-target-time-invalid")
+                        .with_span(&suite.span)
                         .with_location(suite_location.clone()),
                     );
                 }
@@ -233,19 +357,24 @@ fn validate_chart_dataset_constraints(suite: &Suite, result: &mut ValidationResu
                     directive.chart_type.as_str(),
                     suite.name
                 ))
+                .with_rule("chart-requires-same-dataset")
+                .with_span(&directive.span)
                 .with_location(format!("suite.{}", suite.name)),
             );
         }
+        let expanded_benchmarks: usize = suite.benchmarks.iter().map(|b| b.expansion_count()).sum();
         if matches!(directive.chart_type, ChartType::LineChart | ChartType::BarChart) &&
-            suite.benchmarks.len() < 2
+            expanded_benchmarks < 2
         {
             result.add_error(
                 ValidationError::new(format!(
                     "Chart '{}' requires at least 2 benchmarks for meaningful comparison; suite '{}' has {}",
                     directive.chart_type.as_str(),
                     suite.name,
-                    suite.benchmarks.len()
+                    expanded_benchmarks
                 ))
+                .with_rule("chart-requires-multiple-benchmarks")
+                .with_span(&directive.span)
                 .with_location(format!("suite.{}", suite.name)),
             );
         }
@@ -257,6 +386,8 @@ fn validate_chart_dataset_constraints(suite: &Suite, result: &mut ValidationResu
                     "Chart '{}' requires a 'description' parameter",
                     directive.chart_type.as_str()
                 ))
+                .with_rule("chart-requires-description")
+                .with_span(&directive.span)
                 .with_location(format!("suite.{}", suite.name)),
             );
         }
@@ -270,6 +401,8 @@ fn validate_chart_dataset_constraints(suite: &Suite, result: &mut ValidationResu
                         directive.chart_type.as_str(),
                         directive.y_scale
                     ))
+                    .with_rule("invalid-chart-y-scale")
+                    .with_span(&directive.span)
                     .with_location(format!("suite.{}", suite.name)),
                 );
             }
@@ -286,12 +419,15 @@ pub fn validate_charting_imports(file: &File) -> ValidationResult {
 
     // Check each suite for chart directives
     for suite in &file.suites {
-        if !suite.chart_directives.is_empty() && !has_charting_import {
+        let Some(directive) = suite.chart_directives.first() else { continue };
+        if !has_charting_import {
             result.add_error(
                 ValidationError::new(format!(
                     "Suite '{}' uses charting functions but 'use std::charting' is missing",
                     suite.name
                 ))
+                .with_rule("missing-charting-import")
+                .with_span(&directive.span)
                 .with_location(format!("suite.{}", suite.name)),
             );
         }
@@ -314,6 +450,8 @@ fn validate_requires(suite: &Suite, result: &mut ValidationResult) {
                         "Benchmark '{}' missing required language implementation '{}'",
                         benchmark.name, lang
                     ))
+                    .with_rule("missing-required-language")
+                    .with_span(&benchmark.span)
                     .with_location(format!("suite.{}.bench.{}", suite.name, benchmark.name)),
                 );
             }
@@ -343,6 +481,8 @@ fn validate_structured_setup(
             ValidationWarning::new(
                 "Go setup has no init section - consider adding one for initialization code",
             )
+            .with_rule("setup-without-init")
+            .with_span(&setup.span)
             .with_location(location.clone()),
         );
     }
@@ -353,6 +493,8 @@ fn validate_structured_setup(
             ValidationWarning::new(
                 "Rust setup has no init section - consider adding one for initialization code",
             )
+            .with_rule("setup-without-init")
+            .with_span(&setup.span)
             .with_location(location.clone()),
         );
     }
@@ -361,6 +503,8 @@ fn validate_structured_setup(
     if setup.async_init && setup.init.is_none() {
         result.add_warning(
             ValidationWarning::new("async modifier specified but no init code provided")
+                .with_rule("async-without-init")
+                .with_span(&setup.span)
                 .with_location(location),
         );
     }
@@ -377,6 +521,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                 "Benchmark '{}' has no language implementations",
                 benchmark.name
             ))
+            .with_rule("empty-benchmark")
+            .with_span(&benchmark.span)
             .with_location(location.clone()),
         );
     }
@@ -389,6 +535,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                     "Skip condition for '{}' but no implementation for that language",
                     lang
                 ))
+                .with_rule("hook-without-impl")
+                .with_span(&benchmark.span)
                 .with_location(location.clone()),
             );
         }
@@ -402,6 +550,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                     "Validation for '{}' but no implementation for that language",
                     lang
                 ))
+                .with_rule("hook-without-impl")
+                .with_span(&benchmark.span)
                 .with_location(location.clone()),
             );
         }
@@ -415,6 +565,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                     "Before hook for '{}' but no implementation for that language",
                     lang
                 ))
+                .with_rule("hook-without-impl")
+                .with_span(&benchmark.span)
                 .with_location(location.clone()),
             );
         }
@@ -427,6 +579,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                     "After hook for '{}' but no implementation for that language",
                     lang
                 ))
+                .with_rule("hook-without-impl")
+                .with_span(&benchmark.span)
                 .with_location(location.clone()),
             );
         }
@@ -439,6 +593,38 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                     "Each hook for '{}' but no implementation for that language",
                     lang
                 ))
+                .with_rule("hook-without-impl")
+                .with_span(&benchmark.span)
+                .with_location(location.clone()),
+            );
+        }
+    }
+
+    // Parameter axes must not shadow fixtures and should be used by some implementation
+    for param in &benchmark.params {
+        if suite.fixtures.iter().any(|f| f.name == param.name) {
+            result.add_error(
+                ValidationError::new(format!(
+                    "Benchmark parameter '{}' shadows a fixture with the same name",
+                    param.name
+                ))
+                .with_rule("param-shadows-fixture")
+                .with_span(&param.span)
+                .with_location(location.clone()),
+            );
+        }
+        let used = benchmark
+            .implementations
+            .values()
+            .any(|code| references_ident(&code.code, &param.name));
+        if !used {
+            result.add_warning(
+                ValidationWarning::new(format!(
+                    "Benchmark parameter '{}' is not referenced by any implementation",
+                    param.name
+                ))
+                .with_rule("unused-param")
+                .with_span(&param.span)
                 .with_location(location.clone()),
             );
         }
@@ -449,10 +635,24 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
             ValidationError::new(
                 "Benchmark-level 'mode' is no longer supported. Configure suite-level 'runMode' instead",
             )
+            .with_rule("legacy-mode-removed")
+            .with_span(&benchmark.span)
             .with_location(location.clone()),
         );
     }
 
+    if benchmark.equivalent.unwrap_or(suite.equivalent) {
+        validate_equivalence_langs(
+            benchmark,
+            benchmark.sink.unwrap_or(suite.sink),
+            &location,
+            result,
+        );
+    }
+
+    validate_adaptive_settings(benchmark, suite, &location, result);
+    validate_helper_calls(benchmark, suite, &location, result);
+
     if let Some(run_mode) = suite.run_mode {
         match run_mode {
             RunMode::Time => {
@@ -461,6 +661,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                         ValidationError::new(
                             "Benchmark-level 'iterations' is invalid when suite run mode is timeBased",
                         )
+                        .with_rule("benchmark-iterations-invalid")
+                        .with_span(&benchmark.span)
                         .with_location(location.clone()),
                     );
                 }
@@ -471,6 +673,8 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
                         ValidationError::new(
                             "Benchmark-level 'targetTime' is invalid when suite run mode is iterationBased",
                         )
+                        .with_rule("benchmark-target-time-invalid")
+                        .with_span(&benchmark.span)
                         .with_location(location.clone()),
                     );
                 }
@@ -479,6 +683,190 @@ fn validate_benchmark(benchmark: &Benchmark, suite: &Suite, result: &mut Validat
     }
 }
 
+/// Languages whose outputs `equivalent: true` cannot compare: C and Zig harnesses never report
+/// return values, and Go, TypeScript, Python and C# only capture them through the sink (Rust
+/// keeps the last value itself)
+fn validate_equivalence_langs(
+    benchmark: &Benchmark,
+    sink: bool,
+    location: &str,
+    result: &mut ValidationResult,
+) {
+    let mut langs: Vec<Lang> = benchmark.implementations.keys().copied().collect();
+    langs.sort_by_key(|l| l.as_str());
+    let names = |pred: fn(Lang) -> bool| -> Vec<&str> {
+        langs.iter().filter(|l| pred(**l)).map(|l| l.as_str()).collect()
+    };
+
+    let unsupported = names(|l| matches!(l, Lang::C | Lang::Zig));
+    if !unsupported.is_empty() {
+        result.add_error(
+            ValidationError::new(format!(
+                "'equivalent: true' cannot compare {} outputs (the harness does not report return values)",
+                unsupported.join(", ")
+            ))
+            .with_rule("equivalent-unsupported-lang")
+            .with_span(&benchmark.span)
+            .with_location(location.to_string()),
+        );
+    }
+
+    let uncaptured =
+        names(|l| matches!(l, Lang::Go | Lang::TypeScript | Lang::Python | Lang::CSharp));
+    if !sink && !uncaptured.is_empty() {
+        result.add_warning(
+            ValidationWarning::new(format!(
+                "'equivalent: true' has no effect for {} without 'sink: true' (outputs are not captured)",
+                uncaptured.join(", ")
+            ))
+            .with_rule("equivalent-without-sink")
+            .with_span(&benchmark.span)
+            .with_location(location.to_string()),
+        );
+    }
+}
+
+/// Check the effective `ciTarget` / `maxRuns` / `maxTime` of a benchmark
+fn validate_adaptive_settings(
+    benchmark: &Benchmark,
+    suite: &Suite,
+    location: &str,
+    result: &mut ValidationResult,
+) {
+    let ci_target = benchmark.ci_target.or(suite.ci_target);
+    let max_runs = benchmark.max_runs.or(suite.max_runs);
+    let count = benchmark.count.or(suite.count).unwrap_or(1);
+    let invalid = |message: String| {
+        ValidationError::new(message)
+            .with_rule("adaptive-settings-invalid")
+            .with_span(&benchmark.span)
+            .with_location(location.to_string())
+    };
+    let no_effect = |message: &str| {
+        ValidationWarning::new(message)
+            .with_rule("adaptive-without-effect")
+            .with_span(&benchmark.span)
+            .with_location(location.to_string())
+    };
+
+    if ci_target.is_some_and(|target| target <= 0.0) {
+        result.add_error(invalid("'ciTarget' must be a percentage greater than 0".to_string()));
+    }
+    if let Some(max_runs) = max_runs {
+        if max_runs < count.max(1) {
+            result.add_error(invalid(format!(
+                "'maxRuns: {}' is lower than 'count: {}'; adaptive runs never go below count",
+                max_runs, count
+            )));
+        }
+    }
+
+    if ci_target.is_none() {
+        if max_runs.is_some() || benchmark.max_time_ms.or(suite.max_time_ms).is_some() {
+            result.add_warning(no_effect("'maxRuns' and 'maxTime' only apply with 'ciTarget'"));
+        }
+    } else if suite.suite_type == Some(SuiteType::Memory) {
+        result.add_warning(no_effect("'ciTarget' is ignored in memory suites"));
+    } else if suite.order == Some(ExecutionOrder::Parallel) {
+        result.add_warning(no_effect("'ciTarget' is ignored for 'order: parallel' suites"));
+    }
+}
+
+/// Flag calls in implementations that resolve to neither a helper of the language's setup nor a
+/// fixture
+fn validate_helper_calls(
+    benchmark: &Benchmark,
+    suite: &Suite,
+    location: &str,
+    result: &mut ValidationResult,
+) {
+    for (lang, code) in &benchmark.implementations {
+        let Some(extractor) = helper_function_extractor(*lang) else { continue };
+        let setup = suite.setups.get(lang);
+        let helpers = setup
+            .and_then(|s| s.helpers.as_ref())
+            .map(|h| extractor.extract_functions(&h.code))
+            .unwrap_or_default();
+
+        for call in extract_function_calls(&code.code, *lang, extractor) {
+            if helpers.contains(&call.name) || suite.fixtures.iter().any(|f| f.name == call.name) {
+                continue;
+            }
+            let message = if setup.is_none() {
+                format!(
+                    "Function '{}' is not defined. Add a 'setup {} {{ helpers {{ ... }} }}' block to define it.",
+                    call.name, lang
+                )
+            } else {
+                format!(
+                    "Function '{}' is not defined in 'setup {} {{ helpers {{ ... }} }}'",
+                    call.name, lang
+                )
+            };
+            result.add_error(
+                ValidationError::new(message)
+                    .with_rule("undefined-function")
+                    .with_span(&span_within(code, call.start_offset, call.end_offset))
+                    .with_location(location.to_string()),
+            );
+        }
+    }
+}
+
+/// The source span of `code.code[start..end]`
+fn span_within(code: &CodeBlock, start: usize, end: usize) -> Span {
+    let before = &code.code[..start];
+    let line = code.span.line + before.matches('\n').count();
+    let col = match before.rfind('\n') {
+        Some(newline) => before[newline + 1..].chars().count() + 1,
+        None => code.span.col + before.chars().count(),
+    };
+    Span::new(code.span.start + start, code.span.start + end, line, col)
+}
+
+/// Flag every setup and code block whose language the project has not configured
+fn validate_runtime_configuration(
+    suite: &Suite,
+    is_configured: &impl Fn(Lang) -> bool,
+    result: &mut ValidationResult,
+) {
+    let mut report = |lang: Lang, span: &Span| {
+        if !is_configured(lang) {
+            result.add_error(
+                ValidationError::new(format!(
+                    "{} is not configured in this project. Run 'poly-bench add-runtime {}' to add this runtime.",
+                    lang, lang
+                ))
+                .with_rule("runtime-not-configured")
+                .with_span(span)
+                .with_location(format!("suite.{}", suite.name)),
+            );
+        }
+    };
+
+    for (lang, setup) in &suite.setups {
+        report(*lang, &setup.span);
+    }
+    for fixture in &suite.fixtures {
+        for (lang, code) in &fixture.implementations {
+            report(*lang, &code.span);
+        }
+    }
+    for benchmark in &suite.benchmarks {
+        let blocks = benchmark
+            .implementations
+            .iter()
+            .chain(&benchmark.before)
+            .chain(&benchmark.after)
+            .chain(&benchmark.each)
+            .chain(&benchmark.skip)
+            .chain(&benchmark.validate);
+        for (lang, code) in blocks {
+            report(*lang, &code.span);
+        }
+    }
+}
+
 /// Extract fixture references from code (heuristic: fixture name appears in code)
 fn extract_fixture_refs(code: &str, known_fixtures: &[String]) -> Vec<String> {
     let mut refs = Vec::new();
@@ -490,6 +878,16 @@ fn extract_fixture_refs(code: &str, known_fixtures: &[String]) -> Vec<String> {
     refs
 }
 
+/// Whole-word identifier search (`n` matches `sort(n)` but not `min`)
+fn references_ident(code: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    code.match_indices(name).any(|(pos, _)| {
+        let prev_ok = code[..pos].chars().next_back().is_none_or(|c| !is_ident(c));
+        let next_ok = code[pos + name.len()..].chars().next().is_none_or(|c| !is_ident(c));
+        prev_ok && next_ok
+    })
+}
+
 /// Validate that when sameDataset: true, all benchmarks use the same fixture set
 fn validate_same_dataset_consistency(suite: &Suite, result: &mut ValidationResult) {
     if suite.benchmarks.len() < 2 || suite.fixtures.is_empty() {
@@ -498,7 +896,7 @@ fn validate_same_dataset_consistency(suite: &Suite, result: &mut ValidationResul
 
     let fixture_names: Vec<String> = suite.fixtures.iter().map(|f| f.name.clone()).collect();
 
-    let mut bench_fixture_sets: Vec<(String, HashSet<String>)> = Vec::new();
+    let mut bench_fixture_sets: Vec<(String, &Span, HashSet<String>)> = Vec::new();
     for benchmark in &suite.benchmarks {
         let mut refs = HashSet::new();
         for code_block in benchmark.implementations.values() {
@@ -506,17 +904,19 @@ fn validate_same_dataset_consistency(suite: &Suite, result: &mut ValidationResul
                 refs.insert(r);
             }
         }
-        bench_fixture_sets.push((benchmark.name.clone(), refs));
+        bench_fixture_sets.push((benchmark.name.clone(), &benchmark.span, refs));
     }
 
-    let first_set = &bench_fixture_sets[0].1;
-    for (bench_name, refs) in bench_fixture_sets.iter().skip(1) {
+    let first_set = &bench_fixture_sets[0].2;
+    for (bench_name, bench_span, refs) in bench_fixture_sets.iter().skip(1) {
         if refs != first_set {
             result.add_warning(
                 ValidationWarning::new(format!(
                     "Benchmark '{}' may use different fixtures than other benchmarks; sameDataset: true expects all benchmarks to operate on the same dataset",
                     bench_name
                 ))
+                .with_rule("same-dataset-inconsistent-fixtures")
+                .with_span(bench_span)
                 .with_location(format!("suite.{}.bench.{}", suite.name, bench_name)),
             );
         }
@@ -530,20 +930,24 @@ fn validate_fixture_references(suite: &Suite, result: &mut ValidationResult) {
         let has_data = fixture.hex_data.is_some() ||
             fixture.hex_file.is_some() ||
             fixture.data.is_some() ||
-            fixture.data_file.is_some();
+            fixture.data_file.is_some() ||
+            fixture.generator.is_some();
         let has_implementations = !fixture.implementations.is_empty();
         let has_params = !fixture.params.is_empty();
 
         let source_count = fixture.hex_data.iter().count() +
             fixture.hex_file.iter().count() +
             fixture.data.iter().count() +
-            fixture.data_file.iter().count();
+            fixture.data_file.iter().count() +
+            fixture.generator.iter().count();
         if source_count > 1 {
             result.add_error(
                 ValidationError::new(format!(
                     "Fixture '{}' defines multiple data sources; use exactly one of hex/data (inline or @file)",
                     fixture.name
                 ))
+                .with_rule("fixture-multiple-sources")
+                .with_span(&fixture.span)
                 .with_location(format!("suite.{}.fixture.{}", suite.name, fixture.name)),
             );
         }
@@ -554,6 +958,8 @@ fn validate_fixture_references(suite: &Suite, result: &mut ValidationResult) {
                     "Fixture '{}' sets encoding but has no data source. Add `data: ...` or remove `encoding`",
                     fixture.name
                 ))
+                .with_rule("fixture-encoding-without-data")
+                .with_span(&fixture.span)
                 .with_location(format!("suite.{}.fixture.{}", suite.name, fixture.name)),
             );
         }
@@ -564,6 +970,8 @@ fn validate_fixture_references(suite: &Suite, result: &mut ValidationResult) {
                     "Fixture '{}' sets selector without format. Add `format: json|csv`",
                     fixture.name
                 ))
+                .with_rule("fixture-selector-without-format")
+                .with_span(&fixture.span)
                 .with_location(format!("suite.{}.fixture.{}", suite.name, fixture.name)),
             );
         }
@@ -574,9 +982,30 @@ fn validate_fixture_references(suite: &Suite, result: &mut ValidationResult) {
                     "Fixture '{}' has no fixture data, implementations, or parameters",
                     fixture.name
                 ))
+                .with_rule("empty-fixture")
+                .with_span(&fixture.span)
                 .with_location(format!("suite.{}.fixture.{}", suite.name, fixture.name)),
             );
         }
+
+        let used = suite.benchmarks.iter().any(|b| {
+            b.implementations
+                .values()
+                .chain(b.before.values())
+                .chain(b.after.values())
+                .chain(b.each.values())
+                .chain(b.skip.values())
+                .chain(b.validate.values())
+                .any(|code| references_ident(&code.code, &fixture.name))
+        });
+        if !used {
+            result.add_warning(
+                ValidationWarning::new(format!("Fixture '{}' appears to be unused", fixture.name))
+                    .with_rule("unused-fixture")
+                    .with_span(&fixture.span)
+                    .with_location(format!("suite.{}.fixture.{}", suite.name, fixture.name)),
+            );
+        }
     }
 }
 
@@ -584,12 +1013,14 @@ fn validate_fixture_references(suite: &Suite, result: &mut ValidationResult) {
 fn validate_baseline(suite: &Suite, result: &mut ValidationResult) {
     if let Some(baseline) = suite.baseline {
         for benchmark in &suite.benchmarks {
-            if !benchmark.implementations.contains_key(&baseline) {
+            if !benchmark.implementations.contains_key(&baseline.base()) {
                 result.add_error(
                     ValidationError::new(format!(
                         "Benchmark '{}' missing baseline language '{}'; baseline comparisons require every benchmark to implement the baseline",
                         benchmark.name, baseline
                     ))
+                    .with_rule("baseline-missing-in-benchmark")
+                    .with_span(&benchmark.span)
                     .with_location(format!("suite.{}.bench.{}", suite.name, benchmark.name)),
                 );
             }
@@ -731,6 +1162,8 @@ fn check_code_block_for_spawn_anvil_ref(
                 "spawnAnvil() can only be called in globalSetup blocks, found in {}",
                 context
             ))
+            .with_rule("spawn-anvil-outside-global-setup")
+            .with_span(&code.span)
             .with_location(format!("suite.{}", suite_name)),
         );
     }
@@ -903,6 +1336,45 @@ declare suite test performance timeBased sameDataset: true {
         }));
     }
 
+    #[test]
+    fn test_validate_chart_counts_parameterized_benchmarks() {
+        let source = r#"
+use std::charting
+
+declare suite test performance timeBased sameDataset: true {
+    targetTime: 2s
+    bench sort(n in [100, 1000]) {
+        go: work(n)
+        ts: work(n)
+    }
+    after {
+        charting.drawLineChart(title: "Trend", description: "d")
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_suite(&ast.suites[0]);
+        assert!(!result.errors.iter().any(|e| e.message.contains("at least 2 benchmarks")));
+    }
+
+    #[test]
+    fn test_validate_bench_param_shadowing_and_unused() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    fixture n {
+        hex: "00"
+    }
+    bench sort(n in [1], size in [2]) {
+        go: work(n)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_suite(&ast.suites[0]);
+        assert!(result.errors.iter().any(|e| e.message.contains("'n' shadows a fixture")));
+        assert!(result.warnings.iter().any(|w| w.message.contains("'size' is not referenced")));
+    }
+
     #[test]
     fn test_validate_baseline_missing_in_benchmark() {
         let source = r#"
@@ -950,4 +1422,227 @@ declare suite test performance timeBased sameDataset: true {
             w.message.contains("different fixtures") || w.message.contains("same dataset")
         }));
     }
+
+    #[test]
+    fn test_validate_equivalent_requires_sink() {
+        let source = r#"
+declare suite test performance iterationBased sameDataset: false {
+    iterations: 10
+    equivalent: true
+    bench captured {
+        go: work()
+    }
+    bench uncaptured {
+        sink: false
+        go: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        assert!(ast.suites[0].equivalent);
+        let result = validate_suite(&ast.suites[0]);
+        let warnings: Vec<_> =
+            result.warnings.iter().filter(|w| w.message.contains("'equivalent: true'")).collect();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].location.as_deref(), Some("suite.test.bench.uncaptured"));
+    }
+
+    #[test]
+    fn test_validate_equivalent_rust_without_sink_and_c() {
+        let source = r#"
+declare suite test performance iterationBased sameDataset: false {
+    iterations: 10
+    equivalent: true
+    sink: false
+    bench rust_only {
+        rust: work()
+    }
+    bench with_c {
+        rust: work()
+        c: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_suite(&ast.suites[0]);
+        assert!(!result.warnings.iter().any(|w| w.rule == Some("equivalent-without-sink")));
+        let errors: Vec<_> = result
+            .errors
+            .iter()
+            .filter(|e| e.rule == Some("equivalent-unsupported-lang"))
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location.as_deref(), Some("suite.test.bench.with_c"));
+        assert!(errors[0].message.contains("cannot compare c outputs"));
+    }
+
+    #[test]
+    fn test_validate_adaptive_settings() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    targetTime: 1s
+    count: 5
+    ciTarget: 2
+    bench ok {
+        go: work()
+    }
+    bench budget_below_count {
+        maxRuns: 3
+        go: work()
+    }
+    bench zero_target {
+        ciTarget: 0
+        go: work()
+    }
+}
+
+declare suite plain performance timeBased sameDataset: false {
+    targetTime: 1s
+    maxRuns: 10
+    bench unused {
+        go: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_suite(&ast.suites[0]);
+        let errors: Vec<_> = result
+            .errors
+            .iter()
+            .filter(|e| e.rule == Some("adaptive-settings-invalid"))
+            .map(|e| e.location.as_deref().unwrap())
+            .collect();
+        assert_eq!(
+            errors,
+            vec!["suite.test.bench.budget_below_count", "suite.test.bench.zero_target"]
+        );
+
+        let result = validate_suite(&ast.suites[1]);
+        assert!(result.warnings.iter().any(|w| w.rule == Some("adaptive-without-effect")));
+    }
+
+    #[test]
+    fn test_apply_lints_relevels_and_suppresses() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    targetTime: 2s
+    bench sort(n in [1], size in [2]) {
+        go: work(n)
+    }
+    # polybench-ignore: empty-benchmark
+    bench empty {
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_suite(&ast.suites[0]);
+        assert!(result.errors.iter().any(|e| e.rule == Some("empty-benchmark")));
+        let unused = result.warnings.iter().find(|w| w.rule == Some("unused-param")).unwrap();
+        assert_eq!(unused.line, Some(4));
+
+        let config = LintConfig::new([("unused-param".to_string(), LintLevel::Error)].into());
+        let linted =
+            validate_suite(&ast.suites[0]).apply_lints(&config, &Suppressions::parse(source));
+        assert!(linted.warnings.is_empty());
+        assert_eq!(linted.errors.len(), 1);
+        assert_eq!(linted.errors[0].rule, Some("unused-param"));
+    }
+
+    #[test]
+    fn test_validate_unused_fixture_and_empty_suite() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    fixture payload {
+        hex: "00"
+    }
+    fixture payloadCopy {
+        hex: "00"
+    }
+    bench a {
+        go: work(payload)
+    }
+}
+
+declare suite empty performance timeBased sameDataset: false {
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_file(&ast);
+        let unused: Vec<_> =
+            result.warnings.iter().filter(|w| w.rule == Some("unused-fixture")).collect();
+        assert_eq!(unused.len(), 1);
+        assert!(unused[0].message.contains("payloadCopy"));
+        let empty = result.warnings.iter().find(|w| w.rule == Some("empty-suite")).unwrap();
+        assert!(empty.message.contains("'empty'"));
+    }
+
+    struct DefsOnly;
+
+    impl crate::helper_extractor::HelperFunctionExtractor for DefsOnly {
+        fn extract_functions(&self, code: &str) -> HashSet<String> {
+            code.lines()
+                .filter_map(|l| l.trim().strip_prefix("def "))
+                .filter_map(|l| l.split('(').next())
+                .map(str::to_string)
+                .collect()
+        }
+
+        fn builtins(&self) -> &'static [&'static str] {
+            &["len"]
+        }
+    }
+
+    #[test]
+    fn test_validate_undefined_function_span() {
+        static EXTRACTOR: DefsOnly = DefsOnly;
+        crate::helper_extractor::set_helper_function_extractors(vec![(Lang::Python, &EXTRACTOR)]);
+
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    setup python {
+        helpers {
+            def known(x):
+                return x
+        }
+    }
+    bench a {
+        python: {
+            known(len([1]))
+            missing(2)
+        }
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_file(&ast);
+        let undefined: Vec<_> =
+            result.errors.iter().filter(|e| e.rule == Some("undefined-function")).collect();
+        assert_eq!(undefined.len(), 1);
+        assert!(undefined[0].message.contains("'missing'"));
+        let span = undefined[0].span.as_ref().unwrap();
+        assert_eq!(&source[span.start..span.end], "missing");
+        assert_eq!((span.line, span.col), (12, 13));
+    }
+
+    #[test]
+    fn test_validate_project_file_flags_unconfigured_runtimes() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    bench a {
+        go: work()
+        rust: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let result = validate_project_file(&ast, |lang| lang == Lang::Go);
+        let unconfigured: Vec<_> =
+            result.errors.iter().filter(|e| e.rule == Some("runtime-not-configured")).collect();
+        assert_eq!(unconfigured.len(), 1);
+        assert!(unconfigured[0].message.starts_with("rust is not configured"));
+        assert!(validate_file(&ast)
+            .errors
+            .iter()
+            .all(|e| e.rule != Some("runtime-not-configured")));
+    }
 }
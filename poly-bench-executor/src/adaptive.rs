@@ -0,0 +1,220 @@
+//! Adaptive run counts (`ciTarget`, `maxRuns`, `maxTime`)
+//!
+//! Instead of running a benchmark exactly `count` times, the scheduler keeps adding run blocks
+//! until the 95% CI of the median across runs is within `ciTarget` percent of the median for
+//! every language, or the run/time budget is spent. `count` (at least [`MIN_ADAPTIVE_RUNS`]) runs
+//! always happen before the CI is first checked. The CI is the bootstrap interval reported as
+//! `ci_95_lower`/`ci_95_upper` on the aggregated measurement.
+
+use poly_bench_dsl::Lang;
+use poly_bench_ir::BenchmarkSpec;
+use poly_bench_runtime::measurement::Measurement;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Fewest runs before the CI is checked; fewer runs give a meaningless interval
+pub const MIN_ADAPTIVE_RUNS: u64 = 3;
+
+/// Stopping rule for one benchmark
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptivePlan {
+    /// Target 95% CI half-width, percent of the median
+    pub target_ci_percent: f64,
+    /// Runs always performed
+    pub min_runs: u64,
+    /// Run budget
+    pub max_runs: u64,
+    /// Time budget for the benchmark's runs
+    pub max_time: Option<Duration>,
+}
+
+impl AdaptivePlan {
+    /// Plan for a benchmark with `ciTarget` set, `None` otherwise
+    pub fn from_spec(spec: &BenchmarkSpec) -> Option<Self> {
+        let target_ci_percent = spec.ci_target.filter(|target| *target > 0.0)?;
+        let min_runs = spec.count.max(MIN_ADAPTIVE_RUNS);
+        Some(Self {
+            target_ci_percent,
+            min_runs,
+            max_runs: spec.max_runs.max(min_runs),
+            max_time: spec.max_time_ms.map(Duration::from_millis),
+        })
+    }
+
+    /// Decide after a completed run block whether to stop. `ci_percents` holds the current CI
+    /// half-width of every language being adapted (`None` when it cannot be computed yet).
+    pub fn check(
+        &self,
+        runs: u64,
+        elapsed: Duration,
+        ci_percents: &[Option<f64>],
+    ) -> Option<AdaptiveStop> {
+        if runs < self.min_runs {
+            return None;
+        }
+        if !ci_percents.is_empty() &&
+            ci_percents.iter().all(|ci| ci.is_some_and(|ci| ci <= self.target_ci_percent))
+        {
+            Some(AdaptiveStop::TargetMet)
+        } else if runs >= self.max_runs {
+            Some(AdaptiveStop::RunBudget)
+        } else if self.max_time.is_some_and(|budget| elapsed >= budget) {
+            Some(AdaptiveStop::TimeBudget)
+        } else {
+            None
+        }
+    }
+}
+
+/// 95% CI half-width of the median nanos/op across runs, as a percentage of the median
+pub fn ci_half_width_percent(runs: &[Measurement]) -> Option<f64> {
+    let nanos: Vec<f64> = runs.iter().map(|m| m.nanos_per_op).collect();
+    let (median, lower, upper) = Measurement::median_ci(&nanos)?;
+    (median > 0.0).then(|| (upper - lower) / 2.0 / median * 100.0)
+}
+
+/// What ended an adaptive benchmark
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum AdaptiveStop {
+    TargetMet,
+    RunBudget,
+    TimeBudget,
+}
+
+impl AdaptiveStop {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::TargetMet => "target met",
+            Self::RunBudget => "run budget exhausted",
+            Self::TimeBudget => "time budget exhausted",
+        }
+    }
+}
+
+/// How many runs an adaptive benchmark took and whether it reached its CI target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AdaptiveOutcome {
+    /// Target 95% CI half-width, percent of the median
+    pub target_ci_percent: f64,
+    /// Runs performed per language
+    pub runs: HashMap<Lang, u64>,
+    /// Achieved 95% CI half-width per language, percent of the median
+    pub ci_percent: HashMap<Lang, f64>,
+    /// Whether every language reached the target
+    pub target_met: bool,
+    /// What ended the runs of the first language that missed the target (or `TargetMet`)
+    pub stopped_by: AdaptiveStop,
+}
+
+impl AdaptiveOutcome {
+    pub fn new(plan: &AdaptivePlan) -> Self {
+        Self {
+            target_ci_percent: plan.target_ci_percent,
+            runs: HashMap::new(),
+            ci_percent: HashMap::new(),
+            target_met: true,
+            stopped_by: AdaptiveStop::TargetMet,
+        }
+    }
+
+    /// Record where one language ended up
+    pub fn record(&mut self, lang: Lang, runs: &[Measurement], stop: AdaptiveStop) {
+        let ci = ci_half_width_percent(runs);
+        self.runs.insert(lang, runs.len() as u64);
+        if let Some(ci) = ci {
+            self.ci_percent.insert(lang, ci);
+        }
+        if self.target_met && !ci.is_some_and(|ci| ci <= self.target_ci_percent) {
+            self.target_met = false;
+            self.stopped_by = stop;
+        }
+    }
+
+    /// One-line summary, e.g. `12 runs, CI ±1.8% (target ±2%)`
+    pub fn summary(&self) -> String {
+        let max_runs = self.runs.values().max().copied().unwrap_or(0);
+        let min_runs = self.runs.values().min().copied().unwrap_or(0);
+        let runs = if min_runs == max_runs {
+            format!("{} runs", max_runs)
+        } else {
+            format!("{}-{} runs", min_runs, max_runs)
+        };
+        let worst = self
+            .ci_percent
+            .values()
+            .copied()
+            .fold(None, |acc: Option<f64>, ci| Some(acc.map_or(ci, |acc| acc.max(ci))));
+        let ci = worst.map(|ci| format!(", CI ±{:.2}%", ci)).unwrap_or_default();
+        if self.target_met {
+            format!("{}{} (target ±{}%)", runs, ci, self.target_ci_percent)
+        } else {
+            format!(
+                "{}{}, target ±{}% not met ({})",
+                runs,
+                ci,
+                self.target_ci_percent,
+                self.stopped_by.label()
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runs(nanos: &[u64]) -> Vec<Measurement> {
+        nanos.iter().map(|&n| Measurement::from_aggregate(1000, n * 1000)).collect()
+    }
+
+    #[test]
+    fn test_plan_requires_ci_target_and_respects_count() {
+        let mut spec = BenchmarkSpec::new("b".to_string(), "s", 100, 10, 0);
+        assert!(AdaptivePlan::from_spec(&spec).is_none());
+
+        spec.ci_target = Some(2.0);
+        spec.count = 1;
+        spec.max_runs = 2;
+        let plan = AdaptivePlan::from_spec(&spec).unwrap();
+        assert_eq!(plan.min_runs, MIN_ADAPTIVE_RUNS);
+        assert_eq!(plan.max_runs, MIN_ADAPTIVE_RUNS);
+    }
+
+    #[test]
+    fn test_check_stops_on_target_or_budget() {
+        let plan = AdaptivePlan {
+            target_ci_percent: 2.0,
+            min_runs: 3,
+            max_runs: 10,
+            max_time: Some(Duration::from_secs(5)),
+        };
+        let second = Duration::from_secs(1);
+        assert_eq!(plan.check(2, second, &[Some(0.1)]), None);
+        assert_eq!(plan.check(3, second, &[Some(0.1), Some(1.9)]), Some(AdaptiveStop::TargetMet));
+        assert_eq!(plan.check(3, second, &[Some(0.1), Some(4.0)]), None);
+        assert_eq!(plan.check(3, second, &[None]), None);
+        assert_eq!(plan.check(10, second, &[Some(4.0)]), Some(AdaptiveStop::RunBudget));
+        assert_eq!(
+            plan.check(4, Duration::from_secs(6), &[Some(4.0)]),
+            Some(AdaptiveStop::TimeBudget)
+        );
+    }
+
+    #[test]
+    fn test_outcome_records_ci_and_misses() {
+        let plan =
+            AdaptivePlan { target_ci_percent: 1.0, min_runs: 3, max_runs: 5, max_time: None };
+        let mut outcome = AdaptiveOutcome::new(&plan);
+        outcome.record(Lang::Go, &runs(&[100, 100, 100, 100]), AdaptiveStop::TargetMet);
+        assert!(outcome.target_met);
+        assert_eq!(outcome.ci_percent[&Lang::Go], 0.0);
+
+        outcome.record(Lang::Rust, &runs(&[80, 120, 95, 140, 60]), AdaptiveStop::RunBudget);
+        assert!(!outcome.target_met);
+        assert_eq!(outcome.stopped_by, AdaptiveStop::RunBudget);
+        assert_eq!(outcome.runs[&Lang::Rust], 5);
+        assert!(outcome.summary().starts_with("4-5 runs, CI ±"));
+        assert!(outcome.summary().ends_with("target ±1% not met (run budget exhausted)"));
+    }
+}
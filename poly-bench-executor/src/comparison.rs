@@ -1,31 +1,90 @@
 //! Cross-language comparison types and logic
 
+use crate::{
+    adaptive::AdaptiveOutcome, environment::RunEnvironment, equivalence::EquivalenceCheck,
+    parallel::PARALLEL_COMPARISON_MODE,
+};
 use poly_bench_dsl::{BenchmarkKind, Lang, SuiteType};
+use poly_bench_ir::{BenchParamIR, SkippedBenchmark};
 use poly_bench_runtime::{
-    measurement::{Comparison, Measurement},
+    measurement::{Comparison, Measurement, Significance},
     supported_languages,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Version of the results format written by this build. Bump it and add a migration to
+/// [`crate::results_file`] when the serialized shape changes incompatibly.
+pub const RESULTS_SCHEMA_VERSION: u32 = 2;
+
 /// Results from running all benchmarks
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BenchmarkResults {
+    /// Results format version; files written before versioning are version 1
+    #[serde(rename = "schemaVersion", default = "unversioned_schema")]
+    pub schema_version: u32,
     /// Results organized by suite
     pub suites: Vec<SuiteResults>,
     /// Overall statistics
     pub summary: OverallSummary,
+    /// Benchmarks not run because they were filtered out (`--tag`, `--suite`, `--bench`, ...)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedBenchmark>,
+    /// Machine, toolchains and sources the results were produced with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<RunEnvironment>,
 }
 
 impl BenchmarkResults {
     pub fn new(suites: Vec<SuiteResults>) -> Self {
         let summary = OverallSummary::calculate(&suites);
-        Self { suites, summary }
+        Self {
+            schema_version: RESULTS_SCHEMA_VERSION,
+            suites,
+            summary,
+            skipped: Vec::new(),
+            environment: None,
+        }
+    }
+
+    /// Attach benchmarks that were filtered out before scheduling
+    pub fn with_skipped(mut self, skipped: Vec<SkippedBenchmark>) -> Self {
+        self.skipped = skipped;
+        self
+    }
+
+    /// Attach the environment the run was captured in
+    pub fn with_environment(mut self, environment: RunEnvironment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Benchmarks whose implementations returned different outputs (`equivalent: true`)
+    pub fn equivalence_failures(&self) -> Vec<&BenchmarkResult> {
+        self.suites
+            .iter()
+            .flat_map(|s| s.benchmarks.iter())
+            .filter(|b| b.equivalence_failed())
+            .collect()
+    }
+
+    /// Benchmarks whose `validate` expression returned false for at least one language
+    pub fn validation_failures(&self) -> Vec<&BenchmarkResult> {
+        self.suites
+            .iter()
+            .flat_map(|s| s.benchmarks.iter())
+            .filter(|b| b.validation_failed())
+            .collect()
     }
 }
 
+fn unversioned_schema() -> u32 {
+    1
+}
+
 /// Results for a single suite
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SuiteResults {
     /// Suite name
     pub name: String,
@@ -37,6 +96,9 @@ pub struct SuiteResults {
     pub benchmarks: Vec<BenchmarkResult>,
     /// Suite-level summary
     pub summary: SuiteSummary,
+    /// Fixtures produced by a generator, with the seeds needed to reproduce them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generated_fixtures: Vec<GeneratedFixture>,
 }
 
 impl SuiteResults {
@@ -47,12 +109,37 @@ impl SuiteResults {
         benchmarks: Vec<BenchmarkResult>,
     ) -> Self {
         let summary = SuiteSummary::calculate(&benchmarks, suite_type);
-        Self { name, description, suite_type, benchmarks, summary }
+        Self { name, description, suite_type, benchmarks, summary, generated_fixtures: Vec::new() }
     }
+
+    /// Attach the generated fixtures the suite's benchmarks ran against
+    pub fn with_generated_fixtures(mut self, generated_fixtures: Vec<GeneratedFixture>) -> Self {
+        self.generated_fixtures = generated_fixtures;
+        self
+    }
+
+    /// Whether this suite was measured under parallel contention (`order: parallel`)
+    pub fn is_parallel(&self) -> bool {
+        !self.benchmarks.is_empty() && self.benchmarks.iter().all(BenchmarkResult::is_parallel)
+    }
+}
+
+/// A fixture whose data came from `@random`, `@sequence` or `@repeat`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GeneratedFixture {
+    /// Fixture name
+    pub name: String,
+    /// Generator call, including the seed when it was defaulted
+    pub generator: String,
+    /// Seed used for random data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Size of the generated data in bytes
+    pub bytes: usize,
 }
 
 /// Result for a single benchmark
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct BenchmarkResult {
     /// Benchmark name
     pub name: String,
@@ -74,10 +161,26 @@ pub struct BenchmarkResult {
     /// Optional fairness seed used for randomized/interleaved execution
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fairness_seed: Option<u64>,
+    /// Parameter bindings for benchmarks expanded from a parameter matrix
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<BenchParamIR>,
+    /// Cross-language output comparison for benchmarks marked `equivalent: true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equivalence: Option<EquivalenceCheck>,
+    /// Runs taken and CI reached for benchmarks with a `ciTarget`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive: Option<AdaptiveOutcome>,
+    /// Fingerprint of the benchmark's inputs (spec, suite setup, referenced fixtures) when it
+    /// ran; editors compare it with the current source to mark results as stale
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_hash: Option<String>,
+    /// CV threshold (%) the measurements' `is_stable` flags were judged against
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cv_threshold: Option<f64>,
 }
 
 /// Extra output included for async benchmarks in `results.json`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AsyncBenchmarkDetails {
     /// Execution semantics for async benchmarks
     pub mode: String,
@@ -133,7 +236,67 @@ impl BenchmarkResult {
             async_details,
             comparison_mode,
             fairness_seed,
+            params: Vec::new(),
+            equivalence: None,
+            adaptive: None,
+            source_hash: None,
+            cv_threshold: None,
+        }
+    }
+
+    /// Whether this result was measured concurrently with other runtimes
+    pub fn is_parallel(&self) -> bool {
+        self.comparison_mode == PARALLEL_COMPARISON_MODE
+    }
+
+    /// Attach the parameter bindings this result was measured with
+    pub fn with_params(mut self, params: Vec<BenchParamIR>) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Compare the outputs captured by each language's measurement
+    pub fn with_equivalence_check(mut self) -> Self {
+        self.equivalence = Some(EquivalenceCheck::from_measurements(&self.measurements));
+        self
+    }
+
+    pub fn with_adaptive(mut self, adaptive: Option<AdaptiveOutcome>) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Judge stability against the suite's `cvThreshold`.
+    ///
+    /// Multi-run aggregation uses the default threshold, so `is_stable` is re-derived here.
+    pub fn with_cv_threshold(mut self, threshold: f64) -> Self {
+        for m in self.measurements.values_mut() {
+            if let Some(cv) = m.cv_percent {
+                m.is_stable = Some(cv <= threshold);
+            }
         }
+        self.cv_threshold = Some(threshold);
+        self
+    }
+
+    pub fn with_source_hash(mut self, source_hash: Option<String>) -> Self {
+        self.source_hash = source_hash;
+        self
+    }
+
+    /// Whether the implementations were checked for equivalence and disagreed
+    pub fn equivalence_failed(&self) -> bool {
+        self.equivalence.as_ref().is_some_and(|e| !e.passed)
+    }
+
+    /// Whether any language's `validate` expression returned false
+    pub fn validation_failed(&self) -> bool {
+        self.measurements.values().any(|m| m.validation_error.is_some())
+    }
+
+    /// Numeric x-axis value for trend charts: the first numeric parameter binding
+    pub fn param_x_value(&self) -> Option<f64> {
+        self.params.iter().find_map(|p| p.value.as_f64())
     }
 
     fn calculate_comparison(
@@ -173,16 +336,23 @@ impl BenchmarkResult {
             None
         };
 
-        Some(Comparison::new_with_metric(
-            String::new(),
-            first_measurement.clone(),
-            poly_bench_runtime::lang_full_name(first_lang).to_string(),
-            second_measurement.clone(),
-            poly_bench_runtime::lang_full_name(second_lang).to_string(),
-            first_val,
-            second_val,
-            ratio_ci_95,
-        ))
+        Some(
+            Comparison::new_with_metric(
+                String::new(),
+                first_measurement.clone(),
+                poly_bench_runtime::lang_full_name(first_lang).to_string(),
+                second_measurement.clone(),
+                poly_bench_runtime::lang_full_name(second_lang).to_string(),
+                first_val,
+                second_val,
+                ratio_ci_95,
+            )
+            .with_significance(if use_memory {
+                None
+            } else {
+                Significance::between(first_measurement, second_measurement)
+            }),
+        )
     }
 
     fn build_async_details(
@@ -249,13 +419,13 @@ impl BenchmarkResult {
 }
 
 /// Summary statistics for a suite
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SuiteSummary {
     /// Total benchmarks
     pub total_benchmarks: usize,
     /// Wins per language
     pub lang_wins: HashMap<Lang, u32>,
-    /// Ties
+    /// Ties (within 5%, or not statistically significant)
     pub ties: usize,
     /// Geometric mean speedup (>1 means Go is faster)
     pub geo_mean_speedup: f64,
@@ -325,11 +495,17 @@ impl SuiteSummary {
                 // Sort by value (lowest first)
                 values.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
                 let (best_lang, best_val) = values[0];
-                let (_, second_val) = values[1];
+                let (second_lang, second_val) = values[1];
 
-                // Check for tie (within 5%)
+                // Check for tie (within 5%, or a gap the samples cannot confirm)
                 let speedup = second_val / best_val;
-                if speedup < 1.05 {
+                let inconclusive = !use_memory &&
+                    Significance::between(
+                        &bench.measurements[&best_lang],
+                        &bench.measurements[&second_lang],
+                    )
+                    .is_some_and(|test| !test.significant);
+                if speedup < 1.05 || inconclusive {
                     ties += 1;
                 } else {
                     lang_wins.entry(best_lang).and_modify(|c| *c += 1).or_insert(1);
@@ -376,7 +552,7 @@ impl SuiteSummary {
 }
 
 /// Overall summary across all suites
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct OverallSummary {
     /// Total suites
     pub total_suites: usize,
@@ -396,6 +572,10 @@ pub struct OverallSummary {
     pub unstable_count: usize,
     /// Total outliers removed across all measurements
     pub total_outliers_removed: u64,
+    /// Benchmarks measured under parallel contention, left out of wins and the geo mean when
+    /// isolated results are also present
+    #[serde(default)]
+    pub parallel_benchmarks: usize,
 }
 
 impl OverallSummary {
@@ -408,9 +588,17 @@ impl OverallSummary {
         let mut unstable_count = 0;
         let mut total_outliers_removed = 0u64;
         let mut log_speedups = Vec::new();
+        let mut parallel_benchmarks = 0;
+        // Contended timings are not comparable with isolated ones; only mix them in when the
+        // whole run was parallel
+        let has_isolated = suites.iter().any(|s| !s.is_parallel());
 
         for suite in suites {
             total_benchmarks += suite.benchmarks.len();
+            if has_isolated && suite.is_parallel() {
+                parallel_benchmarks += suite.benchmarks.len();
+                continue;
+            }
             for (lang, wins) in &suite.summary.lang_wins {
                 lang_wins.entry(*lang).and_modify(|c| *c += *wins).or_insert(*wins);
             }
@@ -474,6 +662,7 @@ impl OverallSummary {
             winner_description,
             unstable_count,
             total_outliers_removed,
+            parallel_benchmarks,
         }
     }
 }
@@ -524,6 +713,71 @@ mod tests {
         assert!(!json.contains("\"fairness_seed\""));
     }
 
+    #[test]
+    fn test_benchmark_result_params_serialization() {
+        use poly_bench_ir::ParamValue;
+
+        let plain = BenchmarkResult::new(
+            "sort".to_string(),
+            "suite_sort".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            HashMap::new(),
+            SuiteType::Performance,
+            "strict".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(!serde_json::to_string(&plain).unwrap().contains("\"params\""));
+        assert_eq!(plain.param_x_value(), None);
+
+        let result = plain.with_params(vec![
+            BenchParamIR::new("algo".to_string(), ParamValue::String("quick".to_string())),
+            BenchParamIR::new("n".to_string(), ParamValue::Int(1000)),
+        ]);
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"params\":[{\"name\":\"algo\",\"value\":\"quick\"}"));
+        assert_eq!(result.param_x_value(), Some(1000.0));
+
+        let back: BenchmarkResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.params, result.params);
+    }
+
+    #[test]
+    fn test_unconfirmed_difference_counts_as_tie() {
+        let mut measurements = HashMap::<Lang, Measurement>::new();
+        measurements.insert(
+            Lang::Go,
+            Measurement::from_samples(vec![100, 130, 90, 120, 95, 125, 85, 115], 8),
+        );
+        measurements.insert(
+            Lang::Rust,
+            Measurement::from_samples(vec![115, 100, 140, 105, 130, 95, 145, 110], 8),
+        );
+        let result = BenchmarkResult::new(
+            "noisy".to_string(),
+            "suite_noisy".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "legacy".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+        let cmp = result.comparison.as_ref().unwrap();
+        assert_eq!(cmp.winner, poly_bench_runtime::measurement::ComparisonWinner::Inconclusive);
+        assert!(cmp.significance.as_ref().is_some_and(|t| !t.significant));
+
+        let suite = SuiteResults::new("s".to_string(), None, SuiteType::Performance, vec![result]);
+        assert_eq!(suite.summary.ties, 1);
+        assert_eq!(suite.summary.lang_wins[&Lang::Go], 0);
+    }
+
     #[test]
     fn test_async_details_include_success_error_ratios() {
         let mut measurements = HashMap::<Lang, Measurement>::new();
@@ -581,4 +835,43 @@ mod tests {
         assert_eq!(details.error_iterations.get(&Lang::TypeScript), Some(&2));
         assert_eq!(details.success_ratio.get(&Lang::TypeScript), Some(&(4.0 / 6.0)));
     }
+
+    #[test]
+    fn test_overall_summary_keeps_parallel_suites_apart() {
+        let suite = |name: &str, mode: &str, go_ns: u64| {
+            let mut measurements = HashMap::new();
+            measurements.insert(Lang::Go, Measurement::from_aggregate(10, go_ns * 10));
+            measurements.insert(Lang::TypeScript, Measurement::from_aggregate(10, 20_000));
+            let bench = BenchmarkResult::new(
+                "bench".to_string(),
+                format!("{}_bench", name),
+                BenchmarkKind::Sync,
+                None,
+                measurements,
+                SuiteType::Performance,
+                mode.to_string(),
+                None,
+                None,
+                None,
+                None,
+            );
+            SuiteResults::new(name.to_string(), None, SuiteType::Performance, vec![bench])
+        };
+
+        // Go wins the isolated suite, TypeScript wins the contended one
+        let mixed = BenchmarkResults::new(vec![
+            suite("isolated", "legacy", 1_000),
+            suite("contended", PARALLEL_COMPARISON_MODE, 3_000),
+        ]);
+        assert!(mixed.suites[1].is_parallel());
+        assert_eq!(mixed.summary.total_benchmarks, 2);
+        assert_eq!(mixed.summary.parallel_benchmarks, 1);
+        assert_eq!(mixed.summary.lang_wins.get(&Lang::TypeScript), Some(&0));
+        assert_eq!(mixed.summary.winner, Some(Lang::Go));
+
+        let parallel_only =
+            BenchmarkResults::new(vec![suite("contended", PARALLEL_COMPARISON_MODE, 3_000)]);
+        assert_eq!(parallel_only.summary.parallel_benchmarks, 0);
+        assert_eq!(parallel_only.summary.winner, Some(Lang::TypeScript));
+    }
 }
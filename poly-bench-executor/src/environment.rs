@@ -0,0 +1,318 @@
+//! Run environment metadata recorded alongside results
+//!
+//! Captures the machine, toolchains and sources a run was produced with so results from
+//! different hosts (a laptop vs a CI runner) can be told apart when they are compared later.
+
+use crate::{compile_cache::CompileCache, isolation::IsolationReport, workspace::format_size};
+use poly_bench_dsl::Lang;
+use poly_bench_project::runtime_check;
+use poly_bench_runtime::{lang_label, variant::variant_toolchain, VariantToolchain};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Environment variables that change how each language compiles or runs benchmarks
+fn compile_env_vars(lang: Lang) -> &'static [&'static str] {
+    match lang.base() {
+        Lang::Go => &["GOFLAGS", "GOAMD64", "GOARM64", "CGO_CFLAGS"],
+        Lang::TypeScript => &["NODE_OPTIONS"],
+        Lang::Rust => &["RUSTFLAGS", "CARGO_PROFILE_RELEASE_LTO"],
+        Lang::Python => &["PYTHONOPTIMIZE"],
+        Lang::C => &["CFLAGS"],
+        Lang::CSharp => &["DOTNET_TieredPGO", "DOTNET_TieredCompilation"],
+        _ => &[],
+    }
+}
+
+/// Where and with what a set of results was produced
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RunEnvironment {
+    /// poly-bench version that ran the benchmarks
+    pub polybench_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Kernel name and release (`uname -sr`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kernel: Option<String>,
+    pub cpu: CpuInfo,
+    /// Total physical memory in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
+    /// Resolved toolchain per language that ran
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub toolchains: Vec<ToolchainInfo>,
+    /// Commit of the project repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<GitInfo>,
+    /// `.bench` files the results came from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bench_files: Vec<BenchFileInfo>,
+    /// Noise control applied to the run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<IsolationReport>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CpuInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Logical CPUs available to the process
+    pub cores: usize,
+    /// Frequency scaling governor of CPU 0 (Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub governor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ToolchainInfo {
+    pub lang: Lang,
+    /// First line of the toolchain's version command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Compiler or interpreter flags from a runtime variant
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    /// Compilation-relevant environment (variant `env` and variables such as `RUSTFLAGS`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GitInfo {
+    pub commit: String,
+    /// Whether the working tree had uncommitted changes
+    pub dirty: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BenchFileInfo {
+    pub path: String,
+    /// SHA-256 of the file contents
+    pub sha256: String,
+}
+
+impl RunEnvironment {
+    /// Capture the current machine, the toolchains of `langs`, the git state of `project_root`
+    /// (or the current directory) and hashes of `bench_files`
+    pub fn capture(langs: &[Lang], project_root: Option<&Path>, bench_files: &[PathBuf]) -> Self {
+        let git_dir = project_root.map(Path::to_path_buf).or_else(|| std::env::current_dir().ok());
+        Self {
+            polybench_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            kernel: command_line("uname", &["-sr"], None),
+            cpu: CpuInfo {
+                model: cpu_model(),
+                cores: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+                governor: read_trimmed("/sys/devices/system/cpu/cpu0/cpufreq/scaling_governor"),
+            },
+            memory_bytes: memory_bytes(),
+            toolchains: langs.iter().map(|lang| ToolchainInfo::capture(*lang)).collect(),
+            git: git_dir.as_deref().and_then(GitInfo::capture),
+            bench_files: bench_files
+                .iter()
+                .filter_map(|path| {
+                    let source = std::fs::read_to_string(path).ok()?;
+                    Some(BenchFileInfo {
+                        path: path.display().to_string(),
+                        sha256: CompileCache::hash_source(&source),
+                    })
+                })
+                .collect(),
+            isolation: None,
+        }
+    }
+
+    /// Record the noise control that was applied
+    pub fn with_isolation(mut self, isolation: Option<IsolationReport>) -> Self {
+        self.isolation = isolation;
+        self
+    }
+
+    /// One-line CPU description, e.g. `AMD EPYC 7763 (16 cores, performance)`
+    pub fn cpu_summary(&self) -> String {
+        let mut details = vec![format!("{} cores", self.cpu.cores)];
+        details.extend(self.cpu.governor.clone());
+        format!("{} ({})", self.cpu.model.as_deref().unwrap_or("unknown CPU"), details.join(", "))
+    }
+
+    /// Label/value rows for report headers
+    pub fn summary_rows(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("poly-bench".to_string(), self.polybench_version.clone()),
+            (
+                "OS".to_string(),
+                match self.kernel {
+                    Some(ref kernel) => format!("{} ({}, {})", self.os, kernel, self.arch),
+                    None => format!("{} ({})", self.os, self.arch),
+                },
+            ),
+            ("CPU".to_string(), self.cpu_summary()),
+        ];
+        if let Some(bytes) = self.memory_bytes {
+            rows.push(("Memory".to_string(), format_size(bytes)));
+        }
+        if let Some(ref git) = self.git {
+            let short = &git.commit[..git.commit.len().min(12)];
+            rows.push((
+                "Git".to_string(),
+                if git.dirty { format!("{} (dirty)", short) } else { short.to_string() },
+            ));
+        }
+        for toolchain in &self.toolchains {
+            let mut value = toolchain.version.clone().unwrap_or_else(|| "unknown".to_string());
+            if let Some(ref path) = toolchain.path {
+                value.push_str(&format!(" ({})", path.display()));
+            }
+            let settings: Vec<String> = toolchain
+                .flags
+                .iter()
+                .cloned()
+                .chain(toolchain.env.iter().map(|(k, v)| format!("{}={}", k, v)))
+                .collect();
+            if !settings.is_empty() {
+                value.push_str(&format!(" [{}]", settings.join(" ")));
+            }
+            rows.push((lang_label(toolchain.lang).to_string(), value));
+        }
+        if let Some(ref isolation) = self.isolation {
+            rows.push(("Isolation".to_string(), isolation.summary()));
+        }
+        for file in &self.bench_files {
+            rows.push((file.path.clone(), format!("sha256:{}", &file.sha256[..16])));
+        }
+        rows
+    }
+}
+
+impl ToolchainInfo {
+    fn capture(lang: Lang) -> Self {
+        let variant = variant_toolchain(lang);
+        let mut env: BTreeMap<String, String> = compile_env_vars(lang)
+            .iter()
+            .filter_map(|name| std::env::var(name).ok().map(|v| (name.to_string(), v)))
+            .collect();
+        if let Some(ref variant) = variant {
+            env.extend(variant.env.clone());
+        }
+        let path = resolved_binary(lang, variant.as_ref());
+        Self {
+            lang,
+            version: path.as_deref().and_then(|p| runtime_check::version_output(lang, p)),
+            path,
+            flags: variant.map(|v| v.flags).unwrap_or_default(),
+            env,
+        }
+    }
+}
+
+/// The binary `lang`'s runtime executes, resolved the way its executor does: a variant's
+/// `binary` override, then the Polybench-managed toolchain, then PATH
+fn resolved_binary(lang: Lang, variant: Option<&VariantToolchain>) -> Option<PathBuf> {
+    if lang.is_external() {
+        return runtime_check::find_binary_path(lang);
+    }
+    variant.cloned().unwrap_or_default().resolve_binary(lang).ok().map(|(path, _)| path)
+}
+
+impl GitInfo {
+    fn capture(dir: &Path) -> Option<Self> {
+        let commit = command_line("git", &["rev-parse", "HEAD"], Some(dir))?;
+        let status = Command::new("git")
+            .args(["status", "--porcelain", "--untracked-files=no"])
+            .current_dir(dir)
+            .output()
+            .ok()?;
+        Some(Self { commit, dirty: !status.stdout.is_empty() })
+    }
+}
+
+/// First line of a successful command's stdout
+fn command_line(program: &str, args: &[&str], dir: Option<&Path>) -> Option<String> {
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if let Some(dir) = dir {
+        cmd.current_dir(dir);
+    }
+    let output = cmd.output().ok().filter(|o| o.status.success())?;
+    let line = String::from_utf8_lossy(&output.stdout).lines().next()?.trim().to_string();
+    (!line.is_empty()).then_some(line)
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn cpu_model() -> Option<String> {
+    if cfg!(target_os = "macos") {
+        return command_line("sysctl", &["-n", "machdep.cpu.brand_string"], None);
+    }
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| matches!(key.trim(), "model name" | "Model" | "Hardware"))
+        .map(|(_, value)| value.trim().to_string())
+}
+
+fn memory_bytes() -> Option<u64> {
+    if cfg!(target_os = "macos") {
+        return command_line("sysctl", &["-n", "hw.memsize"], None)?.parse().ok();
+    }
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kb: u64 = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemTotal:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_hashes_bench_files_and_roundtrips() {
+        let dir = tempfile::tempdir().unwrap();
+        let bench = dir.path().join("a.bench");
+        std::fs::write(&bench, "suite a {}\n").unwrap();
+
+        let env = RunEnvironment::capture(&[], Some(dir.path()), &[bench]);
+        assert_eq!(env.polybench_version, env!("CARGO_PKG_VERSION"));
+        assert!(env.cpu.cores >= 1);
+        assert_eq!(env.bench_files.len(), 1);
+        assert_eq!(env.bench_files[0].sha256, CompileCache::hash_source("suite a {}\n"));
+        // A fresh temp dir is not a git repository
+        assert_eq!(env.git, None);
+
+        let json = serde_json::to_string(&env).unwrap();
+        let parsed: RunEnvironment = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, env);
+        assert!(env.summary_rows().iter().any(|(label, _)| label == "CPU"));
+    }
+
+    #[test]
+    fn test_resolved_binary_prefers_variant_override() {
+        let variant = VariantToolchain {
+            name: "py-custom".to_string(),
+            binary: Some(PathBuf::from("/opt/python-custom/bin/python3")),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolved_binary(Lang::Python, Some(&variant)),
+            Some(PathBuf::from("/opt/python-custom/bin/python3"))
+        );
+    }
+}
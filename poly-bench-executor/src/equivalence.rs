@@ -0,0 +1,262 @@
+//! Cross-language output equivalence (`equivalent: true`)
+//!
+//! Runtimes report the value returned by the last timed call of the implementation as JSON text
+//! in `raw_result`. Each value is normalized to canonical JSON (byte sequences and `0x` hex
+//! strings become arrays of byte values, integral floats and decimal strings become integers)
+//! and compared against the first language (in `Lang::ALL` order) that reported a value.
+
+use poly_bench_dsl::Lang;
+use poly_bench_runtime::{measurement::Measurement, supported_languages};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Longest rendering of a value kept in results and diffs
+const MAX_VALUE_CHARS: usize = 120;
+
+/// Relative tolerance for comparing floating point values
+const FLOAT_TOLERANCE: f64 = 1e-9;
+
+/// Outcome of comparing the outputs of every language that ran a benchmark
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EquivalenceCheck {
+    /// Whether every compared language returned the reference value
+    pub passed: bool,
+    /// Language the other outputs are compared against
+    pub reference: Option<Lang>,
+    /// Canonical output per compared language (truncated JSON)
+    pub outputs: HashMap<Lang, String>,
+    /// Languages whose output differs from the reference
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mismatches: Vec<EquivalenceMismatch>,
+    /// Languages that ran but did not report a comparable value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unchecked: Vec<Lang>,
+}
+
+/// A language whose output disagrees with the reference language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EquivalenceMismatch {
+    pub lang: Lang,
+    /// Where the values first differ, e.g. `at [3]: 7 vs 8`
+    pub diff: String,
+}
+
+impl EquivalenceCheck {
+    /// Compare the captured outputs of each measurement
+    pub fn from_measurements(measurements: &HashMap<Lang, Measurement>) -> Self {
+        let mut values: Vec<(Lang, Value)> = Vec::new();
+        let mut unchecked = Vec::new();
+        // Built-in languages in declaration order first so the reference is stable across runs
+        let supported = supported_languages();
+        let langs = Lang::all()
+            .iter()
+            .chain(supported.iter().filter(|l| l.is_external() || l.is_variant()));
+        for lang in langs {
+            let Some(m) = measurements.get(lang) else { continue };
+            match captured_output(*lang, m) {
+                Some(raw) => values.push((*lang, canonicalize(raw))),
+                None => unchecked.push(*lang),
+            }
+        }
+
+        let reference = values.first().map(|(lang, _)| *lang);
+        let mut mismatches = Vec::new();
+        if let Some((ref_lang, ref_value)) = values.first() {
+            for (lang, value) in &values[1..] {
+                if let Some(diff) = first_difference(ref_value, value, "") {
+                    mismatches.push(EquivalenceMismatch {
+                        lang: *lang,
+                        diff: format!("{} vs {} {}", lang.as_str(), ref_lang.as_str(), diff),
+                    });
+                }
+            }
+        }
+
+        Self {
+            passed: mismatches.is_empty(),
+            reference,
+            outputs: values.iter().map(|(lang, v)| (*lang, render(v))).collect(),
+            mismatches,
+            unchecked,
+        }
+    }
+
+    /// Number of languages whose outputs were compared
+    pub fn compared(&self) -> usize {
+        self.outputs.len()
+    }
+}
+
+/// The value a measurement captured, if its runtime reports one
+fn captured_output(lang: Lang, m: &Measurement) -> Option<&str> {
+    // The C harness only records that the sink was written, not the value
+    if lang.base() == Lang::C {
+        return None;
+    }
+    m.raw_result
+        .as_deref()
+        .or_else(|| m.successful_results.as_ref().and_then(|r| r.last()).map(String::as_str))
+        .filter(|raw| !raw.trim().is_empty())
+}
+
+/// Normalize a raw result to canonical JSON
+pub fn canonicalize(raw: &str) -> Value {
+    let raw = raw.trim();
+    let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()));
+    normalize(value)
+}
+
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() && f.fract() == 0.0 && f.abs() < 9_007_199_254_740_992.0 => {
+                Value::from(f as i64)
+            }
+            _ => Value::Number(n),
+        },
+        Value::String(s) => {
+            if let Some(bytes) = parse_hex_bytes(&s) {
+                Value::Array(bytes.into_iter().map(Value::from).collect())
+            } else if let Ok(n) = s.parse::<i64>() {
+                Value::from(n)
+            } else if let Ok(n) = s.parse::<u64>() {
+                Value::from(n)
+            } else {
+                Value::String(s)
+            }
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        Value::Object(map) => {
+            Value::Object(map.into_iter().map(|(k, v)| (k, normalize(v))).collect())
+        }
+        other => other,
+    }
+}
+
+fn parse_hex_bytes(s: &str) -> Option<Vec<u8>> {
+    let hex = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))?;
+    if hex.len() % 2 != 0 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+/// Describe where `actual` first differs from `expected`, or `None` if they are equivalent
+fn first_difference(expected: &Value, actual: &Value, path: &str) -> Option<String> {
+    let at = if path.is_empty() { String::new() } else { format!("at {}: ", path) };
+    match (expected, actual) {
+        (Value::Number(a), Value::Number(b)) => {
+            let equal = match (a.as_i64(), b.as_i64(), a.as_u64(), b.as_u64()) {
+                (Some(x), Some(y), _, _) => x == y,
+                (_, _, Some(x), Some(y)) => x == y,
+                _ => floats_equal(a.as_f64()?, b.as_f64()?),
+            };
+            (!equal).then(|| format!("{}{} vs {}", at, render(actual), render(expected)))
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for (i, (x, y)) in a.iter().zip(b).enumerate() {
+                if let Some(diff) = first_difference(x, y, &format!("{}[{}]", path, i)) {
+                    return Some(diff);
+                }
+            }
+            (a.len() != b.len()).then(|| format!("{}length {} vs {}", at, b.len(), a.len()))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            let mut keys: Vec<&String> = a.keys().chain(b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = format!("{}.{}", path, key);
+                match (a.get(key), b.get(key)) {
+                    (Some(x), Some(y)) => {
+                        if let Some(diff) = first_difference(x, y, &child) {
+                            return Some(diff);
+                        }
+                    }
+                    (Some(_), None) => return Some(format!("missing {}", child)),
+                    (None, _) => return Some(format!("unexpected {}", child)),
+                }
+            }
+            None
+        }
+        _ => (expected != actual)
+            .then(|| format!("{}{} vs {}", at, render(actual), render(expected))),
+    }
+}
+
+fn floats_equal(a: f64, b: f64) -> bool {
+    a == b || (a - b).abs() <= FLOAT_TOLERANCE * a.abs().max(b.abs())
+}
+
+fn render(value: &Value) -> String {
+    let text = value.to_string();
+    if text.chars().count() <= MAX_VALUE_CHARS {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(MAX_VALUE_CHARS).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(raw: &str) -> Measurement {
+        let mut m = Measurement::from_samples(vec![100, 100], 2);
+        m.raw_result = Some(raw.to_string());
+        m
+    }
+
+    #[test]
+    fn test_canonicalize_bytes_hex_and_numbers() {
+        // Go/Python/TS byte arrays, Rust JSON arrays and 0x-hex strings all agree
+        assert_eq!(canonicalize("[222, 173]"), canonicalize("\"0xdead\""));
+        assert_eq!(canonicalize("42.0"), canonicalize("42"));
+        assert_eq!(canonicalize("\"12345678901234\""), canonicalize("12345678901234"));
+        assert_eq!(canonicalize("Some(3)"), Value::String("Some(3)".to_string()));
+        assert_eq!(canonicalize("\"0xabc\""), Value::String("0xabc".to_string()));
+    }
+
+    #[test]
+    fn test_equivalent_outputs_pass() {
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, measurement("[1,2,3]"));
+        measurements.insert(Lang::TypeScript, measurement("[1,2,3]"));
+        measurements.insert(Lang::Rust, measurement("[1, 2, 3]"));
+        measurements.insert(Lang::Python, measurement("0.30000000000000004"));
+        measurements.get_mut(&Lang::Python).unwrap().raw_result = None;
+
+        let check = EquivalenceCheck::from_measurements(&measurements);
+        assert!(check.passed);
+        assert_eq!(check.reference, Some(Lang::Go));
+        assert_eq!(check.compared(), 3);
+        assert_eq!(check.unchecked, vec![Lang::Python]);
+    }
+
+    #[test]
+    fn test_mismatch_reports_first_difference() {
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, measurement("[1,2,3]"));
+        measurements.insert(Lang::Rust, measurement("[1, 2, 4]"));
+        measurements.insert(Lang::TypeScript, measurement("[1,2]"));
+
+        let check = EquivalenceCheck::from_measurements(&measurements);
+        assert!(!check.passed);
+        let diffs: Vec<&str> = check.mismatches.iter().map(|m| m.diff.as_str()).collect();
+        assert!(diffs.contains(&"rust vs go at [2]: 4 vs 3"), "{:?}", diffs);
+        assert!(diffs.contains(&"ts vs go length 2 vs 3"), "{:?}", diffs);
+    }
+
+    #[test]
+    fn test_float_tolerance_and_objects() {
+        let a = canonicalize(r#"{"sum": 0.1, "n": 2}"#);
+        let b = canonicalize(r#"{"n": 2.0, "sum": 0.10000000000000001}"#);
+        assert_eq!(first_difference(&a, &b, ""), None);
+
+        let c = canonicalize(r#"{"n": 3, "sum": 0.1}"#);
+        assert_eq!(first_difference(&a, &c, "").as_deref(), Some("at .n: 3 vs 2"));
+    }
+}
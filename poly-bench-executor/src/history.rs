@@ -0,0 +1,294 @@
+//! Local result history store
+//!
+//! Every run inside a project is recorded under `.polybench/history/` as one JSON file per
+//! run, keyed by timestamp and git commit, so that two result sets can later be compared
+//! with `poly-bench compare`.
+
+use crate::{comparison::BenchmarkResults, results_file, workspace::POLYBENCH_DIR};
+use miette::{miette, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Directory name within .polybench/ holding recorded runs
+pub const HISTORY_DIR: &str = "history";
+
+/// A single recorded benchmark run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Entry identifier (`<unix-timestamp>-<short-commit>`)
+    pub id: String,
+    /// Unix timestamp (seconds) when the run was recorded
+    pub timestamp: u64,
+    /// Full git commit hash of the project at record time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// Git branch of the project at record time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Whether the working tree had uncommitted changes
+    #[serde(default)]
+    pub dirty: bool,
+    /// The recorded results
+    pub results: BenchmarkResults,
+}
+
+impl HistoryEntry {
+    /// Short commit hash for display
+    pub fn short_commit(&self) -> Option<&str> {
+        self.commit.as_deref().map(|c| &c[..c.len().min(8)])
+    }
+
+    /// Human-readable label, e.g. `a1b2c3d4 (main, dirty)`
+    pub fn label(&self) -> String {
+        let mut label = self.short_commit().unwrap_or(&self.id).to_string();
+        let mut extras = Vec::new();
+        if let Some(ref branch) = self.branch {
+            extras.push(branch.as_str());
+        }
+        if self.dirty {
+            extras.push("dirty");
+        }
+        if !extras.is_empty() {
+            label.push_str(&format!(" ({})", extras.join(", ")));
+        }
+        label
+    }
+}
+
+/// Manages recorded runs in `.polybench/history/`
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    /// Project root (used for git lookups)
+    project_root: PathBuf,
+    /// Root of the history directory
+    root: PathBuf,
+}
+
+impl HistoryStore {
+    /// Open the history store for a project, creating the directory if needed
+    pub fn new(project_root: &Path) -> Result<Self> {
+        let root = project_root.join(POLYBENCH_DIR).join(HISTORY_DIR);
+        fs::create_dir_all(&root)
+            .map_err(|e| miette!("Failed to create directory {:?}: {}", root, e))?;
+        Ok(Self { project_root: project_root.to_path_buf(), root })
+    }
+
+    /// Get the history directory path
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Record a run, tagging it with the current git commit and branch
+    pub fn record(&self, results: &BenchmarkResults) -> Result<HistoryEntry> {
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        let commit = git_output(&self.project_root, &["rev-parse", "HEAD"]);
+        let branch = git_output(&self.project_root, &["rev-parse", "--abbrev-ref", "HEAD"])
+            .filter(|b| b != "HEAD");
+        let dirty = git_output(&self.project_root, &["status", "--porcelain"])
+            .is_some_and(|s| !s.is_empty());
+
+        let short = commit.as_deref().map(|c| &c[..c.len().min(8)]).unwrap_or("nogit");
+        let mut id = format!("{}-{}", timestamp, short);
+        // Keep ids unique when several runs land in the same second
+        let mut suffix = 1;
+        while self.entry_path(&id).exists() {
+            suffix += 1;
+            id = format!("{}-{}-{}", timestamp, short, suffix);
+        }
+
+        let entry = HistoryEntry { id, timestamp, commit, branch, dirty, results: results.clone() };
+        let json = serde_json::to_string_pretty(&entry)
+            .map_err(|e| miette!("Failed to serialize history entry: {}", e))?;
+        fs::write(self.entry_path(&entry.id), json)
+            .map_err(|e| miette!("Failed to write history entry: {}", e))?;
+        Ok(entry)
+    }
+
+    /// List recorded entries, newest first. Entries that cannot be read are skipped with a
+    /// warning so one corrupt file doesn't hide the rest of the history.
+    pub fn list(&self) -> Result<Vec<HistoryEntry>> {
+        let mut entries = Vec::new();
+        for dir_entry in fs::read_dir(&self.root)
+            .map_err(|e| miette!("Failed to read {}: {}", self.root.display(), e))?
+            .flatten()
+        {
+            let path = dir_entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                match load_entry(&path) {
+                    Ok(entry) => entries.push(entry),
+                    Err(e) => eprintln!("  warning: skipping history entry: {}", e),
+                }
+            }
+        }
+        entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp).then_with(|| b.id.cmp(&a.id)));
+        Ok(entries)
+    }
+
+    /// Delete all but the `keep` newest entries, returning how many were removed
+    pub fn prune(&self, keep: usize) -> Result<usize> {
+        let mut removed = 0;
+        for entry in self.list()?.iter().skip(keep) {
+            fs::remove_file(self.entry_path(&entry.id))
+                .map_err(|e| miette!("Failed to remove history entry {}: {}", entry.id, e))?;
+            removed += 1;
+        }
+        Ok(removed)
+    }
+
+    /// Resolve a user-supplied reference to a result set.
+    ///
+    /// Accepts, in order: a path to a results/history JSON file, `latest`, `previous`,
+    /// an entry id, a commit hash prefix, or any git revision (branch, tag, `HEAD~1`)
+    /// whose commit has a recorded run. The newest matching entry wins.
+    pub fn resolve(&self, reference: &str) -> Result<(String, BenchmarkResults)> {
+        let path = Path::new(reference);
+        if path.is_file() {
+            return results_file::load_file(path).map(|r| (reference.to_string(), r));
+        }
+
+        let entries = self.list()?;
+        let found = match reference {
+            "latest" => entries.first(),
+            "previous" => entries.get(1),
+            _ => entries
+                .iter()
+                .find(|e| e.id == reference)
+                .or_else(|| {
+                    if reference.len() < 4 {
+                        return None;
+                    }
+                    entries
+                        .iter()
+                        .find(|e| e.commit.as_deref().is_some_and(|c| c.starts_with(reference)))
+                })
+                .or_else(|| {
+                    let commit = git_output(&self.project_root, &["rev-parse", reference])?;
+                    entries.iter().find(|e| e.commit.as_deref() == Some(commit.as_str()))
+                }),
+        };
+
+        found.map(|e| (e.label(), e.results.clone())).ok_or_else(|| {
+            miette!(
+                "No recorded run matches '{}'. Run benchmarks on that revision first, or pass a \
+                 results JSON path.",
+                reference
+            )
+        })
+    }
+
+    fn entry_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{}.json", id))
+    }
+}
+
+/// Load a history entry; its results go through [`results_file::load`] so older entries are
+/// upgraded like any other results file
+fn load_entry(path: &Path) -> Result<HistoryEntry> {
+    let json = fs::read_to_string(path)
+        .map_err(|e| miette!("Failed to read {}: {}", path.display(), e))?;
+    let results = results_file::load(&json).map_err(|e| miette!("{}: {}", path.display(), e))?;
+    let mut value: serde_json::Value = serde_json::from_str(&json)
+        .map_err(|e| miette!("Failed to parse {}: {}", path.display(), e))?;
+    value["results"] = serde_json::to_value(&results)
+        .map_err(|e| miette!("Failed to serialize results: {}", e))?;
+    serde_json::from_value(value).map_err(|e| miette!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Run a git command in `dir`, returning trimmed stdout on success
+fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comparison::SuiteResults;
+    use poly_bench_dsl::SuiteType;
+    use tempfile::TempDir;
+
+    fn results(suite: &str) -> BenchmarkResults {
+        BenchmarkResults::new(vec![SuiteResults::new(
+            suite.to_string(),
+            None,
+            SuiteType::Performance,
+            vec![],
+        )])
+    }
+
+    #[test]
+    fn test_record_and_resolve_latest() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::new(temp.path()).unwrap();
+        assert!(store.root().ends_with(".polybench/history"));
+
+        let first = store.record(&results("first")).unwrap();
+        let second = store.record(&results("second")).unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!(store.list().unwrap().len(), 2);
+
+        let (_, latest) = store.resolve("latest").unwrap();
+        assert_eq!(latest.suites[0].name, "second");
+        let (_, previous) = store.resolve("previous").unwrap();
+        assert_eq!(previous.suites[0].name, "first");
+        let (_, by_id) = store.resolve(&first.id).unwrap();
+        assert_eq!(by_id.suites[0].name, "first");
+        assert!(store.resolve("does-not-exist").is_err());
+    }
+
+    #[test]
+    fn test_resolve_plain_results_file() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::new(temp.path()).unwrap();
+        let path = temp.path().join("results.json");
+        fs::write(&path, serde_json::to_string(&results("file")).unwrap()).unwrap();
+
+        let (label, loaded) = store.resolve(path.to_str().unwrap()).unwrap();
+        assert_eq!(label, path.to_str().unwrap());
+        assert_eq!(loaded.suites[0].name, "file");
+    }
+
+    #[test]
+    fn test_list_skips_unreadable_entries_and_upgrades_old_ones() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::new(temp.path()).unwrap();
+        let recorded = store.record(&results("recorded")).unwrap();
+        fs::write(store.root().join("corrupt.json"), "{ not json").unwrap();
+
+        // An entry written before results carried a schemaVersion
+        let mut old = serde_json::to_value(&recorded).unwrap();
+        old["id"] = "1-old".into();
+        old["timestamp"] = 1.into();
+        old["results"].as_object_mut().unwrap().remove("schemaVersion");
+        fs::write(store.root().join("1-old.json"), old.to_string()).unwrap();
+
+        let entries = store.list().unwrap();
+        let ids: Vec<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, [recorded.id.as_str(), "1-old"]);
+        assert_eq!(entries[1].results.schema_version, crate::RESULTS_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_entries() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::new(temp.path()).unwrap();
+        for name in ["a", "b", "c"] {
+            store.record(&results(name)).unwrap();
+        }
+
+        assert_eq!(store.prune(2).unwrap(), 1);
+        let names: Vec<String> =
+            store.list().unwrap().iter().map(|e| e.results.suites[0].name.clone()).collect();
+        assert_eq!(names, ["c", "b"]);
+        assert_eq!(store.prune(2).unwrap(), 0);
+    }
+}
@@ -0,0 +1,394 @@
+//! System noise control for benchmark runs
+//!
+//! Benchmark processes can be pinned to a CPU set and re-niced. Runtimes apply both in each
+//! benchmark child (see [`poly_bench_runtime::BenchmarkIsolation`]), so poly-bench and the
+//! toolchains it compiles with are left alone. A handful of stability checks (frequency
+//! governor, turbo boost, ASLR, load) then decide whether the machine is quiet enough. Noisy
+//! findings are warnings; in strict mode the governor and load checks are errors, and the ASLR
+//! and turbo checks only when listed in [`IsolationSettings::strict_checks`]. What was applied
+//! is recorded in [`IsolationReport`] alongside the results.
+
+use miette::{miette, Result};
+use poly_bench_runtime::{set_benchmark_isolation, BenchmarkIsolation};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Nice value tried when isolation is enabled without an explicit `nice`
+const DEFAULT_NICE: i32 = -10;
+
+/// Stability checks that only fail a strict run when asked for by name: both are machine-wide
+/// settings many hosts cannot change, and their effect on timings is usually small
+pub const OPT_IN_STRICT_CHECKS: &[&str] = &["aslr", "turbo"];
+
+/// What to apply before running benchmarks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IsolationSettings {
+    /// CPUs to pin to; empty picks the highest-numbered allowed CPU
+    pub cpus: Vec<usize>,
+    /// Nice value (default: [`DEFAULT_NICE`], skipped when not permitted)
+    pub nice: Option<i32>,
+    /// Refuse to run when a stability check fails
+    pub strict: bool,
+    /// Opt-in checks ([`OPT_IN_STRICT_CHECKS`]) that also fail a strict run
+    pub strict_checks: Vec<String>,
+}
+
+/// What isolation applied and what the stability checks found
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct IsolationReport {
+    /// CPUs the run was pinned to (empty when pinning was not applied)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_cpus: Vec<usize>,
+    /// Nice value in effect (absent when it could not be changed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+    /// Frequency governor of each pinned CPU, in CPU order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub governors: Vec<String>,
+    /// Whether turbo boost was enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turbo: Option<bool>,
+    /// Whether address space layout randomization was enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aslr: Option<bool>,
+    /// One-minute load average before the run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_average: Option<f64>,
+    /// Whether the run was in strict mode
+    #[serde(default)]
+    pub strict: bool,
+    /// Problems found by the stability checks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl IsolationSettings {
+    /// Settings from a CPU list such as `"2-3,6"`, a nice value, the strict flag and the opt-in
+    /// checks strict mode should also enforce
+    pub fn new(
+        cpus: Option<&str>,
+        nice: Option<i32>,
+        strict: bool,
+        strict_checks: Vec<String>,
+    ) -> Result<Self> {
+        let cpus = cpus.map(parse_cpu_list).transpose()?.unwrap_or_default();
+        if let Some(check) =
+            strict_checks.iter().find(|c| !OPT_IN_STRICT_CHECKS.contains(&c.as_str()))
+        {
+            return Err(miette!(
+                "Unknown strict check '{}' (expected one of: {})",
+                check,
+                OPT_IN_STRICT_CHECKS.join(", ")
+            ));
+        }
+        Ok(Self { cpus, nice, strict: strict || !strict_checks.is_empty(), strict_checks })
+    }
+}
+
+impl IsolationReport {
+    /// One-line description, e.g. `CPUs 2,3, nice -10, 1 warning`
+    pub fn summary(&self) -> String {
+        let mut parts = vec![match self.pinned_cpus.len() {
+            0 => "unpinned".to_string(),
+            1 => format!("CPU {}", self.pinned_cpus[0]),
+            _ => format!("CPUs {}", format_cpu_list(&self.pinned_cpus)),
+        }];
+        if let Some(nice) = self.nice {
+            parts.push(format!("nice {}", nice));
+        }
+        if self.strict {
+            parts.push("strict".to_string());
+        }
+        match self.warnings.len() {
+            0 => {}
+            1 => parts.push("1 warning".to_string()),
+            n => parts.push(format!("{} warnings", n)),
+        }
+        parts.join(", ")
+    }
+}
+
+/// Register the CPU set and nice value for benchmark processes, run the stability checks and
+/// report what was applied.
+///
+/// Fails when a requested CPU is unavailable, or in strict mode when an enforced check warns.
+pub fn apply(settings: &IsolationSettings) -> Result<IsolationReport> {
+    let mut report = IsolationReport { strict: settings.strict, ..Default::default() };
+
+    let allowed = allowed_cpus();
+    let cpus = if settings.cpus.is_empty() {
+        allowed.last().copied().into_iter().collect()
+    } else {
+        if let Some(cpu) = settings.cpus.iter().find(|cpu| !allowed.contains(cpu)) {
+            return Err(miette!(
+                "CPU {} is not available to poly-bench (allowed: {})",
+                cpu,
+                format_cpu_list(&allowed)
+            ));
+        }
+        settings.cpus.clone()
+    };
+    match check_pinning(&cpus) {
+        Ok(()) => report.pinned_cpus = cpus,
+        Err(e) => report.warnings.push(format!("could not pin to CPUs: {}", e)),
+    }
+
+    let nice = settings.nice.unwrap_or(DEFAULT_NICE);
+    match check_nice(nice) {
+        Ok(()) => report.nice = Some(nice),
+        // Raising priority is best effort unless it was asked for explicitly
+        Err(e) if settings.nice.is_some() => {
+            report.warnings.push(format!("could not set nice value {}: {}", nice, e))
+        }
+        Err(_) => {}
+    }
+
+    let mut failures = report.warnings.clone();
+    failures.extend(check_stability(&mut report, &settings.strict_checks));
+
+    if settings.strict && !failures.is_empty() {
+        return Err(miette!(
+            "System is too noisy to benchmark (--strict-env):\n  - {}",
+            failures.join("\n  - ")
+        ));
+    }
+    set_benchmark_isolation(Some(BenchmarkIsolation {
+        cpus: report.pinned_cpus.clone(),
+        nice: report.nice,
+    }));
+    Ok(report)
+}
+
+/// Fill in governor, turbo, ASLR and load state and warn about each noisy finding. Returns the
+/// findings that fail a strict run: all but the opt-in checks missing from `strict_checks`.
+fn check_stability(report: &mut IsolationReport, strict_checks: &[String]) -> Vec<String> {
+    let enforced = |check: &str| strict_checks.iter().any(|c| c == check);
+    let mut failures = Vec::new();
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+    let cpus =
+        if report.pinned_cpus.is_empty() { allowed_cpus() } else { report.pinned_cpus.clone() };
+    for cpu in cpus {
+        let path = format!("/sys/devices/system/cpu/cpu{}/cpufreq/scaling_governor", cpu);
+        if let Some(governor) = read_trimmed(&path) {
+            if governor != "performance" {
+                failures.push(format!("CPU {} uses the `{}` frequency governor", cpu, governor));
+            }
+            report.governors.push(governor);
+        }
+    }
+
+    report.load_average = read_trimmed("/proc/loadavg")
+        .and_then(|text| text.split_whitespace().next().and_then(|v| v.parse().ok()));
+    if let Some(load) = report.load_average.filter(|&load| is_busy(load, cores)) {
+        failures.push(format!("load average is {:.2} on {} CPUs", load, cores));
+    }
+    report.warnings.extend(failures.iter().cloned());
+
+    report.turbo = turbo_enabled();
+    if report.turbo == Some(true) {
+        let warning = "turbo boost is enabled".to_string();
+        if enforced("turbo") {
+            failures.push(warning.clone());
+        }
+        report.warnings.push(warning);
+    }
+
+    report.aslr = read_trimmed("/proc/sys/kernel/randomize_va_space").map(|v| v != "0");
+    if report.aslr == Some(true) {
+        let warning = "ASLR is enabled (kernel.randomize_va_space)".to_string();
+        if enforced("aslr") {
+            failures.push(warning.clone());
+        }
+        report.warnings.push(warning);
+    }
+    failures
+}
+
+/// A load above a quarter of the CPUs (and at least one) means other work competes for time
+fn is_busy(load: f64, cores: usize) -> bool {
+    load > (cores as f64 / 4.0).max(1.0)
+}
+
+fn turbo_enabled() -> Option<bool> {
+    if let Some(no_turbo) = read_trimmed("/sys/devices/system/cpu/intel_pstate/no_turbo") {
+        return Some(no_turbo == "0");
+    }
+    read_trimmed("/sys/devices/system/cpu/cpufreq/boost").map(|boost| boost == "1")
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    let text = std::fs::read_to_string(path).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Parse a Linux CPU list (`"0-3,8"`)
+pub fn parse_cpu_list(list: &str) -> Result<Vec<usize>> {
+    let invalid = || miette!("Invalid CPU list '{}': expected e.g. 2-3,6", list);
+    let mut cpus = Vec::new();
+    for part in list.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let start: usize = start.trim().parse().map_err(|_| invalid())?;
+                let end: usize = end.trim().parse().map_err(|_| invalid())?;
+                if start > end {
+                    return Err(invalid());
+                }
+                cpus.extend(start..=end);
+            }
+            None => cpus.push(part.parse().map_err(|_| invalid())?),
+        }
+    }
+    if cpus.is_empty() {
+        return Err(invalid());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+fn format_cpu_list(cpus: &[usize]) -> String {
+    if cpus.is_empty() {
+        return "unknown".to_string();
+    }
+    cpus.iter().map(ToString::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// CPUs in the current thread's affinity mask
+#[cfg(target_os = "linux")]
+fn allowed_cpus() -> Vec<usize> {
+    // SAFETY: cpu_set_t is plain data; sched_getaffinity writes at most size_of::<cpu_set_t>()
+    // bytes into it.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+            return Vec::new();
+        }
+        (0..libc::CPU_SETSIZE as usize).filter(|&cpu| libc::CPU_ISSET(cpu, &set)).collect()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn allowed_cpus() -> Vec<usize> {
+    Vec::new()
+}
+
+/// Restrict the calling thread (and processes it spawns) to a single CPU
+#[cfg(target_os = "linux")]
+pub(crate) fn pin_current_thread(cpu: usize) -> std::io::Result<()> {
+    // SAFETY: cpu_set_t is plain data and is fully initialized before being passed by pointer.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(cpu, &mut set);
+        if libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// CPUs parallel workers spread over: the isolated CPUs when isolation pins benchmarks,
+/// otherwise every allowed CPU
+pub(crate) fn worker_pool_cpus() -> Vec<usize> {
+    match poly_bench_runtime::benchmark_isolation() {
+        Some(isolation) if !isolation.cpus.is_empty() => isolation.cpus,
+        _ => allowed_cpus(),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn pin_current_thread(_cpu: usize) -> std::io::Result<()> {
+    Err(unsupported("CPU pinning"))
+}
+
+/// Whether benchmark processes can be pinned to `cpus`, which `apply` already checked are allowed
+#[cfg(target_os = "linux")]
+fn check_pinning(cpus: &[usize]) -> std::io::Result<()> {
+    if cpus.is_empty() {
+        return Err(std::io::Error::other("no CPUs available"));
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_pinning(_cpus: &[usize]) -> std::io::Result<()> {
+    Err(unsupported("CPU pinning"))
+}
+
+/// Whether benchmark processes may run at `nice`. Raising the nice value is always allowed;
+/// lowering it needs root or enough `RLIMIT_NICE` headroom.
+#[cfg(target_os = "linux")]
+fn check_nice(nice: i32) -> std::io::Result<()> {
+    // SAFETY: getpriority, geteuid and getrlimit take plain integers or a pointer to a local.
+    let permitted = unsafe {
+        let mut limit: libc::rlimit = std::mem::zeroed();
+        nice >= libc::getpriority(libc::PRIO_PROCESS, 0) ||
+            libc::geteuid() == 0 ||
+            (libc::getrlimit(libc::RLIMIT_NICE, &mut limit) == 0 &&
+                (limit.rlim_cur == libc::RLIM_INFINITY ||
+                    20 - nice as i64 <= limit.rlim_cur as i64))
+    };
+    if permitted {
+        Ok(())
+    } else {
+        Err(std::io::Error::from_raw_os_error(libc::EPERM))
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn check_nice(_nice: i32) -> std::io::Result<()> {
+    Err(unsupported("Changing priority"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn unsupported(what: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("{} is only supported on Linux", what),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("2-4,0, 3").unwrap(), vec![0, 2, 3, 4]);
+        assert_eq!(parse_cpu_list("7").unwrap(), vec![7]);
+        assert!(parse_cpu_list("3-1").is_err());
+        assert!(parse_cpu_list("a").is_err());
+        assert!(parse_cpu_list("").is_err());
+    }
+
+    #[test]
+    fn test_is_busy_scales_with_cores() {
+        assert!(!is_busy(0.9, 2));
+        assert!(is_busy(1.5, 2));
+        assert!(!is_busy(3.0, 16));
+        assert!(is_busy(4.5, 16));
+    }
+
+    #[test]
+    fn test_strict_checks_are_opt_in() {
+        let settings = IsolationSettings::new(None, None, false, vec!["aslr".to_string()]).unwrap();
+        assert!(settings.strict, "naming a strict check implies strict mode");
+        assert!(IsolationSettings::new(None, None, true, vec!["smt".to_string()]).is_err());
+
+        let mut report = IsolationReport::default();
+        let failures = check_stability(&mut report, &[]);
+        assert!(failures.iter().all(|f| !f.starts_with("ASLR") && !f.starts_with("turbo")));
+        if report.aslr == Some(true) {
+            assert!(report.warnings.iter().any(|w| w.starts_with("ASLR")));
+            let failures =
+                check_stability(&mut IsolationReport::default(), &settings.strict_checks);
+            assert!(failures.iter().any(|f| f.starts_with("ASLR")));
+        }
+    }
+
+    #[test]
+    fn test_apply_rejects_unavailable_cpu() {
+        let settings = IsolationSettings { cpus: vec![usize::MAX - 1], ..Default::default() };
+        assert!(apply(&settings).is_err());
+    }
+}
@@ -1,17 +1,33 @@
 //! Benchmark execution orchestration
 
+pub mod adaptive;
 mod anvil;
 pub mod comparison;
 pub mod compile_cache;
+pub mod environment;
+pub mod equivalence;
+pub mod history;
+pub mod isolation;
+mod parallel;
+pub mod regression;
+pub mod results_file;
 mod scheduler;
 mod validation;
+pub mod watch;
 pub mod workspace;
 
 use poly_bench_dsl::Lang;
 use std::{collections::HashMap, path::PathBuf};
 
+pub use adaptive::{AdaptiveOutcome, AdaptivePlan, AdaptiveStop};
 pub use anvil::{AnvilConfig, AnvilService};
 pub use compile_cache::{CacheStats, CompileCache};
+pub use environment::RunEnvironment;
+pub use equivalence::{EquivalenceCheck, EquivalenceMismatch};
+pub use history::{HistoryEntry, HistoryStore};
+pub use isolation::{IsolationReport, IsolationSettings};
+pub use parallel::PARALLEL_COMPARISON_MODE;
+pub use regression::{compare_results, RegressionReport, RegressionStatistic, RegressionVerdict};
 pub use workspace::{format_size, CompileWorkspace};
 
 /// Project roots for different languages
@@ -33,8 +49,10 @@ impl ProjectRoots {
     }
 }
 
-pub use comparison::{BenchmarkResult, BenchmarkResults, SuiteResults};
-pub use scheduler::{run, RunOptions};
+pub use comparison::{
+    BenchmarkResult, BenchmarkResults, GeneratedFixture, SuiteResults, RESULTS_SCHEMA_VERSION,
+};
+pub use scheduler::{run, BenchmarkProgress, ProgressHook, RunOptions};
 pub use validation::{
-    validate_benchmarks, validate_benchmarks_with_cache, CompileError, ValidationStats,
+    validate_benchmarks, validate_benchmarks_with_cache, CompileError, ErrorSource, ValidationStats,
 };
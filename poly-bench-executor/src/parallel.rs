@@ -0,0 +1,302 @@
+//! Parallel execution for `order: parallel` suites
+//!
+//! Language runtimes are distributed round-robin across a pool of worker threads. Each worker
+//! owns its runtimes, drives them on its own single-threaded tokio runtime and is optionally
+//! pinned to one CPU; benchmark processes spawned by the worker inherit that affinity.
+//!
+//! Runtimes of the same language share generated sources in the module root, so one runtime
+//! never runs two benchmarks at once: concurrency is across languages, and each worker walks the
+//! suite's benchmarks in order. Within a worker the scheduler's run blocks are reused, so strict
+//! fairness still interleaves runs across the worker's own languages.
+//!
+//! Results measured this way are tagged with [`PARALLEL_COMPARISON_MODE`] since every sample was
+//! taken under contention from the other workers; they are not comparable to sequential runs.
+
+use crate::{
+    isolation::{pin_current_thread, worker_pool_cpus},
+    scheduler::{run_block, strict_run_lang_order, RunOptions},
+};
+use miette::{miette, Result};
+use poly_bench_dsl::{FairnessMode, Lang, SuiteType};
+use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_runtime::{lang_label, measurement::Measurement, traits::Runtime};
+use std::{collections::HashMap, time::Instant};
+
+/// `BenchmarkResult::comparison_mode` for results measured under parallel contention
+pub const PARALLEL_COMPARISON_MODE: &str = "parallel";
+
+/// Measurement and precompile time for one benchmark in one language
+pub(crate) struct ParallelOutcome {
+    pub measurement: Measurement,
+    pub precompile_nanos: u64,
+    /// Number of runs aggregated into `measurement`
+    pub runs_taken: usize,
+}
+
+/// Everything produced by running one suite on the worker pool
+pub(crate) struct ParallelSuiteRun {
+    /// Runtimes handed back by the workers, for shutdown
+    pub runtimes: HashMap<Lang, Box<dyn Runtime>>,
+    /// Outcomes keyed by benchmark full name, then language
+    pub outcomes: HashMap<String, HashMap<Lang, ParallelOutcome>>,
+    /// CPU each worker ran on, in worker order (`None` when unpinned)
+    pub worker_cpus: Vec<Option<usize>>,
+    /// First fatal error reported by any worker
+    pub error: Option<miette::Report>,
+}
+
+/// What a worker thread sends back when it finishes
+struct WorkerOutput {
+    runtimes: HashMap<Lang, Box<dyn Runtime>>,
+    outcomes: Vec<(String, Lang, ParallelOutcome)>,
+    cpu: Option<usize>,
+    error: Option<miette::Report>,
+}
+
+/// Run every benchmark of a suite with language runtimes spread across worker threads.
+///
+/// `benchmarks` must already carry any CLI overrides. Each worker follows the benchmark's fairness
+/// mode over its own languages: strict interleaves run blocks, legacy runs each language's
+/// `count` runs back to back.
+pub(crate) async fn run_suite(
+    mut runtimes: HashMap<Lang, Box<dyn Runtime>>,
+    suite: &SuiteIR,
+    benchmarks: &[BenchmarkSpec],
+    langs: &[Lang],
+    options: &RunOptions,
+) -> Result<ParallelSuiteRun> {
+    let participating: Vec<Lang> = langs
+        .iter()
+        .copied()
+        .filter(|l| runtimes.contains_key(l) && benchmarks.iter().any(|b| b.has_lang(*l)))
+        .collect();
+    let default_workers =
+        std::thread::available_parallelism().map(|n| n.get()).unwrap_or(participating.len());
+    let plan = plan_workers(&participating, options.workers.unwrap_or(default_workers));
+    let cpus = if options.pin_cpus {
+        worker_cpus(&worker_pool_cpus(), plan.len())
+    } else {
+        vec![None; plan.len()]
+    };
+
+    let mut receivers = Vec::new();
+    for (idx, (worker_langs, cpu)) in plan.into_iter().zip(cpus).enumerate() {
+        let worker_runtimes: HashMap<Lang, Box<dyn Runtime>> = worker_langs
+            .iter()
+            .filter_map(|lang| runtimes.remove(lang).map(|rt| (*lang, rt)))
+            .collect();
+        let suite = suite.clone();
+        let benchmarks = benchmarks.to_vec();
+        let verbose = options.verbose;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        std::thread::Builder::new()
+            .name(format!("polybench-worker-{}", idx))
+            .spawn(move || {
+                let output =
+                    run_worker(worker_runtimes, &worker_langs, &suite, &benchmarks, cpu, verbose);
+                let _ = tx.send(output);
+            })
+            .map_err(|e| miette!("Failed to spawn parallel worker {}: {}", idx, e))?;
+        receivers.push(rx);
+    }
+
+    let mut run = ParallelSuiteRun {
+        runtimes,
+        outcomes: HashMap::new(),
+        worker_cpus: Vec::new(),
+        error: None,
+    };
+    for (idx, output) in futures::future::join_all(receivers).await.into_iter().enumerate() {
+        let Ok(output) = output else {
+            run.error.get_or_insert_with(|| miette!("Parallel worker {} panicked", idx));
+            run.worker_cpus.push(None);
+            continue;
+        };
+        run.runtimes.extend(output.runtimes);
+        for (full_name, lang, outcome) in output.outcomes {
+            run.outcomes.entry(full_name).or_default().insert(lang, outcome);
+        }
+        run.worker_cpus.push(output.cpu);
+        if let Some(err) = output.error {
+            run.error.get_or_insert(err);
+        }
+    }
+    Ok(run)
+}
+
+/// Body of a worker thread: pin, then run every benchmark for the worker's languages
+fn run_worker(
+    mut runtimes: HashMap<Lang, Box<dyn Runtime>>,
+    langs: &[Lang],
+    suite: &SuiteIR,
+    benchmarks: &[BenchmarkSpec],
+    cpu: Option<usize>,
+    verbose: bool,
+) -> WorkerOutput {
+    let cpu = cpu.filter(|&cpu| match pin_current_thread(cpu) {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("  warning: could not pin parallel worker to CPU {}: {}", cpu, e);
+            false
+        }
+    });
+
+    let mut outcomes = Vec::new();
+    let error = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(rt) => rt
+            .block_on(run_worker_benchmarks(
+                &mut runtimes,
+                langs,
+                suite,
+                benchmarks,
+                verbose,
+                &mut outcomes,
+            ))
+            .err(),
+        Err(e) => Some(miette!("Failed to start parallel worker runtime: {}", e)),
+    };
+
+    WorkerOutput { runtimes, outcomes, cpu, error }
+}
+
+async fn run_worker_benchmarks(
+    runtimes: &mut HashMap<Lang, Box<dyn Runtime>>,
+    langs: &[Lang],
+    suite: &SuiteIR,
+    benchmarks: &[BenchmarkSpec],
+    verbose: bool,
+    outcomes: &mut Vec<(String, Lang, ParallelOutcome)>,
+) -> Result<()> {
+    let is_memory_suite = suite.suite_type == SuiteType::Memory;
+
+    for spec in benchmarks {
+        let spec_langs: Vec<Lang> = langs.iter().copied().filter(|l| spec.has_lang(*l)).collect();
+        let mut precompile_nanos = HashMap::new();
+        let mut run_measurements: HashMap<Lang, Vec<Measurement>> = HashMap::new();
+
+        if spec.fairness_mode == FairnessMode::Strict {
+            // Precompile everything first so no run block includes compile overhead
+            for &lang in &spec_langs {
+                precompile_nanos.insert(lang, precompile(runtimes, lang, spec, suite).await?);
+            }
+            for run_idx in 0..spec.count.max(1) {
+                let run_langs = strict_run_lang_order(spec, suite, run_idx, spec_langs.clone());
+                run_block(
+                    runtimes,
+                    &run_langs,
+                    spec,
+                    suite,
+                    run_idx,
+                    verbose,
+                    &mut run_measurements,
+                )
+                .await?;
+            }
+        } else {
+            for &lang in &spec_langs {
+                precompile_nanos.insert(lang, precompile(runtimes, lang, spec, suite).await?);
+                for run_idx in 0..spec.count.max(1) {
+                    run_block(
+                        runtimes,
+                        &[lang],
+                        spec,
+                        suite,
+                        run_idx,
+                        verbose,
+                        &mut run_measurements,
+                    )
+                    .await?;
+                }
+            }
+        }
+
+        for (lang, mut runs) in run_measurements {
+            let runs_taken = runs.len();
+            let measurement = if runs.len() == 1 {
+                runs.remove(0)
+            } else if is_memory_suite {
+                Measurement::aggregate_runs_memory(runs)
+            } else {
+                Measurement::aggregate_runs(runs)
+            };
+            outcomes.push((
+                spec.full_name.clone(),
+                lang,
+                ParallelOutcome {
+                    measurement,
+                    precompile_nanos: precompile_nanos.get(&lang).copied().unwrap_or(0),
+                    runs_taken,
+                },
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Precompile one language's benchmark, returning the compile time in nanoseconds
+async fn precompile(
+    runtimes: &mut HashMap<Lang, Box<dyn Runtime>>,
+    lang: Lang,
+    spec: &BenchmarkSpec,
+    suite: &SuiteIR,
+) -> Result<u64> {
+    let Some(rt) = runtimes.get_mut(&lang) else {
+        return Ok(0);
+    };
+    let start = Instant::now();
+    rt.precompile(spec, suite).await.map_err(|e| {
+        miette!("{} pre-compilation failed ({}): {}", lang_label(lang), spec.full_name, e)
+    })?;
+    let wall_nanos = start.elapsed().as_nanos() as u64;
+    Ok(std::cmp::max(wall_nanos, rt.last_precompile_nanos().unwrap_or(0)))
+}
+
+/// Distribute languages round-robin over at most `workers` workers (never more than languages)
+pub(crate) fn plan_workers(langs: &[Lang], workers: usize) -> Vec<Vec<Lang>> {
+    let workers = workers.clamp(1, langs.len().max(1));
+    let mut plan = vec![Vec::new(); workers];
+    for (idx, lang) in langs.iter().enumerate() {
+        plan[idx % workers].push(*lang);
+    }
+    plan.retain(|w| !w.is_empty());
+    plan
+}
+
+/// Choose one CPU per worker from the CPUs this process may run on.
+///
+/// When there are more CPUs than workers the highest-numbered ones are used, leaving CPU 0
+/// (which usually services interrupts and the scheduler's own thread) free. With fewer CPUs
+/// than workers, CPUs are shared round-robin.
+pub(crate) fn worker_cpus(allowed: &[usize], workers: usize) -> Vec<Option<usize>> {
+    if allowed.is_empty() {
+        return vec![None; workers];
+    }
+    let offset = allowed.len().saturating_sub(workers);
+    (0..workers).map(|idx| Some(allowed[offset + idx % (allowed.len() - offset)])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_workers_round_robin() {
+        let langs = [Lang::Go, Lang::TypeScript, Lang::Rust];
+        assert_eq!(
+            plan_workers(&langs, 2),
+            vec![vec![Lang::Go, Lang::Rust], vec![Lang::TypeScript]]
+        );
+        // Never more workers than languages, never zero
+        assert_eq!(plan_workers(&langs, 8).len(), 3);
+        assert_eq!(plan_workers(&langs, 0), vec![langs.to_vec()]);
+        assert!(plan_workers(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_worker_cpus_prefers_high_cpus_and_wraps() {
+        assert_eq!(worker_cpus(&[0, 1, 2, 3], 2), vec![Some(2), Some(3)]);
+        assert_eq!(worker_cpus(&[0, 1], 3), vec![Some(0), Some(1), Some(0)]);
+        assert_eq!(worker_cpus(&[], 2), vec![None, None]);
+    }
+}
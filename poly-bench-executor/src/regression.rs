@@ -0,0 +1,390 @@
+//! Regression detection between two result sets
+//!
+//! Matches benchmarks by suite and name, then compares each language's measurement using a
+//! bootstrap CI on the ratio of medians (candidate / baseline).
+
+use crate::comparison::{BenchmarkResult, BenchmarkResults};
+use poly_bench_dsl::{Lang, SuiteType};
+use poly_bench_runtime::{measurement::Measurement, supported_languages};
+use serde::{Deserialize, Serialize};
+
+/// Default relative change (percent) below which differences are ignored
+pub const DEFAULT_REGRESSION_THRESHOLD_PERCENT: f64 = 5.0;
+
+/// Outcome of comparing one benchmark/language pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegressionVerdict {
+    /// Candidate is significantly slower (or uses more memory)
+    Regression,
+    /// Candidate is significantly faster (or uses less memory)
+    Improvement,
+    /// No significant change
+    Unchanged,
+}
+
+/// Statistic the baseline/candidate values (and thus the ratio) are computed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegressionStatistic {
+    /// Mean nanos/op (or bytes/op), used when no sample distribution is available
+    #[default]
+    Mean,
+    /// Median of the per-op timing distribution, used with the bootstrap CI
+    Median,
+}
+
+/// Comparison of a single benchmark in a single language
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegressionEntry {
+    /// Suite name
+    pub suite: String,
+    /// Benchmark name
+    pub benchmark: String,
+    /// Language
+    pub lang: Lang,
+    /// Baseline metric value (nanos/op, or bytes/op for memory suites)
+    pub baseline: f64,
+    /// Candidate metric value (nanos/op, or bytes/op for memory suites)
+    pub candidate: f64,
+    /// Statistic `baseline` and `candidate` report; `ratio` is always candidate / baseline
+    #[serde(default)]
+    pub statistic: RegressionStatistic,
+    /// Ratio candidate / baseline (>1 means candidate is slower)
+    pub ratio: f64,
+    /// 95% bootstrap CI lower bound for the ratio (if both sides have distributions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_95_lower: Option<f64>,
+    /// 95% bootstrap CI upper bound for the ratio (if both sides have distributions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ci_95_upper: Option<f64>,
+    /// Whether the metric is bytes/op rather than nanos/op
+    pub is_memory: bool,
+    /// Verdict for this pair
+    pub verdict: RegressionVerdict,
+}
+
+impl RegressionEntry {
+    /// Relative change in percent (positive means candidate is slower)
+    pub fn change_percent(&self) -> f64 {
+        (self.ratio - 1.0) * 100.0
+    }
+}
+
+/// Full comparison of two result sets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegressionReport {
+    /// Label of the baseline result set
+    pub baseline_label: String,
+    /// Label of the candidate result set
+    pub candidate_label: String,
+    /// Threshold (percent) used to classify changes
+    pub threshold_percent: f64,
+    /// Per benchmark/language comparisons
+    pub entries: Vec<RegressionEntry>,
+    /// Benchmarks only present in the baseline (`suite.bench`)
+    pub missing_in_candidate: Vec<String>,
+    /// Benchmarks only present in the candidate (`suite.bench`)
+    pub new_in_candidate: Vec<String>,
+    /// Benchmarks not compared because only one side ran under parallel contention
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mode_mismatch: Vec<String>,
+}
+
+impl RegressionReport {
+    /// Entries flagged as regressions
+    pub fn regressions(&self) -> impl Iterator<Item = &RegressionEntry> {
+        self.entries.iter().filter(|e| e.verdict == RegressionVerdict::Regression)
+    }
+
+    /// Entries flagged as improvements
+    pub fn improvements(&self) -> impl Iterator<Item = &RegressionEntry> {
+        self.entries.iter().filter(|e| e.verdict == RegressionVerdict::Improvement)
+    }
+
+    /// Whether any benchmark regressed significantly
+    pub fn has_regressions(&self) -> bool {
+        self.regressions().next().is_some()
+    }
+}
+
+/// Compare two result sets benchmark-by-benchmark and language-by-language
+pub fn compare_results(
+    baseline: &BenchmarkResults,
+    candidate: &BenchmarkResults,
+    baseline_label: &str,
+    candidate_label: &str,
+    threshold_percent: f64,
+) -> RegressionReport {
+    let mut entries = Vec::new();
+    let mut missing_in_candidate = Vec::new();
+    let mut new_in_candidate = Vec::new();
+    let mut mode_mismatch = Vec::new();
+
+    for base_suite in &baseline.suites {
+        let cand_suite = candidate.suites.iter().find(|s| s.name == base_suite.name);
+        for base_bench in &base_suite.benchmarks {
+            let cand_bench =
+                cand_suite.and_then(|s| s.benchmarks.iter().find(|b| b.name == base_bench.name));
+            let Some(cand_bench) = cand_bench else {
+                // Benchmarks deliberately filtered out of the candidate run are not "missing"
+                let filtered = candidate
+                    .skipped
+                    .iter()
+                    .any(|s| s.suite == base_suite.name && s.name == base_bench.name);
+                if !filtered {
+                    missing_in_candidate.push(format!("{}.{}", base_suite.name, base_bench.name));
+                }
+                continue;
+            };
+            if base_bench.is_parallel() != cand_bench.is_parallel() {
+                mode_mismatch.push(format!("{}.{}", base_suite.name, base_bench.name));
+                continue;
+            }
+            let is_memory = base_suite.suite_type == SuiteType::Memory;
+            entries.extend(compare_benchmark(
+                &base_suite.name,
+                base_bench,
+                cand_bench,
+                is_memory,
+                threshold_percent,
+            ));
+        }
+    }
+
+    for cand_suite in &candidate.suites {
+        let base_suite = baseline.suites.iter().find(|s| s.name == cand_suite.name);
+        for cand_bench in &cand_suite.benchmarks {
+            let known =
+                base_suite.is_some_and(|s| s.benchmarks.iter().any(|b| b.name == cand_bench.name));
+            if !known {
+                new_in_candidate.push(format!("{}.{}", cand_suite.name, cand_bench.name));
+            }
+        }
+    }
+
+    RegressionReport {
+        baseline_label: baseline_label.to_string(),
+        candidate_label: candidate_label.to_string(),
+        threshold_percent,
+        entries,
+        missing_in_candidate,
+        new_in_candidate,
+        mode_mismatch,
+    }
+}
+
+fn compare_benchmark(
+    suite: &str,
+    baseline: &BenchmarkResult,
+    candidate: &BenchmarkResult,
+    is_memory: bool,
+    threshold_percent: f64,
+) -> Vec<RegressionEntry> {
+    let mut entries = Vec::new();
+    for lang in &supported_languages() {
+        let (Some(base), Some(cand)) =
+            (baseline.measurements.get(lang), candidate.measurements.get(lang))
+        else {
+            continue;
+        };
+        if base.timed_out == Some(true) || cand.timed_out == Some(true) {
+            continue;
+        }
+        let Some(verdict) = compare_measurement(base, cand, is_memory, threshold_percent) else {
+            continue;
+        };
+        entries.push(RegressionEntry {
+            suite: suite.to_string(),
+            benchmark: baseline.name.clone(),
+            lang: *lang,
+            baseline: verdict.baseline,
+            candidate: verdict.candidate,
+            statistic: verdict.statistic,
+            ratio: verdict.ratio,
+            ci_95_lower: verdict.ci.map(|c| c.0),
+            ci_95_upper: verdict.ci.map(|c| c.1),
+            is_memory,
+            verdict: verdict.verdict,
+        });
+    }
+    entries
+}
+
+/// Metric values, ratio and verdict for one measurement pair
+struct MeasurementDelta {
+    baseline: f64,
+    candidate: f64,
+    statistic: RegressionStatistic,
+    ratio: f64,
+    ci: Option<(f64, f64)>,
+    verdict: RegressionVerdict,
+}
+
+fn compare_measurement(
+    baseline: &Measurement,
+    candidate: &Measurement,
+    is_memory: bool,
+    threshold_percent: f64,
+) -> Option<MeasurementDelta> {
+    let (base_val, cand_val) = if is_memory {
+        (baseline.bytes_per_op? as f64, candidate.bytes_per_op? as f64)
+    } else {
+        (baseline.nanos_per_op, candidate.nanos_per_op)
+    };
+    if base_val <= 0.0 {
+        return None;
+    }
+
+    let threshold = threshold_percent / 100.0;
+    // Memory is deterministic enough that only the threshold applies
+    let distributions = if is_memory {
+        None
+    } else {
+        baseline.timing_distribution().zip(candidate.timing_distribution())
+    };
+    let ci = distributions.as_ref().and_then(|(b, c)| Measurement::independent_ratio_ci(b, c));
+
+    // With a CI the verdict is about medians, so report medians to keep the printed values and
+    // the change consistent with it
+    let (base_val, cand_val, statistic) = match (&ci, &distributions) {
+        (Some(_), Some((b, c))) => {
+            (Measurement::median_of(b), Measurement::median_of(c), RegressionStatistic::Median)
+        }
+        _ => (base_val, cand_val, RegressionStatistic::Mean),
+    };
+
+    let (ratio, verdict) = match ci {
+        Some((ratio, lo, hi)) => {
+            let verdict = if lo > 1.0 && ratio > 1.0 + threshold {
+                RegressionVerdict::Regression
+            } else if hi < 1.0 && ratio < 1.0 - threshold {
+                RegressionVerdict::Improvement
+            } else {
+                RegressionVerdict::Unchanged
+            };
+            (ratio, verdict)
+        }
+        None => {
+            let ratio = cand_val / base_val;
+            let verdict = if ratio > 1.0 + threshold {
+                RegressionVerdict::Regression
+            } else if ratio < 1.0 - threshold {
+                RegressionVerdict::Improvement
+            } else {
+                RegressionVerdict::Unchanged
+            };
+            (ratio, verdict)
+        }
+    };
+
+    Some(MeasurementDelta {
+        baseline: base_val,
+        candidate: cand_val,
+        statistic,
+        ratio,
+        ci: ci.map(|(_, lo, hi)| (lo, hi)),
+        verdict,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comparison::SuiteResults;
+    use poly_bench_dsl::BenchmarkKind;
+    use std::collections::HashMap;
+
+    fn bench(name: &str, samples: Vec<u64>) -> BenchmarkResult {
+        bench_with_mode(name, samples, "legacy")
+    }
+
+    fn bench_with_mode(name: &str, samples: Vec<u64>, mode: &str) -> BenchmarkResult {
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, Measurement::from_samples(samples, 8));
+        BenchmarkResult::new(
+            name.to_string(),
+            format!("suite_{}", name),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            mode.to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn results(benches: Vec<BenchmarkResult>) -> BenchmarkResults {
+        BenchmarkResults::new(vec![SuiteResults::new(
+            "suite".to_string(),
+            None,
+            SuiteType::Performance,
+            benches,
+        )])
+    }
+
+    #[test]
+    fn test_compare_flags_significant_regression() {
+        let base = results(vec![
+            bench("hash", vec![100, 101, 99, 102, 98, 100, 101, 99]),
+            bench("sort", vec![500, 505, 495, 500, 502, 498, 501, 499]),
+        ]);
+        let cand = results(vec![
+            bench("hash", vec![150, 151, 149, 152, 148, 150, 151, 149]),
+            bench("sort", vec![501, 504, 496, 500, 503, 497, 500, 499]),
+        ]);
+
+        let report = compare_results(&base, &cand, "base", "cand", 5.0);
+        assert_eq!(report.entries.len(), 2);
+        assert!(report.has_regressions());
+        let hash = report.entries.iter().find(|e| e.benchmark == "hash").unwrap();
+        assert_eq!(hash.verdict, RegressionVerdict::Regression);
+        assert!(hash.ci_95_lower.unwrap() > 1.0);
+        // Printed values are the medians the verdict compares, so the change matches them
+        assert_eq!(hash.statistic, RegressionStatistic::Median);
+        assert_eq!((hash.baseline, hash.candidate), (100.0, 150.0));
+        assert!((hash.candidate / hash.baseline - hash.ratio).abs() < 1e-12);
+        let sort = report.entries.iter().find(|e| e.benchmark == "sort").unwrap();
+        assert_eq!(sort.verdict, RegressionVerdict::Unchanged);
+    }
+
+    #[test]
+    fn test_compare_tracks_added_and_removed_benchmarks() {
+        let base = results(vec![bench("old", vec![100, 100, 100, 100])]);
+        let cand = results(vec![bench("new", vec![100, 100, 100, 100])]);
+
+        let report = compare_results(&base, &cand, "base", "cand", 5.0);
+        assert!(report.entries.is_empty());
+        assert_eq!(report.missing_in_candidate, vec!["suite.old".to_string()]);
+        assert_eq!(report.new_in_candidate, vec!["suite.new".to_string()]);
+        assert!(!report.has_regressions());
+    }
+
+    #[test]
+    fn test_compare_improvement() {
+        let base = results(vec![bench("hash", vec![200, 202, 198, 201, 199, 200, 203, 197])]);
+        let cand = results(vec![bench("hash", vec![100, 101, 99, 102, 98, 100, 101, 99])]);
+
+        let report = compare_results(&base, &cand, "base", "cand", 5.0);
+        assert_eq!(report.improvements().count(), 1);
+        assert!(!report.has_regressions());
+    }
+
+    #[test]
+    fn test_compare_skips_parallel_vs_isolated() {
+        let base = results(vec![bench("hash", vec![100, 101, 99, 102])]);
+        let cand = results(vec![bench_with_mode(
+            "hash",
+            vec![150, 151, 149, 152],
+            crate::PARALLEL_COMPARISON_MODE,
+        )]);
+
+        let report = compare_results(&base, &cand, "base", "cand", 5.0);
+        assert!(report.entries.is_empty());
+        assert_eq!(report.mode_mismatch, vec!["suite.hash".to_string()]);
+        assert!(!report.has_regressions());
+    }
+}
@@ -0,0 +1,113 @@
+//! Loading `results.json` files written by any poly-bench version
+//!
+//! Results carry a `schemaVersion` (files written before versioning are version 1). Loading
+//! upgrades older files to the current shape one migration at a time, so `poly-bench plot`,
+//! `compare` and the run history keep working on archived results. The reporter re-exports
+//! these functions next to the published JSON Schema.
+
+use miette::{miette, Result};
+use crate::comparison::{BenchmarkResults, RESULTS_SCHEMA_VERSION};
+use serde_json::Value;
+use std::path::Path;
+
+/// Upgrades results from version `N` (index `N - 1`) to `N + 1`
+const MIGRATIONS: &[fn(&mut Value)] = &[migrate_v1_to_v2];
+
+/// Read and upgrade a results file; history entries (`.polybench/history/*.json`) are accepted too
+pub fn load_file(path: &Path) -> Result<BenchmarkResults> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| miette!("Failed to read results file {}: {}", path.display(), e))?;
+    load(&json).map_err(|e| miette!("{}: {}", path.display(), e))
+}
+
+/// Parse and upgrade serialized results
+pub fn load(json: &str) -> Result<BenchmarkResults> {
+    let mut value: Value =
+        serde_json::from_str(json).map_err(|e| miette!("Failed to parse results JSON: {}", e))?;
+    // A history entry wraps the results with run metadata
+    if value.get("results").is_some_and(Value::is_object) && value.get("suites").is_none() {
+        value = value["results"].take();
+    }
+    upgrade(&mut value)?;
+    serde_json::from_value(value).map_err(|e| {
+        miette!("Results do not match schema version {}: {}", RESULTS_SCHEMA_VERSION, e)
+    })
+}
+
+/// Upgrade a results document in place to [`RESULTS_SCHEMA_VERSION`]
+pub fn upgrade(value: &mut Value) -> Result<()> {
+    let root = value.as_object_mut().ok_or_else(|| miette!("Results JSON is not an object"))?;
+    let version = match root.get("schemaVersion") {
+        None => 1,
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| miette!("Invalid schemaVersion {}", v))?,
+    };
+    if version > RESULTS_SCHEMA_VERSION {
+        return Err(miette!(
+            "Results use schema version {} but this poly-bench reads up to version {}; upgrade \
+             poly-bench to load them",
+            version,
+            RESULTS_SCHEMA_VERSION
+        ));
+    }
+    for migration in MIGRATIONS.iter().skip(version.saturating_sub(1) as usize) {
+        migration(value);
+    }
+    value["schemaVersion"] = Value::from(RESULTS_SCHEMA_VERSION);
+    Ok(())
+}
+
+/// Version 2 only added optional fields (`skipped`, `environment`, benchmark `params`,
+/// `equivalence`, `adaptive`, measurement `counters`, ...) that deserialize to their defaults,
+/// so a version 1 file already has the version 2 shape; [`upgrade`] stamps `schemaVersion`.
+fn migrate_v1_to_v2(_value: &mut Value) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Written by the last unversioned release (schema version 1)
+    const V1_RESULTS: &str = include_str!("../testdata/results_v1.json");
+
+    #[test]
+    fn test_load_upgrades_unversioned_results() {
+        let results = load(V1_RESULTS).unwrap();
+        assert_eq!(results.schema_version, RESULTS_SCHEMA_VERSION);
+        let suite = &results.suites[0];
+        assert_eq!(suite.name, "hash");
+        let bench = &suite.benchmarks[0];
+        assert_eq!(bench.full_name, "hash_sha256");
+        assert_eq!(bench.comparison_mode, "legacy");
+        assert_eq!(bench.measurements.len(), 2);
+        assert!(bench.comparison.is_some());
+        assert!(bench.params.is_empty());
+        assert!(results.environment.is_none());
+
+        let wrapped = format!(r#"{{"id": "abc", "results": {}}}"#, V1_RESULTS);
+        assert_eq!(load(&wrapped).unwrap().suites[0].name, "hash");
+    }
+
+    #[test]
+    fn test_upgrade_only_stamps_version_on_v1() {
+        let original: Value = serde_json::from_str(V1_RESULTS).unwrap();
+        let mut upgraded = original.clone();
+        upgrade(&mut upgraded).unwrap();
+        assert_eq!(upgraded["schemaVersion"], RESULTS_SCHEMA_VERSION);
+        upgraded.as_object_mut().unwrap().remove("schemaVersion");
+        assert_eq!(upgraded, original);
+    }
+
+    #[test]
+    fn test_load_current_roundtrip_and_rejects_newer() {
+        let results = load(V1_RESULTS).unwrap();
+        let json = serde_json::to_string_pretty(&results).unwrap();
+        assert!(json.contains("\"schemaVersion\": 2"));
+        assert_eq!(load(&json).unwrap().suites.len(), 1);
+
+        let newer = json.replace("\"schemaVersion\": 2", "\"schemaVersion\": 99");
+        let err = load(&newer).unwrap_err().to_string();
+        assert!(err.contains("schema version 99"), "{}", err);
+    }
+}
@@ -1,7 +1,12 @@
 //! Benchmark execution scheduler
 
 use super::{AnvilConfig, AnvilService, ProjectRoots};
-use crate::comparison::{BenchmarkResult, BenchmarkResults, SuiteResults};
+use crate::{
+    adaptive::{ci_half_width_percent, AdaptiveOutcome, AdaptivePlan, AdaptiveStop},
+    comparison::{BenchmarkResult, BenchmarkResults, GeneratedFixture, SuiteResults},
+    parallel::{self, PARALLEL_COMPARISON_MODE},
+    watch::{EnvFingerprints, Fingerprints},
+};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
 use miette::{miette, Result};
@@ -35,6 +40,42 @@ struct TimerState {
 #[derive(Debug, Clone, Default)]
 pub struct RunOptions {
     pub verbose: bool,
+    /// Worker threads for `order: parallel` suites (default: available CPUs, capped at the
+    /// number of languages)
+    pub workers: Option<usize>,
+    /// Pin each parallel worker to its own CPU (Linux only)
+    pub pin_cpus: bool,
+    /// Notified as each benchmark starts, for callers that show their own progress
+    pub progress: Option<ProgressHook>,
+}
+
+/// A benchmark about to run. `index` counts benchmarks across every suite of the run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkProgress {
+    pub index: usize,
+    pub total: usize,
+    pub suite: String,
+    pub name: String,
+}
+
+/// Callback receiving [`BenchmarkProgress`] updates
+#[derive(Clone)]
+pub struct ProgressHook(Arc<dyn Fn(BenchmarkProgress) + Send + Sync>);
+
+impl ProgressHook {
+    pub fn new(hook: impl Fn(BenchmarkProgress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    fn notify(&self, progress: BenchmarkProgress) {
+        (self.0)(progress)
+    }
+}
+
+impl std::fmt::Debug for ProgressHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressHook")
+    }
 }
 
 /// Simple deterministic RNG (xorshift64*) for reproducible shuffles without extra deps.
@@ -79,7 +120,7 @@ fn shuffle_slice<T>(slice: &mut [T], seed: u64) {
     }
 }
 
-fn strict_run_lang_order(
+pub(crate) fn strict_run_lang_order(
     spec: &BenchmarkSpec,
     suite: &SuiteIR,
     run_idx: u64,
@@ -115,6 +156,78 @@ async fn run_with_optional_timeout(
     }
 }
 
+/// Execute one run block: run `run_idx` of `spec` once on each runtime in `run_langs`, in that
+/// order, appending each measurement to `run_measurements`. Async failures are recorded as
+/// error measurements; any other failure aborts the block.
+pub(crate) async fn run_block(
+    runtimes: &mut HashMap<Lang, Box<dyn Runtime>>,
+    run_langs: &[Lang],
+    spec: &BenchmarkSpec,
+    suite: &SuiteIR,
+    run_idx: u64,
+    verbose: bool,
+    run_measurements: &mut HashMap<Lang, Vec<Measurement>>,
+) -> Result<()> {
+    for &lang in run_langs {
+        let Some(rt) = runtimes.get_mut(&lang) else {
+            continue;
+        };
+        match run_with_optional_timeout(rt.as_mut(), spec, suite).await {
+            Ok(m) => run_measurements.entry(lang).or_default().push(m),
+            Err(e) if spec.kind == BenchmarkKind::Async => {
+                let label = format!("{}:", lang_label(lang));
+                eprintln!(
+                    "\n    {} run {} failed for {} (recorded as async error)",
+                    colorize_lang_label(&label, lang),
+                    run_idx + 1,
+                    spec.full_name
+                );
+                run_measurements
+                    .entry(lang)
+                    .or_default()
+                    .push(async_error_measurement(&format!("{}", e)));
+            }
+            Err(e) => {
+                return Err(format_runtime_error(
+                    lang,
+                    run_idx + 1,
+                    spec,
+                    &suite.name,
+                    &e.to_string(),
+                    verbose,
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Per-language summary printed once a language has finished all of its runs
+fn runs_summary_line(
+    lang: Lang,
+    measurement: &Measurement,
+    suite_type: SuiteType,
+    kind: BenchmarkKind,
+    runs_taken: usize,
+) -> String {
+    let ci_str = if let (Some(median), Some(ci_upper)) =
+        (measurement.median_across_runs, measurement.ci_95_upper)
+    {
+        format!(" ±{}", Measurement::format_duration(ci_upper - median))
+    } else {
+        String::new()
+    };
+    format!(
+        "    {} {}{}{} ({}x runs, {:.2}s)",
+        colorize_lang_label(&format!("{}:", lang_label(lang)), lang),
+        format_primary_metric(measurement, suite_type),
+        ci_str,
+        async_outcome_suffix(kind, measurement),
+        runs_taken,
+        measurement.total_nanos as f64 / 1e9
+    )
+}
+
 fn async_success_error_counts(measurement: &Measurement) -> (u64, u64) {
     let mut success = measurement
         .async_success_count
@@ -226,7 +339,7 @@ fn format_runtime_error(
     lines.push(format!("reason: {}", reason));
 
     if let (Some(src), Some(gen_line)) =
-        (spec.implementation_sources.get(&lang), parse_generated_line(raw_error))
+        (spec.implementation_sources.get(&lang.base()), parse_generated_line(raw_error))
     {
         // In generated scripts, user impl usually starts after wrapper lines. Approximate with
         // relative offset to provide a useful .bench pointer.
@@ -236,7 +349,7 @@ fn format_runtime_error(
             bench_line,
             lang_label(lang)
         ));
-    } else if let Some(src) = spec.implementation_sources.get(&lang) {
+    } else if let Some(src) = spec.implementation_sources.get(&lang.base()) {
         lines.push(format!(
             "location: .bench line {} ({} implementation)",
             src.bench_file_line,
@@ -387,6 +500,13 @@ pub async fn run(
 ) -> Result<BenchmarkResults> {
     let mut suite_results = Vec::new();
 
+    // Large fixtures are read from shared files at startup instead of being inlined
+    for fixture in ir.suites.iter().flat_map(|suite| &suite.fixtures) {
+        fixture.write_external_file().map_err(|e| {
+            miette!("Failed to write data file for fixture '{}': {}", fixture.name, e)
+        })?;
+    }
+
     // Check if globalSetup has spawnAnvil() and spawn Anvil if needed
     let anvil_service = if let Some(ref anvil_ir) = ir.anvil_config {
         let anvil_spinner = ProgressBar::new_spinner();
@@ -427,6 +547,12 @@ pub async fn run(
     // Get the Anvil RPC URL if available
     let anvil_rpc_url = anvil_service.as_ref().map(|s| s.rpc_url.clone());
 
+    // Source-only fingerprints, so results can be matched against later edits of the file
+    let source_hashes = Fingerprints::of(ir, &EnvFingerprints::default());
+
+    let total_benchmarks = ir.suites.iter().map(|suite| suite.benchmarks.len()).sum();
+    let mut started_benchmarks = 0;
+
     for suite in &ir.suites {
         print!("\n{} Suite: {}", "▶".blue().bold(), suite.name.bold());
         if let Some(ref desc) = suite.description {
@@ -438,7 +564,7 @@ pub async fn run(
         let mut benchmark_results = Vec::new();
 
         // Initialize runtimes via registry
-        let config = RuntimeConfig { roots: project_roots.roots.clone() };
+        let config = RuntimeConfig { roots: project_roots.roots.clone(), ..Default::default() };
         let mut runtimes = create_runtimes(langs, &config)
             .map_err(|e| miette!("Runtime initialization failed: {}", e))?;
 
@@ -465,14 +591,72 @@ pub async fn run(
                     .unwrap_or_else(|| hash_str_to_u64(&format!("suite-order:{}", suite.name)));
                 shuffle_slice(&mut suite_benchmarks, seed);
             }
-            ExecutionOrder::Parallel => {
-                // Runtime-level benchmark parallelism is not yet enabled; preserve deterministic
-                // sequential execution to avoid cross-runtime contention skew.
+            ExecutionOrder::Parallel => {}
+        }
+
+        // Parallel suites run every benchmark up front on the worker pool; the loop below only
+        // collects and prints their outcomes.
+        let mut parallel_outcomes = None;
+        if suite.order == ExecutionOrder::Parallel {
+            let specs: Vec<BenchmarkSpec> = suite_benchmarks
+                .iter()
+                .map(|spec| {
+                    let mut spec = spec.clone();
+                    if let Some(override_iters) = iterations_override {
+                        spec.iterations = override_iters;
+                    }
+                    spec
+                })
+                .collect();
+            let timer = start_timer(&format!("▸ {} parallel", suite.name));
+            let run =
+                parallel::run_suite(std::mem::take(&mut runtimes), suite, &specs, langs, options)
+                    .await;
+            stop_timer(&timer);
+            let run = run?;
+            runtimes = run.runtimes;
+
+            if let Some(err) = run.error {
+                for (_, rt) in runtimes.iter_mut() {
+                    let _ = rt.as_mut().shutdown().await;
+                }
+                return Err(err);
             }
+
+            let placement = if run.worker_cpus.iter().any(Option::is_some) {
+                let cpus: Vec<String> = run
+                    .worker_cpus
+                    .iter()
+                    .map(|cpu| cpu.map(|c| format!("cpu{}", c)).unwrap_or_else(|| "-".into()))
+                    .collect();
+                format!("pinned to {}", cpus.join(", "))
+            } else {
+                "unpinned".to_string()
+            };
+            println!(
+                "  {}",
+                format!(
+                    "parallel: {} workers ({}), results measured under contention",
+                    run.worker_cpus.len(),
+                    placement
+                )
+                .dimmed()
+            );
+            parallel_outcomes = Some(run.outcomes);
         }
 
         // Run each benchmark
         for spec in &suite_benchmarks {
+            if let Some(ref hook) = options.progress {
+                hook.notify(BenchmarkProgress {
+                    index: started_benchmarks,
+                    total: total_benchmarks,
+                    suite: suite.name.clone(),
+                    name: spec.name.clone(),
+                });
+            }
+            started_benchmarks += 1;
+
             let mut spec_clone = spec.clone();
             if let Some(override_iters) = iterations_override {
                 spec_clone.iterations = override_iters;
@@ -482,8 +666,36 @@ pub async fn run(
             let mut precompile_nanos: HashMap<Lang, u64> = HashMap::new();
             let strict_fairness = spec_clone.fairness_mode == FairnessMode::Strict;
             let bench_wall_elapsed: Option<f64>;
+            // Adaptive run counts (ciTarget) apply to timing suites run sequentially
+            let adaptive_plan = if is_memory_suite || parallel_outcomes.is_some() {
+                None
+            } else {
+                AdaptivePlan::from_spec(&spec_clone)
+            };
+            let mut adaptive_outcome = adaptive_plan.as_ref().map(AdaptiveOutcome::new);
 
-            if strict_fairness {
+            if let Some(ref mut outcomes) = parallel_outcomes {
+                let mut lang_outcomes = outcomes.remove(&spec.full_name).unwrap_or_default();
+                for lang in langs {
+                    let Some(outcome) = lang_outcomes.remove(lang) else {
+                        continue;
+                    };
+                    println!(
+                        "{}",
+                        runs_summary_line(
+                            *lang,
+                            &outcome.measurement,
+                            suite.suite_type,
+                            spec.kind,
+                            outcome.runs_taken
+                        )
+                    );
+                    measurements.insert(*lang, outcome.measurement);
+                    precompile_nanos.insert(*lang, outcome.precompile_nanos);
+                }
+                // Workers overlap, so there is no meaningful per-benchmark wall time
+                bench_wall_elapsed = None;
+            } else if strict_fairness {
                 // Precompile all participating runtimes before timed runs so interleaving does not
                 // include compile overhead in any runtime's measured path.
                 let precompile_label = format!("▸ {} precompile", spec.name);
@@ -510,7 +722,8 @@ pub async fn run(
                 }
                 stop_timer(&precompile_timer);
 
-                let run_count = spec_clone.count.max(1);
+                let run_count = adaptive_plan.map_or(spec_clone.count.max(1), |p| p.max_runs);
+                let mut adaptive_stop = AdaptiveStop::RunBudget;
                 let mut run_measurements: HashMap<Lang, Vec<Measurement>> = HashMap::new();
                 let strict_label = format!("{}:", suite.name);
                 let strict_timer = start_multi_run_timer(&strict_label, "cyan", run_count);
@@ -526,39 +739,27 @@ pub async fn run(
                         .collect();
                     let run_langs = strict_run_lang_order(&spec_clone, suite, run_idx, run_langs);
 
-                    for lang in run_langs {
-                        if let Some(ref mut rt) = runtimes.get_mut(&lang) {
-                            match run_with_optional_timeout(rt.as_mut(), &spec_clone, suite).await {
-                                Ok(m) => {
-                                    run_measurements.entry(lang).or_default().push(m);
-                                }
-                                Err(e) => {
-                                    let err = format!("{}", e);
-                                    let label = &format!("{}:", lang_label(lang));
-                                    let colored_label = colorize_lang_label(label, lang);
-                                    if spec_clone.kind == BenchmarkKind::Async {
-                                        eprintln!(
-                                            "\n    {} run {} failed for {} (recorded as async error)",
-                                            colored_label,
-                                            run_idx + 1,
-                                            spec_clone.full_name
-                                        );
-                                        run_measurements
-                                            .entry(lang)
-                                            .or_default()
-                                            .push(async_error_measurement(&err));
-                                    } else {
-                                        return Err(format_runtime_error(
-                                            lang,
-                                            run_idx + 1,
-                                            &spec_clone,
-                                            &suite.name,
-                                            &e.to_string(),
-                                            options.verbose,
-                                        ));
-                                    }
-                                }
-                            }
+                    run_block(
+                        &mut runtimes,
+                        &run_langs,
+                        &spec_clone,
+                        suite,
+                        run_idx,
+                        options.verbose,
+                        &mut run_measurements,
+                    )
+                    .await?;
+
+                    if let Some(plan) = adaptive_plan {
+                        let ci_percents: Vec<Option<f64>> = run_measurements
+                            .values()
+                            .map(|runs| ci_half_width_percent(runs))
+                            .collect();
+                        if let Some(stop) =
+                            plan.check(run_idx + 1, bench_wall_start.elapsed(), &ci_percents)
+                        {
+                            adaptive_stop = stop;
+                            break;
                         }
                     }
                 }
@@ -569,6 +770,9 @@ pub async fn run(
                     if runs.is_empty() {
                         continue;
                     }
+                    if let Some(outcome) = adaptive_outcome.as_mut() {
+                        outcome.record(lang, &runs, adaptive_stop);
+                    }
                     let aggregated = if runs.len() == 1 {
                         runs.into_iter().next().unwrap()
                     } else if is_memory_suite {
@@ -611,12 +815,15 @@ pub async fn run(
                     let timer_color = poly_bench_runtime::lang_display(*lang).terminal_color;
                     let lang_label_str = lang_label(*lang);
 
-                    if spec_clone.count > 1 {
+                    if spec_clone.count > 1 || adaptive_plan.is_some() {
+                        let run_count = adaptive_plan.map_or(spec_clone.count, |p| p.max_runs);
                         let label = format!("{} {}:", suite.name, lang_label_str);
-                        let timer = start_multi_run_timer(&label, timer_color, spec_clone.count);
+                        let timer = start_multi_run_timer(&label, timer_color, run_count);
                         let mut run_measurements = Vec::new();
+                        let mut adaptive_stop = AdaptiveStop::RunBudget;
+                        let lang_start = Instant::now();
 
-                        for run_idx in 0..spec_clone.count {
+                        for run_idx in 0..run_count {
                             timer.current_run.store(run_idx + 1, Ordering::Relaxed);
 
                             match run_with_optional_timeout(rt.as_mut(), &spec_clone, suite).await {
@@ -637,34 +844,39 @@ pub async fn run(
                                     }
                                 }
                             }
+
+                            if let Some(plan) = adaptive_plan {
+                                let ci_percent = ci_half_width_percent(&run_measurements);
+                                if let Some(stop) =
+                                    plan.check(run_idx + 1, lang_start.elapsed(), &[ci_percent])
+                                {
+                                    adaptive_stop = stop;
+                                    break;
+                                }
+                            }
                         }
 
                         stop_multi_run_timer(&timer);
 
                         if !run_measurements.is_empty() {
+                            let runs_taken = run_measurements.len();
+                            if let Some(outcome) = adaptive_outcome.as_mut() {
+                                outcome.record(*lang, &run_measurements, adaptive_stop);
+                            }
                             let aggregated = if is_memory_suite {
                                 Measurement::aggregate_runs_memory(run_measurements)
                             } else {
                                 Measurement::aggregate_runs(run_measurements)
                             };
-                            let actual_run_secs = aggregated.total_nanos as f64 / 1e9;
-                            let ci_str = if let (Some(median), Some(ci_upper)) =
-                                (aggregated.median_across_runs, aggregated.ci_95_upper)
-                            {
-                                format!(" ±{}", Measurement::format_duration(ci_upper - median))
-                            } else {
-                                String::new()
-                            };
-                            let colored_label =
-                                colorize_lang_label(&format!("{}:", lang_label_str), *lang);
                             print!(
-                                "\r    {} {}{}{} ({}x runs, {:.2}s)                    ",
-                                colored_label,
-                                format_primary_metric(&aggregated, suite.suite_type),
-                                ci_str,
-                                async_outcome_suffix(spec.kind, &aggregated),
-                                spec_clone.count,
-                                actual_run_secs
+                                "\r{}                    ",
+                                runs_summary_line(
+                                    *lang,
+                                    &aggregated,
+                                    suite.suite_type,
+                                    spec.kind,
+                                    runs_taken
+                                )
                             );
                             measurements.insert(*lang, aggregated);
                         }
@@ -716,9 +928,11 @@ pub async fn run(
             let base_elapsed = bench_wall_elapsed.unwrap_or_else(|| {
                 measurements.values().map(|m| m.total_nanos).sum::<u64>() as f64 / 1e9
             });
-            // Strict mode: bench_wall excludes precompile; non-strict: bench_wall already includes
-            // it
-            let total_elapsed = base_elapsed + if strict_fairness { sum_precompile_s } else { 0.0 };
+            // Strict and parallel: base excludes precompile; non-strict: bench_wall already
+            // includes it
+            let is_parallel = parallel_outcomes.is_some();
+            let total_elapsed =
+                base_elapsed + if strict_fairness || is_parallel { sum_precompile_s } else { 0.0 };
             println!("  ▸ {} {:.2}s", spec.name.bold(), total_elapsed);
 
             println!(
@@ -797,16 +1011,30 @@ pub async fn run(
                 )
                 .dimmed()
             );
+            if let Some(ref outcome) = adaptive_outcome {
+                let line = format!("adaptive: {}", outcome.summary());
+                if outcome.target_met {
+                    println!("    {}", line.dimmed());
+                } else {
+                    println!("    {}", line.yellow());
+                }
+            }
             println!();
 
-            benchmark_results.push(BenchmarkResult::new(
+            let result = BenchmarkResult::new(
                 spec.name.clone(),
                 spec.full_name.clone(),
                 spec.kind,
                 spec.description.clone(),
                 measurements,
                 suite.suite_type,
-                if strict_fairness { "strict".to_string() } else { "legacy".to_string() },
+                if is_parallel {
+                    PARALLEL_COMPARISON_MODE.to_string()
+                } else if strict_fairness {
+                    "strict".to_string()
+                } else {
+                    "legacy".to_string()
+                },
                 spec_clone.fairness_seed,
                 Some(spec_clone.async_warmup_cap),
                 Some(spec_clone.async_sample_cap),
@@ -814,7 +1042,15 @@ pub async fn run(
                     poly_bench_dsl::AsyncSamplingPolicy::FixedCap => "fixedCap".to_string(),
                     poly_bench_dsl::AsyncSamplingPolicy::TimeBudgeted => "timeBudgeted".to_string(),
                 }),
-            ));
+            )
+            .with_params(spec.params.clone())
+            .with_adaptive(adaptive_outcome)
+            .with_cv_threshold(spec.cv_threshold);
+            benchmark_results.push(if spec.equivalent {
+                result.with_equivalence_check()
+            } else {
+                result
+            });
         }
 
         // Shutdown runtimes
@@ -822,12 +1058,34 @@ pub async fn run(
             let _ = rt.as_mut().shutdown().await;
         }
 
-        suite_results.push(SuiteResults::new(
-            suite.name.clone(),
-            suite.description.clone(),
-            suite.suite_type,
-            benchmark_results,
-        ));
+        let generated_fixtures = suite
+            .fixtures
+            .iter()
+            .filter_map(|fixture| {
+                Some(GeneratedFixture {
+                    name: fixture.name.clone(),
+                    generator: fixture.generator.clone()?,
+                    seed: fixture.seed,
+                    bytes: fixture.data.len(),
+                })
+            })
+            .collect();
+        let benchmark_results = benchmark_results
+            .into_iter()
+            .map(|result: BenchmarkResult| {
+                let hash = source_hashes.get(&suite.name, &result.name).map(String::from);
+                result.with_source_hash(hash)
+            })
+            .collect();
+        suite_results.push(
+            SuiteResults::new(
+                suite.name.clone(),
+                suite.description.clone(),
+                suite.suite_type,
+                benchmark_results,
+            )
+            .with_generated_fixtures(generated_fixtures),
+        );
         println!(
             "  {}",
             format!("suite total: {:.2}s", suite_start.elapsed().as_secs_f64()).dimmed()
@@ -137,7 +137,7 @@ pub async fn validate_benchmarks(
         Arc::new(Mutex::new(std::collections::HashMap::new()));
 
     // Initialize runtimes for each language via registry
-    let config = RuntimeConfig { roots: project_roots.roots.clone() };
+    let config = RuntimeConfig { roots: project_roots.roots.clone(), ..Default::default() };
     let runtimes = create_runtimes_arc(langs, &config);
 
     // Helper to add an error with deduplication
@@ -325,7 +325,7 @@ pub async fn validate_benchmarks_with_cache(
         Arc::new(Mutex::new(std::collections::HashMap::new()));
 
     // Initialize runtimes for each language via registry
-    let config = RuntimeConfig { roots: project_roots.roots.clone() };
+    let config = RuntimeConfig { roots: project_roots.roots.clone(), ..Default::default() };
     let runtimes = create_runtimes_arc(langs, &config);
 
     // Helper to add an error with deduplication
@@ -0,0 +1,534 @@
+//! Change detection for `poly-bench watch`
+//!
+//! Every benchmark gets a fingerprint covering everything that feeds its generated harness:
+//! the spec itself, its suite's setup, the fixtures it references and the dependency
+//! manifests and local sources of its languages' project roots. Diffing the fingerprints of a
+//! freshly lowered IR against the previous ones yields the benchmarks that need to re-run. File
+//! changes are picked up by polling modification times and sizes, so no platform watcher is
+//! needed.
+
+use crate::ProjectRoots;
+use poly_bench_dsl::{File, Lang};
+use poly_bench_ir::{BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
+use poly_bench_runtime::{go, rust, source_files, zig, ArtifactKey};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Identifies a benchmark within one `.bench` file
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BenchKey {
+    pub suite: String,
+    pub name: String,
+}
+
+impl fmt::Display for BenchKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.suite, self.name)
+    }
+}
+
+/// Dependency manifests that change what a language's project root resolves to.
+///
+/// Generated harness files are deliberately absent: runtimes rewrite them on every run.
+pub fn manifest_files(lang: Lang) -> &'static [&'static str] {
+    match lang {
+        Lang::Go => &["go.mod", "go.sum"],
+        Lang::TypeScript => &["package.json", "package-lock.json", "tsconfig.json"],
+        Lang::Rust => &["Cargo.toml", "Cargo.lock"],
+        Lang::Python => &["requirements.txt", "pyproject.toml"],
+        Lang::C => &["vcpkg.json"],
+        Lang::CSharp => &["polybench.csproj"],
+        Lang::Zig => &["build.zig", "build.zig.zon"],
+        Lang::External(_) => &[],
+        Lang::Variant(variant) => manifest_files(variant.base()),
+    }
+}
+
+/// Project-local source trees compiled into a language's harness, with the extensions that
+/// count: the same trees its runtime keys cached builds on. Interpreted languages load
+/// project code at run time, so nothing of theirs is listed.
+pub fn source_trees(lang: Lang, root: &Path) -> Vec<(PathBuf, &'static [&'static str])> {
+    match lang {
+        Lang::Go => vec![(root.to_path_buf(), go::SOURCE_EXTENSIONS)],
+        Lang::Rust => rust::path_dependencies(root)
+            .into_iter()
+            .map(|dir| (dir, rust::SOURCE_EXTENSIONS))
+            .collect(),
+        Lang::Zig => vec![(root.to_path_buf(), zig::SOURCE_EXTENSIONS)],
+        Lang::Variant(variant) => source_trees(variant.base(), root),
+        _ => Vec::new(),
+    }
+}
+
+/// Manifest and source paths to watch for every resolved project root
+pub fn project_paths(roots: &ProjectRoots) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    for (lang, root) in &roots.roots {
+        let Some(root) = root else { continue };
+        paths.extend(manifest_files(*lang).iter().map(|f| root.join(f)));
+        for (dir, extensions) in source_trees(*lang, root) {
+            paths.extend(source_files(&dir, extensions));
+        }
+    }
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Content hashes of each language's dependency manifests and local sources
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvFingerprints {
+    hashes: HashMap<Lang, String>,
+}
+
+impl EnvFingerprints {
+    /// Hash the manifests and source trees of each project root
+    pub fn scan(roots: &ProjectRoots) -> Self {
+        let mut hashes = HashMap::new();
+        for (lang, root) in &roots.roots {
+            let Some(root) = root else { continue };
+            let key = manifest_files(*lang)
+                .iter()
+                .fold(ArtifactKey::new(*lang, ""), |key, file| key.file(&root.join(file)));
+            let key = source_trees(*lang, root)
+                .into_iter()
+                .fold(key, |key, (dir, extensions)| key.source_tree(&dir, extensions));
+            hashes.insert(*lang, key.digest());
+        }
+        Self { hashes }
+    }
+
+    fn get(&self, lang: Lang) -> &str {
+        self.hashes.get(&lang).map(String::as_str).unwrap_or("")
+    }
+}
+
+/// Per-benchmark input fingerprints for one lowered `.bench` file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Fingerprints {
+    benches: HashMap<BenchKey, String>,
+}
+
+impl Fingerprints {
+    /// Fingerprint every benchmark in the IR
+    pub fn of(ir: &BenchmarkIR, env: &EnvFingerprints) -> Self {
+        let mut stdlib: Vec<&String> = ir.stdlib_imports.iter().collect();
+        stdlib.sort();
+        let global = stable_json(&(stdlib, &ir.anvil_config));
+
+        let mut benches = HashMap::new();
+        for suite in &ir.suites {
+            let setup = stable_json(&suite_setup(suite));
+            let setup_fixtures = referenced_fixtures(suite, &setup_code(suite));
+            for spec in &suite.benchmarks {
+                let mut hasher = Sha256::new();
+                hasher.update(global.as_bytes());
+                hasher.update(setup.as_bytes());
+                hasher.update(stable_json(&spec_inputs(spec)).as_bytes());
+
+                let mut fixtures = referenced_fixtures(suite, &bench_code(spec));
+                fixtures.extend(setup_fixtures.iter().copied());
+                fixtures
+                    .extend(suite.fixtures.iter().filter(|f| spec.fixture_refs.contains(&f.name)));
+                fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+                fixtures.dedup_by(|a, b| a.name == b.name);
+                for fixture in fixtures {
+                    hasher.update(fixture_inputs(fixture).to_string().as_bytes());
+                }
+
+                let mut langs: Vec<Lang> = spec.implementations.keys().copied().collect();
+                langs.sort_by_key(|l| l.as_str());
+                for lang in langs {
+                    hasher.update(lang.as_str().as_bytes());
+                    hasher.update(env.get(lang).as_bytes());
+                }
+
+                let key = BenchKey { suite: suite.name.clone(), name: spec.name.clone() };
+                benches.insert(key, format!("{:x}", hasher.finalize()));
+            }
+        }
+        Self { benches }
+    }
+
+    /// Benchmarks that are new or whose inputs differ from `previous`, in sorted order
+    pub fn changed_since(&self, previous: &Fingerprints) -> Vec<BenchKey> {
+        let mut changed: Vec<BenchKey> = self
+            .benches
+            .iter()
+            .filter(|(key, hash)| previous.benches.get(*key) != Some(*hash))
+            .map(|(key, _)| key.clone())
+            .collect();
+        changed.sort();
+        changed
+    }
+
+    /// Fingerprint of one benchmark
+    pub fn get(&self, suite: &str, name: &str) -> Option<&str> {
+        self.benches
+            .get(&BenchKey { suite: suite.to_string(), name: name.to_string() })
+            .map(String::as_str)
+    }
+
+    /// Number of fingerprinted benchmarks
+    pub fn len(&self) -> usize {
+        self.benches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.benches.is_empty()
+    }
+}
+
+/// Drop every benchmark not in `keys` (and suites left empty) from the IR
+pub fn retain_benchmarks(ir: &mut BenchmarkIR, keys: &HashSet<BenchKey>) {
+    for suite in &mut ir.suites {
+        let suite_name = suite.name.clone();
+        suite.benchmarks.retain(|spec| {
+            keys.contains(&BenchKey { suite: suite_name.clone(), name: spec.name.clone() })
+        });
+    }
+    ir.suites.retain(|s| !s.benchmarks.is_empty());
+}
+
+/// Fixture data files referenced by a parsed `.bench` file, resolved against `base_dir`
+pub fn fixture_files(ast: &File, base_dir: Option<&Path>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = ast
+        .suites
+        .iter()
+        .flat_map(|suite| &suite.fixtures)
+        .flat_map(|fixture| [fixture.data_file.as_ref(), fixture.hex_file.as_ref()])
+        .flatten()
+        .map(|file| {
+            let path = PathBuf::from(file);
+            match base_dir {
+                Some(base) if path.is_relative() => base.join(path),
+                _ => path,
+            }
+        })
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Modification time and size of each watched path, used to poll for changes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileSnapshot {
+    entries: BTreeMap<PathBuf, Option<(SystemTime, u64)>>,
+}
+
+impl FileSnapshot {
+    /// Stat every path; missing paths are recorded so that their creation is noticed
+    pub fn capture<'a>(paths: impl IntoIterator<Item = &'a PathBuf>) -> Self {
+        let entries = paths.into_iter().map(|path| (path.clone(), stamp(path))).collect();
+        Self { entries }
+    }
+
+    /// The snapshot of `paths`: stamps already recorded are kept, the rest are statted now
+    pub fn with_paths<'a>(&self, paths: impl IntoIterator<Item = &'a PathBuf>) -> Self {
+        let entries = paths
+            .into_iter()
+            .map(|path| {
+                let recorded = self.entries.get(path).copied();
+                (path.clone(), recorded.unwrap_or_else(|| stamp(path)))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Paths added, removed or modified between `self` and `newer`
+    pub fn changed_paths(&self, newer: &FileSnapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = newer
+            .entries
+            .iter()
+            .filter(|(path, stamp)| self.entries.get(*path) != Some(*stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(self.entries.keys().filter(|p| !newer.entries.contains_key(*p)).cloned());
+        changed.sort();
+        changed.dedup();
+        changed
+    }
+}
+
+/// Modification time and size of a path, `None` when it does not exist
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+/// Suite-level inputs shared by all of the suite's benchmarks.
+///
+/// Source locations are left out: shifting lines changes error mapping, not measurements.
+fn suite_setup(suite: &SuiteIR) -> Value {
+    let mut value = serde_json::to_value(suite).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        for field in [
+            "name",
+            "description",
+            "benchmarks",
+            "fixtures",
+            "imports_source",
+            "declarations_source",
+            "init_source",
+            "helpers_source",
+        ] {
+            map.remove(field);
+        }
+    }
+    value
+}
+
+fn spec_inputs(spec: &BenchmarkSpec) -> Value {
+    let mut value = serde_json::to_value(spec).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        map.remove("description");
+        map.remove("implementation_sources");
+    }
+    value
+}
+
+/// A fixture's data and code. The external file is left out: its path follows from the data
+/// and from where fixture files are kept, which says nothing about the benchmark.
+fn fixture_inputs(fixture: &FixtureIR) -> Value {
+    let mut value = serde_json::to_value(fixture).unwrap_or(Value::Null);
+    if let Value::Object(map) = &mut value {
+        map.remove("external_file");
+    }
+    value
+}
+
+/// Serialize through `Value` so that map keys come out sorted
+fn stable_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value).map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn setup_code(suite: &SuiteIR) -> Vec<&str> {
+    suite
+        .declarations
+        .values()
+        .chain(suite.init_code.values())
+        .chain(suite.helpers.values())
+        .map(String::as_str)
+        .collect()
+}
+
+fn bench_code(spec: &BenchmarkSpec) -> Vec<&str> {
+    spec.implementations
+        .values()
+        .chain(spec.before_hooks.values())
+        .chain(spec.after_hooks.values())
+        .chain(spec.each_hooks.values())
+        .chain(spec.validations.values())
+        .map(String::as_str)
+        .collect()
+}
+
+/// Fixtures whose name appears as an identifier in any of the given code snippets
+fn referenced_fixtures<'a>(suite: &'a SuiteIR, code: &[&str]) -> Vec<&'a FixtureIR> {
+    suite
+        .fixtures
+        .iter()
+        .filter(|fixture| code.iter().any(|snippet| mentions_identifier(snippet, &fixture.name)))
+        .collect()
+}
+
+fn mentions_identifier(code: &str, ident: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    code.match_indices(ident).any(|(start, _)| {
+        let before = code[..start].chars().next_back();
+        let after = code[start + ident.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::parse;
+
+    const SOURCE: &str = r#"
+declare suite hashing performance timeBased sameDataset: false {
+    setup go {
+        helpers {
+            func double(x int) int { return x * 2 }
+        }
+    }
+
+    fixture small {
+        hex: "0102"
+    }
+
+    fixture large {
+        hex: "01020304"
+    }
+
+    bench uses_small {
+        go: sum(small)
+    }
+
+    bench uses_large {
+        go: sum(large)
+    }
+
+    bench no_fixture {
+        go: double(21)
+    }
+}
+"#;
+
+    fn fingerprints(source: &str) -> Fingerprints {
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = poly_bench_ir::lower(&ast, None).unwrap();
+        Fingerprints::of(&ir, &EnvFingerprints::default())
+    }
+
+    fn names(keys: Vec<BenchKey>) -> Vec<String> {
+        keys.into_iter().map(|k| k.to_string()).collect()
+    }
+
+    #[test]
+    fn test_unchanged_ir_reruns_nothing() {
+        let before = fingerprints(SOURCE);
+        assert_eq!(before.len(), 3);
+        assert!(fingerprints(SOURCE).changed_since(&before).is_empty());
+        assert_eq!(before.changed_since(&Fingerprints::default()).len(), 3);
+    }
+
+    #[test]
+    fn test_spec_and_fixture_changes_are_scoped() {
+        let before = fingerprints(SOURCE);
+
+        let edited = SOURCE.replace("double(21)", "double(42)");
+        assert_eq!(names(fingerprints(&edited).changed_since(&before)), ["hashing.no_fixture"]);
+
+        let edited = SOURCE.replace("\"01020304\"", "\"0102030405\"");
+        assert_eq!(names(fingerprints(&edited).changed_since(&before)), ["hashing.uses_large"]);
+
+        // Moving code around without changing it does not count as a change
+        let edited = SOURCE.replace("    bench uses_small", "\n\n    bench uses_small");
+        assert!(fingerprints(&edited).changed_since(&before).is_empty());
+    }
+
+    #[test]
+    fn test_external_fixture_location_is_not_an_input() {
+        let ast = parse(SOURCE, "test.bench").unwrap();
+        let mut ir = poly_bench_ir::lower(&ast, None).unwrap();
+        let before = Fingerprints::of(&ir, &EnvFingerprints::default());
+        let large = ir.suites[0].fixtures.iter_mut().find(|f| f.name == "large").unwrap();
+        large.external_file = Some(PathBuf::from("/elsewhere/large.bin"));
+        let after = Fingerprints::of(&ir, &EnvFingerprints::default());
+        assert!(after.changed_since(&before).is_empty());
+    }
+
+    #[test]
+    fn test_setup_change_reruns_whole_suite() {
+        let before = fingerprints(SOURCE);
+        let edited = SOURCE.replace("x * 2", "x << 1");
+        assert_eq!(fingerprints(&edited).changed_since(&before).len(), 3);
+    }
+
+    #[test]
+    fn test_manifest_change_reruns_benchmarks_of_that_language() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("go.mod"), "module bench\n").unwrap();
+        let mut roots = ProjectRoots::default();
+        roots.set_root(Lang::Go, Some(dir.path().to_path_buf()));
+
+        let ast = parse(SOURCE, "test.bench").unwrap();
+        let ir = poly_bench_ir::lower(&ast, None).unwrap();
+        let before = Fingerprints::of(&ir, &EnvFingerprints::scan(&roots));
+        assert!(Fingerprints::of(&ir, &EnvFingerprints::scan(&roots))
+            .changed_since(&before)
+            .is_empty());
+
+        std::fs::write(dir.path().join("go.mod"), "module bench\n\ngo 1.22\n").unwrap();
+        let after = Fingerprints::of(&ir, &EnvFingerprints::scan(&roots));
+        assert_eq!(after.changed_since(&before).len(), 3);
+        assert_eq!(project_paths(&roots), [dir.path().join("go.mod"), dir.path().join("go.sum")]);
+    }
+
+    #[test]
+    fn test_source_change_in_project_root_reruns_benchmarks_of_that_language() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("go.mod"), "module bench\n").unwrap();
+        let helper = dir.path().join("helpers.go");
+        std::fs::write(&helper, "package main\n\nfunc sum(b []byte) int { return 0 }\n").unwrap();
+        std::fs::write(dir.path().join("bench_standalone.go"), "package main\n").unwrap();
+        let mut roots = ProjectRoots::default();
+        roots.set_root(Lang::Go, Some(dir.path().to_path_buf()));
+
+        // Generated harness sources are neither watched nor fingerprinted
+        let paths = project_paths(&roots);
+        assert!(paths.contains(&helper));
+        assert!(!paths.contains(&dir.path().join("bench_standalone.go")));
+
+        let ast = parse(SOURCE, "test.bench").unwrap();
+        let ir = poly_bench_ir::lower(&ast, None).unwrap();
+        let before = Fingerprints::of(&ir, &EnvFingerprints::scan(&roots));
+        std::fs::write(dir.path().join("bench_standalone.go"), "package main\n\n").unwrap();
+        assert!(Fingerprints::of(&ir, &EnvFingerprints::scan(&roots))
+            .changed_since(&before)
+            .is_empty());
+
+        std::fs::write(&helper, "package main\n\nfunc sum(b []byte) int { return 1 }\n").unwrap();
+        let after = Fingerprints::of(&ir, &EnvFingerprints::scan(&roots));
+        assert_eq!(after.changed_since(&before).len(), 3);
+    }
+
+    #[test]
+    fn test_retain_benchmarks() {
+        let ast = parse(SOURCE, "test.bench").unwrap();
+        let mut ir = poly_bench_ir::lower(&ast, None).unwrap();
+        let keep: HashSet<BenchKey> =
+            [BenchKey { suite: "hashing".to_string(), name: "uses_large".to_string() }].into();
+        retain_benchmarks(&mut ir, &keep);
+        assert_eq!(ir.benchmark_count(), 1);
+        assert_eq!(ir.suites[0].benchmarks[0].name, "uses_large");
+    }
+
+    #[test]
+    fn test_snapshot_detects_modified_and_created_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let existing = dir.path().join("a.bench");
+        let missing = dir.path().join("data.hex");
+        std::fs::write(&existing, "a").unwrap();
+        let paths = vec![existing.clone(), missing.clone()];
+
+        let before = FileSnapshot::capture(&paths);
+        assert!(before.changed_paths(&FileSnapshot::capture(&paths)).is_empty());
+
+        std::fs::write(&existing, "ab").unwrap();
+        std::fs::write(&missing, "00").unwrap();
+        assert_eq!(before.changed_paths(&FileSnapshot::capture(&paths)), [existing, missing]);
+    }
+
+    #[test]
+    fn test_snapshot_with_paths_keeps_recorded_stamps() {
+        let dir = tempfile::tempdir().unwrap();
+        let bench = dir.path().join("a.bench");
+        let helper = dir.path().join("helpers.go");
+        std::fs::write(&bench, "a").unwrap();
+        std::fs::write(&helper, "package main").unwrap();
+
+        // A file saved after the snapshot still counts as changed once more paths are added
+        let before = FileSnapshot::capture(&vec![bench.clone()]);
+        std::fs::write(&bench, "ab").unwrap();
+        let paths = vec![bench.clone(), helper.clone()];
+        let snapshot = before.with_paths(&paths);
+        assert_eq!(snapshot.changed_paths(&FileSnapshot::capture(&paths)), [bench]);
+    }
+
+    #[test]
+    fn test_mentions_identifier() {
+        assert!(mentions_identifier("sum(small)", "small"));
+        assert!(!mentions_identifier("sum(small_data)", "small"));
+        assert!(!mentions_identifier("smallest", "small"));
+    }
+}
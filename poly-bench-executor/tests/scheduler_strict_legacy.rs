@@ -1,5 +1,7 @@
-use poly_bench_dsl::{BenchmarkKind, FairnessMode, Lang};
-use poly_bench_executor::{comparison::BenchmarkResult, run, ProjectRoots, RunOptions};
+use poly_bench_dsl::{BenchmarkKind, ExecutionOrder, FairnessMode, Lang};
+use poly_bench_executor::{
+    comparison::BenchmarkResult, run, ProjectRoots, RunOptions, PARALLEL_COMPARISON_MODE,
+};
 use poly_bench_ir::{BenchmarkIR, BenchmarkSpec, SuiteIR};
 use poly_bench_runtime::measurement::Measurement;
 use std::collections::HashMap;
@@ -110,3 +112,23 @@ async fn test_scheduler_emits_strict_and_legacy_modes() {
     assert_eq!(strict_seed, Some(5));
     assert_eq!(legacy_mode, "legacy");
 }
+
+#[tokio::test]
+async fn test_scheduler_emits_parallel_mode() {
+    let mut suite = SuiteIR::new("parallel_suite".to_string());
+    suite.order = ExecutionOrder::Parallel;
+    let mut bench = BenchmarkSpec::new("bench".to_string(), "parallel_suite", 1, 0, 0);
+    bench.kind = BenchmarkKind::Sync;
+    bench.fairness_mode = FairnessMode::Strict;
+    suite.benchmarks.push(bench);
+
+    let opts = RunOptions { workers: Some(2), pin_cpus: true, ..Default::default() };
+    let results = run(&BenchmarkIR::new(vec![suite]), &[], None, &ProjectRoots::default(), &opts)
+        .await
+        .unwrap();
+
+    let result = &results.suites[0].benchmarks[0];
+    assert_eq!(result.comparison_mode, PARALLEL_COMPARISON_MODE);
+    assert!(result.is_parallel());
+    assert!(results.suites[0].is_parallel());
+}
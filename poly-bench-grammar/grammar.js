@@ -246,9 +246,29 @@ module.exports = grammar({
       choice(
         $.string,
         $.file_ref,
+        $.generator_call,
       ),
     ),
 
+    // Deterministic fixture generators: @random(...), @sequence(...), @repeat(...)
+    generator_call: $ => seq(
+      '@',
+      field('name', $.identifier),
+      '(',
+      optional(seq(
+        $.generator_arg,
+        repeat(seq(',', $.generator_arg)),
+        optional(','),
+      )),
+      ')',
+    ),
+
+    generator_arg: $ => seq(
+      field('key', $.identifier),
+      ':',
+      field('value', choice($.number, $.string)),
+    ),
+
     encoding_property: $ => seq(
       'encoding',
       ':',
@@ -287,9 +307,42 @@ module.exports = grammar({
     benchmark: $ => seq(
       choice('bench', 'benchAsync'),
       field('name', $.identifier),
+      optional(field('params', $.bench_params)),
       $.benchmark_body,
     ),
 
+    // Parameter matrix: bench sort(n in [100, 1000], order in ["asc", "desc"])
+    bench_params: $ => seq(
+      '(',
+      $.bench_param,
+      repeat(seq(',', $.bench_param)),
+      optional(','),
+      ')',
+    ),
+
+    bench_param: $ => seq(
+      field('name', $.identifier),
+      'in',
+      field('values', $.param_value_array),
+    ),
+
+    param_value_array: $ => seq(
+      '[',
+      optional(seq(
+        $._param_value,
+        repeat(seq(',', $._param_value)),
+        optional(','),
+      )),
+      ']',
+    ),
+
+    _param_value: $ => choice(
+      $.string,
+      $.number,
+      $.float,
+      $.boolean,
+    ),
+
     benchmark_body: $ => seq(
       '{',
       repeat($._benchmark_item),
@@ -469,7 +522,13 @@ module.exports = grammar({
       'outlierDetection',
       'cvThreshold',
       'count',
+      'ciTarget',
+      'maxRuns',
+      'maxTime',
       'memory',
+      'counters',
+      'equivalent',
+      'external',
       'fairness',
       'fairnessSeed',
       'asyncSamplingPolicy',
@@ -27,6 +27,7 @@
 ; Benchmark keywords
 "bench" @keyword
 "fixture" @keyword
+"in" @keyword
 
 ; Hook keywords
 "before" @keyword
@@ -7,16 +7,16 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 251
+#define STATE_COUNT 437
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 188
+#define SYMBOL_COUNT 205
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 105
+#define TOKEN_COUNT 113
 #define EXTERNAL_TOKEN_COUNT 2
-#define FIELD_COUNT 9
+#define FIELD_COUNT 12
 #define MAX_ALIAS_SEQUENCE_LENGTH 9
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 11
+#define PRODUCTION_ID_COUNT 14
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
@@ -50,163 +50,180 @@ enum ts_symbol_identifiers {
   anon_sym_fixture = 28,
   anon_sym_hex = 29,
   anon_sym_data = 30,
-  anon_sym_encoding = 31,
-  anon_sym_format = 32,
-  anon_sym_selector = 33,
-  anon_sym_shape = 34,
-  anon_sym_ATfile = 35,
-  anon_sym_bench = 36,
-  anon_sym_benchAsync = 37,
-  anon_sym_tags = 38,
-  anon_sym_skip = 39,
-  anon_sym_validate = 40,
-  anon_sym_before = 41,
-  anon_sym_after = 42,
-  anon_sym_each = 43,
-  anon_sym_charting = 44,
-  anon_sym_drawSpeedupChart = 45,
-  anon_sym_drawTable = 46,
-  anon_sym_drawLineChart = 47,
-  anon_sym_drawBarChart = 48,
-  anon_sym_title = 49,
-  anon_sym_description = 50,
-  anon_sym_output = 51,
-  anon_sym_sortBy = 52,
-  anon_sym_sortOrder = 53,
-  anon_sym_baselineBenchmark = 54,
-  anon_sym_baseline = 55,
-  anon_sym_filterWinner = 56,
-  anon_sym_theme = 57,
-  anon_sym_width = 58,
-  anon_sym_rowCount = 59,
-  anon_sym_height = 60,
-  anon_sym_limit = 61,
-  anon_sym_minSpeedup = 62,
-  anon_sym_includeBenchmarks = 63,
-  anon_sym_excludeBenchmarks = 64,
-  anon_sym_showStdDev = 65,
-  anon_sym_showErrorBars = 66,
-  anon_sym_showRegression = 67,
-  anon_sym_regressionModel = 68,
-  anon_sym_yScale = 69,
-  anon_sym_showStatsTable = 70,
-  anon_sym_iterations = 71,
-  anon_sym_warmup = 72,
-  anon_sym_timeout = 73,
-  anon_sym_requires = 74,
-  anon_sym_order = 75,
-  anon_sym_mode = 76,
-  anon_sym_targetTime = 77,
-  anon_sym_sink = 78,
-  anon_sym_outlierDetection = 79,
-  anon_sym_cvThreshold = 80,
-  anon_sym_count = 81,
-  anon_sym_fairness = 82,
-  anon_sym_fairnessSeed = 83,
-  anon_sym_asyncSamplingPolicy = 84,
-  anon_sym_asyncWarmupCap = 85,
-  anon_sym_asyncSampleCap = 86,
-  sym_inline_code = 87,
-  anon_sym_DQUOTE = 88,
-  anon_sym_SQUOTE = 89,
-  aux_sym_string_content_token1 = 90,
-  aux_sym_single_string_content_token1 = 91,
-  sym_escape_sequence = 92,
-  sym_number = 93,
-  sym_float = 94,
-  anon_sym_ms = 95,
-  anon_sym_s = 96,
-  anon_sym_m = 97,
-  anon_sym_true = 98,
-  anon_sym_false = 99,
-  anon_sym_LBRACK = 100,
-  anon_sym_RBRACK = 101,
-  sym_comment = 102,
-  sym_embedded_code = 103,
-  sym__embedded_code_start = 104,
-  sym_source_file = 105,
-  sym_use_statement = 106,
-  sym_global_setup = 107,
-  sym_global_setup_body = 108,
-  sym_global_setup_statement = 109,
-  sym_anvil_call = 110,
-  sym_anvil_args = 111,
-  sym_function_call = 112,
-  sym_argument_list = 113,
-  sym_argument = 114,
-  sym_suite = 115,
-  sym_suite_type = 116,
-  sym_run_mode = 117,
-  sym_suite_body = 118,
-  sym__suite_item = 119,
-  sym_setup_block = 120,
-  sym_setup_body = 121,
-  sym__setup_section = 122,
-  sym_import_section = 123,
-  sym_declare_section = 124,
-  sym_init_section = 125,
-  sym_helpers_section = 126,
-  sym_fixture = 127,
-  sym_fixture_params = 128,
-  sym_fixture_param = 129,
-  sym_fixture_body = 130,
-  sym__fixture_item = 131,
-  sym_hex_property = 132,
-  sym_data_property = 133,
-  sym_encoding_property = 134,
-  sym_format_property = 135,
-  sym_selector_property = 136,
-  sym_shape_property = 137,
-  sym_file_ref = 138,
-  sym_benchmark = 139,
-  sym_benchmark_body = 140,
-  sym__benchmark_item = 141,
-  sym_tags_property = 142,
-  sym_skip_hook = 143,
-  sym_validate_hook = 144,
-  sym_before_hook = 145,
-  sym_after_hook = 146,
-  sym_each_hook = 147,
-  sym_hook_flat = 148,
-  sym_hook_grouped = 149,
-  sym_after_block = 150,
-  sym_after_body = 151,
-  sym_chart_directive = 152,
-  sym_chart_function_name = 153,
-  sym_chart_params = 154,
-  sym_chart_param = 155,
-  sym_chart_param_name = 156,
-  sym__chart_value = 157,
-  sym_property = 158,
-  sym_property_name = 159,
-  sym__value = 160,
-  sym_language_implementation = 161,
-  sym_language_tag = 162,
-  sym__code_or_inline = 163,
-  sym_code_block = 164,
-  sym_paren_code_block = 165,
-  sym_string = 166,
-  sym_string_content = 167,
-  sym_single_string_content = 168,
-  sym_duration = 169,
-  sym_duration_unit = 170,
-  sym_boolean = 171,
-  sym_string_array = 172,
-  aux_sym_source_file_repeat1 = 173,
-  aux_sym_source_file_repeat2 = 174,
-  aux_sym_global_setup_body_repeat1 = 175,
-  aux_sym_argument_list_repeat1 = 176,
-  aux_sym_suite_body_repeat1 = 177,
-  aux_sym_setup_body_repeat1 = 178,
-  aux_sym_fixture_params_repeat1 = 179,
-  aux_sym_fixture_body_repeat1 = 180,
-  aux_sym_benchmark_body_repeat1 = 181,
-  aux_sym_hook_grouped_repeat1 = 182,
-  aux_sym_after_body_repeat1 = 183,
-  aux_sym_chart_params_repeat1 = 184,
-  aux_sym_string_content_repeat1 = 185,
-  aux_sym_single_string_content_repeat1 = 186,
-  aux_sym_string_array_repeat1 = 187,
+  anon_sym_AT = 31,
+  anon_sym_encoding = 32,
+  anon_sym_format = 33,
+  anon_sym_selector = 34,
+  anon_sym_shape = 35,
+  anon_sym_ATfile = 36,
+  anon_sym_bench = 37,
+  anon_sym_benchAsync = 38,
+  anon_sym_in = 39,
+  anon_sym_LBRACK = 40,
+  anon_sym_RBRACK = 41,
+  anon_sym_tags = 42,
+  anon_sym_skip = 43,
+  anon_sym_validate = 44,
+  anon_sym_before = 45,
+  anon_sym_after = 46,
+  anon_sym_each = 47,
+  anon_sym_charting = 48,
+  anon_sym_drawSpeedupChart = 49,
+  anon_sym_drawTable = 50,
+  anon_sym_drawLineChart = 51,
+  anon_sym_drawBarChart = 52,
+  anon_sym_title = 53,
+  anon_sym_description = 54,
+  anon_sym_output = 55,
+  anon_sym_sortBy = 56,
+  anon_sym_sortOrder = 57,
+  anon_sym_baselineBenchmark = 58,
+  anon_sym_baseline = 59,
+  anon_sym_filterWinner = 60,
+  anon_sym_theme = 61,
+  anon_sym_width = 62,
+  anon_sym_rowCount = 63,
+  anon_sym_height = 64,
+  anon_sym_limit = 65,
+  anon_sym_minSpeedup = 66,
+  anon_sym_includeBenchmarks = 67,
+  anon_sym_excludeBenchmarks = 68,
+  anon_sym_showStdDev = 69,
+  anon_sym_showErrorBars = 70,
+  anon_sym_showRegression = 71,
+  anon_sym_regressionModel = 72,
+  anon_sym_yScale = 73,
+  anon_sym_showStatsTable = 74,
+  anon_sym_iterations = 75,
+  anon_sym_warmup = 76,
+  anon_sym_timeout = 77,
+  anon_sym_requires = 78,
+  anon_sym_order = 79,
+  anon_sym_mode = 80,
+  anon_sym_targetTime = 81,
+  anon_sym_sink = 82,
+  anon_sym_outlierDetection = 83,
+  anon_sym_cvThreshold = 84,
+  anon_sym_count = 85,
+  anon_sym_ciTarget = 86,
+  anon_sym_maxRuns = 87,
+  anon_sym_maxTime = 88,
+  anon_sym_counters = 89,
+  anon_sym_equivalent = 90,
+  anon_sym_external = 91,
+  anon_sym_fairness = 92,
+  anon_sym_fairnessSeed = 93,
+  anon_sym_asyncSamplingPolicy = 94,
+  anon_sym_asyncWarmupCap = 95,
+  anon_sym_asyncSampleCap = 96,
+  sym_inline_code = 97,
+  anon_sym_DQUOTE = 98,
+  anon_sym_SQUOTE = 99,
+  aux_sym_string_content_token1 = 100,
+  aux_sym_single_string_content_token1 = 101,
+  sym_escape_sequence = 102,
+  sym_number = 103,
+  sym_float = 104,
+  anon_sym_ms = 105,
+  anon_sym_s = 106,
+  anon_sym_m = 107,
+  anon_sym_true = 108,
+  anon_sym_false = 109,
+  sym_comment = 110,
+  sym_embedded_code = 111,
+  sym__embedded_code_start = 112,
+  sym_source_file = 113,
+  sym_use_statement = 114,
+  sym_global_setup = 115,
+  sym_global_setup_body = 116,
+  sym_global_setup_statement = 117,
+  sym_anvil_call = 118,
+  sym_anvil_args = 119,
+  sym_function_call = 120,
+  sym_argument_list = 121,
+  sym_argument = 122,
+  sym_suite = 123,
+  sym_suite_type = 124,
+  sym_run_mode = 125,
+  sym_suite_body = 126,
+  sym__suite_item = 127,
+  sym_setup_block = 128,
+  sym_setup_body = 129,
+  sym__setup_section = 130,
+  sym_import_section = 131,
+  sym_declare_section = 132,
+  sym_init_section = 133,
+  sym_helpers_section = 134,
+  sym_fixture = 135,
+  sym_fixture_params = 136,
+  sym_fixture_param = 137,
+  sym_fixture_body = 138,
+  sym__fixture_item = 139,
+  sym_hex_property = 140,
+  sym_data_property = 141,
+  sym_generator_call = 142,
+  sym_generator_arg = 143,
+  sym_encoding_property = 144,
+  sym_format_property = 145,
+  sym_selector_property = 146,
+  sym_shape_property = 147,
+  sym_file_ref = 148,
+  sym_benchmark = 149,
+  sym_bench_params = 150,
+  sym_bench_param = 151,
+  sym_param_value_array = 152,
+  sym__param_value = 153,
+  sym_benchmark_body = 154,
+  sym__benchmark_item = 155,
+  sym_tags_property = 156,
+  sym_skip_hook = 157,
+  sym_validate_hook = 158,
+  sym_before_hook = 159,
+  sym_after_hook = 160,
+  sym_each_hook = 161,
+  sym_hook_flat = 162,
+  sym_hook_grouped = 163,
+  sym_after_block = 164,
+  sym_after_body = 165,
+  sym_chart_directive = 166,
+  sym_chart_function_name = 167,
+  sym_chart_params = 168,
+  sym_chart_param = 169,
+  sym_chart_param_name = 170,
+  sym__chart_value = 171,
+  sym_property = 172,
+  sym_property_name = 173,
+  sym__value = 174,
+  sym_language_implementation = 175,
+  sym_language_tag = 176,
+  sym__code_or_inline = 177,
+  sym_code_block = 178,
+  sym_paren_code_block = 179,
+  sym_string = 180,
+  sym_string_content = 181,
+  sym_single_string_content = 182,
+  sym_duration = 183,
+  sym_duration_unit = 184,
+  sym_boolean = 185,
+  sym_string_array = 186,
+  aux_sym_source_file_repeat1 = 187,
+  aux_sym_source_file_repeat2 = 188,
+  aux_sym_global_setup_body_repeat1 = 189,
+  aux_sym_argument_list_repeat1 = 190,
+  aux_sym_suite_body_repeat1 = 191,
+  aux_sym_setup_body_repeat1 = 192,
+  aux_sym_fixture_params_repeat1 = 193,
+  aux_sym_fixture_body_repeat1 = 194,
+  aux_sym_generator_call_repeat1 = 195,
+  aux_sym_bench_params_repeat1 = 196,
+  aux_sym_param_value_array_repeat1 = 197,
+  aux_sym_benchmark_body_repeat1 = 198,
+  aux_sym_hook_grouped_repeat1 = 199,
+  aux_sym_after_body_repeat1 = 200,
+  aux_sym_chart_params_repeat1 = 201,
+  aux_sym_string_content_repeat1 = 202,
+  aux_sym_single_string_content_repeat1 = 203,
+  aux_sym_string_array_repeat1 = 204,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -241,6 +258,7 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_fixture] = "fixture",
   [anon_sym_hex] = "hex",
   [anon_sym_data] = "data",
+  [anon_sym_AT] = "@",
   [anon_sym_encoding] = "encoding",
   [anon_sym_format] = "format",
   [anon_sym_selector] = "selector",
@@ -248,6 +266,9 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_ATfile] = "@file",
   [anon_sym_bench] = "bench",
   [anon_sym_benchAsync] = "benchAsync",
+  [anon_sym_in] = "in",
+  [anon_sym_LBRACK] = "[",
+  [anon_sym_RBRACK] = "]",
   [anon_sym_tags] = "tags",
   [anon_sym_skip] = "skip",
   [anon_sym_validate] = "validate",
@@ -292,6 +313,12 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_outlierDetection] = "outlierDetection",
   [anon_sym_cvThreshold] = "cvThreshold",
   [anon_sym_count] = "count",
+  [anon_sym_ciTarget] = "ciTarget",
+  [anon_sym_maxRuns] = "maxRuns",
+  [anon_sym_maxTime] = "maxTime",
+  [anon_sym_counters] = "counters",
+  [anon_sym_equivalent] = "equivalent",
+  [anon_sym_external] = "external",
   [anon_sym_fairness] = "fairness",
   [anon_sym_fairnessSeed] = "fairnessSeed",
   [anon_sym_asyncSamplingPolicy] = "asyncSamplingPolicy",
@@ -310,8 +337,6 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_m] = "m",
   [anon_sym_true] = "true",
   [anon_sym_false] = "false",
-  [anon_sym_LBRACK] = "[",
-  [anon_sym_RBRACK] = "]",
   [sym_comment] = "comment",
   [sym_embedded_code] = "embedded_code",
   [sym__embedded_code_start] = "_embedded_code_start",
@@ -344,12 +369,18 @@ static const char * const ts_symbol_names[] = {
   [sym__fixture_item] = "_fixture_item",
   [sym_hex_property] = "hex_property",
   [sym_data_property] = "data_property",
+  [sym_generator_call] = "generator_call",
+  [sym_generator_arg] = "generator_arg",
   [sym_encoding_property] = "encoding_property",
   [sym_format_property] = "format_property",
   [sym_selector_property] = "selector_property",
   [sym_shape_property] = "shape_property",
   [sym_file_ref] = "file_ref",
   [sym_benchmark] = "benchmark",
+  [sym_bench_params] = "bench_params",
+  [sym_bench_param] = "bench_param",
+  [sym_param_value_array] = "param_value_array",
+  [sym__param_value] = "_param_value",
   [sym_benchmark_body] = "benchmark_body",
   [sym__benchmark_item] = "_benchmark_item",
   [sym_tags_property] = "tags_property",
@@ -391,6 +422,9 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_setup_body_repeat1] = "setup_body_repeat1",
   [aux_sym_fixture_params_repeat1] = "fixture_params_repeat1",
   [aux_sym_fixture_body_repeat1] = "fixture_body_repeat1",
+  [aux_sym_generator_call_repeat1] = "generator_call_repeat1",
+  [aux_sym_bench_params_repeat1] = "bench_params_repeat1",
+  [aux_sym_param_value_array_repeat1] = "param_value_array_repeat1",
   [aux_sym_benchmark_body_repeat1] = "benchmark_body_repeat1",
   [aux_sym_hook_grouped_repeat1] = "hook_grouped_repeat1",
   [aux_sym_after_body_repeat1] = "after_body_repeat1",
@@ -432,6 +466,7 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_fixture] = anon_sym_fixture,
   [anon_sym_hex] = anon_sym_hex,
   [anon_sym_data] = anon_sym_data,
+  [anon_sym_AT] = anon_sym_AT,
   [anon_sym_encoding] = anon_sym_encoding,
   [anon_sym_format] = anon_sym_format,
   [anon_sym_selector] = anon_sym_selector,
@@ -439,6 +474,9 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_ATfile] = anon_sym_ATfile,
   [anon_sym_bench] = anon_sym_bench,
   [anon_sym_benchAsync] = anon_sym_benchAsync,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_LBRACK] = anon_sym_LBRACK,
+  [anon_sym_RBRACK] = anon_sym_RBRACK,
   [anon_sym_tags] = anon_sym_tags,
   [anon_sym_skip] = anon_sym_skip,
   [anon_sym_validate] = anon_sym_validate,
@@ -483,6 +521,12 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_outlierDetection] = anon_sym_outlierDetection,
   [anon_sym_cvThreshold] = anon_sym_cvThreshold,
   [anon_sym_count] = anon_sym_count,
+  [anon_sym_ciTarget] = anon_sym_ciTarget,
+  [anon_sym_maxRuns] = anon_sym_maxRuns,
+  [anon_sym_maxTime] = anon_sym_maxTime,
+  [anon_sym_counters] = anon_sym_counters,
+  [anon_sym_equivalent] = anon_sym_equivalent,
+  [anon_sym_external] = anon_sym_external,
   [anon_sym_fairness] = anon_sym_fairness,
   [anon_sym_fairnessSeed] = anon_sym_fairnessSeed,
   [anon_sym_asyncSamplingPolicy] = anon_sym_asyncSamplingPolicy,
@@ -501,8 +545,6 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_m] = anon_sym_m,
   [anon_sym_true] = anon_sym_true,
   [anon_sym_false] = anon_sym_false,
-  [anon_sym_LBRACK] = anon_sym_LBRACK,
-  [anon_sym_RBRACK] = anon_sym_RBRACK,
   [sym_comment] = sym_comment,
   [sym_embedded_code] = sym_embedded_code,
   [sym__embedded_code_start] = sym__embedded_code_start,
@@ -535,12 +577,18 @@ static const TSSymbol ts_symbol_map[] = {
   [sym__fixture_item] = sym__fixture_item,
   [sym_hex_property] = sym_hex_property,
   [sym_data_property] = sym_data_property,
+  [sym_generator_call] = sym_generator_call,
+  [sym_generator_arg] = sym_generator_arg,
   [sym_encoding_property] = sym_encoding_property,
   [sym_format_property] = sym_format_property,
   [sym_selector_property] = sym_selector_property,
   [sym_shape_property] = sym_shape_property,
   [sym_file_ref] = sym_file_ref,
   [sym_benchmark] = sym_benchmark,
+  [sym_bench_params] = sym_bench_params,
+  [sym_bench_param] = sym_bench_param,
+  [sym_param_value_array] = sym_param_value_array,
+  [sym__param_value] = sym__param_value,
   [sym_benchmark_body] = sym_benchmark_body,
   [sym__benchmark_item] = sym__benchmark_item,
   [sym_tags_property] = sym_tags_property,
@@ -582,6 +630,9 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_setup_body_repeat1] = aux_sym_setup_body_repeat1,
   [aux_sym_fixture_params_repeat1] = aux_sym_fixture_params_repeat1,
   [aux_sym_fixture_body_repeat1] = aux_sym_fixture_body_repeat1,
+  [aux_sym_generator_call_repeat1] = aux_sym_generator_call_repeat1,
+  [aux_sym_bench_params_repeat1] = aux_sym_bench_params_repeat1,
+  [aux_sym_param_value_array_repeat1] = aux_sym_param_value_array_repeat1,
   [aux_sym_benchmark_body_repeat1] = aux_sym_benchmark_body_repeat1,
   [aux_sym_hook_grouped_repeat1] = aux_sym_hook_grouped_repeat1,
   [aux_sym_after_body_repeat1] = aux_sym_after_body_repeat1,
@@ -716,6 +767,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_AT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_encoding] = {
     .visible = true,
     .named = false,
@@ -744,6 +799,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_LBRACK] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RBRACK] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_tags] = {
     .visible = true,
     .named = false,
@@ -920,6 +987,30 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_ciTarget] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_maxRuns] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_maxTime] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_counters] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_equivalent] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_external] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_fairness] = {
     .visible = true,
     .named = false,
@@ -992,14 +1083,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_LBRACK] = {
-    .visible = true,
-    .named = false,
-  },
-  [anon_sym_RBRACK] = {
-    .visible = true,
-    .named = false,
-  },
   [sym_comment] = {
     .visible = true,
     .named = true,
@@ -1128,6 +1211,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_generator_call] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_generator_arg] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_encoding_property] = {
     .visible = true,
     .named = true,
@@ -1152,6 +1243,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_bench_params] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_bench_param] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_param_value_array] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym__param_value] = {
+    .visible = false,
+    .named = true,
+  },
   [sym_benchmark_body] = {
     .visible = true,
     .named = true,
@@ -1316,6 +1423,18 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_generator_call_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_bench_params_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_param_value_array_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_benchmark_body_repeat1] = {
     .visible = false,
     .named = false,
@@ -1348,70 +1467,88 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
 
 enum ts_field_identifiers {
   field_function = 1,
-  field_language = 2,
-  field_module = 3,
-  field_name = 4,
-  field_run_mode = 5,
-  field_same_dataset = 6,
-  field_suite_type = 7,
-  field_type = 8,
-  field_value = 9,
+  field_key = 2,
+  field_language = 3,
+  field_module = 4,
+  field_name = 5,
+  field_params = 6,
+  field_run_mode = 7,
+  field_same_dataset = 8,
+  field_suite_type = 9,
+  field_type = 10,
+  field_value = 11,
+  field_values = 12,
 };
 
 static const char * const ts_field_names[] = {
   [0] = NULL,
   [field_function] = "function",
+  [field_key] = "key",
   [field_language] = "language",
   [field_module] = "module",
   [field_name] = "name",
+  [field_params] = "params",
   [field_run_mode] = "run_mode",
   [field_same_dataset] = "same_dataset",
   [field_suite_type] = "suite_type",
   [field_type] = "type",
   [field_value] = "value",
+  [field_values] = "values",
 };
 
 static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
-  [2] = {.index = 1, .length = 1},
-  [3] = {.index = 2, .length = 1},
-  [4] = {.index = 3, .length = 1},
-  [5] = {.index = 4, .length = 2},
-  [6] = {.index = 6, .length = 4},
-  [7] = {.index = 10, .length = 4},
-  [8] = {.index = 14, .length = 1},
-  [9] = {.index = 15, .length = 2},
-  [10] = {.index = 17, .length = 1},
+  [2] = {.index = 1, .length = 2},
+  [3] = {.index = 3, .length = 4},
+  [4] = {.index = 7, .length = 1},
+  [5] = {.index = 8, .length = 4},
+  [6] = {.index = 12, .length = 1},
+  [7] = {.index = 13, .length = 1},
+  [8] = {.index = 14, .length = 2},
+  [9] = {.index = 16, .length = 2},
+  [10] = {.index = 18, .length = 2},
+  [11] = {.index = 20, .length = 2},
+  [12] = {.index = 22, .length = 1},
+  [13] = {.index = 23, .length = 1},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
-    {field_name, 1},
-  [1] =
     {field_module, 3},
-  [2] =
-    {field_name, 2},
-  [3] =
-    {field_language, 1},
-  [4] =
+  [1] =
     {field_name, 0},
     {field_value, 2},
-  [6] =
-    {field_name, 1},
-    {field_run_mode, 3},
-    {field_same_dataset, 6},
-    {field_suite_type, 2},
-  [10] =
+  [3] =
     {field_name, 2},
     {field_run_mode, 4},
     {field_same_dataset, 7},
     {field_suite_type, 3},
+  [7] =
+    {field_name, 2},
+  [8] =
+    {field_name, 1},
+    {field_run_mode, 3},
+    {field_same_dataset, 6},
+    {field_suite_type, 2},
+  [12] =
+    {field_name, 1},
+  [13] =
+    {field_language, 1},
   [14] =
-    {field_language, 0},
-  [15] =
     {field_name, 0},
     {field_type, 2},
-  [17] =
+  [16] =
+    {field_key, 0},
+    {field_value, 2},
+  [18] =
+    {field_name, 1},
+    {field_params, 2},
+  [20] =
+    {field_name, 0},
+    {field_values, 2},
+  [22] =
+    {field_language, 0},
+  [23] =
     {field_function, 2},
 };
 
@@ -1449,8 +1586,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [22] = 22,
   [23] = 23,
   [24] = 24,
-  [25] = 24,
-  [26] = 24,
+  [25] = 25,
+  [26] = 26,
   [27] = 27,
   [28] = 28,
   [29] = 29,
@@ -1463,8 +1600,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [36] = 36,
   [37] = 37,
   [38] = 38,
-  [39] = 39,
-  [40] = 40,
+  [39] = 38,
+  [40] = 38,
   [41] = 41,
   [42] = 42,
   [43] = 43,
@@ -1488,8 +1625,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [61] = 61,
   [62] = 62,
   [63] = 63,
-  [64] = 63,
-  [65] = 63,
+  [64] = 64,
+  [65] = 65,
   [66] = 66,
   [67] = 67,
   [68] = 68,
@@ -1507,7 +1644,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [80] = 80,
   [81] = 81,
   [82] = 82,
-  [83] = 24,
+  [83] = 83,
   [84] = 84,
   [85] = 85,
   [86] = 86,
@@ -1546,8 +1683,8 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [119] = 119,
   [120] = 120,
   [121] = 121,
-  [122] = 122,
-  [123] = 123,
+  [122] = 120,
+  [123] = 120,
   [124] = 124,
   [125] = 125,
   [126] = 126,
@@ -1561,10 +1698,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [134] = 134,
   [135] = 135,
   [136] = 136,
-  [137] = 137,
-  [138] = 138,
+  [137] = 134,
+  [138] = 135,
   [139] = 139,
-  [140] = 140,
+  [140] = 38,
   [141] = 141,
   [142] = 142,
   [143] = 143,
@@ -1623,14 +1760,14 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [196] = 196,
   [197] = 197,
   [198] = 198,
-  [199] = 199,
-  [200] = 200,
+  [199] = 97,
+  [200] = 99,
   [201] = 201,
   [202] = 202,
   [203] = 203,
   [204] = 204,
   [205] = 205,
-  [206] = 206,
+  [206] = 105,
   [207] = 207,
   [208] = 208,
   [209] = 209,
@@ -1672,9 +1809,195 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [245] = 245,
   [246] = 246,
   [247] = 247,
-  [248] = 217,
-  [249] = 217,
+  [248] = 248,
+  [249] = 249,
   [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 261,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
+  [279] = 279,
+  [280] = 280,
+  [281] = 281,
+  [282] = 282,
+  [283] = 283,
+  [284] = 284,
+  [285] = 285,
+  [286] = 286,
+  [287] = 287,
+  [288] = 288,
+  [289] = 289,
+  [290] = 290,
+  [291] = 291,
+  [292] = 292,
+  [293] = 293,
+  [294] = 294,
+  [295] = 295,
+  [296] = 296,
+  [297] = 297,
+  [298] = 298,
+  [299] = 299,
+  [300] = 300,
+  [301] = 301,
+  [302] = 302,
+  [303] = 303,
+  [304] = 304,
+  [305] = 305,
+  [306] = 306,
+  [307] = 307,
+  [308] = 308,
+  [309] = 309,
+  [310] = 310,
+  [311] = 311,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
+  [315] = 315,
+  [316] = 316,
+  [317] = 317,
+  [318] = 318,
+  [319] = 319,
+  [320] = 320,
+  [321] = 321,
+  [322] = 322,
+  [323] = 323,
+  [324] = 324,
+  [325] = 325,
+  [326] = 326,
+  [327] = 327,
+  [328] = 328,
+  [329] = 329,
+  [330] = 330,
+  [331] = 331,
+  [332] = 332,
+  [333] = 333,
+  [334] = 334,
+  [335] = 335,
+  [336] = 336,
+  [337] = 337,
+  [338] = 338,
+  [339] = 339,
+  [340] = 340,
+  [341] = 341,
+  [342] = 342,
+  [343] = 343,
+  [344] = 344,
+  [345] = 345,
+  [346] = 346,
+  [347] = 347,
+  [348] = 348,
+  [349] = 349,
+  [350] = 350,
+  [351] = 351,
+  [352] = 352,
+  [353] = 353,
+  [354] = 354,
+  [355] = 355,
+  [356] = 356,
+  [357] = 357,
+  [358] = 358,
+  [359] = 359,
+  [360] = 360,
+  [361] = 361,
+  [362] = 362,
+  [363] = 363,
+  [364] = 364,
+  [365] = 365,
+  [366] = 366,
+  [367] = 367,
+  [368] = 368,
+  [369] = 369,
+  [370] = 370,
+  [371] = 371,
+  [372] = 372,
+  [373] = 373,
+  [374] = 374,
+  [375] = 375,
+  [376] = 376,
+  [377] = 377,
+  [378] = 378,
+  [379] = 379,
+  [380] = 380,
+  [381] = 381,
+  [382] = 336,
+  [383] = 383,
+  [384] = 384,
+  [385] = 385,
+  [386] = 336,
+  [387] = 387,
+  [388] = 388,
+  [389] = 389,
+  [390] = 390,
+  [391] = 391,
+  [392] = 392,
+  [393] = 393,
+  [394] = 394,
+  [395] = 395,
+  [396] = 396,
+  [397] = 397,
+  [398] = 398,
+  [399] = 399,
+  [400] = 400,
+  [401] = 401,
+  [402] = 402,
+  [403] = 403,
+  [404] = 404,
+  [405] = 405,
+  [406] = 406,
+  [407] = 407,
+  [408] = 408,
+  [409] = 409,
+  [410] = 410,
+  [411] = 411,
+  [412] = 412,
+  [413] = 413,
+  [414] = 414,
+  [415] = 415,
+  [416] = 416,
+  [417] = 417,
+  [418] = 418,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
+  [422] = 422,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
+  [429] = 429,
+  [430] = 430,
+  [431] = 431,
+  [432] = 432,
+  [433] = 433,
+  [434] = 434,
+  [435] = 435,
+  [436] = 436,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1682,254 +2005,575 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(12);
+      if (eof) ADVANCE(39);
       ADVANCE_MAP(
-        '"', 26,
-        '#', 40,
-        '\'', 27,
-        '(', 18,
-        ')', 19,
-        ',', 21,
-        '.', 17,
-        ':', 20,
-        '@', 7,
-        '[', 38,
-        '\\', 10,
-        ']', 39,
-        '{', 14,
-        '}', 16,
+        '"', 40,
+        '#', 41,
+        '\'', 42,
+        '(', 43,
+        ')', 44,
+        ',', 45,
+        '.', 46,
+        ':', 48,
+        '@', 49,
+        '[', 51,
+        '\\', 52,
+        ']', 53,
+        '{', 54,
+        '}', 55,
       );
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(37);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 1:
-      if (lookahead == '"') ADVANCE(26);
-      if (lookahead == '#') ADVANCE(28);
-      if (lookahead == '\\') ADVANCE(10);
+      if (eof) ADVANCE(39);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(29);
-      if (lookahead != 0) ADVANCE(30);
+          lookahead == ' ') SKIP(1);
+      if (lookahead == '#') ADVANCE(41);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 2:
-      if (lookahead == '#') ADVANCE(40);
-      if (lookahead == ':') ADVANCE(5);
       if (('\t' <= lookahead && lookahead <= '\r') ||
           lookahead == ' ') SKIP(2);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (lookahead == ',') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == ']') ADVANCE(53);
+      if (lookahead == '{') ADVANCE(54);
+      if (lookahead == '}') ADVANCE(55);
       END_STATE();
     case 3:
-      if (lookahead == '#') ADVANCE(31);
-      if (lookahead == '\'') ADVANCE(27);
-      if (lookahead == '\\') ADVANCE(10);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(32);
-      if (lookahead != 0) ADVANCE(33);
+          lookahead == ' ') SKIP(3);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (lookahead == ',') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == ']') ADVANCE(53);
+      if (lookahead == '}') ADVANCE(55);
       END_STATE();
     case 4:
-      if (lookahead == '#') ADVANCE(23);
-      if (lookahead == '{') ADVANCE(15);
-      if (lookahead == '\n' ||
-          lookahead == '\r') SKIP(4);
-      if (('\t' <= lookahead && lookahead <= '\f') ||
-          lookahead == ' ') ADVANCE(24);
-      if (lookahead != 0) ADVANCE(25);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(4);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (lookahead == ',') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == '}') ADVANCE(55);
       END_STATE();
     case 5:
-      if (lookahead == ':') ADVANCE(13);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(5);
+      if (lookahead == '#') ADVANCE(41);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == '}') ADVANCE(55);
       END_STATE();
     case 6:
-      if (lookahead == 'e') ADVANCE(22);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(6);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 7:
-      if (lookahead == 'f') ADVANCE(8);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(7);
+      if (lookahead == '#') ADVANCE(41);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 8:
-      if (lookahead == 'i') ADVANCE(9);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(8);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == '[') ADVANCE(51);
       END_STATE();
     case 9:
-      if (lookahead == 'l') ADVANCE(6);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(9);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == ']') ADVANCE(53);
       END_STATE();
     case 10:
-      if (lookahead == '"' ||
-          lookahead == '\'' ||
-          lookahead == '\\' ||
-          lookahead == 'n' ||
-          lookahead == 'r' ||
-          lookahead == 't') ADVANCE(34);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(10);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 11:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(11);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == '@') ADVANCE(49);
       END_STATE();
     case 12:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(12);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (lookahead == ',') ADVANCE(45);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 13:
-      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(13);
+      if (lookahead == '#') ADVANCE(41);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      if (lookahead == '{') ADVANCE(54);
       END_STATE();
     case 14:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(57);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(58);
+      if (lookahead == '\\') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          lookahead != '\\') ADVANCE(56);
       END_STATE();
     case 15:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(60);
+      if (lookahead == '#') ADVANCE(61);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == '\\') ADVANCE(52);
       if (lookahead != 0 &&
-          lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(25);
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '#' &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(59);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(16);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ':') ADVANCE(48);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(anon_sym_DOT);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(17);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == '@') ADVANCE(62);
       END_STATE();
     case 18:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(18);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '(') ADVANCE(43);
+      if (lookahead == '{') ADVANCE(54);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(19);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == ']') ADVANCE(53);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(anon_sym_COLON);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(20);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      if (lookahead == '\t' ||
+          lookahead == 0x0b ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead == '\n' ||
+          lookahead == '\r') SKIP(21);
+      if (lookahead == '#') ADVANCE(65);
+      if (lookahead == '{') ADVANCE(66);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '#' &&
+          lookahead != '{') ADVANCE(63);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(anon_sym_ATfile);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(22);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(67);
       END_STATE();
     case 23:
-      ACCEPT_TOKEN(sym_inline_code);
-      if (lookahead == '\r') ADVANCE(40);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(23);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(23);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
+      if (lookahead == ',') ADVANCE(45);
       END_STATE();
     case 24:
-      ACCEPT_TOKEN(sym_inline_code);
-      if (lookahead == '#') ADVANCE(23);
-      if (lookahead == '{') ADVANCE(15);
-      if (lookahead == '\t' ||
-          lookahead == 0x0b ||
-          lookahead == '\f' ||
-          lookahead == ' ') ADVANCE(24);
-      if (lookahead != 0 &&
-          (lookahead < '\t' || '\r' < lookahead)) ADVANCE(25);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(24);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ',') ADVANCE(45);
+      if (lookahead == ']') ADVANCE(53);
       END_STATE();
     case 25:
-      ACCEPT_TOKEN(sym_inline_code);
-      if (lookahead != 0 &&
-          lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(25);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(25);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
       END_STATE();
     case 26:
-      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(26);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '{') ADVANCE(54);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(27);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '(') ADVANCE(43);
+      if (lookahead == '.') ADVANCE(46);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(aux_sym_string_content_token1);
-      if (lookahead == '\n') ADVANCE(30);
-      if (lookahead == '"' ||
-          lookahead == '\\') ADVANCE(40);
-      if (lookahead != 0) ADVANCE(28);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(28);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ':') ADVANCE(48);
+      if (lookahead == '{') ADVANCE(54);
       END_STATE();
     case 29:
-      ACCEPT_TOKEN(aux_sym_string_content_token1);
-      if (lookahead == '#') ADVANCE(28);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(29);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '#' &&
-          lookahead != '\\') ADVANCE(30);
+          lookahead == ' ') SKIP(29);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '}') ADVANCE(55);
       END_STATE();
     case 30:
-      ACCEPT_TOKEN(aux_sym_string_content_token1);
-      if (lookahead != 0 &&
-          lookahead != '"' &&
-          lookahead != '\\') ADVANCE(30);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(30);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ')') ADVANCE(44);
       END_STATE();
     case 31:
-      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
-      if (lookahead == '\n') ADVANCE(33);
-      if (lookahead == '\'' ||
-          lookahead == '\\') ADVANCE(40);
-      if (lookahead != 0) ADVANCE(31);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(31);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '[') ADVANCE(51);
       END_STATE();
     case 32:
-      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
-      if (lookahead == '#') ADVANCE(31);
+      if (eof) ADVANCE(39);
       if (('\t' <= lookahead && lookahead <= '\r') ||
-          lookahead == ' ') ADVANCE(32);
-      if (lookahead != 0 &&
-          lookahead != '\'' &&
-          lookahead != '\\') ADVANCE(33);
+          lookahead == ' ') SKIP(32);
+      if (lookahead == '#') ADVANCE(41);
       END_STATE();
     case 33:
-      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
-      if (lookahead != 0 &&
-          lookahead != '\'' &&
-          lookahead != '\\') ADVANCE(33);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(33);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ':') ADVANCE(68);
       END_STATE();
     case 34:
-      ACCEPT_TOKEN(sym_escape_sequence);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(34);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '.') ADVANCE(46);
       END_STATE();
     case 35:
-      ACCEPT_TOKEN(sym_number);
-      if (lookahead == '.') ADVANCE(11);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(35);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == ':') ADVANCE(48);
       END_STATE();
     case 36:
-      ACCEPT_TOKEN(sym_float);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(36);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '(') ADVANCE(43);
       END_STATE();
     case 37:
-      ACCEPT_TOKEN(sym_identifier);
-      if (('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(37);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(37);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(41);
       END_STATE();
     case 38:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') SKIP(38);
+      if (lookahead == '#') ADVANCE(41);
+      if (lookahead == '\'') ADVANCE(42);
       END_STATE();
     case 39:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 40:
+      ACCEPT_TOKEN(anon_sym_DQUOTE);
+      END_STATE();
+    case 41:
       ACCEPT_TOKEN(sym_comment);
       if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(40);
+          lookahead != '\n') ADVANCE(41);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
-  START_LEXER();
-  eof = lexer->eof(lexer);
-  switch (state) {
-    case 0:
-      ADVANCE_MAP(
-        'a', 1,
-        'b', 2,
-        'c', 3,
-        'd', 4,
-        'e', 5,
-        'f', 6,
-        'g', 7,
-        'h', 8,
-        'i', 9,
-        'l', 10,
-        'm', 11,
-        'o', 12,
-        'p', 13,
-        'r', 14,
-        's', 15,
-        't', 16,
+    case 42:
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(sym_number);
+      if (lookahead == '.') ADVANCE(69);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_AT);
+      if (lookahead == 'f') ADVANCE(70);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_identifier);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(50);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 52:
+      if (lookahead == '"' ||
+          lookahead == '\'' ||
+          lookahead == '\\' ||
+          lookahead == 'n' ||
+          lookahead == 'r' ||
+          lookahead == 't') ADVANCE(71);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(aux_sym_string_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(56);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(aux_sym_string_content_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(57);
+      if (lookahead == '"') ADVANCE(40);
+      if (lookahead == '#') ADVANCE(58);
+      if (lookahead == '\\') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '"' &&
+          lookahead != '#' &&
+          lookahead != '\\') ADVANCE(56);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(aux_sym_string_content_token1);
+      if (lookahead == '\n') ADVANCE(56);
+      if (lookahead == '"' ||
+          lookahead == '\\') ADVANCE(41);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '"' &&
+          lookahead != '\\') ADVANCE(58);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
+      if (lookahead != 0 &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(59);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
+      if (('\t' <= lookahead && lookahead <= '\r') ||
+          lookahead == ' ') ADVANCE(60);
+      if (lookahead == '#') ADVANCE(61);
+      if (lookahead == '\'') ADVANCE(42);
+      if (lookahead == '\\') ADVANCE(52);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '#' &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(59);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(aux_sym_single_string_content_token1);
+      if (lookahead == '\n') ADVANCE(59);
+      if (lookahead == '\'' ||
+          lookahead == '\\') ADVANCE(41);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\'' &&
+          lookahead != '\\') ADVANCE(61);
+      END_STATE();
+    case 62:
+      if (lookahead == 'f') ADVANCE(70);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_inline_code);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(63);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_inline_code);
+      if (lookahead == '\t' ||
+          lookahead == 0x0b ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(64);
+      if (lookahead == '#') ADVANCE(65);
+      if (lookahead == '{') ADVANCE(66);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          lookahead != '#' &&
+          lookahead != '{') ADVANCE(63);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_inline_code);
+      if (lookahead == '\r') ADVANCE(41);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(65);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(63);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(67);
+      END_STATE();
+    case 68:
+      if (lookahead == ':') ADVANCE(72);
+      END_STATE();
+    case 69:
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      END_STATE();
+    case 70:
+      if (lookahead == 'i') ADVANCE(74);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(sym_escape_sequence);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(sym_float);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(73);
+      END_STATE();
+    case 74:
+      if (lookahead == 'l') ADVANCE(75);
+      END_STATE();
+    case 75:
+      if (lookahead == 'e') ADVANCE(76);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(anon_sym_ATfile);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
+  START_LEXER();
+  eof = lexer->eof(lexer);
+  switch (state) {
+    case 0:
+      ADVANCE_MAP(
+        'a', 1,
+        'b', 2,
+        'c', 3,
+        'd', 4,
+        'e', 5,
+        'f', 6,
+        'g', 7,
+        'h', 8,
+        'i', 9,
+        'l', 10,
+        'm', 11,
+        'o', 12,
+        'p', 13,
+        'r', 14,
+        's', 15,
+        't', 16,
         'u', 17,
         'v', 18,
         'w', 19,
@@ -1949,1586 +2593,1698 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
       END_STATE();
     case 3:
       if (lookahead == 'h') ADVANCE(26);
-      if (lookahead == 'o') ADVANCE(27);
-      if (lookahead == 'v') ADVANCE(28);
+      if (lookahead == 'i') ADVANCE(27);
+      if (lookahead == 'o') ADVANCE(28);
+      if (lookahead == 'v') ADVANCE(29);
       END_STATE();
     case 4:
-      if (lookahead == 'a') ADVANCE(29);
-      if (lookahead == 'e') ADVANCE(30);
-      if (lookahead == 'r') ADVANCE(31);
+      if (lookahead == 'a') ADVANCE(30);
+      if (lookahead == 'e') ADVANCE(31);
+      if (lookahead == 'r') ADVANCE(32);
       END_STATE();
     case 5:
-      if (lookahead == 'a') ADVANCE(32);
-      if (lookahead == 'n') ADVANCE(33);
-      if (lookahead == 'x') ADVANCE(34);
+      if (lookahead == 'a') ADVANCE(33);
+      if (lookahead == 'n') ADVANCE(34);
+      if (lookahead == 'q') ADVANCE(35);
+      if (lookahead == 'x') ADVANCE(36);
       END_STATE();
     case 6:
-      if (lookahead == 'a') ADVANCE(35);
-      if (lookahead == 'i') ADVANCE(36);
-      if (lookahead == 'o') ADVANCE(37);
+      if (lookahead == 'a') ADVANCE(37);
+      if (lookahead == 'i') ADVANCE(38);
+      if (lookahead == 'o') ADVANCE(39);
       END_STATE();
     case 7:
-      if (lookahead == 'l') ADVANCE(38);
+      if (lookahead == 'l') ADVANCE(40);
       END_STATE();
     case 8:
-      if (lookahead == 'e') ADVANCE(39);
+      if (lookahead == 'e') ADVANCE(41);
       END_STATE();
     case 9:
-      if (lookahead == 'm') ADVANCE(40);
-      if (lookahead == 'n') ADVANCE(41);
-      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'm') ADVANCE(42);
+      if (lookahead == 'n') ADVANCE(43);
+      if (lookahead == 't') ADVANCE(44);
       END_STATE();
     case 10:
-      if (lookahead == 'i') ADVANCE(43);
+      if (lookahead == 'i') ADVANCE(45);
       END_STATE();
     case 11:
       ACCEPT_TOKEN(anon_sym_m);
-      if (lookahead == 'e') ADVANCE(44);
-      if (lookahead == 'i') ADVANCE(45);
-      if (lookahead == 'o') ADVANCE(46);
-      if (lookahead == 's') ADVANCE(47);
+      if (lookahead == 'a') ADVANCE(46);
+      if (lookahead == 'e') ADVANCE(47);
+      if (lookahead == 'i') ADVANCE(48);
+      if (lookahead == 'o') ADVANCE(49);
+      if (lookahead == 's') ADVANCE(50);
       END_STATE();
     case 12:
-      if (lookahead == 'r') ADVANCE(48);
-      if (lookahead == 'u') ADVANCE(49);
+      if (lookahead == 'r') ADVANCE(51);
+      if (lookahead == 'u') ADVANCE(52);
       END_STATE();
     case 13:
-      if (lookahead == 'e') ADVANCE(50);
+      if (lookahead == 'e') ADVANCE(53);
       END_STATE();
     case 14:
-      if (lookahead == 'e') ADVANCE(51);
-      if (lookahead == 'o') ADVANCE(52);
+      if (lookahead == 'e') ADVANCE(54);
+      if (lookahead == 'o') ADVANCE(55);
       END_STATE();
     case 15:
       ACCEPT_TOKEN(anon_sym_s);
       ADVANCE_MAP(
-        'a', 53,
-        'e', 54,
-        'h', 55,
-        'i', 56,
-        'k', 57,
-        'o', 58,
-        'p', 59,
-        't', 60,
-        'u', 61,
+        'a', 56,
+        'e', 57,
+        'h', 58,
+        'i', 59,
+        'k', 60,
+        'o', 61,
+        'p', 62,
+        't', 63,
+        'u', 64,
       );
       END_STATE();
     case 16:
-      if (lookahead == 'a') ADVANCE(62);
-      if (lookahead == 'h') ADVANCE(63);
-      if (lookahead == 'i') ADVANCE(64);
-      if (lookahead == 'r') ADVANCE(65);
+      if (lookahead == 'a') ADVANCE(65);
+      if (lookahead == 'h') ADVANCE(66);
+      if (lookahead == 'i') ADVANCE(67);
+      if (lookahead == 'r') ADVANCE(68);
       END_STATE();
     case 17:
-      if (lookahead == 's') ADVANCE(66);
+      if (lookahead == 's') ADVANCE(69);
       END_STATE();
     case 18:
-      if (lookahead == 'a') ADVANCE(67);
+      if (lookahead == 'a') ADVANCE(70);
       END_STATE();
     case 19:
-      if (lookahead == 'a') ADVANCE(68);
-      if (lookahead == 'i') ADVANCE(69);
+      if (lookahead == 'a') ADVANCE(71);
+      if (lookahead == 'i') ADVANCE(72);
       END_STATE();
     case 20:
-      if (lookahead == 'S') ADVANCE(70);
+      if (lookahead == 'S') ADVANCE(73);
       END_STATE();
     case 21:
-      if (lookahead == 't') ADVANCE(71);
+      if (lookahead == 't') ADVANCE(74);
       END_STATE();
     case 22:
-      if (lookahead == 'v') ADVANCE(72);
+      if (lookahead == 'v') ADVANCE(75);
       END_STATE();
     case 23:
-      if (lookahead == 'y') ADVANCE(73);
+      if (lookahead == 'y') ADVANCE(76);
       END_STATE();
     case 24:
-      if (lookahead == 's') ADVANCE(74);
+      if (lookahead == 's') ADVANCE(77);
       END_STATE();
     case 25:
-      if (lookahead == 'f') ADVANCE(75);
-      if (lookahead == 'n') ADVANCE(76);
+      if (lookahead == 'f') ADVANCE(78);
+      if (lookahead == 'n') ADVANCE(79);
       END_STATE();
     case 26:
-      if (lookahead == 'a') ADVANCE(77);
+      if (lookahead == 'a') ADVANCE(80);
       END_STATE();
     case 27:
-      if (lookahead == 'u') ADVANCE(78);
+      if (lookahead == 'T') ADVANCE(81);
       END_STATE();
     case 28:
-      if (lookahead == 'T') ADVANCE(79);
+      if (lookahead == 'u') ADVANCE(82);
       END_STATE();
     case 29:
-      if (lookahead == 't') ADVANCE(80);
+      if (lookahead == 'T') ADVANCE(83);
       END_STATE();
     case 30:
-      if (lookahead == 'c') ADVANCE(81);
-      if (lookahead == 's') ADVANCE(82);
+      if (lookahead == 't') ADVANCE(84);
       END_STATE();
     case 31:
-      if (lookahead == 'a') ADVANCE(83);
+      if (lookahead == 'c') ADVANCE(85);
+      if (lookahead == 's') ADVANCE(86);
       END_STATE();
     case 32:
-      if (lookahead == 'c') ADVANCE(84);
+      if (lookahead == 'a') ADVANCE(87);
       END_STATE();
     case 33:
-      if (lookahead == 'c') ADVANCE(85);
+      if (lookahead == 'c') ADVANCE(88);
       END_STATE();
     case 34:
-      if (lookahead == 'c') ADVANCE(86);
+      if (lookahead == 'c') ADVANCE(89);
       END_STATE();
     case 35:
-      if (lookahead == 'i') ADVANCE(87);
-      if (lookahead == 'l') ADVANCE(88);
+      if (lookahead == 'u') ADVANCE(90);
       END_STATE();
     case 36:
-      if (lookahead == 'l') ADVANCE(89);
-      if (lookahead == 'x') ADVANCE(90);
+      if (lookahead == 'c') ADVANCE(91);
+      if (lookahead == 't') ADVANCE(92);
       END_STATE();
     case 37:
-      if (lookahead == 'r') ADVANCE(91);
+      if (lookahead == 'i') ADVANCE(93);
+      if (lookahead == 'l') ADVANCE(94);
       END_STATE();
     case 38:
-      if (lookahead == 'o') ADVANCE(92);
+      if (lookahead == 'l') ADVANCE(95);
+      if (lookahead == 'x') ADVANCE(96);
       END_STATE();
     case 39:
-      if (lookahead == 'i') ADVANCE(93);
-      if (lookahead == 'l') ADVANCE(94);
-      if (lookahead == 'x') ADVANCE(95);
+      if (lookahead == 'r') ADVANCE(97);
       END_STATE();
     case 40:
-      if (lookahead == 'p') ADVANCE(96);
+      if (lookahead == 'o') ADVANCE(98);
       END_STATE();
     case 41:
-      if (lookahead == 'c') ADVANCE(97);
-      if (lookahead == 'i') ADVANCE(98);
+      if (lookahead == 'i') ADVANCE(99);
+      if (lookahead == 'l') ADVANCE(100);
+      if (lookahead == 'x') ADVANCE(101);
       END_STATE();
     case 42:
-      if (lookahead == 'e') ADVANCE(99);
+      if (lookahead == 'p') ADVANCE(102);
       END_STATE();
     case 43:
-      if (lookahead == 'm') ADVANCE(100);
+      ACCEPT_TOKEN(anon_sym_in);
+      if (lookahead == 'c') ADVANCE(103);
+      if (lookahead == 'i') ADVANCE(104);
       END_STATE();
     case 44:
-      if (lookahead == 'm') ADVANCE(101);
+      if (lookahead == 'e') ADVANCE(105);
       END_STATE();
     case 45:
-      if (lookahead == 'n') ADVANCE(102);
+      if (lookahead == 'm') ADVANCE(106);
       END_STATE();
     case 46:
-      if (lookahead == 'd') ADVANCE(103);
+      if (lookahead == 'x') ADVANCE(107);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(anon_sym_ms);
+      if (lookahead == 'm') ADVANCE(108);
       END_STATE();
     case 48:
-      if (lookahead == 'd') ADVANCE(104);
+      if (lookahead == 'n') ADVANCE(109);
       END_STATE();
     case 49:
-      if (lookahead == 't') ADVANCE(105);
+      if (lookahead == 'd') ADVANCE(110);
       END_STATE();
     case 50:
-      if (lookahead == 'r') ADVANCE(106);
+      ACCEPT_TOKEN(anon_sym_ms);
       END_STATE();
     case 51:
-      if (lookahead == 'g') ADVANCE(107);
-      if (lookahead == 'q') ADVANCE(108);
+      if (lookahead == 'd') ADVANCE(111);
       END_STATE();
     case 52:
-      if (lookahead == 'w') ADVANCE(109);
+      if (lookahead == 't') ADVANCE(112);
       END_STATE();
     case 53:
-      if (lookahead == 'm') ADVANCE(110);
+      if (lookahead == 'r') ADVANCE(113);
       END_STATE();
     case 54:
-      if (lookahead == 'l') ADVANCE(111);
-      if (lookahead == 't') ADVANCE(112);
+      if (lookahead == 'g') ADVANCE(114);
+      if (lookahead == 'q') ADVANCE(115);
       END_STATE();
     case 55:
-      if (lookahead == 'a') ADVANCE(113);
-      if (lookahead == 'o') ADVANCE(114);
+      if (lookahead == 'w') ADVANCE(116);
       END_STATE();
     case 56:
-      if (lookahead == 'n') ADVANCE(115);
+      if (lookahead == 'm') ADVANCE(117);
       END_STATE();
     case 57:
-      if (lookahead == 'i') ADVANCE(116);
+      if (lookahead == 'l') ADVANCE(118);
+      if (lookahead == 't') ADVANCE(119);
       END_STATE();
     case 58:
-      if (lookahead == 'r') ADVANCE(117);
+      if (lookahead == 'a') ADVANCE(120);
+      if (lookahead == 'o') ADVANCE(121);
       END_STATE();
     case 59:
-      if (lookahead == 'a') ADVANCE(118);
+      if (lookahead == 'n') ADVANCE(122);
       END_STATE();
     case 60:
-      if (lookahead == 'd') ADVANCE(119);
+      if (lookahead == 'i') ADVANCE(123);
       END_STATE();
     case 61:
-      if (lookahead == 'i') ADVANCE(120);
+      if (lookahead == 'r') ADVANCE(124);
       END_STATE();
     case 62:
-      if (lookahead == 'g') ADVANCE(121);
-      if (lookahead == 'r') ADVANCE(122);
+      if (lookahead == 'a') ADVANCE(125);
       END_STATE();
     case 63:
-      if (lookahead == 'e') ADVANCE(123);
+      if (lookahead == 'd') ADVANCE(126);
       END_STATE();
     case 64:
-      if (lookahead == 'm') ADVANCE(124);
-      if (lookahead == 't') ADVANCE(125);
+      if (lookahead == 'i') ADVANCE(127);
       END_STATE();
     case 65:
-      if (lookahead == 'u') ADVANCE(126);
+      if (lookahead == 'g') ADVANCE(128);
+      if (lookahead == 'r') ADVANCE(129);
       END_STATE();
     case 66:
-      if (lookahead == 'e') ADVANCE(127);
+      if (lookahead == 'e') ADVANCE(130);
       END_STATE();
     case 67:
-      if (lookahead == 'l') ADVANCE(128);
+      if (lookahead == 'm') ADVANCE(131);
+      if (lookahead == 't') ADVANCE(132);
       END_STATE();
     case 68:
-      if (lookahead == 'r') ADVANCE(129);
+      if (lookahead == 'u') ADVANCE(133);
       END_STATE();
     case 69:
-      if (lookahead == 'd') ADVANCE(130);
+      if (lookahead == 'e') ADVANCE(134);
       END_STATE();
     case 70:
-      if (lookahead == 'c') ADVANCE(131);
+      if (lookahead == 'l') ADVANCE(135);
       END_STATE();
     case 71:
-      if (lookahead == 'e') ADVANCE(132);
+      if (lookahead == 'r') ADVANCE(136);
       END_STATE();
     case 72:
-      if (lookahead == 'i') ADVANCE(133);
+      if (lookahead == 'd') ADVANCE(137);
       END_STATE();
     case 73:
-      if (lookahead == 'n') ADVANCE(134);
+      if (lookahead == 'c') ADVANCE(138);
       END_STATE();
     case 74:
-      if (lookahead == 'e') ADVANCE(135);
+      if (lookahead == 'e') ADVANCE(139);
       END_STATE();
     case 75:
-      if (lookahead == 'o') ADVANCE(136);
+      if (lookahead == 'i') ADVANCE(140);
       END_STATE();
     case 76:
-      if (lookahead == 'c') ADVANCE(137);
+      if (lookahead == 'n') ADVANCE(141);
       END_STATE();
     case 77:
-      if (lookahead == 'r') ADVANCE(138);
+      if (lookahead == 'e') ADVANCE(142);
       END_STATE();
     case 78:
-      if (lookahead == 'n') ADVANCE(139);
+      if (lookahead == 'o') ADVANCE(143);
       END_STATE();
     case 79:
-      if (lookahead == 'h') ADVANCE(140);
+      if (lookahead == 'c') ADVANCE(144);
       END_STATE();
     case 80:
-      if (lookahead == 'a') ADVANCE(141);
+      if (lookahead == 'r') ADVANCE(145);
       END_STATE();
     case 81:
-      if (lookahead == 'l') ADVANCE(142);
+      if (lookahead == 'a') ADVANCE(146);
       END_STATE();
     case 82:
-      if (lookahead == 'c') ADVANCE(143);
+      if (lookahead == 'n') ADVANCE(147);
       END_STATE();
     case 83:
-      if (lookahead == 'w') ADVANCE(144);
+      if (lookahead == 'h') ADVANCE(148);
       END_STATE();
     case 84:
-      if (lookahead == 'h') ADVANCE(145);
+      if (lookahead == 'a') ADVANCE(149);
       END_STATE();
     case 85:
-      if (lookahead == 'o') ADVANCE(146);
+      if (lookahead == 'l') ADVANCE(150);
       END_STATE();
     case 86:
-      if (lookahead == 'l') ADVANCE(147);
+      if (lookahead == 'c') ADVANCE(151);
       END_STATE();
     case 87:
-      if (lookahead == 'r') ADVANCE(148);
+      if (lookahead == 'w') ADVANCE(152);
       END_STATE();
     case 88:
-      if (lookahead == 's') ADVANCE(149);
+      if (lookahead == 'h') ADVANCE(153);
       END_STATE();
     case 89:
-      if (lookahead == 't') ADVANCE(150);
+      if (lookahead == 'o') ADVANCE(154);
       END_STATE();
     case 90:
-      if (lookahead == 't') ADVANCE(151);
+      if (lookahead == 'i') ADVANCE(155);
       END_STATE();
     case 91:
-      if (lookahead == 'k') ADVANCE(152);
-      if (lookahead == 'm') ADVANCE(153);
+      if (lookahead == 'l') ADVANCE(156);
       END_STATE();
     case 92:
-      if (lookahead == 'b') ADVANCE(154);
+      if (lookahead == 'e') ADVANCE(157);
       END_STATE();
     case 93:
-      if (lookahead == 'g') ADVANCE(155);
+      if (lookahead == 'r') ADVANCE(158);
       END_STATE();
     case 94:
-      if (lookahead == 'p') ADVANCE(156);
+      if (lookahead == 's') ADVANCE(159);
       END_STATE();
     case 95:
-      ACCEPT_TOKEN(anon_sym_hex);
+      if (lookahead == 't') ADVANCE(160);
       END_STATE();
     case 96:
-      if (lookahead == 'o') ADVANCE(157);
+      if (lookahead == 't') ADVANCE(161);
       END_STATE();
     case 97:
-      if (lookahead == 'l') ADVANCE(158);
+      if (lookahead == 'k') ADVANCE(162);
+      if (lookahead == 'm') ADVANCE(163);
       END_STATE();
     case 98:
-      if (lookahead == 't') ADVANCE(159);
+      if (lookahead == 'b') ADVANCE(164);
       END_STATE();
     case 99:
-      if (lookahead == 'r') ADVANCE(160);
+      if (lookahead == 'g') ADVANCE(165);
       END_STATE();
     case 100:
-      if (lookahead == 'i') ADVANCE(161);
+      if (lookahead == 'p') ADVANCE(166);
       END_STATE();
     case 101:
-      if (lookahead == 'o') ADVANCE(162);
+      ACCEPT_TOKEN(anon_sym_hex);
       END_STATE();
     case 102:
-      if (lookahead == 'S') ADVANCE(163);
+      if (lookahead == 'o') ADVANCE(167);
       END_STATE();
     case 103:
-      if (lookahead == 'e') ADVANCE(164);
+      if (lookahead == 'l') ADVANCE(168);
       END_STATE();
     case 104:
-      if (lookahead == 'e') ADVANCE(165);
+      if (lookahead == 't') ADVANCE(169);
       END_STATE();
     case 105:
-      if (lookahead == 'l') ADVANCE(166);
-      if (lookahead == 'p') ADVANCE(167);
+      if (lookahead == 'r') ADVANCE(170);
       END_STATE();
     case 106:
-      if (lookahead == 'f') ADVANCE(168);
+      if (lookahead == 'i') ADVANCE(171);
       END_STATE();
     case 107:
-      if (lookahead == 'r') ADVANCE(169);
+      if (lookahead == 'R') ADVANCE(172);
+      if (lookahead == 'T') ADVANCE(173);
       END_STATE();
     case 108:
-      if (lookahead == 'u') ADVANCE(170);
+      if (lookahead == 'o') ADVANCE(174);
       END_STATE();
     case 109:
-      if (lookahead == 'C') ADVANCE(171);
+      if (lookahead == 'S') ADVANCE(175);
       END_STATE();
     case 110:
-      if (lookahead == 'e') ADVANCE(172);
+      if (lookahead == 'e') ADVANCE(176);
       END_STATE();
     case 111:
-      if (lookahead == 'e') ADVANCE(173);
+      if (lookahead == 'e') ADVANCE(177);
       END_STATE();
     case 112:
-      if (lookahead == 'u') ADVANCE(174);
+      if (lookahead == 'l') ADVANCE(178);
+      if (lookahead == 'p') ADVANCE(179);
       END_STATE();
     case 113:
-      if (lookahead == 'p') ADVANCE(175);
+      if (lookahead == 'f') ADVANCE(180);
       END_STATE();
     case 114:
-      if (lookahead == 'w') ADVANCE(176);
+      if (lookahead == 'r') ADVANCE(181);
       END_STATE();
     case 115:
-      if (lookahead == 'k') ADVANCE(177);
+      if (lookahead == 'u') ADVANCE(182);
       END_STATE();
     case 116:
-      if (lookahead == 'p') ADVANCE(178);
+      if (lookahead == 'C') ADVANCE(183);
       END_STATE();
     case 117:
-      if (lookahead == 't') ADVANCE(179);
+      if (lookahead == 'e') ADVANCE(184);
       END_STATE();
     case 118:
-      if (lookahead == 'w') ADVANCE(180);
+      if (lookahead == 'e') ADVANCE(185);
       END_STATE();
     case 119:
-      ACCEPT_TOKEN(anon_sym_std);
+      if (lookahead == 'u') ADVANCE(186);
       END_STATE();
     case 120:
-      if (lookahead == 't') ADVANCE(181);
+      if (lookahead == 'p') ADVANCE(187);
       END_STATE();
     case 121:
-      if (lookahead == 's') ADVANCE(182);
+      if (lookahead == 'w') ADVANCE(188);
       END_STATE();
     case 122:
-      if (lookahead == 'g') ADVANCE(183);
+      if (lookahead == 'k') ADVANCE(189);
       END_STATE();
     case 123:
-      if (lookahead == 'm') ADVANCE(184);
+      if (lookahead == 'p') ADVANCE(190);
       END_STATE();
     case 124:
-      if (lookahead == 'e') ADVANCE(185);
+      if (lookahead == 't') ADVANCE(191);
       END_STATE();
     case 125:
-      if (lookahead == 'l') ADVANCE(186);
+      if (lookahead == 'w') ADVANCE(192);
       END_STATE();
     case 126:
-      if (lookahead == 'e') ADVANCE(187);
+      ACCEPT_TOKEN(anon_sym_std);
       END_STATE();
     case 127:
-      ACCEPT_TOKEN(anon_sym_use);
+      if (lookahead == 't') ADVANCE(193);
       END_STATE();
     case 128:
-      if (lookahead == 'i') ADVANCE(188);
+      if (lookahead == 's') ADVANCE(194);
       END_STATE();
     case 129:
-      if (lookahead == 'm') ADVANCE(189);
+      if (lookahead == 'g') ADVANCE(195);
       END_STATE();
     case 130:
-      if (lookahead == 't') ADVANCE(190);
+      if (lookahead == 'm') ADVANCE(196);
       END_STATE();
     case 131:
-      if (lookahead == 'a') ADVANCE(191);
+      if (lookahead == 'e') ADVANCE(197);
       END_STATE();
     case 132:
-      if (lookahead == 'r') ADVANCE(192);
+      if (lookahead == 'l') ADVANCE(198);
       END_STATE();
     case 133:
-      if (lookahead == 'l') ADVANCE(193);
+      if (lookahead == 'e') ADVANCE(199);
       END_STATE();
     case 134:
-      if (lookahead == 'c') ADVANCE(194);
+      ACCEPT_TOKEN(anon_sym_use);
       END_STATE();
     case 135:
-      if (lookahead == 'l') ADVANCE(195);
+      if (lookahead == 'i') ADVANCE(200);
       END_STATE();
     case 136:
-      if (lookahead == 'r') ADVANCE(196);
+      if (lookahead == 'm') ADVANCE(201);
       END_STATE();
     case 137:
-      if (lookahead == 'h') ADVANCE(197);
+      if (lookahead == 't') ADVANCE(202);
       END_STATE();
     case 138:
-      if (lookahead == 't') ADVANCE(198);
+      if (lookahead == 'a') ADVANCE(203);
       END_STATE();
     case 139:
-      if (lookahead == 't') ADVANCE(199);
+      if (lookahead == 'r') ADVANCE(204);
       END_STATE();
     case 140:
-      if (lookahead == 'r') ADVANCE(200);
+      if (lookahead == 'l') ADVANCE(205);
       END_STATE();
     case 141:
-      ACCEPT_TOKEN(anon_sym_data);
+      if (lookahead == 'c') ADVANCE(206);
       END_STATE();
     case 142:
-      if (lookahead == 'a') ADVANCE(201);
+      if (lookahead == 'l') ADVANCE(207);
       END_STATE();
     case 143:
-      if (lookahead == 'r') ADVANCE(202);
+      if (lookahead == 'r') ADVANCE(208);
       END_STATE();
     case 144:
-      if (lookahead == 'B') ADVANCE(203);
-      if (lookahead == 'L') ADVANCE(204);
-      if (lookahead == 'S') ADVANCE(205);
-      if (lookahead == 'T') ADVANCE(206);
+      if (lookahead == 'h') ADVANCE(209);
       END_STATE();
     case 145:
-      ACCEPT_TOKEN(anon_sym_each);
+      if (lookahead == 't') ADVANCE(210);
       END_STATE();
     case 146:
-      if (lookahead == 'd') ADVANCE(207);
+      if (lookahead == 'r') ADVANCE(211);
       END_STATE();
     case 147:
-      if (lookahead == 'u') ADVANCE(208);
+      if (lookahead == 't') ADVANCE(212);
       END_STATE();
     case 148:
-      if (lookahead == 'n') ADVANCE(209);
+      if (lookahead == 'r') ADVANCE(213);
       END_STATE();
     case 149:
-      if (lookahead == 'e') ADVANCE(210);
+      ACCEPT_TOKEN(anon_sym_data);
       END_STATE();
     case 150:
-      if (lookahead == 'e') ADVANCE(211);
+      if (lookahead == 'a') ADVANCE(214);
       END_STATE();
     case 151:
-      if (lookahead == 'u') ADVANCE(212);
+      if (lookahead == 'r') ADVANCE(215);
       END_STATE();
     case 152:
-      ACCEPT_TOKEN(anon_sym_fork);
+      if (lookahead == 'B') ADVANCE(216);
+      if (lookahead == 'L') ADVANCE(217);
+      if (lookahead == 'S') ADVANCE(218);
+      if (lookahead == 'T') ADVANCE(219);
       END_STATE();
     case 153:
-      if (lookahead == 'a') ADVANCE(213);
+      ACCEPT_TOKEN(anon_sym_each);
       END_STATE();
     case 154:
-      if (lookahead == 'a') ADVANCE(214);
+      if (lookahead == 'd') ADVANCE(220);
       END_STATE();
     case 155:
-      if (lookahead == 'h') ADVANCE(215);
+      if (lookahead == 'v') ADVANCE(221);
       END_STATE();
     case 156:
-      if (lookahead == 'e') ADVANCE(216);
+      if (lookahead == 'u') ADVANCE(222);
       END_STATE();
     case 157:
-      if (lookahead == 'r') ADVANCE(217);
+      if (lookahead == 'r') ADVANCE(223);
       END_STATE();
     case 158:
-      if (lookahead == 'u') ADVANCE(218);
+      if (lookahead == 'n') ADVANCE(224);
       END_STATE();
     case 159:
-      ACCEPT_TOKEN(anon_sym_init);
+      if (lookahead == 'e') ADVANCE(225);
       END_STATE();
     case 160:
-      if (lookahead == 'a') ADVANCE(219);
+      if (lookahead == 'e') ADVANCE(226);
       END_STATE();
     case 161:
-      if (lookahead == 't') ADVANCE(220);
+      if (lookahead == 'u') ADVANCE(227);
       END_STATE();
     case 162:
-      if (lookahead == 'r') ADVANCE(221);
+      ACCEPT_TOKEN(anon_sym_fork);
       END_STATE();
     case 163:
-      if (lookahead == 'p') ADVANCE(222);
+      if (lookahead == 'a') ADVANCE(228);
       END_STATE();
     case 164:
-      ACCEPT_TOKEN(anon_sym_mode);
+      if (lookahead == 'a') ADVANCE(229);
       END_STATE();
     case 165:
-      if (lookahead == 'r') ADVANCE(223);
+      if (lookahead == 'h') ADVANCE(230);
       END_STATE();
     case 166:
-      if (lookahead == 'i') ADVANCE(224);
+      if (lookahead == 'e') ADVANCE(231);
       END_STATE();
     case 167:
-      if (lookahead == 'u') ADVANCE(225);
+      if (lookahead == 'r') ADVANCE(232);
       END_STATE();
     case 168:
-      if (lookahead == 'o') ADVANCE(226);
+      if (lookahead == 'u') ADVANCE(233);
       END_STATE();
     case 169:
-      if (lookahead == 'e') ADVANCE(227);
+      ACCEPT_TOKEN(anon_sym_init);
       END_STATE();
     case 170:
-      if (lookahead == 'i') ADVANCE(228);
+      if (lookahead == 'a') ADVANCE(234);
       END_STATE();
     case 171:
-      if (lookahead == 'o') ADVANCE(229);
+      if (lookahead == 't') ADVANCE(235);
       END_STATE();
     case 172:
-      if (lookahead == 'D') ADVANCE(230);
+      if (lookahead == 'u') ADVANCE(236);
       END_STATE();
     case 173:
-      if (lookahead == 'c') ADVANCE(231);
+      if (lookahead == 'i') ADVANCE(237);
       END_STATE();
     case 174:
-      if (lookahead == 'p') ADVANCE(232);
+      if (lookahead == 'r') ADVANCE(238);
       END_STATE();
     case 175:
-      if (lookahead == 'e') ADVANCE(233);
+      if (lookahead == 'p') ADVANCE(239);
       END_STATE();
     case 176:
-      if (lookahead == 'E') ADVANCE(234);
-      if (lookahead == 'R') ADVANCE(235);
-      if (lookahead == 'S') ADVANCE(236);
+      ACCEPT_TOKEN(anon_sym_mode);
       END_STATE();
     case 177:
-      ACCEPT_TOKEN(anon_sym_sink);
+      if (lookahead == 'r') ADVANCE(240);
       END_STATE();
     case 178:
-      ACCEPT_TOKEN(anon_sym_skip);
+      if (lookahead == 'i') ADVANCE(241);
       END_STATE();
     case 179:
-      if (lookahead == 'B') ADVANCE(237);
-      if (lookahead == 'O') ADVANCE(238);
+      if (lookahead == 'u') ADVANCE(242);
       END_STATE();
     case 180:
-      if (lookahead == 'n') ADVANCE(239);
+      if (lookahead == 'o') ADVANCE(243);
       END_STATE();
     case 181:
-      if (lookahead == 'e') ADVANCE(240);
+      if (lookahead == 'e') ADVANCE(244);
       END_STATE();
     case 182:
-      ACCEPT_TOKEN(anon_sym_tags);
+      if (lookahead == 'i') ADVANCE(245);
       END_STATE();
     case 183:
-      if (lookahead == 'e') ADVANCE(241);
+      if (lookahead == 'o') ADVANCE(246);
       END_STATE();
     case 184:
-      if (lookahead == 'e') ADVANCE(242);
+      if (lookahead == 'D') ADVANCE(247);
       END_STATE();
     case 185:
-      if (lookahead == 'B') ADVANCE(243);
-      if (lookahead == 'o') ADVANCE(244);
+      if (lookahead == 'c') ADVANCE(248);
       END_STATE();
     case 186:
-      if (lookahead == 'e') ADVANCE(245);
+      if (lookahead == 'p') ADVANCE(249);
       END_STATE();
     case 187:
-      ACCEPT_TOKEN(anon_sym_true);
+      if (lookahead == 'e') ADVANCE(250);
       END_STATE();
     case 188:
-      if (lookahead == 'd') ADVANCE(246);
+      if (lookahead == 'E') ADVANCE(251);
+      if (lookahead == 'R') ADVANCE(252);
+      if (lookahead == 'S') ADVANCE(253);
       END_STATE();
     case 189:
-      if (lookahead == 'u') ADVANCE(247);
+      ACCEPT_TOKEN(anon_sym_sink);
       END_STATE();
     case 190:
-      if (lookahead == 'h') ADVANCE(248);
+      ACCEPT_TOKEN(anon_sym_skip);
       END_STATE();
     case 191:
-      if (lookahead == 'l') ADVANCE(249);
+      if (lookahead == 'B') ADVANCE(254);
+      if (lookahead == 'O') ADVANCE(255);
       END_STATE();
     case 192:
-      ACCEPT_TOKEN(anon_sym_after);
+      if (lookahead == 'n') ADVANCE(256);
       END_STATE();
     case 193:
-      ACCEPT_TOKEN(anon_sym_anvil);
+      if (lookahead == 'e') ADVANCE(257);
       END_STATE();
     case 194:
-      ACCEPT_TOKEN(anon_sym_async);
-      if (lookahead == 'S') ADVANCE(250);
-      if (lookahead == 'W') ADVANCE(251);
+      ACCEPT_TOKEN(anon_sym_tags);
       END_STATE();
     case 195:
-      if (lookahead == 'i') ADVANCE(252);
+      if (lookahead == 'e') ADVANCE(258);
       END_STATE();
     case 196:
-      if (lookahead == 'e') ADVANCE(253);
+      if (lookahead == 'e') ADVANCE(259);
       END_STATE();
     case 197:
-      ACCEPT_TOKEN(anon_sym_bench);
-      if (lookahead == 'A') ADVANCE(254);
+      if (lookahead == 'B') ADVANCE(260);
+      if (lookahead == 'o') ADVANCE(261);
       END_STATE();
     case 198:
-      if (lookahead == 'i') ADVANCE(255);
+      if (lookahead == 'e') ADVANCE(262);
       END_STATE();
     case 199:
-      ACCEPT_TOKEN(anon_sym_count);
+      ACCEPT_TOKEN(anon_sym_true);
       END_STATE();
     case 200:
-      if (lookahead == 'e') ADVANCE(256);
+      if (lookahead == 'd') ADVANCE(263);
       END_STATE();
     case 201:
-      if (lookahead == 'r') ADVANCE(257);
+      if (lookahead == 'u') ADVANCE(264);
       END_STATE();
     case 202:
-      if (lookahead == 'i') ADVANCE(258);
+      if (lookahead == 'h') ADVANCE(265);
       END_STATE();
     case 203:
-      if (lookahead == 'a') ADVANCE(259);
+      if (lookahead == 'l') ADVANCE(266);
       END_STATE();
     case 204:
-      if (lookahead == 'i') ADVANCE(260);
+      ACCEPT_TOKEN(anon_sym_after);
       END_STATE();
     case 205:
-      if (lookahead == 'p') ADVANCE(261);
+      ACCEPT_TOKEN(anon_sym_anvil);
       END_STATE();
     case 206:
-      if (lookahead == 'a') ADVANCE(262);
+      ACCEPT_TOKEN(anon_sym_async);
+      if (lookahead == 'S') ADVANCE(267);
+      if (lookahead == 'W') ADVANCE(268);
       END_STATE();
     case 207:
-      if (lookahead == 'i') ADVANCE(263);
+      if (lookahead == 'i') ADVANCE(269);
       END_STATE();
     case 208:
-      if (lookahead == 'd') ADVANCE(264);
+      if (lookahead == 'e') ADVANCE(270);
       END_STATE();
     case 209:
-      if (lookahead == 'e') ADVANCE(265);
+      ACCEPT_TOKEN(anon_sym_bench);
+      if (lookahead == 'A') ADVANCE(271);
       END_STATE();
     case 210:
-      ACCEPT_TOKEN(anon_sym_false);
+      if (lookahead == 'i') ADVANCE(272);
       END_STATE();
     case 211:
-      if (lookahead == 'r') ADVANCE(266);
+      if (lookahead == 'g') ADVANCE(273);
       END_STATE();
     case 212:
-      if (lookahead == 'r') ADVANCE(267);
+      ACCEPT_TOKEN(anon_sym_count);
+      if (lookahead == 'e') ADVANCE(274);
       END_STATE();
     case 213:
-      if (lookahead == 't') ADVANCE(268);
+      if (lookahead == 'e') ADVANCE(275);
       END_STATE();
     case 214:
-      if (lookahead == 'l') ADVANCE(269);
+      if (lookahead == 'r') ADVANCE(276);
       END_STATE();
     case 215:
-      if (lookahead == 't') ADVANCE(270);
+      if (lookahead == 'i') ADVANCE(277);
       END_STATE();
     case 216:
-      if (lookahead == 'r') ADVANCE(271);
+      if (lookahead == 'a') ADVANCE(278);
       END_STATE();
     case 217:
-      if (lookahead == 't') ADVANCE(272);
+      if (lookahead == 'i') ADVANCE(279);
       END_STATE();
     case 218:
-      if (lookahead == 'd') ADVANCE(273);
+      if (lookahead == 'p') ADVANCE(280);
       END_STATE();
     case 219:
-      if (lookahead == 't') ADVANCE(274);
+      if (lookahead == 'a') ADVANCE(281);
       END_STATE();
     case 220:
-      ACCEPT_TOKEN(anon_sym_limit);
+      if (lookahead == 'i') ADVANCE(282);
       END_STATE();
     case 221:
-      if (lookahead == 'y') ADVANCE(275);
+      if (lookahead == 'a') ADVANCE(283);
       END_STATE();
     case 222:
-      if (lookahead == 'e') ADVANCE(276);
+      if (lookahead == 'd') ADVANCE(284);
       END_STATE();
     case 223:
-      ACCEPT_TOKEN(anon_sym_order);
+      if (lookahead == 'n') ADVANCE(285);
       END_STATE();
     case 224:
-      if (lookahead == 'e') ADVANCE(277);
+      if (lookahead == 'e') ADVANCE(286);
       END_STATE();
     case 225:
-      if (lookahead == 't') ADVANCE(278);
+      ACCEPT_TOKEN(anon_sym_false);
       END_STATE();
     case 226:
-      if (lookahead == 'r') ADVANCE(279);
+      if (lookahead == 'r') ADVANCE(287);
       END_STATE();
     case 227:
-      if (lookahead == 's') ADVANCE(280);
+      if (lookahead == 'r') ADVANCE(288);
       END_STATE();
     case 228:
-      if (lookahead == 'r') ADVANCE(281);
+      if (lookahead == 't') ADVANCE(289);
       END_STATE();
     case 229:
-      if (lookahead == 'u') ADVANCE(282);
+      if (lookahead == 'l') ADVANCE(290);
       END_STATE();
     case 230:
-      if (lookahead == 'a') ADVANCE(283);
+      if (lookahead == 't') ADVANCE(291);
       END_STATE();
     case 231:
-      if (lookahead == 't') ADVANCE(284);
+      if (lookahead == 'r') ADVANCE(292);
       END_STATE();
     case 232:
-      ACCEPT_TOKEN(anon_sym_setup);
+      if (lookahead == 't') ADVANCE(293);
       END_STATE();
     case 233:
-      ACCEPT_TOKEN(anon_sym_shape);
+      if (lookahead == 'd') ADVANCE(294);
       END_STATE();
     case 234:
-      if (lookahead == 'r') ADVANCE(285);
+      if (lookahead == 't') ADVANCE(295);
       END_STATE();
     case 235:
-      if (lookahead == 'e') ADVANCE(286);
+      ACCEPT_TOKEN(anon_sym_limit);
       END_STATE();
     case 236:
-      if (lookahead == 't') ADVANCE(287);
+      if (lookahead == 'n') ADVANCE(296);
       END_STATE();
     case 237:
-      if (lookahead == 'y') ADVANCE(288);
+      if (lookahead == 'm') ADVANCE(297);
       END_STATE();
     case 238:
-      if (lookahead == 'r') ADVANCE(289);
+      if (lookahead == 'y') ADVANCE(298);
       END_STATE();
     case 239:
-      if (lookahead == 'A') ADVANCE(290);
+      if (lookahead == 'e') ADVANCE(299);
       END_STATE();
     case 240:
-      ACCEPT_TOKEN(anon_sym_suite);
+      ACCEPT_TOKEN(anon_sym_order);
       END_STATE();
     case 241:
-      if (lookahead == 't') ADVANCE(291);
+      if (lookahead == 'e') ADVANCE(300);
       END_STATE();
     case 242:
-      ACCEPT_TOKEN(anon_sym_theme);
+      if (lookahead == 't') ADVANCE(301);
       END_STATE();
     case 243:
-      if (lookahead == 'a') ADVANCE(292);
+      if (lookahead == 'r') ADVANCE(302);
       END_STATE();
     case 244:
-      if (lookahead == 'u') ADVANCE(293);
+      if (lookahead == 's') ADVANCE(303);
       END_STATE();
     case 245:
-      ACCEPT_TOKEN(anon_sym_title);
+      if (lookahead == 'r') ADVANCE(304);
       END_STATE();
     case 246:
-      if (lookahead == 'a') ADVANCE(294);
+      if (lookahead == 'u') ADVANCE(305);
       END_STATE();
     case 247:
-      if (lookahead == 'p') ADVANCE(295);
+      if (lookahead == 'a') ADVANCE(306);
       END_STATE();
     case 248:
-      ACCEPT_TOKEN(anon_sym_width);
+      if (lookahead == 't') ADVANCE(307);
       END_STATE();
     case 249:
-      if (lookahead == 'e') ADVANCE(296);
+      ACCEPT_TOKEN(anon_sym_setup);
       END_STATE();
     case 250:
-      if (lookahead == 'a') ADVANCE(297);
+      ACCEPT_TOKEN(anon_sym_shape);
       END_STATE();
     case 251:
-      if (lookahead == 'a') ADVANCE(298);
+      if (lookahead == 'r') ADVANCE(308);
       END_STATE();
     case 252:
-      if (lookahead == 'n') ADVANCE(299);
+      if (lookahead == 'e') ADVANCE(309);
       END_STATE();
     case 253:
-      ACCEPT_TOKEN(anon_sym_before);
+      if (lookahead == 't') ADVANCE(310);
       END_STATE();
     case 254:
-      if (lookahead == 's') ADVANCE(300);
+      if (lookahead == 'y') ADVANCE(311);
       END_STATE();
     case 255:
-      if (lookahead == 'n') ADVANCE(301);
+      if (lookahead == 'r') ADVANCE(312);
       END_STATE();
     case 256:
-      if (lookahead == 's') ADVANCE(302);
+      if (lookahead == 'A') ADVANCE(313);
       END_STATE();
     case 257:
-      if (lookahead == 'e') ADVANCE(303);
+      ACCEPT_TOKEN(anon_sym_suite);
       END_STATE();
     case 258:
-      if (lookahead == 'p') ADVANCE(304);
+      if (lookahead == 't') ADVANCE(314);
       END_STATE();
     case 259:
-      if (lookahead == 'r') ADVANCE(305);
+      ACCEPT_TOKEN(anon_sym_theme);
       END_STATE();
     case 260:
-      if (lookahead == 'n') ADVANCE(306);
+      if (lookahead == 'a') ADVANCE(315);
       END_STATE();
     case 261:
-      if (lookahead == 'e') ADVANCE(307);
+      if (lookahead == 'u') ADVANCE(316);
       END_STATE();
     case 262:
-      if (lookahead == 'b') ADVANCE(308);
+      ACCEPT_TOKEN(anon_sym_title);
       END_STATE();
     case 263:
-      if (lookahead == 'n') ADVANCE(309);
+      if (lookahead == 'a') ADVANCE(317);
       END_STATE();
     case 264:
-      if (lookahead == 'e') ADVANCE(310);
+      if (lookahead == 'p') ADVANCE(318);
       END_STATE();
     case 265:
-      if (lookahead == 's') ADVANCE(311);
+      ACCEPT_TOKEN(anon_sym_width);
       END_STATE();
     case 266:
-      if (lookahead == 'W') ADVANCE(312);
+      if (lookahead == 'e') ADVANCE(319);
       END_STATE();
     case 267:
-      if (lookahead == 'e') ADVANCE(313);
+      if (lookahead == 'a') ADVANCE(320);
       END_STATE();
     case 268:
-      ACCEPT_TOKEN(anon_sym_format);
+      if (lookahead == 'a') ADVANCE(321);
       END_STATE();
     case 269:
-      if (lookahead == 'S') ADVANCE(314);
+      if (lookahead == 'n') ADVANCE(322);
       END_STATE();
     case 270:
-      ACCEPT_TOKEN(anon_sym_height);
+      ACCEPT_TOKEN(anon_sym_before);
       END_STATE();
     case 271:
-      if (lookahead == 's') ADVANCE(315);
+      if (lookahead == 's') ADVANCE(323);
       END_STATE();
     case 272:
-      ACCEPT_TOKEN(anon_sym_import);
+      if (lookahead == 'n') ADVANCE(324);
       END_STATE();
     case 273:
-      if (lookahead == 'e') ADVANCE(316);
+      if (lookahead == 'e') ADVANCE(325);
       END_STATE();
     case 274:
-      if (lookahead == 'i') ADVANCE(317);
+      if (lookahead == 'r') ADVANCE(326);
       END_STATE();
     case 275:
-      ACCEPT_TOKEN(anon_sym_memory);
+      if (lookahead == 's') ADVANCE(327);
       END_STATE();
     case 276:
-      if (lookahead == 'e') ADVANCE(318);
+      if (lookahead == 'e') ADVANCE(328);
       END_STATE();
     case 277:
-      if (lookahead == 'r') ADVANCE(319);
+      if (lookahead == 'p') ADVANCE(329);
       END_STATE();
     case 278:
-      ACCEPT_TOKEN(anon_sym_output);
+      if (lookahead == 'r') ADVANCE(330);
       END_STATE();
     case 279:
-      if (lookahead == 'm') ADVANCE(320);
+      if (lookahead == 'n') ADVANCE(331);
       END_STATE();
     case 280:
-      if (lookahead == 's') ADVANCE(321);
+      if (lookahead == 'e') ADVANCE(332);
       END_STATE();
     case 281:
-      if (lookahead == 'e') ADVANCE(322);
+      if (lookahead == 'b') ADVANCE(333);
       END_STATE();
     case 282:
-      if (lookahead == 'n') ADVANCE(323);
+      if (lookahead == 'n') ADVANCE(334);
       END_STATE();
     case 283:
-      if (lookahead == 't') ADVANCE(324);
+      if (lookahead == 'l') ADVANCE(335);
       END_STATE();
     case 284:
-      if (lookahead == 'o') ADVANCE(325);
+      if (lookahead == 'e') ADVANCE(336);
       END_STATE();
     case 285:
-      if (lookahead == 'r') ADVANCE(326);
+      if (lookahead == 'a') ADVANCE(337);
       END_STATE();
     case 286:
-      if (lookahead == 'g') ADVANCE(327);
+      if (lookahead == 's') ADVANCE(338);
       END_STATE();
     case 287:
-      if (lookahead == 'a') ADVANCE(328);
-      if (lookahead == 'd') ADVANCE(329);
+      if (lookahead == 'W') ADVANCE(339);
       END_STATE();
     case 288:
-      ACCEPT_TOKEN(anon_sym_sortBy);
+      if (lookahead == 'e') ADVANCE(340);
       END_STATE();
     case 289:
-      if (lookahead == 'd') ADVANCE(330);
+      ACCEPT_TOKEN(anon_sym_format);
       END_STATE();
     case 290:
-      if (lookahead == 'n') ADVANCE(331);
+      if (lookahead == 'S') ADVANCE(341);
       END_STATE();
     case 291:
-      if (lookahead == 'T') ADVANCE(332);
+      ACCEPT_TOKEN(anon_sym_height);
       END_STATE();
     case 292:
-      if (lookahead == 's') ADVANCE(333);
+      if (lookahead == 's') ADVANCE(342);
       END_STATE();
     case 293:
-      if (lookahead == 't') ADVANCE(334);
+      ACCEPT_TOKEN(anon_sym_import);
       END_STATE();
     case 294:
-      if (lookahead == 't') ADVANCE(335);
+      if (lookahead == 'e') ADVANCE(343);
       END_STATE();
     case 295:
-      ACCEPT_TOKEN(anon_sym_warmup);
+      if (lookahead == 'i') ADVANCE(344);
       END_STATE();
     case 296:
-      ACCEPT_TOKEN(anon_sym_yScale);
+      if (lookahead == 's') ADVANCE(345);
       END_STATE();
     case 297:
-      if (lookahead == 'm') ADVANCE(336);
+      if (lookahead == 'e') ADVANCE(346);
       END_STATE();
     case 298:
-      if (lookahead == 'r') ADVANCE(337);
+      ACCEPT_TOKEN(anon_sym_memory);
       END_STATE();
     case 299:
-      if (lookahead == 'e') ADVANCE(338);
+      if (lookahead == 'e') ADVANCE(347);
       END_STATE();
     case 300:
-      if (lookahead == 'y') ADVANCE(339);
+      if (lookahead == 'r') ADVANCE(348);
       END_STATE();
     case 301:
-      if (lookahead == 'g') ADVANCE(340);
+      ACCEPT_TOKEN(anon_sym_output);
       END_STATE();
     case 302:
-      if (lookahead == 'h') ADVANCE(341);
+      if (lookahead == 'm') ADVANCE(349);
       END_STATE();
     case 303:
-      ACCEPT_TOKEN(anon_sym_declare);
+      if (lookahead == 's') ADVANCE(350);
       END_STATE();
     case 304:
-      if (lookahead == 't') ADVANCE(342);
+      if (lookahead == 'e') ADVANCE(351);
       END_STATE();
     case 305:
-      if (lookahead == 'C') ADVANCE(343);
+      if (lookahead == 'n') ADVANCE(352);
       END_STATE();
     case 306:
-      if (lookahead == 'e') ADVANCE(344);
+      if (lookahead == 't') ADVANCE(353);
       END_STATE();
     case 307:
-      if (lookahead == 'e') ADVANCE(345);
+      if (lookahead == 'o') ADVANCE(354);
       END_STATE();
     case 308:
-      if (lookahead == 'l') ADVANCE(346);
+      if (lookahead == 'r') ADVANCE(355);
       END_STATE();
     case 309:
-      if (lookahead == 'g') ADVANCE(347);
+      if (lookahead == 'g') ADVANCE(356);
       END_STATE();
     case 310:
-      if (lookahead == 'B') ADVANCE(348);
+      if (lookahead == 'a') ADVANCE(357);
+      if (lookahead == 'd') ADVANCE(358);
       END_STATE();
     case 311:
-      if (lookahead == 's') ADVANCE(349);
+      ACCEPT_TOKEN(anon_sym_sortBy);
       END_STATE();
     case 312:
-      if (lookahead == 'i') ADVANCE(350);
+      if (lookahead == 'd') ADVANCE(359);
       END_STATE();
     case 313:
-      ACCEPT_TOKEN(anon_sym_fixture);
+      if (lookahead == 'n') ADVANCE(360);
       END_STATE();
     case 314:
-      if (lookahead == 'e') ADVANCE(351);
+      if (lookahead == 'T') ADVANCE(361);
       END_STATE();
     case 315:
-      ACCEPT_TOKEN(anon_sym_helpers);
+      if (lookahead == 's') ADVANCE(362);
       END_STATE();
     case 316:
-      if (lookahead == 'B') ADVANCE(352);
+      if (lookahead == 't') ADVANCE(363);
       END_STATE();
     case 317:
-      if (lookahead == 'o') ADVANCE(353);
+      if (lookahead == 't') ADVANCE(364);
       END_STATE();
     case 318:
-      if (lookahead == 'd') ADVANCE(354);
+      ACCEPT_TOKEN(anon_sym_warmup);
       END_STATE();
     case 319:
-      if (lookahead == 'D') ADVANCE(355);
+      ACCEPT_TOKEN(anon_sym_yScale);
       END_STATE();
     case 320:
-      if (lookahead == 'a') ADVANCE(356);
+      if (lookahead == 'm') ADVANCE(365);
       END_STATE();
     case 321:
-      if (lookahead == 'i') ADVANCE(357);
+      if (lookahead == 'r') ADVANCE(366);
       END_STATE();
     case 322:
-      if (lookahead == 's') ADVANCE(358);
+      if (lookahead == 'e') ADVANCE(367);
       END_STATE();
     case 323:
-      if (lookahead == 't') ADVANCE(359);
+      if (lookahead == 'y') ADVANCE(368);
       END_STATE();
     case 324:
-      if (lookahead == 'a') ADVANCE(360);
+      if (lookahead == 'g') ADVANCE(369);
       END_STATE();
     case 325:
-      if (lookahead == 'r') ADVANCE(361);
+      if (lookahead == 't') ADVANCE(370);
       END_STATE();
     case 326:
-      if (lookahead == 'o') ADVANCE(362);
+      if (lookahead == 's') ADVANCE(371);
       END_STATE();
     case 327:
-      if (lookahead == 'r') ADVANCE(363);
+      if (lookahead == 'h') ADVANCE(372);
       END_STATE();
     case 328:
-      if (lookahead == 't') ADVANCE(364);
+      ACCEPT_TOKEN(anon_sym_declare);
       END_STATE();
     case 329:
-      if (lookahead == 'D') ADVANCE(365);
+      if (lookahead == 't') ADVANCE(373);
       END_STATE();
     case 330:
-      if (lookahead == 'e') ADVANCE(366);
+      if (lookahead == 'C') ADVANCE(374);
       END_STATE();
     case 331:
-      if (lookahead == 'v') ADVANCE(367);
+      if (lookahead == 'e') ADVANCE(375);
       END_STATE();
     case 332:
-      if (lookahead == 'i') ADVANCE(368);
+      if (lookahead == 'e') ADVANCE(376);
       END_STATE();
     case 333:
-      if (lookahead == 'e') ADVANCE(369);
+      if (lookahead == 'l') ADVANCE(377);
       END_STATE();
     case 334:
-      ACCEPT_TOKEN(anon_sym_timeout);
+      if (lookahead == 'g') ADVANCE(378);
       END_STATE();
     case 335:
-      if (lookahead == 'e') ADVANCE(370);
+      if (lookahead == 'e') ADVANCE(379);
       END_STATE();
     case 336:
-      if (lookahead == 'p') ADVANCE(371);
+      if (lookahead == 'B') ADVANCE(380);
       END_STATE();
     case 337:
-      if (lookahead == 'm') ADVANCE(372);
+      if (lookahead == 'l') ADVANCE(381);
       END_STATE();
     case 338:
-      ACCEPT_TOKEN(anon_sym_baseline);
-      if (lookahead == 'B') ADVANCE(373);
+      if (lookahead == 's') ADVANCE(382);
       END_STATE();
     case 339:
-      if (lookahead == 'n') ADVANCE(374);
+      if (lookahead == 'i') ADVANCE(383);
       END_STATE();
     case 340:
-      ACCEPT_TOKEN(anon_sym_charting);
+      ACCEPT_TOKEN(anon_sym_fixture);
       END_STATE();
     case 341:
-      if (lookahead == 'o') ADVANCE(375);
+      if (lookahead == 'e') ADVANCE(384);
       END_STATE();
     case 342:
-      if (lookahead == 'i') ADVANCE(376);
+      ACCEPT_TOKEN(anon_sym_helpers);
       END_STATE();
     case 343:
-      if (lookahead == 'h') ADVANCE(377);
+      if (lookahead == 'B') ADVANCE(385);
       END_STATE();
     case 344:
-      if (lookahead == 'C') ADVANCE(378);
+      if (lookahead == 'o') ADVANCE(386);
       END_STATE();
     case 345:
-      if (lookahead == 'd') ADVANCE(379);
+      ACCEPT_TOKEN(anon_sym_maxRuns);
       END_STATE();
     case 346:
-      if (lookahead == 'e') ADVANCE(380);
+      ACCEPT_TOKEN(anon_sym_maxTime);
       END_STATE();
     case 347:
-      ACCEPT_TOKEN(anon_sym_encoding);
+      if (lookahead == 'd') ADVANCE(387);
       END_STATE();
     case 348:
-      if (lookahead == 'e') ADVANCE(381);
+      if (lookahead == 'D') ADVANCE(388);
       END_STATE();
     case 349:
-      ACCEPT_TOKEN(anon_sym_fairness);
-      if (lookahead == 'S') ADVANCE(382);
+      if (lookahead == 'a') ADVANCE(389);
       END_STATE();
     case 350:
-      if (lookahead == 'n') ADVANCE(383);
+      if (lookahead == 'i') ADVANCE(390);
       END_STATE();
     case 351:
-      if (lookahead == 't') ADVANCE(384);
+      if (lookahead == 's') ADVANCE(391);
       END_STATE();
     case 352:
-      if (lookahead == 'e') ADVANCE(385);
+      if (lookahead == 't') ADVANCE(392);
       END_STATE();
     case 353:
-      if (lookahead == 'n') ADVANCE(386);
+      if (lookahead == 'a') ADVANCE(393);
       END_STATE();
     case 354:
-      if (lookahead == 'u') ADVANCE(387);
+      if (lookahead == 'r') ADVANCE(394);
       END_STATE();
     case 355:
-      if (lookahead == 'e') ADVANCE(388);
+      if (lookahead == 'o') ADVANCE(395);
       END_STATE();
     case 356:
-      if (lookahead == 'n') ADVANCE(389);
+      if (lookahead == 'r') ADVANCE(396);
       END_STATE();
     case 357:
-      if (lookahead == 'o') ADVANCE(390);
+      if (lookahead == 't') ADVANCE(397);
       END_STATE();
     case 358:
-      ACCEPT_TOKEN(anon_sym_requires);
+      if (lookahead == 'D') ADVANCE(398);
       END_STATE();
     case 359:
-      ACCEPT_TOKEN(anon_sym_rowCount);
+      if (lookahead == 'e') ADVANCE(399);
       END_STATE();
     case 360:
-      if (lookahead == 's') ADVANCE(391);
+      if (lookahead == 'v') ADVANCE(400);
       END_STATE();
     case 361:
-      ACCEPT_TOKEN(anon_sym_selector);
+      if (lookahead == 'i') ADVANCE(401);
       END_STATE();
     case 362:
-      if (lookahead == 'r') ADVANCE(392);
+      if (lookahead == 'e') ADVANCE(402);
       END_STATE();
     case 363:
-      if (lookahead == 'e') ADVANCE(393);
+      ACCEPT_TOKEN(anon_sym_timeout);
       END_STATE();
     case 364:
-      if (lookahead == 's') ADVANCE(394);
+      if (lookahead == 'e') ADVANCE(403);
       END_STATE();
     case 365:
-      if (lookahead == 'e') ADVANCE(395);
+      if (lookahead == 'p') ADVANCE(404);
       END_STATE();
     case 366:
-      if (lookahead == 'r') ADVANCE(396);
+      if (lookahead == 'm') ADVANCE(405);
       END_STATE();
     case 367:
-      if (lookahead == 'i') ADVANCE(397);
+      ACCEPT_TOKEN(anon_sym_baseline);
+      if (lookahead == 'B') ADVANCE(406);
       END_STATE();
     case 368:
-      if (lookahead == 'm') ADVANCE(398);
+      if (lookahead == 'n') ADVANCE(407);
       END_STATE();
     case 369:
-      if (lookahead == 'd') ADVANCE(399);
+      ACCEPT_TOKEN(anon_sym_charting);
       END_STATE();
     case 370:
-      ACCEPT_TOKEN(anon_sym_validate);
+      ACCEPT_TOKEN(anon_sym_ciTarget);
       END_STATE();
     case 371:
-      if (lookahead == 'l') ADVANCE(400);
+      ACCEPT_TOKEN(anon_sym_counters);
       END_STATE();
     case 372:
-      if (lookahead == 'u') ADVANCE(401);
+      if (lookahead == 'o') ADVANCE(408);
       END_STATE();
     case 373:
-      if (lookahead == 'e') ADVANCE(402);
+      if (lookahead == 'i') ADVANCE(409);
       END_STATE();
     case 374:
-      if (lookahead == 'c') ADVANCE(403);
+      if (lookahead == 'h') ADVANCE(410);
       END_STATE();
     case 375:
-      if (lookahead == 'l') ADVANCE(404);
+      if (lookahead == 'C') ADVANCE(411);
       END_STATE();
     case 376:
-      if (lookahead == 'o') ADVANCE(405);
+      if (lookahead == 'd') ADVANCE(412);
       END_STATE();
     case 377:
-      if (lookahead == 'a') ADVANCE(406);
+      if (lookahead == 'e') ADVANCE(413);
       END_STATE();
     case 378:
-      if (lookahead == 'h') ADVANCE(407);
+      ACCEPT_TOKEN(anon_sym_encoding);
       END_STATE();
     case 379:
-      if (lookahead == 'u') ADVANCE(408);
+      if (lookahead == 'n') ADVANCE(414);
       END_STATE();
     case 380:
-      ACCEPT_TOKEN(anon_sym_drawTable);
+      if (lookahead == 'e') ADVANCE(415);
       END_STATE();
     case 381:
-      if (lookahead == 'n') ADVANCE(409);
+      ACCEPT_TOKEN(anon_sym_external);
       END_STATE();
     case 382:
-      if (lookahead == 'e') ADVANCE(410);
+      ACCEPT_TOKEN(anon_sym_fairness);
+      if (lookahead == 'S') ADVANCE(416);
       END_STATE();
     case 383:
-      if (lookahead == 'n') ADVANCE(411);
+      if (lookahead == 'n') ADVANCE(417);
       END_STATE();
     case 384:
-      if (lookahead == 'u') ADVANCE(412);
+      if (lookahead == 't') ADVANCE(418);
       END_STATE();
     case 385:
-      if (lookahead == 'n') ADVANCE(413);
+      if (lookahead == 'e') ADVANCE(419);
       END_STATE();
     case 386:
-      if (lookahead == 'B') ADVANCE(414);
-      if (lookahead == 's') ADVANCE(415);
+      if (lookahead == 'n') ADVANCE(420);
       END_STATE();
     case 387:
-      if (lookahead == 'p') ADVANCE(416);
+      if (lookahead == 'u') ADVANCE(421);
       END_STATE();
     case 388:
-      if (lookahead == 't') ADVANCE(417);
+      if (lookahead == 'e') ADVANCE(422);
       END_STATE();
     case 389:
-      if (lookahead == 'c') ADVANCE(418);
+      if (lookahead == 'n') ADVANCE(423);
       END_STATE();
     case 390:
-      if (lookahead == 'n') ADVANCE(419);
+      if (lookahead == 'o') ADVANCE(424);
       END_STATE();
     case 391:
-      if (lookahead == 'e') ADVANCE(420);
+      ACCEPT_TOKEN(anon_sym_requires);
       END_STATE();
     case 392:
-      if (lookahead == 'B') ADVANCE(421);
+      ACCEPT_TOKEN(anon_sym_rowCount);
       END_STATE();
     case 393:
-      if (lookahead == 's') ADVANCE(422);
+      if (lookahead == 's') ADVANCE(425);
       END_STATE();
     case 394:
-      if (lookahead == 'T') ADVANCE(423);
+      ACCEPT_TOKEN(anon_sym_selector);
       END_STATE();
     case 395:
-      if (lookahead == 'v') ADVANCE(424);
+      if (lookahead == 'r') ADVANCE(426);
       END_STATE();
     case 396:
-      ACCEPT_TOKEN(anon_sym_sortOrder);
+      if (lookahead == 'e') ADVANCE(427);
       END_STATE();
     case 397:
-      if (lookahead == 'l') ADVANCE(425);
+      if (lookahead == 's') ADVANCE(428);
       END_STATE();
     case 398:
-      if (lookahead == 'e') ADVANCE(426);
+      if (lookahead == 'e') ADVANCE(429);
       END_STATE();
     case 399:
-      ACCEPT_TOKEN(anon_sym_timeBased);
+      if (lookahead == 'r') ADVANCE(430);
       END_STATE();
     case 400:
-      if (lookahead == 'e') ADVANCE(427);
-      if (lookahead == 'i') ADVANCE(428);
+      if (lookahead == 'i') ADVANCE(431);
       END_STATE();
     case 401:
-      if (lookahead == 'p') ADVANCE(429);
+      if (lookahead == 'm') ADVANCE(432);
       END_STATE();
     case 402:
-      if (lookahead == 'n') ADVANCE(430);
+      if (lookahead == 'd') ADVANCE(433);
       END_STATE();
     case 403:
-      ACCEPT_TOKEN(anon_sym_benchAsync);
+      ACCEPT_TOKEN(anon_sym_validate);
       END_STATE();
     case 404:
-      if (lookahead == 'd') ADVANCE(431);
+      if (lookahead == 'l') ADVANCE(434);
       END_STATE();
     case 405:
-      if (lookahead == 'n') ADVANCE(432);
+      if (lookahead == 'u') ADVANCE(435);
       END_STATE();
     case 406:
-      if (lookahead == 'r') ADVANCE(433);
+      if (lookahead == 'e') ADVANCE(436);
       END_STATE();
     case 407:
-      if (lookahead == 'a') ADVANCE(434);
+      if (lookahead == 'c') ADVANCE(437);
       END_STATE();
     case 408:
-      if (lookahead == 'p') ADVANCE(435);
+      if (lookahead == 'l') ADVANCE(438);
       END_STATE();
     case 409:
-      if (lookahead == 'c') ADVANCE(436);
+      if (lookahead == 'o') ADVANCE(439);
       END_STATE();
     case 410:
-      if (lookahead == 'e') ADVANCE(437);
+      if (lookahead == 'a') ADVANCE(440);
       END_STATE();
     case 411:
-      if (lookahead == 'e') ADVANCE(438);
+      if (lookahead == 'h') ADVANCE(441);
       END_STATE();
     case 412:
-      if (lookahead == 'p') ADVANCE(439);
+      if (lookahead == 'u') ADVANCE(442);
       END_STATE();
     case 413:
-      if (lookahead == 'c') ADVANCE(440);
+      ACCEPT_TOKEN(anon_sym_drawTable);
       END_STATE();
     case 414:
-      if (lookahead == 'a') ADVANCE(441);
+      if (lookahead == 't') ADVANCE(443);
       END_STATE();
     case 415:
-      ACCEPT_TOKEN(anon_sym_iterations);
+      if (lookahead == 'n') ADVANCE(444);
       END_STATE();
     case 416:
-      ACCEPT_TOKEN(anon_sym_minSpeedup);
+      if (lookahead == 'e') ADVANCE(445);
       END_STATE();
     case 417:
-      if (lookahead == 'e') ADVANCE(442);
+      if (lookahead == 'n') ADVANCE(446);
       END_STATE();
     case 418:
-      if (lookahead == 'e') ADVANCE(443);
+      if (lookahead == 'u') ADVANCE(447);
       END_STATE();
     case 419:
-      if (lookahead == 'M') ADVANCE(444);
+      if (lookahead == 'n') ADVANCE(448);
       END_STATE();
     case 420:
-      if (lookahead == 't') ADVANCE(445);
+      if (lookahead == 'B') ADVANCE(449);
+      if (lookahead == 's') ADVANCE(450);
       END_STATE();
     case 421:
-      if (lookahead == 'a') ADVANCE(446);
+      if (lookahead == 'p') ADVANCE(451);
       END_STATE();
     case 422:
-      if (lookahead == 's') ADVANCE(447);
+      if (lookahead == 't') ADVANCE(452);
       END_STATE();
     case 423:
-      if (lookahead == 'a') ADVANCE(448);
+      if (lookahead == 'c') ADVANCE(453);
       END_STATE();
     case 424:
-      ACCEPT_TOKEN(anon_sym_showStdDev);
+      if (lookahead == 'n') ADVANCE(454);
       END_STATE();
     case 425:
-      ACCEPT_TOKEN(anon_sym_spawnAnvil);
+      if (lookahead == 'e') ADVANCE(455);
       END_STATE();
     case 426:
-      ACCEPT_TOKEN(anon_sym_targetTime);
+      if (lookahead == 'B') ADVANCE(456);
       END_STATE();
     case 427:
-      if (lookahead == 'C') ADVANCE(449);
+      if (lookahead == 's') ADVANCE(457);
       END_STATE();
     case 428:
-      if (lookahead == 'n') ADVANCE(450);
+      if (lookahead == 'T') ADVANCE(458);
       END_STATE();
     case 429:
-      if (lookahead == 'C') ADVANCE(451);
+      if (lookahead == 'v') ADVANCE(459);
       END_STATE();
     case 430:
-      if (lookahead == 'c') ADVANCE(452);
+      ACCEPT_TOKEN(anon_sym_sortOrder);
       END_STATE();
     case 431:
-      ACCEPT_TOKEN(anon_sym_cvThreshold);
+      if (lookahead == 'l') ADVANCE(460);
       END_STATE();
     case 432:
-      ACCEPT_TOKEN(anon_sym_description);
+      if (lookahead == 'e') ADVANCE(461);
       END_STATE();
     case 433:
-      if (lookahead == 't') ADVANCE(453);
+      ACCEPT_TOKEN(anon_sym_timeBased);
       END_STATE();
     case 434:
-      if (lookahead == 'r') ADVANCE(454);
+      if (lookahead == 'e') ADVANCE(462);
+      if (lookahead == 'i') ADVANCE(463);
       END_STATE();
     case 435:
-      if (lookahead == 'C') ADVANCE(455);
+      if (lookahead == 'p') ADVANCE(464);
       END_STATE();
     case 436:
-      if (lookahead == 'h') ADVANCE(456);
+      if (lookahead == 'n') ADVANCE(465);
       END_STATE();
     case 437:
-      if (lookahead == 'd') ADVANCE(457);
+      ACCEPT_TOKEN(anon_sym_benchAsync);
       END_STATE();
     case 438:
-      if (lookahead == 'r') ADVANCE(458);
+      if (lookahead == 'd') ADVANCE(466);
       END_STATE();
     case 439:
-      ACCEPT_TOKEN(anon_sym_globalSetup);
+      if (lookahead == 'n') ADVANCE(467);
       END_STATE();
     case 440:
-      if (lookahead == 'h') ADVANCE(459);
+      if (lookahead == 'r') ADVANCE(468);
       END_STATE();
     case 441:
-      if (lookahead == 's') ADVANCE(460);
+      if (lookahead == 'a') ADVANCE(469);
       END_STATE();
     case 442:
-      if (lookahead == 'c') ADVANCE(461);
+      if (lookahead == 'p') ADVANCE(470);
       END_STATE();
     case 443:
-      ACCEPT_TOKEN(anon_sym_performance);
+      ACCEPT_TOKEN(anon_sym_equivalent);
       END_STATE();
     case 444:
-      if (lookahead == 'o') ADVANCE(462);
+      if (lookahead == 'c') ADVANCE(471);
       END_STATE();
     case 445:
-      ACCEPT_TOKEN(anon_sym_sameDataset);
+      if (lookahead == 'e') ADVANCE(472);
       END_STATE();
     case 446:
-      if (lookahead == 'r') ADVANCE(463);
+      if (lookahead == 'e') ADVANCE(473);
       END_STATE();
     case 447:
-      if (lookahead == 'i') ADVANCE(464);
+      if (lookahead == 'p') ADVANCE(474);
       END_STATE();
     case 448:
-      if (lookahead == 'b') ADVANCE(465);
+      if (lookahead == 'c') ADVANCE(475);
       END_STATE();
     case 449:
-      if (lookahead == 'a') ADVANCE(466);
+      if (lookahead == 'a') ADVANCE(476);
       END_STATE();
     case 450:
-      if (lookahead == 'g') ADVANCE(467);
+      ACCEPT_TOKEN(anon_sym_iterations);
       END_STATE();
     case 451:
-      if (lookahead == 'a') ADVANCE(468);
+      ACCEPT_TOKEN(anon_sym_minSpeedup);
       END_STATE();
     case 452:
-      if (lookahead == 'h') ADVANCE(469);
+      if (lookahead == 'e') ADVANCE(477);
       END_STATE();
     case 453:
-      ACCEPT_TOKEN(anon_sym_drawBarChart);
+      if (lookahead == 'e') ADVANCE(478);
       END_STATE();
     case 454:
-      if (lookahead == 't') ADVANCE(470);
+      if (lookahead == 'M') ADVANCE(479);
       END_STATE();
     case 455:
-      if (lookahead == 'h') ADVANCE(471);
+      if (lookahead == 't') ADVANCE(480);
       END_STATE();
     case 456:
-      if (lookahead == 'm') ADVANCE(472);
+      if (lookahead == 'a') ADVANCE(481);
       END_STATE();
     case 457:
-      ACCEPT_TOKEN(anon_sym_fairnessSeed);
+      if (lookahead == 's') ADVANCE(482);
       END_STATE();
     case 458:
-      ACCEPT_TOKEN(anon_sym_filterWinner);
+      if (lookahead == 'a') ADVANCE(483);
       END_STATE();
     case 459:
-      if (lookahead == 'm') ADVANCE(473);
+      ACCEPT_TOKEN(anon_sym_showStdDev);
       END_STATE();
     case 460:
-      if (lookahead == 'e') ADVANCE(474);
+      ACCEPT_TOKEN(anon_sym_spawnAnvil);
       END_STATE();
     case 461:
-      if (lookahead == 't') ADVANCE(475);
+      ACCEPT_TOKEN(anon_sym_targetTime);
       END_STATE();
     case 462:
-      if (lookahead == 'd') ADVANCE(476);
+      if (lookahead == 'C') ADVANCE(484);
       END_STATE();
     case 463:
-      if (lookahead == 's') ADVANCE(477);
+      if (lookahead == 'n') ADVANCE(485);
       END_STATE();
     case 464:
-      if (lookahead == 'o') ADVANCE(478);
+      if (lookahead == 'C') ADVANCE(486);
       END_STATE();
     case 465:
-      if (lookahead == 'l') ADVANCE(479);
+      if (lookahead == 'c') ADVANCE(487);
       END_STATE();
     case 466:
-      if (lookahead == 'p') ADVANCE(480);
+      ACCEPT_TOKEN(anon_sym_cvThreshold);
       END_STATE();
     case 467:
-      if (lookahead == 'P') ADVANCE(481);
+      ACCEPT_TOKEN(anon_sym_description);
       END_STATE();
     case 468:
-      if (lookahead == 'p') ADVANCE(482);
+      if (lookahead == 't') ADVANCE(488);
       END_STATE();
     case 469:
-      if (lookahead == 'm') ADVANCE(483);
+      if (lookahead == 'r') ADVANCE(489);
       END_STATE();
     case 470:
-      ACCEPT_TOKEN(anon_sym_drawLineChart);
+      if (lookahead == 'C') ADVANCE(490);
       END_STATE();
     case 471:
-      if (lookahead == 'a') ADVANCE(484);
+      if (lookahead == 'h') ADVANCE(491);
       END_STATE();
     case 472:
-      if (lookahead == 'a') ADVANCE(485);
+      if (lookahead == 'd') ADVANCE(492);
       END_STATE();
     case 473:
-      if (lookahead == 'a') ADVANCE(486);
+      if (lookahead == 'r') ADVANCE(493);
       END_STATE();
     case 474:
-      if (lookahead == 'd') ADVANCE(487);
+      ACCEPT_TOKEN(anon_sym_globalSetup);
       END_STATE();
     case 475:
-      if (lookahead == 'i') ADVANCE(488);
+      if (lookahead == 'h') ADVANCE(494);
       END_STATE();
     case 476:
-      if (lookahead == 'e') ADVANCE(489);
+      if (lookahead == 's') ADVANCE(495);
       END_STATE();
     case 477:
-      ACCEPT_TOKEN(anon_sym_showErrorBars);
+      if (lookahead == 'c') ADVANCE(496);
       END_STATE();
     case 478:
-      if (lookahead == 'n') ADVANCE(490);
+      ACCEPT_TOKEN(anon_sym_performance);
       END_STATE();
     case 479:
-      if (lookahead == 'e') ADVANCE(491);
+      if (lookahead == 'o') ADVANCE(497);
       END_STATE();
     case 480:
-      ACCEPT_TOKEN(anon_sym_asyncSampleCap);
+      ACCEPT_TOKEN(anon_sym_sameDataset);
       END_STATE();
     case 481:
-      if (lookahead == 'o') ADVANCE(492);
+      if (lookahead == 'r') ADVANCE(498);
       END_STATE();
     case 482:
-      ACCEPT_TOKEN(anon_sym_asyncWarmupCap);
+      if (lookahead == 'i') ADVANCE(499);
       END_STATE();
     case 483:
-      if (lookahead == 'a') ADVANCE(493);
+      if (lookahead == 'b') ADVANCE(500);
       END_STATE();
     case 484:
-      if (lookahead == 'r') ADVANCE(494);
+      if (lookahead == 'a') ADVANCE(501);
       END_STATE();
     case 485:
-      if (lookahead == 'r') ADVANCE(495);
+      if (lookahead == 'g') ADVANCE(502);
       END_STATE();
     case 486:
-      if (lookahead == 'r') ADVANCE(496);
+      if (lookahead == 'a') ADVANCE(503);
       END_STATE();
     case 487:
-      ACCEPT_TOKEN(anon_sym_iterationBased);
+      if (lookahead == 'h') ADVANCE(504);
       END_STATE();
     case 488:
-      if (lookahead == 'o') ADVANCE(497);
+      ACCEPT_TOKEN(anon_sym_drawBarChart);
       END_STATE();
     case 489:
-      if (lookahead == 'l') ADVANCE(498);
+      if (lookahead == 't') ADVANCE(505);
       END_STATE();
     case 490:
-      ACCEPT_TOKEN(anon_sym_showRegression);
+      if (lookahead == 'h') ADVANCE(506);
       END_STATE();
     case 491:
-      ACCEPT_TOKEN(anon_sym_showStatsTable);
+      if (lookahead == 'm') ADVANCE(507);
       END_STATE();
     case 492:
-      if (lookahead == 'l') ADVANCE(499);
+      ACCEPT_TOKEN(anon_sym_fairnessSeed);
       END_STATE();
     case 493:
-      if (lookahead == 'r') ADVANCE(500);
+      ACCEPT_TOKEN(anon_sym_filterWinner);
       END_STATE();
     case 494:
-      if (lookahead == 't') ADVANCE(501);
+      if (lookahead == 'm') ADVANCE(508);
       END_STATE();
     case 495:
-      if (lookahead == 'k') ADVANCE(502);
+      if (lookahead == 'e') ADVANCE(509);
       END_STATE();
     case 496:
-      if (lookahead == 'k') ADVANCE(503);
+      if (lookahead == 't') ADVANCE(510);
       END_STATE();
     case 497:
-      if (lookahead == 'n') ADVANCE(504);
+      if (lookahead == 'd') ADVANCE(511);
       END_STATE();
     case 498:
-      ACCEPT_TOKEN(anon_sym_regressionModel);
+      if (lookahead == 's') ADVANCE(512);
       END_STATE();
     case 499:
-      if (lookahead == 'i') ADVANCE(505);
+      if (lookahead == 'o') ADVANCE(513);
       END_STATE();
     case 500:
-      if (lookahead == 'k') ADVANCE(506);
+      if (lookahead == 'l') ADVANCE(514);
       END_STATE();
     case 501:
-      ACCEPT_TOKEN(anon_sym_drawSpeedupChart);
+      if (lookahead == 'p') ADVANCE(515);
       END_STATE();
     case 502:
-      if (lookahead == 's') ADVANCE(507);
+      if (lookahead == 'P') ADVANCE(516);
       END_STATE();
     case 503:
-      if (lookahead == 's') ADVANCE(508);
+      if (lookahead == 'p') ADVANCE(517);
       END_STATE();
     case 504:
-      ACCEPT_TOKEN(anon_sym_outlierDetection);
+      if (lookahead == 'm') ADVANCE(518);
       END_STATE();
     case 505:
-      if (lookahead == 'c') ADVANCE(509);
+      ACCEPT_TOKEN(anon_sym_drawLineChart);
       END_STATE();
     case 506:
-      ACCEPT_TOKEN(anon_sym_baselineBenchmark);
+      if (lookahead == 'a') ADVANCE(519);
       END_STATE();
     case 507:
-      ACCEPT_TOKEN(anon_sym_excludeBenchmarks);
+      if (lookahead == 'a') ADVANCE(520);
       END_STATE();
     case 508:
-      ACCEPT_TOKEN(anon_sym_includeBenchmarks);
+      if (lookahead == 'a') ADVANCE(521);
       END_STATE();
     case 509:
-      if (lookahead == 'y') ADVANCE(510);
+      if (lookahead == 'd') ADVANCE(522);
       END_STATE();
     case 510:
+      if (lookahead == 'i') ADVANCE(523);
+      END_STATE();
+    case 511:
+      if (lookahead == 'e') ADVANCE(524);
+      END_STATE();
+    case 512:
+      ACCEPT_TOKEN(anon_sym_showErrorBars);
+      END_STATE();
+    case 513:
+      if (lookahead == 'n') ADVANCE(525);
+      END_STATE();
+    case 514:
+      if (lookahead == 'e') ADVANCE(526);
+      END_STATE();
+    case 515:
+      ACCEPT_TOKEN(anon_sym_asyncSampleCap);
+      END_STATE();
+    case 516:
+      if (lookahead == 'o') ADVANCE(527);
+      END_STATE();
+    case 517:
+      ACCEPT_TOKEN(anon_sym_asyncWarmupCap);
+      END_STATE();
+    case 518:
+      if (lookahead == 'a') ADVANCE(528);
+      END_STATE();
+    case 519:
+      if (lookahead == 'r') ADVANCE(529);
+      END_STATE();
+    case 520:
+      if (lookahead == 'r') ADVANCE(530);
+      END_STATE();
+    case 521:
+      if (lookahead == 'r') ADVANCE(531);
+      END_STATE();
+    case 522:
+      ACCEPT_TOKEN(anon_sym_iterationBased);
+      END_STATE();
+    case 523:
+      if (lookahead == 'o') ADVANCE(532);
+      END_STATE();
+    case 524:
+      if (lookahead == 'l') ADVANCE(533);
+      END_STATE();
+    case 525:
+      ACCEPT_TOKEN(anon_sym_showRegression);
+      END_STATE();
+    case 526:
+      ACCEPT_TOKEN(anon_sym_showStatsTable);
+      END_STATE();
+    case 527:
+      if (lookahead == 'l') ADVANCE(534);
+      END_STATE();
+    case 528:
+      if (lookahead == 'r') ADVANCE(535);
+      END_STATE();
+    case 529:
+      if (lookahead == 't') ADVANCE(536);
+      END_STATE();
+    case 530:
+      if (lookahead == 'k') ADVANCE(537);
+      END_STATE();
+    case 531:
+      if (lookahead == 'k') ADVANCE(538);
+      END_STATE();
+    case 532:
+      if (lookahead == 'n') ADVANCE(539);
+      END_STATE();
+    case 533:
+      ACCEPT_TOKEN(anon_sym_regressionModel);
+      END_STATE();
+    case 534:
+      if (lookahead == 'i') ADVANCE(540);
+      END_STATE();
+    case 535:
+      if (lookahead == 'k') ADVANCE(541);
+      END_STATE();
+    case 536:
+      ACCEPT_TOKEN(anon_sym_drawSpeedupChart);
+      END_STATE();
+    case 537:
+      if (lookahead == 's') ADVANCE(542);
+      END_STATE();
+    case 538:
+      if (lookahead == 's') ADVANCE(543);
+      END_STATE();
+    case 539:
+      ACCEPT_TOKEN(anon_sym_outlierDetection);
+      END_STATE();
+    case 540:
+      if (lookahead == 'c') ADVANCE(544);
+      END_STATE();
+    case 541:
+      ACCEPT_TOKEN(anon_sym_baselineBenchmark);
+      END_STATE();
+    case 542:
+      ACCEPT_TOKEN(anon_sym_excludeBenchmarks);
+      END_STATE();
+    case 543:
+      ACCEPT_TOKEN(anon_sym_includeBenchmarks);
+      END_STATE();
+    case 544:
+      if (lookahead == 'y') ADVANCE(545);
+      END_STATE();
+    case 545:
       ACCEPT_TOKEN(anon_sym_asyncSamplingPolicy);
       END_STATE();
     default:
@@ -3538,256 +4294,442 @@ static bool ts_lex_keywords(TSLexer *lexer, TSStateId state) {
 
 static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0, .external_lex_state = 1},
-  [1] = {.lex_state = 0},
-  [2] = {.lex_state = 0},
-  [3] = {.lex_state = 0},
-  [4] = {.lex_state = 0},
-  [5] = {.lex_state = 0},
-  [6] = {.lex_state = 0},
-  [7] = {.lex_state = 0},
-  [8] = {.lex_state = 0},
-  [9] = {.lex_state = 0},
-  [10] = {.lex_state = 0},
-  [11] = {.lex_state = 0},
-  [12] = {.lex_state = 0},
-  [13] = {.lex_state = 0},
-  [14] = {.lex_state = 0},
-  [15] = {.lex_state = 0},
-  [16] = {.lex_state = 0},
-  [17] = {.lex_state = 0},
-  [18] = {.lex_state = 0},
-  [19] = {.lex_state = 0},
-  [20] = {.lex_state = 0},
-  [21] = {.lex_state = 0},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 0},
-  [24] = {.lex_state = 0},
-  [25] = {.lex_state = 0},
-  [26] = {.lex_state = 0},
-  [27] = {.lex_state = 0},
-  [28] = {.lex_state = 0},
-  [29] = {.lex_state = 0},
-  [30] = {.lex_state = 0},
-  [31] = {.lex_state = 0},
-  [32] = {.lex_state = 0},
-  [33] = {.lex_state = 0},
-  [34] = {.lex_state = 0},
-  [35] = {.lex_state = 0},
-  [36] = {.lex_state = 0},
-  [37] = {.lex_state = 0},
-  [38] = {.lex_state = 0},
-  [39] = {.lex_state = 0},
-  [40] = {.lex_state = 0},
-  [41] = {.lex_state = 0},
-  [42] = {.lex_state = 0},
-  [43] = {.lex_state = 0},
-  [44] = {.lex_state = 0},
-  [45] = {.lex_state = 0},
-  [46] = {.lex_state = 0},
-  [47] = {.lex_state = 0},
-  [48] = {.lex_state = 0},
-  [49] = {.lex_state = 0},
-  [50] = {.lex_state = 0},
-  [51] = {.lex_state = 0},
-  [52] = {.lex_state = 0},
-  [53] = {.lex_state = 0},
-  [54] = {.lex_state = 0},
-  [55] = {.lex_state = 0},
-  [56] = {.lex_state = 0},
-  [57] = {.lex_state = 0},
-  [58] = {.lex_state = 0},
-  [59] = {.lex_state = 0},
-  [60] = {.lex_state = 0},
-  [61] = {.lex_state = 0},
-  [62] = {.lex_state = 0},
-  [63] = {.lex_state = 0},
-  [64] = {.lex_state = 0},
-  [65] = {.lex_state = 0},
-  [66] = {.lex_state = 0},
-  [67] = {.lex_state = 0},
-  [68] = {.lex_state = 0},
-  [69] = {.lex_state = 0},
-  [70] = {.lex_state = 0},
-  [71] = {.lex_state = 0},
-  [72] = {.lex_state = 0},
-  [73] = {.lex_state = 0},
-  [74] = {.lex_state = 0},
-  [75] = {.lex_state = 0},
-  [76] = {.lex_state = 0},
-  [77] = {.lex_state = 0},
-  [78] = {.lex_state = 0},
-  [79] = {.lex_state = 0},
-  [80] = {.lex_state = 0},
-  [81] = {.lex_state = 0},
-  [82] = {.lex_state = 0},
-  [83] = {.lex_state = 0},
-  [84] = {.lex_state = 0},
-  [85] = {.lex_state = 0},
-  [86] = {.lex_state = 0},
-  [87] = {.lex_state = 0},
-  [88] = {.lex_state = 0},
-  [89] = {.lex_state = 0},
-  [90] = {.lex_state = 0},
-  [91] = {.lex_state = 0},
-  [92] = {.lex_state = 0},
-  [93] = {.lex_state = 0},
-  [94] = {.lex_state = 0},
-  [95] = {.lex_state = 1},
-  [96] = {.lex_state = 0},
-  [97] = {.lex_state = 0},
-  [98] = {.lex_state = 0},
-  [99] = {.lex_state = 0},
-  [100] = {.lex_state = 3},
-  [101] = {.lex_state = 0},
-  [102] = {.lex_state = 0},
-  [103] = {.lex_state = 0},
-  [104] = {.lex_state = 0},
-  [105] = {.lex_state = 0},
-  [106] = {.lex_state = 3},
-  [107] = {.lex_state = 0},
-  [108] = {.lex_state = 1},
-  [109] = {.lex_state = 0},
-  [110] = {.lex_state = 0},
-  [111] = {.lex_state = 0},
-  [112] = {.lex_state = 0},
-  [113] = {.lex_state = 0},
-  [114] = {.lex_state = 0},
-  [115] = {.lex_state = 0},
-  [116] = {.lex_state = 0},
-  [117] = {.lex_state = 4},
-  [118] = {.lex_state = 1},
-  [119] = {.lex_state = 0},
-  [120] = {.lex_state = 0},
-  [121] = {.lex_state = 3},
-  [122] = {.lex_state = 0},
-  [123] = {.lex_state = 4},
-  [124] = {.lex_state = 0},
-  [125] = {.lex_state = 0},
-  [126] = {.lex_state = 0},
-  [127] = {.lex_state = 0},
-  [128] = {.lex_state = 0},
-  [129] = {.lex_state = 0},
-  [130] = {.lex_state = 0},
-  [131] = {.lex_state = 0},
-  [132] = {.lex_state = 0},
-  [133] = {.lex_state = 0},
-  [134] = {.lex_state = 0},
-  [135] = {.lex_state = 0},
-  [136] = {.lex_state = 0},
-  [137] = {.lex_state = 0},
-  [138] = {.lex_state = 0},
-  [139] = {.lex_state = 0},
-  [140] = {.lex_state = 0},
-  [141] = {.lex_state = 0},
-  [142] = {.lex_state = 0},
-  [143] = {.lex_state = 0},
-  [144] = {.lex_state = 0},
-  [145] = {.lex_state = 0},
-  [146] = {.lex_state = 0},
-  [147] = {.lex_state = 0},
-  [148] = {.lex_state = 0},
-  [149] = {.lex_state = 0},
-  [150] = {.lex_state = 0},
-  [151] = {.lex_state = 0},
-  [152] = {.lex_state = 0},
-  [153] = {.lex_state = 0},
-  [154] = {.lex_state = 0},
-  [155] = {.lex_state = 0},
-  [156] = {.lex_state = 0},
-  [157] = {.lex_state = 0},
-  [158] = {.lex_state = 0},
-  [159] = {.lex_state = 0},
-  [160] = {.lex_state = 0},
-  [161] = {.lex_state = 0},
-  [162] = {.lex_state = 0},
-  [163] = {.lex_state = 0},
-  [164] = {.lex_state = 0},
-  [165] = {.lex_state = 0},
-  [166] = {.lex_state = 0},
-  [167] = {.lex_state = 0},
-  [168] = {.lex_state = 0},
-  [169] = {.lex_state = 0},
-  [170] = {.lex_state = 0},
-  [171] = {.lex_state = 0},
-  [172] = {.lex_state = 0},
-  [173] = {.lex_state = 0, .external_lex_state = 2},
-  [174] = {.lex_state = 0},
-  [175] = {.lex_state = 0, .external_lex_state = 2},
-  [176] = {.lex_state = 0},
-  [177] = {.lex_state = 0},
-  [178] = {.lex_state = 0},
-  [179] = {.lex_state = 0},
-  [180] = {.lex_state = 0},
-  [181] = {.lex_state = 0},
-  [182] = {.lex_state = 0},
-  [183] = {.lex_state = 0},
-  [184] = {.lex_state = 0},
-  [185] = {.lex_state = 0},
-  [186] = {.lex_state = 0},
-  [187] = {.lex_state = 0},
-  [188] = {.lex_state = 0},
-  [189] = {.lex_state = 0},
-  [190] = {.lex_state = 0},
-  [191] = {.lex_state = 0},
-  [192] = {.lex_state = 0},
-  [193] = {.lex_state = 0},
-  [194] = {.lex_state = 0},
-  [195] = {.lex_state = 0},
-  [196] = {.lex_state = 0},
-  [197] = {.lex_state = 0},
-  [198] = {.lex_state = 0},
-  [199] = {.lex_state = 0},
-  [200] = {.lex_state = 0},
-  [201] = {.lex_state = 0},
-  [202] = {.lex_state = 0},
-  [203] = {.lex_state = 0},
-  [204] = {.lex_state = 0},
-  [205] = {.lex_state = 0},
-  [206] = {.lex_state = 0},
-  [207] = {.lex_state = 0},
-  [208] = {.lex_state = 0},
-  [209] = {.lex_state = 0},
-  [210] = {.lex_state = 0},
-  [211] = {.lex_state = 0},
-  [212] = {.lex_state = 0},
-  [213] = {.lex_state = 0},
-  [214] = {.lex_state = 0},
-  [215] = {.lex_state = 0},
-  [216] = {.lex_state = 0},
-  [217] = {.lex_state = 0},
-  [218] = {.lex_state = 0},
-  [219] = {.lex_state = 0},
-  [220] = {.lex_state = 0},
-  [221] = {.lex_state = 0},
-  [222] = {.lex_state = 0},
-  [223] = {.lex_state = 0},
-  [224] = {.lex_state = 0},
-  [225] = {.lex_state = 0},
-  [226] = {.lex_state = 0},
-  [227] = {.lex_state = 0},
-  [228] = {.lex_state = 0},
-  [229] = {.lex_state = 0},
-  [230] = {.lex_state = 0},
-  [231] = {.lex_state = 0},
-  [232] = {.lex_state = 0},
-  [233] = {.lex_state = 0},
-  [234] = {.lex_state = 0},
-  [235] = {.lex_state = 0},
-  [236] = {.lex_state = 0},
-  [237] = {.lex_state = 0},
-  [238] = {.lex_state = 0},
-  [239] = {.lex_state = 0},
-  [240] = {.lex_state = 0},
-  [241] = {.lex_state = 0},
-  [242] = {.lex_state = 0},
-  [243] = {.lex_state = 0},
-  [244] = {.lex_state = 0},
-  [245] = {.lex_state = 0},
-  [246] = {.lex_state = 0},
-  [247] = {.lex_state = 2},
-  [248] = {.lex_state = 0},
-  [249] = {.lex_state = 0},
-  [250] = {.lex_state = 0},
+  [1] = {.lex_state = 1},
+  [2] = {.lex_state = 2},
+  [3] = {.lex_state = 2},
+  [4] = {.lex_state = 3},
+  [5] = {.lex_state = 3},
+  [6] = {.lex_state = 3},
+  [7] = {.lex_state = 3},
+  [8] = {.lex_state = 4},
+  [9] = {.lex_state = 4},
+  [10] = {.lex_state = 4},
+  [11] = {.lex_state = 4},
+  [12] = {.lex_state = 4},
+  [13] = {.lex_state = 4},
+  [14] = {.lex_state = 4},
+  [15] = {.lex_state = 4},
+  [16] = {.lex_state = 4},
+  [17] = {.lex_state = 4},
+  [18] = {.lex_state = 4},
+  [19] = {.lex_state = 4},
+  [20] = {.lex_state = 4},
+  [21] = {.lex_state = 4},
+  [22] = {.lex_state = 4},
+  [23] = {.lex_state = 5},
+  [24] = {.lex_state = 5},
+  [25] = {.lex_state = 5},
+  [26] = {.lex_state = 5},
+  [27] = {.lex_state = 5},
+  [28] = {.lex_state = 5},
+  [29] = {.lex_state = 5},
+  [30] = {.lex_state = 5},
+  [31] = {.lex_state = 5},
+  [32] = {.lex_state = 5},
+  [33] = {.lex_state = 5},
+  [34] = {.lex_state = 5},
+  [35] = {.lex_state = 5},
+  [36] = {.lex_state = 5},
+  [37] = {.lex_state = 5},
+  [38] = {.lex_state = 5},
+  [39] = {.lex_state = 5},
+  [40] = {.lex_state = 5},
+  [41] = {.lex_state = 5},
+  [42] = {.lex_state = 5},
+  [43] = {.lex_state = 5},
+  [44] = {.lex_state = 5},
+  [45] = {.lex_state = 5},
+  [46] = {.lex_state = 5},
+  [47] = {.lex_state = 5},
+  [48] = {.lex_state = 5},
+  [49] = {.lex_state = 5},
+  [50] = {.lex_state = 5},
+  [51] = {.lex_state = 5},
+  [52] = {.lex_state = 5},
+  [53] = {.lex_state = 5},
+  [54] = {.lex_state = 5},
+  [55] = {.lex_state = 5},
+  [56] = {.lex_state = 5},
+  [57] = {.lex_state = 5},
+  [58] = {.lex_state = 5},
+  [59] = {.lex_state = 5},
+  [60] = {.lex_state = 5},
+  [61] = {.lex_state = 5},
+  [62] = {.lex_state = 5},
+  [63] = {.lex_state = 5},
+  [64] = {.lex_state = 5},
+  [65] = {.lex_state = 5},
+  [66] = {.lex_state = 5},
+  [67] = {.lex_state = 5},
+  [68] = {.lex_state = 5},
+  [69] = {.lex_state = 5},
+  [70] = {.lex_state = 5},
+  [71] = {.lex_state = 5},
+  [72] = {.lex_state = 5},
+  [73] = {.lex_state = 5},
+  [74] = {.lex_state = 5},
+  [75] = {.lex_state = 5},
+  [76] = {.lex_state = 5},
+  [77] = {.lex_state = 5},
+  [78] = {.lex_state = 5},
+  [79] = {.lex_state = 5},
+  [80] = {.lex_state = 5},
+  [81] = {.lex_state = 5},
+  [82] = {.lex_state = 5},
+  [83] = {.lex_state = 5},
+  [84] = {.lex_state = 5},
+  [85] = {.lex_state = 5},
+  [86] = {.lex_state = 5},
+  [87] = {.lex_state = 5},
+  [88] = {.lex_state = 5},
+  [89] = {.lex_state = 5},
+  [90] = {.lex_state = 5},
+  [91] = {.lex_state = 5},
+  [92] = {.lex_state = 5},
+  [93] = {.lex_state = 5},
+  [94] = {.lex_state = 5},
+  [95] = {.lex_state = 5},
+  [96] = {.lex_state = 5},
+  [97] = {.lex_state = 5},
+  [98] = {.lex_state = 5},
+  [99] = {.lex_state = 5},
+  [100] = {.lex_state = 5},
+  [101] = {.lex_state = 5},
+  [102] = {.lex_state = 5},
+  [103] = {.lex_state = 5},
+  [104] = {.lex_state = 5},
+  [105] = {.lex_state = 5},
+  [106] = {.lex_state = 5},
+  [107] = {.lex_state = 5},
+  [108] = {.lex_state = 5},
+  [109] = {.lex_state = 5},
+  [110] = {.lex_state = 5},
+  [111] = {.lex_state = 5},
+  [112] = {.lex_state = 5},
+  [113] = {.lex_state = 5},
+  [114] = {.lex_state = 5},
+  [115] = {.lex_state = 5},
+  [116] = {.lex_state = 6},
+  [117] = {.lex_state = 6},
+  [118] = {.lex_state = 6},
+  [119] = {.lex_state = 7},
+  [120] = {.lex_state = 8},
+  [121] = {.lex_state = 8},
+  [122] = {.lex_state = 8},
+  [123] = {.lex_state = 8},
+  [124] = {.lex_state = 5},
+  [125] = {.lex_state = 5},
+  [126] = {.lex_state = 5},
+  [127] = {.lex_state = 8},
+  [128] = {.lex_state = 1},
+  [129] = {.lex_state = 9},
+  [130] = {.lex_state = 9},
+  [131] = {.lex_state = 9},
+  [132] = {.lex_state = 10},
+  [133] = {.lex_state = 1},
+  [134] = {.lex_state = 5},
+  [135] = {.lex_state = 5},
+  [136] = {.lex_state = 5},
+  [137] = {.lex_state = 5},
+  [138] = {.lex_state = 5},
+  [139] = {.lex_state = 11},
+  [140] = {.lex_state = 12},
+  [141] = {.lex_state = 5},
+  [142] = {.lex_state = 5},
+  [143] = {.lex_state = 5},
+  [144] = {.lex_state = 5},
+  [145] = {.lex_state = 5},
+  [146] = {.lex_state = 5},
+  [147] = {.lex_state = 5},
+  [148] = {.lex_state = 5},
+  [149] = {.lex_state = 5},
+  [150] = {.lex_state = 5},
+  [151] = {.lex_state = 5},
+  [152] = {.lex_state = 5},
+  [153] = {.lex_state = 5},
+  [154] = {.lex_state = 1},
+  [155] = {.lex_state = 1},
+  [156] = {.lex_state = 1},
+  [157] = {.lex_state = 1},
+  [158] = {.lex_state = 1},
+  [159] = {.lex_state = 1},
+  [160] = {.lex_state = 1},
+  [161] = {.lex_state = 13},
+  [162] = {.lex_state = 1},
+  [163] = {.lex_state = 13},
+  [164] = {.lex_state = 1},
+  [165] = {.lex_state = 14},
+  [166] = {.lex_state = 15},
+  [167] = {.lex_state = 16},
+  [168] = {.lex_state = 16},
+  [169] = {.lex_state = 16},
+  [170] = {.lex_state = 16},
+  [171] = {.lex_state = 16},
+  [172] = {.lex_state = 7},
+  [173] = {.lex_state = 17},
+  [174] = {.lex_state = 5},
+  [175] = {.lex_state = 5},
+  [176] = {.lex_state = 5},
+  [177] = {.lex_state = 6},
+  [178] = {.lex_state = 18},
+  [179] = {.lex_state = 18},
+  [180] = {.lex_state = 18},
+  [181] = {.lex_state = 5},
+  [182] = {.lex_state = 6},
+  [183] = {.lex_state = 5},
+  [184] = {.lex_state = 19},
+  [185] = {.lex_state = 18},
+  [186] = {.lex_state = 5},
+  [187] = {.lex_state = 14},
+  [188] = {.lex_state = 15},
+  [189] = {.lex_state = 20},
+  [190] = {.lex_state = 20},
+  [191] = {.lex_state = 21},
+  [192] = {.lex_state = 14},
+  [193] = {.lex_state = 15},
+  [194] = {.lex_state = 19},
+  [195] = {.lex_state = 21},
+  [196] = {.lex_state = 19},
+  [197] = {.lex_state = 22},
+  [198] = {.lex_state = 1},
+  [199] = {.lex_state = 1},
+  [200] = {.lex_state = 1},
+  [201] = {.lex_state = 5},
+  [202] = {.lex_state = 5},
+  [203] = {.lex_state = 5},
+  [204] = {.lex_state = 7},
+  [205] = {.lex_state = 1},
+  [206] = {.lex_state = 1},
+  [207] = {.lex_state = 7},
+  [208] = {.lex_state = 1},
+  [209] = {.lex_state = 1},
+  [210] = {.lex_state = 5},
+  [211] = {.lex_state = 23},
+  [212] = {.lex_state = 1},
+  [213] = {.lex_state = 6},
+  [214] = {.lex_state = 5},
+  [215] = {.lex_state = 23},
+  [216] = {.lex_state = 6},
+  [217] = {.lex_state = 7},
+  [218] = {.lex_state = 6},
+  [219] = {.lex_state = 5},
+  [220] = {.lex_state = 5},
+  [221] = {.lex_state = 6},
+  [222] = {.lex_state = 23},
+  [223] = {.lex_state = 7},
+  [224] = {.lex_state = 23},
+  [225] = {.lex_state = 23},
+  [226] = {.lex_state = 14},
+  [227] = {.lex_state = 14},
+  [228] = {.lex_state = 15},
+  [229] = {.lex_state = 15},
+  [230] = {.lex_state = 24},
+  [231] = {.lex_state = 5},
+  [232] = {.lex_state = 25},
+  [233] = {.lex_state = 5},
+  [234] = {.lex_state = 1},
+  [235] = {.lex_state = 23},
+  [236] = {.lex_state = 6},
+  [237] = {.lex_state = 25},
+  [238] = {.lex_state = 23},
+  [239] = {.lex_state = 6},
+  [240] = {.lex_state = 24},
+  [241] = {.lex_state = 1},
+  [242] = {.lex_state = 6},
+  [243] = {.lex_state = 23},
+  [244] = {.lex_state = 6},
+  [245] = {.lex_state = 23},
+  [246] = {.lex_state = 24},
+  [247] = {.lex_state = 25},
+  [248] = {.lex_state = 24},
+  [249] = {.lex_state = 23},
+  [250] = {.lex_state = 25},
+  [251] = {.lex_state = 6},
+  [252] = {.lex_state = 24},
+  [253] = {.lex_state = 23},
+  [254] = {.lex_state = 23},
+  [255] = {.lex_state = 24},
+  [256] = {.lex_state = 23},
+  [257] = {.lex_state = 23},
+  [258] = {.lex_state = 6},
+  [259] = {.lex_state = 6},
+  [260] = {.lex_state = 23},
+  [261] = {.lex_state = 26},
+  [262] = {.lex_state = 27},
+  [263] = {.lex_state = 7},
+  [264] = {.lex_state = 7},
+  [265] = {.lex_state = 26},
+  [266] = {.lex_state = 7},
+  [267] = {.lex_state = 26},
+  [268] = {.lex_state = 26},
+  [269] = {.lex_state = 28},
+  [270] = {.lex_state = 26},
+  [271] = {.lex_state = 26},
+  [272] = {.lex_state = 7},
+  [273] = {.lex_state = 26},
+  [274] = {.lex_state = 5},
+  [275] = {.lex_state = 23},
+  [276] = {.lex_state = 23},
+  [277] = {.lex_state = 26},
+  [278] = {.lex_state = 26},
+  [279] = {.lex_state = 26},
+  [280] = {.lex_state = 26},
+  [281] = {.lex_state = 7},
+  [282] = {.lex_state = 26},
+  [283] = {.lex_state = 29, .external_lex_state = 2},
+  [284] = {.lex_state = 30, .external_lex_state = 2},
+  [285] = {.lex_state = 26},
+  [286] = {.lex_state = 26},
+  [287] = {.lex_state = 31},
+  [288] = {.lex_state = 31},
+  [289] = {.lex_state = 23},
+  [290] = {.lex_state = 23},
+  [291] = {.lex_state = 23},
+  [292] = {.lex_state = 23},
+  [293] = {.lex_state = 24},
+  [294] = {.lex_state = 7},
+  [295] = {.lex_state = 7},
+  [296] = {.lex_state = 23},
+  [297] = {.lex_state = 24},
+  [298] = {.lex_state = 24},
+  [299] = {.lex_state = 24},
+  [300] = {.lex_state = 24},
+  [301] = {.lex_state = 5},
+  [302] = {.lex_state = 5},
+  [303] = {.lex_state = 23},
+  [304] = {.lex_state = 5},
+  [305] = {.lex_state = 23},
+  [306] = {.lex_state = 23},
+  [307] = {.lex_state = 24},
+  [308] = {.lex_state = 23},
+  [309] = {.lex_state = 23},
+  [310] = {.lex_state = 23},
+  [311] = {.lex_state = 23},
+  [312] = {.lex_state = 23},
+  [313] = {.lex_state = 23},
+  [314] = {.lex_state = 23},
+  [315] = {.lex_state = 23},
+  [316] = {.lex_state = 23},
+  [317] = {.lex_state = 23},
+  [318] = {.lex_state = 23},
+  [319] = {.lex_state = 7},
+  [320] = {.lex_state = 7},
+  [321] = {.lex_state = 7},
+  [322] = {.lex_state = 7},
+  [323] = {.lex_state = 32},
+  [324] = {.lex_state = 33},
+  [325] = {.lex_state = 7},
+  [326] = {.lex_state = 7},
+  [327] = {.lex_state = 34},
+  [328] = {.lex_state = 7},
+  [329] = {.lex_state = 7},
+  [330] = {.lex_state = 7},
+  [331] = {.lex_state = 7},
+  [332] = {.lex_state = 7},
+  [333] = {.lex_state = 7},
+  [334] = {.lex_state = 7},
+  [335] = {.lex_state = 7},
+  [336] = {.lex_state = 35},
+  [337] = {.lex_state = 35},
+  [338] = {.lex_state = 35},
+  [339] = {.lex_state = 35},
+  [340] = {.lex_state = 35},
+  [341] = {.lex_state = 35},
+  [342] = {.lex_state = 35},
+  [343] = {.lex_state = 35},
+  [344] = {.lex_state = 35},
+  [345] = {.lex_state = 35},
+  [346] = {.lex_state = 35},
+  [347] = {.lex_state = 35},
+  [348] = {.lex_state = 35},
+  [349] = {.lex_state = 35},
+  [350] = {.lex_state = 35},
+  [351] = {.lex_state = 35},
+  [352] = {.lex_state = 35},
+  [353] = {.lex_state = 35},
+  [354] = {.lex_state = 35},
+  [355] = {.lex_state = 35},
+  [356] = {.lex_state = 35},
+  [357] = {.lex_state = 35},
+  [358] = {.lex_state = 35},
+  [359] = {.lex_state = 35},
+  [360] = {.lex_state = 35},
+  [361] = {.lex_state = 35},
+  [362] = {.lex_state = 36},
+  [363] = {.lex_state = 36},
+  [364] = {.lex_state = 30},
+  [365] = {.lex_state = 35},
+  [366] = {.lex_state = 7},
+  [367] = {.lex_state = 35},
+  [368] = {.lex_state = 35},
+  [369] = {.lex_state = 34},
+  [370] = {.lex_state = 30},
+  [371] = {.lex_state = 35},
+  [372] = {.lex_state = 30},
+  [373] = {.lex_state = 7},
+  [374] = {.lex_state = 26},
+  [375] = {.lex_state = 35},
+  [376] = {.lex_state = 35},
+  [377] = {.lex_state = 35},
+  [378] = {.lex_state = 35},
+  [379] = {.lex_state = 35},
+  [380] = {.lex_state = 35},
+  [381] = {.lex_state = 35},
+  [382] = {.lex_state = 35},
+  [383] = {.lex_state = 35},
+  [384] = {.lex_state = 7},
+  [385] = {.lex_state = 35},
+  [386] = {.lex_state = 35},
+  [387] = {.lex_state = 37},
+  [388] = {.lex_state = 38},
+  [389] = {.lex_state = 26},
+  [390] = {.lex_state = 7},
+  [391] = {.lex_state = 26},
+  [392] = {.lex_state = 35},
+  [393] = {.lex_state = 26},
+  [394] = {.lex_state = 36},
+  [395] = {.lex_state = 36},
+  [396] = {.lex_state = 36},
+  [397] = {.lex_state = 36},
+  [398] = {.lex_state = 36},
+  [399] = {.lex_state = 30},
+  [400] = {.lex_state = 29},
+  [401] = {.lex_state = 30},
+  [402] = {.lex_state = 26},
+  [403] = {.lex_state = 26},
+  [404] = {.lex_state = 36},
+  [405] = {.lex_state = 7},
+  [406] = {.lex_state = 26},
+  [407] = {.lex_state = 26},
+  [408] = {.lex_state = 26},
+  [409] = {.lex_state = 36},
+  [410] = {.lex_state = 26},
+  [411] = {.lex_state = 30},
+  [412] = {.lex_state = 35},
+  [413] = {.lex_state = 35},
+  [414] = {.lex_state = 35},
+  [415] = {.lex_state = 35},
+  [416] = {.lex_state = 35},
+  [417] = {.lex_state = 35},
+  [418] = {.lex_state = 35},
+  [419] = {.lex_state = 35},
+  [420] = {.lex_state = 35},
+  [421] = {.lex_state = 35},
+  [422] = {.lex_state = 35},
+  [423] = {.lex_state = 35},
+  [424] = {.lex_state = 35},
+  [425] = {.lex_state = 35},
+  [426] = {.lex_state = 35},
+  [427] = {.lex_state = 35},
+  [428] = {.lex_state = 35},
+  [429] = {.lex_state = 35},
+  [430] = {.lex_state = 35},
+  [431] = {.lex_state = 35},
+  [432] = {.lex_state = 35},
+  [433] = {.lex_state = 35},
+  [434] = {.lex_state = 35},
+  [435] = {.lex_state = 30},
+  [436] = {.lex_state = 35},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -3822,6 +4764,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_fixture] = ACTIONS(1),
     [anon_sym_hex] = ACTIONS(1),
     [anon_sym_data] = ACTIONS(1),
+    [anon_sym_AT] = ACTIONS(1),
     [anon_sym_encoding] = ACTIONS(1),
     [anon_sym_format] = ACTIONS(1),
     [anon_sym_selector] = ACTIONS(1),
@@ -3829,6 +4772,9 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_ATfile] = ACTIONS(1),
     [anon_sym_bench] = ACTIONS(1),
     [anon_sym_benchAsync] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_LBRACK] = ACTIONS(1),
+    [anon_sym_RBRACK] = ACTIONS(1),
     [anon_sym_tags] = ACTIONS(1),
     [anon_sym_skip] = ACTIONS(1),
     [anon_sym_validate] = ACTIONS(1),
@@ -3873,6 +4819,12 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_outlierDetection] = ACTIONS(1),
     [anon_sym_cvThreshold] = ACTIONS(1),
     [anon_sym_count] = ACTIONS(1),
+    [anon_sym_ciTarget] = ACTIONS(1),
+    [anon_sym_maxRuns] = ACTIONS(1),
+    [anon_sym_maxTime] = ACTIONS(1),
+    [anon_sym_counters] = ACTIONS(1),
+    [anon_sym_equivalent] = ACTIONS(1),
+    [anon_sym_external] = ACTIONS(1),
     [anon_sym_fairness] = ACTIONS(1),
     [anon_sym_fairnessSeed] = ACTIONS(1),
     [anon_sym_asyncSamplingPolicy] = ACTIONS(1),
@@ -3888,38 +4840,38 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_m] = ACTIONS(1),
     [anon_sym_true] = ACTIONS(1),
     [anon_sym_false] = ACTIONS(1),
-    [anon_sym_LBRACK] = ACTIONS(1),
-    [anon_sym_RBRACK] = ACTIONS(1),
-    [sym_comment] = ACTIONS(3),
+    [sym_comment] = ACTIONS(1),
     [sym_embedded_code] = ACTIONS(1),
     [sym__embedded_code_start] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(226),
-    [sym_use_statement] = STATE(71),
-    [sym_global_setup] = STATE(86),
-    [sym_suite] = STATE(85),
-    [aux_sym_source_file_repeat1] = STATE(71),
-    [aux_sym_source_file_repeat2] = STATE(85),
-    [ts_builtin_sym_end] = ACTIONS(5),
-    [anon_sym_use] = ACTIONS(7),
-    [anon_sym_globalSetup] = ACTIONS(9),
-    [anon_sym_declare] = ACTIONS(11),
-    [anon_sym_suite] = ACTIONS(13),
-    [sym_comment] = ACTIONS(3),
+    [sym_source_file] = STATE(323),
+    [sym_use_statement] = STATE(156),
+    [sym_global_setup] = STATE(154),
+    [sym_suite] = STATE(198),
+    [aux_sym_source_file_repeat1] = STATE(128),
+    [aux_sym_source_file_repeat2] = STATE(155),
+    [ts_builtin_sym_end] = ACTIONS(3),
+    [anon_sym_use] = ACTIONS(5),
+    [anon_sym_globalSetup] = ACTIONS(7),
+    [anon_sym_declare] = ACTIONS(9),
+    [anon_sym_suite] = ACTIONS(11),
+    [sym_comment] = ACTIONS(13),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
   [0] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(17), 4,
+    ACTIONS(17), 5,
       anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(15), 37,
+      anon_sym_RBRACK,
+    ACTIONS(15), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -3951,21 +4903,28 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [49] = 3,
-    ACTIONS(3), 1,
+  [56] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(21), 4,
+    ACTIONS(21), 5,
+      anon_sym_LBRACE,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
       anon_sym_RBRACK,
-    ACTIONS(19), 37,
+    ACTIONS(19), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -3997,21 +4956,27 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [98] = 3,
-    ACTIONS(3), 1,
+  [112] = 3,
+    ACTIONS(13), 1,
       sym_comment,
     ACTIONS(25), 4,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
       anon_sym_RBRACK,
-    ACTIONS(23), 37,
+    ACTIONS(23), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4043,20 +5008,27 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [147] = 3,
-    ACTIONS(3), 1,
+  [167] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(29), 3,
+    ACTIONS(29), 4,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(27), 37,
+      anon_sym_RBRACK,
+    ACTIONS(27), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4088,20 +5060,27 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [195] = 3,
-    ACTIONS(3), 1,
+  [222] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(33), 3,
+    ACTIONS(33), 4,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(31), 37,
+      anon_sym_RBRACK,
+    ACTIONS(31), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4133,20 +5112,27 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [243] = 3,
-    ACTIONS(3), 1,
+  [277] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(37), 3,
+    ACTIONS(37), 4,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(35), 37,
+      anon_sym_RBRACK,
+    ACTIONS(35), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4178,20 +5164,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [291] = 3,
-    ACTIONS(3), 1,
+  [332] = 3,
+    ACTIONS(13), 1,
       sym_comment,
     ACTIONS(41), 3,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(39), 37,
+    ACTIONS(39), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4223,20 +5215,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [339] = 3,
-    ACTIONS(3), 1,
+  [386] = 3,
+    ACTIONS(13), 1,
       sym_comment,
     ACTIONS(45), 3,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(43), 37,
+    ACTIONS(43), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4268,20 +5266,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [387] = 3,
-    ACTIONS(3), 1,
+  [440] = 3,
+    ACTIONS(13), 1,
       sym_comment,
     ACTIONS(49), 3,
       anon_sym_RBRACE,
       anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(47), 37,
+    ACTIONS(47), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4313,48 +5317,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [435] = 13,
-    ACTIONS(3), 1,
+  [494] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(51), 1,
-      sym_identifier,
-    ACTIONS(53), 1,
+    ACTIONS(53), 3,
       anon_sym_RBRACE,
-    ACTIONS(57), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(51), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
       anon_sym_hex,
-    ACTIONS(59), 1,
       anon_sym_data,
-    ACTIONS(61), 1,
       anon_sym_encoding,
-    ACTIONS(63), 1,
       anon_sym_format,
-    ACTIONS(65), 1,
       anon_sym_selector,
-    ACTIONS(67), 1,
       anon_sym_shape,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(248), 1,
-      sym_property_name,
-    STATE(13), 10,
-      sym__fixture_item,
-      sym_hex_property,
-      sym_data_property,
-      sym_encoding_property,
-      sym_format_property,
-      sym_selector_property,
-      sym_shape_property,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_fixture_body_repeat1,
-    ACTIONS(55), 19,
-      anon_sym_memory,
+      anon_sym_bench,
+      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4368,47 +5368,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [502] = 13,
-    ACTIONS(3), 1,
+  [548] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(51), 1,
-      sym_identifier,
-    ACTIONS(69), 1,
+    ACTIONS(57), 3,
       anon_sym_RBRACE,
-    ACTIONS(71), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(55), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_bench,
+      anon_sym_benchAsync,
       anon_sym_tags,
-    ACTIONS(73), 1,
       anon_sym_skip,
-    ACTIONS(75), 1,
       anon_sym_validate,
-    ACTIONS(77), 1,
       anon_sym_before,
-    ACTIONS(79), 1,
       anon_sym_after,
-    ACTIONS(81), 1,
       anon_sym_each,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(249), 1,
-      sym_property_name,
-    STATE(14), 10,
-      sym__benchmark_item,
-      sym_tags_property,
-      sym_skip_hook,
-      sym_validate_hook,
-      sym_before_hook,
-      sym_after_hook,
-      sym_each_hook,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_benchmark_body_repeat1,
-    ACTIONS(55), 19,
-      anon_sym_memory,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4422,47 +5419,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [569] = 13,
-    ACTIONS(3), 1,
+  [602] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(51), 1,
+    ACTIONS(61), 3,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(59), 43,
       sym_identifier,
-    ACTIONS(57), 1,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
       anon_sym_hex,
-    ACTIONS(59), 1,
       anon_sym_data,
-    ACTIONS(61), 1,
       anon_sym_encoding,
-    ACTIONS(63), 1,
       anon_sym_format,
-    ACTIONS(65), 1,
       anon_sym_selector,
-    ACTIONS(67), 1,
       anon_sym_shape,
-    ACTIONS(83), 1,
-      anon_sym_RBRACE,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(248), 1,
-      sym_property_name,
-    STATE(15), 10,
-      sym__fixture_item,
-      sym_hex_property,
-      sym_data_property,
-      sym_encoding_property,
-      sym_format_property,
-      sym_selector_property,
-      sym_shape_property,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_fixture_body_repeat1,
-    ACTIONS(55), 19,
-      anon_sym_memory,
+      anon_sym_bench,
+      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4476,47 +5470,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [636] = 13,
-    ACTIONS(3), 1,
+  [656] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(51), 1,
+    ACTIONS(65), 3,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(63), 43,
       sym_identifier,
-    ACTIONS(71), 1,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_bench,
+      anon_sym_benchAsync,
       anon_sym_tags,
-    ACTIONS(73), 1,
       anon_sym_skip,
-    ACTIONS(75), 1,
       anon_sym_validate,
-    ACTIONS(77), 1,
       anon_sym_before,
-    ACTIONS(79), 1,
       anon_sym_after,
-    ACTIONS(81), 1,
       anon_sym_each,
-    ACTIONS(85), 1,
-      anon_sym_RBRACE,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(249), 1,
-      sym_property_name,
-    STATE(16), 10,
-      sym__benchmark_item,
-      sym_tags_property,
-      sym_skip_hook,
-      sym_validate_hook,
-      sym_before_hook,
-      sym_after_hook,
-      sym_each_hook,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_benchmark_body_repeat1,
-    ACTIONS(55), 19,
-      anon_sym_memory,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4530,47 +5521,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [703] = 13,
-    ACTIONS(3), 1,
+  [710] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(87), 1,
-      sym_identifier,
-    ACTIONS(90), 1,
+    ACTIONS(69), 3,
       anon_sym_RBRACE,
-    ACTIONS(95), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(67), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
       anon_sym_hex,
-    ACTIONS(98), 1,
       anon_sym_data,
-    ACTIONS(101), 1,
       anon_sym_encoding,
-    ACTIONS(104), 1,
       anon_sym_format,
-    ACTIONS(107), 1,
       anon_sym_selector,
-    ACTIONS(110), 1,
       anon_sym_shape,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(248), 1,
-      sym_property_name,
-    STATE(15), 10,
-      sym__fixture_item,
-      sym_hex_property,
-      sym_data_property,
-      sym_encoding_property,
-      sym_format_property,
-      sym_selector_property,
-      sym_shape_property,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_fixture_body_repeat1,
-    ACTIONS(92), 19,
-      anon_sym_memory,
+      anon_sym_bench,
+      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4584,47 +5572,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [770] = 13,
-    ACTIONS(3), 1,
+  [764] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(113), 1,
-      sym_identifier,
-    ACTIONS(116), 1,
+    ACTIONS(73), 3,
       anon_sym_RBRACE,
-    ACTIONS(121), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(71), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_bench,
+      anon_sym_benchAsync,
       anon_sym_tags,
-    ACTIONS(124), 1,
       anon_sym_skip,
-    ACTIONS(127), 1,
       anon_sym_validate,
-    ACTIONS(130), 1,
       anon_sym_before,
-    ACTIONS(133), 1,
       anon_sym_after,
-    ACTIONS(136), 1,
       anon_sym_each,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(249), 1,
-      sym_property_name,
-    STATE(16), 10,
-      sym__benchmark_item,
-      sym_tags_property,
-      sym_skip_hook,
-      sym_validate_hook,
-      sym_before_hook,
-      sym_after_hook,
-      sym_each_hook,
-      sym_property,
-      sym_language_implementation,
-      aux_sym_benchmark_body_repeat1,
-    ACTIONS(118), 19,
-      anon_sym_memory,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4638,17 +5623,26 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [837] = 3,
-    ACTIONS(3), 1,
+  [818] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(141), 1,
+    ACTIONS(77), 3,
       anon_sym_RBRACE,
-    ACTIONS(139), 37,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(75), 43,
+      sym_identifier,
       anon_sym_globalSetup,
       anon_sym_memory,
       anon_sym_setup,
@@ -4680,30 +5674,38 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [883] = 3,
-    ACTIONS(3), 1,
+  [872] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(145), 1,
+    ACTIONS(81), 3,
       anon_sym_RBRACE,
-    ACTIONS(143), 37,
-      anon_sym_declare,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(79), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
       anon_sym_memory,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
+      anon_sym_setup,
+      anon_sym_fixture,
       anon_sym_hex,
       anon_sym_data,
       anon_sym_encoding,
       anon_sym_format,
       anon_sym_selector,
       anon_sym_shape,
+      anon_sym_bench,
+      anon_sym_benchAsync,
       anon_sym_tags,
       anon_sym_skip,
       anon_sym_validate,
@@ -4723,30 +5725,38 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [929] = 3,
-    ACTIONS(3), 1,
+  [926] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(149), 1,
+    ACTIONS(85), 3,
       anon_sym_RBRACE,
-    ACTIONS(147), 37,
-      anon_sym_declare,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(83), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
       anon_sym_memory,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
+      anon_sym_setup,
+      anon_sym_fixture,
       anon_sym_hex,
       anon_sym_data,
       anon_sym_encoding,
       anon_sym_format,
       anon_sym_selector,
       anon_sym_shape,
+      anon_sym_bench,
+      anon_sym_benchAsync,
       anon_sym_tags,
       anon_sym_skip,
       anon_sym_validate,
@@ -4766,44 +5776,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [975] = 12,
-    ACTIONS(3), 1,
+  [980] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(9), 1,
-      anon_sym_globalSetup,
-    ACTIONS(55), 1,
-      anon_sym_fairness,
-    ACTIONS(151), 1,
+    ACTIONS(89), 3,
       anon_sym_RBRACE,
-    ACTIONS(155), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(87), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
       anon_sym_setup,
-    ACTIONS(157), 1,
       anon_sym_fixture,
-    ACTIONS(159), 1,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_bench,
-    ACTIONS(161), 1,
       anon_sym_benchAsync,
-    ACTIONS(163), 1,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
-    STATE(217), 1,
-      sym_property_name,
-    STATE(21), 8,
-      sym_global_setup,
-      sym__suite_item,
-      sym_setup_block,
-      sym_fixture,
-      sym_benchmark,
-      sym_after_block,
-      sym_property,
-      aux_sym_suite_body_repeat1,
-    ACTIONS(153), 18,
-      anon_sym_memory,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4817,42 +5827,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [1036] = 12,
-    ACTIONS(3), 1,
+  [1034] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(165), 1,
-      anon_sym_globalSetup,
-    ACTIONS(168), 1,
+    ACTIONS(93), 3,
       anon_sym_RBRACE,
-    ACTIONS(173), 1,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(91), 43,
+      sym_identifier,
+      anon_sym_globalSetup,
+      anon_sym_memory,
       anon_sym_setup,
-    ACTIONS(176), 1,
       anon_sym_fixture,
-    ACTIONS(179), 1,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_bench,
-    ACTIONS(182), 1,
       anon_sym_benchAsync,
-    ACTIONS(185), 1,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
-    ACTIONS(188), 1,
-      anon_sym_fairness,
-    STATE(217), 1,
-      sym_property_name,
-    STATE(21), 8,
-      sym_global_setup,
-      sym__suite_item,
-      sym_setup_block,
-      sym_fixture,
-      sym_benchmark,
-      sym_after_block,
-      sym_property,
-      aux_sym_suite_body_repeat1,
-    ACTIONS(170), 18,
-      anon_sym_memory,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4866,42 +5878,44 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [1097] = 12,
-    ACTIONS(3), 1,
+  [1088] = 3,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(9), 1,
+    ACTIONS(97), 3,
+      anon_sym_RBRACE,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+    ACTIONS(95), 43,
+      sym_identifier,
       anon_sym_globalSetup,
-    ACTIONS(55), 1,
-      anon_sym_fairness,
-    ACTIONS(155), 1,
+      anon_sym_memory,
       anon_sym_setup,
-    ACTIONS(157), 1,
       anon_sym_fixture,
-    ACTIONS(159), 1,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_bench,
-    ACTIONS(161), 1,
       anon_sym_benchAsync,
-    ACTIONS(163), 1,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
-    ACTIONS(191), 1,
-      anon_sym_RBRACE,
-    STATE(217), 1,
-      sym_property_name,
-    STATE(20), 8,
-      sym_global_setup,
-      sym__suite_item,
-      sym_setup_block,
-      sym_fixture,
-      sym_benchmark,
-      sym_after_block,
-      sym_property,
-      aux_sym_suite_body_repeat1,
-    ACTIONS(153), 18,
-      anon_sym_memory,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -4915,247 +5929,600 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [1158] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(195), 1,
+  [1142] = 46,
+    ACTIONS(99), 1,
+      sym_identifier,
+    ACTIONS(101), 1,
       anon_sym_RBRACE,
-    ACTIONS(193), 32,
+    ACTIONS(103), 1,
       anon_sym_memory,
+    ACTIONS(105), 1,
       anon_sym_hex,
+    ACTIONS(107), 1,
       anon_sym_data,
+    ACTIONS(109), 1,
       anon_sym_encoding,
+    ACTIONS(111), 1,
       anon_sym_format,
+    ACTIONS(113), 1,
       anon_sym_selector,
+    ACTIONS(115), 1,
       anon_sym_shape,
+    ACTIONS(117), 1,
+      anon_sym_description,
+    ACTIONS(119), 1,
+      anon_sym_baseline,
+    ACTIONS(121), 1,
+      anon_sym_iterations,
+    ACTIONS(123), 1,
+      anon_sym_warmup,
+    ACTIONS(125), 1,
+      anon_sym_timeout,
+    ACTIONS(127), 1,
+      anon_sym_requires,
+    ACTIONS(129), 1,
+      anon_sym_order,
+    ACTIONS(131), 1,
+      anon_sym_mode,
+    ACTIONS(133), 1,
+      anon_sym_targetTime,
+    ACTIONS(135), 1,
+      anon_sym_sink,
+    ACTIONS(137), 1,
+      anon_sym_outlierDetection,
+    ACTIONS(139), 1,
+      anon_sym_cvThreshold,
+    ACTIONS(141), 1,
+      anon_sym_count,
+    ACTIONS(143), 1,
+      anon_sym_ciTarget,
+    ACTIONS(145), 1,
+      anon_sym_maxRuns,
+    ACTIONS(147), 1,
+      anon_sym_maxTime,
+    ACTIONS(149), 1,
+      anon_sym_counters,
+    ACTIONS(151), 1,
+      anon_sym_equivalent,
+    ACTIONS(153), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
+      anon_sym_fairness,
+    ACTIONS(157), 1,
+      anon_sym_fairnessSeed,
+    ACTIONS(159), 1,
+      anon_sym_asyncSamplingPolicy,
+    ACTIONS(161), 1,
+      anon_sym_asyncWarmupCap,
+    ACTIONS(163), 1,
+      anon_sym_asyncSampleCap,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(49), 1,
+      sym__fixture_item,
+    STATE(42), 1,
+      sym_hex_property,
+    STATE(43), 1,
+      sym_data_property,
+    STATE(44), 1,
+      sym_encoding_property,
+    STATE(45), 1,
+      sym_format_property,
+    STATE(46), 1,
+      sym_selector_property,
+    STATE(47), 1,
+      sym_shape_property,
+    STATE(41), 1,
+      sym_property,
+    STATE(382), 1,
+      sym_property_name,
+    STATE(48), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(25), 1,
+      aux_sym_fixture_body_repeat1,
+  [1281] = 46,
+    ACTIONS(99), 1,
+      sym_identifier,
+    ACTIONS(165), 1,
+      anon_sym_RBRACE,
+    ACTIONS(103), 1,
+      anon_sym_memory,
+    ACTIONS(167), 1,
       anon_sym_tags,
+    ACTIONS(169), 1,
       anon_sym_skip,
+    ACTIONS(171), 1,
       anon_sym_validate,
+    ACTIONS(173), 1,
       anon_sym_before,
+    ACTIONS(175), 1,
       anon_sym_after,
+    ACTIONS(177), 1,
       anon_sym_each,
+    ACTIONS(117), 1,
       anon_sym_description,
+    ACTIONS(119), 1,
       anon_sym_baseline,
+    ACTIONS(121), 1,
       anon_sym_iterations,
+    ACTIONS(123), 1,
       anon_sym_warmup,
+    ACTIONS(125), 1,
       anon_sym_timeout,
+    ACTIONS(127), 1,
       anon_sym_requires,
+    ACTIONS(129), 1,
       anon_sym_order,
+    ACTIONS(131), 1,
       anon_sym_mode,
+    ACTIONS(133), 1,
       anon_sym_targetTime,
+    ACTIONS(135), 1,
       anon_sym_sink,
+    ACTIONS(137), 1,
       anon_sym_outlierDetection,
+    ACTIONS(139), 1,
       anon_sym_cvThreshold,
+    ACTIONS(141), 1,
       anon_sym_count,
+    ACTIONS(143), 1,
+      anon_sym_ciTarget,
+    ACTIONS(145), 1,
+      anon_sym_maxRuns,
+    ACTIONS(147), 1,
+      anon_sym_maxTime,
+    ACTIONS(149), 1,
+      anon_sym_counters,
+    ACTIONS(151), 1,
+      anon_sym_equivalent,
+    ACTIONS(153), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
       anon_sym_fairness,
+    ACTIONS(157), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(159), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(161), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(163), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1199] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(199), 1,
+    STATE(58), 1,
+      sym__benchmark_item,
+    STATE(51), 1,
+      sym_tags_property,
+    STATE(52), 1,
+      sym_skip_hook,
+    STATE(53), 1,
+      sym_validate_hook,
+    STATE(54), 1,
+      sym_before_hook,
+    STATE(55), 1,
+      sym_after_hook,
+    STATE(56), 1,
+      sym_each_hook,
+    STATE(50), 1,
+      sym_property,
+    STATE(386), 1,
+      sym_property_name,
+    STATE(57), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(26), 1,
+      aux_sym_benchmark_body_repeat1,
+  [1420] = 46,
+    ACTIONS(99), 1,
+      sym_identifier,
+    ACTIONS(179), 1,
       anon_sym_RBRACE,
-    STATE(5), 1,
-      sym_duration_unit,
-    ACTIONS(201), 3,
-      anon_sym_ms,
-      anon_sym_s,
-      anon_sym_m,
-    ACTIONS(197), 26,
+    ACTIONS(103), 1,
       anon_sym_memory,
+    ACTIONS(105), 1,
       anon_sym_hex,
+    ACTIONS(107), 1,
       anon_sym_data,
+    ACTIONS(109), 1,
       anon_sym_encoding,
+    ACTIONS(111), 1,
       anon_sym_format,
+    ACTIONS(113), 1,
       anon_sym_selector,
+    ACTIONS(115), 1,
       anon_sym_shape,
+    ACTIONS(117), 1,
       anon_sym_description,
+    ACTIONS(119), 1,
       anon_sym_baseline,
+    ACTIONS(121), 1,
       anon_sym_iterations,
+    ACTIONS(123), 1,
       anon_sym_warmup,
+    ACTIONS(125), 1,
       anon_sym_timeout,
+    ACTIONS(127), 1,
       anon_sym_requires,
+    ACTIONS(129), 1,
       anon_sym_order,
+    ACTIONS(131), 1,
       anon_sym_mode,
+    ACTIONS(133), 1,
       anon_sym_targetTime,
+    ACTIONS(135), 1,
       anon_sym_sink,
+    ACTIONS(137), 1,
       anon_sym_outlierDetection,
+    ACTIONS(139), 1,
       anon_sym_cvThreshold,
+    ACTIONS(141), 1,
       anon_sym_count,
+    ACTIONS(143), 1,
+      anon_sym_ciTarget,
+    ACTIONS(145), 1,
+      anon_sym_maxRuns,
+    ACTIONS(147), 1,
+      anon_sym_maxTime,
+    ACTIONS(149), 1,
+      anon_sym_counters,
+    ACTIONS(151), 1,
+      anon_sym_equivalent,
+    ACTIONS(153), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
       anon_sym_fairness,
+    ACTIONS(157), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(159), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(161), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(163), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1242] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(199), 1,
+    STATE(49), 1,
+      sym__fixture_item,
+    STATE(42), 1,
+      sym_hex_property,
+    STATE(43), 1,
+      sym_data_property,
+    STATE(44), 1,
+      sym_encoding_property,
+    STATE(45), 1,
+      sym_format_property,
+    STATE(46), 1,
+      sym_selector_property,
+    STATE(47), 1,
+      sym_shape_property,
+    STATE(41), 1,
+      sym_property,
+    STATE(382), 1,
+      sym_property_name,
+    STATE(48), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(27), 1,
+      aux_sym_fixture_body_repeat1,
+  [1559] = 46,
+    ACTIONS(99), 1,
+      sym_identifier,
+    ACTIONS(181), 1,
       anon_sym_RBRACE,
-    STATE(5), 1,
-      sym_duration_unit,
-    ACTIONS(201), 3,
-      anon_sym_ms,
-      anon_sym_s,
-      anon_sym_m,
-    ACTIONS(197), 26,
+    ACTIONS(103), 1,
       anon_sym_memory,
+    ACTIONS(167), 1,
       anon_sym_tags,
+    ACTIONS(169), 1,
       anon_sym_skip,
+    ACTIONS(171), 1,
       anon_sym_validate,
+    ACTIONS(173), 1,
       anon_sym_before,
+    ACTIONS(175), 1,
       anon_sym_after,
+    ACTIONS(177), 1,
       anon_sym_each,
+    ACTIONS(117), 1,
       anon_sym_description,
+    ACTIONS(119), 1,
       anon_sym_baseline,
+    ACTIONS(121), 1,
       anon_sym_iterations,
+    ACTIONS(123), 1,
       anon_sym_warmup,
+    ACTIONS(125), 1,
       anon_sym_timeout,
+    ACTIONS(127), 1,
       anon_sym_requires,
+    ACTIONS(129), 1,
       anon_sym_order,
+    ACTIONS(131), 1,
       anon_sym_mode,
+    ACTIONS(133), 1,
       anon_sym_targetTime,
+    ACTIONS(135), 1,
       anon_sym_sink,
+    ACTIONS(137), 1,
       anon_sym_outlierDetection,
+    ACTIONS(139), 1,
       anon_sym_cvThreshold,
+    ACTIONS(141), 1,
       anon_sym_count,
+    ACTIONS(143), 1,
+      anon_sym_ciTarget,
+    ACTIONS(145), 1,
+      anon_sym_maxRuns,
+    ACTIONS(147), 1,
+      anon_sym_maxTime,
+    ACTIONS(149), 1,
+      anon_sym_counters,
+    ACTIONS(151), 1,
+      anon_sym_equivalent,
+    ACTIONS(153), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
       anon_sym_fairness,
+    ACTIONS(157), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(159), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(161), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(163), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1285] = 6,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(203), 1,
-      anon_sym_ms,
-    STATE(5), 1,
-      sym_duration_unit,
-    ACTIONS(197), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(201), 2,
-      anon_sym_s,
-      anon_sym_m,
-    ACTIONS(199), 24,
-      anon_sym_globalSetup,
+    STATE(58), 1,
+      sym__benchmark_item,
+    STATE(51), 1,
+      sym_tags_property,
+    STATE(52), 1,
+      sym_skip_hook,
+    STATE(53), 1,
+      sym_validate_hook,
+    STATE(54), 1,
+      sym_before_hook,
+    STATE(55), 1,
+      sym_after_hook,
+    STATE(56), 1,
+      sym_each_hook,
+    STATE(50), 1,
+      sym_property,
+    STATE(386), 1,
+      sym_property_name,
+    STATE(57), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(28), 1,
+      aux_sym_benchmark_body_repeat1,
+  [1698] = 46,
+    ACTIONS(183), 1,
+      sym_identifier,
+    ACTIONS(186), 1,
       anon_sym_RBRACE,
+    ACTIONS(188), 1,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+    ACTIONS(191), 1,
+      anon_sym_hex,
+    ACTIONS(194), 1,
+      anon_sym_data,
+    ACTIONS(197), 1,
+      anon_sym_encoding,
+    ACTIONS(200), 1,
+      anon_sym_format,
+    ACTIONS(203), 1,
+      anon_sym_selector,
+    ACTIONS(206), 1,
+      anon_sym_shape,
+    ACTIONS(209), 1,
       anon_sym_description,
+    ACTIONS(212), 1,
       anon_sym_baseline,
+    ACTIONS(215), 1,
       anon_sym_iterations,
+    ACTIONS(218), 1,
       anon_sym_warmup,
+    ACTIONS(221), 1,
       anon_sym_timeout,
+    ACTIONS(224), 1,
       anon_sym_requires,
+    ACTIONS(227), 1,
       anon_sym_order,
+    ACTIONS(230), 1,
       anon_sym_mode,
+    ACTIONS(233), 1,
       anon_sym_targetTime,
+    ACTIONS(236), 1,
       anon_sym_sink,
+    ACTIONS(239), 1,
       anon_sym_outlierDetection,
+    ACTIONS(242), 1,
       anon_sym_cvThreshold,
+    ACTIONS(245), 1,
       anon_sym_count,
+    ACTIONS(248), 1,
+      anon_sym_ciTarget,
+    ACTIONS(251), 1,
+      anon_sym_maxRuns,
+    ACTIONS(254), 1,
+      anon_sym_maxTime,
+    ACTIONS(257), 1,
+      anon_sym_counters,
+    ACTIONS(260), 1,
+      anon_sym_equivalent,
+    ACTIONS(263), 1,
+      anon_sym_external,
+    ACTIONS(266), 1,
+      anon_sym_fairness,
+    ACTIONS(269), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(272), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(275), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(278), 1,
       anon_sym_asyncSampleCap,
-  [1329] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(207), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(205), 27,
-      ts_builtin_sym_end,
-      anon_sym_globalSetup,
+    STATE(49), 1,
+      sym__fixture_item,
+    STATE(42), 1,
+      sym_hex_property,
+    STATE(43), 1,
+      sym_data_property,
+    STATE(44), 1,
+      sym_encoding_property,
+    STATE(45), 1,
+      sym_format_property,
+    STATE(46), 1,
+      sym_selector_property,
+    STATE(47), 1,
+      sym_shape_property,
+    STATE(41), 1,
+      sym_property,
+    STATE(382), 1,
+      sym_property_name,
+    STATE(48), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(27), 1,
+      aux_sym_fixture_body_repeat1,
+  [1837] = 46,
+    ACTIONS(281), 1,
+      sym_identifier,
+    ACTIONS(284), 1,
       anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_suite,
+    ACTIONS(286), 1,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+    ACTIONS(289), 1,
+      anon_sym_tags,
+    ACTIONS(292), 1,
+      anon_sym_skip,
+    ACTIONS(295), 1,
+      anon_sym_validate,
+    ACTIONS(298), 1,
+      anon_sym_before,
+    ACTIONS(301), 1,
       anon_sym_after,
+    ACTIONS(304), 1,
+      anon_sym_each,
+    ACTIONS(307), 1,
       anon_sym_description,
+    ACTIONS(310), 1,
       anon_sym_baseline,
+    ACTIONS(313), 1,
       anon_sym_iterations,
+    ACTIONS(316), 1,
       anon_sym_warmup,
+    ACTIONS(319), 1,
       anon_sym_timeout,
+    ACTIONS(322), 1,
       anon_sym_requires,
+    ACTIONS(325), 1,
       anon_sym_order,
+    ACTIONS(328), 1,
       anon_sym_mode,
+    ACTIONS(331), 1,
       anon_sym_targetTime,
+    ACTIONS(334), 1,
       anon_sym_sink,
+    ACTIONS(337), 1,
       anon_sym_outlierDetection,
+    ACTIONS(340), 1,
       anon_sym_cvThreshold,
+    ACTIONS(343), 1,
       anon_sym_count,
+    ACTIONS(346), 1,
+      anon_sym_ciTarget,
+    ACTIONS(349), 1,
+      anon_sym_maxRuns,
+    ACTIONS(352), 1,
+      anon_sym_maxTime,
+    ACTIONS(355), 1,
+      anon_sym_counters,
+    ACTIONS(358), 1,
+      anon_sym_equivalent,
+    ACTIONS(361), 1,
+      anon_sym_external,
+    ACTIONS(364), 1,
+      anon_sym_fairness,
+    ACTIONS(367), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(370), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(373), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(376), 1,
       anon_sym_asyncSampleCap,
-  [1366] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(211), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(209), 27,
-      ts_builtin_sym_end,
-      anon_sym_globalSetup,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_suite,
-      anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
-      anon_sym_description,
-      anon_sym_baseline,
-      anon_sym_iterations,
-      anon_sym_warmup,
-      anon_sym_timeout,
-      anon_sym_requires,
-      anon_sym_order,
-      anon_sym_mode,
-      anon_sym_targetTime,
-      anon_sym_sink,
-      anon_sym_outlierDetection,
-      anon_sym_cvThreshold,
-      anon_sym_count,
-      anon_sym_fairnessSeed,
-      anon_sym_asyncSamplingPolicy,
-      anon_sym_asyncWarmupCap,
-      anon_sym_asyncSampleCap,
-  [1403] = 3,
-    ACTIONS(3), 1,
+    STATE(58), 1,
+      sym__benchmark_item,
+    STATE(51), 1,
+      sym_tags_property,
+    STATE(52), 1,
+      sym_skip_hook,
+    STATE(53), 1,
+      sym_validate_hook,
+    STATE(54), 1,
+      sym_before_hook,
+    STATE(55), 1,
+      sym_after_hook,
+    STATE(56), 1,
+      sym_each_hook,
+    STATE(50), 1,
+      sym_property,
+    STATE(386), 1,
+      sym_property_name,
+    STATE(57), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(28), 1,
+      aux_sym_benchmark_body_repeat1,
+  [1976] = 3,
+    ACTIONS(381), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(215), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(213), 27,
-      ts_builtin_sym_end,
+    ACTIONS(379), 43,
+      sym_identifier,
       anon_sym_globalSetup,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_suite,
       anon_sym_memory,
       anon_sym_setup,
       anon_sym_fixture,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_bench,
       anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5169,49 +6536,36 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [1440] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(219), 1,
+  [2028] = 3,
+    ACTIONS(385), 1,
       anon_sym_RBRACE,
-    ACTIONS(217), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(383), 43,
+      sym_identifier,
+      anon_sym_declare,
       anon_sym_memory,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
       anon_sym_hex,
       anon_sym_data,
       anon_sym_encoding,
       anon_sym_format,
       anon_sym_selector,
       anon_sym_shape,
-      anon_sym_description,
-      anon_sym_baseline,
-      anon_sym_iterations,
-      anon_sym_warmup,
-      anon_sym_timeout,
-      anon_sym_requires,
-      anon_sym_order,
-      anon_sym_mode,
-      anon_sym_targetTime,
-      anon_sym_sink,
-      anon_sym_outlierDetection,
-      anon_sym_cvThreshold,
-      anon_sym_count,
-      anon_sym_fairness,
-      anon_sym_fairnessSeed,
-      anon_sym_asyncSamplingPolicy,
-      anon_sym_asyncWarmupCap,
-      anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1475] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(223), 1,
-      anon_sym_RBRACE,
-    ACTIONS(221), 26,
-      anon_sym_memory,
       anon_sym_tags,
       anon_sym_skip,
       anon_sym_validate,
@@ -5231,19 +6585,36 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1510] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(227), 1,
+  [2080] = 3,
+    ACTIONS(389), 1,
       anon_sym_RBRACE,
-    ACTIONS(225), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(387), 43,
+      sym_identifier,
+      anon_sym_declare,
       anon_sym_memory,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_tags,
       anon_sym_skip,
       anon_sym_validate,
@@ -5263,115 +6634,286 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1545] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(231), 1,
+  [2132] = 42,
+    ACTIONS(391), 1,
+      anon_sym_globalSetup,
+    ACTIONS(393), 1,
       anon_sym_RBRACE,
-    ACTIONS(229), 26,
+    ACTIONS(395), 1,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
+    ACTIONS(397), 1,
+      anon_sym_setup,
+    ACTIONS(399), 1,
+      anon_sym_fixture,
+    ACTIONS(401), 1,
+      anon_sym_bench,
+    ACTIONS(403), 1,
+      anon_sym_benchAsync,
+    ACTIONS(405), 1,
       anon_sym_after,
-      anon_sym_each,
+    ACTIONS(407), 1,
       anon_sym_description,
+    ACTIONS(409), 1,
       anon_sym_baseline,
+    ACTIONS(411), 1,
       anon_sym_iterations,
+    ACTIONS(413), 1,
       anon_sym_warmup,
+    ACTIONS(415), 1,
       anon_sym_timeout,
+    ACTIONS(417), 1,
       anon_sym_requires,
+    ACTIONS(419), 1,
       anon_sym_order,
+    ACTIONS(421), 1,
       anon_sym_mode,
+    ACTIONS(423), 1,
       anon_sym_targetTime,
+    ACTIONS(425), 1,
       anon_sym_sink,
+    ACTIONS(427), 1,
       anon_sym_outlierDetection,
+    ACTIONS(429), 1,
       anon_sym_cvThreshold,
+    ACTIONS(141), 1,
       anon_sym_count,
+    ACTIONS(431), 1,
+      anon_sym_ciTarget,
+    ACTIONS(433), 1,
+      anon_sym_maxRuns,
+    ACTIONS(435), 1,
+      anon_sym_maxTime,
+    ACTIONS(437), 1,
+      anon_sym_counters,
+    ACTIONS(439), 1,
+      anon_sym_equivalent,
+    ACTIONS(441), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
       anon_sym_fairness,
+    ACTIONS(443), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(445), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(447), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(449), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1580] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(235), 1,
+    STATE(91), 1,
+      sym_global_setup,
+    STATE(96), 1,
+      sym__suite_item,
+    STATE(92), 1,
+      sym_setup_block,
+    STATE(93), 1,
+      sym_fixture,
+    STATE(94), 1,
+      sym_benchmark,
+    STATE(95), 1,
+      sym_after_block,
+    STATE(90), 1,
+      sym_property,
+    STATE(336), 1,
+      sym_property_name,
+    STATE(33), 1,
+      aux_sym_suite_body_repeat1,
+  [2259] = 42,
+    ACTIONS(391), 1,
+      anon_sym_globalSetup,
+    ACTIONS(451), 1,
       anon_sym_RBRACE,
-    ACTIONS(233), 26,
+    ACTIONS(395), 1,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
+    ACTIONS(397), 1,
+      anon_sym_setup,
+    ACTIONS(399), 1,
+      anon_sym_fixture,
+    ACTIONS(401), 1,
+      anon_sym_bench,
+    ACTIONS(403), 1,
+      anon_sym_benchAsync,
+    ACTIONS(405), 1,
       anon_sym_after,
-      anon_sym_each,
+    ACTIONS(407), 1,
       anon_sym_description,
+    ACTIONS(409), 1,
       anon_sym_baseline,
+    ACTIONS(411), 1,
       anon_sym_iterations,
+    ACTIONS(413), 1,
       anon_sym_warmup,
+    ACTIONS(415), 1,
       anon_sym_timeout,
+    ACTIONS(417), 1,
       anon_sym_requires,
+    ACTIONS(419), 1,
       anon_sym_order,
+    ACTIONS(421), 1,
       anon_sym_mode,
+    ACTIONS(423), 1,
       anon_sym_targetTime,
+    ACTIONS(425), 1,
       anon_sym_sink,
+    ACTIONS(427), 1,
       anon_sym_outlierDetection,
+    ACTIONS(429), 1,
       anon_sym_cvThreshold,
+    ACTIONS(141), 1,
       anon_sym_count,
+    ACTIONS(431), 1,
+      anon_sym_ciTarget,
+    ACTIONS(433), 1,
+      anon_sym_maxRuns,
+    ACTIONS(435), 1,
+      anon_sym_maxTime,
+    ACTIONS(437), 1,
+      anon_sym_counters,
+    ACTIONS(439), 1,
+      anon_sym_equivalent,
+    ACTIONS(441), 1,
+      anon_sym_external,
+    ACTIONS(155), 1,
       anon_sym_fairness,
+    ACTIONS(443), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(445), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(447), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(449), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1615] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(239), 1,
+    STATE(91), 1,
+      sym_global_setup,
+    STATE(96), 1,
+      sym__suite_item,
+    STATE(92), 1,
+      sym_setup_block,
+    STATE(93), 1,
+      sym_fixture,
+    STATE(94), 1,
+      sym_benchmark,
+    STATE(95), 1,
+      sym_after_block,
+    STATE(90), 1,
+      sym_property,
+    STATE(336), 1,
+      sym_property_name,
+    STATE(34), 1,
+      aux_sym_suite_body_repeat1,
+  [2386] = 42,
+    ACTIONS(453), 1,
+      anon_sym_globalSetup,
+    ACTIONS(456), 1,
       anon_sym_RBRACE,
-    ACTIONS(237), 26,
+    ACTIONS(458), 1,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
+    ACTIONS(461), 1,
+      anon_sym_setup,
+    ACTIONS(464), 1,
+      anon_sym_fixture,
+    ACTIONS(467), 1,
+      anon_sym_bench,
+    ACTIONS(470), 1,
+      anon_sym_benchAsync,
+    ACTIONS(473), 1,
       anon_sym_after,
-      anon_sym_each,
+    ACTIONS(476), 1,
       anon_sym_description,
+    ACTIONS(479), 1,
       anon_sym_baseline,
+    ACTIONS(482), 1,
       anon_sym_iterations,
+    ACTIONS(485), 1,
       anon_sym_warmup,
+    ACTIONS(488), 1,
       anon_sym_timeout,
+    ACTIONS(491), 1,
       anon_sym_requires,
+    ACTIONS(494), 1,
       anon_sym_order,
+    ACTIONS(497), 1,
       anon_sym_mode,
+    ACTIONS(500), 1,
       anon_sym_targetTime,
+    ACTIONS(503), 1,
       anon_sym_sink,
+    ACTIONS(506), 1,
       anon_sym_outlierDetection,
+    ACTIONS(509), 1,
       anon_sym_cvThreshold,
+    ACTIONS(512), 1,
       anon_sym_count,
+    ACTIONS(515), 1,
+      anon_sym_ciTarget,
+    ACTIONS(518), 1,
+      anon_sym_maxRuns,
+    ACTIONS(521), 1,
+      anon_sym_maxTime,
+    ACTIONS(524), 1,
+      anon_sym_counters,
+    ACTIONS(527), 1,
+      anon_sym_equivalent,
+    ACTIONS(530), 1,
+      anon_sym_external,
+    ACTIONS(533), 1,
       anon_sym_fairness,
+    ACTIONS(536), 1,
       anon_sym_fairnessSeed,
+    ACTIONS(539), 1,
       anon_sym_asyncSamplingPolicy,
+    ACTIONS(542), 1,
       anon_sym_asyncWarmupCap,
+    ACTIONS(545), 1,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1650] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(243), 1,
+    STATE(91), 1,
+      sym_global_setup,
+    STATE(96), 1,
+      sym__suite_item,
+    STATE(92), 1,
+      sym_setup_block,
+    STATE(93), 1,
+      sym_fixture,
+    STATE(94), 1,
+      sym_benchmark,
+    STATE(95), 1,
+      sym_after_block,
+    STATE(90), 1,
+      sym_property,
+    STATE(336), 1,
+      sym_property_name,
+    STATE(34), 1,
+      aux_sym_suite_body_repeat1,
+  [2513] = 3,
+    ACTIONS(550), 1,
       anon_sym_RBRACE,
-    ACTIONS(241), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(548), 38,
+      sym_identifier,
       anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_tags,
       anon_sym_skip,
       anon_sym_validate,
@@ -5391,18 +6933,24 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1685] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(247), 1,
+  [2560] = 3,
+    ACTIONS(554), 1,
       anon_sym_RBRACE,
-    ACTIONS(245), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(552), 38,
+      sym_identifier,
       anon_sym_memory,
       anon_sym_hex,
       anon_sym_data,
@@ -5410,6 +6958,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_format,
       anon_sym_selector,
       anon_sym_shape,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5423,18 +6977,24 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1720] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(251), 1,
+  [2607] = 3,
+    ACTIONS(558), 1,
       anon_sym_RBRACE,
-    ACTIONS(249), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(556), 38,
+      sym_identifier,
       anon_sym_memory,
       anon_sym_hex,
       anon_sym_data,
@@ -5442,6 +7002,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_format,
       anon_sym_selector,
       anon_sym_shape,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5455,18 +7021,32 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1755] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(255), 1,
+  [2654] = 7,
+    ACTIONS(562), 1,
       anon_sym_RBRACE,
-    ACTIONS(253), 26,
+    ACTIONS(564), 1,
+      anon_sym_ms,
+    ACTIONS(566), 1,
+      anon_sym_s,
+    ACTIONS(568), 1,
+      anon_sym_m,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(14), 1,
+      sym_duration_unit,
+    ACTIONS(560), 32,
+      sym_identifier,
       anon_sym_memory,
       anon_sym_hex,
       anon_sym_data,
@@ -5487,25 +7067,39 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1790] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(259), 1,
+  [2707] = 7,
+    ACTIONS(562), 1,
       anon_sym_RBRACE,
-    ACTIONS(257), 26,
+    ACTIONS(564), 1,
+      anon_sym_ms,
+    ACTIONS(566), 1,
+      anon_sym_s,
+    ACTIONS(568), 1,
+      anon_sym_m,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(14), 1,
+      sym_duration_unit,
+    ACTIONS(560), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_hex,
-      anon_sym_data,
-      anon_sym_encoding,
-      anon_sym_format,
-      anon_sym_selector,
-      anon_sym_shape,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5519,25 +7113,40 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1825] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(263), 1,
+  [2760] = 7,
+    ACTIONS(570), 1,
+      anon_sym_ms,
+    ACTIONS(566), 1,
+      anon_sym_s,
+    ACTIONS(568), 1,
+      anon_sym_m,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(14), 1,
+      sym_duration_unit,
+    ACTIONS(560), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(562), 29,
+      anon_sym_globalSetup,
       anon_sym_RBRACE,
-    ACTIONS(261), 26,
       anon_sym_memory,
-      anon_sym_hex,
-      anon_sym_data,
-      anon_sym_encoding,
-      anon_sym_format,
-      anon_sym_selector,
-      anon_sym_shape,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5550,19 +7159,23 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_sink,
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
-      anon_sym_count,
-      anon_sym_fairness,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1860] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(267), 1,
+  [2812] = 3,
+    ACTIONS(574), 1,
       anon_sym_RBRACE,
-    ACTIONS(265), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(572), 32,
+      sym_identifier,
       anon_sym_memory,
       anon_sym_hex,
       anon_sym_data,
@@ -5583,25 +7196,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1895] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(271), 1,
+  [2853] = 3,
+    ACTIONS(578), 1,
       anon_sym_RBRACE,
-    ACTIONS(269), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(576), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
-      anon_sym_after,
-      anon_sym_each,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5615,25 +7234,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1930] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(275), 1,
+  [2894] = 3,
+    ACTIONS(582), 1,
       anon_sym_RBRACE,
-    ACTIONS(273), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(580), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
-      anon_sym_after,
-      anon_sym_each,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5647,25 +7272,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [1965] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(279), 1,
+  [2935] = 3,
+    ACTIONS(586), 1,
       anon_sym_RBRACE,
-    ACTIONS(277), 26,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(584), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_tags,
-      anon_sym_skip,
-      anon_sym_validate,
-      anon_sym_before,
-      anon_sym_after,
-      anon_sym_each,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5679,26 +7310,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-      sym_identifier,
-  [2000] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(283), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(281), 24,
-      anon_sym_globalSetup,
+  [2976] = 3,
+    ACTIONS(590), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(588), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5712,24 +7348,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2034] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(287), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(285), 24,
-      anon_sym_globalSetup,
+  [3017] = 3,
+    ACTIONS(594), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(592), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5743,24 +7386,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2068] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(291), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(289), 24,
-      anon_sym_globalSetup,
+  [3058] = 3,
+    ACTIONS(598), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(596), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5774,24 +7424,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2102] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(295), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(293), 24,
-      anon_sym_globalSetup,
+  [3099] = 3,
+    ACTIONS(602), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(600), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5805,24 +7462,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2136] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(299), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(297), 24,
-      anon_sym_globalSetup,
+  [3140] = 3,
+    ACTIONS(606), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(604), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
-      anon_sym_after,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5836,24 +7500,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2170] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(303), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(301), 24,
-      anon_sym_globalSetup,
+  [3181] = 3,
+    ACTIONS(610), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(608), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5867,24 +7538,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2204] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(307), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(305), 24,
-      anon_sym_globalSetup,
+  [3222] = 3,
+    ACTIONS(614), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(612), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5898,24 +7576,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2238] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(311), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(309), 24,
-      anon_sym_globalSetup,
+  [3263] = 3,
+    ACTIONS(618), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(616), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5929,24 +7614,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2272] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(315), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(313), 24,
-      anon_sym_globalSetup,
+  [3304] = 3,
+    ACTIONS(622), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(620), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5960,24 +7652,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2306] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(319), 2,
-      anon_sym_bench,
-      anon_sym_fairness,
-    ACTIONS(317), 24,
-      anon_sym_globalSetup,
+  [3345] = 3,
+    ACTIONS(626), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(624), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -5991,24 +7690,69 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2340] = 3,
-    ACTIONS(3), 1,
+  [3386] = 3,
+    ACTIONS(630), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(323), 2,
-      anon_sym_bench,
+    ACTIONS(628), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
-    ACTIONS(321), 24,
-      anon_sym_globalSetup,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3427] = 3,
+    ACTIONS(634), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(632), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -6022,24 +7766,69 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2374] = 3,
-    ACTIONS(3), 1,
+  [3468] = 3,
+    ACTIONS(638), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(327), 2,
-      anon_sym_bench,
+    ACTIONS(636), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
-    ACTIONS(325), 24,
-      anon_sym_globalSetup,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3509] = 3,
+    ACTIONS(642), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(640), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -6053,24 +7842,69 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2408] = 3,
-    ACTIONS(3), 1,
+  [3550] = 3,
+    ACTIONS(646), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(331), 2,
-      anon_sym_bench,
+    ACTIONS(644), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
       anon_sym_fairness,
-    ACTIONS(329), 24,
-      anon_sym_globalSetup,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3591] = 3,
+    ACTIONS(650), 1,
       anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(648), 32,
+      sym_identifier,
       anon_sym_memory,
-      anon_sym_setup,
-      anon_sym_fixture,
-      anon_sym_benchAsync,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
       anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
       anon_sym_baseline,
       anon_sym_iterations,
@@ -6084,2382 +7918,6138 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_outlierDetection,
       anon_sym_cvThreshold,
       anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
       anon_sym_fairnessSeed,
       anon_sym_asyncSamplingPolicy,
       anon_sym_asyncWarmupCap,
       anon_sym_asyncSampleCap,
-  [2442] = 7,
-    ACTIONS(3), 1,
+  [3632] = 3,
+    ACTIONS(654), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(333), 1,
-      anon_sym_RPAREN,
-    ACTIONS(337), 1,
-      anon_sym_baseline,
-    STATE(153), 1,
-      sym_chart_param,
-    STATE(225), 1,
-      sym_chart_params,
-    STATE(227), 1,
-      sym_chart_param_name,
-    ACTIONS(335), 21,
-      anon_sym_title,
+    ACTIONS(652), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
-      anon_sym_output,
-      anon_sym_sortBy,
-      anon_sym_sortOrder,
-      anon_sym_baselineBenchmark,
-      anon_sym_filterWinner,
-      anon_sym_theme,
-      anon_sym_width,
-      anon_sym_rowCount,
-      anon_sym_height,
-      anon_sym_limit,
-      anon_sym_minSpeedup,
-      anon_sym_includeBenchmarks,
-      anon_sym_excludeBenchmarks,
-      anon_sym_showStdDev,
-      anon_sym_showErrorBars,
-      anon_sym_showRegression,
-      anon_sym_regressionModel,
-      anon_sym_yScale,
-      anon_sym_showStatsTable,
-  [2484] = 6,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(337), 1,
       anon_sym_baseline,
-    ACTIONS(339), 1,
-      anon_sym_RPAREN,
-    STATE(190), 1,
-      sym_chart_param,
-    STATE(227), 1,
-      sym_chart_param_name,
-    ACTIONS(335), 21,
-      anon_sym_title,
-      anon_sym_description,
-      anon_sym_output,
-      anon_sym_sortBy,
-      anon_sym_sortOrder,
-      anon_sym_baselineBenchmark,
-      anon_sym_filterWinner,
-      anon_sym_theme,
-      anon_sym_width,
-      anon_sym_rowCount,
-      anon_sym_height,
-      anon_sym_limit,
-      anon_sym_minSpeedup,
-      anon_sym_includeBenchmarks,
-      anon_sym_excludeBenchmarks,
-      anon_sym_showStdDev,
-      anon_sym_showErrorBars,
-      anon_sym_showRegression,
-      anon_sym_regressionModel,
-      anon_sym_yScale,
-      anon_sym_showStatsTable,
-  [2523] = 6,
-    ACTIONS(3), 1,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3673] = 3,
+    ACTIONS(658), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(337), 1,
-      anon_sym_baseline,
-    ACTIONS(341), 1,
-      anon_sym_RPAREN,
-    STATE(190), 1,
-      sym_chart_param,
-    STATE(227), 1,
-      sym_chart_param_name,
-    ACTIONS(335), 21,
-      anon_sym_title,
+    ACTIONS(656), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
       anon_sym_description,
-      anon_sym_output,
-      anon_sym_sortBy,
-      anon_sym_sortOrder,
-      anon_sym_baselineBenchmark,
-      anon_sym_filterWinner,
-      anon_sym_theme,
-      anon_sym_width,
-      anon_sym_rowCount,
-      anon_sym_height,
-      anon_sym_limit,
-      anon_sym_minSpeedup,
-      anon_sym_includeBenchmarks,
-      anon_sym_excludeBenchmarks,
-      anon_sym_showStdDev,
-      anon_sym_showErrorBars,
-      anon_sym_showRegression,
-      anon_sym_regressionModel,
-      anon_sym_yScale,
-      anon_sym_showStatsTable,
-  [2562] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(337), 1,
       anon_sym_baseline,
-    STATE(190), 1,
-      sym_chart_param,
-    STATE(227), 1,
-      sym_chart_param_name,
-    ACTIONS(335), 21,
-      anon_sym_title,
-      anon_sym_description,
-      anon_sym_output,
-      anon_sym_sortBy,
-      anon_sym_sortOrder,
-      anon_sym_baselineBenchmark,
-      anon_sym_filterWinner,
-      anon_sym_theme,
-      anon_sym_width,
-      anon_sym_rowCount,
-      anon_sym_height,
-      anon_sym_limit,
-      anon_sym_minSpeedup,
-      anon_sym_includeBenchmarks,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3714] = 3,
+    ACTIONS(662), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(660), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3755] = 3,
+    ACTIONS(666), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(664), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3796] = 3,
+    ACTIONS(670), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(668), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3837] = 3,
+    ACTIONS(674), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(672), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3878] = 3,
+    ACTIONS(678), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(676), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3919] = 3,
+    ACTIONS(682), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(680), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [3960] = 3,
+    ACTIONS(686), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(684), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4001] = 3,
+    ACTIONS(690), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(688), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4042] = 3,
+    ACTIONS(694), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(692), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4083] = 3,
+    ACTIONS(698), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(696), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4124] = 3,
+    ACTIONS(702), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(700), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4165] = 3,
+    ACTIONS(706), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(704), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4206] = 3,
+    ACTIONS(710), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(708), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4247] = 3,
+    ACTIONS(714), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(712), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4288] = 3,
+    ACTIONS(718), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(716), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4329] = 3,
+    ACTIONS(722), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(720), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4370] = 3,
+    ACTIONS(726), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(724), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4411] = 3,
+    ACTIONS(730), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(728), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4452] = 3,
+    ACTIONS(734), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(732), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4493] = 3,
+    ACTIONS(738), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(736), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4534] = 3,
+    ACTIONS(742), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(740), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_tags,
+      anon_sym_skip,
+      anon_sym_validate,
+      anon_sym_before,
+      anon_sym_after,
+      anon_sym_each,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4575] = 3,
+    ACTIONS(746), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(744), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4616] = 3,
+    ACTIONS(750), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(748), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4657] = 3,
+    ACTIONS(754), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(752), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4698] = 3,
+    ACTIONS(758), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(756), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4739] = 3,
+    ACTIONS(762), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(760), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4780] = 3,
+    ACTIONS(766), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(764), 32,
+      sym_identifier,
+      anon_sym_memory,
+      anon_sym_hex,
+      anon_sym_data,
+      anon_sym_encoding,
+      anon_sym_format,
+      anon_sym_selector,
+      anon_sym_shape,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_count,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairness,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4821] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(770), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(768), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4861] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(774), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(772), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4901] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(778), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(776), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4941] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(782), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(780), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [4981] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(786), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(784), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5021] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(790), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(788), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5061] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(794), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(792), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5101] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(798), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(796), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5141] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(802), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(800), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5181] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(806), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(804), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5221] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(810), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(808), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5261] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(814), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(812), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5301] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(818), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(816), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5341] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(822), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(820), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5381] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(826), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(824), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5421] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(830), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(828), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5461] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(834), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(832), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5501] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(838), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(836), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5541] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(842), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(840), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5581] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(846), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(844), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5621] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(850), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(848), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5661] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(854), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(852), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5701] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(858), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(856), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5741] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(862), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(860), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5781] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(866), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(864), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5821] = 3,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(870), 3,
+      anon_sym_bench,
+      anon_sym_count,
+      anon_sym_fairness,
+    ACTIONS(868), 29,
+      anon_sym_globalSetup,
+      anon_sym_RBRACE,
+      anon_sym_memory,
+      anon_sym_setup,
+      anon_sym_fixture,
+      anon_sym_benchAsync,
+      anon_sym_after,
+      anon_sym_description,
+      anon_sym_baseline,
+      anon_sym_iterations,
+      anon_sym_warmup,
+      anon_sym_timeout,
+      anon_sym_requires,
+      anon_sym_order,
+      anon_sym_mode,
+      anon_sym_targetTime,
+      anon_sym_sink,
+      anon_sym_outlierDetection,
+      anon_sym_cvThreshold,
+      anon_sym_ciTarget,
+      anon_sym_maxRuns,
+      anon_sym_maxTime,
+      anon_sym_counters,
+      anon_sym_equivalent,
+      anon_sym_external,
+      anon_sym_fairnessSeed,
+      anon_sym_asyncSamplingPolicy,
+      anon_sym_asyncWarmupCap,
+      anon_sym_asyncSampleCap,
+  [5861] = 27,
+    ACTIONS(872), 1,
+      anon_sym_RPAREN,
+    ACTIONS(874), 1,
+      anon_sym_title,
+    ACTIONS(876), 1,
+      anon_sym_description,
+    ACTIONS(878), 1,
+      anon_sym_output,
+    ACTIONS(880), 1,
+      anon_sym_sortBy,
+    ACTIONS(882), 1,
+      anon_sym_sortOrder,
+    ACTIONS(884), 1,
+      anon_sym_baselineBenchmark,
+    ACTIONS(886), 1,
+      anon_sym_baseline,
+    ACTIONS(888), 1,
+      anon_sym_filterWinner,
+    ACTIONS(890), 1,
+      anon_sym_theme,
+    ACTIONS(892), 1,
+      anon_sym_width,
+    ACTIONS(894), 1,
+      anon_sym_rowCount,
+    ACTIONS(896), 1,
+      anon_sym_height,
+    ACTIONS(898), 1,
+      anon_sym_limit,
+    ACTIONS(900), 1,
+      anon_sym_minSpeedup,
+    ACTIONS(902), 1,
+      anon_sym_includeBenchmarks,
+    ACTIONS(904), 1,
+      anon_sym_excludeBenchmarks,
+    ACTIONS(906), 1,
+      anon_sym_showStdDev,
+    ACTIONS(908), 1,
+      anon_sym_showErrorBars,
+    ACTIONS(910), 1,
+      anon_sym_showRegression,
+    ACTIONS(912), 1,
+      anon_sym_regressionModel,
+    ACTIONS(914), 1,
+      anon_sym_yScale,
+    ACTIONS(916), 1,
+      anon_sym_showStatsTable,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(411), 1,
+      sym_chart_params,
+    STATE(249), 1,
+      sym_chart_param,
+    STATE(412), 1,
+      sym_chart_param_name,
+  [5943] = 26,
+    ACTIONS(918), 1,
+      anon_sym_RPAREN,
+    ACTIONS(874), 1,
+      anon_sym_title,
+    ACTIONS(876), 1,
+      anon_sym_description,
+    ACTIONS(878), 1,
+      anon_sym_output,
+    ACTIONS(880), 1,
+      anon_sym_sortBy,
+    ACTIONS(882), 1,
+      anon_sym_sortOrder,
+    ACTIONS(884), 1,
+      anon_sym_baselineBenchmark,
+    ACTIONS(886), 1,
+      anon_sym_baseline,
+    ACTIONS(888), 1,
+      anon_sym_filterWinner,
+    ACTIONS(890), 1,
+      anon_sym_theme,
+    ACTIONS(892), 1,
+      anon_sym_width,
+    ACTIONS(894), 1,
+      anon_sym_rowCount,
+    ACTIONS(896), 1,
+      anon_sym_height,
+    ACTIONS(898), 1,
+      anon_sym_limit,
+    ACTIONS(900), 1,
+      anon_sym_minSpeedup,
+    ACTIONS(902), 1,
+      anon_sym_includeBenchmarks,
+    ACTIONS(904), 1,
+      anon_sym_excludeBenchmarks,
+    ACTIONS(906), 1,
+      anon_sym_showStdDev,
+    ACTIONS(908), 1,
+      anon_sym_showErrorBars,
+    ACTIONS(910), 1,
+      anon_sym_showRegression,
+    ACTIONS(912), 1,
+      anon_sym_regressionModel,
+    ACTIONS(914), 1,
+      anon_sym_yScale,
+    ACTIONS(916), 1,
+      anon_sym_showStatsTable,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(308), 1,
+      sym_chart_param,
+    STATE(412), 1,
+      sym_chart_param_name,
+  [6022] = 26,
+    ACTIONS(920), 1,
+      anon_sym_RPAREN,
+    ACTIONS(874), 1,
+      anon_sym_title,
+    ACTIONS(876), 1,
+      anon_sym_description,
+    ACTIONS(878), 1,
+      anon_sym_output,
+    ACTIONS(880), 1,
+      anon_sym_sortBy,
+    ACTIONS(882), 1,
+      anon_sym_sortOrder,
+    ACTIONS(884), 1,
+      anon_sym_baselineBenchmark,
+    ACTIONS(886), 1,
+      anon_sym_baseline,
+    ACTIONS(888), 1,
+      anon_sym_filterWinner,
+    ACTIONS(890), 1,
+      anon_sym_theme,
+    ACTIONS(892), 1,
+      anon_sym_width,
+    ACTIONS(894), 1,
+      anon_sym_rowCount,
+    ACTIONS(896), 1,
+      anon_sym_height,
+    ACTIONS(898), 1,
+      anon_sym_limit,
+    ACTIONS(900), 1,
+      anon_sym_minSpeedup,
+    ACTIONS(902), 1,
+      anon_sym_includeBenchmarks,
+    ACTIONS(904), 1,
+      anon_sym_excludeBenchmarks,
+    ACTIONS(906), 1,
+      anon_sym_showStdDev,
+    ACTIONS(908), 1,
+      anon_sym_showErrorBars,
+    ACTIONS(910), 1,
+      anon_sym_showRegression,
+    ACTIONS(912), 1,
+      anon_sym_regressionModel,
+    ACTIONS(914), 1,
+      anon_sym_yScale,
+    ACTIONS(916), 1,
+      anon_sym_showStatsTable,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(308), 1,
+      sym_chart_param,
+    STATE(412), 1,
+      sym_chart_param_name,
+  [6101] = 25,
+    ACTIONS(874), 1,
+      anon_sym_title,
+    ACTIONS(876), 1,
+      anon_sym_description,
+    ACTIONS(878), 1,
+      anon_sym_output,
+    ACTIONS(880), 1,
+      anon_sym_sortBy,
+    ACTIONS(882), 1,
+      anon_sym_sortOrder,
+    ACTIONS(884), 1,
+      anon_sym_baselineBenchmark,
+    ACTIONS(886), 1,
+      anon_sym_baseline,
+    ACTIONS(888), 1,
+      anon_sym_filterWinner,
+    ACTIONS(890), 1,
+      anon_sym_theme,
+    ACTIONS(892), 1,
+      anon_sym_width,
+    ACTIONS(894), 1,
+      anon_sym_rowCount,
+    ACTIONS(896), 1,
+      anon_sym_height,
+    ACTIONS(898), 1,
+      anon_sym_limit,
+    ACTIONS(900), 1,
+      anon_sym_minSpeedup,
+    ACTIONS(902), 1,
+      anon_sym_includeBenchmarks,
+    ACTIONS(904), 1,
       anon_sym_excludeBenchmarks,
+    ACTIONS(906), 1,
       anon_sym_showStdDev,
+    ACTIONS(908), 1,
       anon_sym_showErrorBars,
+    ACTIONS(910), 1,
       anon_sym_showRegression,
+    ACTIONS(912), 1,
       anon_sym_regressionModel,
+    ACTIONS(914), 1,
       anon_sym_yScale,
+    ACTIONS(916), 1,
       anon_sym_showStatsTable,
-  [2598] = 9,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(308), 1,
+      sym_chart_param,
+    STATE(412), 1,
+      sym_chart_param_name,
+  [6177] = 14,
+    ACTIONS(922), 1,
+      sym_identifier,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(930), 1,
+      sym_number,
+    ACTIONS(932), 1,
+      sym_float,
+    ACTIONS(934), 1,
+      anon_sym_true,
+    ACTIONS(936), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(29), 1,
+      sym__value,
+    STATE(8), 1,
+      sym_string,
+    STATE(10), 1,
+      sym_duration,
+    STATE(11), 1,
+      sym_boolean,
+    STATE(13), 1,
+      sym_string_array,
+  [6220] = 14,
+    ACTIONS(922), 1,
+      sym_identifier,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(938), 1,
+      sym_number,
+    ACTIONS(932), 1,
+      sym_float,
+    ACTIONS(934), 1,
+      anon_sym_true,
+    ACTIONS(936), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(276), 1,
+      sym__value,
+    STATE(8), 1,
+      sym_string,
+    STATE(10), 1,
+      sym_duration,
+    STATE(11), 1,
+      sym_boolean,
+    STATE(13), 1,
+      sym_string_array,
+  [6263] = 14,
+    ACTIONS(922), 1,
+      sym_identifier,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(940), 1,
+      sym_number,
+    ACTIONS(932), 1,
+      sym_float,
+    ACTIONS(934), 1,
+      anon_sym_true,
+    ACTIONS(936), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(29), 1,
+      sym__value,
+    STATE(8), 1,
+      sym_string,
+    STATE(10), 1,
+      sym_duration,
+    STATE(11), 1,
+      sym_boolean,
+    STATE(13), 1,
+      sym_string_array,
+  [6306] = 14,
+    ACTIONS(922), 1,
+      sym_identifier,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(942), 1,
+      sym_number,
+    ACTIONS(932), 1,
+      sym_float,
+    ACTIONS(934), 1,
+      anon_sym_true,
+    ACTIONS(936), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(29), 1,
+      sym__value,
+    STATE(8), 1,
+      sym_string,
+    STATE(10), 1,
+      sym_duration,
+    STATE(11), 1,
+      sym_boolean,
+    STATE(13), 1,
+      sym_string_array,
+  [6349] = 13,
+    ACTIONS(944), 1,
+      anon_sym_RBRACE,
+    ACTIONS(946), 1,
+      anon_sym_declare,
+    ACTIONS(948), 1,
+      anon_sym_import,
+    ACTIONS(950), 1,
+      anon_sym_async,
+    ACTIONS(952), 1,
+      anon_sym_init,
+    ACTIONS(954), 1,
+      anon_sym_helpers,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(145), 1,
+      sym__setup_section,
+    STATE(141), 1,
+      sym_import_section,
+    STATE(142), 1,
+      sym_declare_section,
+    STATE(143), 1,
+      sym_init_section,
+    STATE(144), 1,
+      sym_helpers_section,
+    STATE(125), 1,
+      aux_sym_setup_body_repeat1,
+  [6389] = 13,
+    ACTIONS(956), 1,
+      anon_sym_RBRACE,
+    ACTIONS(946), 1,
+      anon_sym_declare,
+    ACTIONS(948), 1,
+      anon_sym_import,
+    ACTIONS(950), 1,
+      anon_sym_async,
+    ACTIONS(952), 1,
+      anon_sym_init,
+    ACTIONS(954), 1,
+      anon_sym_helpers,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(145), 1,
+      sym__setup_section,
+    STATE(141), 1,
+      sym_import_section,
+    STATE(142), 1,
+      sym_declare_section,
+    STATE(143), 1,
+      sym_init_section,
+    STATE(144), 1,
+      sym_helpers_section,
+    STATE(126), 1,
+      aux_sym_setup_body_repeat1,
+  [6429] = 13,
+    ACTIONS(958), 1,
+      anon_sym_RBRACE,
+    ACTIONS(960), 1,
+      anon_sym_declare,
+    ACTIONS(963), 1,
+      anon_sym_import,
+    ACTIONS(966), 1,
+      anon_sym_async,
+    ACTIONS(969), 1,
+      anon_sym_init,
+    ACTIONS(972), 1,
+      anon_sym_helpers,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(145), 1,
+      sym__setup_section,
+    STATE(141), 1,
+      sym_import_section,
+    STATE(142), 1,
+      sym_declare_section,
+    STATE(143), 1,
+      sym_init_section,
+    STATE(144), 1,
+      sym_helpers_section,
+    STATE(126), 1,
+      aux_sym_setup_body_repeat1,
+  [6469] = 12,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(975), 1,
+      sym_number,
+    ACTIONS(977), 1,
+      sym_float,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(309), 1,
+      sym__chart_value,
+    STATE(310), 1,
+      sym_string,
+    STATE(313), 1,
+      sym_boolean,
+    STATE(314), 1,
+      sym_string_array,
+  [6506] = 11,
+    ACTIONS(983), 1,
+      ts_builtin_sym_end,
+    ACTIONS(5), 1,
+      anon_sym_use,
+    ACTIONS(7), 1,
+      anon_sym_globalSetup,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(156), 1,
+      sym_use_statement,
+    STATE(157), 1,
+      sym_global_setup,
+    STATE(198), 1,
+      sym_suite,
+    STATE(133), 1,
+      aux_sym_source_file_repeat1,
+    STATE(158), 1,
+      aux_sym_source_file_repeat2,
+  [6540] = 11,
+    ACTIONS(985), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(987), 1,
+      sym_number,
+    ACTIONS(989), 1,
+      sym_float,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(248), 1,
+      sym__param_value,
+    STATE(297), 1,
+      sym_string,
+    STATE(300), 1,
+      sym_boolean,
+  [6574] = 11,
+    ACTIONS(991), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(987), 1,
+      sym_number,
+    ACTIONS(989), 1,
+      sym_float,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(307), 1,
+      sym__param_value,
+    STATE(297), 1,
+      sym_string,
+    STATE(300), 1,
+      sym_boolean,
+  [6608] = 11,
+    ACTIONS(993), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(987), 1,
+      sym_number,
+    ACTIONS(989), 1,
+      sym_float,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(307), 1,
+      sym__param_value,
+    STATE(297), 1,
+      sym_string,
+    STATE(300), 1,
+      sym_boolean,
+  [6642] = 10,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(987), 1,
+      sym_number,
+    ACTIONS(989), 1,
+      sym_float,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(307), 1,
+      sym__param_value,
+    STATE(297), 1,
+      sym_string,
+    STATE(300), 1,
+      sym_boolean,
+  [6673] = 5,
+    ACTIONS(997), 1,
+      anon_sym_use,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(156), 1,
+      sym_use_statement,
+    STATE(133), 1,
+      aux_sym_source_file_repeat1,
+    ACTIONS(995), 4,
+      ts_builtin_sym_end,
+      anon_sym_globalSetup,
+      anon_sym_declare,
+      anon_sym_suite,
+  [6692] = 8,
+    ACTIONS(1000), 1,
+      sym_identifier,
+    ACTIONS(1002), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1004), 1,
+      anon_sym_anvil,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(203), 1,
+      sym_global_setup_statement,
+    STATE(201), 1,
+      sym_anvil_call,
+    STATE(202), 1,
+      sym_function_call,
+    STATE(135), 1,
+      aux_sym_global_setup_body_repeat1,
+  [6717] = 8,
+    ACTIONS(1000), 1,
+      sym_identifier,
+    ACTIONS(1006), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1004), 1,
+      anon_sym_anvil,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(203), 1,
+      sym_global_setup_statement,
+    STATE(201), 1,
+      sym_anvil_call,
+    STATE(202), 1,
+      sym_function_call,
+    STATE(136), 1,
+      aux_sym_global_setup_body_repeat1,
+  [6742] = 8,
+    ACTIONS(1008), 1,
+      sym_identifier,
+    ACTIONS(1011), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1013), 1,
+      anon_sym_anvil,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(203), 1,
+      sym_global_setup_statement,
+    STATE(201), 1,
+      sym_anvil_call,
+    STATE(202), 1,
+      sym_function_call,
+    STATE(136), 1,
+      aux_sym_global_setup_body_repeat1,
+  [6767] = 8,
+    ACTIONS(1000), 1,
+      sym_identifier,
+    ACTIONS(1016), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1004), 1,
+      anon_sym_anvil,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(203), 1,
+      sym_global_setup_statement,
+    STATE(201), 1,
+      sym_anvil_call,
+    STATE(202), 1,
+      sym_function_call,
+    STATE(138), 1,
+      aux_sym_global_setup_body_repeat1,
+  [6792] = 8,
+    ACTIONS(1000), 1,
+      sym_identifier,
+    ACTIONS(1018), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1004), 1,
+      anon_sym_anvil,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(203), 1,
+      sym_global_setup_statement,
+    STATE(201), 1,
+      sym_anvil_call,
+    STATE(202), 1,
+      sym_function_call,
+    STATE(136), 1,
+      aux_sym_global_setup_body_repeat1,
+  [6817] = 8,
+    ACTIONS(1020), 1,
+      anon_sym_AT,
+    ACTIONS(1022), 1,
+      anon_sym_ATfile,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(73), 1,
+      sym_generator_call,
+    STATE(72), 1,
+      sym_file_ref,
+    STATE(71), 1,
+      sym_string,
+  [6842] = 6,
+    ACTIONS(570), 1,
+      anon_sym_ms,
+    ACTIONS(1024), 1,
+      anon_sym_s,
+    ACTIONS(568), 1,
+      anon_sym_m,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(14), 1,
+      sym_duration_unit,
+    ACTIONS(562), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [6862] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1026), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6874] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1028), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6886] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1030), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6898] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1032), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6910] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1034), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6922] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1036), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6934] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1038), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6946] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1040), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6958] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1042), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6970] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1044), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6982] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1046), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [6994] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1048), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [7006] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1050), 6,
+      anon_sym_RBRACE,
+      anon_sym_declare,
+      anon_sym_import,
+      anon_sym_async,
+      anon_sym_init,
+      anon_sym_helpers,
+  [7018] = 6,
+    ACTIONS(1052), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(159), 1,
+      aux_sym_source_file_repeat2,
+  [7037] = 6,
+    ACTIONS(1054), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(160), 1,
+      aux_sym_source_file_repeat2,
+  [7056] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1056), 5,
+      ts_builtin_sym_end,
+      anon_sym_use,
+      anon_sym_globalSetup,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7067] = 6,
+    ACTIONS(1058), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(162), 1,
+      aux_sym_source_file_repeat2,
+  [7086] = 6,
+    ACTIONS(1060), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(160), 1,
+      aux_sym_source_file_repeat2,
+  [7105] = 6,
+    ACTIONS(1062), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(160), 1,
+      aux_sym_source_file_repeat2,
+  [7124] = 6,
+    ACTIONS(1064), 1,
+      ts_builtin_sym_end,
+    ACTIONS(1066), 1,
+      anon_sym_declare,
+    ACTIONS(1069), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(160), 1,
+      aux_sym_source_file_repeat2,
+  [7143] = 6,
+    ACTIONS(1072), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1074), 1,
+      anon_sym_performance,
+    ACTIONS(1076), 1,
+      anon_sym_memory,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(204), 1,
+      sym_suite_type,
+    STATE(205), 1,
+      sym_suite_body,
+  [7162] = 6,
+    ACTIONS(1078), 1,
+      ts_builtin_sym_end,
+    ACTIONS(9), 1,
+      anon_sym_declare,
+    ACTIONS(11), 1,
+      anon_sym_suite,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(198), 1,
+      sym_suite,
+    STATE(160), 1,
+      aux_sym_source_file_repeat2,
+  [7181] = 6,
+    ACTIONS(1072), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1074), 1,
+      anon_sym_performance,
+    ACTIONS(1076), 1,
+      anon_sym_memory,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(207), 1,
+      sym_suite_type,
+    STATE(208), 1,
+      sym_suite_body,
+  [7200] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1080), 5,
+      ts_builtin_sym_end,
+      anon_sym_use,
+      anon_sym_globalSetup,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7211] = 6,
+    ACTIONS(1082), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1084), 1,
+      aux_sym_string_content_token1,
+    ACTIONS(1086), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(387), 1,
+      sym_string_content,
+    STATE(187), 1,
+      aux_sym_string_content_repeat1,
+  [7230] = 6,
+    ACTIONS(1088), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(1090), 1,
+      aux_sym_single_string_content_token1,
+    ACTIONS(1092), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(388), 1,
+      sym_single_string_content,
+    STATE(188), 1,
+      aux_sym_single_string_content_repeat1,
+  [7249] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1096), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(59), 1,
+      sym_hook_flat,
+    STATE(60), 1,
+      sym_hook_grouped,
+    STATE(392), 1,
+      sym_language_tag,
+  [7268] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1096), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(61), 1,
+      sym_hook_flat,
+    STATE(62), 1,
+      sym_hook_grouped,
+    STATE(392), 1,
+      sym_language_tag,
+  [7287] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1096), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(63), 1,
+      sym_hook_flat,
+    STATE(64), 1,
+      sym_hook_grouped,
+    STATE(392), 1,
+      sym_language_tag,
+  [7306] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1096), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(65), 1,
+      sym_hook_flat,
+    STATE(66), 1,
+      sym_hook_grouped,
+    STATE(392), 1,
+      sym_language_tag,
+  [7325] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1096), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(67), 1,
+      sym_hook_flat,
+    STATE(68), 1,
+      sym_hook_grouped,
+    STATE(392), 1,
+      sym_language_tag,
+  [7344] = 6,
+    ACTIONS(1098), 1,
+      anon_sym_drawSpeedupChart,
+    ACTIONS(1100), 1,
+      anon_sym_drawTable,
+    ACTIONS(1102), 1,
+      anon_sym_drawLineChart,
+    ACTIONS(1104), 1,
+      anon_sym_drawBarChart,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(394), 1,
+      sym_chart_function_name,
+  [7363] = 6,
+    ACTIONS(1022), 1,
+      anon_sym_ATfile,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(70), 1,
+      sym_file_ref,
+    STATE(69), 1,
+      sym_string,
+  [7382] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1106), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(301), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(175), 1,
+      aux_sym_hook_grouped_repeat1,
+  [7401] = 6,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(1108), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(301), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(176), 1,
+      aux_sym_hook_grouped_repeat1,
+  [7420] = 6,
+    ACTIONS(1110), 1,
+      sym_identifier,
+    ACTIONS(1113), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(301), 1,
+      sym_language_implementation,
+    STATE(383), 1,
+      sym_language_tag,
+    STATE(176), 1,
+      aux_sym_hook_grouped_repeat1,
+  [7439] = 5,
+    ACTIONS(1115), 1,
+      sym_identifier,
+    ACTIONS(1117), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(364), 1,
+      sym_argument_list,
+    STATE(211), 1,
+      sym_argument,
+  [7455] = 5,
+    ACTIONS(1119), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1121), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(270), 1,
+      sym_fixture_params,
+    STATE(101), 1,
+      sym_fixture_body,
+  [7471] = 5,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1125), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(271), 1,
+      sym_bench_params,
+    STATE(102), 1,
+      sym_benchmark_body,
+  [7487] = 5,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1125), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(273), 1,
+      sym_bench_params,
+    STATE(103), 1,
+      sym_benchmark_body,
+  [7503] = 5,
+    ACTIONS(1127), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1129), 1,
+      anon_sym_charting,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(274), 1,
+      sym_chart_directive,
+    STATE(183), 1,
+      aux_sym_after_body_repeat1,
+  [7519] = 5,
+    ACTIONS(1115), 1,
+      sym_identifier,
+    ACTIONS(1131), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(372), 1,
+      sym_argument_list,
+    STATE(211), 1,
+      sym_argument,
+  [7535] = 5,
+    ACTIONS(1133), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1129), 1,
+      anon_sym_charting,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(274), 1,
+      sym_chart_directive,
+    STATE(186), 1,
+      aux_sym_after_body_repeat1,
+  [7551] = 5,
+    ACTIONS(1135), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(230), 1,
+      sym_string,
+  [7567] = 5,
+    ACTIONS(1137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1139), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(147), 1,
+      sym_code_block,
+    STATE(146), 1,
+      sym_paren_code_block,
+  [7583] = 5,
+    ACTIONS(1141), 1,
+      anon_sym_RBRACE,
+    ACTIONS(1143), 1,
+      anon_sym_charting,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(274), 1,
+      sym_chart_directive,
+    STATE(186), 1,
+      aux_sym_after_body_repeat1,
+  [7599] = 5,
+    ACTIONS(1146), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(1084), 1,
+      aux_sym_string_content_token1,
+    ACTIONS(1086), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(192), 1,
+      aux_sym_string_content_repeat1,
+  [7615] = 5,
+    ACTIONS(1148), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(1090), 1,
+      aux_sym_single_string_content_token1,
+    ACTIONS(1092), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(193), 1,
+      aux_sym_single_string_content_repeat1,
+  [7631] = 5,
+    ACTIONS(1150), 1,
+      sym_identifier,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(75), 1,
+      sym_string,
+  [7647] = 5,
+    ACTIONS(1152), 1,
+      sym_identifier,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(77), 1,
+      sym_string,
+  [7663] = 5,
+    ACTIONS(1154), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1156), 1,
+      sym_inline_code,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(343), 1,
-      sym_identifier,
-    ACTIONS(345), 1,
+    STATE(35), 1,
+      sym__code_or_inline,
+    STATE(36), 1,
+      sym_code_block,
+  [7679] = 5,
+    ACTIONS(1158), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(1160), 1,
+      aux_sym_string_content_token1,
+    ACTIONS(1163), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(192), 1,
+      aux_sym_string_content_repeat1,
+  [7695] = 5,
+    ACTIONS(1166), 1,
       anon_sym_SQUOTE,
-    ACTIONS(349), 1,
-      sym_number,
-    ACTIONS(351), 1,
-      sym_float,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    ACTIONS(353), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(17), 5,
-      sym__value,
-      sym_string,
-      sym_duration,
-      sym_boolean,
-      sym_string_array,
-  [2631] = 9,
-    ACTIONS(3), 1,
+    ACTIONS(1168), 1,
+      aux_sym_single_string_content_token1,
+    ACTIONS(1171), 1,
+      sym_escape_sequence,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(343), 1,
-      sym_identifier,
-    ACTIONS(345), 1,
+    STATE(193), 1,
+      aux_sym_single_string_content_repeat1,
+  [7711] = 5,
+    ACTIONS(1174), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(928), 1,
       anon_sym_SQUOTE,
-    ACTIONS(351), 1,
-      sym_float,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    ACTIONS(357), 1,
-      sym_number,
-    ACTIONS(353), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(17), 5,
-      sym__value,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(293), 1,
       sym_string,
-      sym_duration,
-      sym_boolean,
-      sym_string_array,
-  [2664] = 9,
-    ACTIONS(3), 1,
+  [7727] = 5,
+    ACTIONS(1154), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1156), 1,
+      sym_inline_code,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(343), 1,
-      sym_identifier,
-    ACTIONS(345), 1,
+    STATE(81), 1,
+      sym__code_or_inline,
+    STATE(36), 1,
+      sym_code_block,
+  [7743] = 5,
+    ACTIONS(1176), 1,
+      anon_sym_RBRACK,
+    ACTIONS(926), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(928), 1,
       anon_sym_SQUOTE,
-    ACTIONS(351), 1,
-      sym_float,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    ACTIONS(359), 1,
-      sym_number,
-    ACTIONS(353), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(17), 5,
-      sym__value,
-      sym_string,
-      sym_duration,
-      sym_boolean,
-      sym_string_array,
-  [2697] = 9,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
+    STATE(293), 1,
+      sym_string,
+  [7759] = 5,
+    ACTIONS(926), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(928), 1,
       anon_sym_SQUOTE,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    ACTIONS(361), 1,
-      sym_identifier,
-    ACTIONS(363), 1,
+    ACTIONS(1178), 1,
       sym_number,
-    ACTIONS(365), 1,
-      sym_float,
-    ACTIONS(353), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(168), 5,
-      sym__value,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(318), 1,
       sym_string,
-      sym_duration,
-      sym_boolean,
-      sym_string_array,
-  [2730] = 8,
-    ACTIONS(3), 1,
+  [7775] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(367), 1,
-      anon_sym_RBRACE,
-    ACTIONS(369), 1,
+    ACTIONS(1180), 3,
+      ts_builtin_sym_end,
       anon_sym_declare,
-    ACTIONS(372), 1,
-      anon_sym_import,
-    ACTIONS(375), 1,
-      anon_sym_async,
-    ACTIONS(378), 1,
-      anon_sym_init,
-    ACTIONS(381), 1,
-      anon_sym_helpers,
-    STATE(67), 6,
-      sym__setup_section,
-      sym_import_section,
-      sym_declare_section,
-      sym_init_section,
-      sym_helpers_section,
-      aux_sym_setup_body_repeat1,
-  [2760] = 8,
-    ACTIONS(3), 1,
+      anon_sym_suite,
+  [7784] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(796), 3,
+      ts_builtin_sym_end,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7793] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(804), 3,
+      ts_builtin_sym_end,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7802] = 3,
+    ACTIONS(1184), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1182), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [7813] = 3,
+    ACTIONS(1188), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(384), 1,
+    ACTIONS(1186), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [7824] = 3,
+    ACTIONS(1192), 1,
       anon_sym_RBRACE,
-    ACTIONS(386), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1190), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [7835] = 4,
+    ACTIONS(1194), 1,
+      anon_sym_timeBased,
+    ACTIONS(1196), 1,
+      anon_sym_iterationBased,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(330), 1,
+      sym_run_mode,
+  [7848] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1198), 3,
+      ts_builtin_sym_end,
       anon_sym_declare,
-    ACTIONS(388), 1,
-      anon_sym_import,
-    ACTIONS(390), 1,
-      anon_sym_async,
-    ACTIONS(392), 1,
-      anon_sym_init,
-    ACTIONS(394), 1,
-      anon_sym_helpers,
-    STATE(67), 6,
-      sym__setup_section,
-      sym_import_section,
-      sym_declare_section,
-      sym_init_section,
-      sym_helpers_section,
-      aux_sym_setup_body_repeat1,
-  [2790] = 8,
-    ACTIONS(3), 1,
+      anon_sym_suite,
+  [7857] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(386), 1,
+    ACTIONS(828), 3,
+      ts_builtin_sym_end,
       anon_sym_declare,
-    ACTIONS(388), 1,
-      anon_sym_import,
-    ACTIONS(390), 1,
-      anon_sym_async,
-    ACTIONS(392), 1,
-      anon_sym_init,
-    ACTIONS(394), 1,
-      anon_sym_helpers,
-    ACTIONS(396), 1,
+      anon_sym_suite,
+  [7866] = 4,
+    ACTIONS(1194), 1,
+      anon_sym_timeBased,
+    ACTIONS(1196), 1,
+      anon_sym_iterationBased,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(366), 1,
+      sym_run_mode,
+  [7879] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1200), 3,
+      ts_builtin_sym_end,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7888] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1202), 3,
+      ts_builtin_sym_end,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7897] = 3,
+    ACTIONS(1206), 1,
       anon_sym_RBRACE,
-    STATE(68), 6,
-      sym__setup_section,
-      sym_import_section,
-      sym_declare_section,
-      sym_init_section,
-      sym_helpers_section,
-      aux_sym_setup_body_repeat1,
-  [2820] = 8,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1204), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [7908] = 4,
+    ACTIONS(1208), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1210), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(215), 1,
+      aux_sym_argument_list_repeat1,
+  [7921] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1212), 3,
+      ts_builtin_sym_end,
+      anon_sym_declare,
+      anon_sym_suite,
+  [7930] = 4,
+    ACTIONS(1214), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1216), 1,
+      anon_sym_fork,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(370), 1,
+      sym_anvil_args,
+  [7943] = 3,
+    ACTIONS(1220), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1218), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [7954] = 4,
+    ACTIONS(1222), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1224), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(222), 1,
+      aux_sym_argument_list_repeat1,
+  [7967] = 4,
+    ACTIONS(1115), 1,
+      sym_identifier,
+    ACTIONS(1226), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(275), 1,
+      sym_argument,
+  [7980] = 4,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(277), 1,
+      sym_boolean,
+  [7993] = 4,
+    ACTIONS(1228), 1,
+      sym_identifier,
+    ACTIONS(1230), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(224), 1,
+      sym_fixture_param,
+  [8006] = 3,
+    ACTIONS(1234), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1232), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [8017] = 3,
+    ACTIONS(1238), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1236), 2,
+      sym_identifier,
+      anon_sym_anvil,
+  [8028] = 4,
+    ACTIONS(1115), 1,
+      sym_identifier,
+    ACTIONS(1240), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(275), 1,
+      sym_argument,
+  [8041] = 4,
+    ACTIONS(1242), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1244), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(222), 1,
+      aux_sym_argument_list_repeat1,
+  [8054] = 4,
+    ACTIONS(979), 1,
+      anon_sym_true,
+    ACTIONS(981), 1,
+      anon_sym_false,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(282), 1,
+      sym_boolean,
+  [8067] = 4,
+    ACTIONS(1247), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1249), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(235), 1,
+      aux_sym_fixture_params_repeat1,
+  [8080] = 4,
+    ACTIONS(1251), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1253), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(238), 1,
+      aux_sym_bench_params_repeat1,
+  [8093] = 3,
+    ACTIONS(1257), 1,
+      aux_sym_string_content_token1,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1255), 2,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+  [8104] = 3,
+    ACTIONS(1261), 1,
+      aux_sym_string_content_token1,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1259), 2,
+      anon_sym_DQUOTE,
+      sym_escape_sequence,
+  [8115] = 3,
+    ACTIONS(1265), 1,
+      aux_sym_single_string_content_token1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(1263), 2,
       anon_sym_SQUOTE,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    ACTIONS(398), 1,
-      sym_number,
-    ACTIONS(400), 1,
-      sym_float,
-    ACTIONS(402), 2,
-      anon_sym_true,
-      anon_sym_false,
-    STATE(191), 4,
-      sym__chart_value,
-      sym_string,
-      sym_boolean,
-      sym_string_array,
-  [2849] = 9,
-    ACTIONS(3), 1,
+      sym_escape_sequence,
+  [8126] = 3,
+    ACTIONS(1269), 1,
+      aux_sym_single_string_content_token1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(7), 1,
-      anon_sym_use,
-    ACTIONS(9), 1,
-      anon_sym_globalSetup,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+    ACTIONS(1267), 2,
+      anon_sym_SQUOTE,
+      sym_escape_sequence,
+  [8137] = 4,
+    ACTIONS(1271), 1,
+      anon_sym_COMMA,
+    ACTIONS(1273), 1,
+      anon_sym_RBRACK,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(404), 1,
-      ts_builtin_sym_end,
-    STATE(99), 1,
-      sym_global_setup,
-    STATE(75), 2,
-      sym_use_statement,
-      aux_sym_source_file_repeat1,
-    STATE(97), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [2879] = 6,
-    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(406), 1,
-      sym_identifier,
-    ACTIONS(408), 1,
+    STATE(240), 1,
+      aux_sym_string_array_repeat1,
+  [8150] = 3,
+    ACTIONS(1277), 1,
       anon_sym_RBRACE,
-    ACTIONS(410), 1,
-      anon_sym_anvil,
-    STATE(73), 2,
-      sym_global_setup_statement,
-      aux_sym_global_setup_body_repeat1,
-    STATE(144), 2,
-      sym_anvil_call,
-      sym_function_call,
-  [2900] = 6,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(406), 1,
+    ACTIONS(1275), 2,
       sym_identifier,
-    ACTIONS(410), 1,
       anon_sym_anvil,
-    ACTIONS(412), 1,
+  [8161] = 4,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(399), 1,
+      sym_string,
+  [8174] = 3,
+    ACTIONS(1281), 1,
       anon_sym_RBRACE,
-    STATE(74), 2,
-      sym_global_setup_statement,
-      aux_sym_global_setup_body_repeat1,
-    STATE(144), 2,
-      sym_anvil_call,
-      sym_function_call,
-  [2921] = 6,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(414), 1,
+    ACTIONS(1279), 2,
       sym_identifier,
-    ACTIONS(417), 1,
-      anon_sym_RBRACE,
-    ACTIONS(419), 1,
       anon_sym_anvil,
-    STATE(74), 2,
-      sym_global_setup_statement,
-      aux_sym_global_setup_body_repeat1,
-    STATE(144), 2,
-      sym_anvil_call,
-      sym_function_call,
-  [2942] = 4,
-    ACTIONS(3), 1,
+  [8185] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(424), 1,
-      anon_sym_use,
-    STATE(75), 2,
-      sym_use_statement,
-      aux_sym_source_file_repeat1,
-    ACTIONS(422), 4,
+    ACTIONS(1283), 3,
       ts_builtin_sym_end,
-      anon_sym_globalSetup,
       anon_sym_declare,
       anon_sym_suite,
-  [2959] = 2,
-    ACTIONS(3), 1,
+  [8194] = 4,
+    ACTIONS(1285), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1287), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(427), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [2971] = 2,
-    ACTIONS(3), 1,
+    STATE(243), 1,
+      aux_sym_fixture_params_repeat1,
+  [8207] = 4,
+    ACTIONS(1228), 1,
+      sym_identifier,
+    ACTIONS(1289), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(429), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [2983] = 2,
-    ACTIONS(3), 1,
+    STATE(289), 1,
+      sym_fixture_param,
+  [8220] = 4,
+    ACTIONS(926), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(928), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(431), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [2995] = 2,
-    ACTIONS(3), 1,
+    STATE(78), 1,
+      sym_string,
+  [8233] = 4,
+    ACTIONS(1291), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1293), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(433), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [3007] = 2,
-    ACTIONS(3), 1,
+    STATE(245), 1,
+      aux_sym_bench_params_repeat1,
+  [8246] = 4,
+    ACTIONS(1295), 1,
+      sym_identifier,
+    ACTIONS(1297), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(435), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [3019] = 2,
-    ACTIONS(3), 1,
+    STATE(291), 1,
+      sym_bench_param,
+  [8259] = 4,
+    ACTIONS(1299), 1,
+      anon_sym_COMMA,
+    ACTIONS(1301), 1,
+      anon_sym_RBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(437), 6,
-      anon_sym_RBRACE,
-      anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [3031] = 2,
-    ACTIONS(3), 1,
+    STATE(246), 1,
+      aux_sym_string_array_repeat1,
+  [8272] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(439), 6,
-      anon_sym_RBRACE,
+    ACTIONS(1303), 3,
+      ts_builtin_sym_end,
       anon_sym_declare,
-      anon_sym_import,
-      anon_sym_async,
-      anon_sym_init,
-      anon_sym_helpers,
-  [3043] = 5,
-    ACTIONS(3), 1,
+      anon_sym_suite,
+  [8281] = 4,
+    ACTIONS(1228), 1,
+      sym_identifier,
+    ACTIONS(1305), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(201), 1,
-      anon_sym_m,
-    STATE(5), 1,
-      sym_duration_unit,
-    ACTIONS(199), 2,
+    STATE(289), 1,
+      sym_fixture_param,
+  [8294] = 4,
+    ACTIONS(1307), 1,
       anon_sym_RPAREN,
+    ACTIONS(1309), 1,
       anon_sym_COMMA,
-    ACTIONS(203), 2,
-      anon_sym_ms,
-      anon_sym_s,
-  [3061] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(441), 5,
-      ts_builtin_sym_end,
-      anon_sym_use,
-      anon_sym_globalSetup,
-      anon_sym_declare,
-      anon_sym_suite,
-  [3072] = 5,
-    ACTIONS(3), 1,
+    STATE(243), 1,
+      aux_sym_fixture_params_repeat1,
+  [8307] = 4,
+    ACTIONS(1295), 1,
+      sym_identifier,
+    ACTIONS(1312), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+    STATE(291), 1,
+      sym_bench_param,
+  [8320] = 4,
+    ACTIONS(1314), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1316), 1,
+      anon_sym_COMMA,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(404), 1,
-      ts_builtin_sym_end,
-    STATE(102), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3089] = 5,
-    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+    STATE(245), 1,
+      aux_sym_bench_params_repeat1,
+  [8333] = 4,
+    ACTIONS(1319), 1,
+      anon_sym_COMMA,
+    ACTIONS(1322), 1,
+      anon_sym_RBRACK,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(404), 1,
-      ts_builtin_sym_end,
-    STATE(97), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3106] = 5,
-    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(345), 1,
+    STATE(246), 1,
+      aux_sym_string_array_repeat1,
+  [8346] = 4,
+    ACTIONS(926), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(928), 1,
       anon_sym_SQUOTE,
-    ACTIONS(443), 1,
-      anon_sym_ATfile,
-    STATE(37), 2,
-      sym_file_ref,
-      sym_string,
-  [3123] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    ACTIONS(447), 1,
-      anon_sym_COLON,
-    STATE(242), 1,
-      sym_language_tag,
-    STATE(32), 2,
-      sym_hook_flat,
-      sym_hook_grouped,
-  [3140] = 5,
-    ACTIONS(3), 1,
+    STATE(435), 1,
+      sym_string,
+  [8359] = 4,
+    ACTIONS(1324), 1,
+      anon_sym_COMMA,
+    ACTIONS(1326), 1,
+      anon_sym_RBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    ACTIONS(447), 1,
-      anon_sym_COLON,
-    STATE(242), 1,
-      sym_language_tag,
-    STATE(31), 2,
-      sym_hook_flat,
-      sym_hook_grouped,
-  [3157] = 5,
-    ACTIONS(3), 1,
+    STATE(252), 1,
+      aux_sym_param_value_array_repeat1,
+  [8372] = 4,
+    ACTIONS(1328), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1330), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
+    STATE(253), 1,
+      aux_sym_chart_params_repeat1,
+  [8385] = 4,
+    ACTIONS(926), 1,
       anon_sym_DQUOTE,
-    ACTIONS(347), 1,
+    ACTIONS(928), 1,
       anon_sym_SQUOTE,
-    ACTIONS(443), 1,
-      anon_sym_ATfile,
-    STATE(38), 2,
-      sym_file_ref,
-      sym_string,
-  [3174] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
+    STATE(293), 1,
+      sym_string,
+  [8398] = 4,
+    ACTIONS(1332), 1,
       sym_identifier,
-    ACTIONS(447), 1,
-      anon_sym_COLON,
-    STATE(242), 1,
-      sym_language_tag,
-    STATE(33), 2,
-      sym_hook_flat,
-      sym_hook_grouped,
-  [3191] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(1334), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    ACTIONS(447), 1,
-      anon_sym_COLON,
-    STATE(242), 1,
-      sym_language_tag,
-    STATE(34), 2,
-      sym_hook_flat,
-      sym_hook_grouped,
-  [3208] = 5,
-    ACTIONS(3), 1,
+    STATE(254), 1,
+      sym_generator_arg,
+  [8411] = 4,
+    ACTIONS(1336), 1,
+      anon_sym_COMMA,
+    ACTIONS(1338), 1,
+      anon_sym_RBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    ACTIONS(447), 1,
-      anon_sym_COLON,
-    STATE(242), 1,
-      sym_language_tag,
-    STATE(35), 2,
-      sym_hook_flat,
-      sym_hook_grouped,
-  [3225] = 5,
-    ACTIONS(3), 1,
+    STATE(255), 1,
+      aux_sym_param_value_array_repeat1,
+  [8424] = 4,
+    ACTIONS(1340), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1342), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(449), 1,
-      anon_sym_LBRACE,
-    STATE(157), 1,
-      sym_suite_type,
-    STATE(159), 1,
-      sym_suite_body,
-    ACTIONS(451), 2,
-      anon_sym_performance,
-      anon_sym_memory,
-  [3242] = 5,
-    ACTIONS(453), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(457), 1,
+    STATE(256), 1,
+      aux_sym_chart_params_repeat1,
+  [8437] = 4,
+    ACTIONS(1344), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1346), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(108), 1,
-      aux_sym_string_content_repeat1,
-    STATE(219), 1,
-      sym_string_content,
-    ACTIONS(455), 2,
-      aux_sym_string_content_token1,
-      sym_escape_sequence,
-  [3259] = 5,
-    ACTIONS(3), 1,
+    STATE(257), 1,
+      aux_sym_generator_call_repeat1,
+  [8450] = 4,
+    ACTIONS(1348), 1,
+      anon_sym_COMMA,
+    ACTIONS(1351), 1,
+      anon_sym_RBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    ACTIONS(459), 1,
-      anon_sym_RBRACE,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(98), 2,
-      sym_language_implementation,
-      aux_sym_hook_grouped_repeat1,
-  [3276] = 5,
-    ACTIONS(3), 1,
+    STATE(255), 1,
+      aux_sym_param_value_array_repeat1,
+  [8463] = 4,
+    ACTIONS(1353), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1355), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+    STATE(256), 1,
+      aux_sym_chart_params_repeat1,
+  [8476] = 4,
+    ACTIONS(1358), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1360), 1,
+      anon_sym_COMMA,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(461), 1,
-      ts_builtin_sym_end,
-    STATE(102), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3293] = 5,
-    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(445), 1,
+    STATE(260), 1,
+      aux_sym_generator_call_repeat1,
+  [8489] = 4,
+    ACTIONS(1332), 1,
       sym_identifier,
-    ACTIONS(463), 1,
-      anon_sym_RBRACE,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(101), 2,
-      sym_language_implementation,
-      aux_sym_hook_grouped_repeat1,
-  [3310] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+    ACTIONS(1362), 1,
+      anon_sym_RPAREN,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(461), 1,
-      ts_builtin_sym_end,
-    STATE(105), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3327] = 5,
-    ACTIONS(453), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(457), 1,
-      sym_comment,
-    STATE(106), 1,
-      aux_sym_single_string_content_repeat1,
-    STATE(205), 1,
-      sym_single_string_content,
-    ACTIONS(465), 2,
-      aux_sym_single_string_content_token1,
-      sym_escape_sequence,
-  [3344] = 5,
-    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(467), 1,
+    STATE(316), 1,
+      sym_generator_arg,
+  [8502] = 4,
+    ACTIONS(1332), 1,
       sym_identifier,
-    ACTIONS(470), 1,
-      anon_sym_RBRACE,
-    STATE(222), 1,
-      sym_language_tag,
-    STATE(101), 2,
-      sym_language_implementation,
-      aux_sym_hook_grouped_repeat1,
-  [3361] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(1364), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(472), 1,
-      ts_builtin_sym_end,
-    ACTIONS(474), 1,
-      anon_sym_declare,
-    ACTIONS(477), 1,
-      anon_sym_suite,
-    STATE(102), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3378] = 5,
-    ACTIONS(3), 1,
+    STATE(316), 1,
+      sym_generator_arg,
+  [8515] = 4,
+    ACTIONS(1366), 1,
+      anon_sym_RPAREN,
+    ACTIONS(1368), 1,
+      anon_sym_COMMA,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(449), 1,
+    STATE(260), 1,
+      aux_sym_generator_call_repeat1,
+  [8528] = 3,
+    ACTIONS(1371), 1,
       anon_sym_LBRACE,
-    STATE(160), 1,
-      sym_suite_type,
-    STATE(161), 1,
-      sym_suite_body,
-    ACTIONS(451), 2,
-      anon_sym_performance,
-      anon_sym_memory,
-  [3395] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(204), 1,
-      sym_chart_function_name,
-    ACTIONS(480), 4,
-      anon_sym_drawSpeedupChart,
-      anon_sym_drawTable,
-      anon_sym_drawLineChart,
-      anon_sym_drawBarChart,
-  [3408] = 5,
-    ACTIONS(3), 1,
+    STATE(199), 1,
+      sym_global_setup_body,
+  [8538] = 3,
+    ACTIONS(1373), 1,
+      anon_sym_DOT,
+    ACTIONS(1375), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(11), 1,
-      anon_sym_declare,
+  [8548] = 2,
     ACTIONS(13), 1,
-      anon_sym_suite,
-    ACTIONS(482), 1,
-      ts_builtin_sym_end,
-    STATE(102), 2,
-      sym_suite,
-      aux_sym_source_file_repeat2,
-  [3425] = 4,
-    ACTIONS(457), 1,
       sym_comment,
-    ACTIONS(484), 1,
-      anon_sym_SQUOTE,
-    STATE(121), 1,
-      aux_sym_single_string_content_repeat1,
-    ACTIONS(486), 2,
-      aux_sym_single_string_content_token1,
-      sym_escape_sequence,
-  [3439] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(1377), 2,
+      anon_sym_timeBased,
+      anon_sym_iterationBased,
+  [8556] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(488), 1,
-      anon_sym_RBRACK,
-    STATE(130), 1,
-      sym_string,
-  [3455] = 4,
-    ACTIONS(457), 1,
+    ACTIONS(1379), 2,
+      anon_sym_timeBased,
+      anon_sym_iterationBased,
+  [8564] = 3,
+    ACTIONS(1381), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(490), 1,
-      anon_sym_DQUOTE,
-    STATE(118), 1,
-      aux_sym_string_content_repeat1,
-    ACTIONS(492), 2,
-      aux_sym_string_content_token1,
-      sym_escape_sequence,
-  [3469] = 4,
-    ACTIONS(3), 1,
+    STATE(97), 1,
+      sym_global_setup_body,
+  [8574] = 3,
+    ACTIONS(1094), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
+    STATE(268), 1,
+      sym_language_tag,
+  [8584] = 3,
+    ACTIONS(1383), 1,
       anon_sym_LBRACE,
-    ACTIONS(496), 1,
-      anon_sym_LPAREN,
-    STATE(76), 2,
-      sym_code_block,
-      sym_paren_code_block,
-  [3483] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(498), 1,
-      anon_sym_RBRACK,
-    STATE(184), 1,
-      sym_string,
-  [3499] = 5,
-    ACTIONS(3), 1,
+    STATE(98), 1,
+      sym_after_body,
+  [8594] = 3,
+    ACTIONS(1385), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(500), 1,
-      sym_identifier,
-    STATE(39), 1,
-      sym_string,
-  [3515] = 5,
-    ACTIONS(3), 1,
+    STATE(100), 1,
+      sym_setup_body,
+  [8604] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(502), 1,
-      sym_identifier,
-    ACTIONS(504), 1,
-      anon_sym_RPAREN,
-    STATE(148), 1,
-      sym_argument,
-    STATE(238), 1,
-      sym_argument_list,
-  [3531] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1387), 2,
+      anon_sym_LBRACE,
+      anon_sym_COLON,
+  [8612] = 3,
+    ACTIONS(1119), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(506), 1,
-      anon_sym_RBRACE,
-    ACTIONS(508), 1,
-      anon_sym_charting,
-    STATE(115), 2,
-      sym_chart_directive,
-      aux_sym_after_body_repeat1,
-  [3545] = 5,
-    ACTIONS(3), 1,
+    STATE(107), 1,
+      sym_fixture_body,
+  [8622] = 3,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(510), 1,
+    STATE(109), 1,
+      sym_benchmark_body,
+  [8632] = 3,
+    ACTIONS(1295), 1,
       sym_identifier,
-    STATE(40), 1,
-      sym_string,
-  [3561] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(508), 1,
-      anon_sym_charting,
-    ACTIONS(512), 1,
+    STATE(225), 1,
+      sym_bench_param,
+  [8642] = 3,
+    ACTIONS(1123), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(111), 1,
+      sym_benchmark_body,
+  [8652] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1389), 2,
       anon_sym_RBRACE,
-    STATE(120), 2,
-      sym_chart_directive,
-      aux_sym_after_body_repeat1,
-  [3575] = 5,
-    ACTIONS(3), 1,
+      anon_sym_charting,
+  [8660] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1391), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [8668] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(514), 1,
+    ACTIONS(1393), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [8676] = 3,
+    ACTIONS(1072), 1,
       anon_sym_LBRACE,
-    ACTIONS(516), 1,
-      anon_sym_LPAREN,
-    STATE(49), 1,
-      sym_fixture_body,
-    STATE(185), 1,
-      sym_fixture_params,
-  [3591] = 4,
-    ACTIONS(457), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(518), 1,
+    STATE(234), 1,
+      sym_suite_body,
+  [8686] = 3,
+    ACTIONS(1137), 1,
       anon_sym_LBRACE,
-    ACTIONS(520), 1,
-      sym_inline_code,
-    STATE(23), 2,
-      sym__code_or_inline,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(148), 1,
       sym_code_block,
-  [3605] = 4,
-    ACTIONS(457), 1,
+  [8696] = 3,
+    ACTIONS(1137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(522), 1,
-      anon_sym_DQUOTE,
-    STATE(118), 1,
-      aux_sym_string_content_repeat1,
-    ACTIONS(524), 2,
-      aux_sym_string_content_token1,
-      sym_escape_sequence,
-  [3619] = 5,
-    ACTIONS(3), 1,
+    STATE(149), 1,
+      sym_code_block,
+  [8706] = 3,
+    ACTIONS(1137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(502), 1,
+    STATE(150), 1,
+      sym_code_block,
+  [8716] = 3,
+    ACTIONS(1115), 1,
       sym_identifier,
-    ACTIONS(527), 1,
-      anon_sym_RPAREN,
-    STATE(148), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+    STATE(275), 1,
       sym_argument,
-    STATE(208), 1,
-      sym_argument_list,
-  [3635] = 4,
-    ACTIONS(3), 1,
+  [8726] = 3,
+    ACTIONS(1072), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(529), 1,
+    STATE(241), 1,
+      sym_suite_body,
+  [8736] = 3,
+    ACTIONS(1395), 1,
       anon_sym_RBRACE,
-    ACTIONS(531), 1,
-      anon_sym_charting,
-    STATE(120), 2,
-      sym_chart_directive,
-      aux_sym_after_body_repeat1,
-  [3649] = 4,
-    ACTIONS(457), 1,
-      sym_comment,
-    ACTIONS(534), 1,
-      anon_sym_SQUOTE,
-    STATE(121), 1,
-      aux_sym_single_string_content_repeat1,
-    ACTIONS(536), 2,
-      aux_sym_single_string_content_token1,
-      sym_escape_sequence,
-  [3663] = 5,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(539), 1,
-      anon_sym_RBRACK,
-    STATE(184), 1,
-      sym_string,
-  [3679] = 4,
-    ACTIONS(457), 1,
+    ACTIONS(1397), 1,
+      sym_embedded_code,
+  [8746] = 3,
+    ACTIONS(1399), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(518), 1,
+    ACTIONS(1401), 1,
+      sym_embedded_code,
+  [8756] = 3,
+    ACTIONS(1137), 1,
       anon_sym_LBRACE,
-    ACTIONS(541), 1,
-      sym_inline_code,
-    STATE(43), 2,
-      sym__code_or_inline,
-      sym_code_block,
-  [3693] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    STATE(41), 1,
-      sym_string,
-  [3706] = 2,
-    ACTIONS(3), 1,
+    STATE(152), 1,
+      sym_code_block,
+  [8766] = 3,
+    ACTIONS(1137), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(543), 3,
-      ts_builtin_sym_end,
-      anon_sym_declare,
-      anon_sym_suite,
-  [3715] = 2,
-    ACTIONS(3), 1,
+    STATE(79), 1,
+      sym_code_block,
+  [8776] = 3,
+    ACTIONS(1403), 1,
+      anon_sym_LBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(545), 3,
-      ts_builtin_sym_end,
-      anon_sym_declare,
-      anon_sym_suite,
-  [3724] = 3,
-    ACTIONS(3), 1,
+    STATE(292), 1,
+      sym_param_value_array,
+  [8786] = 3,
+    ACTIONS(924), 1,
+      anon_sym_LBRACK,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(549), 1,
-      anon_sym_RBRACE,
-    ACTIONS(547), 2,
-      anon_sym_anvil,
-      sym_identifier,
-  [3735] = 4,
-    ACTIONS(3), 1,
+    STATE(80), 1,
+      sym_string_array,
+  [8796] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(502), 1,
-      sym_identifier,
-    ACTIONS(551), 1,
+    ACTIONS(1405), 2,
       anon_sym_RPAREN,
-    STATE(171), 1,
-      sym_argument,
-  [3748] = 4,
-    ACTIONS(3), 1,
+      anon_sym_COMMA,
+  [8804] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(551), 1,
+    ACTIONS(1407), 2,
       anon_sym_RPAREN,
-    ACTIONS(553), 1,
       anon_sym_COMMA,
-    STATE(146), 1,
-      aux_sym_argument_list_repeat1,
-  [3761] = 4,
-    ACTIONS(3), 1,
+  [8812] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(555), 1,
+    ACTIONS(1409), 2,
+      anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(557), 1,
-      anon_sym_RBRACK,
-    STATE(141), 1,
-      aux_sym_string_array_repeat1,
-  [3774] = 4,
-    ACTIONS(3), 1,
+  [8820] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    STATE(223), 1,
-      sym_string,
-  [3787] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(1411), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [8828] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(561), 1,
-      anon_sym_RBRACE,
-    ACTIONS(559), 2,
-      anon_sym_anvil,
+    ACTIONS(1413), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [8836] = 3,
+    ACTIONS(1228), 1,
       sym_identifier,
-  [3798] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(565), 1,
-      anon_sym_RBRACE,
-    ACTIONS(563), 2,
-      anon_sym_anvil,
+    STATE(289), 1,
+      sym_fixture_param,
+  [8846] = 3,
+    ACTIONS(1295), 1,
       sym_identifier,
-  [3809] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(567), 1,
-      sym_identifier,
-    ACTIONS(569), 1,
+    STATE(291), 1,
+      sym_bench_param,
+  [8856] = 2,
+    ACTIONS(13), 1,
+      sym_comment,
+    ACTIONS(1415), 2,
       anon_sym_RPAREN,
-    STATE(156), 1,
-      sym_fixture_param,
-  [3822] = 3,
-    ACTIONS(3), 1,
+      anon_sym_COMMA,
+  [8864] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(573), 1,
-      anon_sym_RBRACE,
-    ACTIONS(571), 2,
-      anon_sym_anvil,
-      sym_identifier,
-  [3833] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1417), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [8872] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(567), 1,
-      sym_identifier,
-    ACTIONS(575), 1,
-      anon_sym_RPAREN,
-    STATE(179), 1,
-      sym_fixture_param,
-  [3846] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1419), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [8880] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(575), 1,
-      anon_sym_RPAREN,
-    ACTIONS(577), 1,
+    ACTIONS(1421), 2,
       anon_sym_COMMA,
-    STATE(150), 1,
-      aux_sym_fixture_params_repeat1,
-  [3859] = 3,
-    ACTIONS(3), 1,
+      anon_sym_RBRACK,
+  [8888] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(167), 1,
-      sym_boolean,
-    ACTIONS(402), 2,
-      anon_sym_true,
-      anon_sym_false,
-  [3870] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(1423), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [8896] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(581), 1,
-      anon_sym_RBRACE,
-    ACTIONS(579), 2,
-      anon_sym_anvil,
+    ACTIONS(1425), 2,
       sym_identifier,
-  [3881] = 3,
-    ACTIONS(3), 1,
+      anon_sym_RBRACE,
+  [8904] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(585), 1,
+    ACTIONS(1427), 2,
       anon_sym_RBRACE,
-    ACTIONS(583), 2,
-      anon_sym_anvil,
-      sym_identifier,
-  [3892] = 4,
-    ACTIONS(3), 1,
+      anon_sym_charting,
+  [8912] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(539), 1,
-      anon_sym_RBRACK,
-    ACTIONS(587), 1,
+    ACTIONS(1429), 2,
+      anon_sym_RPAREN,
       anon_sym_COMMA,
-    STATE(151), 1,
-      aux_sym_string_array_repeat1,
-  [3905] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(589), 3,
-      ts_builtin_sym_end,
-      anon_sym_declare,
-      anon_sym_suite,
-  [3914] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(591), 3,
-      ts_builtin_sym_end,
-      anon_sym_declare,
-      anon_sym_suite,
-  [3923] = 3,
-    ACTIONS(3), 1,
+  [8920] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(595), 1,
+    ACTIONS(1431), 2,
       anon_sym_RBRACE,
-    ACTIONS(593), 2,
-      anon_sym_anvil,
-      sym_identifier,
-  [3934] = 4,
-    ACTIONS(3), 1,
+      anon_sym_charting,
+  [8928] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(502), 1,
-      sym_identifier,
-    ACTIONS(597), 1,
+    ACTIONS(1433), 2,
       anon_sym_RPAREN,
-    STATE(171), 1,
-      sym_argument,
-  [3947] = 4,
-    ACTIONS(3), 1,
+      anon_sym_COMMA,
+  [8936] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(599), 1,
+    ACTIONS(1435), 2,
       anon_sym_RPAREN,
-    ACTIONS(601), 1,
       anon_sym_COMMA,
-    STATE(146), 1,
-      aux_sym_argument_list_repeat1,
-  [3960] = 3,
-    ACTIONS(3), 1,
+  [8944] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(172), 1,
-      sym_boolean,
-    ACTIONS(402), 2,
-      anon_sym_true,
-      anon_sym_false,
-  [3971] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1437), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [8952] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(604), 1,
+    ACTIONS(1439), 2,
       anon_sym_RPAREN,
-    ACTIONS(606), 1,
       anon_sym_COMMA,
-    STATE(129), 1,
-      aux_sym_argument_list_repeat1,
-  [3984] = 4,
-    ACTIONS(3), 1,
+  [8960] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(567), 1,
-      sym_identifier,
-    ACTIONS(608), 1,
+    ACTIONS(1441), 2,
       anon_sym_RPAREN,
-    STATE(179), 1,
-      sym_fixture_param,
-  [3997] = 4,
-    ACTIONS(3), 1,
+      anon_sym_COMMA,
+  [8968] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(610), 1,
+    ACTIONS(1443), 2,
       anon_sym_RPAREN,
-    ACTIONS(612), 1,
       anon_sym_COMMA,
-    STATE(150), 1,
-      aux_sym_fixture_params_repeat1,
-  [4010] = 4,
-    ACTIONS(3), 1,
+  [8976] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(615), 1,
+    ACTIONS(1445), 2,
+      anon_sym_RPAREN,
       anon_sym_COMMA,
-    ACTIONS(618), 1,
-      anon_sym_RBRACK,
-    STATE(151), 1,
-      aux_sym_string_array_repeat1,
-  [4023] = 4,
-    ACTIONS(3), 1,
+  [8984] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    STATE(230), 1,
-      sym_string,
-  [4036] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1447), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [8992] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(620), 1,
+    ACTIONS(1449), 2,
       anon_sym_RPAREN,
-    ACTIONS(622), 1,
       anon_sym_COMMA,
-    STATE(155), 1,
-      aux_sym_chart_params_repeat1,
-  [4049] = 4,
-    ACTIONS(3), 1,
+  [9000] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(345), 1,
-      anon_sym_DQUOTE,
-    ACTIONS(347), 1,
-      anon_sym_SQUOTE,
-    STATE(184), 1,
-      sym_string,
-  [4062] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1451), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [9008] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(339), 1,
+    ACTIONS(1453), 2,
       anon_sym_RPAREN,
-    ACTIONS(624), 1,
       anon_sym_COMMA,
-    STATE(158), 1,
-      aux_sym_chart_params_repeat1,
-  [4075] = 4,
-    ACTIONS(3), 1,
+  [9016] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(626), 1,
+    ACTIONS(1455), 2,
       anon_sym_RPAREN,
-    ACTIONS(628), 1,
       anon_sym_COMMA,
-    STATE(137), 1,
-      aux_sym_fixture_params_repeat1,
-  [4088] = 3,
-    ACTIONS(3), 1,
+  [9024] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(235), 1,
-      sym_run_mode,
-    ACTIONS(630), 2,
-      anon_sym_timeBased,
-      anon_sym_iterationBased,
-  [4099] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(1457), 2,
+      anon_sym_RPAREN,
+      anon_sym_COMMA,
+  [9032] = 2,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(632), 1,
+    ACTIONS(1459), 2,
       anon_sym_RPAREN,
-    ACTIONS(634), 1,
       anon_sym_COMMA,
-    STATE(158), 1,
-      aux_sym_chart_params_repeat1,
-  [4112] = 2,
-    ACTIONS(3), 1,
+  [9040] = 3,
+    ACTIONS(1332), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(637), 3,
-      ts_builtin_sym_end,
-      anon_sym_declare,
+    STATE(316), 1,
+      sym_generator_arg,
+  [9050] = 2,
+    ACTIONS(1461), 1,
+      anon_sym_std,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9057] = 2,
+    ACTIONS(1463), 1,
       anon_sym_suite,
-  [4121] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    STATE(199), 1,
-      sym_run_mode,
-    ACTIONS(630), 2,
-      anon_sym_timeBased,
-      anon_sym_iterationBased,
-  [4132] = 2,
-    ACTIONS(3), 1,
+  [9064] = 2,
+    ACTIONS(1465), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(639), 3,
+  [9071] = 2,
+    ACTIONS(1467), 1,
       ts_builtin_sym_end,
-      anon_sym_declare,
-      anon_sym_suite,
-  [4141] = 4,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(641), 1,
-      anon_sym_RPAREN,
-    ACTIONS(643), 1,
-      anon_sym_fork,
-    STATE(198), 1,
-      sym_anvil_args,
-  [4154] = 3,
-    ACTIONS(3), 1,
+  [9078] = 2,
+    ACTIONS(1469), 1,
+      anon_sym_COLON_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
-      anon_sym_LBRACE,
-    STATE(42), 1,
-      sym_code_block,
-  [4164] = 3,
-    ACTIONS(3), 1,
+  [9085] = 2,
+    ACTIONS(1471), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(355), 1,
-      anon_sym_LBRACK,
-    STATE(36), 1,
-      sym_string_array,
-  [4174] = 3,
-    ACTIONS(3), 1,
+  [9092] = 2,
+    ACTIONS(1473), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9099] = 2,
+    ACTIONS(1475), 1,
+      anon_sym_DOT,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9106] = 2,
+    ACTIONS(1477), 1,
+      anon_sym_spawnAnvil,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9113] = 2,
+    ACTIONS(1479), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9120] = 2,
+    ACTIONS(1481), 1,
+      anon_sym_sameDataset,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9127] = 2,
+    ACTIONS(1483), 1,
+      anon_sym_sameDataset,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9134] = 2,
+    ACTIONS(1485), 1,
+      anon_sym_sameDataset,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9141] = 2,
+    ACTIONS(1487), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9148] = 2,
+    ACTIONS(1489), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9155] = 2,
+    ACTIONS(1491), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9162] = 2,
+    ACTIONS(1493), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9169] = 2,
+    ACTIONS(1495), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9176] = 2,
+    ACTIONS(1497), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9183] = 2,
+    ACTIONS(1499), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9190] = 2,
+    ACTIONS(1501), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9197] = 2,
+    ACTIONS(1503), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9204] = 2,
+    ACTIONS(1505), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9211] = 2,
+    ACTIONS(1507), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9218] = 2,
+    ACTIONS(1509), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9225] = 2,
+    ACTIONS(1511), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9232] = 2,
+    ACTIONS(1513), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9239] = 2,
+    ACTIONS(1515), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9246] = 2,
+    ACTIONS(1517), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9253] = 2,
+    ACTIONS(1519), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9260] = 2,
+    ACTIONS(1521), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9267] = 2,
+    ACTIONS(1523), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9274] = 2,
+    ACTIONS(1525), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9281] = 2,
+    ACTIONS(1527), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9288] = 2,
+    ACTIONS(1529), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9295] = 2,
+    ACTIONS(1531), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9302] = 2,
+    ACTIONS(1533), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9309] = 2,
+    ACTIONS(1535), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(445), 1,
-      sym_identifier,
-    STATE(183), 1,
-      sym_language_tag,
-  [4184] = 3,
-    ACTIONS(3), 1,
+  [9316] = 2,
+    ACTIONS(1537), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(645), 1,
-      anon_sym_LBRACE,
-    STATE(28), 1,
-      sym_global_setup_body,
-  [4194] = 3,
-    ACTIONS(3), 1,
+  [9323] = 2,
+    ACTIONS(1539), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(449), 1,
-      anon_sym_LBRACE,
-    STATE(142), 1,
-      sym_suite_body,
-  [4204] = 2,
-    ACTIONS(3), 1,
+  [9330] = 2,
+    ACTIONS(1541), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(647), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4212] = 3,
-    ACTIONS(3), 1,
+  [9337] = 2,
+    ACTIONS(1543), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(649), 1,
-      anon_sym_DOT,
-    ACTIONS(651), 1,
+  [9344] = 2,
+    ACTIONS(1545), 1,
       anon_sym_LPAREN,
-  [4222] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(502), 1,
-      sym_identifier,
-    STATE(171), 1,
-      sym_argument,
-  [4232] = 2,
-    ACTIONS(3), 1,
+  [9351] = 2,
+    ACTIONS(1547), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(599), 2,
+  [9358] = 2,
+    ACTIONS(1549), 1,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4240] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(449), 1,
-      anon_sym_LBRACE,
-    STATE(143), 1,
-      sym_suite_body,
-  [4250] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(653), 1,
-      anon_sym_RBRACE,
-    ACTIONS(655), 1,
-      sym_embedded_code,
-  [4260] = 3,
-    ACTIONS(3), 1,
+  [9365] = 2,
+    ACTIONS(1551), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(657), 1,
-      anon_sym_LBRACE,
-    STATE(47), 1,
-      sym_after_body,
-  [4270] = 3,
-    ACTIONS(3), 1,
+  [9372] = 2,
+    ACTIONS(1553), 1,
+      anon_sym_sameDataset,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(659), 1,
-      anon_sym_RPAREN,
-    ACTIONS(661), 1,
-      sym_embedded_code,
-  [4280] = 3,
-    ACTIONS(3), 1,
+  [9379] = 2,
+    ACTIONS(1555), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
-      anon_sym_LBRACE,
-    STATE(82), 1,
-      sym_code_block,
-  [4290] = 3,
-    ACTIONS(3), 1,
+  [9386] = 2,
+    ACTIONS(1557), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
-      anon_sym_LBRACE,
-    STATE(80), 1,
-      sym_code_block,
-  [4300] = 2,
-    ACTIONS(3), 1,
+  [9393] = 2,
+    ACTIONS(1559), 1,
+      anon_sym_DOT,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(663), 2,
+  [9400] = 2,
+    ACTIONS(1561), 1,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4308] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9407] = 2,
+    ACTIONS(1563), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(610), 2,
+  [9414] = 2,
+    ACTIONS(1565), 1,
       anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4316] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
-      anon_sym_LBRACE,
-    STATE(79), 1,
-      sym_code_block,
-  [4326] = 3,
-    ACTIONS(3), 1,
+  [9421] = 2,
+    ACTIONS(1567), 1,
+      anon_sym_init,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(494), 1,
+  [9428] = 2,
+    ACTIONS(1569), 1,
       anon_sym_LBRACE,
-    STATE(77), 1,
-      sym_code_block,
-  [4336] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(665), 2,
-      anon_sym_LBRACE,
+  [9435] = 2,
+    ACTIONS(1571), 1,
       anon_sym_COLON,
-  [4344] = 3,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(667), 1,
-      anon_sym_LBRACE,
-    STATE(48), 1,
-      sym_setup_body,
-  [4354] = 2,
-    ACTIONS(3), 1,
+  [9442] = 2,
+    ACTIONS(1573), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(618), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [4362] = 3,
-    ACTIONS(3), 1,
+  [9449] = 2,
+    ACTIONS(1575), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(514), 1,
-      anon_sym_LBRACE,
-    STATE(54), 1,
-      sym_fixture_body,
-  [4372] = 3,
-    ACTIONS(3), 1,
+  [9456] = 2,
+    ACTIONS(1577), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(567), 1,
-      sym_identifier,
-    STATE(179), 1,
-      sym_fixture_param,
-  [4382] = 2,
-    ACTIONS(3), 1,
+  [9463] = 2,
+    ACTIONS(1579), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(669), 2,
-      anon_sym_RBRACE,
-      anon_sym_charting,
-  [4390] = 2,
-    ACTIONS(3), 1,
+  [9470] = 2,
+    ACTIONS(1581), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(671), 2,
-      anon_sym_timeBased,
-      anon_sym_iterationBased,
-  [4398] = 2,
-    ACTIONS(3), 1,
+  [9477] = 2,
+    ACTIONS(1583), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(673), 2,
-      anon_sym_RBRACE,
-      anon_sym_charting,
-  [4406] = 2,
-    ACTIONS(3), 1,
+  [9484] = 2,
+    ACTIONS(1585), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(632), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4414] = 2,
-    ACTIONS(3), 1,
+  [9491] = 2,
+    ACTIONS(1587), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(675), 2,
-      anon_sym_RPAREN,
-      anon_sym_COMMA,
-  [4422] = 3,
-    ACTIONS(3), 1,
+  [9498] = 2,
+    ACTIONS(1589), 1,
+      anon_sym_in,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(677), 1,
-      anon_sym_LBRACE,
-    STATE(50), 1,
-      sym_benchmark_body,
-  [4432] = 2,
-    ACTIONS(3), 1,
+  [9505] = 2,
+    ACTIONS(1591), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(679), 1,
+  [9512] = 2,
+    ACTIONS(1593), 1,
       anon_sym_COLON,
-  [4439] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(681), 1,
-      anon_sym_LPAREN,
-  [4446] = 2,
-    ACTIONS(3), 1,
+  [9519] = 2,
+    ACTIONS(1595), 1,
+      anon_sym_DQUOTE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(683), 1,
-      sym_identifier,
-  [4453] = 2,
-    ACTIONS(3), 1,
+  [9526] = 2,
+    ACTIONS(1597), 1,
+      anon_sym_SQUOTE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(685), 1,
+  [9533] = 2,
+    ACTIONS(1599), 1,
       anon_sym_LBRACE,
-  [4460] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(687), 1,
-      anon_sym_COLON,
-  [4467] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(689), 1,
-      anon_sym_RPAREN,
-  [4474] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(691), 1,
-      anon_sym_sameDataset,
-  [4481] = 2,
-    ACTIONS(3), 1,
+  [9540] = 2,
+    ACTIONS(1601), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(693), 1,
-      anon_sym_init,
-  [4488] = 2,
-    ACTIONS(3), 1,
+  [9547] = 2,
+    ACTIONS(1603), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(695), 1,
+  [9554] = 2,
+    ACTIONS(1605), 1,
       anon_sym_COLON,
-  [4495] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(697), 1,
-      anon_sym_LPAREN,
-  [4502] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(699), 1,
+  [9561] = 2,
+    ACTIONS(1607), 1,
       anon_sym_LBRACE,
-  [4509] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(701), 1,
+  [9568] = 2,
+    ACTIONS(1609), 1,
       anon_sym_LPAREN,
-  [4516] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(703), 1,
-      anon_sym_SQUOTE,
-  [4523] = 2,
-    ACTIONS(3), 1,
+  [9575] = 2,
+    ACTIONS(1611), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(705), 1,
-      anon_sym_DOT,
-  [4530] = 2,
-    ACTIONS(3), 1,
+  [9582] = 2,
+    ACTIONS(1613), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(707), 1,
-      sym_identifier,
-  [4537] = 2,
-    ACTIONS(3), 1,
+  [9589] = 2,
+    ACTIONS(1615), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(709), 1,
-      anon_sym_RPAREN,
-  [4544] = 2,
-    ACTIONS(3), 1,
+  [9596] = 2,
+    ACTIONS(1617), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(711), 1,
-      sym_identifier,
-  [4551] = 2,
-    ACTIONS(3), 1,
+  [9603] = 2,
+    ACTIONS(1619), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(713), 1,
-      anon_sym_COLON,
-  [4558] = 2,
-    ACTIONS(3), 1,
+  [9610] = 2,
+    ACTIONS(1621), 1,
+      anon_sym_RBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(715), 1,
-      anon_sym_COLON,
-  [4565] = 2,
-    ACTIONS(3), 1,
+  [9617] = 2,
+    ACTIONS(1623), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(717), 1,
+  [9624] = 2,
+    ACTIONS(1625), 1,
       anon_sym_LBRACE,
-  [4572] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(719), 1,
-      anon_sym_COLON,
-  [4579] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(721), 1,
-      anon_sym_COLON,
-  [4586] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(723), 1,
-      anon_sym_suite,
-  [4593] = 2,
-    ACTIONS(3), 1,
+  [9631] = 2,
+    ACTIONS(1627), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(725), 1,
-      anon_sym_COLON,
-  [4600] = 2,
-    ACTIONS(3), 1,
+  [9638] = 2,
+    ACTIONS(1629), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(727), 1,
-      anon_sym_COLON,
-  [4607] = 2,
-    ACTIONS(3), 1,
+  [9645] = 2,
+    ACTIONS(1631), 1,
+      sym_identifier,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(729), 1,
+  [9652] = 2,
+    ACTIONS(1633), 1,
       anon_sym_LBRACE,
-  [4614] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(703), 1,
-      anon_sym_DQUOTE,
-  [4621] = 2,
-    ACTIONS(3), 1,
+  [9659] = 2,
+    ACTIONS(1635), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(731), 1,
-      anon_sym_COLON,
-  [4628] = 2,
-    ACTIONS(3), 1,
+  [9666] = 2,
+    ACTIONS(1637), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(733), 1,
-      sym_identifier,
-  [4635] = 2,
-    ACTIONS(3), 1,
+  [9673] = 2,
+    ACTIONS(1639), 1,
+      anon_sym_LPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(735), 1,
-      anon_sym_COLON,
-  [4642] = 2,
-    ACTIONS(3), 1,
+  [9680] = 2,
+    ACTIONS(1641), 1,
+      anon_sym_LBRACE,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(737), 1,
+  [9687] = 2,
+    ACTIONS(1643), 1,
       anon_sym_RPAREN,
-  [4649] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(739), 1,
+  [9694] = 2,
+    ACTIONS(1645), 1,
       anon_sym_COLON,
-  [4656] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(741), 1,
-      anon_sym_RPAREN,
-  [4663] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(743), 1,
-      ts_builtin_sym_end,
-  [4670] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(745), 1,
+  [9701] = 2,
+    ACTIONS(1647), 1,
       anon_sym_COLON,
-  [4677] = 2,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(747), 1,
-      anon_sym_spawnAnvil,
-  [4684] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(749), 1,
-      anon_sym_sameDataset,
-  [4691] = 2,
-    ACTIONS(3), 1,
+  [9708] = 2,
+    ACTIONS(1649), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(751), 1,
-      anon_sym_RPAREN,
-  [4698] = 2,
-    ACTIONS(3), 1,
+  [9715] = 2,
+    ACTIONS(1651), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(753), 1,
-      sym_identifier,
-  [4705] = 2,
-    ACTIONS(3), 1,
+  [9722] = 2,
+    ACTIONS(1653), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(755), 1,
-      anon_sym_LBRACE,
-  [4712] = 2,
-    ACTIONS(3), 1,
+  [9729] = 2,
+    ACTIONS(1655), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(757), 1,
-      anon_sym_RBRACE,
-  [4719] = 2,
-    ACTIONS(3), 1,
+  [9736] = 2,
+    ACTIONS(1657), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(759), 1,
+  [9743] = 2,
+    ACTIONS(1659), 1,
       anon_sym_COLON,
-  [4726] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(761), 1,
-      anon_sym_sameDataset,
-  [4733] = 2,
-    ACTIONS(3), 1,
+  [9750] = 2,
+    ACTIONS(1661), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(763), 1,
-      anon_sym_DOT,
-  [4740] = 2,
-    ACTIONS(3), 1,
+  [9757] = 2,
+    ACTIONS(1663), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(765), 1,
-      anon_sym_LPAREN,
-  [4747] = 2,
-    ACTIONS(3), 1,
+  [9764] = 2,
+    ACTIONS(1665), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(767), 1,
-      anon_sym_RPAREN,
-  [4754] = 2,
-    ACTIONS(3), 1,
+  [9771] = 2,
+    ACTIONS(1667), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(769), 1,
-      sym_identifier,
-  [4761] = 2,
-    ACTIONS(3), 1,
+  [9778] = 2,
+    ACTIONS(1669), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(771), 1,
-      anon_sym_LPAREN,
-  [4768] = 2,
-    ACTIONS(3), 1,
+  [9785] = 2,
+    ACTIONS(1671), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(773), 1,
-      anon_sym_RPAREN,
-  [4775] = 2,
-    ACTIONS(3), 1,
+  [9792] = 2,
+    ACTIONS(1673), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(775), 1,
+  [9799] = 2,
+    ACTIONS(1675), 1,
       anon_sym_COLON,
-  [4782] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(777), 1,
+  [9806] = 2,
+    ACTIONS(1677), 1,
       anon_sym_COLON,
-  [4789] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(779), 1,
+  [9813] = 2,
+    ACTIONS(1679), 1,
       anon_sym_COLON,
-  [4796] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(781), 1,
-      sym_identifier,
-  [4803] = 2,
-    ACTIONS(3), 1,
+  [9820] = 2,
+    ACTIONS(1681), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(783), 1,
-      anon_sym_std,
-  [4810] = 2,
-    ACTIONS(3), 1,
+  [9827] = 2,
+    ACTIONS(1683), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(785), 1,
-      anon_sym_COLON_COLON,
-  [4817] = 2,
-    ACTIONS(3), 1,
+  [9834] = 2,
+    ACTIONS(1685), 1,
+      anon_sym_COLON,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(787), 1,
+  [9841] = 2,
+    ACTIONS(1687), 1,
       anon_sym_COLON,
-  [4824] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(789), 1,
+  [9848] = 2,
+    ACTIONS(1689), 1,
       anon_sym_COLON,
-  [4831] = 2,
-    ACTIONS(3), 1,
+    ACTIONS(13), 1,
+      sym_comment,
+  [9855] = 2,
+    ACTIONS(1691), 1,
+      anon_sym_RPAREN,
+    ACTIONS(13), 1,
       sym_comment,
-    ACTIONS(791), 1,
+  [9862] = 2,
+    ACTIONS(1693), 1,
       anon_sym_COLON,
+    ACTIONS(13), 1,
+      sym_comment,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 49,
-  [SMALL_STATE(4)] = 98,
-  [SMALL_STATE(5)] = 147,
-  [SMALL_STATE(6)] = 195,
-  [SMALL_STATE(7)] = 243,
-  [SMALL_STATE(8)] = 291,
-  [SMALL_STATE(9)] = 339,
-  [SMALL_STATE(10)] = 387,
-  [SMALL_STATE(11)] = 435,
-  [SMALL_STATE(12)] = 502,
-  [SMALL_STATE(13)] = 569,
-  [SMALL_STATE(14)] = 636,
-  [SMALL_STATE(15)] = 703,
-  [SMALL_STATE(16)] = 770,
-  [SMALL_STATE(17)] = 837,
-  [SMALL_STATE(18)] = 883,
-  [SMALL_STATE(19)] = 929,
-  [SMALL_STATE(20)] = 975,
-  [SMALL_STATE(21)] = 1036,
-  [SMALL_STATE(22)] = 1097,
-  [SMALL_STATE(23)] = 1158,
-  [SMALL_STATE(24)] = 1199,
-  [SMALL_STATE(25)] = 1242,
-  [SMALL_STATE(26)] = 1285,
-  [SMALL_STATE(27)] = 1329,
-  [SMALL_STATE(28)] = 1366,
-  [SMALL_STATE(29)] = 1403,
-  [SMALL_STATE(30)] = 1440,
-  [SMALL_STATE(31)] = 1475,
-  [SMALL_STATE(32)] = 1510,
-  [SMALL_STATE(33)] = 1545,
-  [SMALL_STATE(34)] = 1580,
-  [SMALL_STATE(35)] = 1615,
-  [SMALL_STATE(36)] = 1650,
-  [SMALL_STATE(37)] = 1685,
-  [SMALL_STATE(38)] = 1720,
-  [SMALL_STATE(39)] = 1755,
-  [SMALL_STATE(40)] = 1790,
-  [SMALL_STATE(41)] = 1825,
-  [SMALL_STATE(42)] = 1860,
-  [SMALL_STATE(43)] = 1895,
-  [SMALL_STATE(44)] = 1930,
-  [SMALL_STATE(45)] = 1965,
-  [SMALL_STATE(46)] = 2000,
-  [SMALL_STATE(47)] = 2034,
-  [SMALL_STATE(48)] = 2068,
-  [SMALL_STATE(49)] = 2102,
-  [SMALL_STATE(50)] = 2136,
-  [SMALL_STATE(51)] = 2170,
-  [SMALL_STATE(52)] = 2204,
-  [SMALL_STATE(53)] = 2238,
-  [SMALL_STATE(54)] = 2272,
-  [SMALL_STATE(55)] = 2306,
-  [SMALL_STATE(56)] = 2340,
-  [SMALL_STATE(57)] = 2374,
-  [SMALL_STATE(58)] = 2408,
-  [SMALL_STATE(59)] = 2442,
-  [SMALL_STATE(60)] = 2484,
-  [SMALL_STATE(61)] = 2523,
-  [SMALL_STATE(62)] = 2562,
-  [SMALL_STATE(63)] = 2598,
-  [SMALL_STATE(64)] = 2631,
-  [SMALL_STATE(65)] = 2664,
-  [SMALL_STATE(66)] = 2697,
-  [SMALL_STATE(67)] = 2730,
-  [SMALL_STATE(68)] = 2760,
-  [SMALL_STATE(69)] = 2790,
-  [SMALL_STATE(70)] = 2820,
-  [SMALL_STATE(71)] = 2849,
-  [SMALL_STATE(72)] = 2879,
-  [SMALL_STATE(73)] = 2900,
-  [SMALL_STATE(74)] = 2921,
-  [SMALL_STATE(75)] = 2942,
-  [SMALL_STATE(76)] = 2959,
-  [SMALL_STATE(77)] = 2971,
-  [SMALL_STATE(78)] = 2983,
-  [SMALL_STATE(79)] = 2995,
-  [SMALL_STATE(80)] = 3007,
-  [SMALL_STATE(81)] = 3019,
-  [SMALL_STATE(82)] = 3031,
-  [SMALL_STATE(83)] = 3043,
-  [SMALL_STATE(84)] = 3061,
-  [SMALL_STATE(85)] = 3072,
-  [SMALL_STATE(86)] = 3089,
-  [SMALL_STATE(87)] = 3106,
-  [SMALL_STATE(88)] = 3123,
-  [SMALL_STATE(89)] = 3140,
-  [SMALL_STATE(90)] = 3157,
-  [SMALL_STATE(91)] = 3174,
-  [SMALL_STATE(92)] = 3191,
-  [SMALL_STATE(93)] = 3208,
-  [SMALL_STATE(94)] = 3225,
-  [SMALL_STATE(95)] = 3242,
-  [SMALL_STATE(96)] = 3259,
-  [SMALL_STATE(97)] = 3276,
-  [SMALL_STATE(98)] = 3293,
-  [SMALL_STATE(99)] = 3310,
-  [SMALL_STATE(100)] = 3327,
-  [SMALL_STATE(101)] = 3344,
-  [SMALL_STATE(102)] = 3361,
-  [SMALL_STATE(103)] = 3378,
-  [SMALL_STATE(104)] = 3395,
-  [SMALL_STATE(105)] = 3408,
-  [SMALL_STATE(106)] = 3425,
-  [SMALL_STATE(107)] = 3439,
-  [SMALL_STATE(108)] = 3455,
-  [SMALL_STATE(109)] = 3469,
-  [SMALL_STATE(110)] = 3483,
-  [SMALL_STATE(111)] = 3499,
-  [SMALL_STATE(112)] = 3515,
-  [SMALL_STATE(113)] = 3531,
-  [SMALL_STATE(114)] = 3545,
-  [SMALL_STATE(115)] = 3561,
-  [SMALL_STATE(116)] = 3575,
-  [SMALL_STATE(117)] = 3591,
-  [SMALL_STATE(118)] = 3605,
-  [SMALL_STATE(119)] = 3619,
-  [SMALL_STATE(120)] = 3635,
-  [SMALL_STATE(121)] = 3649,
-  [SMALL_STATE(122)] = 3663,
-  [SMALL_STATE(123)] = 3679,
-  [SMALL_STATE(124)] = 3693,
-  [SMALL_STATE(125)] = 3706,
-  [SMALL_STATE(126)] = 3715,
-  [SMALL_STATE(127)] = 3724,
-  [SMALL_STATE(128)] = 3735,
-  [SMALL_STATE(129)] = 3748,
-  [SMALL_STATE(130)] = 3761,
-  [SMALL_STATE(131)] = 3774,
-  [SMALL_STATE(132)] = 3787,
-  [SMALL_STATE(133)] = 3798,
-  [SMALL_STATE(134)] = 3809,
-  [SMALL_STATE(135)] = 3822,
-  [SMALL_STATE(136)] = 3833,
-  [SMALL_STATE(137)] = 3846,
-  [SMALL_STATE(138)] = 3859,
-  [SMALL_STATE(139)] = 3870,
-  [SMALL_STATE(140)] = 3881,
-  [SMALL_STATE(141)] = 3892,
-  [SMALL_STATE(142)] = 3905,
-  [SMALL_STATE(143)] = 3914,
-  [SMALL_STATE(144)] = 3923,
-  [SMALL_STATE(145)] = 3934,
-  [SMALL_STATE(146)] = 3947,
-  [SMALL_STATE(147)] = 3960,
-  [SMALL_STATE(148)] = 3971,
-  [SMALL_STATE(149)] = 3984,
-  [SMALL_STATE(150)] = 3997,
-  [SMALL_STATE(151)] = 4010,
-  [SMALL_STATE(152)] = 4023,
-  [SMALL_STATE(153)] = 4036,
-  [SMALL_STATE(154)] = 4049,
-  [SMALL_STATE(155)] = 4062,
-  [SMALL_STATE(156)] = 4075,
-  [SMALL_STATE(157)] = 4088,
-  [SMALL_STATE(158)] = 4099,
-  [SMALL_STATE(159)] = 4112,
-  [SMALL_STATE(160)] = 4121,
-  [SMALL_STATE(161)] = 4132,
-  [SMALL_STATE(162)] = 4141,
-  [SMALL_STATE(163)] = 4154,
-  [SMALL_STATE(164)] = 4164,
-  [SMALL_STATE(165)] = 4174,
-  [SMALL_STATE(166)] = 4184,
-  [SMALL_STATE(167)] = 4194,
-  [SMALL_STATE(168)] = 4204,
-  [SMALL_STATE(169)] = 4212,
-  [SMALL_STATE(170)] = 4222,
-  [SMALL_STATE(171)] = 4232,
-  [SMALL_STATE(172)] = 4240,
-  [SMALL_STATE(173)] = 4250,
-  [SMALL_STATE(174)] = 4260,
-  [SMALL_STATE(175)] = 4270,
-  [SMALL_STATE(176)] = 4280,
-  [SMALL_STATE(177)] = 4290,
-  [SMALL_STATE(178)] = 4300,
-  [SMALL_STATE(179)] = 4308,
-  [SMALL_STATE(180)] = 4316,
-  [SMALL_STATE(181)] = 4326,
-  [SMALL_STATE(182)] = 4336,
-  [SMALL_STATE(183)] = 4344,
-  [SMALL_STATE(184)] = 4354,
-  [SMALL_STATE(185)] = 4362,
-  [SMALL_STATE(186)] = 4372,
-  [SMALL_STATE(187)] = 4382,
-  [SMALL_STATE(188)] = 4390,
-  [SMALL_STATE(189)] = 4398,
-  [SMALL_STATE(190)] = 4406,
-  [SMALL_STATE(191)] = 4414,
-  [SMALL_STATE(192)] = 4422,
-  [SMALL_STATE(193)] = 4432,
-  [SMALL_STATE(194)] = 4439,
-  [SMALL_STATE(195)] = 4446,
-  [SMALL_STATE(196)] = 4453,
-  [SMALL_STATE(197)] = 4460,
-  [SMALL_STATE(198)] = 4467,
-  [SMALL_STATE(199)] = 4474,
-  [SMALL_STATE(200)] = 4481,
-  [SMALL_STATE(201)] = 4488,
-  [SMALL_STATE(202)] = 4495,
-  [SMALL_STATE(203)] = 4502,
-  [SMALL_STATE(204)] = 4509,
-  [SMALL_STATE(205)] = 4516,
-  [SMALL_STATE(206)] = 4523,
-  [SMALL_STATE(207)] = 4530,
-  [SMALL_STATE(208)] = 4537,
-  [SMALL_STATE(209)] = 4544,
-  [SMALL_STATE(210)] = 4551,
-  [SMALL_STATE(211)] = 4558,
-  [SMALL_STATE(212)] = 4565,
-  [SMALL_STATE(213)] = 4572,
-  [SMALL_STATE(214)] = 4579,
-  [SMALL_STATE(215)] = 4586,
-  [SMALL_STATE(216)] = 4593,
-  [SMALL_STATE(217)] = 4600,
-  [SMALL_STATE(218)] = 4607,
-  [SMALL_STATE(219)] = 4614,
-  [SMALL_STATE(220)] = 4621,
-  [SMALL_STATE(221)] = 4628,
-  [SMALL_STATE(222)] = 4635,
-  [SMALL_STATE(223)] = 4642,
-  [SMALL_STATE(224)] = 4649,
-  [SMALL_STATE(225)] = 4656,
-  [SMALL_STATE(226)] = 4663,
-  [SMALL_STATE(227)] = 4670,
-  [SMALL_STATE(228)] = 4677,
-  [SMALL_STATE(229)] = 4684,
-  [SMALL_STATE(230)] = 4691,
-  [SMALL_STATE(231)] = 4698,
-  [SMALL_STATE(232)] = 4705,
-  [SMALL_STATE(233)] = 4712,
-  [SMALL_STATE(234)] = 4719,
-  [SMALL_STATE(235)] = 4726,
-  [SMALL_STATE(236)] = 4733,
-  [SMALL_STATE(237)] = 4740,
-  [SMALL_STATE(238)] = 4747,
-  [SMALL_STATE(239)] = 4754,
-  [SMALL_STATE(240)] = 4761,
-  [SMALL_STATE(241)] = 4768,
-  [SMALL_STATE(242)] = 4775,
-  [SMALL_STATE(243)] = 4782,
-  [SMALL_STATE(244)] = 4789,
-  [SMALL_STATE(245)] = 4796,
-  [SMALL_STATE(246)] = 4803,
-  [SMALL_STATE(247)] = 4810,
-  [SMALL_STATE(248)] = 4817,
-  [SMALL_STATE(249)] = 4824,
-  [SMALL_STATE(250)] = 4831,
+  [SMALL_STATE(3)] = 56,
+  [SMALL_STATE(4)] = 112,
+  [SMALL_STATE(5)] = 167,
+  [SMALL_STATE(6)] = 222,
+  [SMALL_STATE(7)] = 277,
+  [SMALL_STATE(8)] = 332,
+  [SMALL_STATE(9)] = 386,
+  [SMALL_STATE(10)] = 440,
+  [SMALL_STATE(11)] = 494,
+  [SMALL_STATE(12)] = 548,
+  [SMALL_STATE(13)] = 602,
+  [SMALL_STATE(14)] = 656,
+  [SMALL_STATE(15)] = 710,
+  [SMALL_STATE(16)] = 764,
+  [SMALL_STATE(17)] = 818,
+  [SMALL_STATE(18)] = 872,
+  [SMALL_STATE(19)] = 926,
+  [SMALL_STATE(20)] = 980,
+  [SMALL_STATE(21)] = 1034,
+  [SMALL_STATE(22)] = 1088,
+  [SMALL_STATE(23)] = 1142,
+  [SMALL_STATE(24)] = 1281,
+  [SMALL_STATE(25)] = 1420,
+  [SMALL_STATE(26)] = 1559,
+  [SMALL_STATE(27)] = 1698,
+  [SMALL_STATE(28)] = 1837,
+  [SMALL_STATE(29)] = 1976,
+  [SMALL_STATE(30)] = 2028,
+  [SMALL_STATE(31)] = 2080,
+  [SMALL_STATE(32)] = 2132,
+  [SMALL_STATE(33)] = 2259,
+  [SMALL_STATE(34)] = 2386,
+  [SMALL_STATE(35)] = 2513,
+  [SMALL_STATE(36)] = 2560,
+  [SMALL_STATE(37)] = 2607,
+  [SMALL_STATE(38)] = 2654,
+  [SMALL_STATE(39)] = 2707,
+  [SMALL_STATE(40)] = 2760,
+  [SMALL_STATE(41)] = 2812,
+  [SMALL_STATE(42)] = 2853,
+  [SMALL_STATE(43)] = 2894,
+  [SMALL_STATE(44)] = 2935,
+  [SMALL_STATE(45)] = 2976,
+  [SMALL_STATE(46)] = 3017,
+  [SMALL_STATE(47)] = 3058,
+  [SMALL_STATE(48)] = 3099,
+  [SMALL_STATE(49)] = 3140,
+  [SMALL_STATE(50)] = 3181,
+  [SMALL_STATE(51)] = 3222,
+  [SMALL_STATE(52)] = 3263,
+  [SMALL_STATE(53)] = 3304,
+  [SMALL_STATE(54)] = 3345,
+  [SMALL_STATE(55)] = 3386,
+  [SMALL_STATE(56)] = 3427,
+  [SMALL_STATE(57)] = 3468,
+  [SMALL_STATE(58)] = 3509,
+  [SMALL_STATE(59)] = 3550,
+  [SMALL_STATE(60)] = 3591,
+  [SMALL_STATE(61)] = 3632,
+  [SMALL_STATE(62)] = 3673,
+  [SMALL_STATE(63)] = 3714,
+  [SMALL_STATE(64)] = 3755,
+  [SMALL_STATE(65)] = 3796,
+  [SMALL_STATE(66)] = 3837,
+  [SMALL_STATE(67)] = 3878,
+  [SMALL_STATE(68)] = 3919,
+  [SMALL_STATE(69)] = 3960,
+  [SMALL_STATE(70)] = 4001,
+  [SMALL_STATE(71)] = 4042,
+  [SMALL_STATE(72)] = 4083,
+  [SMALL_STATE(73)] = 4124,
+  [SMALL_STATE(74)] = 4165,
+  [SMALL_STATE(75)] = 4206,
+  [SMALL_STATE(76)] = 4247,
+  [SMALL_STATE(77)] = 4288,
+  [SMALL_STATE(78)] = 4329,
+  [SMALL_STATE(79)] = 4370,
+  [SMALL_STATE(80)] = 4411,
+  [SMALL_STATE(81)] = 4452,
+  [SMALL_STATE(82)] = 4493,
+  [SMALL_STATE(83)] = 4534,
+  [SMALL_STATE(84)] = 4575,
+  [SMALL_STATE(85)] = 4616,
+  [SMALL_STATE(86)] = 4657,
+  [SMALL_STATE(87)] = 4698,
+  [SMALL_STATE(88)] = 4739,
+  [SMALL_STATE(89)] = 4780,
+  [SMALL_STATE(90)] = 4821,
+  [SMALL_STATE(91)] = 4861,
+  [SMALL_STATE(92)] = 4901,
+  [SMALL_STATE(93)] = 4941,
+  [SMALL_STATE(94)] = 4981,
+  [SMALL_STATE(95)] = 5021,
+  [SMALL_STATE(96)] = 5061,
+  [SMALL_STATE(97)] = 5101,
+  [SMALL_STATE(98)] = 5141,
+  [SMALL_STATE(99)] = 5181,
+  [SMALL_STATE(100)] = 5221,
+  [SMALL_STATE(101)] = 5261,
+  [SMALL_STATE(102)] = 5301,
+  [SMALL_STATE(103)] = 5341,
+  [SMALL_STATE(104)] = 5381,
+  [SMALL_STATE(105)] = 5421,
+  [SMALL_STATE(106)] = 5461,
+  [SMALL_STATE(107)] = 5501,
+  [SMALL_STATE(108)] = 5541,
+  [SMALL_STATE(109)] = 5581,
+  [SMALL_STATE(110)] = 5621,
+  [SMALL_STATE(111)] = 5661,
+  [SMALL_STATE(112)] = 5701,
+  [SMALL_STATE(113)] = 5741,
+  [SMALL_STATE(114)] = 5781,
+  [SMALL_STATE(115)] = 5821,
+  [SMALL_STATE(116)] = 5861,
+  [SMALL_STATE(117)] = 5943,
+  [SMALL_STATE(118)] = 6022,
+  [SMALL_STATE(119)] = 6101,
+  [SMALL_STATE(120)] = 6177,
+  [SMALL_STATE(121)] = 6220,
+  [SMALL_STATE(122)] = 6263,
+  [SMALL_STATE(123)] = 6306,
+  [SMALL_STATE(124)] = 6349,
+  [SMALL_STATE(125)] = 6389,
+  [SMALL_STATE(126)] = 6429,
+  [SMALL_STATE(127)] = 6469,
+  [SMALL_STATE(128)] = 6506,
+  [SMALL_STATE(129)] = 6540,
+  [SMALL_STATE(130)] = 6574,
+  [SMALL_STATE(131)] = 6608,
+  [SMALL_STATE(132)] = 6642,
+  [SMALL_STATE(133)] = 6673,
+  [SMALL_STATE(134)] = 6692,
+  [SMALL_STATE(135)] = 6717,
+  [SMALL_STATE(136)] = 6742,
+  [SMALL_STATE(137)] = 6767,
+  [SMALL_STATE(138)] = 6792,
+  [SMALL_STATE(139)] = 6817,
+  [SMALL_STATE(140)] = 6842,
+  [SMALL_STATE(141)] = 6862,
+  [SMALL_STATE(142)] = 6874,
+  [SMALL_STATE(143)] = 6886,
+  [SMALL_STATE(144)] = 6898,
+  [SMALL_STATE(145)] = 6910,
+  [SMALL_STATE(146)] = 6922,
+  [SMALL_STATE(147)] = 6934,
+  [SMALL_STATE(148)] = 6946,
+  [SMALL_STATE(149)] = 6958,
+  [SMALL_STATE(150)] = 6970,
+  [SMALL_STATE(151)] = 6982,
+  [SMALL_STATE(152)] = 6994,
+  [SMALL_STATE(153)] = 7006,
+  [SMALL_STATE(154)] = 7018,
+  [SMALL_STATE(155)] = 7037,
+  [SMALL_STATE(156)] = 7056,
+  [SMALL_STATE(157)] = 7067,
+  [SMALL_STATE(158)] = 7086,
+  [SMALL_STATE(159)] = 7105,
+  [SMALL_STATE(160)] = 7124,
+  [SMALL_STATE(161)] = 7143,
+  [SMALL_STATE(162)] = 7162,
+  [SMALL_STATE(163)] = 7181,
+  [SMALL_STATE(164)] = 7200,
+  [SMALL_STATE(165)] = 7211,
+  [SMALL_STATE(166)] = 7230,
+  [SMALL_STATE(167)] = 7249,
+  [SMALL_STATE(168)] = 7268,
+  [SMALL_STATE(169)] = 7287,
+  [SMALL_STATE(170)] = 7306,
+  [SMALL_STATE(171)] = 7325,
+  [SMALL_STATE(172)] = 7344,
+  [SMALL_STATE(173)] = 7363,
+  [SMALL_STATE(174)] = 7382,
+  [SMALL_STATE(175)] = 7401,
+  [SMALL_STATE(176)] = 7420,
+  [SMALL_STATE(177)] = 7439,
+  [SMALL_STATE(178)] = 7455,
+  [SMALL_STATE(179)] = 7471,
+  [SMALL_STATE(180)] = 7487,
+  [SMALL_STATE(181)] = 7503,
+  [SMALL_STATE(182)] = 7519,
+  [SMALL_STATE(183)] = 7535,
+  [SMALL_STATE(184)] = 7551,
+  [SMALL_STATE(185)] = 7567,
+  [SMALL_STATE(186)] = 7583,
+  [SMALL_STATE(187)] = 7599,
+  [SMALL_STATE(188)] = 7615,
+  [SMALL_STATE(189)] = 7631,
+  [SMALL_STATE(190)] = 7647,
+  [SMALL_STATE(191)] = 7663,
+  [SMALL_STATE(192)] = 7679,
+  [SMALL_STATE(193)] = 7695,
+  [SMALL_STATE(194)] = 7711,
+  [SMALL_STATE(195)] = 7727,
+  [SMALL_STATE(196)] = 7743,
+  [SMALL_STATE(197)] = 7759,
+  [SMALL_STATE(198)] = 7775,
+  [SMALL_STATE(199)] = 7784,
+  [SMALL_STATE(200)] = 7793,
+  [SMALL_STATE(201)] = 7802,
+  [SMALL_STATE(202)] = 7813,
+  [SMALL_STATE(203)] = 7824,
+  [SMALL_STATE(204)] = 7835,
+  [SMALL_STATE(205)] = 7848,
+  [SMALL_STATE(206)] = 7857,
+  [SMALL_STATE(207)] = 7866,
+  [SMALL_STATE(208)] = 7879,
+  [SMALL_STATE(209)] = 7888,
+  [SMALL_STATE(210)] = 7897,
+  [SMALL_STATE(211)] = 7908,
+  [SMALL_STATE(212)] = 7921,
+  [SMALL_STATE(213)] = 7930,
+  [SMALL_STATE(214)] = 7943,
+  [SMALL_STATE(215)] = 7954,
+  [SMALL_STATE(216)] = 7967,
+  [SMALL_STATE(217)] = 7980,
+  [SMALL_STATE(218)] = 7993,
+  [SMALL_STATE(219)] = 8006,
+  [SMALL_STATE(220)] = 8017,
+  [SMALL_STATE(221)] = 8028,
+  [SMALL_STATE(222)] = 8041,
+  [SMALL_STATE(223)] = 8054,
+  [SMALL_STATE(224)] = 8067,
+  [SMALL_STATE(225)] = 8080,
+  [SMALL_STATE(226)] = 8093,
+  [SMALL_STATE(227)] = 8104,
+  [SMALL_STATE(228)] = 8115,
+  [SMALL_STATE(229)] = 8126,
+  [SMALL_STATE(230)] = 8137,
+  [SMALL_STATE(231)] = 8150,
+  [SMALL_STATE(232)] = 8161,
+  [SMALL_STATE(233)] = 8174,
+  [SMALL_STATE(234)] = 8185,
+  [SMALL_STATE(235)] = 8194,
+  [SMALL_STATE(236)] = 8207,
+  [SMALL_STATE(237)] = 8220,
+  [SMALL_STATE(238)] = 8233,
+  [SMALL_STATE(239)] = 8246,
+  [SMALL_STATE(240)] = 8259,
+  [SMALL_STATE(241)] = 8272,
+  [SMALL_STATE(242)] = 8281,
+  [SMALL_STATE(243)] = 8294,
+  [SMALL_STATE(244)] = 8307,
+  [SMALL_STATE(245)] = 8320,
+  [SMALL_STATE(246)] = 8333,
+  [SMALL_STATE(247)] = 8346,
+  [SMALL_STATE(248)] = 8359,
+  [SMALL_STATE(249)] = 8372,
+  [SMALL_STATE(250)] = 8385,
+  [SMALL_STATE(251)] = 8398,
+  [SMALL_STATE(252)] = 8411,
+  [SMALL_STATE(253)] = 8424,
+  [SMALL_STATE(254)] = 8437,
+  [SMALL_STATE(255)] = 8450,
+  [SMALL_STATE(256)] = 8463,
+  [SMALL_STATE(257)] = 8476,
+  [SMALL_STATE(258)] = 8489,
+  [SMALL_STATE(259)] = 8502,
+  [SMALL_STATE(260)] = 8515,
+  [SMALL_STATE(261)] = 8528,
+  [SMALL_STATE(262)] = 8538,
+  [SMALL_STATE(263)] = 8548,
+  [SMALL_STATE(264)] = 8556,
+  [SMALL_STATE(265)] = 8564,
+  [SMALL_STATE(266)] = 8574,
+  [SMALL_STATE(267)] = 8584,
+  [SMALL_STATE(268)] = 8594,
+  [SMALL_STATE(269)] = 8604,
+  [SMALL_STATE(270)] = 8612,
+  [SMALL_STATE(271)] = 8622,
+  [SMALL_STATE(272)] = 8632,
+  [SMALL_STATE(273)] = 8642,
+  [SMALL_STATE(274)] = 8652,
+  [SMALL_STATE(275)] = 8660,
+  [SMALL_STATE(276)] = 8668,
+  [SMALL_STATE(277)] = 8676,
+  [SMALL_STATE(278)] = 8686,
+  [SMALL_STATE(279)] = 8696,
+  [SMALL_STATE(280)] = 8706,
+  [SMALL_STATE(281)] = 8716,
+  [SMALL_STATE(282)] = 8726,
+  [SMALL_STATE(283)] = 8736,
+  [SMALL_STATE(284)] = 8746,
+  [SMALL_STATE(285)] = 8756,
+  [SMALL_STATE(286)] = 8766,
+  [SMALL_STATE(287)] = 8776,
+  [SMALL_STATE(288)] = 8786,
+  [SMALL_STATE(289)] = 8796,
+  [SMALL_STATE(290)] = 8804,
+  [SMALL_STATE(291)] = 8812,
+  [SMALL_STATE(292)] = 8820,
+  [SMALL_STATE(293)] = 8828,
+  [SMALL_STATE(294)] = 8836,
+  [SMALL_STATE(295)] = 8846,
+  [SMALL_STATE(296)] = 8856,
+  [SMALL_STATE(297)] = 8864,
+  [SMALL_STATE(298)] = 8872,
+  [SMALL_STATE(299)] = 8880,
+  [SMALL_STATE(300)] = 8888,
+  [SMALL_STATE(301)] = 8896,
+  [SMALL_STATE(302)] = 8904,
+  [SMALL_STATE(303)] = 8912,
+  [SMALL_STATE(304)] = 8920,
+  [SMALL_STATE(305)] = 8928,
+  [SMALL_STATE(306)] = 8936,
+  [SMALL_STATE(307)] = 8944,
+  [SMALL_STATE(308)] = 8952,
+  [SMALL_STATE(309)] = 8960,
+  [SMALL_STATE(310)] = 8968,
+  [SMALL_STATE(311)] = 8976,
+  [SMALL_STATE(312)] = 8984,
+  [SMALL_STATE(313)] = 8992,
+  [SMALL_STATE(314)] = 9000,
+  [SMALL_STATE(315)] = 9008,
+  [SMALL_STATE(316)] = 9016,
+  [SMALL_STATE(317)] = 9024,
+  [SMALL_STATE(318)] = 9032,
+  [SMALL_STATE(319)] = 9040,
+  [SMALL_STATE(320)] = 9050,
+  [SMALL_STATE(321)] = 9057,
+  [SMALL_STATE(322)] = 9064,
+  [SMALL_STATE(323)] = 9071,
+  [SMALL_STATE(324)] = 9078,
+  [SMALL_STATE(325)] = 9085,
+  [SMALL_STATE(326)] = 9092,
+  [SMALL_STATE(327)] = 9099,
+  [SMALL_STATE(328)] = 9106,
+  [SMALL_STATE(329)] = 9113,
+  [SMALL_STATE(330)] = 9120,
+  [SMALL_STATE(331)] = 9127,
+  [SMALL_STATE(332)] = 9134,
+  [SMALL_STATE(333)] = 9141,
+  [SMALL_STATE(334)] = 9148,
+  [SMALL_STATE(335)] = 9155,
+  [SMALL_STATE(336)] = 9162,
+  [SMALL_STATE(337)] = 9169,
+  [SMALL_STATE(338)] = 9176,
+  [SMALL_STATE(339)] = 9183,
+  [SMALL_STATE(340)] = 9190,
+  [SMALL_STATE(341)] = 9197,
+  [SMALL_STATE(342)] = 9204,
+  [SMALL_STATE(343)] = 9211,
+  [SMALL_STATE(344)] = 9218,
+  [SMALL_STATE(345)] = 9225,
+  [SMALL_STATE(346)] = 9232,
+  [SMALL_STATE(347)] = 9239,
+  [SMALL_STATE(348)] = 9246,
+  [SMALL_STATE(349)] = 9253,
+  [SMALL_STATE(350)] = 9260,
+  [SMALL_STATE(351)] = 9267,
+  [SMALL_STATE(352)] = 9274,
+  [SMALL_STATE(353)] = 9281,
+  [SMALL_STATE(354)] = 9288,
+  [SMALL_STATE(355)] = 9295,
+  [SMALL_STATE(356)] = 9302,
+  [SMALL_STATE(357)] = 9309,
+  [SMALL_STATE(358)] = 9316,
+  [SMALL_STATE(359)] = 9323,
+  [SMALL_STATE(360)] = 9330,
+  [SMALL_STATE(361)] = 9337,
+  [SMALL_STATE(362)] = 9344,
+  [SMALL_STATE(363)] = 9351,
+  [SMALL_STATE(364)] = 9358,
+  [SMALL_STATE(365)] = 9365,
+  [SMALL_STATE(366)] = 9372,
+  [SMALL_STATE(367)] = 9379,
+  [SMALL_STATE(368)] = 9386,
+  [SMALL_STATE(369)] = 9393,
+  [SMALL_STATE(370)] = 9400,
+  [SMALL_STATE(371)] = 9407,
+  [SMALL_STATE(372)] = 9414,
+  [SMALL_STATE(373)] = 9421,
+  [SMALL_STATE(374)] = 9428,
+  [SMALL_STATE(375)] = 9435,
+  [SMALL_STATE(376)] = 9442,
+  [SMALL_STATE(377)] = 9449,
+  [SMALL_STATE(378)] = 9456,
+  [SMALL_STATE(379)] = 9463,
+  [SMALL_STATE(380)] = 9470,
+  [SMALL_STATE(381)] = 9477,
+  [SMALL_STATE(382)] = 9484,
+  [SMALL_STATE(383)] = 9491,
+  [SMALL_STATE(384)] = 9498,
+  [SMALL_STATE(385)] = 9505,
+  [SMALL_STATE(386)] = 9512,
+  [SMALL_STATE(387)] = 9519,
+  [SMALL_STATE(388)] = 9526,
+  [SMALL_STATE(389)] = 9533,
+  [SMALL_STATE(390)] = 9540,
+  [SMALL_STATE(391)] = 9547,
+  [SMALL_STATE(392)] = 9554,
+  [SMALL_STATE(393)] = 9561,
+  [SMALL_STATE(394)] = 9568,
+  [SMALL_STATE(395)] = 9575,
+  [SMALL_STATE(396)] = 9582,
+  [SMALL_STATE(397)] = 9589,
+  [SMALL_STATE(398)] = 9596,
+  [SMALL_STATE(399)] = 9603,
+  [SMALL_STATE(400)] = 9610,
+  [SMALL_STATE(401)] = 9617,
+  [SMALL_STATE(402)] = 9624,
+  [SMALL_STATE(403)] = 9631,
+  [SMALL_STATE(404)] = 9638,
+  [SMALL_STATE(405)] = 9645,
+  [SMALL_STATE(406)] = 9652,
+  [SMALL_STATE(407)] = 9659,
+  [SMALL_STATE(408)] = 9666,
+  [SMALL_STATE(409)] = 9673,
+  [SMALL_STATE(410)] = 9680,
+  [SMALL_STATE(411)] = 9687,
+  [SMALL_STATE(412)] = 9694,
+  [SMALL_STATE(413)] = 9701,
+  [SMALL_STATE(414)] = 9708,
+  [SMALL_STATE(415)] = 9715,
+  [SMALL_STATE(416)] = 9722,
+  [SMALL_STATE(417)] = 9729,
+  [SMALL_STATE(418)] = 9736,
+  [SMALL_STATE(419)] = 9743,
+  [SMALL_STATE(420)] = 9750,
+  [SMALL_STATE(421)] = 9757,
+  [SMALL_STATE(422)] = 9764,
+  [SMALL_STATE(423)] = 9771,
+  [SMALL_STATE(424)] = 9778,
+  [SMALL_STATE(425)] = 9785,
+  [SMALL_STATE(426)] = 9792,
+  [SMALL_STATE(427)] = 9799,
+  [SMALL_STATE(428)] = 9806,
+  [SMALL_STATE(429)] = 9813,
+  [SMALL_STATE(430)] = 9820,
+  [SMALL_STATE(431)] = 9827,
+  [SMALL_STATE(432)] = 9834,
+  [SMALL_STATE(433)] = 9841,
+  [SMALL_STATE(434)] = 9848,
+  [SMALL_STATE(435)] = 9855,
+  [SMALL_STATE(436)] = 9862,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
-  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [3] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0, 0, 0),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [15] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
   [17] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
-  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
-  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
-  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
-  [27] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration, 2, 0, 0),
-  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration, 2, 0, 0),
-  [31] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 3, 0, 0),
-  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 3, 0, 0),
-  [35] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 5, 0, 0),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 5, 0, 0),
-  [39] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 2, 0, 0),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 2, 0, 0),
-  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration_unit, 1, 0, 0),
-  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration_unit, 1, 0, 0),
-  [47] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 4, 0, 0),
-  [49] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 4, 0, 0),
-  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(182),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
-  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(244),
-  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(210),
-  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(211),
-  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(193),
-  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(216),
-  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(220),
-  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(250),
-  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
-  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(243),
-  [73] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [75] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
-  [77] = {.entry = {.count = 1, .reusable = false}}, SHIFT(91),
-  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(92),
-  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [87] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(182),
-  [90] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0),
-  [92] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(244),
-  [95] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(210),
-  [98] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(211),
-  [101] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(193),
-  [104] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(216),
-  [107] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(220),
-  [110] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(250),
-  [113] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(182),
-  [116] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0),
-  [118] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(244),
-  [121] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(243),
-  [124] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(89),
-  [127] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(88),
-  [130] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(91),
-  [133] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(92),
-  [136] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(93),
-  [139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_property, 3, 0, 5),
-  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property, 3, 0, 5),
-  [143] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_code_block, 2, 0, 0),
-  [145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_block, 2, 0, 0),
-  [147] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_code_block, 3, 0, 0),
-  [149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_block, 3, 0, 0),
-  [151] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [153] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [155] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [157] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
-  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(195),
-  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [165] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(166),
-  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0),
-  [170] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(244),
-  [173] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(165),
-  [176] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(207),
-  [179] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(195),
-  [182] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(195),
-  [185] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(174),
-  [188] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(244),
-  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
-  [193] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_language_implementation, 3, 0, 8),
-  [195] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_language_implementation, 3, 0, 8),
-  [197] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
-  [199] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
-  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
-  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_body, 2, 0, 0),
-  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_body, 2, 0, 0),
-  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup, 2, 0, 0),
-  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup, 2, 0, 0),
-  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_body, 3, 0, 0),
-  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_body, 3, 0, 0),
-  [217] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_file_ref, 4, 0, 0),
-  [219] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_ref, 4, 0, 0),
-  [221] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_skip_hook, 2, 0, 0),
-  [223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_skip_hook, 2, 0, 0),
-  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_validate_hook, 2, 0, 0),
-  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_validate_hook, 2, 0, 0),
-  [229] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_before_hook, 2, 0, 0),
-  [231] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_before_hook, 2, 0, 0),
-  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_hook, 2, 0, 0),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_hook, 2, 0, 0),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_hook, 2, 0, 0),
-  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_hook, 2, 0, 0),
-  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tags_property, 3, 0, 0),
-  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tags_property, 3, 0, 0),
-  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hex_property, 3, 0, 0),
-  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hex_property, 3, 0, 0),
-  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data_property, 3, 0, 0),
-  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data_property, 3, 0, 0),
-  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_encoding_property, 3, 0, 0),
-  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_encoding_property, 3, 0, 0),
-  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_format_property, 3, 0, 0),
-  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_format_property, 3, 0, 0),
-  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_selector_property, 3, 0, 0),
-  [263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_property, 3, 0, 0),
-  [265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_shape_property, 3, 0, 0),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shape_property, 3, 0, 0),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_flat, 3, 0, 8),
-  [271] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_flat, 3, 0, 8),
-  [273] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_grouped, 3, 0, 0),
-  [275] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_grouped, 3, 0, 0),
-  [277] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_grouped, 4, 0, 0),
-  [279] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_grouped, 4, 0, 0),
-  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_body, 3, 0, 0),
-  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture_body, 3, 0, 0),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_block, 2, 0, 0),
-  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_block, 2, 0, 0),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_block, 3, 0, 4),
-  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_block, 3, 0, 4),
-  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture, 3, 0, 1),
-  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture, 3, 0, 1),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark, 3, 0, 1),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark, 3, 0, 1),
-  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_body, 2, 0, 0),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_body, 2, 0, 0),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_body, 2, 0, 0),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_body, 2, 0, 0),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_body, 2, 0, 0),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture_body, 2, 0, 0),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture, 4, 0, 1),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture, 4, 0, 1),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark_body, 2, 0, 0),
-  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark_body, 2, 0, 0),
-  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_body, 3, 0, 0),
-  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_body, 3, 0, 0),
-  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_body, 3, 0, 0),
-  [327] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_body, 3, 0, 0),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark_body, 3, 0, 0),
-  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark_body, 3, 0, 0),
-  [333] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [335] = {.entry = {.count = 1, .reusable = true}}, SHIFT(224),
-  [337] = {.entry = {.count = 1, .reusable = false}}, SHIFT(224),
-  [339] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 2, 0, 0),
-  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 3, 0, 0),
-  [343] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(95),
-  [347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [349] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
-  [351] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [353] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
-  [355] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [357] = {.entry = {.count = 1, .reusable = false}}, SHIFT(25),
-  [359] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
-  [361] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
-  [363] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
-  [365] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0),
-  [369] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(176),
-  [372] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(109),
-  [375] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(200),
-  [378] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(177),
-  [381] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(181),
-  [384] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [386] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [390] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
-  [392] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [394] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
-  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [398] = {.entry = {.count = 1, .reusable = false}}, SHIFT(191),
-  [400] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [404] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
-  [406] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
-  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [410] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
-  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [414] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
-  [417] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0),
-  [419] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(206),
-  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [424] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(246),
-  [427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_section, 2, 0, 0),
-  [429] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_helpers_section, 2, 0, 0),
-  [431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_paren_code_block, 2, 0, 0),
-  [433] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_init_section, 3, 0, 0),
-  [435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_init_section, 2, 0, 0),
-  [437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_paren_code_block, 3, 0, 0),
-  [439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declare_section, 2, 0, 0),
-  [441] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 4, 0, 2),
-  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
-  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
-  [447] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
-  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [453] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
-  [455] = {.entry = {.count = 1, .reusable = false}}, SHIFT(108),
-  [457] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
-  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
-  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
-  [463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
-  [465] = {.entry = {.count = 1, .reusable = false}}, SHIFT(106),
-  [467] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_hook_grouped_repeat1, 2, 0, 0), SHIFT_REPEAT(182),
-  [470] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_hook_grouped_repeat1, 2, 0, 0),
-  [472] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0),
-  [474] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0), SHIFT_REPEAT(215),
-  [477] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0), SHIFT_REPEAT(239),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
-  [484] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_single_string_content, 1, 0, 0),
-  [486] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [490] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_content, 1, 0, 0),
-  [492] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(51),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
-  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(173),
-  [520] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
-  [522] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_content_repeat1, 2, 0, 0),
-  [524] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(118),
-  [527] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
-  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_after_body_repeat1, 2, 0, 0),
-  [531] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_after_body_repeat1, 2, 0, 0), SHIFT_REPEAT(236),
-  [534] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_single_string_content_repeat1, 2, 0, 0),
-  [536] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_single_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(121),
-  [539] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [541] = {.entry = {.count = 1, .reusable = false}}, SHIFT(43),
-  [543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_body, 3, 0, 0),
-  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_body, 2, 0, 0),
-  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 4, 0, 0),
-  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 0),
-  [551] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 2, 0, 0),
-  [553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [559] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_anvil_call, 6, 0, 0),
-  [561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_call, 6, 0, 0),
-  [563] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 6, 0, 0),
-  [565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 6, 0, 0),
-  [567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [569] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
-  [571] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 3, 0, 0),
-  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 3, 0, 0),
-  [575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [579] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_anvil_call, 5, 0, 0),
-  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_call, 5, 0, 0),
-  [583] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 5, 0, 0),
-  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 5, 0, 0),
-  [587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 8, 0, 6),
-  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 9, 0, 7),
-  [593] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
-  [595] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
-  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 3, 0, 0),
-  [599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0),
-  [601] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0), SHIFT_REPEAT(170),
-  [604] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 1, 0, 0),
-  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [610] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_params_repeat1, 2, 0, 0),
-  [612] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fixture_params_repeat1, 2, 0, 0), SHIFT_REPEAT(186),
-  [615] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_array_repeat1, 2, 0, 0), SHIFT_REPEAT(154),
-  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_array_repeat1, 2, 0, 0),
-  [620] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 1, 0, 0),
-  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
-  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
-  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_chart_params_repeat1, 2, 0, 0),
-  [634] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chart_params_repeat1, 2, 0, 0), SHIFT_REPEAT(62),
-  [637] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 3, 0, 1),
-  [639] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 4, 0, 3),
-  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
-  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument, 3, 0, 5),
-  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
-  [663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_param, 3, 0, 9),
-  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_language_tag, 1, 0, 0),
-  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_directive, 5, 0, 10),
-  [671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_type, 1, 0, 0),
-  [673] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_directive, 6, 0, 10),
-  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param, 3, 0, 5),
-  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [685] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 3, 0, 0),
-  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
-  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_function_name, 1, 0, 0),
-  [699] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 4, 0, 0),
-  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(59),
-  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
-  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
-  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
-  [721] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
-  [723] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [725] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [727] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 5, 0, 0),
-  [731] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [733] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
-  [737] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_args, 3, 0, 0),
-  [739] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
-  [741] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [743] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [745] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
-  [749] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_run_mode, 1, 0, 0),
-  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 2, 0, 0),
-  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
-  [761] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
-  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [779] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
-  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_boolean, 1, 0, 0),
+  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_boolean, 1, 0, 0),
+  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [27] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 2, 0, 0),
+  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 2, 0, 0),
+  [31] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [35] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string, 3, 0, 0),
+  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string, 3, 0, 0),
+  [39] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [47] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [49] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [51] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [55] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [57] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [59] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [63] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration, 2, 0, 0),
+  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration, 2, 0, 0),
+  [67] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [71] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [73] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_duration_unit, 1, 0, 0),
+  [79] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 2, 0, 0),
+  [81] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 2, 0, 0),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 3, 0, 0),
+  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 3, 0, 0),
+  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 4, 0, 0),
+  [89] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 4, 0, 0),
+  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 4, 0, 0),
+  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 4, 0, 0),
+  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_string_array, 5, 0, 0),
+  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_array, 5, 0, 0),
+  [99] = {.entry = {.count = 1, .reusable = false}}, SHIFT(269),
+  [101] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [103] = {.entry = {.count = 1, .reusable = false}}, SHIFT(353),
+  [105] = {.entry = {.count = 1, .reusable = false}}, SHIFT(376),
+  [107] = {.entry = {.count = 1, .reusable = false}}, SHIFT(377),
+  [109] = {.entry = {.count = 1, .reusable = false}}, SHIFT(378),
+  [111] = {.entry = {.count = 1, .reusable = false}}, SHIFT(379),
+  [113] = {.entry = {.count = 1, .reusable = false}}, SHIFT(380),
+  [115] = {.entry = {.count = 1, .reusable = false}}, SHIFT(381),
+  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(337),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(343),
+  [121] = {.entry = {.count = 1, .reusable = false}}, SHIFT(338),
+  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(339),
+  [125] = {.entry = {.count = 1, .reusable = false}}, SHIFT(340),
+  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(341),
+  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(342),
+  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(344),
+  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(345),
+  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(346),
+  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
+  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(348),
+  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(349),
+  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(350),
+  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(351),
+  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(352),
+  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
+  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(355),
+  [153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(357),
+  [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(358),
+  [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(359),
+  [161] = {.entry = {.count = 1, .reusable = false}}, SHIFT(360),
+  [163] = {.entry = {.count = 1, .reusable = false}}, SHIFT(361),
+  [165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [167] = {.entry = {.count = 1, .reusable = false}}, SHIFT(385),
+  [169] = {.entry = {.count = 1, .reusable = false}}, SHIFT(167),
+  [171] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(170),
+  [177] = {.entry = {.count = 1, .reusable = false}}, SHIFT(171),
+  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [183] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(269),
+  [186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0),
+  [188] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(353),
+  [191] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(376),
+  [194] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(377),
+  [197] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(378),
+  [200] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(379),
+  [203] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(380),
+  [206] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(381),
+  [209] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
+  [212] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [215] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(338),
+  [218] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(339),
+  [221] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(340),
+  [224] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(341),
+  [227] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(342),
+  [230] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(344),
+  [233] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(345),
+  [236] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(346),
+  [239] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [242] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [245] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(349),
+  [248] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [251] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(351),
+  [254] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(352),
+  [257] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(354),
+  [260] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(355),
+  [263] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(356),
+  [266] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(357),
+  [269] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(358),
+  [272] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(359),
+  [275] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(360),
+  [278] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [281] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(269),
+  [284] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0),
+  [286] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(353),
+  [289] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(385),
+  [292] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(167),
+  [295] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(168),
+  [298] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(169),
+  [301] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(170),
+  [304] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(171),
+  [307] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
+  [310] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [313] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(338),
+  [316] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(339),
+  [319] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(340),
+  [322] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(341),
+  [325] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(342),
+  [328] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(344),
+  [331] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(345),
+  [334] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(346),
+  [337] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [340] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [343] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(349),
+  [346] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [349] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(351),
+  [352] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(352),
+  [355] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(354),
+  [358] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(355),
+  [361] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(356),
+  [364] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(357),
+  [367] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(358),
+  [370] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(359),
+  [373] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(360),
+  [376] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [379] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_property, 3, 0, 2),
+  [381] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property, 3, 0, 2),
+  [383] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_code_block, 2, 0, 0),
+  [385] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_block, 2, 0, 0),
+  [387] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_code_block, 3, 0, 0),
+  [389] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_code_block, 3, 0, 0),
+  [391] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [393] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [401] = {.entry = {.count = 1, .reusable = false}}, SHIFT(334),
+  [403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
+  [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [409] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [411] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
+  [413] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
+  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
+  [421] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
+  [427] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [429] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [431] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
+  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
+  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [439] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [445] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [447] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [449] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
+  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [453] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(265),
+  [456] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0),
+  [458] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(353),
+  [461] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(266),
+  [464] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(333),
+  [467] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(334),
+  [470] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(335),
+  [473] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(267),
+  [476] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(337),
+  [479] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(343),
+  [482] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(338),
+  [485] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(339),
+  [488] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(340),
+  [491] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(341),
+  [494] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(342),
+  [497] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(344),
+  [500] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(345),
+  [503] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(346),
+  [506] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(347),
+  [509] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(348),
+  [512] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(349),
+  [515] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(350),
+  [518] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(351),
+  [521] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(352),
+  [524] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(354),
+  [527] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(355),
+  [530] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(356),
+  [533] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(357),
+  [536] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(358),
+  [539] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(359),
+  [542] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(360),
+  [545] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 2, 0, 0), SHIFT_REPEAT(361),
+  [548] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_language_implementation, 3, 0, 12),
+  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_language_implementation, 3, 0, 12),
+  [552] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__code_or_inline, 1, 0, 0),
+  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__code_or_inline, 1, 0, 0),
+  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__code_or_inline, 1, 0, 0),
+  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__code_or_inline, 1, 0, 0),
+  [560] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__value, 1, 0, 0),
+  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__value, 1, 0, 0),
+  [564] = {.entry = {.count = 1, .reusable = false}}, SHIFT(15),
+  [566] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [568] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [572] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [576] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [578] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [580] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [584] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [588] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [592] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [594] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [596] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [600] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [602] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__fixture_item, 1, 0, 0),
+  [604] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_fixture_body_repeat1, 1, 0, 0),
+  [606] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_body_repeat1, 1, 0, 0),
+  [608] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [610] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [612] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [614] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [616] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [620] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [622] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [624] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [626] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [628] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [630] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [632] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [634] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [636] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [638] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__benchmark_item, 1, 0, 0),
+  [640] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_benchmark_body_repeat1, 1, 0, 0),
+  [642] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_benchmark_body_repeat1, 1, 0, 0),
+  [644] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_skip_hook, 2, 0, 0),
+  [646] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_skip_hook, 2, 0, 0),
+  [648] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_skip_hook, 2, 0, 0),
+  [650] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_skip_hook, 2, 0, 0),
+  [652] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_validate_hook, 2, 0, 0),
+  [654] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_validate_hook, 2, 0, 0),
+  [656] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_validate_hook, 2, 0, 0),
+  [658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_validate_hook, 2, 0, 0),
+  [660] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_before_hook, 2, 0, 0),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_before_hook, 2, 0, 0),
+  [664] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_before_hook, 2, 0, 0),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_before_hook, 2, 0, 0),
+  [668] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_hook, 2, 0, 0),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_hook, 2, 0, 0),
+  [672] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_hook, 2, 0, 0),
+  [674] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_hook, 2, 0, 0),
+  [676] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_hook, 2, 0, 0),
+  [678] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_hook, 2, 0, 0),
+  [680] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_each_hook, 2, 0, 0),
+  [682] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_each_hook, 2, 0, 0),
+  [684] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hex_property, 3, 0, 0),
+  [686] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hex_property, 3, 0, 0),
+  [688] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hex_property, 3, 0, 0),
+  [690] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hex_property, 3, 0, 0),
+  [692] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data_property, 3, 0, 0),
+  [694] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data_property, 3, 0, 0),
+  [696] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data_property, 3, 0, 0),
+  [698] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data_property, 3, 0, 0),
+  [700] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_data_property, 3, 0, 0),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_data_property, 3, 0, 0),
+  [704] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_encoding_property, 3, 0, 0),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_encoding_property, 3, 0, 0),
+  [708] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_encoding_property, 3, 0, 0),
+  [710] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_encoding_property, 3, 0, 0),
+  [712] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_format_property, 3, 0, 0),
+  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_format_property, 3, 0, 0),
+  [716] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_format_property, 3, 0, 0),
+  [718] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_format_property, 3, 0, 0),
+  [720] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_selector_property, 3, 0, 0),
+  [722] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_selector_property, 3, 0, 0),
+  [724] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_shape_property, 3, 0, 0),
+  [726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shape_property, 3, 0, 0),
+  [728] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tags_property, 3, 0, 0),
+  [730] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tags_property, 3, 0, 0),
+  [732] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_flat, 3, 0, 12),
+  [734] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_flat, 3, 0, 12),
+  [736] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_grouped, 3, 0, 0),
+  [738] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_grouped, 3, 0, 0),
+  [740] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hook_grouped, 4, 0, 0),
+  [742] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hook_grouped, 4, 0, 0),
+  [744] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_file_ref, 4, 0, 0),
+  [746] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_file_ref, 4, 0, 0),
+  [748] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generator_call, 4, 0, 6),
+  [750] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_call, 4, 0, 6),
+  [752] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generator_call, 5, 0, 6),
+  [754] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_call, 5, 0, 6),
+  [756] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generator_call, 6, 0, 6),
+  [758] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_call, 6, 0, 6),
+  [760] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generator_call, 6, 0, 6),
+  [762] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_call, 6, 0, 6),
+  [764] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_generator_call, 7, 0, 6),
+  [766] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_call, 7, 0, 6),
+  [768] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [770] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [772] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [774] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [776] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [778] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [780] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [782] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [784] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [786] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [788] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [790] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__suite_item, 1, 0, 0),
+  [792] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_suite_body_repeat1, 1, 0, 0),
+  [794] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_suite_body_repeat1, 1, 0, 0),
+  [796] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup, 2, 0, 0),
+  [798] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup, 2, 0, 0),
+  [800] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_block, 2, 0, 0),
+  [802] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_block, 2, 0, 0),
+  [804] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_body, 2, 0, 0),
+  [806] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_body, 2, 0, 0),
+  [808] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_block, 3, 0, 7),
+  [810] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_block, 3, 0, 7),
+  [812] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture, 3, 0, 6),
+  [814] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture, 3, 0, 6),
+  [816] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark, 3, 0, 6),
+  [818] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark, 3, 0, 6),
+  [820] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark, 3, 0, 6),
+  [822] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark, 3, 0, 6),
+  [824] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_body, 2, 0, 0),
+  [826] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_body, 2, 0, 0),
+  [828] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_body, 3, 0, 0),
+  [830] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_body, 3, 0, 0),
+  [832] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_body, 2, 0, 0),
+  [834] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_body, 2, 0, 0),
+  [836] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture, 4, 0, 6),
+  [838] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture, 4, 0, 6),
+  [840] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_body, 2, 0, 0),
+  [842] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture_body, 2, 0, 0),
+  [844] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark, 4, 0, 10),
+  [846] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark, 4, 0, 10),
+  [848] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark_body, 2, 0, 0),
+  [850] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark_body, 2, 0, 0),
+  [852] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark, 4, 0, 10),
+  [854] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark, 4, 0, 10),
+  [856] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_after_body, 3, 0, 0),
+  [858] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_after_body, 3, 0, 0),
+  [860] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_setup_body, 3, 0, 0),
+  [862] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_setup_body, 3, 0, 0),
+  [864] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_body, 3, 0, 0),
+  [866] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_fixture_body, 3, 0, 0),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_benchmark_body, 3, 0, 0),
+  [870] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_benchmark_body, 3, 0, 0),
+  [872] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
+  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
+  [876] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
+  [878] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [880] = {.entry = {.count = 1, .reusable = true}}, SHIFT(416),
+  [882] = {.entry = {.count = 1, .reusable = true}}, SHIFT(417),
+  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
+  [886] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
+  [888] = {.entry = {.count = 1, .reusable = true}}, SHIFT(420),
+  [890] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [892] = {.entry = {.count = 1, .reusable = true}}, SHIFT(422),
+  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [896] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [900] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
+  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
+  [904] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
+  [910] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
+  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [914] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [916] = {.entry = {.count = 1, .reusable = true}}, SHIFT(434),
+  [918] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 2, 0, 0),
+  [920] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 3, 0, 0),
+  [922] = {.entry = {.count = 1, .reusable = false}}, SHIFT(12),
+  [924] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [930] = {.entry = {.count = 1, .reusable = false}}, SHIFT(40),
+  [932] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [934] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [936] = {.entry = {.count = 1, .reusable = false}}, SHIFT(3),
+  [938] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
+  [940] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [942] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [946] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
+  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [958] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0),
+  [960] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(278),
+  [963] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(185),
+  [966] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(373),
+  [969] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(279),
+  [972] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(280),
+  [975] = {.entry = {.count = 1, .reusable = false}}, SHIFT(311),
+  [977] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
+  [979] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [981] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [983] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [985] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
+  [987] = {.entry = {.count = 1, .reusable = false}}, SHIFT(298),
+  [989] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [991] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [993] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [995] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [997] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(320),
+  [1000] = {.entry = {.count = 1, .reusable = false}}, SHIFT(262),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [1004] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [1008] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(262),
+  [1011] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0),
+  [1013] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_global_setup_body_repeat1, 2, 0, 0), SHIFT_REPEAT(327),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
+  [1018] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
+  [1020] = {.entry = {.count = 1, .reusable = false}}, SHIFT(405),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__setup_section, 1, 0, 0),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__setup_section, 1, 0, 0),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__setup_section, 1, 0, 0),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__setup_section, 1, 0, 0),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_setup_body_repeat1, 1, 0, 0),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_section, 2, 0, 0),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import_section, 2, 0, 0),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_declare_section, 2, 0, 0),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_init_section, 2, 0, 0),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_helpers_section, 2, 0, 0),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_paren_code_block, 2, 0, 0),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_init_section, 3, 0, 0),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_paren_code_block, 3, 0, 0),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1, 0, 0),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1, 0, 0),
+  [1058] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [1062] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0),
+  [1066] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0), SHIFT_REPEAT(321),
+  [1069] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2, 0, 0), SHIFT_REPEAT(322),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(263),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [1078] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
+  [1080] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_use_statement, 4, 0, 1),
+  [1082] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
+  [1084] = {.entry = {.count = 1, .reusable = false}}, SHIFT(226),
+  [1086] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [1088] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
+  [1090] = {.entry = {.count = 1, .reusable = false}}, SHIFT(228),
+  [1092] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [1094] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [1096] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
+  [1098] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
+  [1100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [1102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [1104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [1108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [1110] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_hook_grouped_repeat1, 2, 0, 0), SHIFT_REPEAT(269),
+  [1113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_hook_grouped_repeat1, 2, 0, 0),
+  [1115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [1117] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [1119] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [1121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
+  [1123] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [1125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [1127] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [1129] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
+  [1131] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [1133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [1135] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [1139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [1141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_after_body_repeat1, 2, 0, 0),
+  [1143] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_after_body_repeat1, 2, 0, 0), SHIFT_REPEAT(369),
+  [1146] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_string_content, 1, 0, 0),
+  [1148] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_single_string_content, 1, 0, 0),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, SHIFT(74),
+  [1152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [1154] = {.entry = {.count = 1, .reusable = false}}, SHIFT(283),
+  [1156] = {.entry = {.count = 1, .reusable = false}}, SHIFT(37),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_content_repeat1, 2, 0, 0),
+  [1160] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(226),
+  [1163] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(227),
+  [1166] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_single_string_content_repeat1, 2, 0, 0),
+  [1168] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_single_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(228),
+  [1171] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_single_string_content_repeat1, 2, 0, 0), SHIFT_REPEAT(229),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
+  [1176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [1178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [1180] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 1, 0, 0),
+  [1182] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
+  [1186] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
+  [1188] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_global_setup_statement, 1, 0, 0),
+  [1190] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_global_setup_body_repeat1, 1, 0, 0),
+  [1192] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_global_setup_body_repeat1, 1, 0, 0),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 3, 0, 6),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 4, 0, 4),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_body, 2, 0, 0),
+  [1204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 3, 0, 0),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 3, 0, 0),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 1, 0, 0),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_body, 3, 0, 0),
+  [1214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [1216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [1218] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 4, 0, 0),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 4, 0, 0),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 2, 0, 0),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 2, 0, 0),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [1230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(374),
+  [1232] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_anvil_call, 5, 0, 0),
+  [1234] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_call, 5, 0, 0),
+  [1236] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 5, 0, 0),
+  [1238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 5, 0, 0),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument_list, 3, 0, 0),
+  [1242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0),
+  [1244] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0), SHIFT_REPEAT(281),
+  [1247] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
+  [1249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [1251] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [1253] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [1255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_content_repeat1, 1, 0, 0),
+  [1257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_content_repeat1, 1, 0, 0),
+  [1259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_content_repeat1, 1, 0, 0),
+  [1261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_string_content_repeat1, 1, 0, 0),
+  [1263] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_single_string_content_repeat1, 1, 0, 0),
+  [1265] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_single_string_content_repeat1, 1, 0, 0),
+  [1267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_single_string_content_repeat1, 1, 0, 0),
+  [1269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_single_string_content_repeat1, 1, 0, 0),
+  [1271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [1273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [1275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_anvil_call, 6, 0, 0),
+  [1277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_call, 6, 0, 0),
+  [1279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_call, 6, 0, 0),
+  [1281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_call, 6, 0, 0),
+  [1283] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 8, 0, 5),
+  [1285] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
+  [1287] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1289] = {.entry = {.count = 1, .reusable = true}}, SHIFT(403),
+  [1291] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [1293] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
+  [1295] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [1297] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
+  [1299] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1301] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
+  [1303] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite, 9, 0, 3),
+  [1305] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
+  [1307] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_params_repeat1, 2, 0, 0),
+  [1309] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_fixture_params_repeat1, 2, 0, 0), SHIFT_REPEAT(294),
+  [1312] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_bench_params_repeat1, 2, 0, 0),
+  [1316] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_bench_params_repeat1, 2, 0, 0), SHIFT_REPEAT(295),
+  [1319] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_string_array_repeat1, 2, 0, 0), SHIFT_REPEAT(250),
+  [1322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_array_repeat1, 2, 0, 0),
+  [1324] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [1326] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [1328] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 1, 0, 0),
+  [1330] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [1332] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
+  [1334] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [1336] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [1338] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1340] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_params, 2, 0, 0),
+  [1342] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
+  [1344] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [1346] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [1348] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_param_value_array_repeat1, 2, 0, 0), SHIFT_REPEAT(132),
+  [1351] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_param_value_array_repeat1, 2, 0, 0),
+  [1353] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_chart_params_repeat1, 2, 0, 0),
+  [1355] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_chart_params_repeat1, 2, 0, 0), SHIFT_REPEAT(119),
+  [1358] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [1360] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [1362] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
+  [1364] = {.entry = {.count = 1, .reusable = true}}, SHIFT(89),
+  [1366] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_generator_call_repeat1, 2, 0, 0),
+  [1368] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_generator_call_repeat1, 2, 0, 0), SHIFT_REPEAT(319),
+  [1371] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [1373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
+  [1375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [1377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_type, 1, 0, 0),
+  [1379] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_suite_type, 1, 0, 0),
+  [1381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [1383] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [1385] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
+  [1387] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_language_tag, 1, 0, 0),
+  [1389] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_after_body_repeat1, 1, 0, 0),
+  [1391] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_argument_list_repeat1, 2, 0, 0),
+  [1393] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_argument, 3, 0, 2),
+  [1395] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
+  [1397] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [1399] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [1401] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [1403] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [1405] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_fixture_params_repeat1, 2, 0, 0),
+  [1407] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_param, 3, 0, 8),
+  [1409] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_bench_params_repeat1, 2, 0, 0),
+  [1411] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bench_param, 3, 0, 11),
+  [1413] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_string_array_repeat1, 2, 0, 0),
+  [1415] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param_value_array, 2, 0, 0),
+  [1417] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__param_value, 1, 0, 0),
+  [1419] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__param_value, 1, 0, 0),
+  [1421] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__param_value, 1, 0, 0),
+  [1423] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__param_value, 1, 0, 0),
+  [1425] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_hook_grouped_repeat1, 1, 0, 0),
+  [1427] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_directive, 5, 0, 13),
+  [1429] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param_value_array, 3, 0, 0),
+  [1431] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_directive, 6, 0, 13),
+  [1433] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param_value_array, 4, 0, 0),
+  [1435] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param_value_array, 4, 0, 0),
+  [1437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_param_value_array_repeat1, 2, 0, 0),
+  [1439] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_chart_params_repeat1, 2, 0, 0),
+  [1441] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param, 3, 0, 2),
+  [1443] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__chart_value, 1, 0, 0),
+  [1445] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__chart_value, 1, 0, 0),
+  [1447] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__chart_value, 1, 0, 0),
+  [1449] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__chart_value, 1, 0, 0),
+  [1451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__chart_value, 1, 0, 0),
+  [1453] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_param_value_array, 5, 0, 0),
+  [1455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_generator_call_repeat1, 2, 0, 0),
+  [1457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_arg, 3, 0, 9),
+  [1459] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_generator_arg, 3, 0, 9),
+  [1461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [1463] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [1465] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [1467] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1469] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [1471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [1473] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [1475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1477] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [1479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [1481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [1483] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_run_mode, 1, 0, 0),
+  [1485] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_run_mode, 1, 0, 0),
+  [1487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [1489] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [1491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [1493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
+  [1495] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1499] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1503] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1511] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1515] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1523] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1527] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1531] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1535] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1539] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1543] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_property_name, 1, 0, 0),
+  [1545] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [1547] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [1549] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [1551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [1553] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [1555] = {.entry = {.count = 1, .reusable = true}}, SHIFT(217),
+  [1557] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
+  [1559] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [1561] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
+  [1563] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
+  [1565] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
+  [1567] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [1569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 2, 0, 0),
+  [1571] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
+  [1573] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [1575] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [1577] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [1579] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [1581] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [1583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(286),
+  [1585] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [1587] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [1589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [1591] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [1593] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [1595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1597] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [1599] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 3, 0, 0),
+  [1601] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [1603] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bench_params, 3, 0, 0),
+  [1605] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [1607] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [1609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [1611] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_function_name, 1, 0, 0),
+  [1613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_function_name, 1, 0, 0),
+  [1615] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_function_name, 1, 0, 0),
+  [1617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_function_name, 1, 0, 0),
+  [1619] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_anvil_args, 3, 0, 0),
+  [1621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [1623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [1625] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 4, 0, 0),
+  [1627] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 4, 0, 0),
+  [1629] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [1631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(409),
+  [1633] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bench_params, 4, 0, 0),
+  [1635] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bench_params, 4, 0, 0),
+  [1637] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_fixture_params, 5, 0, 0),
+  [1639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [1641] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bench_params, 5, 0, 0),
+  [1643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [1645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [1647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1649] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1651] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1653] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1655] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1657] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1659] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1661] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1663] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1669] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1671] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1673] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1677] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1681] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1683] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1685] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1687] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1689] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_chart_param_name, 1, 0, 0),
+  [1691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [1693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
 };
 
 enum ts_external_scanner_symbol_identifiers {
@@ -0,0 +1,208 @@
+//! Benchmark selection by tag, suite and name
+//!
+//! Filters are applied to a lowered [`BenchmarkIR`] before scheduling. Benchmarks that are
+//! filtered out are returned as [`SkippedBenchmark`]s so they can be reported.
+
+use crate::{BenchmarkIR, BenchmarkSpec, SuiteIR};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Selectors for `--tag`, `--exclude-tag`, `--suite` and `--bench`
+#[derive(Debug, Clone, Default)]
+pub struct BenchFilter {
+    /// Keep only benchmarks carrying at least one of these tags
+    pub tags: Vec<String>,
+    /// Drop benchmarks carrying any of these tags
+    pub exclude_tags: Vec<String>,
+    /// Keep only these suites (glob patterns)
+    pub suites: Vec<String>,
+    /// Keep only benchmarks whose name (or `suite.bench`) matches one of these globs
+    pub benches: Vec<String>,
+}
+
+/// A benchmark that was not run because it did not match the active filter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SkippedBenchmark {
+    /// Suite name
+    pub suite: String,
+    /// Benchmark name
+    pub name: String,
+    /// Why the benchmark was skipped
+    pub reason: String,
+}
+
+impl BenchFilter {
+    /// Whether no selector is set (everything runs)
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() &&
+            self.exclude_tags.is_empty() &&
+            self.suites.is_empty() &&
+            self.benches.is_empty()
+    }
+
+    /// Returns `None` if the benchmark is selected, otherwise the reason it is filtered out
+    pub fn rejection_reason(&self, suite: &SuiteIR, spec: &BenchmarkSpec) -> Option<String> {
+        if !self.suites.is_empty() && !self.suites.iter().any(|p| glob_match(p, &suite.name)) {
+            return Some(format!("suite not selected by --suite {}", self.suites.join(",")));
+        }
+
+        if let Some(tag) = spec.tags.iter().find(|t| self.exclude_tags.contains(t)) {
+            return Some(format!("excluded by --exclude-tag {}", tag));
+        }
+
+        if !self.tags.is_empty() && !spec.tags.iter().any(|t| self.tags.contains(t)) {
+            return Some(format!("no tag matching --tag {}", self.tags.join(",")));
+        }
+
+        if !self.benches.is_empty() {
+            let qualified = format!("{}.{}", suite.name, spec.name);
+            let selected = self.benches.iter().any(|p| {
+                let target = if p.contains('.') { &qualified } else { &spec.name };
+                glob_match(p, target)
+            });
+            if !selected {
+                return Some(format!("name not matching --bench {}", self.benches.join(",")));
+            }
+        }
+
+        None
+    }
+
+    /// Remove unselected benchmarks (and suites left empty) from the IR.
+    ///
+    /// Returns the benchmarks that were removed.
+    pub fn apply(&self, ir: &mut BenchmarkIR) -> Vec<SkippedBenchmark> {
+        let mut skipped = Vec::new();
+        if self.is_empty() {
+            return skipped;
+        }
+
+        for suite in &mut ir.suites {
+            let benchmarks = std::mem::take(&mut suite.benchmarks);
+            for spec in benchmarks {
+                match self.rejection_reason(suite, &spec) {
+                    None => suite.benchmarks.push(spec),
+                    Some(reason) => skipped.push(SkippedBenchmark {
+                        suite: suite.name.clone(),
+                        name: spec.name.clone(),
+                        reason,
+                    }),
+                }
+            }
+        }
+        ir.suites.retain(|s| !s.benchmarks.is_empty());
+
+        skipped
+    }
+}
+
+/// Minimal glob matching supporting `*` (any run of characters) and `?` (one character)
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` absorb one more character and retry
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::parse;
+
+    fn lowered() -> BenchmarkIR {
+        let source = r#"
+declare suite crypto performance timeBased sameDataset: false {
+    bench keccak {
+        tags: ["crypto", "hash"]
+        go: keccak(data)
+    }
+    bench sha256 {
+        tags: ["crypto", "slow"]
+        go: sha256(data)
+    }
+}
+
+declare suite sorting performance timeBased sameDataset: false {
+    bench quicksort {
+        go: quicksort(data)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        crate::lower(&ast, None).unwrap()
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("keccak*", "keccak256"));
+        assert!(glob_match("*sort", "quicksort"));
+        assert!(glob_match("sha?56", "sha256"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("keccak", "keccak256"));
+        assert!(!glob_match("a*b*c", "abx"));
+    }
+
+    #[test]
+    fn test_filter_by_tag_and_exclude_tag() {
+        let mut ir = lowered();
+        let filter = BenchFilter {
+            tags: vec!["crypto".to_string()],
+            exclude_tags: vec!["slow".to_string()],
+            ..Default::default()
+        };
+        let skipped = filter.apply(&mut ir);
+
+        assert_eq!(ir.suites.len(), 1);
+        assert_eq!(ir.suites[0].benchmarks.len(), 1);
+        assert_eq!(ir.suites[0].benchmarks[0].name, "keccak");
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(skipped[0].name, "sha256");
+        assert!(skipped[0].reason.contains("--exclude-tag slow"));
+        assert_eq!(skipped[1].suite, "sorting");
+    }
+
+    #[test]
+    fn test_filter_by_suite_and_bench_glob() {
+        let mut ir = lowered();
+        let filter = BenchFilter {
+            suites: vec!["crypto".to_string()],
+            benches: vec!["sha*".to_string()],
+            ..Default::default()
+        };
+        filter.apply(&mut ir);
+        assert_eq!(ir.benchmark_count(), 1);
+        assert_eq!(ir.suites[0].benchmarks[0].name, "sha256");
+
+        let mut ir = lowered();
+        let filter = BenchFilter { benches: vec!["sorting.*".to_string()], ..Default::default() };
+        filter.apply(&mut ir);
+        assert_eq!(ir.suites.len(), 1);
+        assert_eq!(ir.suites[0].name, "sorting");
+    }
+
+    #[test]
+    fn test_empty_filter_keeps_everything() {
+        let mut ir = lowered();
+        assert!(BenchFilter::default().apply(&mut ir).is_empty());
+        assert_eq!(ir.benchmark_count(), 3);
+    }
+}
@@ -1,6 +1,7 @@
 //! Fixture resolution and hex parsing
 
 use miette::{miette, Result};
+use poly_bench_dsl::FixtureGenerator;
 use serde_json::Value;
 use std::path::Path;
 
@@ -169,6 +170,210 @@ fn select_json_value<'a>(root: &'a Value, selector: Option<&str>) -> Result<&'a
     Ok(current)
 }
 
+/// Largest fixture a generator may produce (1 GiB), so a stray extra zero fails fast
+pub const MAX_GENERATED_FIXTURE_BYTES: u64 = 1 << 30;
+
+/// Bytes produced by a fixture generator
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedData {
+    pub data: Vec<u8>,
+    /// Seed actually used (`@random` only); defaulted seeds are derived from the fixture name
+    pub seed: Option<u64>,
+    /// Canonical generator call including the resolved seed, suitable for reproducing the run
+    pub description: String,
+}
+
+/// Resolve a `data: @generator(...)` fixture source to bytes.
+///
+/// Generation happens once here so every language receives identical bytes:
+/// - `@random(bytes: N, seed: S)` or `@random(count: N, width: W, seed: S)` — SplitMix64 stream,
+///   little-endian
+/// - `@sequence(count: N, width: 4, start: 0, step: 1)` — little-endian integers, wrapping at the
+///   width
+/// - `@repeat(hex: "..." | text: "...", times: N | bytes: N)` — a pattern tiled to size
+pub fn generate_fixture_data(
+    generator: &FixtureGenerator,
+    fixture_name: &str,
+) -> Result<GeneratedData> {
+    let mut canonical = generator.clone();
+    let (data, seed) = match generator.name.as_str() {
+        "random" => {
+            check_generator_args(generator, fixture_name, &["bytes", "count", "width", "seed"])?;
+            let len = sized_length(generator, fixture_name)?;
+            let seed = match generator_u64(generator, fixture_name, "seed")? {
+                Some(seed) => seed,
+                None => {
+                    let seed = default_seed(fixture_name);
+                    canonical.args.push(("seed".to_string(), poly_bench_dsl::Value::Number(seed)));
+                    seed
+                }
+            };
+            (random_bytes(seed, len), Some(seed))
+        }
+        "sequence" => {
+            check_generator_args(generator, fixture_name, &["count", "width", "start", "step"])?;
+            let count = generator_u64(generator, fixture_name, "count")?
+                .ok_or_else(|| miette!("Fixture '{}': @sequence requires 'count'", fixture_name))?;
+            let width = generator_width(generator, fixture_name)?;
+            check_generated_size(count.saturating_mul(width as u64), fixture_name)?;
+            let start = generator_u64(generator, fixture_name, "start")?.unwrap_or(0);
+            let step = generator_u64(generator, fixture_name, "step")?.unwrap_or(1);
+            let mut data = Vec::with_capacity(count as usize * width);
+            let mut value = start;
+            for _ in 0..count {
+                data.extend_from_slice(&value.to_le_bytes()[..width]);
+                value = value.wrapping_add(step);
+            }
+            (data, None)
+        }
+        "repeat" => {
+            check_generator_args(generator, fixture_name, &["hex", "text", "times", "bytes"])?;
+            let pattern = match (generator.arg("hex"), generator.arg("text")) {
+                (Some(poly_bench_dsl::Value::String(hex)), None) => decode_hex(hex)?,
+                (None, Some(poly_bench_dsl::Value::String(text))) => text.as_bytes().to_vec(),
+                _ => {
+                    return Err(miette!(
+                    "Fixture '{}': @repeat requires exactly one string argument of 'hex' or 'text'",
+                    fixture_name
+                ))
+                }
+            };
+            if pattern.is_empty() {
+                return Err(miette!("Fixture '{}': @repeat pattern is empty", fixture_name));
+            }
+            let len = match (
+                generator_u64(generator, fixture_name, "times")?,
+                generator_u64(generator, fixture_name, "bytes")?,
+            ) {
+                (Some(times), None) => times.saturating_mul(pattern.len() as u64),
+                (None, Some(bytes)) => bytes,
+                _ => {
+                    return Err(miette!(
+                        "Fixture '{}': @repeat requires exactly one of 'times' or 'bytes'",
+                        fixture_name
+                    ))
+                }
+            };
+            check_generated_size(len, fixture_name)?;
+            let data = pattern.iter().copied().cycle().take(len as usize).collect();
+            (data, None)
+        }
+        other => {
+            return Err(miette!(
+                "Fixture '{}': unknown generator '@{}' (expected @random, @sequence or @repeat)",
+                fixture_name,
+                other
+            ))
+        }
+    };
+    Ok(GeneratedData { data, seed, description: canonical.to_string() })
+}
+
+fn check_generator_args(
+    generator: &FixtureGenerator,
+    fixture_name: &str,
+    allowed: &[&str],
+) -> Result<()> {
+    for (key, _) in &generator.args {
+        if !allowed.contains(&key.as_str()) {
+            return Err(miette!(
+                "Fixture '{}': unknown argument '{}' for @{} (expected one of: {})",
+                fixture_name,
+                key,
+                generator.name,
+                allowed.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn generator_u64(
+    generator: &FixtureGenerator,
+    fixture_name: &str,
+    key: &str,
+) -> Result<Option<u64>> {
+    match generator.arg(key) {
+        None => Ok(None),
+        Some(poly_bench_dsl::Value::Number(n)) => Ok(Some(*n)),
+        Some(_) => Err(miette!(
+            "Fixture '{}': argument '{}' of @{} must be a number",
+            fixture_name,
+            key,
+            generator.name
+        )),
+    }
+}
+
+fn generator_width(generator: &FixtureGenerator, fixture_name: &str) -> Result<usize> {
+    match generator_u64(generator, fixture_name, "width")?.unwrap_or(4) {
+        width @ (1 | 2 | 4 | 8) => Ok(width as usize),
+        other => Err(miette!(
+            "Fixture '{}': width must be 1, 2, 4 or 8 bytes, got {}",
+            fixture_name,
+            other
+        )),
+    }
+}
+
+/// Output length for generators sized by `bytes` or by `count` elements of `width` bytes
+fn sized_length(generator: &FixtureGenerator, fixture_name: &str) -> Result<u64> {
+    let len = match (
+        generator_u64(generator, fixture_name, "bytes")?,
+        generator_u64(generator, fixture_name, "count")?,
+    ) {
+        (Some(bytes), None) if generator.arg("width").is_none() => bytes,
+        (None, Some(count)) => {
+            count.saturating_mul(generator_width(generator, fixture_name)? as u64)
+        }
+        _ => {
+            return Err(miette!(
+                "Fixture '{}': @{} requires either 'bytes' or 'count' (with optional 'width')",
+                fixture_name,
+                generator.name
+            ))
+        }
+    };
+    check_generated_size(len, fixture_name)?;
+    Ok(len)
+}
+
+fn check_generated_size(len: u64, fixture_name: &str) -> Result<()> {
+    if len > MAX_GENERATED_FIXTURE_BYTES {
+        return Err(miette!(
+            "Fixture '{}': generated size {} bytes exceeds the {} byte limit",
+            fixture_name,
+            len,
+            MAX_GENERATED_FIXTURE_BYTES
+        ));
+    }
+    Ok(())
+}
+
+/// Seed used when `@random` has none: FNV-1a of the fixture name, stable across runs and hosts
+fn default_seed(fixture_name: &str) -> u64 {
+    fixture_name
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, b| (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// SplitMix64 stream serialized little-endian. The algorithm is fixed so results stay
+/// reproducible across poly-bench versions.
+fn random_bytes(seed: u64, len: u64) -> Vec<u8> {
+    let mut state = seed;
+    let mut data = Vec::with_capacity(len as usize + 8);
+    while (data.len() as u64) < len {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        data.extend_from_slice(&z.to_le_bytes());
+    }
+    data.truncate(len as usize);
+    data
+}
+
 /// Returns true if the byte is an identifier-continuation (alphanumeric or underscore).
 fn is_ident_byte(b: u8) -> bool {
     matches!(b, b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'_')
@@ -270,4 +475,58 @@ mod tests {
         let refs = extract_fixture_refs(code, &fixtures);
         assert_eq!(refs, vec!["s1000"]);
     }
+
+    fn generator(src: &str) -> FixtureGenerator {
+        let source = format!(
+            "declare suite s performance timeBased sameDataset: false {{\n    fixture f {{\n        data: {}\n    }}\n}}\n",
+            src
+        );
+        let file = poly_bench_dsl::parse(&source, "t.bench").unwrap();
+        file.suites[0].fixtures[0].generator.clone().unwrap()
+    }
+
+    #[test]
+    fn test_random_generator_is_deterministic() {
+        let a = generate_fixture_data(&generator("@random(bytes: 1000, seed: 42)"), "f").unwrap();
+        let b = generate_fixture_data(&generator("@random(bytes: 1000, seed: 42)"), "f").unwrap();
+        let c = generate_fixture_data(&generator("@random(bytes: 1000, seed: 43)"), "f").unwrap();
+        assert_eq!(a.data.len(), 1000);
+        assert_eq!(a.data, b.data);
+        assert_ne!(a.data, c.data);
+        assert_eq!(a.seed, Some(42));
+        // SplitMix64 reference output for seed 0
+        assert_eq!(random_bytes(0, 8), 0xe220_a839_7b1d_cdafu64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_random_generator_default_seed_is_recorded() {
+        let out =
+            generate_fixture_data(&generator("@random(count: 10, width: 8)"), "keys").unwrap();
+        assert_eq!(out.data.len(), 80);
+        assert_eq!(out.seed, Some(default_seed("keys")));
+        assert!(out.description.contains(&format!("seed: {}", default_seed("keys"))));
+    }
+
+    #[test]
+    fn test_sequence_and_repeat_generators() {
+        let seq =
+            generate_fixture_data(&generator("@sequence(count: 3, width: 2, start: 255)"), "f")
+                .unwrap();
+        assert_eq!(seq.data, vec![0xff, 0x00, 0x00, 0x01, 0x01, 0x01]);
+
+        let rep =
+            generate_fixture_data(&generator("@repeat(text: \"ab\", bytes: 5)"), "f").unwrap();
+        assert_eq!(rep.data, b"ababa");
+        let rep =
+            generate_fixture_data(&generator("@repeat(hex: \"00ff\", times: 2)"), "f").unwrap();
+        assert_eq!(rep.data, vec![0x00, 0xff, 0x00, 0xff]);
+    }
+
+    #[test]
+    fn test_generator_errors() {
+        assert!(generate_fixture_data(&generator("@noise(bytes: 1)"), "f").is_err());
+        assert!(generate_fixture_data(&generator("@random(bytes: 1, size: 2)"), "f").is_err());
+        assert!(generate_fixture_data(&generator("@sequence(count: 1, width: 3)"), "f").is_err());
+        assert!(generate_fixture_data(&generator("@random(bytes: 4294967296)"), "f").is_err());
+    }
 }
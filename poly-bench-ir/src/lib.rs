@@ -3,13 +3,17 @@
 //! The IR is a normalized, validated representation of benchmarks
 //! that is easier to work with for code generation and execution.
 
+mod filter;
 mod fixtures;
 pub mod imports;
 mod lower;
+mod params;
 mod types;
 
+pub use filter::{glob_match, BenchFilter, SkippedBenchmark};
 pub use fixtures::*;
 pub use imports::{extract_imports, set_import_extractors};
 pub use lower::lower;
+pub use params::{bind_params, expand_param_matrix, expanded_bench_name, param_declarations};
 pub use poly_bench_ir_traits::{ImportExtractor, ParsedSetup};
 pub use types::*;
@@ -4,13 +4,14 @@
 
 use crate::{
     fixtures::{
-        decode_base64, decode_hex, decode_raw, decode_utf8, extract_fixture_refs, load_base64_file,
-        load_hex_file, load_raw_file, load_utf8_file, normalize_csv_to_bytes,
-        normalize_json_to_bytes,
+        decode_base64, decode_hex, decode_raw, decode_utf8, extract_fixture_refs,
+        generate_fixture_data, load_base64_file, load_hex_file, load_raw_file, load_utf8_file,
+        normalize_csv_to_bytes, normalize_json_to_bytes, GeneratedData,
     },
     imports::extract_imports,
-    AnvilConfigIR, BenchmarkIR, BenchmarkSpec, ChartDirectiveIR, FixtureIR, FixtureParamIR,
-    SourceLocation, SuiteIR,
+    params::{bind_params, expand_param_matrix, expanded_bench_name},
+    AnvilConfigIR, BenchParamIR, BenchmarkIR, BenchmarkSpec, ChartDirectiveIR, FixtureIR,
+    FixtureParamIR, SourceLocation, SuiteIR, EXTERNAL_FIXTURE_THRESHOLD,
 };
 use miette::{miette, Result};
 use poly_bench_dsl::{
@@ -107,10 +108,15 @@ fn lower_suite(
     ir.outlier_detection = suite.outlier_detection; // Already defaults to true in AST
     ir.cv_threshold = suite.cv_threshold.unwrap_or(crate::DEFAULT_CV_THRESHOLD);
     ir.count = suite.count.unwrap_or(1); // Default: single run (backward compatible)
+    ir.ci_target = suite.ci_target;
+    ir.max_runs = suite.max_runs.unwrap_or(crate::DEFAULT_ADAPTIVE_MAX_RUNS);
+    ir.max_time_ms = suite.max_time_ms;
     ir.fairness_mode = suite.fairness_mode.unwrap_or_default();
     ir.fairness_seed = suite.fairness_seed;
 
     // Observability settings (Phase 2B)
+    ir.counters = suite.counters;
+    ir.equivalent = suite.equivalent;
     ir.async_sampling_policy = suite.async_sampling_policy.unwrap_or_default();
     ir.async_warmup_cap = suite.async_warmup_cap.unwrap_or(5);
     ir.async_sample_cap = suite.async_sample_cap.unwrap_or(50);
@@ -163,10 +169,24 @@ fn lower_suite(
         ir.fixtures.push(fixture_ir);
     }
 
-    // Lower benchmarks
+    // Lower benchmarks, expanding parameterized benchmarks into one spec per matrix point
+    let mut bench_names = HashSet::new();
     for benchmark in &suite.benchmarks {
-        let bench_ir = lower_benchmark(benchmark, &suite.name, &ir, &fixture_names)?;
-        ir.benchmarks.push(bench_ir);
+        let matrix = expand_param_matrix(&benchmark.params).map_err(|e| {
+            miette!("Benchmark '{}' in suite '{}': {}", benchmark.name, suite.name, e)
+        })?;
+        for bindings in matrix {
+            let bench_ir = lower_benchmark(benchmark, &suite.name, &ir, &fixture_names, &bindings)?;
+            if !bench_names.insert(bench_ir.name.clone()) {
+                return Err(miette!(
+                    "Benchmark '{}' in suite '{}' expands to '{}', which is already a benchmark name; use parameter values that label differently",
+                    benchmark.name,
+                    suite.name,
+                    bench_ir.name
+                ));
+            }
+            ir.benchmarks.push(bench_ir);
+        }
     }
 
     Ok(ir)
@@ -175,7 +195,13 @@ fn lower_suite(
 /// Lower a Fixture to FixtureIR
 fn lower_fixture(fixture: &Fixture, base_dir: Option<&Path>) -> Result<FixtureIR> {
     // Resolve the fixture data
-    let data = if let Some(ref data) = fixture.data {
+    let mut generated = None;
+    let data = if let Some(ref generator) = fixture.generator {
+        let GeneratedData { data, seed, description } =
+            generate_fixture_data(generator, &fixture.name)?;
+        generated = Some((description, seed));
+        data
+    } else if let Some(ref data) = fixture.data {
         decode_fixture_source(
             data,
             fixture.encoding.as_deref().unwrap_or("utf8"),
@@ -207,9 +233,17 @@ fn lower_fixture(fixture: &Fixture, base_dir: Option<&Path>) -> Result<FixtureIR
         return Err(miette!("Fixture '{}' has no hex data or implementations", fixture.name));
     };
 
+    let external = fixture.external.unwrap_or(data.len() >= EXTERNAL_FIXTURE_THRESHOLD);
     let mut ir = FixtureIR::new(fixture.name.clone(), data);
+    if external {
+        ir.externalize();
+    }
     ir.description = fixture.description.clone();
     ir.shape = fixture.shape.clone();
+    if let Some((description, seed)) = generated {
+        ir.generator = Some(description);
+        ir.seed = seed;
+    }
 
     // Copy parameters for parameterized fixtures
     ir.params = fixture
@@ -301,12 +335,13 @@ fn load_fixture_file(
     }
 }
 
-/// Lower a Benchmark to BenchmarkSpec
+/// Lower a Benchmark to BenchmarkSpec, binding one point of its parameter matrix
 fn lower_benchmark(
     benchmark: &Benchmark,
     suite_name: &str,
     suite_ir: &SuiteIR,
     fixture_names: &[String],
+    bindings: &[BenchParamIR],
 ) -> Result<BenchmarkSpec> {
     let iterations = benchmark.iterations.unwrap_or(suite_ir.default_iterations);
     let warmup_iterations =
@@ -314,13 +349,14 @@ fn lower_benchmark(
     let warmup_time_ms = benchmark.warmup_time_ms.unwrap_or(suite_ir.default_warmup_time_ms);
 
     let mut spec = BenchmarkSpec::new(
-        benchmark.name.clone(),
+        expanded_bench_name(&benchmark.name, bindings),
         suite_name,
         iterations,
         warmup_iterations,
         warmup_time_ms,
     );
     spec.kind = benchmark.kind;
+    spec.params = bindings.to_vec();
 
     spec.description = benchmark.description.clone();
 
@@ -346,57 +382,72 @@ fn lower_benchmark(
     spec.outlier_detection = benchmark.outlier_detection.unwrap_or(suite_ir.outlier_detection);
     spec.cv_threshold = benchmark.cv_threshold.unwrap_or(suite_ir.cv_threshold);
     spec.count = benchmark.count.unwrap_or(suite_ir.count);
+    spec.ci_target = benchmark.ci_target.or(suite_ir.ci_target);
+    spec.max_runs = benchmark.max_runs.unwrap_or(suite_ir.max_runs);
+    spec.max_time_ms = benchmark.max_time_ms.or(suite_ir.max_time_ms);
     spec.fairness_mode = suite_ir.fairness_mode;
     spec.fairness_seed = suite_ir.fairness_seed;
 
     // Observability settings (Phase 2B)
     // Memory tracking: derived from suiteType (memory = enabled, performance = disabled)
     spec.memory = suite_ir.suite_type == poly_bench_dsl::SuiteType::Memory;
+    spec.counters = benchmark.counters.unwrap_or(suite_ir.counters);
+    spec.equivalent = benchmark.equivalent.unwrap_or(suite_ir.equivalent);
     spec.async_sampling_policy = suite_ir.async_sampling_policy;
     spec.async_warmup_cap = suite_ir.async_warmup_cap;
     spec.async_sample_cap = suite_ir.async_sample_cap;
 
     // Copy skip conditions
     for (lang, code_block) in &benchmark.skip {
-        spec.skip_conditions.insert(*lang, code_block.code.clone());
+        spec.skip_conditions.insert(*lang, bind_external(&code_block.code, bindings, *lang));
     }
 
     // Copy validations
     for (lang, code_block) in &benchmark.validate {
-        spec.validations.insert(*lang, code_block.code.clone());
+        spec.validations.insert(*lang, bind_external(&code_block.code, bindings, *lang));
     }
 
     // Phase 3: Lifecycle hooks
     for (lang, code_block) in &benchmark.before {
-        spec.before_hooks.insert(*lang, code_block.code.clone());
+        spec.before_hooks.insert(*lang, bind_external(&code_block.code, bindings, *lang));
     }
 
     for (lang, code_block) in &benchmark.after {
-        spec.after_hooks.insert(*lang, code_block.code.clone());
+        spec.after_hooks.insert(*lang, bind_external(&code_block.code, bindings, *lang));
     }
 
     for (lang, code_block) in &benchmark.each {
-        spec.each_hooks.insert(*lang, code_block.code.clone());
+        spec.each_hooks.insert(*lang, bind_external(&code_block.code, bindings, *lang));
     }
 
-    // Copy implementations and extract fixture references
+    // Copy implementations (with parameters bound) and extract fixture references
     for (lang, code_block) in &benchmark.implementations {
-        spec.implementations.insert(*lang, code_block.code.clone());
+        let code = bind_external(&code_block.code, bindings, *lang);
         spec.implementation_sources
             .insert(*lang, SourceLocation::new(code_block.span.line, code_block.span.col));
 
         // Extract fixture references from the code
-        let refs = extract_fixture_refs(&code_block.code, fixture_names);
+        let refs = extract_fixture_refs(&code, fixture_names);
         for r in refs {
             if !spec.fixture_refs.contains(&r) {
                 spec.fixture_refs.push(r);
             }
         }
+        spec.implementations.insert(*lang, code);
     }
 
     Ok(spec)
 }
 
+/// Code as written for built-in languages, whose harnesses declare the parameters; external
+/// runtimes get the values substituted into the code
+fn bind_external(code: &str, bindings: &[BenchParamIR], lang: Lang) -> String {
+    match lang.base() {
+        Lang::External(_) => bind_params(code, bindings, lang),
+        _ => code.to_string(),
+    }
+}
+
 /// Lower a ChartDirective to ChartDirectiveIR
 fn lower_chart_directive(directive: &ChartDirective, suite_name: Option<&str>) -> ChartDirectiveIR {
     let output_file = directive.get_output_file();
@@ -440,7 +491,7 @@ fn lower_chart_directive(directive: &ChartDirective, suite_name: Option<&str>) -
 #[cfg(test)]
 mod tests {
     use super::*;
-    use poly_bench_dsl::parse;
+    use poly_bench_dsl::{parse, Lang};
 
     #[test]
     fn test_lower_simple() {
@@ -482,6 +533,43 @@ declare suite hash performance iterationBased sameDataset: false {
         assert!(bench.has_lang(Lang::TypeScript));
     }
 
+    #[test]
+    fn test_lower_parameterized_benchmark() {
+        let source = r#"
+declare suite sorting performance timeBased sameDataset: false {
+    bench sort(n in [100, 1000]) {
+        go: sortInts(n)
+        python: sort_ints(n)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = lower(&ast, None).unwrap();
+        let benches = &ir.suites[0].benchmarks;
+
+        assert_eq!(benches.len(), 2);
+        assert_eq!(benches[0].name, "sort_n100");
+        assert_eq!(benches[0].full_name, "sorting_sort_n100");
+        // Code is kept as written; runtimes declare `n` in the harness
+        assert_eq!(benches[0].get_impl(Lang::Go), Some("sortInts(n)"));
+        assert_eq!(benches[1].get_impl(Lang::Python), Some("sort_ints(n)"));
+        assert_eq!(benches[1].param("n"), Some(&crate::ParamValue::Int(1000)));
+    }
+
+    #[test]
+    fn test_lower_rejects_colliding_expanded_names() {
+        let source = r#"
+declare suite s performance timeBased sameDataset: false {
+    bench scale(x in [1.5, "1_5"]) {
+        go: scale(x)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let err = lower(&ast, None).unwrap_err();
+        assert!(err.to_string().contains("expands to 'scale_x1_5'"));
+    }
+
     #[test]
     fn test_lower_bench_async_kind() {
         let source = r#"
@@ -614,6 +702,60 @@ declare suite fairnessTest performance timeBased sameDataset: false {
         assert_eq!(bench.async_sample_cap, 88);
     }
 
+    #[test]
+    fn test_lower_counters_inherit_and_override() {
+        let source = r#"
+declare suite perf performance timeBased sameDataset: false {
+    counters: true
+
+    bench inherited {
+        go: work()
+    }
+
+    bench disabled {
+        counters: false
+        go: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = lower(&ast, None).unwrap();
+        let suite = &ir.suites[0];
+
+        assert!(suite.counters);
+        assert!(suite.benchmarks[0].counters);
+        assert!(!suite.benchmarks[1].counters);
+    }
+
+    #[test]
+    fn test_lower_adaptive_settings_inherit_and_override() {
+        let source = r#"
+declare suite perf performance timeBased sameDataset: false {
+    ciTarget: 2
+    maxTime: 20s
+
+    bench inherited {
+        go: work()
+    }
+
+    bench tighter {
+        ciTarget: 0.5
+        maxRuns: 100
+        go: work()
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = lower(&ast, None).unwrap();
+        let suite = &ir.suites[0];
+
+        assert_eq!(suite.benchmarks[0].ci_target, Some(2.0));
+        assert_eq!(suite.benchmarks[0].max_runs, crate::DEFAULT_ADAPTIVE_MAX_RUNS);
+        assert_eq!(suite.benchmarks[0].max_time_ms, Some(20_000));
+        assert_eq!(suite.benchmarks[1].ci_target, Some(0.5));
+        assert_eq!(suite.benchmarks[1].max_runs, 100);
+    }
+
     #[test]
     fn test_lower_fixture_raw_data() {
         let source = r#"
@@ -633,6 +775,99 @@ declare suite test performance timeBased sameDataset: false {
         assert_eq!(ir.suites[0].fixtures[0].data, b"hello");
     }
 
+    #[test]
+    fn test_lower_fixture_external_threshold_and_override() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("big.bin"), vec![7u8; EXTERNAL_FIXTURE_THRESHOLD]).unwrap();
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    fixture big {
+        data: @file("big.bin")
+        encoding: raw
+    }
+
+    fixture inlined {
+        data: @file("big.bin")
+        encoding: raw
+        external: false
+    }
+
+    fixture small {
+        data: "hi"
+        external: true
+    }
+
+    bench foo {
+        go: run(big, inlined, small)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = lower(&ast, Some(dir.path())).unwrap();
+        let suite = &ir.suites[0];
+        let big = suite.get_fixture("big").unwrap();
+        let path = big.external_file.as_ref().expect("large fixture is external");
+        assert!(path.to_string_lossy().ends_with(".bin"));
+        assert!(suite.get_fixture("inlined").unwrap().external_file.is_none());
+        assert!(suite.get_fixture("small").unwrap().external_file.is_some());
+        assert!(suite.uses_external_fixture(&suite.benchmarks[0], Lang::Go));
+
+        // Content-addressed: the same bytes always map to the same file
+        let mut copy = FixtureIR::new("copy".to_string(), big.data.clone());
+        copy.externalize();
+        assert_eq!(copy.external_file, big.external_file);
+    }
+
+    #[test]
+    fn test_external_fixture_file_is_verified_and_quoted_per_language() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut fixture = FixtureIR::new("data".to_string(), b"fixture bytes".to_vec());
+        let path = dir.path().join("a\"b\u{1}.bin");
+        fixture.external_file = Some(path.clone());
+
+        // A file under the expected name with the wrong contents is replaced, not reused
+        std::fs::write(&path, b"tampered bytes").unwrap();
+        fixture.write_external_file().unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"fixture bytes");
+
+        let dir = dir.path().to_string_lossy();
+        let literal = |lang| fixture.external_file_literal(lang).unwrap();
+        assert_eq!(literal(Lang::Rust), format!("\"{}/a\\\"b\\u{{1}}.bin\"", dir));
+        assert_eq!(literal(Lang::Zig), format!("\"{}/a\\\"b\\u{{1}}.bin\"", dir));
+        assert_eq!(literal(Lang::C), format!("\"{}/a\\\"b\\001.bin\"", dir));
+        assert_eq!(literal(Lang::Go), format!("\"{}/a\\\"b\\u0001.bin\"", dir));
+        assert_eq!(literal(Lang::Python), literal(Lang::Go));
+    }
+
+    #[test]
+    fn test_lower_fixture_generator_records_seed() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: false {
+    fixture noise {
+        data: @random(bytes: 64, seed: 42)
+    }
+
+    fixture ids {
+        data: @sequence(count: 4, width: 1)
+    }
+
+    bench foo {
+        go: run(noise, ids)
+    }
+}
+"#;
+        let ast = parse(source, "test.bench").unwrap();
+        let ir = lower(&ast, None).unwrap();
+        let suite = &ir.suites[0];
+        let noise = suite.get_fixture("noise").unwrap();
+        assert_eq!(noise.data.len(), 64);
+        assert_eq!(noise.seed, Some(42));
+        assert_eq!(noise.generator.as_deref(), Some("@random(bytes: 64, seed: 42)"));
+        let ids = suite.get_fixture("ids").unwrap();
+        assert_eq!(ids.data, vec![0, 1, 2, 3]);
+        assert_eq!(ids.seed, None);
+    }
+
     #[test]
     fn test_lower_fixture_base64_data() {
         let source = r#"
@@ -0,0 +1,255 @@
+//! Benchmark parameter matrices
+//!
+//! Expands `bench name(n in [...], m in [...])` into one binding set per point of the
+//! cartesian product. Runtimes declare each bound parameter as a constant or local in the
+//! generated harness (see [`param_declarations`]), so the embedded code is left as written.
+
+use crate::{BenchParamIR, ParamValue};
+use miette::{miette, Result};
+use poly_bench_dsl::{BenchParam, Lang, Value};
+
+/// Convert a DSL parameter value into its IR form
+pub fn param_value_from_dsl(value: &Value) -> Option<ParamValue> {
+    match value {
+        Value::Number(n) => Some(ParamValue::Int(*n)),
+        Value::Float(f) => Some(ParamValue::Float(*f)),
+        Value::Bool(b) => Some(ParamValue::Bool(*b)),
+        Value::String(s) => Some(ParamValue::String(s.clone())),
+        Value::Identifier(_) | Value::FileRef(_) => None,
+    }
+}
+
+/// Expand parameter axes into their cartesian product, first axis varying slowest.
+///
+/// Returns a single empty binding set when there are no axes, and an error when a value is
+/// not a number, string or boolean literal.
+pub fn expand_param_matrix(params: &[BenchParam]) -> Result<Vec<Vec<BenchParamIR>>> {
+    let mut combos: Vec<Vec<BenchParamIR>> = vec![Vec::new()];
+    for param in params {
+        let values = param
+            .values
+            .iter()
+            .map(|value| {
+                param_value_from_dsl(value).ok_or_else(|| {
+                    miette!(
+                        "Parameter '{}' has a non-literal value {:?}; parameter values must be numbers, strings or booleans",
+                        param.name,
+                        value
+                    )
+                })
+            })
+            .collect::<Result<Vec<ParamValue>>>()?;
+        let mut next = Vec::with_capacity(combos.len() * values.len());
+        for combo in &combos {
+            for value in &values {
+                let mut extended = combo.clone();
+                extended.push(BenchParamIR::new(param.name.clone(), value.clone()));
+                next.push(extended);
+            }
+        }
+        combos = next;
+    }
+    Ok(combos)
+}
+
+/// Name of an expanded benchmark, e.g. `sort` + `n=100` -> `sort_n100`
+pub fn expanded_bench_name(base: &str, bindings: &[BenchParamIR]) -> String {
+    let mut name = base.to_string();
+    for binding in bindings {
+        name.push('_');
+        name.push_str(&binding.name);
+        name.push_str(&binding.value.label());
+    }
+    name
+}
+
+/// Declarations binding each parameter to its value in `lang`, one per line prefixed with
+/// `indent`.
+///
+/// Go, TypeScript, Python, C, C# and Zig get constants meant for module (or class) scope, next
+/// to the benchmark's fixtures; Rust gets `let` statements for the top of `main`. Unused
+/// declarations are allowed in every language, so hooks and implementations are free to ignore
+/// a parameter.
+pub fn param_declarations(bindings: &[BenchParamIR], lang: Lang, indent: &str) -> String {
+    let mut out = String::new();
+    for binding in bindings {
+        let (name, value) = (&binding.name, &binding.value);
+        let literal = value.literal(lang.base());
+        let declaration = match lang.base() {
+            Lang::Go => format!("const {} = {}", name, literal),
+            Lang::TypeScript | Lang::Zig => format!("const {} = {};", name, literal),
+            Lang::Python => format!("{} = {}", name, literal),
+            Lang::Rust => format!("#[allow(unused_variables)] let {} = {};", name, literal),
+            Lang::C => match value {
+                ParamValue::Int(n) => format!("static const long long {} = {};", name, n),
+                ParamValue::Float(_) => format!("static const double {} = {};", name, literal),
+                ParamValue::Bool(b) => format!("static const int {} = {};", name, *b as u8),
+                ParamValue::String(_) => {
+                    format!("static const char *const {} = {};", name, literal)
+                }
+            },
+            Lang::CSharp => {
+                let ty = match value {
+                    ParamValue::Int(n) if *n <= i32::MAX as u64 => "int",
+                    ParamValue::Int(n) if *n <= i64::MAX as u64 => "long",
+                    ParamValue::Int(_) => "ulong",
+                    ParamValue::Float(_) => "double",
+                    ParamValue::Bool(_) => "bool",
+                    ParamValue::String(_) => "string",
+                };
+                format!("const {} {} = {};", ty, name, literal)
+            }
+            // External runtimes bind parameters textually (see `bind_params`)
+            Lang::External(_) | Lang::Variant(_) => continue,
+        };
+        out.push_str(indent);
+        out.push_str(&declaration);
+        out.push('\n');
+    }
+    out
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Replace whole-word references to parameters with their literal values.
+///
+/// Only used for external runtimes, whose language the harness knows nothing about; built-in
+/// languages declare parameters instead (see [`param_declarations`]). Occurrences inside string
+/// literals and member accesses (`x.n`) are left untouched.
+pub fn bind_params(code: &str, bindings: &[BenchParamIR], lang: Lang) -> String {
+    if bindings.is_empty() {
+        return code.to_string();
+    }
+
+    let chars: Vec<char> = code.chars().collect();
+    let mut out = String::with_capacity(code.len());
+    let mut quote: Option<char> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(q) = quote {
+            out.push(c);
+            if c == '\\' && i + 1 < chars.len() {
+                out.push(chars[i + 1]);
+                i += 2;
+                continue;
+            }
+            if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+
+        // Rust lifetimes (`'a`) look like an opening quote; only treat `'` as a char literal
+        // when it is closed right after one (possibly escaped) character.
+        let rust_lifetime = lang == Lang::Rust &&
+            c == '\'' &&
+            chars.get(i + 1) != Some(&'\\') &&
+            chars.get(i + 2) != Some(&'\'');
+        if (c == '"' || c == '\'' || c == '`') && !rust_lifetime {
+            quote = Some(c);
+            out.push(c);
+            i += 1;
+            continue;
+        }
+
+        if is_ident_char(c) {
+            let start = i;
+            while i < chars.len() && is_ident_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let after_dot = start > 0 && chars[start - 1] == '.';
+            match bindings.iter().find(|b| b.name == word) {
+                Some(binding) if !after_dot => out.push_str(&binding.value.literal(lang)),
+                _ => out.push_str(&word),
+            }
+            continue;
+        }
+
+        out.push(c);
+        i += 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::Span;
+
+    fn axis(name: &str, values: Vec<Value>) -> BenchParam {
+        BenchParam::new(name.to_string(), values, Span::dummy())
+    }
+
+    #[test]
+    fn test_expand_param_matrix_cartesian_product() {
+        let params = vec![
+            axis("n", vec![Value::Number(10), Value::Number(20)]),
+            axis("mode", vec![Value::String("a".into()), Value::String("b".into())]),
+        ];
+        let combos = expand_param_matrix(&params).unwrap();
+        assert_eq!(combos.len(), 4);
+        assert_eq!(expanded_bench_name("sort", &combos[0]), "sort_n10_modea");
+        assert_eq!(expanded_bench_name("sort", &combos[3]), "sort_n20_modeb");
+    }
+
+    #[test]
+    fn test_expand_param_matrix_rejects_non_literal_values() {
+        let params = vec![axis("n", vec![Value::Number(10), Value::Identifier("big".into())])];
+        let err = expand_param_matrix(&params).unwrap_err();
+        assert!(err.to_string().contains("Parameter 'n' has a non-literal value"));
+    }
+
+    #[test]
+    fn test_param_declarations_per_language() {
+        let bindings = vec![
+            BenchParamIR::new("n".to_string(), ParamValue::Int(100)),
+            BenchParamIR::new("flag".to_string(), ParamValue::Bool(true)),
+        ];
+        assert_eq!(
+            param_declarations(&bindings, Lang::Go, ""),
+            "const n = 100\nconst flag = true\n"
+        );
+        assert_eq!(param_declarations(&bindings, Lang::Python, ""), "n = 100\nflag = True\n");
+        assert_eq!(
+            param_declarations(&bindings, Lang::Rust, "    "),
+            "    #[allow(unused_variables)] let n = 100;\n    #[allow(unused_variables)] let flag = true;\n"
+        );
+        assert_eq!(
+            param_declarations(&bindings, Lang::C, ""),
+            "static const long long n = 100;\nstatic const int flag = 1;\n"
+        );
+        assert_eq!(
+            param_declarations(&bindings, Lang::CSharp, ""),
+            "const int n = 100;\nconst bool flag = true;\n"
+        );
+    }
+
+    #[test]
+    fn test_bind_params_whole_words_only() {
+        let bindings = vec![BenchParamIR::new("n".to_string(), ParamValue::Int(100))];
+        let code = r#"sortN(n, min, obj.n, "n")"#;
+        assert_eq!(bind_params(code, &bindings, Lang::Go), r#"sortN(100, min, obj.n, "n")"#);
+    }
+
+    #[test]
+    fn test_bind_params_language_literals() {
+        let bindings = vec![BenchParamIR::new("flag".to_string(), ParamValue::Bool(true))];
+        assert_eq!(bind_params("run(flag)", &bindings, Lang::Python), "run(True)");
+        assert_eq!(bind_params("run(flag)", &bindings, Lang::Rust), "run(true)");
+    }
+
+    #[test]
+    fn test_bind_params_rust_lifetime_is_not_a_quote() {
+        let bindings = vec![BenchParamIR::new("n".to_string(), ParamValue::Int(8))];
+        let code = "take::<&'static [u8]>(n)";
+        assert_eq!(bind_params(code, &bindings, Lang::Rust), "take::<&'static [u8]>(8)");
+    }
+}
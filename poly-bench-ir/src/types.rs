@@ -4,8 +4,17 @@ use poly_bench_dsl::{
     AsyncSamplingPolicy, BenchMode, BenchmarkKind, ChartType, ExecutionOrder, FairnessMode, Lang,
     RunMode, SuiteType,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+/// Fixtures at least this large are delivered from a file instead of being inlined into
+/// generated source, unless the fixture sets `external: false`
+pub const EXTERNAL_FIXTURE_THRESHOLD: usize = 256 * 1024;
 
 /// A text annotation to place on a chart
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,6 +120,11 @@ impl BenchmarkIR {
             .flat_map(|suite| suite.benchmarks.iter().map(move |bench| (suite, bench)))
     }
 
+    /// Total number of benchmarks across all suites
+    pub fn benchmark_count(&self) -> usize {
+        self.suites.iter().map(|s| s.benchmarks.len()).sum()
+    }
+
     /// Check if a stdlib module is imported
     pub fn has_stdlib(&self, module: &str) -> bool {
         self.stdlib_imports.contains(module)
@@ -184,12 +198,22 @@ pub struct SuiteIR {
     pub cv_threshold: f64,
     /// Number of times to run each benchmark for statistical consistency
     pub count: u64,
+    /// Adaptive runs: target 95% CI half-width as a percentage of the median
+    pub ci_target: Option<f64>,
+    /// Adaptive runs: most runs per benchmark
+    pub max_runs: u64,
+    /// Adaptive runs: time budget per benchmark in milliseconds
+    pub max_time_ms: Option<u64>,
     /// Fairness mode for cross-runtime execution/comparison
     pub fairness_mode: FairnessMode,
     /// Optional deterministic seed for fairness randomization
     pub fairness_seed: Option<u64>,
 
     // Observability settings (Phase 2B)
+    /// Collect hardware performance counters
+    pub counters: bool,
+    /// Compare each language's returned value after the run
+    pub equivalent: bool,
     /// Async sampling policy
     pub async_sampling_policy: AsyncSamplingPolicy,
     /// Async warmup cap
@@ -231,6 +255,9 @@ pub struct SuiteIR {
 /// Default CV threshold percentage (5%) - matches poly-bench-runtime
 pub const DEFAULT_CV_THRESHOLD: f64 = 5.0;
 
+/// Default run budget for adaptive runs (`ciTarget` without `maxRuns`)
+pub const DEFAULT_ADAPTIVE_MAX_RUNS: u64 = 30;
+
 impl SuiteIR {
     pub fn new(name: String) -> Self {
         Self {
@@ -254,9 +281,14 @@ impl SuiteIR {
             outlier_detection: true, // Enabled by default for statistical accuracy
             cv_threshold: DEFAULT_CV_THRESHOLD, // 5% threshold
             count: 1,                // Single run by default (backward compatible)
+            ci_target: None,         // Adaptive runs disabled by default
+            max_runs: DEFAULT_ADAPTIVE_MAX_RUNS,
+            max_time_ms: None,
             fairness_mode: FairnessMode::Strict,
             fairness_seed: None,
             // Observability defaults
+            counters: false,
+            equivalent: false,
             async_sampling_policy: AsyncSamplingPolicy::TimeBudgeted,
             async_warmup_cap: 5,
             async_sample_cap: 50,
@@ -275,6 +307,13 @@ impl SuiteIR {
         }
     }
 
+    /// Whether `lang` loads any fixture referenced by `spec` from an external file
+    pub fn uses_external_fixture(&self, spec: &BenchmarkSpec, lang: Lang) -> bool {
+        spec.fixture_refs.iter().filter_map(|name| self.get_fixture(name)).any(|fixture| {
+            fixture.external_file.is_some() && !fixture.implementations.contains_key(&lang)
+        })
+    }
+
     /// Get a fixture by name
     pub fn get_fixture(&self, name: &str) -> Option<&FixtureIR> {
         self.fixtures.iter().find(|f| f.name == name)
@@ -303,6 +342,20 @@ pub struct FixtureIR {
     pub shape: Option<String>,
     /// Parameter definitions for parameterized fixtures
     pub params: Vec<FixtureParamIR>,
+    /// File the data is loaded from at startup instead of being inlined (see
+    /// [`FixtureIR::externalize`])
+    pub external_file: Option<PathBuf>,
+    /// Generator call the data came from, with any defaulted seed filled in
+    pub generator: Option<String>,
+    /// Seed used by a `@random` generator
+    pub seed: Option<u64>,
+}
+
+/// Directory for external fixture files (`POLYBENCH_FIXTURE_DIR`, else a shared temp directory)
+pub fn external_fixture_dir() -> PathBuf {
+    std::env::var_os("POLYBENCH_FIXTURE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| std::env::temp_dir().join("polybench-fixtures"))
 }
 
 /// Parameter definition for parameterized fixtures
@@ -323,7 +376,64 @@ impl FixtureIR {
             implementations: HashMap::new(),
             shape: None,
             params: Vec::new(),
+            external_file: None,
+            generator: None,
+            seed: None,
+        }
+    }
+
+    /// Deliver the data from a content-addressed file in [`external_fixture_dir`], so every
+    /// language reads the same bytes
+    pub fn externalize(&mut self) {
+        if self.data.is_empty() {
+            return;
+        }
+        let digest = hex::encode(Sha256::digest(&self.data));
+        self.external_file = Some(external_fixture_dir().join(format!("{}.bin", digest)));
+    }
+
+    /// Path of the external file as a string literal in `lang`'s syntax
+    pub fn external_file_literal(&self, lang: Lang) -> Option<String> {
+        let path = self.external_file.as_ref()?.to_string_lossy();
+        let mut literal = String::from("\"");
+        for c in path.chars() {
+            match c {
+                '"' | '\\' => {
+                    literal.push('\\');
+                    literal.push(c);
+                }
+                c if c.is_control() => literal.push_str(&match lang.base() {
+                    Lang::Rust | Lang::Zig => format!("\\u{{{:x}}}", c as u32),
+                    // \u is a universal character name in C and may not name a control char
+                    Lang::C => {
+                        c.encode_utf8(&mut [0; 4]).bytes().map(|b| format!("\\{:03o}", b)).collect()
+                    }
+                    _ => format!("\\u{:04x}", c as u32),
+                }),
+                c => literal.push(c),
+            }
         }
+        literal.push('"');
+        Some(literal)
+    }
+
+    /// Write the external file unless one with the expected contents already exists
+    pub fn write_external_file(&self) -> std::io::Result<()> {
+        let Some(ref path) = self.external_file else { return Ok(()) };
+        // The file name is the digest, but the shared directory is writable by anyone, so the
+        // contents are checked rather than trusted
+        if std::fs::read(path)
+            .is_ok_and(|existing| Sha256::digest(&existing) == Sha256::digest(&self.data))
+        {
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Write then rename so concurrent runs never observe a partial file
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        std::fs::write(&tmp, &self.data)?;
+        std::fs::rename(&tmp, path)
     }
 
     /// Check if this is a parameterized fixture
@@ -375,6 +485,80 @@ impl FixtureIR {
     }
 }
 
+/// A concrete value bound to a benchmark parameter after matrix expansion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ParamValue {
+    Int(u64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl ParamValue {
+    /// Numeric value for chart axes (None for strings and booleans)
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            ParamValue::Int(n) => Some(*n as f64),
+            ParamValue::Float(f) => Some(*f),
+            _ => None,
+        }
+    }
+
+    /// Source literal for this value in the given language
+    pub fn literal(&self, lang: Lang) -> String {
+        match self {
+            ParamValue::Int(n) => n.to_string(),
+            ParamValue::Float(f) => format!("{:?}", f),
+            ParamValue::Bool(b) => match (lang, b) {
+                (Lang::Python, true) => "True".to_string(),
+                (Lang::Python, false) => "False".to_string(),
+                _ => b.to_string(),
+            },
+            ParamValue::String(s) => {
+                format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+        }
+    }
+
+    /// Identifier-safe label used when naming expanded benchmarks (e.g. `0.5` -> `0_5`)
+    pub fn label(&self) -> String {
+        let raw = match self {
+            ParamValue::Int(n) => n.to_string(),
+            ParamValue::Float(f) => f.to_string(),
+            ParamValue::Bool(b) => b.to_string(),
+            ParamValue::String(s) => s.clone(),
+        };
+        raw.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+    }
+}
+
+impl std::fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Int(n) => write!(f, "{}", n),
+            ParamValue::Float(v) => write!(f, "{}", v),
+            ParamValue::Bool(b) => write!(f, "{}", b),
+            ParamValue::String(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A parameter binding for one point of a benchmark matrix
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BenchParamIR {
+    /// Parameter name as declared in `bench name(param in [...])`
+    pub name: String,
+    /// Value bound for this expansion
+    pub value: ParamValue,
+}
+
+impl BenchParamIR {
+    pub fn new(name: String, value: ParamValue) -> Self {
+        Self { name, value }
+    }
+}
+
 /// A single benchmark specification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkSpec {
@@ -414,8 +598,15 @@ pub struct BenchmarkSpec {
     pub outlier_detection: bool,
     /// Coefficient of variation threshold percentage for stability check
     pub cv_threshold: f64,
-    /// Number of times to run this benchmark for statistical consistency
+    /// Number of times to run this benchmark for statistical consistency; the minimum when
+    /// `ci_target` is set
     pub count: u64,
+    /// Keep adding runs until the 95% CI half-width is below this percentage of the median
+    pub ci_target: Option<f64>,
+    /// Run budget for adaptive runs
+    pub max_runs: u64,
+    /// Time budget for adaptive runs in milliseconds
+    pub max_time_ms: Option<u64>,
     /// Fairness mode for this benchmark (resolved from suite + benchmark overrides)
     pub fairness_mode: FairnessMode,
     /// Optional deterministic seed for fairness randomization
@@ -424,6 +615,11 @@ pub struct BenchmarkSpec {
     // Observability settings (Phase 2B)
     /// Enable memory allocation profiling
     pub memory: bool,
+    /// Collect hardware performance counters (instructions, cycles, cache and branch misses)
+    /// around the timed region
+    pub counters: bool,
+    /// Fail the benchmark when languages return different (canonicalized) values
+    pub equivalent: bool,
     /// Async sampling policy
     pub async_sampling_policy: AsyncSamplingPolicy,
     /// Async warmup cap
@@ -445,6 +641,9 @@ pub struct BenchmarkSpec {
     pub implementation_sources: HashMap<Lang, SourceLocation>,
     /// Referenced fixtures
     pub fixture_refs: Vec<String>,
+
+    /// Parameter bindings when this spec was expanded from a parameterized benchmark
+    pub params: Vec<BenchParamIR>,
 }
 
 impl BenchmarkSpec {
@@ -474,9 +673,14 @@ impl BenchmarkSpec {
             outlier_detection: true,
             cv_threshold: DEFAULT_CV_THRESHOLD,
             count: 1,
+            ci_target: None,
+            max_runs: DEFAULT_ADAPTIVE_MAX_RUNS,
+            max_time_ms: None,
             fairness_mode: FairnessMode::Strict,
             fairness_seed: None,
             memory: false,
+            counters: false,
+            equivalent: false,
             async_sampling_policy: AsyncSamplingPolicy::TimeBudgeted,
             async_warmup_cap: 5,
             async_sample_cap: 50,
@@ -486,21 +690,29 @@ impl BenchmarkSpec {
             implementations: HashMap::new(),
             implementation_sources: HashMap::new(),
             fixture_refs: Vec::new(),
+            params: Vec::new(),
         }
     }
 
-    /// Check if this benchmark has an implementation for a language
+    /// Get the bound value of a benchmark parameter
+    pub fn param(&self, name: &str) -> Option<&ParamValue> {
+        self.params.iter().find(|p| p.name == name).map(|p| &p.value)
+    }
+
+    /// Check if this benchmark has an implementation for a language (runtime variants use their
+    /// base language's implementation)
     pub fn has_lang(&self, lang: Lang) -> bool {
-        self.implementations.contains_key(&lang)
+        self.implementations.contains_key(&lang.base())
     }
 
     /// Get the implementation for a language
     pub fn get_impl(&self, lang: Lang) -> Option<&str> {
-        self.implementations.get(&lang).map(|s| s.as_str())
+        self.implementations.get(&lang.base()).map(|s| s.as_str())
     }
 
     /// Check if this benchmark has any lifecycle hooks for a language
     pub fn has_hooks(&self, lang: Lang) -> bool {
+        let lang = lang.base();
         self.before_hooks.contains_key(&lang) ||
             self.after_hooks.contains_key(&lang) ||
             self.each_hooks.contains_key(&lang)
@@ -508,7 +720,7 @@ impl BenchmarkSpec {
 
     /// Check if this benchmark should be skipped for a language
     pub fn should_skip(&self, lang: Lang) -> bool {
-        self.skip_conditions.contains_key(&lang)
+        self.skip_conditions.contains_key(&lang.base())
     }
 }
 
@@ -0,0 +1,530 @@
+//! Running benchmarks from the editor
+//!
+//! The `polybench.run` and `polybench.compileCheck` commands behind the code lenses (see
+//! [`crate::code_lens`]) run a suite, a single benchmark or one language of it with the same
+//! executor as `poly-bench run`. The run happens on its own thread with its own Tokio runtime so
+//! the server keeps answering requests; progress and the final outcome are sent back over a
+//! channel. Outcomes are kept per document as [`RunFinding`]s and published as diagnostics on
+//! the benchmark they belong to.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use poly_bench_dsl::{Lang, SuiteType};
+use poly_bench_executor::{
+    BenchmarkProgress, BenchmarkResult, CompileError, ProgressHook, ProjectRoots, RunOptions,
+};
+use poly_bench_ir::{expanded_bench_name, BenchFilter, BenchParamIR, BenchmarkIR, BenchmarkSpec};
+use poly_bench_runtime::{
+    lang_display::lang_label, lang_full_name, measurement::ComparisonWinner, supported_languages,
+    Measurement,
+};
+use poly_bench_syntax::Span;
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range, Url};
+
+use crate::document::Document;
+
+/// Command that compiles and runs the target
+pub const RUN_COMMAND: &str = "polybench.run";
+/// Whether [`RUN_COMMAND`] is offered. Runs print executor progress to stdout, which only Unix
+/// can move off the protocol stream (see `protocol_stdout`); compile checks print nothing.
+pub const RUN_SUPPORTED: bool = cfg!(unix);
+/// Command that only runs the pre-run compile validation for the target
+pub const COMPILE_CHECK_COMMAND: &str = "polybench.compileCheck";
+
+/// What a run command applies to; sent as the command's only argument
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunTarget {
+    pub uri: Url,
+    pub suite: String,
+    /// Benchmark name; the whole suite when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<String>,
+    /// Run only this language (as accepted by `--lang`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lang: Option<String>,
+}
+
+impl RunTarget {
+    /// e.g. `sorting.bubble (go)`
+    pub fn label(&self) -> String {
+        let mut label = self.suite.clone();
+        if let Some(ref bench) = self.bench {
+            label.push('.');
+            label.push_str(bench);
+        }
+        if let Some(ref lang) = self.lang {
+            label.push_str(&format!(" ({})", lang));
+        }
+        label
+    }
+
+    /// Whether a finding from an earlier run is superseded by running this target
+    pub fn covers(&self, finding: &RunFinding) -> bool {
+        finding.suite == self.suite &&
+            match (&self.bench, &finding.bench) {
+                (None, _) => true,
+                (Some(bench), Some(other)) => is_expansion_of(other, &finding.params, bench),
+                (Some(_), None) => false,
+            }
+    }
+}
+
+/// Whether benchmark `name`, expanded with `params`, is `bench` itself or one of its
+/// parameter-matrix expansions
+pub fn is_expansion_of(name: &str, params: &[BenchParamIR], bench: &str) -> bool {
+    name == bench || (!params.is_empty() && expanded_bench_name(bench, params) == name)
+}
+
+/// One message from a run, attached to a suite or benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunFinding {
+    pub suite: String,
+    pub bench: Option<String>,
+    /// Parameter bindings when `bench` is a parameter-matrix expansion
+    pub params: Vec<BenchParamIR>,
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+}
+
+/// Stage updates sent while a run is in progress
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunProgress {
+    Compiling,
+    Running { index: usize, total: usize, name: String },
+}
+
+/// Result of a finished run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOutcome {
+    pub findings: Vec<RunFinding>,
+    /// One line for the progress end / status message
+    pub summary: String,
+}
+
+/// Compile (and unless `compile_only`, run) `target` from the document text `source`. Blocks
+/// until finished; call it from a dedicated thread.
+pub fn run_target(
+    target: &RunTarget,
+    source: &str,
+    compile_only: bool,
+    progress: impl Fn(RunProgress) + Send + Sync + 'static,
+) -> Result<RunOutcome, String> {
+    let progress = Arc::new(progress);
+    let path = target.uri.to_file_path().map_err(|_| format!("{} is not a file", target.uri))?;
+    let filename = path.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+    if let Some(root) = poly_bench_project::find_project_root(&path) {
+        register_project_runtimes(&root)?;
+    }
+
+    let ast = poly_bench_dsl::parse(source, filename).map_err(|e| e.to_string())?;
+    let mut ir = poly_bench_ir::lower(&ast, path.parent()).map_err(|e| e.to_string())?;
+    select(&mut ir, target);
+    if ir.suites.is_empty() {
+        return Err(format!("No benchmarks match {}", target.label()));
+    }
+    let langs = languages(&ir, target.lang.as_deref())?;
+    let roots = project_roots(&path);
+
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
+    progress(RunProgress::Compiling);
+    let errors = runtime
+        .block_on(poly_bench_executor::validate_benchmarks(&ir, &langs, &roots))
+        .map_err(|e| e.to_string())?;
+    if !errors.is_empty() {
+        return Ok(RunOutcome {
+            summary: format!("{}: {} compile error(s)", target.label(), errors.len()),
+            findings: compile_error_findings(&ir, &errors),
+        });
+    }
+
+    let langs_label = langs.iter().map(|l| lang_label(*l)).collect::<Vec<_>>().join(", ");
+    let benches: Vec<(String, String, Vec<BenchParamIR>)> = ir
+        .suites
+        .iter()
+        .flat_map(|suite| {
+            suite
+                .benchmarks
+                .iter()
+                .map(|spec| (suite.name.clone(), spec.name.clone(), spec.params.clone()))
+        })
+        .collect();
+    if compile_only {
+        return Ok(RunOutcome {
+            findings: benches
+                .into_iter()
+                .map(|(suite, bench, params)| RunFinding {
+                    suite,
+                    bench: Some(bench),
+                    params,
+                    severity: DiagnosticSeverity::INFORMATION,
+                    message: format!("compile check passed ({})", langs_label),
+                })
+                .collect(),
+            summary: format!("{}: compile check passed ({})", target.label(), langs_label),
+        });
+    }
+
+    // One executor run for the whole selection; the executor reports each benchmark as it starts
+    let current: Arc<Mutex<Option<(String, String)>>> = Arc::default();
+    let hook = {
+        let (current, progress) = (Arc::clone(&current), Arc::clone(&progress));
+        ProgressHook::new(move |p: BenchmarkProgress| {
+            *current.lock().unwrap() = Some((p.suite.clone(), p.name.clone()));
+            progress(RunProgress::Running {
+                index: p.index,
+                total: p.total,
+                name: format!("{}.{}", p.suite, p.name),
+            });
+        })
+    };
+    let options = RunOptions { pin_cpus: true, progress: Some(hook), ..Default::default() };
+    match runtime.block_on(poly_bench_executor::run(&ir, &langs, None, &roots, &options)) {
+        Ok(results) => {
+            let mut findings = Vec::new();
+            for suite_results in &results.suites {
+                let is_memory = suite_results.suite_type == SuiteType::Memory;
+                findings.extend(suite_results.benchmarks.iter().map(|result| RunFinding {
+                    suite: suite_results.name.clone(),
+                    bench: Some(result.name.clone()),
+                    params: result.params.clone(),
+                    severity: DiagnosticSeverity::INFORMATION,
+                    message: result_summary(result, is_memory),
+                }));
+            }
+            let summary = format!("{}: ran {} benchmark(s)", target.label(), findings.len());
+            Ok(RunOutcome { findings, summary })
+        }
+        Err(e) => {
+            // Attach the error to the benchmark that was running, else to the suite
+            let failed = current.lock().unwrap().take();
+            let running = failed
+                .as_ref()
+                .and_then(|(suite, name)| benches.iter().find(|(s, b, _)| s == suite && b == name));
+            let (suite, bench, params) = match running {
+                Some((suite, bench, params)) => {
+                    (suite.clone(), Some(bench.clone()), params.clone())
+                }
+                None => (target.suite.clone(), None, Vec::new()),
+            };
+            let summary = match bench {
+                Some(ref bench) => format!("{}: {}.{} failed", target.label(), suite, bench),
+                None => format!("{}: run failed", target.label()),
+            };
+            let finding = RunFinding {
+                suite,
+                bench,
+                params,
+                severity: DiagnosticSeverity::ERROR,
+                message: format!("run failed: {}", e),
+            };
+            Ok(RunOutcome { findings: vec![finding], summary })
+        }
+    }
+}
+
+/// Keep only the target's suite and benchmark (including its parameter-matrix expansions)
+fn select(ir: &mut BenchmarkIR, target: &RunTarget) {
+    BenchFilter { suites: vec![target.suite.clone()], ..Default::default() }.apply(ir);
+    if let Some(ref bench) = target.bench {
+        for suite in &mut ir.suites {
+            suite.benchmarks.retain(|spec| is_expansion_of(&spec.name, &spec.params, bench));
+        }
+        ir.suites.retain(|s| !s.benchmarks.is_empty());
+    }
+}
+
+/// Languages to run, in `supported_languages` order like `poly-bench run`
+fn languages(ir: &BenchmarkIR, lang: Option<&str>) -> Result<Vec<Lang>, String> {
+    let used = ir.languages_used();
+    if let Some(raw) = lang {
+        let lang = Lang::from_str_with_variants(raw)
+            .ok_or_else(|| format!("Unknown language '{}'", raw))?;
+        if !used.contains(&lang.base()) {
+            return Err(format!("{} has no {} implementation", ir.suites[0].name, raw));
+        }
+        return Ok(vec![lang]);
+    }
+    let supported = supported_languages();
+    let variants = poly_bench_runtime::variant::variant_languages()
+        .into_iter()
+        .filter(|v| used.contains(&v.base()))
+        .collect::<Vec<_>>();
+    let mut langs: Vec<_> = used.into_iter().chain(variants).collect();
+    langs.sort_by_key(|l| supported.iter().position(|s| s == l).unwrap_or(usize::MAX));
+    Ok(langs)
+}
+
+/// Project roots for the bench file: `.polybench/runtime-env/{lang}` inside a project, else the
+/// nearest directory each language's detector accepts
+fn project_roots(bench_path: &Path) -> ProjectRoots {
+    let mut roots = ProjectRoots::default();
+    let mut current = bench_path.parent().and_then(|p| p.canonicalize().ok());
+    while let Some(dir) = current {
+        for lang in &supported_languages() {
+            if roots.get_root(*lang).is_some() {
+                continue;
+            }
+            let env = poly_bench_project::runtime_env(&dir, *lang);
+            if dir.join(poly_bench_project::MANIFEST_FILENAME).exists() &&
+                poly_bench_project::is_valid_project_root_for_lang(&env, *lang)
+            {
+                roots.set_root(*lang, Some(env));
+            } else if let Some(detector) = poly_bench_project::get_detector(*lang) {
+                roots.set_root(*lang, detector.detect(&dir));
+            }
+        }
+        if supported_languages().iter().all(|l| roots.get_root(*l).is_some()) {
+            break;
+        }
+        current = dir.parent().map(PathBuf::from);
+    }
+    roots
+}
+
+/// Register the project's external runtimes and variants so their names resolve
+pub(crate) fn register_project_runtimes(project_root: &Path) -> Result<(), String> {
+    // Always register, even outside a project, so the previous project's names are cleared
+    let manifest_path = project_root.join(poly_bench_project::MANIFEST_FILENAME);
+    let (runtimes, variants) = if manifest_path.exists() {
+        let manifest =
+            poly_bench_project::load_manifest(project_root).map_err(|e| e.to_string())?;
+        (manifest.runtimes, manifest.variants)
+    } else {
+        Default::default()
+    };
+    poly_bench_runtime::register_external_runtimes(project_root, &runtimes)
+        .map_err(|e| e.to_string())?;
+    poly_bench_runtime::variant::register_runtime_variants(project_root, &variants)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// One error finding per affected benchmark; compile errors name benchmarks by full name
+fn compile_error_findings(ir: &BenchmarkIR, errors: &[CompileError]) -> Vec<RunFinding> {
+    let mut names: HashMap<&str, (&str, &BenchmarkSpec)> = HashMap::new();
+    for suite in &ir.suites {
+        for spec in &suite.benchmarks {
+            names.insert(&spec.full_name, (&suite.name, spec));
+            names.insert(&spec.name, (&suite.name, spec));
+        }
+    }
+    let names = &names;
+    let fallback_suite = ir.suites.first().map(|s| s.name.as_str()).unwrap_or_default();
+    errors
+        .iter()
+        .flat_map(|error| {
+            let message = format!(
+                "{} compile error ({}): {}",
+                lang_label(error.lang),
+                error.source,
+                error.message
+            );
+            error.benchmarks.iter().map(move |name| {
+                let (suite, bench, params) = match names.get(name.as_str()) {
+                    Some((suite, spec)) => (*suite, spec.name.as_str(), spec.params.clone()),
+                    None => (fallback_suite, name.as_str(), Vec::new()),
+                };
+                RunFinding {
+                    suite: suite.to_string(),
+                    bench: Some(bench.to_string()),
+                    params,
+                    severity: DiagnosticSeverity::ERROR,
+                    message: message.clone(),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Diagnostics for `findings`, placed on the declaration line of their benchmark (or suite).
+/// Findings whose suite no longer exists in the document are dropped.
+pub fn finding_diagnostics(doc: &Document, findings: &[RunFinding]) -> Vec<Diagnostic> {
+    findings
+        .iter()
+        .filter_map(|finding| {
+            let suite = doc.partial_ast.valid_suites().find(|s| s.name == finding.suite)?;
+            let bench = finding.bench.as_deref().and_then(|name| {
+                suite
+                    .benchmarks
+                    .iter()
+                    .filter_map(|b| b.as_valid())
+                    .find(|b| is_expansion_of(name, &finding.params, &b.name))
+            });
+            // Parameter-matrix expansions share a declaration, so name the expansion
+            let message = match (finding.bench.as_deref(), bench) {
+                (Some(name), Some(bench)) if name != bench.name => {
+                    format!("{}: {}", name, finding.message)
+                }
+                _ => finding.message.clone(),
+            };
+            Some(Diagnostic {
+                range: declaration_range(doc, bench.map_or(&suite.span, |b| &b.span)),
+                severity: Some(finding.severity),
+                source: Some("poly-bench run".to_string()),
+                message,
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// From the start of `span` to the end of its first line
+fn declaration_range(doc: &Document, span: &Span) -> Range {
+    let line = doc.source.get_line(span.start_line).map(|l| l.to_string()).unwrap_or_default();
+    let end = line.trim_end().len().max(span.start_col);
+    Range::new(
+        Position::new(span.start_line as u32, span.start_col as u32),
+        Position::new(span.start_line as u32, end as u32),
+    )
+}
+
+/// e.g. `Go 1.20 µs/op · TS 2.41 µs/op → Go 2.01x faster`
+pub fn result_summary(result: &BenchmarkResult, is_memory: bool) -> String {
+    let mut summary = ranked_measurements(result, is_memory)
+        .iter()
+        .map(|(lang, m)| format!("{} {}", lang_label(*lang), format_measurement(m, is_memory)))
+        .collect::<Vec<_>>()
+        .join(" · ");
+    if let Some((_, winner)) = winner_summary(result, is_memory) {
+        summary.push_str(&format!(" → {}", winner));
+    }
+    summary
+}
+
+/// The verdict of the result's comparison, e.g. `Go 2.01x faster`, with the winning language
+/// when there is one (not for ties or differences the significance test cannot confirm).
+/// `None` with one language.
+pub fn winner_summary(result: &BenchmarkResult, is_memory: bool) -> Option<(Option<Lang>, String)> {
+    let comparison = result.comparison.as_ref()?;
+    let lang_named =
+        |name: &str| result.measurements.keys().copied().find(|l| lang_full_name(*l) == name);
+    let faster =
+        if comparison.ratio > 1.0 { &comparison.second_lang } else { &comparison.first_lang };
+    let faster = lang_named(faster);
+    let label = faster.map(lang_label).unwrap_or_default();
+    let verdict = if is_memory {
+        format!("{} uses {:.2}x less memory", label, comparison.speedup)
+    } else {
+        format!("{} {:.2}x faster", label, comparison.speedup)
+    };
+    match comparison.winner {
+        ComparisonWinner::First | ComparisonWinner::Second => Some((faster, verdict)),
+        ComparisonWinner::Tie => Some((None, "similar performance".to_string())),
+        ComparisonWinner::Inconclusive => {
+            Some((None, format!("inconclusive ({}, not significant)", verdict)))
+        }
+    }
+}
+
+/// e.g. `1.20 µs/op`, or bytes per op for memory suites
+pub fn format_measurement(m: &Measurement, is_memory: bool) -> String {
+    if is_memory {
+        m.bytes_per_op.map(Measurement::format_bytes).unwrap_or_else(|| "n/a".to_string())
+    } else {
+        format!("{}/op", Measurement::format_duration(m.nanos_per_op))
+    }
+}
+
+/// Measurements from best to worst
+fn ranked_measurements(result: &BenchmarkResult, is_memory: bool) -> Vec<(Lang, &Measurement)> {
+    let mut measured: Vec<(Lang, &Measurement)> =
+        result.measurements.iter().map(|(lang, m)| (*lang, m)).collect();
+    measured.sort_by(|a, b| {
+        primary_value(a.1, is_memory)
+            .partial_cmp(&primary_value(b.1, is_memory))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    measured
+}
+
+fn primary_value(m: &Measurement, is_memory: bool) -> f64 {
+    if is_memory {
+        m.bytes_per_op.map(|b| b as f64).unwrap_or(f64::MAX)
+    } else {
+        m.nanos_per_op
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_ir::ParamValue;
+
+    fn target(bench: Option<&str>) -> RunTarget {
+        RunTarget {
+            uri: Url::parse("file:///tmp/s.bench").unwrap(),
+            suite: "sorting".to_string(),
+            bench: bench.map(String::from),
+            lang: None,
+        }
+    }
+
+    fn finding(suite: &str, bench: Option<&str>) -> RunFinding {
+        RunFinding {
+            suite: suite.to_string(),
+            bench: bench.map(String::from),
+            params: Vec::new(),
+            severity: DiagnosticSeverity::INFORMATION,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_target_covers_its_benchmark_and_expansions() {
+        let bench = target(Some("sort"));
+        assert!(bench.covers(&finding("sorting", Some("sort"))));
+        let mut expansion = finding("sorting", Some("sort_n100"));
+        expansion.params = vec![BenchParamIR::new("n".to_string(), ParamValue::Int(100))];
+        assert!(bench.covers(&expansion));
+        assert!(!bench.covers(&finding("sorting", Some("sortFast"))));
+        // A separate benchmark whose name merely starts with `sort_`
+        assert!(!bench.covers(&finding("sorting", Some("sort_fast"))));
+        assert!(!bench.covers(&finding("other", Some("sort"))));
+        assert!(target(None).covers(&finding("sorting", Some("sortFast"))));
+        assert_eq!(target(Some("sort")).label(), "sorting.sort");
+    }
+
+    #[test]
+    fn test_result_summary_names_the_winner() {
+        let result = |go_nanos, ts_nanos| {
+            BenchmarkResult::new(
+                "sort".to_string(),
+                "sorting_sort".to_string(),
+                poly_bench_dsl::BenchmarkKind::Sync,
+                None,
+                HashMap::from([
+                    (Lang::Go, Measurement::from_aggregate(1000, go_nanos)),
+                    (Lang::TypeScript, Measurement::from_aggregate(1000, ts_nanos)),
+                ]),
+                SuiteType::Performance,
+                "strict".to_string(),
+                None,
+                None,
+                None,
+                None,
+            )
+        };
+        let mut decided = result(1_200_000, 2_400_000);
+        assert_eq!(
+            result_summary(&decided, false),
+            "Go 1.20 µs/op · TS 2.40 µs/op → Go 2.00x faster"
+        );
+        assert_eq!(winner_summary(&decided, false).unwrap().0, Some(Lang::Go));
+
+        let similar = result(1_200_000, 1_210_000);
+        assert!(result_summary(&similar, false).ends_with("→ similar performance"));
+
+        // A difference the significance test cannot confirm has no winner
+        decided.comparison.as_mut().unwrap().winner = ComparisonWinner::Inconclusive;
+        assert_eq!(
+            winner_summary(&decided, false),
+            Some((None, "inconclusive (Go 2.00x faster, not significant)".to_string()))
+        );
+    }
+}
@@ -0,0 +1,548 @@
+//! Code actions: quick fixes for DSL diagnostics and helper extraction
+//!
+//! Quick fixes are keyed on the lint codes set by [`crate::diagnostics`]:
+//!
+//! - `missing-charting-import`: add `use std::charting`
+//! - `baseline-missing-in-benchmark`: insert a stub implementation for the baseline language
+//! - `legacy-mode-removed`: drop `mode` and move its run mode into the `declare suite` header
+//! - `undefined-function`: create a fixture skeleton or a helper stub for the unknown name
+//!
+//! The "extract into helper" refactoring moves the implementation under the cursor into a
+//! function in the suite's `setup <lang> { helpers { ... } }` block and calls it instead.
+
+use std::collections::HashMap;
+
+use poly_bench_runtime::lang_display::lang_full_name;
+use poly_bench_syntax::{
+    BenchmarkKind, CodeBlock, Lang, Node, PartialBenchmark, PartialSuite, PropertyValue, Span,
+};
+use tower_lsp::lsp_types::*;
+
+use crate::document::Document;
+
+/// Indentation step used for inserted code, matching the formatter
+const INDENT: &str = "    ";
+
+/// An edit offered for one diagnostic
+struct Fix {
+    title: String,
+    edits: Vec<TextEdit>,
+}
+
+/// Quick fixes for `diagnostics` and refactorings available at `range`
+pub fn get_code_actions(
+    doc: &Document,
+    range: Range,
+    diagnostics: &[Diagnostic],
+) -> Vec<CodeActionOrCommand> {
+    let mut actions = Vec::new();
+    for diagnostic in diagnostics {
+        let Some(NumberOrString::String(code)) = &diagnostic.code else { continue };
+        let fixes: Vec<Fix> = match code.as_str() {
+            "missing-charting-import" => vec![add_charting_import(doc)],
+            "baseline-missing-in-benchmark" => {
+                add_baseline_stub(doc, diagnostic.range).into_iter().collect()
+            }
+            "legacy-mode-removed" => {
+                migrate_legacy_mode(doc, diagnostic.range).into_iter().collect()
+            }
+            "undefined-function" => define_unknown_reference(doc, diagnostic),
+            _ => Vec::new(),
+        };
+        let preferred = fixes.len() == 1;
+        actions.extend(fixes.into_iter().map(|fix| {
+            code_action(doc, fix, CodeActionKind::QUICKFIX, Some(diagnostic), preferred)
+        }));
+    }
+    actions.extend(extract_into_helper(doc, range.start));
+    actions
+}
+
+fn add_charting_import(doc: &Document) -> Fix {
+    let last_use =
+        doc.partial_ast.use_stds.iter().filter_map(Node::as_valid).map(|u| u.span.end_line).max();
+    let edit = match last_use {
+        Some(line) => insert(line + 1, "use std::charting\n".to_string()),
+        None => insert(0, "use std::charting\n\n".to_string()),
+    };
+    Fix { title: "Add `use std::charting`".to_string(), edits: vec![edit] }
+}
+
+/// Insert `<baseline>: { // TODO }` before the closing brace of the benchmark at `range`
+fn add_baseline_stub(doc: &Document, range: Range) -> Option<Fix> {
+    let line = range.start.line as usize;
+    let (suite, bench) = doc.partial_ast.valid_suites().find_map(|suite| {
+        suite
+            .benchmarks
+            .iter()
+            .filter_map(Node::as_valid)
+            .find(|b| contains_line(&b.span, line))
+            .map(|bench| (suite, bench))
+    })?;
+    let lang = suite.properties.iter().filter_map(Node::as_valid).find_map(|p| match &p.value {
+        PropertyValue::String(lang) if p.name == "baseline" => Lang::from_str(lang),
+        _ => None,
+    })?;
+    if bench.span.end_line == bench.span.start_line {
+        return None;
+    }
+
+    let indent = format!("{}{}", line_indent(doc, bench.span.start_line), INDENT);
+    let todo = comment(lang, &format!("TODO: implement {}", bench.name));
+    let text = format!("{indent}{}: {{\n{indent}{INDENT}{todo}\n{indent}}}\n", lang.as_str());
+    Some(Fix {
+        title: format!("Add {} implementation stub", lang_name(lang)),
+        edits: vec![insert(bench.span.end_line, text)],
+    })
+}
+
+/// Remove the `mode` property at `range`; when the suite header has no run mode yet, declare
+/// the equivalent one there (`fixed` is `iterationBased`, `auto` is `timeBased`)
+fn migrate_legacy_mode(doc: &Document, range: Range) -> Option<Fix> {
+    // Findings point at the benchmark or suite that declares `mode`
+    let line = range.start.line as usize;
+    let (suite, property) =
+        doc.partial_ast.valid_suites().filter(|s| contains_line(&s.span, line)).find_map(
+            |suite| {
+                let bench_properties = suite
+                    .benchmarks
+                    .iter()
+                    .filter_map(Node::as_valid)
+                    .filter(|b| contains_line(&b.span, line))
+                    .flat_map(|b| b.properties.iter());
+                bench_properties
+                    .chain(suite.properties.iter())
+                    .filter_map(Node::as_valid)
+                    .find(|p| p.name == "mode")
+                    .map(|p| (suite, p))
+            },
+        )?;
+
+    let line = property.span.start_line as u32;
+    let remove = TextEdit {
+        range: Range::new(Position::new(line, 0), Position::new(line + 1, 0)),
+        new_text: String::new(),
+    };
+    let run_mode = match &property.value {
+        PropertyValue::String(mode) if mode == "fixed" => Some("iterationBased"),
+        PropertyValue::String(mode) if mode == "auto" => Some("timeBased"),
+        _ => None,
+    };
+    match run_mode.filter(|_| suite.run_mode.is_none()) {
+        Some(run_mode) => Some(Fix {
+            title: format!("Move `mode` into the suite header as `{}`", run_mode),
+            edits: vec![header_edit(doc, suite, run_mode)?, remove],
+        }),
+        None => Some(Fix { title: "Remove deprecated `mode`".to_string(), edits: vec![remove] }),
+    }
+}
+
+/// Rewrite the suite header up to its `{` as a full `declare suite` header with `run_mode`
+fn header_edit(doc: &Document, suite: &PartialSuite, run_mode: &str) -> Option<TextEdit> {
+    let line = suite.span.start_line;
+    let brace = doc.source.get_line(line)?.chars().position(|c| c == '{')?;
+    let header = format!(
+        "declare suite {} {} {} sameDataset: {} ",
+        suite.name,
+        suite.suite_type.as_deref().unwrap_or("performance"),
+        run_mode,
+        suite.same_dataset.unwrap_or(false)
+    );
+    Some(TextEdit {
+        range: Range::new(
+            Position::new(line as u32, suite.span.start_col as u32),
+            Position::new(line as u32, brace as u32),
+        ),
+        new_text: header,
+    })
+}
+
+/// A fixture skeleton and, inside an implementation, a helper stub for the name quoted in the
+/// diagnostic's message
+fn define_unknown_reference(doc: &Document, diagnostic: &Diagnostic) -> Vec<Fix> {
+    let range = diagnostic.range;
+    let Some(name) = diagnostic.message.split('\'').nth(1) else { return Vec::new() };
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Vec::new();
+    }
+    let Some(suite) =
+        doc.partial_ast.valid_suites().find(|s| contains_line(&s.span, range.start.line as usize))
+    else {
+        return Vec::new();
+    };
+
+    let mut fixes = Vec::new();
+    if let Some(first) = suite.benchmarks.iter().filter_map(Node::as_valid).next() {
+        let indent = line_indent(doc, first.span.start_line);
+        let text = format!("{indent}fixture {name} {{\n{indent}{INDENT}hex: \"\"\n{indent}}}\n\n");
+        fixes.push(Fix {
+            title: format!("Create fixture '{}'", name),
+            edits: vec![insert(first.span.start_line, text)],
+        });
+    }
+    if let Some((_, lang, _)) = implementation_at(suite, range.start.line as usize) {
+        let mut body = vec![comment(lang, &format!("TODO: implement {}", name))];
+        if lang == Lang::Python {
+            body.push("pass".to_string());
+        }
+        if let Some(edit) = insert_helper(doc, suite, lang, &helper_function(lang, name, body)) {
+            fixes.push(Fix {
+                title: format!("Add helper '{}' to setup {}", name, lang.as_str()),
+                edits: vec![edit],
+            });
+        }
+    }
+    fixes
+}
+
+/// Move the implementation under the cursor into a `<bench>_<lang>` helper. Implementations
+/// that use fixtures (which helpers cannot see), async benchmarks and Zig (whose statements
+/// cannot discard arbitrary values) are left alone.
+fn extract_into_helper(doc: &Document, position: Position) -> Option<CodeActionOrCommand> {
+    let line = position.line as usize;
+    let suite = doc.partial_ast.valid_suites().find(|s| contains_line(&s.span, line))?;
+    let (bench, lang, block) = implementation_at(suite, line)?;
+    if bench.kind == BenchmarkKind::Async || lang == Lang::Zig {
+        return None;
+    }
+    let uses_fixture = suite
+        .fixtures
+        .iter()
+        .filter_map(Node::as_valid)
+        .any(|f| block.code.contains(f.name.as_str()));
+    if uses_fixture {
+        return None;
+    }
+
+    let mut body = dedent(&block.code);
+    let last = body.last_mut()?;
+    if matches!(lang, Lang::Rust | Lang::C | Lang::CSharp) &&
+        !last.ends_with(';') &&
+        !last.ends_with('}')
+    {
+        last.push(';');
+    }
+    let name = format!("{}_{}", bench.name, lang.as_str());
+    let helper = insert_helper(doc, suite, lang, &helper_function(lang, &name, body))?;
+    let call = TextEdit { range: trimmed_range(doc, &block.span), new_text: format!("{}()", name) };
+
+    let fix = Fix {
+        title: format!("Extract {} implementation into helper `{}`", lang_name(lang), name),
+        edits: vec![helper, call],
+    };
+    Some(code_action(doc, fix, CodeActionKind::REFACTOR_EXTRACT, None, false))
+}
+
+/// The benchmark implementation spanning `line`
+fn implementation_at(
+    suite: &PartialSuite,
+    line: usize,
+) -> Option<(&PartialBenchmark, Lang, &CodeBlock)> {
+    suite.benchmarks.iter().filter_map(Node::as_valid).find_map(|bench| {
+        bench.impl_order.iter().find_map(|lang| {
+            let block = bench.implementations.get(lang)?.as_valid()?;
+            contains_line(&block.span, line).then_some((bench, *lang, block))
+        })
+    })
+}
+
+/// Add `function` to the helpers of the suite's `lang` setup, creating the `helpers` section or
+/// the whole setup (before the first fixture or benchmark) when missing
+fn insert_helper(
+    doc: &Document,
+    suite: &PartialSuite,
+    lang: Lang,
+    function: &[String],
+) -> Option<TextEdit> {
+    let Some(setup) = suite.setups.get(&lang) else {
+        let anchor = suite
+            .fixtures
+            .iter()
+            .filter_map(|f| f.as_valid().map(|f| &f.span))
+            .chain(suite.benchmarks.iter().filter_map(|b| b.as_valid().map(|b| &b.span)))
+            .min_by_key(|span| span.start)?;
+        let indent = line_indent(doc, anchor.start_line);
+        let inner = format!("{indent}{INDENT}");
+        let text = format!(
+            "{indent}setup {} {{\n{inner}helpers {{\n{}{inner}}}\n{indent}}}\n\n",
+            lang.as_str(),
+            indented(function, &format!("{inner}{INDENT}"))
+        );
+        return Some(insert(anchor.start_line, text));
+    };
+    let setup = setup.as_valid()?;
+
+    match &setup.helpers {
+        Some(helpers) if helpers.span.end_line > helpers.span.start_line => {
+            let indent = format!("{}{}", line_indent(doc, helpers.span.end_line), INDENT);
+            let separator = if helpers.code.trim().is_empty() { "" } else { "\n" };
+            let text = format!("{}{}", separator, indented(function, &indent));
+            Some(insert(helpers.span.end_line, text))
+        }
+        Some(helpers) => {
+            // `helpers { ... }` on a single line: spread it over several
+            let indent = line_indent(doc, helpers.span.start_line);
+            let inner = format!("{indent}{INDENT}");
+            let existing = helpers.code.trim();
+            let existing =
+                if existing.is_empty() { String::new() } else { format!("{inner}{existing}\n\n") };
+            Some(TextEdit {
+                range: doc.span_to_range(&helpers.span),
+                new_text: format!("\n{}{}{}", existing, indented(function, &inner), indent),
+            })
+        }
+        None if setup.span.end_line > setup.span.start_line => {
+            let inner = format!("{}{}", line_indent(doc, setup.span.start_line), INDENT);
+            let text = format!(
+                "\n{inner}helpers {{\n{}{inner}}}\n",
+                indented(function, &format!("{inner}{INDENT}"))
+            );
+            Some(insert(setup.span.end_line, text))
+        }
+        None => None,
+    }
+}
+
+/// A helper function named `name` with `body` in `lang`, one entry per line
+fn helper_function(lang: Lang, name: &str, body: Vec<String>) -> Vec<String> {
+    let signature = match lang {
+        Lang::Go => format!("func {}() {{", name),
+        Lang::TypeScript => format!("function {}() {{", name),
+        Lang::Rust => format!("fn {}() {{", name),
+        Lang::Python => format!("def {}():", name),
+        Lang::C => format!("static void {}(void) {{", name),
+        Lang::CSharp => format!("static void {}() {{", name),
+        Lang::Zig => format!("fn {}() void {{", name),
+    };
+    let mut lines = vec![signature];
+    lines.extend(
+        body.into_iter().map(|l| if l.is_empty() { l } else { format!("{}{}", INDENT, l) }),
+    );
+    if lang != Lang::Python {
+        lines.push("}".to_string());
+    }
+    lines
+}
+
+fn comment(lang: Lang, text: &str) -> String {
+    match lang {
+        Lang::Python => format!("# {}", text),
+        _ => format!("// {}", text),
+    }
+}
+
+fn lang_name(lang: Lang) -> &'static str {
+    lang_full_name(poly_bench_traits::syntax_lang_to_dsl(lang))
+}
+
+/// Lines of `code` without surrounding blank lines and common indentation
+fn dedent(code: &str) -> Vec<String> {
+    let lines: Vec<&str> = code.lines().collect();
+    let Some(first) = lines.iter().position(|l| !l.trim().is_empty()) else { return Vec::new() };
+    let last = lines.iter().rposition(|l| !l.trim().is_empty()).unwrap_or(first);
+    let lines = &lines[first..=last];
+    let margin = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines.iter().map(|l| l.get(margin..).unwrap_or("").trim_end().to_string()).collect()
+}
+
+/// Prefix every non-empty line with `indent`, newline-terminated
+fn indented(lines: &[String], indent: &str) -> String {
+    lines
+        .iter()
+        .map(|l| if l.is_empty() { "\n".to_string() } else { format!("{}{}\n", indent, l) })
+        .collect()
+}
+
+/// Leading whitespace of `line`
+fn line_indent(doc: &Document, line: usize) -> String {
+    doc.source
+        .get_line(line)
+        .map(|l| l.chars().take_while(|c| *c == ' ' || *c == '\t').collect())
+        .unwrap_or_default()
+}
+
+/// Range of `span` without leading and trailing whitespace
+fn trimmed_range(doc: &Document, span: &Span) -> Range {
+    let text = doc.source_slice(span);
+    let lead = text.len() - text.trim_start().len();
+    let trail = text.len() - text.trim_end().len();
+    let (start_line, start_col) = doc.byte_to_position(span.start + lead);
+    let (end_line, end_col) = doc.byte_to_position(span.end - trail);
+    Range::new(
+        Position::new(start_line as u32, start_col as u32),
+        Position::new(end_line as u32, end_col as u32),
+    )
+}
+
+fn contains_line(span: &Span, line: usize) -> bool {
+    span.start_line <= line && line <= span.end_line
+}
+
+fn insert(line: usize, text: String) -> TextEdit {
+    let at = Position::new(line as u32, 0);
+    TextEdit { range: Range::new(at, at), new_text: text }
+}
+
+fn code_action(
+    doc: &Document,
+    fix: Fix,
+    kind: CodeActionKind,
+    diagnostic: Option<&Diagnostic>,
+    preferred: bool,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: fix.title,
+        kind: Some(kind),
+        diagnostics: diagnostic.map(|d| vec![d.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(doc.uri.clone(), fix.edits)])),
+            ..Default::default()
+        }),
+        is_preferred: preferred.then_some(true),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::compute_diagnostics;
+
+    fn make_doc(source: &str) -> Document {
+        poly_bench_runtime::init_helper_function_extractors();
+        Document::new(Url::parse("file:///test.bench").unwrap(), source.to_string(), 1)
+    }
+
+    /// Apply the edits of the action titled `title` to `source`
+    fn apply(source: &str, range: Range, title: &str) -> String {
+        let doc = make_doc(source);
+        let actions = get_code_actions(&doc, range, &compute_diagnostics(&doc));
+        let action = actions
+            .into_iter()
+            .find_map(|a| match a {
+                CodeActionOrCommand::CodeAction(a) if a.title == title => Some(a),
+                _ => None,
+            })
+            .unwrap_or_else(|| panic!("no action titled {:?}", title));
+        let mut edits = action.edit.unwrap().changes.unwrap().remove(&doc.uri).unwrap();
+        edits.sort_by_key(|e| std::cmp::Reverse(e.range.start));
+        let mut result = doc.source.clone();
+        for edit in edits {
+            let start = result.line_to_char(edit.range.start.line as usize) +
+                edit.range.start.character as usize;
+            let end = result.line_to_char(edit.range.end.line as usize) +
+                edit.range.end.character as usize;
+            result.remove(start..end);
+            result.insert(start, &edit.new_text);
+        }
+        result.to_string()
+    }
+
+    fn at(line: u32) -> Range {
+        Range::new(Position::new(line, 0), Position::new(line, 0))
+    }
+
+    #[test]
+    fn test_add_charting_import() {
+        let source = r#"declare suite s performance timeBased sameDataset: true {
+    bench a {
+        go: work()
+    }
+    after {
+        charting.drawTable(title: "t")
+    }
+}
+"#;
+        let fixed = apply(source, at(5), "Add `use std::charting`");
+        assert_eq!(fixed, format!("use std::charting\n\n{}", source));
+    }
+
+    #[test]
+    fn test_add_baseline_stub() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    baseline: "go"
+    bench a {
+        ts: work()
+    }
+}
+"#;
+        let fixed = apply(source, at(2), "Add Go implementation stub");
+        assert!(fixed.contains(
+            "        ts: work()\n        go: {\n            // TODO: implement a\n        }\n    }"
+        ));
+    }
+
+    #[test]
+    fn test_migrate_legacy_mode_into_header() {
+        let source = r#"suite s {
+    mode: "fixed"
+    bench a {
+        go: work()
+    }
+}
+"#;
+        let fixed = apply(source, at(1), "Move `mode` into the suite header as `iterationBased`");
+        assert_eq!(
+            fixed,
+            r#"declare suite s performance iterationBased sameDataset: false {
+    bench a {
+        go: work()
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_unknown_reference_fixes() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    bench a {
+        go: encode(payload)
+    }
+}
+"#;
+        let fixed = apply(source, at(2), "Create fixture 'encode'");
+        assert!(fixed.contains("    fixture encode {\n        hex: \"\"\n    }\n\n    bench a {"));
+
+        let fixed = apply(source, at(2), "Add helper 'encode' to setup go");
+        assert!(fixed.contains(
+            "    setup go {\n        helpers {\n            func encode() {\n                // TODO: implement encode\n            }\n        }\n    }\n\n    bench a {"
+        ));
+    }
+
+    #[test]
+    fn test_extract_into_existing_helpers() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    setup rust {
+        helpers {
+            fn work() -> u64 { 1 }
+        }
+    }
+    bench a {
+        go: work()
+        rust: {
+            let x = work();
+            x + 1
+        }
+    }
+}
+"#;
+        let fixed = apply(source, at(9), "Extract Rust implementation into helper `a_rust`");
+        assert!(fixed.contains(
+            "            fn work() -> u64 { 1 }\n\n            fn a_rust() {\n                let x = work();\n                x + 1;\n            }\n        }"
+        ));
+        assert!(fixed.contains("        rust: {\n            a_rust()\n        }"));
+
+        let doc = make_doc(
+            &source
+                .replace("bench a {", "fixture data {\n        hex: \"00\"\n    }\n    bench a {")
+                .replace("work()\n        rust", "work(data)\n        rust"),
+        );
+        assert!(extract_into_helper(&doc, Position::new(10, 12)).is_none());
+    }
+}
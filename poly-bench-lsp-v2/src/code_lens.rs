@@ -0,0 +1,107 @@
+//! Code lenses for running suites and benchmarks
+//!
+//! Every suite gets "Run suite" and "Compile check"; every `bench`/`benchAsync` gets "Run", one
+//! "Run <language> only" per implementation, and "Compile check". The lenses invoke the commands
+//! handled by [`crate::bench_runner`]. Where runs are not supported
+//! ([`crate::bench_runner::RUN_SUPPORTED`]) only the compile checks are shown.
+
+use poly_bench_runtime::lang_display::lang_full_name;
+use poly_bench_syntax::{Lang, Span};
+use tower_lsp::lsp_types::{CodeLens, Command, Position, Range};
+
+use crate::{
+    bench_runner::{RunTarget, COMPILE_CHECK_COMMAND, RUN_COMMAND, RUN_SUPPORTED},
+    document::Document,
+};
+
+/// Lenses for all suites and benchmarks in the document
+pub fn get_code_lenses(doc: &Document) -> Vec<CodeLens> {
+    let mut lenses = Vec::new();
+    for suite in doc.partial_ast.valid_suites() {
+        let target =
+            RunTarget { uri: doc.uri.clone(), suite: suite.name.clone(), bench: None, lang: None };
+        if RUN_SUPPORTED {
+            lenses.push(lens(&suite.span, "▶ Run suite", RUN_COMMAND, &target));
+        }
+        lenses.push(lens(&suite.span, "Compile check", COMPILE_CHECK_COMMAND, &target));
+
+        for bench in suite.benchmarks.iter().filter_map(|b| b.as_valid()) {
+            let target = RunTarget { bench: Some(bench.name.clone()), ..target.clone() };
+            if RUN_SUPPORTED {
+                lenses.push(lens(&bench.span, "▶ Run", RUN_COMMAND, &target));
+                for lang in &bench.impl_order {
+                    let title = format!("Run {} only", lang_name(*lang));
+                    let target =
+                        RunTarget { lang: Some(lang.as_str().to_string()), ..target.clone() };
+                    lenses.push(lens(&bench.span, &title, RUN_COMMAND, &target));
+                }
+            }
+            lenses.push(lens(&bench.span, "Compile check", COMPILE_CHECK_COMMAND, &target));
+        }
+    }
+    lenses
+}
+
+fn lang_name(lang: Lang) -> &'static str {
+    lang_full_name(poly_bench_traits::syntax_lang_to_dsl(lang))
+}
+
+/// A lens on the first line of `span`
+fn lens(span: &Span, title: &str, command: &str, target: &RunTarget) -> CodeLens {
+    let start = Position::new(span.start_line as u32, span.start_col as u32);
+    CodeLens {
+        range: Range::new(start, start),
+        command: Some(Command {
+            title: title.to_string(),
+            command: command.to_string(),
+            arguments: serde_json::to_value(target).ok().map(|arg| vec![arg]),
+        }),
+        data: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp::lsp_types::Url;
+
+    #[test]
+    fn test_lenses_for_suite_and_benchmarks() {
+        let source = r#"suite sorting {
+    bench sortInts {
+        go: sortInts(data)
+        ts: sortInts(data)
+    }
+}
+"#;
+        let uri = Url::parse("file:///tmp/sorting.bench").unwrap();
+        let doc = Document::new(uri.clone(), source.to_string(), 1);
+        let lenses = get_code_lenses(&doc);
+        let titles: Vec<_> =
+            lenses.iter().map(|l| l.command.as_ref().unwrap().title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "▶ Run suite",
+                "Compile check",
+                "▶ Run",
+                "Run Go only",
+                "Run TypeScript only",
+                "Compile check"
+            ]
+        );
+        assert_eq!(lenses[2].range.start, Position::new(1, 4));
+
+        let args = lenses[4].command.as_ref().unwrap().arguments.clone().unwrap();
+        let target: RunTarget = serde_json::from_value(args[0].clone()).unwrap();
+        assert_eq!(
+            target,
+            RunTarget {
+                uri,
+                suite: "sorting".to_string(),
+                bench: Some("sortInts".to_string()),
+                lang: Some("ts".to_string()),
+            }
+        );
+    }
+}
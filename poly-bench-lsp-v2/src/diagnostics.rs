@@ -2,34 +2,95 @@
 //!
 //! This module provides diagnostics from multiple sources:
 //! 1. Syntax errors from Tree-sitter (always available)
-//! 2. Semantic errors from partial AST validation
-//! 3. Helper reference validation (function calls vs defined helpers)
-//! 4. Embedded language errors (on save)
+//! 2. Semantic lint findings from poly-bench-dsl's validation, once the file parses
+//! 3. Embedded language errors (on save)
 
 use crate::document::Document;
-use poly_bench_project::{find_project_root, load_manifest};
-use poly_bench_runtime::get_helper_function_extractor;
-use poly_bench_syntax::{
-    Lang, Node, PartialBenchmark, PartialFixture, PartialSuite, PropertyValue, StructuredSetup,
+use poly_bench_dsl::{
+    lint::{self, LintConfig, LintLevel, Suppressions},
+    RuleViolation, Span as DslSpan, ValidationError, ValidationResult,
 };
-use poly_bench_traits::syntax_lang_to_dsl;
-use std::{collections::HashSet, path::Path};
+use poly_bench_project::{find_project_root, load_manifest, Manifest};
+use poly_bench_syntax::Node;
+use std::path::Path;
 use tower_lsp::lsp_types::*;
 
 /// Compute diagnostics for a document
 pub fn compute_diagnostics(doc: &Document) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
+    let manifest = load_document_manifest(doc);
+    let config = manifest.as_ref().map(|m| m.lint_config()).unwrap_or_default();
+    let suppressions = Suppressions::parse(&doc.source_text());
 
-    // 1. Syntax errors from Tree-sitter
+    // 1. Syntax errors from Tree-sitter and unrecoverable declarations
     collect_syntax_errors(&doc.tree, &doc.source_text(), &mut diagnostics);
+    collect_partial_ast_errors(doc, &mut diagnostics);
+    apply_lint_config(&config, &suppressions, &mut diagnostics);
+
+    // 2. Semantic lint findings, including runtimes not configured in polybench.toml
+    let has_syntax_errors = !diagnostics.is_empty();
+    validate_document(
+        doc,
+        manifest.as_ref(),
+        &config,
+        &suppressions,
+        has_syntax_errors,
+        &mut diagnostics,
+    );
 
-    // 2. Semantic errors from partial AST
-    validate_partial_ast(doc, &mut diagnostics);
+    diagnostics
+}
 
-    // 3. Runtime configuration validation (languages used vs polybench.toml)
-    validate_runtime_configuration(doc, &mut diagnostics);
+/// Build a diagnostic for a registered lint rule with the rule's default severity and docs link
+fn lint_diagnostic(id: &str, range: Range, message: impl Into<String>) -> Diagnostic {
+    let rule = lint::rule(id);
+    Diagnostic {
+        range,
+        severity: lint_severity(rule.map_or(LintLevel::Warn, |r| r.default_level)),
+        code: Some(NumberOrString::String(id.to_string())),
+        code_description: rule
+            .and_then(|r| Url::parse(&r.docs_url()).ok())
+            .map(|href| CodeDescription { href }),
+        source: Some("poly-bench".to_string()),
+        message: message.into(),
+        ..Default::default()
+    }
+}
 
-    diagnostics
+fn lint_severity(level: LintLevel) -> Option<DiagnosticSeverity> {
+    match level {
+        LintLevel::Allow => None,
+        LintLevel::Hint => Some(DiagnosticSeverity::HINT),
+        LintLevel::Warn => Some(DiagnosticSeverity::WARNING),
+        LintLevel::Error => Some(DiagnosticSeverity::ERROR),
+    }
+}
+
+/// Re-level diagnostics per the project's lint overrides and drop allowed or suppressed ones
+fn apply_lint_config(
+    config: &LintConfig,
+    suppressions: &Suppressions,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    diagnostics.retain_mut(|d| {
+        let Some(NumberOrString::String(id)) = &d.code else { return true };
+        if lint::rule(id).is_none() {
+            return true;
+        }
+        if suppressions.is_suppressed(id, Some(d.range.start.line as usize + 1)) {
+            return false;
+        }
+        d.severity = lint_severity(config.level(id));
+        d.severity.is_some()
+    });
+}
+
+/// Manifest of the project containing the document, if any
+fn load_document_manifest(doc: &Document) -> Option<Manifest> {
+    let bench_path = doc.uri.to_file_path().ok()?;
+    let start_path = bench_path.parent().unwrap_or(&bench_path);
+    let project_root = find_project_root(Path::new(start_path))?;
+    load_manifest(&project_root).ok()
 }
 
 /// Collect syntax errors from the Tree-sitter tree
@@ -60,31 +121,25 @@ fn collect_errors_recursive(
             format!("Syntax error near: {}", text)
         };
 
-        diagnostics.push(Diagnostic {
-            range: Range {
+        diagnostics.push(lint_diagnostic(
+            "syntax-error",
+            Range {
                 start: Position { line: start.row as u32, character: start.column as u32 },
                 end: Position { line: end.row as u32, character: end.column as u32 },
             },
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("syntax-error".to_string())),
-            source: Some("poly-bench".to_string()),
             message,
-            ..Default::default()
-        });
+        ));
     } else if node.is_missing() {
         let start = node.start_position();
 
-        diagnostics.push(Diagnostic {
-            range: Range {
+        diagnostics.push(lint_diagnostic(
+            "missing-token",
+            Range {
                 start: Position { line: start.row as u32, character: start.column as u32 },
                 end: Position { line: start.row as u32, character: start.column as u32 + 1 },
             },
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("missing-token".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!("Missing {}", node.kind()),
-            ..Default::default()
-        });
+            format!("Missing {}", node.kind()),
+        ));
     }
 
     // Recurse into children
@@ -99,873 +154,112 @@ fn collect_errors_recursive(
     }
 }
 
-/// Validate the partial AST for semantic errors
-fn validate_partial_ast(doc: &Document, diagnostics: &mut Vec<Diagnostic>) {
-    let ast = &doc.partial_ast;
-
-    // Validate suites
-    for suite in &ast.suites {
-        if let Node::Valid(s) = suite {
-            validate_suite(s, doc, diagnostics);
-        } else if let Node::Error { span, message } = suite {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(span),
-                severity: Some(DiagnosticSeverity::ERROR),
-                code: Some(NumberOrString::String("parse-error".to_string())),
-                source: Some("poly-bench".to_string()),
-                message: message.clone(),
-                ..Default::default()
-            });
-        }
-    }
-}
-
-/// Validate that languages used in setups, benchmarks, and fixtures are configured in
-/// polybench.toml
-fn validate_runtime_configuration(doc: &Document, diagnostics: &mut Vec<Diagnostic>) {
-    let bench_path = match doc.uri.to_file_path() {
-        Ok(p) => p,
-        Err(_) => return,
-    };
-    let start_path = bench_path.parent().unwrap_or(&bench_path);
-    let project_root = match find_project_root(Path::new(start_path)) {
-        Some(root) => root,
-        None => return,
-    };
-    let manifest = match load_manifest(&project_root) {
-        Ok(m) => m,
-        Err(_) => return,
-    };
-
-    let ast = &doc.partial_ast;
-    for suite in &ast.suites {
-        let Node::Valid(s) = suite else { continue };
-
-        for (lang, setup_node) in &s.setups {
-            if !manifest.has_runtime(syntax_lang_to_dsl(*lang)) {
-                let span = match setup_node {
-                    Node::Valid(setup) => &setup.span,
-                    _ => continue,
-                };
-                diagnostics.push(Diagnostic {
-                    range: doc.span_to_range(span),
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("runtime-not-configured".to_string())),
-                    source: Some("poly-bench".to_string()),
-                    message: format!(
-                        "{} is not configured in this project. Run 'poly-bench add-runtime {}' to add this runtime.",
-                        lang.as_str(),
-                        lang.as_str()
-                    ),
-                    ..Default::default()
-                });
-            }
-        }
-
-        for fixture in &s.fixtures {
-            let Node::Valid(f) = fixture else { continue };
-            for (lang, impl_node) in &f.implementations {
-                if !manifest.has_runtime(syntax_lang_to_dsl(*lang)) {
-                    let span = match impl_node {
-                        Node::Valid(code) => &code.span,
-                        _ => continue,
-                    };
-                    diagnostics.push(Diagnostic {
-                        range: doc.span_to_range(span),
-                        severity: Some(DiagnosticSeverity::ERROR),
-                        code: Some(NumberOrString::String("runtime-not-configured".to_string())),
-                        source: Some("poly-bench".to_string()),
-                        message: format!(
-                            "{} is not configured in this project. Run 'poly-bench add-runtime {}' to add this runtime.",
-                            lang.as_str(),
-                            lang.as_str()
-                        ),
-                        ..Default::default()
-                    });
-                }
-            }
-        }
-
-        for benchmark in &s.benchmarks {
-            let Node::Valid(b) = benchmark else { continue };
-
-            for (lang, impl_node) in &b.implementations {
-                if !manifest.has_runtime(syntax_lang_to_dsl(*lang)) {
-                    let span = match impl_node {
-                        Node::Valid(code) => &code.span,
-                        _ => continue,
-                    };
-                    diagnostics.push(Diagnostic {
-                        range: doc.span_to_range(span),
-                        severity: Some(DiagnosticSeverity::ERROR),
-                        code: Some(NumberOrString::String("runtime-not-configured".to_string())),
-                        source: Some("poly-bench".to_string()),
-                        message: format!(
-                            "{} is not configured in this project. Run 'poly-bench add-runtime {}' to add this runtime.",
-                            lang.as_str(),
-                            lang.as_str()
-                        ),
-                        ..Default::default()
-                    });
-                }
-            }
-            for (lang, hook_node) in b
-                .before
-                .iter()
-                .chain(b.after.iter())
-                .chain(b.each.iter())
-                .chain(b.skip.iter())
-                .chain(b.validate.iter())
-            {
-                if !manifest.has_runtime(syntax_lang_to_dsl(*lang)) {
-                    let span = match hook_node {
-                        Node::Valid(code) => &code.span,
-                        _ => continue,
-                    };
-                    diagnostics.push(Diagnostic {
-                        range: doc.span_to_range(span),
-                        severity: Some(DiagnosticSeverity::ERROR),
-                        code: Some(NumberOrString::String("runtime-not-configured".to_string())),
-                        source: Some("poly-bench".to_string()),
-                        message: format!(
-                            "{} is not configured in this project. Run 'poly-bench add-runtime {}' to add this runtime.",
-                            lang.as_str(),
-                            lang.as_str()
-                        ),
-                        ..Default::default()
-                    });
-                }
-            }
-        }
-    }
-}
-
-fn validate_suite(suite: &PartialSuite, doc: &Document, diagnostics: &mut Vec<Diagnostic>) {
-    // Check for empty suite
-    if suite.benchmarks.is_empty() && suite.fixtures.is_empty() {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&suite.span),
-            severity: Some(DiagnosticSeverity::WARNING),
-            code: Some(NumberOrString::String("empty-suite".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!("Suite '{}' has no benchmarks or fixtures", suite.name),
-            ..Default::default()
-        });
-    }
-
-    // Validate fixtures
-    for fixture in &suite.fixtures {
-        if let Node::Valid(f) = fixture {
-            validate_fixture(f, suite, doc, diagnostics);
-        }
-    }
-
-    // Validate benchmarks
-    for benchmark in &suite.benchmarks {
-        if let Node::Valid(b) = benchmark {
-            validate_benchmark(b, suite, doc, diagnostics);
-        }
-    }
-
-    let mut suite_has_iterations = false;
-    let mut suite_has_target_time = false;
-    let parsed_run_mode = suite.run_mode.clone();
-
-    // Check for baseline language existence
-    for prop in &suite.properties {
-        if let Node::Valid(p) = prop {
-            if matches!(p.name.as_str(), "suiteType" | "runMode" | "sameDataset") {
-                diagnostics.push(Diagnostic {
-                    range: doc.span_to_range(&p.span),
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("suite-semantics-in-header".to_string())),
-                    source: Some("poly-bench".to_string()),
-                    message:
-                        "Suite semantics must be declared in header: declare suite <name> <performance|memory> <timeBased|iterationBased> sameDataset: <true|false>"
-                            .to_string(),
-                    ..Default::default()
-                });
-            }
-            if p.name == "iterations" {
-                suite_has_iterations = true;
-            }
-            if p.name == "targetTime" {
-                suite_has_target_time = true;
-            }
-            if p.name == "mode" {
-                diagnostics.push(Diagnostic {
-                    range: doc.span_to_range(&p.span),
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("legacy-mode-removed".to_string())),
-                    source: Some("poly-bench".to_string()),
-                    message: "Property 'mode' is no longer supported. Use suite declaration run mode instead"
-                        .to_string(),
-                    ..Default::default()
-                });
-            }
-            if p.name == "baseline" {
-                if let PropertyValue::String(lang) = &p.value {
-                    let lang_enum = Lang::from_str(lang.as_str());
-                    if lang_enum.is_none() {
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&p.span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String("invalid-baseline".to_string())),
-                            source: Some("poly-bench".to_string()),
-                            message: format!("Unknown baseline language: '{}'", lang),
-                            ..Default::default()
-                        });
-                    } else {
-                        // Require every benchmark to implement baseline
-                        let lang_enum = lang_enum.unwrap();
-                        for bench in &suite.benchmarks {
-                            if let Node::Valid(bench_node) = bench {
-                                if !bench_node.implementations.contains_key(&lang_enum) {
-                                    diagnostics.push(Diagnostic {
-                                        range: doc.span_to_range(&bench_node.span),
-                                        severity: Some(DiagnosticSeverity::ERROR),
-                                        code: Some(NumberOrString::String(
-                                            "baseline-missing-in-benchmark".to_string(),
-                                        )),
-                                        source: Some("poly-bench".to_string()),
-                                        message: format!(
-                                            "Benchmark '{}' missing baseline language '{}'; baseline comparisons require every benchmark to implement the baseline",
-                                            bench_node.name, lang
-                                        ),
-                                        ..Default::default()
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            if p.name == "fairness" {
-                if let PropertyValue::String(mode) = &p.value {
-                    if mode != "legacy" && mode != "strict" {
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&p.span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String("invalid-fairness-mode".to_string())),
-                            source: Some("poly-bench".to_string()),
-                            message: format!(
-                                "Invalid fairness mode '{}'. Expected \"legacy\" or \"strict\"",
-                                mode
-                            ),
-                            ..Default::default()
-                        });
-                    }
-                }
-            }
-            if p.name == "asyncSamplingPolicy" {
-                if let PropertyValue::String(policy) = &p.value {
-                    if policy != "timeBudgeted" && policy != "fixedCap" {
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&p.span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String(
-                                "invalid-async-sampling-policy".to_string(),
-                            )),
-                            source: Some("poly-bench".to_string()),
-                            message: format!(
-                                "Invalid asyncSamplingPolicy '{}'. Expected \"timeBudgeted\" or \"fixedCap\"",
-                                policy
-                            ),
-                            ..Default::default()
-                        });
-                    }
-                }
-            }
-            if matches!(p.name.as_str(), "fairnessSeed" | "asyncWarmupCap" | "asyncSampleCap") {
-                if let PropertyValue::Number(value) = &p.value {
-                    if *value < 0 {
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&p.span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String(
-                                "negative-suite-property".to_string(),
-                            )),
-                            source: Some("poly-bench".to_string()),
-                            message: format!("Property '{}' must be >= 0", p.name),
-                            ..Default::default()
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    match suite.suite_type.as_deref() {
-        Some("memory") | Some("performance") => {}
-        _ => {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&suite.span),
-                severity: Some(DiagnosticSeverity::ERROR),
-                code: Some(NumberOrString::String("missing-suite-type".to_string())),
-                source: Some("poly-bench".to_string()),
-                message:
-                    "Missing/invalid suite type in declaration header (expected memory|performance)"
-                        .to_string(),
-                ..Default::default()
-            });
-        }
-    }
-    match suite.run_mode.as_deref() {
-        Some("timeBased") | Some("iterationBased") => {}
-        _ => {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&suite.span),
-                severity: Some(DiagnosticSeverity::ERROR),
-                code: Some(NumberOrString::String("missing-run-mode".to_string())),
-                source: Some("poly-bench".to_string()),
-                message:
-                    "Missing/invalid run mode in declaration header (expected timeBased|iterationBased)"
-                        .to_string(),
-                ..Default::default()
-            });
-        }
-    }
-    if suite.same_dataset.is_none() {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&suite.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("missing-same-dataset".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: "Missing sameDataset boolean in declaration header".to_string(),
-            ..Default::default()
-        });
-    }
-    if let Some(run_mode) = parsed_run_mode {
-        if run_mode == "timeBased" && suite_has_iterations {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&suite.span),
-                severity: Some(DiagnosticSeverity::ERROR),
-                code: Some(NumberOrString::String("suite-iterations-invalid".to_string())),
-                source: Some("poly-bench".to_string()),
-                message: "iterations is invalid when run mode is timeBased".to_string(),
-                ..Default::default()
-            });
-        }
-        if run_mode == "iterationBased" && suite_has_target_time {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&suite.span),
-                severity: Some(DiagnosticSeverity::ERROR),
-                code: Some(NumberOrString::String("suite-target-time-invalid".to_string())),
-                source: Some("poly-bench".to_string()),
-                message: "targetTime is invalid when run mode is iterationBased".to_string(),
-                ..Default::default()
-            });
-        }
-    }
-
-    // Line/bar charts are only valid for same-dataset suites.
-    if let Some(Node::Valid(after_block)) = &suite.after_block {
-        let has_line_or_bar = after_block.directives.iter().any(|d| {
-            if let Node::Valid(chart) = d {
-                chart.function == "drawLineChart" || chart.function == "drawBarChart"
-            } else {
-                false
-            }
-        });
-
-        if has_line_or_bar {
-            if suite.same_dataset != Some(true) {
-                for directive in &after_block.directives {
-                    if let Node::Valid(chart) = directive {
-                        if chart.function == "drawLineChart" || chart.function == "drawBarChart" {
-                            diagnostics.push(Diagnostic {
-                                range: doc.span_to_range(&chart.span),
-                                severity: Some(DiagnosticSeverity::ERROR),
-                                code: Some(NumberOrString::String(
-                                    "chart-requires-same-dataset".to_string(),
-                                )),
-                                source: Some("poly-bench".to_string()),
-                                message:
-                                    "drawLineChart/drawBarChart require suite declaration sameDataset: true"
-                                        .to_string(),
-                                ..Default::default()
-                            });
-                        }
-                    }
-                }
-            }
-            if suite.benchmarks.len() < 2 {
-                for directive in &after_block.directives {
-                    if let Node::Valid(chart) = directive {
-                        if chart.function == "drawLineChart" || chart.function == "drawBarChart" {
-                            diagnostics.push(Diagnostic {
-                                range: doc.span_to_range(&chart.span),
-                                severity: Some(DiagnosticSeverity::ERROR),
-                                code: Some(NumberOrString::String(
-                                    "chart-requires-multiple-benchmarks".to_string(),
-                                )),
-                                source: Some("poly-bench".to_string()),
-                                message: format!(
-                                    "drawLineChart/drawBarChart require at least 2 benchmarks for meaningful comparison; suite has {}",
-                                    suite.benchmarks.len()
-                                ),
-                                ..Default::default()
-                            });
-                        }
-                    }
-                }
-            }
-        }
-    }
-
-    // sameDataset: true consistency - fixture refs should match across benchmarks
-    if suite.same_dataset == Some(true) && suite.benchmarks.len() >= 2 && !suite.fixtures.is_empty()
-    {
-        let fixture_names: Vec<String> = suite
-            .fixtures
-            .iter()
-            .filter_map(|f| if let Node::Valid(fi) = f { Some(fi.name.clone()) } else { None })
-            .collect();
-
-        let mut bench_refs: Vec<(String, HashSet<String>)> = Vec::new();
-        for bench in &suite.benchmarks {
-            if let Node::Valid(b) = bench {
-                let mut refs = HashSet::new();
-                for impl_node in b.implementations.values() {
-                    if let Node::Valid(code) = impl_node {
-                        for name in &fixture_names {
-                            if code.code.contains(name.as_str()) {
-                                refs.insert(name.clone());
-                            }
-                        }
-                    }
-                }
-                bench_refs.push((b.name.clone(), refs));
-            }
-        }
-
-        // Scaling suites: each benchmark uses exactly one fixture, all different (n100→s100,
-        // n200→s200, etc.) Benchmark names have trailing numbers (n100, size8) indicating
-        // intentional scaling; skip the warning.
-        let has_trailing_num = |s: &str| {
-            let digits: String = s.chars().rev().take_while(|c| c.is_ascii_digit()).collect();
-            !digits.is_empty() && digits.chars().rev().collect::<String>().parse::<u32>().is_ok()
-        };
-        let is_scaling_suite = bench_refs.iter().all(|(_, refs)| refs.len() == 1) &&
-            bench_refs
-                .iter()
-                .map(|(_, refs)| refs.iter().next().unwrap().as_str())
-                .collect::<std::collections::HashSet<_>>()
-                .len() ==
-                bench_refs.len() &&
-            bench_refs.iter().all(|(bench_name, _)| has_trailing_num(bench_name));
-
-        if !is_scaling_suite {
-            if let Some((_, first_set)) = bench_refs.first() {
-                for (bench_name, refs) in bench_refs.iter().skip(1) {
-                    if refs != first_set {
-                        if let Some(Node::Valid(bench)) = suite.benchmarks.iter().find(|b| {
-                            if let Node::Valid(b) = b {
-                                b.name == *bench_name
-                            } else {
-                                false
-                            }
-                        }) {
-                            diagnostics.push(Diagnostic {
-                                range: doc.span_to_range(&bench.span),
-                                severity: Some(DiagnosticSeverity::WARNING),
-                                code: Some(NumberOrString::String(
-                                    "same-dataset-inconsistent-fixtures".to_string(),
-                                )),
-                                source: Some("poly-bench".to_string()),
-                                message: format!(
-                                    "Benchmark '{}' may use different fixtures than other benchmarks; sameDataset: true expects all benchmarks to operate on the same dataset",
-                                    bench_name
-                                ),
-                                ..Default::default()
-                            });
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
-fn validate_fixture(
-    fixture: &PartialFixture,
-    suite: &PartialSuite,
-    doc: &Document,
-    diagnostics: &mut Vec<Diagnostic>,
-) {
-    // Check for empty fixture
-    if fixture.hex.is_none() &&
-        fixture.data.is_none() &&
-        fixture.implementations.is_empty() &&
-        fixture.shape.is_none()
-    {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&fixture.span),
-            severity: Some(DiagnosticSeverity::WARNING),
-            code: Some(NumberOrString::String("empty-fixture".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!("Fixture '{}' has no data or implementations", fixture.name),
-            ..Default::default()
-        });
-    }
-
-    if fixture.encoding.is_some() && fixture.data.is_none() {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&fixture.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("fixture-encoding-without-data".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!(
-                "Fixture '{}' sets encoding but has no data source. Add data: ...",
-                fixture.name
-            ),
-            ..Default::default()
-        });
-    }
-
-    if fixture.selector.is_some() && fixture.format.is_none() {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&fixture.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("fixture-selector-without-format".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!(
-                "Fixture '{}' sets selector without format. Add format: json|csv",
-                fixture.name
-            ),
-            ..Default::default()
-        });
-    }
-
-    // Check for unused fixture
-    let fixture_used = suite.benchmarks.iter().any(|b| {
-        if let Node::Valid(bench) = b {
-            // Check if fixture name appears in any implementation code
-            bench.implementations.values().any(|impl_node| {
-                if let Node::Valid(code) = impl_node {
-                    code.code.contains(&fixture.name)
-                } else {
-                    false
-                }
-            })
-        } else {
-            false
+/// Report suites the error-tolerant parser could not recover
+fn collect_partial_ast_errors(doc: &Document, diagnostics: &mut Vec<Diagnostic>) {
+    for suite in &doc.partial_ast.suites {
+        if let Node::Error { span, message } = suite {
+            diagnostics.push(lint_diagnostic(
+                "parse-error",
+                doc.span_to_range(span),
+                message.clone(),
+            ));
         }
-    });
-
-    if !fixture_used {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&fixture.span),
-            severity: Some(DiagnosticSeverity::HINT),
-            code: Some(NumberOrString::String("unused-fixture".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!("Fixture '{}' appears to be unused", fixture.name),
-            tags: Some(vec![DiagnosticTag::UNNECESSARY]),
-            ..Default::default()
-        });
     }
 }
 
-fn validate_benchmark(
-    benchmark: &PartialBenchmark,
-    suite: &PartialSuite,
+/// Run poly-bench-dsl's validation over the document and map the findings that survive the
+/// project's `[lint]` overrides and inline suppressions into diagnostics
+fn validate_document(
     doc: &Document,
+    manifest: Option<&Manifest>,
+    config: &LintConfig,
+    suppressions: &Suppressions,
+    has_syntax_errors: bool,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    let suite_run_mode = suite.run_mode.as_deref();
-
-    // Check for empty benchmark
-    if benchmark.implementations.is_empty() {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&benchmark.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("empty-benchmark".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: format!("Benchmark '{}' has no implementations", benchmark.name),
-            ..Default::default()
-        });
-    }
-
-    let mut has_benchmark_iterations = false;
-    let mut has_benchmark_target_time = false;
-    for prop in &benchmark.properties {
-        if let Node::Valid(p) = prop {
-            if p.name == "mode" {
-                diagnostics.push(Diagnostic {
-                    range: doc.span_to_range(&p.span),
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    code: Some(NumberOrString::String("legacy-mode-removed".to_string())),
-                    source: Some("poly-bench".to_string()),
-                    message: "Benchmark-level mode is no longer supported. Use suite declaration run mode"
-                        .to_string(),
-                    ..Default::default()
-                });
-            }
-            if p.name == "iterations" {
-                has_benchmark_iterations = true;
+    let source = doc.source_text();
+    let filename = doc
+        .uri
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or("document.bench");
+
+    let result = match poly_bench_dsl::parse(&source, filename) {
+        Ok(file) => match manifest {
+            Some(manifest) => {
+                poly_bench_dsl::validate_project_file(&file, |lang| manifest.has_runtime(lang))
             }
-            if p.name == "targetTime" {
-                has_benchmark_target_time = true;
+            None => poly_bench_dsl::validate_file(&file),
+        },
+        Err(report) => {
+            let mut result = ValidationResult::new();
+            if let Some(violation) = report.downcast_ref::<RuleViolation>() {
+                result.add_error(
+                    ValidationError::new(violation.message.clone())
+                        .with_rule(violation.rule)
+                        .with_span(&violation.span),
+                );
+            } else if !has_syntax_errors {
+                // Tree-sitter accepted what the DSL parser rejects; point at the parser's label
+                let label = report.labels().and_then(|mut labels| labels.next());
+                let (start, end) = label.map_or((0, 0), |l| (l.offset(), l.offset() + l.len()));
+                result.add_error(
+                    ValidationError::new(report.to_string())
+                        .with_rule("parse-error")
+                        .with_span(&DslSpan::new(start, end, 1, 1)),
+                );
             }
+            result
         }
-    }
-
-    if suite_run_mode == Some("timeBased") && has_benchmark_iterations {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&benchmark.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("benchmark-iterations-invalid".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: "Benchmark iterations is invalid when suite run mode is timeBased".to_string(),
-            ..Default::default()
-        });
-    }
-    if suite_run_mode == Some("iterationBased") && has_benchmark_target_time {
-        diagnostics.push(Diagnostic {
-            range: doc.span_to_range(&benchmark.span),
-            severity: Some(DiagnosticSeverity::ERROR),
-            code: Some(NumberOrString::String("benchmark-target-time-invalid".to_string())),
-            source: Some("poly-bench".to_string()),
-            message: "Benchmark targetTime is invalid when suite run mode is iterationBased"
-                .to_string(),
-            ..Default::default()
-        });
-    }
-
-    // Check that hooks have corresponding implementations
-    for (lang, _) in &benchmark.before {
-        if !benchmark.implementations.contains_key(lang) {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&benchmark.span),
-                severity: Some(DiagnosticSeverity::WARNING),
-                code: Some(NumberOrString::String("hook-without-impl".to_string())),
-                source: Some("poly-bench".to_string()),
-                message: format!(
-                    "Benchmark '{}' has a 'before' hook for {} but no {} implementation",
-                    benchmark.name,
-                    lang.as_str(),
-                    lang.as_str()
-                ),
-                ..Default::default()
-            });
-        }
-    }
-
-    for (lang, _) in &benchmark.after {
-        if !benchmark.implementations.contains_key(lang) {
-            diagnostics.push(Diagnostic {
-                range: doc.span_to_range(&benchmark.span),
-                severity: Some(DiagnosticSeverity::WARNING),
-                code: Some(NumberOrString::String("hook-without-impl".to_string())),
-                source: Some("poly-bench".to_string()),
-                message: format!(
-                    "Benchmark '{}' has an 'after' hook for {} but no {} implementation",
-                    benchmark.name,
-                    lang.as_str(),
-                    lang.as_str()
-                ),
-                ..Default::default()
-            });
-        }
-    }
-
-    // Collect fixture names for this suite (these are valid "function-like" references)
-    let fixture_names: HashSet<String> =
-        suite
-            .fixtures
-            .iter()
-            .filter_map(|f| {
-                if let Node::Valid(fixture) = f {
-                    Some(fixture.name.clone())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-    // Validate function calls in implementations
-    for (lang, impl_node) in &benchmark.implementations {
-        if let Node::Valid(code) = impl_node {
-            let code_text = &code.code;
-
-            // Get defined helper functions for this language
-            let helper_functions = if let Some(setup_node) = suite.setups.get(lang) {
-                if let Node::Valid(setup) = setup_node {
-                    extract_helper_functions(setup, lang)
-                } else {
-                    HashSet::new()
-                }
-            } else {
-                HashSet::new()
-            };
-
-            // Extract function calls from the implementation
-            let calls = extract_function_calls(code_text, lang);
-
-            // Check each call against defined helpers and fixtures
-            for call in calls {
-                // Skip if it's a fixture reference
-                if fixture_names.contains(&call.name) {
-                    continue;
-                }
-
-                // Check if the function is defined in helpers
-                if !helper_functions.contains(&call.name) {
-                    // Calculate the position within the .bench file
-                    let call_start = code.span.start + call.start_offset;
-                    let call_end = code.span.start + call.end_offset;
-
-                    let call_span = poly_bench_syntax::Span {
-                        start: call_start,
-                        end: call_end,
-                        start_line: code.span.start_line,
-                        start_col: code.span.start_col + call.start_offset,
-                        end_line: code.span.start_line,
-                        end_col: code.span.start_col + call.end_offset,
-                    };
-
-                    if helper_functions.is_empty() && !suite.setups.contains_key(lang) {
-                        // No setup block at all - suggest adding one
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&call_span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String("undefined-function".to_string())),
-                            source: Some("poly-bench".to_string()),
-                            message: format!(
-                                "Function '{}' is not defined. Add a 'setup {} {{ helpers {{ ... }} }}' block to define it.",
-                                call.name,
-                                lang.as_str()
-                            ),
-                            ..Default::default()
-                        });
-                    } else {
-                        // Setup exists but function not found
-                        diagnostics.push(Diagnostic {
-                            range: doc.span_to_range(&call_span),
-                            severity: Some(DiagnosticSeverity::ERROR),
-                            code: Some(NumberOrString::String("undefined-function".to_string())),
-                            source: Some("poly-bench".to_string()),
-                            message: format!(
-                                "Function '{}' is not defined in 'setup {} {{ helpers {{ ... }} }}'",
-                                call.name,
-                                lang.as_str()
-                            ),
-                            ..Default::default()
-                        });
-                    }
-                }
-            }
-        }
-    }
-}
-
-/// Extract function names defined in a helpers block for a given language
-fn extract_helper_functions(setup: &StructuredSetup, lang: &Lang) -> HashSet<String> {
-    if let Some(helpers) = &setup.helpers {
-        let dsl_lang = syntax_lang_to_dsl(*lang);
-        if let Some(extractor) = get_helper_function_extractor(dsl_lang) {
-            return extractor.extract_functions(&helpers.code);
-        }
-    }
-    HashSet::new()
-}
-
-/// A function call found in code, with its position
-#[derive(Debug, Clone)]
-struct FunctionCall {
-    name: String,
-    /// Byte offset within the code string where the function name starts
-    start_offset: usize,
-    /// Byte offset where the function name ends
-    end_offset: usize,
-}
-
-/// Extract function calls from benchmark implementation code
-/// Returns function names that look like custom helper calls (not builtins or method calls)
-fn extract_function_calls(code: &str, lang: &Lang) -> Vec<FunctionCall> {
-    let mut calls = Vec::new();
-
-    let dsl_lang = syntax_lang_to_dsl(*lang);
-    let builtins: HashSet<&str> = match get_helper_function_extractor(dsl_lang) {
-        Some(extractor) => extractor.builtins().iter().copied().collect(),
-        None => return calls,
     };
 
-    // Find function call patterns: identifier followed by (
-    // We need to be careful to:
-    // 1. Skip method calls (preceded by .)
-    // 2. Skip type casts/constructors in some cases
-    // 3. Handle Rust's macro calls (name!)
-
-    let chars: Vec<char> = code.chars().collect();
-    let mut i = 0;
-
-    while i < chars.len() {
-        // Skip if we're inside a string literal
-        if chars[i] == '"' || chars[i] == '\'' || chars[i] == '`' {
-            let quote = chars[i];
-            i += 1;
-            while i < chars.len() {
-                if chars[i] == '\\' && i + 1 < chars.len() {
-                    i += 2; // Skip escaped character
-                } else if chars[i] == quote {
-                    i += 1;
-                    break;
-                } else {
-                    i += 1;
-                }
+    let result = result.apply_lints(config, suppressions);
+    let findings = result
+        .errors
+        .into_iter()
+        .map(|e| (e.rule, e.span, e.line, e.message, DiagnosticSeverity::ERROR))
+        .chain(
+            result
+                .warnings
+                .into_iter()
+                .map(|w| (w.rule, w.span, w.line, w.message, DiagnosticSeverity::WARNING)),
+        );
+    for (rule, span, line, message, reported) in findings {
+        let range = match (span, line) {
+            (Some(span), _) => {
+                let (start_line, start_col) = doc.byte_to_position(span.start);
+                let (end_line, end_col) = doc.byte_to_position(span.end);
+                Range::new(
+                    Position::new(start_line as u32, start_col as u32),
+                    Position::new(end_line as u32, end_col as u32),
+                )
             }
-            continue;
-        }
-
-        // Look for identifier start
-        if chars[i].is_alphabetic() || chars[i] == '_' {
-            let start = i;
-
-            // Collect the identifier
-            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
-                i += 1;
-            }
-
-            let name: String = chars[start..i].iter().collect();
-
-            // Skip whitespace
-            while i < chars.len() && chars[i].is_whitespace() {
-                i += 1;
-            }
-
-            // Check if followed by ( - this is a function call
-            if i < chars.len() && chars[i] == '(' {
-                // Check if this is NOT a method call (preceded by .)
-                let is_method_call = start > 0 && {
-                    let mut j = start - 1;
-                    while j > 0 && chars[j].is_whitespace() {
-                        j -= 1;
-                    }
-                    chars[j] == '.'
-                };
-
-                // Check if this is a Rust reference (&name)
-                let is_rust_ref = *lang == Lang::Rust && start > 0 && chars[start - 1] == '&';
-
-                if !is_method_call && !is_rust_ref && !builtins.contains(name.as_str()) {
-                    // Calculate byte offsets
-                    let byte_start: usize = chars[..start].iter().map(|c| c.len_utf8()).sum();
-                    let byte_end: usize = chars[..i].iter().map(|c| c.len_utf8()).sum();
-
-                    calls.push(FunctionCall {
-                        name,
-                        start_offset: byte_start,
-                        end_offset: byte_end,
-                    });
-                }
+            (None, Some(line)) => {
+                let line = line.saturating_sub(1) as u32;
+                Range::new(Position::new(line, 0), Position::new(line, 0))
             }
+            (None, None) => Range::default(),
+        };
+        let diagnostic = match rule {
+            Some(id) => Diagnostic {
+                severity: lint_severity(config.level(id)),
+                ..lint_diagnostic(id, range, message)
+            },
+            None => Diagnostic {
+                range,
+                severity: Some(reported),
+                source: Some("poly-bench".to_string()),
+                message,
+                ..Default::default()
+            },
+        };
+        if rule == Some("unused-fixture") {
+            diagnostics
+                .push(Diagnostic { tags: Some(vec![DiagnosticTag::UNNECESSARY]), ..diagnostic });
         } else {
-            i += 1;
+            diagnostics.push(diagnostic);
         }
     }
-
-    calls
 }
 
 #[cfg(test)]
@@ -973,6 +267,7 @@ mod tests {
     use super::*;
 
     fn make_doc(source: &str) -> Document {
+        poly_bench_runtime::init_helper_function_extractors();
         Document::new(Url::parse("file:///test.bench").unwrap(), source.to_string(), 1)
     }
 
@@ -1015,7 +310,7 @@ mod tests {
         // Should have error about empty benchmark
         assert!(diagnostics.iter().any(|d| {
             d.severity == Some(DiagnosticSeverity::ERROR) &&
-                d.message.contains("no implementations")
+                d.message.contains("no language implementations")
         }));
     }
 
@@ -1064,6 +359,17 @@ declare suite test performance timeBased sameDataset: true {
         let doc = make_doc(source);
         let diagnostics = compute_diagnostics(&doc);
         assert!(has_code(&diagnostics, "chart-requires-multiple-benchmarks"));
+        assert!(!has_code(&diagnostics, "missing-charting-import"));
+
+        // One finding, from validate.rs, on the first chart directive
+        let without_import = source.replace("use std::charting", "");
+        let diagnostics = compute_diagnostics(&make_doc(&without_import));
+        let missing: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.code == Some(NumberOrString::String("missing-charting-import".into())))
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].range.start.line, 10);
     }
 
     #[test]
@@ -1108,4 +414,108 @@ declare suite test performance timeBased sameDataset: true {
         let diagnostics = compute_diagnostics(&doc);
         assert!(has_code(&diagnostics, "same-dataset-inconsistent-fixtures"));
     }
+
+    #[test]
+    fn test_inline_suppression_and_docs_link() {
+        let source = r#"
+declare suite test performance timeBased sameDataset: true {
+    targetTime: 2s
+    # polybench-ignore: empty-benchmark
+    bench empty {
+    }
+    bench foo {
+        go: work()
+    }
+}
+"#;
+        let diagnostics = compute_diagnostics(&make_doc(source));
+        assert!(!has_code(&diagnostics, "empty-benchmark"));
+
+        let unsuppressed = source.replace("# polybench-ignore: empty-benchmark", "");
+        let diagnostics = compute_diagnostics(&make_doc(&unsuppressed));
+        let empty = diagnostics
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("empty-benchmark".to_string())))
+            .unwrap();
+        assert_eq!(
+            empty.code_description.as_ref().unwrap().href.as_str(),
+            "https://polybench.evm-tooling.tools/docs/core/dsl-reference#empty-benchmark"
+        );
+    }
+
+    #[test]
+    fn test_lint_config_overrides_severity() {
+        let range = Range::default();
+        let mut diagnostics = vec![
+            lint_diagnostic("unused-fixture", range, "unused"),
+            lint_diagnostic("empty-suite", range, "empty"),
+        ];
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::HINT));
+
+        let config = LintConfig::new(
+            [
+                ("unused-fixture".to_string(), LintLevel::Error),
+                ("empty-suite".to_string(), LintLevel::Allow),
+            ]
+            .into(),
+        );
+        apply_lint_config(&config, &Suppressions::default(), &mut diagnostics);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn test_undefined_function_range_on_later_line() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    bench a {
+        go: {
+            x := 1
+            encode(x)
+        }
+    }
+}
+"#;
+        let diagnostics = compute_diagnostics(&make_doc(source));
+        let undefined = diagnostics
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("undefined-function".to_string())))
+            .unwrap();
+        assert_eq!(undefined.range, Range::new(Position::new(4, 12), Position::new(4, 18)));
+    }
+
+    #[test]
+    fn test_keywords_and_zig_builtins_are_not_undefined_functions() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    bench a {
+        c: { for (int i = 0; i < 3; i++) { if (i) { work(i); } } }
+        zig: { const x: u8 = @intCast(3); _ = x; }
+    }
+}
+"#;
+        let diagnostics = compute_diagnostics(&make_doc(source));
+        let undefined: Vec<&str> = diagnostics
+            .iter()
+            .filter(|d| d.code == Some(NumberOrString::String("undefined-function".to_string())))
+            .map(|d| d.message.split('\'').nth(1).unwrap())
+            .collect();
+        assert_eq!(undefined, vec!["work"]);
+    }
+
+    #[test]
+    fn test_parser_rule_violation_reported_under_rule() {
+        let source = r#"declare suite s performance timeBased sameDataset: false {
+    fairness: "loose"
+    bench a {
+        go: work()
+    }
+}
+"#;
+        let diagnostics = compute_diagnostics(&make_doc(source));
+        let invalid = diagnostics
+            .iter()
+            .find(|d| d.code == Some(NumberOrString::String("invalid-fairness-mode".to_string())))
+            .unwrap();
+        assert_eq!(invalid.range.start, Position::new(1, 14));
+        assert_eq!(invalid.severity, Some(DiagnosticSeverity::ERROR));
+    }
 }
@@ -0,0 +1,341 @@
+//! Completion, go-to-definition, signature help and references inside embedded code
+//!
+//! Requests at a position inside a `go:`/`ts:`/... implementation, hook or setup section are
+//! forwarded to that language's server (gopls, tsserver, rust-analyzer, pyright, zls, OmniSharp,
+//! clangd) against the block's virtual file, the same way hover is. Ranges in the answer that
+//! point into the virtual file are mapped back into the `.bench` file; ranges in generated
+//! scaffolding that has no `.bench` counterpart are dropped. Locations in other files (standard
+//! library, dependencies) are passed through unchanged.
+
+use std::sync::Arc;
+
+use poly_bench_traits::{EmbeddedHoverContext, EmbeddedLspClient, LspPosition, VirtualFile};
+use tower_lsp::lsp_types::*;
+
+use crate::{
+    document::Document,
+    embedded::{extract_embedded_blocks, find_block_at_offset, EmbeddedConfig},
+    embedded_hover_context::LspEmbeddedHoverContext,
+    virtual_files::VirtualFileManagers,
+};
+
+/// The embedded server, virtual file and translated position for a `.bench` position
+struct EmbeddedTarget<'a> {
+    doc: &'a Document,
+    bench_uri: &'a Url,
+    virtual_file: Arc<dyn VirtualFile>,
+    client: Arc<dyn EmbeddedLspClient>,
+    position: LspPosition,
+}
+
+impl EmbeddedTarget<'_> {
+    /// Map a virtual-file range back into the `.bench` file
+    fn to_bench_range(&self, range: Range) -> Option<Range> {
+        let start = self.virtual_file.virtual_to_bench(range.start.line, range.start.character)?;
+        let end = self.virtual_file.virtual_to_bench(range.end.line, range.end.character)?;
+        let (start_line, start_col) = self.doc.byte_to_position(start);
+        let (end_line, end_col) = self.doc.byte_to_position(end);
+        Some(Range::new(
+            Position::new(start_line as u32, start_col as u32),
+            Position::new(end_line as u32, end_col as u32),
+        ))
+    }
+
+    /// Map a location into the `.bench` file when it points into the virtual file
+    fn to_bench_location(&self, location: Location) -> Option<Location> {
+        if location.uri.as_str() != self.virtual_file.uri() {
+            return Some(location);
+        }
+        self.to_bench_range(location.range)
+            .map(|range| Location::new(self.bench_uri.clone(), range))
+    }
+
+    fn to_bench_edit(&self, edit: TextEdit) -> Option<TextEdit> {
+        self.to_bench_range(edit.range).map(|range| TextEdit::new(range, edit.new_text))
+    }
+}
+
+/// Resolve the embedded block under `position` and sync its virtual file with the server
+fn embedded_target<'a>(
+    doc: &'a Document,
+    position: Position,
+    config: &EmbeddedConfig,
+    uri: &'a Url,
+    managers: &VirtualFileManagers,
+) -> Option<EmbeddedTarget<'a>> {
+    let offset = doc.position_to_byte(position.line as usize, position.character as usize);
+    let blocks = extract_embedded_blocks(&doc.partial_ast);
+    let block = find_block_at_offset(&blocks, offset)?;
+    let module_root = config.module_root(block.lang)?;
+    let bench_path = uri.to_file_path().ok()?.to_string_lossy().to_string();
+
+    let ctx = LspEmbeddedHoverContext {
+        doc,
+        bench_uri: uri.as_str(),
+        bench_path: &bench_path,
+        blocks: &blocks,
+        module_root,
+        bench_offset: offset,
+        managers,
+    };
+    let virtual_file = ctx.get_virtual_file(block.lang)?;
+    let client =
+        ctx.get_lsp_client(poly_bench_traits::syntax_lang_to_dsl(block.lang), module_root)?;
+    // The cursor may sit just past the last character of the block (e.g. while typing)
+    let position = virtual_file.bench_to_virtual(offset).or_else(|| {
+        virtual_file
+            .bench_to_virtual(offset.saturating_sub(1))
+            .map(|p| LspPosition { line: p.line, character: p.character + 1 })
+    })?;
+
+    if let Err(e) =
+        client.did_change(virtual_file.uri(), virtual_file.content(), virtual_file.version())
+    {
+        tracing::warn!("[embedded] failed to sync virtual {:?} file: {}", block.lang, e);
+        return None;
+    }
+    Some(EmbeddedTarget { doc, bench_uri: uri, virtual_file, client, position })
+}
+
+/// Completions from the embedded language server
+pub fn get_embedded_completion(
+    doc: &Document,
+    position: Position,
+    config: &EmbeddedConfig,
+    uri: &Url,
+    managers: &VirtualFileManagers,
+) -> Option<CompletionResponse> {
+    let target = embedded_target(doc, position, config, uri, managers)?;
+    let response = target
+        .client
+        .completion(target.virtual_file.uri(), target.position.line, target.position.character)
+        .map_err(|e| tracing::debug!("[embedded] completion failed: {}", e))
+        .ok()??;
+
+    let (is_incomplete, items) = match response {
+        CompletionResponse::Array(items) => (false, items),
+        CompletionResponse::List(list) => (list.is_incomplete, list.items),
+    };
+    let items: Vec<CompletionItem> =
+        items.into_iter().map(|item| translate_completion_item(&target, item)).collect();
+    (!items.is_empty()).then_some(CompletionResponse::List(CompletionList { is_incomplete, items }))
+}
+
+fn translate_completion_item(target: &EmbeddedTarget, mut item: CompletionItem) -> CompletionItem {
+    item.text_edit = item.text_edit.take().and_then(|edit| match edit {
+        CompletionTextEdit::Edit(edit) => target.to_bench_edit(edit).map(CompletionTextEdit::Edit),
+        CompletionTextEdit::InsertAndReplace(edit) => {
+            let insert = target.to_bench_range(edit.insert)?;
+            let replace = target.to_bench_range(edit.replace)?;
+            Some(CompletionTextEdit::InsertAndReplace(InsertReplaceEdit {
+                new_text: edit.new_text,
+                insert,
+                replace,
+            }))
+        }
+    });
+    // Auto-import edits usually land in generated scaffolding; keep the ones that map
+    item.additional_text_edits = item
+        .additional_text_edits
+        .take()
+        .map(|edits| edits.into_iter().filter_map(|edit| target.to_bench_edit(edit)).collect());
+    item
+}
+
+/// Definition of the symbol under the cursor, from the embedded language server
+pub fn get_embedded_definition(
+    doc: &Document,
+    position: Position,
+    config: &EmbeddedConfig,
+    uri: &Url,
+    managers: &VirtualFileManagers,
+) -> Option<GotoDefinitionResponse> {
+    let target = embedded_target(doc, position, config, uri, managers)?;
+    let response = target
+        .client
+        .definition(target.virtual_file.uri(), target.position.line, target.position.character)
+        .map_err(|e| tracing::debug!("[embedded] definition failed: {}", e))
+        .ok()??;
+
+    let locations: Vec<Location> = match response {
+        GotoDefinitionResponse::Scalar(location) => vec![location],
+        GotoDefinitionResponse::Array(locations) => locations,
+        GotoDefinitionResponse::Link(links) => links
+            .into_iter()
+            .map(|link| Location::new(link.target_uri, link.target_selection_range))
+            .collect(),
+    };
+    let mut locations: Vec<Location> =
+        locations.into_iter().filter_map(|l| target.to_bench_location(l)).collect();
+    match locations.len() {
+        0 => None,
+        1 => locations.pop().map(GotoDefinitionResponse::Scalar),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    }
+}
+
+/// Signature help for the call under the cursor, from the embedded language server
+pub fn get_embedded_signature_help(
+    doc: &Document,
+    position: Position,
+    config: &EmbeddedConfig,
+    uri: &Url,
+    managers: &VirtualFileManagers,
+) -> Option<SignatureHelp> {
+    let target = embedded_target(doc, position, config, uri, managers)?;
+    target
+        .client
+        .signature_help(target.virtual_file.uri(), target.position.line, target.position.character)
+        .map_err(|e| tracing::debug!("[embedded] signature help failed: {}", e))
+        .ok()
+        .flatten()
+        .filter(|help| !help.signatures.is_empty())
+}
+
+/// References to the symbol under the cursor, from the embedded language server
+pub fn get_embedded_references(
+    doc: &Document,
+    position: Position,
+    config: &EmbeddedConfig,
+    uri: &Url,
+    managers: &VirtualFileManagers,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let target = embedded_target(doc, position, config, uri, managers)?;
+    let locations = target
+        .client
+        .references(
+            target.virtual_file.uri(),
+            target.position.line,
+            target.position.character,
+            include_declaration,
+        )
+        .map_err(|e| tracing::debug!("[embedded] references failed: {}", e))
+        .ok()?;
+    let locations: Vec<Location> =
+        locations.into_iter().filter_map(|l| target.to_bench_location(l)).collect();
+    (!locations.is_empty()).then_some(locations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_syntax::Span;
+    use poly_bench_traits::{BlockType, SectionMapping};
+
+    const BENCH: &str = "suite s {\n    go: foo(x)\n}\n";
+    const VIRTUAL_URI: &str = "file:///tmp/virtual_s.go";
+
+    struct TestVirtualFile {
+        mappings: Vec<SectionMapping>,
+    }
+
+    impl VirtualFile for TestVirtualFile {
+        fn uri(&self) -> &str {
+            VIRTUAL_URI
+        }
+        fn path(&self) -> &str {
+            "/tmp/virtual_s.go"
+        }
+        fn content(&self) -> &str {
+            "package main\nfunc bench() {\n\tfoo(x)\n}\n"
+        }
+        fn version(&self) -> i32 {
+            1
+        }
+        fn section_mappings(&self) -> &[SectionMapping] {
+            &self.mappings
+        }
+        fn bench_uri(&self) -> &str {
+            "file:///tmp/s.bench"
+        }
+    }
+
+    struct TestClient;
+
+    impl EmbeddedLspClient for TestClient {
+        fn did_change(&self, _: &str, _: &str, _: i32) -> Result<(), String> {
+            Ok(())
+        }
+        fn hover(&self, _: &str, _: u32, _: u32) -> Result<Option<Hover>, String> {
+            Ok(None)
+        }
+    }
+
+    fn with_target(check: impl FnOnce(&EmbeddedTarget)) {
+        let uri = Url::parse("file:///tmp/s.bench").unwrap();
+        let doc = Document::new(uri.clone(), BENCH.to_string(), 1);
+        let start = BENCH.find("foo").unwrap();
+        let end = start + "foo(x)".len();
+        let virtual_file = TestVirtualFile {
+            mappings: vec![SectionMapping {
+                virtual_start_line: 2,
+                line_count: 1,
+                bench_span: Span::new(start, end, 1, 8, 1, 14),
+                block_type: BlockType::Benchmark,
+                code: "foo(x)".to_string(),
+                bench_indent: Some(1),
+                indent_stripped: None,
+            }],
+        };
+        let target = EmbeddedTarget {
+            doc: &doc,
+            bench_uri: &uri,
+            virtual_file: Arc::new(virtual_file),
+            client: Arc::new(TestClient),
+            position: LspPosition { line: 2, character: 1 },
+        };
+        check(&target);
+    }
+
+    fn range(line: u32, start: u32, end: u32) -> Range {
+        Range::new(Position::new(line, start), Position::new(line, end))
+    }
+
+    #[test]
+    fn test_locations_map_back_into_bench_file() {
+        with_target(|target| {
+            let virtual_uri = Url::parse(VIRTUAL_URI).unwrap();
+            let mapped =
+                target.to_bench_location(Location::new(virtual_uri.clone(), range(2, 1, 4)));
+            assert_eq!(mapped, Some(Location::new(target.bench_uri.clone(), range(1, 8, 11))));
+
+            // Generated scaffolding has no .bench counterpart
+            assert_eq!(target.to_bench_location(Location::new(virtual_uri, range(1, 5, 10))), None);
+
+            let stdlib =
+                Location::new(Url::parse("file:///usr/lib/go/fmt.go").unwrap(), range(9, 0, 3));
+            assert_eq!(target.to_bench_location(stdlib.clone()), Some(stdlib));
+        });
+    }
+
+    #[test]
+    fn test_completion_edits_are_translated_or_dropped() {
+        with_target(|target| {
+            let item = CompletionItem {
+                label: "foobar".to_string(),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range(2, 1, 4),
+                    "foobar".to_string(),
+                ))),
+                additional_text_edits: Some(vec![
+                    TextEdit::new(range(0, 12, 12), "\nimport \"bar\"".to_string()),
+                    TextEdit::new(range(2, 5, 6), "y".to_string()),
+                ]),
+                ..Default::default()
+            };
+            let item = translate_completion_item(target, item);
+            assert_eq!(
+                item.text_edit,
+                Some(CompletionTextEdit::Edit(TextEdit::new(
+                    range(1, 8, 11),
+                    "foobar".to_string()
+                )))
+            );
+            assert_eq!(
+                item.additional_text_edits,
+                Some(vec![TextEdit::new(range(1, 12, 13), "y".to_string())])
+            );
+        });
+    }
+}
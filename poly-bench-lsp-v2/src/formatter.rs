@@ -6,8 +6,8 @@
 
 use crate::document::Document;
 use poly_bench_syntax::{
-    BenchmarkKind, Node, PartialBenchmark, PartialFixture, PartialSuite, Property, PropertyValue,
-    UseStd,
+    BenchParam, BenchmarkKind, Node, PartialBenchmark, PartialFixture, PartialSuite, Property,
+    PropertyValue, UseStd,
 };
 use tower_lsp::lsp_types::{Position, Range, TextEdit};
 
@@ -405,6 +405,9 @@ fn format_fixture(fixture: &PartialFixture, config: &FormatterConfig, depth: usi
             poly_bench_syntax::FixtureData::File(path) => {
                 formatted.push_str(&format!("data: @file(\"{}\")\n", path));
             }
+            poly_bench_syntax::FixtureData::Generator(call) => {
+                formatted.push_str(&format!("data: {}\n", call));
+            }
         }
     }
     if let Some(encoding) = &fixture.encoding {
@@ -467,7 +470,12 @@ fn format_benchmark(
         (BenchmarkKind::Async, true) => "benchAsync",
         _ => "bench",
     };
-    formatted.push_str(&format!("{} {} {{\n", keyword, benchmark.name));
+    formatted.push_str(&format!(
+        "{} {}{} {{\n",
+        keyword,
+        benchmark.name,
+        format_bench_params(&benchmark.params)
+    ));
 
     // Properties
     for prop in &benchmark.properties {
@@ -512,6 +520,28 @@ fn format_benchmark(
     formatted
 }
 
+fn format_bench_params(params: &[BenchParam]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let axes: Vec<String> = params
+        .iter()
+        .map(|p| {
+            let values: Vec<String> = p
+                .values
+                .iter()
+                .map(|v| match v {
+                    // Keep full precision; `format_value` rounds floats to one decimal
+                    PropertyValue::Float(f) => format!("{:?}", f),
+                    other => format_value(other),
+                })
+                .collect();
+            format!("{} in [{}]", p.name, values.join(", "))
+        })
+        .collect();
+    format!("({})", axes.join(", "))
+}
+
 fn format_hooks(
     hooks: &std::collections::HashMap<
         poly_bench_syntax::Lang,
@@ -764,4 +794,35 @@ bench foo{go:run()}}"#;
         assert!(formatted
             .starts_with("declare suite evmBench performance timeBased sameDataset: false {"));
     }
+
+    #[test]
+    fn test_format_bench_params_roundtrip() {
+        let source = r#"suite sorting {
+    bench sort(n in [100, 1000], ratio in [0.25], label in ["a"]) {
+        go: sortInts(n)
+    }
+}
+"#;
+        let formatted = format_source(source);
+        assert!(
+            formatted.contains(
+                "bench sort(n in [100, 1000], ratio in [0.25], label in [\"a\"]) {"
+            ),
+            "Expected parameter axes in output: {}",
+            formatted
+        );
+
+        let axes = |source: &str| -> Vec<(String, Vec<String>)> {
+            let doc = make_doc(source);
+            let file = &doc.partial_ast;
+            let suite = file.suites[0].as_valid().unwrap();
+            let bench = suite.benchmarks[0].as_valid().unwrap();
+            bench
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), p.values.iter().map(|v| format!("{:?}", v)).collect()))
+                .collect()
+        };
+        assert_eq!(axes(source), axes(&formatted));
+    }
 }
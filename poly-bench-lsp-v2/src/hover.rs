@@ -243,6 +243,14 @@ fn get_property_documentation(name: &str) -> String {
             "**memory** (removed)\n\nUse `suiteType: memory` in the suite declaration instead."
                 .to_string()
         }
+        "counters" => {
+            "**counters**: `boolean`\n\nCollect hardware performance counters (Linux `perf_event_open`) for the timed region."
+                .to_string()
+        }
+        "equivalent" => {
+            "**equivalent**: `boolean`\n\nFail the benchmark when languages return different values."
+                .to_string()
+        }
         "outlierDetection" => {
             "**outlierDetection**: `boolean`\n\nEnable IQR-based outlier detection and removal."
                 .to_string()
@@ -251,6 +259,12 @@ fn get_property_documentation(name: &str) -> String {
             "**cvThreshold**: `number`\n\nCoefficient of variation threshold (%) for stability warnings."
                 .to_string()
         }
+        "ciTarget" => {
+            "**ciTarget**: `number`\n\nKeep adding runs until the 95% CI half-width is within this percentage of the median."
+                .to_string()
+        }
+        "maxRuns" => "**maxRuns**: `number`\n\nRun budget for `ciTarget`.".to_string(),
+        "maxTime" => "**maxTime**: `duration`\n\nTime budget for `ciTarget`.".to_string(),
         "fairness" => {
             "**fairness**: `\"legacy\" | \"strict\"`\n\nRuntime scheduling fairness mode.\n- `legacy`: previous grouped execution order\n- `strict`: interleaved per-run ordering across runtimes (fairness-first)"
                 .to_string()
@@ -416,6 +430,25 @@ fn keyword_docs(word: &str) -> Option<&'static str> {
             eliminating the benchmarked code.\n\n\
             Default: `true`",
         ),
+        "counters" => Some(
+            "**counters:** `true | false`\n\n\
+            Collect hardware performance counters for the timed region.\n\n\
+            On Linux the Go, Rust, C and Zig harnesses read instructions, cycles,\n\
+            cache misses and branch misses via `perf_event_open` and report them\n\
+            per operation. If the kernel refuses counters (for example because of\n\
+            `perf_event_paranoid`), the run continues and the reason is reported.\n\n\
+            Default: `false`",
+        ),
+        "equivalent" => Some(
+            "**equivalent:** `true | false`\n\n\
+            Check that every language computes the same answer.\n\n\
+            After the run, each language's returned value is normalized to\n\
+            canonical JSON (byte sequences and `0x` hex strings become byte arrays,\n\
+            numbers compare by value) and compared. If implementations disagree,\n\
+            the benchmark fails and the report shows where the values differ.\n\
+            Requires `sink: true`. C and Zig results are not captured.\n\n\
+            Default: `false`",
+        ),
         "outlierDetection" => Some(
             "**outlierDetection:** `true | false`\n\n\
             Enable IQR-based outlier detection and removal.\n\n\
@@ -431,6 +464,25 @@ fn keyword_docs(word: &str) -> Option<&'static str> {
             is shown indicating the results may be unstable.\n\n\
             Default: `5` (5%)",
         ),
+        "ciTarget" => Some(
+            "**ciTarget:** `<number>`\n\n\
+            Adaptive run count: keep adding runs until the 95% confidence\n\
+            interval of the median is within this percentage of the median\n\
+            for every language (e.g. `2` = ±2%).\n\n\
+            `count` runs (at least 3) always happen first; `maxRuns` and\n\
+            `maxTime` bound the rest. Ignored for memory and parallel suites.",
+        ),
+        "maxRuns" => Some(
+            "**maxRuns:** `<number>`\n\n\
+            Maximum runs per benchmark when `ciTarget` is set.\n\n\
+            Default: `30`",
+        ),
+        "maxTime" => Some(
+            "**maxTime:** `<duration>`\n\n\
+            Stop adding runs once a benchmark has run this long when\n\
+            `ciTarget` is set.\n\n\
+            Examples: `30s`, `2m`",
+        ),
         "memory" => Some(
             "**memory** (removed)\n\n\
             Use `suiteType: memory` in the suite declaration instead.\n\n\
@@ -12,15 +12,21 @@
 //! - **Better diagnostics**: Syntax errors from Tree-sitter + semantic validation
 //! - **Embedded language hover**: Full hover support for Go, TypeScript, Rust, and Python code
 
+pub mod bench_runner;
+pub mod code_actions;
+pub mod code_lens;
 pub mod diagnostics;
 pub mod document;
 pub mod embedded;
 pub mod embedded_diagnostic_context;
 pub mod embedded_diagnostics;
 pub mod embedded_hover_context;
+pub mod embedded_requests;
 pub mod formatter;
 pub mod hover;
 pub mod hover_cache;
+pub mod navigation;
+pub mod result_hints;
 pub mod semantic_tokens;
 pub mod server;
 pub mod virtual_files;
@@ -43,8 +49,35 @@ pub async fn run_server() {
         .init();
 
     let stdin = tokio::io::stdin();
-    let stdout = tokio::io::stdout();
+    let stdout = protocol_stdout();
 
     let (service, socket) = LspService::new(|client| PolyBenchLanguageServer::new(client));
     Server::new(stdin, stdout, socket).serve(service).await;
 }
+
+/// Writer for protocol messages. Benchmarks started from code lenses run in-process and the
+/// executor prints its progress to stdout, so on Unix the protocol gets its own copy of the
+/// stdout descriptor and fd 1 is pointed at stderr.
+#[cfg(unix)]
+fn protocol_stdout() -> Box<dyn tokio::io::AsyncWrite + Send + Unpin> {
+    use std::os::fd::FromRawFd;
+
+    // SAFETY: dup/dup2 on the standard descriptors; the duplicate is owned by the File below
+    let fd = unsafe { libc::dup(libc::STDOUT_FILENO) };
+    if fd < 0 {
+        return Box::new(tokio::io::stdout());
+    }
+    if unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+        unsafe { libc::close(fd) };
+        return Box::new(tokio::io::stdout());
+    }
+    let file = unsafe { std::fs::File::from_raw_fd(fd) };
+    Box::new(tokio::fs::File::from_std(file))
+}
+
+/// Elsewhere stdout stays the protocol stream, so the server does not offer
+/// [`bench_runner::RUN_COMMAND`] (see [`bench_runner::RUN_SUPPORTED`]).
+#[cfg(not(unix))]
+fn protocol_stdout() -> Box<dyn tokio::io::AsyncWrite + Send + Unpin> {
+    Box::new(tokio::io::stdout())
+}
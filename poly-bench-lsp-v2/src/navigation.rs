@@ -0,0 +1,859 @@
+//! Go-to-definition, find-references and rename for DSL symbols
+//!
+//! Navigable symbols are fixtures, helper functions declared in
+//! `setup <lang> { helpers { ... } }`, benchmarks named by chart `includeBenchmarks`,
+//! `excludeBenchmarks` and `baselineBenchmark`, and `@file(...)` paths. Fixtures, helpers and
+//! benchmarks are scoped to their suite (suite names are unique within a project), helpers
+//! additionally to their language. `@file` paths resolve relative to the `.bench` file, like
+//! `poly-bench run` does.
+//!
+//! References inside embedded code are found lexically: any identifier token in a code block of
+//! the suite that matches the symbol name and is not a member access (`x.name`). The tokenizer
+//! knows each language's string, character and comment syntax, so text inside those is never
+//! taken for a reference. Every other identifier is recorded too, so a rename cannot capture a
+//! name the code already uses.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+};
+
+use once_cell::sync::Lazy;
+use poly_bench_syntax::Lang;
+use regex::Regex;
+use ropey::Rope;
+use tower_lsp::lsp_types::*;
+use tree_sitter::{Node, Tree};
+
+/// Chart parameters whose string values name benchmarks
+const BENCHMARK_CHART_PARAMS: &[&str] =
+    &["includeBenchmarks", "excludeBenchmarks", "baselineBenchmark", "baseline"];
+
+static IDENTIFIER: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap());
+
+/// What kind of thing a symbol names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Fixture,
+    Helper(Lang),
+    Benchmark,
+    File,
+    /// Any other identifier in a language's embedded code. Never navigable; recorded so renames
+    /// can reject names the code already uses.
+    Identifier(Lang),
+}
+
+impl SymbolKind {
+    fn label(&self) -> &'static str {
+        match self {
+            SymbolKind::Fixture => "fixture",
+            SymbolKind::Helper(_) => "helper",
+            SymbolKind::Benchmark => "benchmark",
+            SymbolKind::File => "file",
+            SymbolKind::Identifier(_) => "identifier",
+        }
+    }
+}
+
+/// A navigable symbol. `suite` is `None` for `@file` paths, whose `name` is the resolved path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    pub suite: Option<String>,
+    pub name: String,
+}
+
+/// One place a symbol is declared or used
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence {
+    pub symbol: Symbol,
+    pub location: Location,
+    pub is_definition: bool,
+}
+
+/// Collect every symbol occurrence in a `.bench` document
+pub fn collect_occurrences(uri: &Url, source: &str, tree: &Tree) -> Vec<Occurrence> {
+    let mut collector = Collector {
+        uri,
+        source,
+        rope: Rope::from_str(source),
+        base_dir: uri.to_file_path().ok().and_then(|p| p.parent().map(Path::to_path_buf)),
+        occurrences: Vec::new(),
+    };
+    let root = tree.root_node();
+    for suite in children_of_kind(root, "suite") {
+        collector.collect_suite(suite);
+    }
+    collector.collect_file_refs(root);
+    collector.occurrences
+}
+
+/// The navigable occurrence under the cursor, if any. Like the occurrence ranges, `position`
+/// counts UTF-16 code units, as LSP clients send it.
+pub fn occurrence_at(occurrences: &[Occurrence], position: Position) -> Option<&Occurrence> {
+    occurrences.iter().find(|o| {
+        let range = o.location.range;
+        !matches!(o.symbol.kind, SymbolKind::Identifier(_)) &&
+            range.start.line == position.line &&
+            range.start.character <= position.character &&
+            position.character <= range.end.character
+    })
+}
+
+/// Where a symbol is declared. `@file` paths resolve to the file itself when it exists.
+pub fn definitions(symbol: &Symbol, workspace: &[Occurrence]) -> Vec<Location> {
+    if symbol.kind == SymbolKind::File {
+        let path = Path::new(&symbol.name);
+        return match Url::from_file_path(path) {
+            Ok(uri) if path.is_file() => vec![Location::new(uri, Range::default())],
+            _ => Vec::new(),
+        };
+    }
+    workspace
+        .iter()
+        .filter(|o| o.is_definition && o.symbol == *symbol)
+        .map(|o| o.location.clone())
+        .collect()
+}
+
+/// Every use of a symbol, optionally including its declaration
+pub fn references(
+    symbol: &Symbol,
+    workspace: &[Occurrence],
+    include_declaration: bool,
+) -> Vec<Location> {
+    workspace
+        .iter()
+        .filter(|o| o.symbol == *symbol && (include_declaration || !o.is_definition))
+        .map(|o| o.location.clone())
+        .collect()
+}
+
+/// Edits renaming a symbol everywhere it occurs, or why the rename would be unsafe. `origin` is
+/// the document the rename was started from.
+pub fn rename(
+    symbol: &Symbol,
+    workspace: &[Occurrence],
+    new_name: &str,
+    origin: &Url,
+) -> Result<WorkspaceEdit, String> {
+    if symbol.kind == SymbolKind::File {
+        return rename_file(symbol, workspace, new_name, origin);
+    }
+    if !IDENTIFIER.is_match(new_name) {
+        return Err(format!("`{}` is not a valid {} name", new_name, symbol.kind.label()));
+    }
+    if new_name == symbol.name {
+        return Ok(WorkspaceEdit::default());
+    }
+    let suite = symbol.suite.as_deref().unwrap_or_default();
+    if let Some(clash) = workspace
+        .iter()
+        .find(|o| o.is_definition && o.symbol.name == new_name && clashes(symbol, &o.symbol))
+    {
+        return Err(format!(
+            "a {} named `{}` already exists in suite `{}`",
+            clash.symbol.kind.label(),
+            new_name,
+            suite
+        ));
+    }
+    if workspace.iter().any(|o| {
+        matches!(o.symbol.kind, SymbolKind::Identifier(_)) &&
+            o.symbol.name == new_name &&
+            clashes(symbol, &o.symbol)
+    }) {
+        return Err(format!("`{}` is already used in the code of suite `{}`", new_name, suite));
+    }
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for location in references(symbol, workspace, true) {
+        changes
+            .entry(location.uri)
+            .or_default()
+            .push(TextEdit::new(location.range, new_name.to_string()));
+    }
+    Ok(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+/// Whether a declaration named like the rename target would collide with `symbol`. Fixtures and
+/// helpers share the generated code's namespace, so they clash with each other.
+fn clashes(symbol: &Symbol, other: &Symbol) -> bool {
+    if symbol.suite != other.suite {
+        return false;
+    }
+    match (symbol.kind, other.kind) {
+        (SymbolKind::Benchmark, SymbolKind::Benchmark) => true,
+        (SymbolKind::Fixture, SymbolKind::Fixture | SymbolKind::Helper(_)) => true,
+        (SymbolKind::Helper(_), SymbolKind::Fixture) => true,
+        (SymbolKind::Helper(a), SymbolKind::Helper(b)) => a == b,
+        (SymbolKind::Fixture, SymbolKind::Identifier(_)) => true,
+        (SymbolKind::Helper(a), SymbolKind::Identifier(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Rename a fixture file: move it and rewrite every `@file(...)` that points at it. `new_name` is
+/// relative to the `.bench` file the rename started from, like the path it replaces.
+fn rename_file(
+    symbol: &Symbol,
+    workspace: &[Occurrence],
+    new_name: &str,
+    origin: &Url,
+) -> Result<WorkspaceEdit, String> {
+    if new_name.trim().is_empty() || new_name.contains(['"', '\n', '\r']) {
+        return Err(format!("`{}` is not a valid file path", new_name));
+    }
+    let old_path = Path::new(&symbol.name);
+    let Some(origin_dir) =
+        origin.to_file_path().ok().and_then(|p| p.parent().map(Path::to_path_buf))
+    else {
+        return Err("renaming a fixture file needs a saved .bench file".to_string());
+    };
+    let new_path = normalize(&origin_dir.join(new_name));
+    if new_path == old_path {
+        return Ok(WorkspaceEdit::default());
+    }
+    if new_path.exists() {
+        return Err(format!("{} already exists", new_path.display()));
+    }
+
+    let mut operations = Vec::new();
+    if old_path.is_file() {
+        let (Ok(old_uri), Ok(new_uri)) =
+            (Url::from_file_path(old_path), Url::from_file_path(&new_path))
+        else {
+            return Err(format!("cannot rename {}", old_path.display()));
+        };
+        operations.push(DocumentChangeOperation::Op(ResourceOp::Rename(RenameFile {
+            old_uri,
+            new_uri,
+            options: None,
+            annotation_id: None,
+        })));
+    }
+    let mut edits: HashMap<Url, Vec<OneOf<TextEdit, AnnotatedTextEdit>>> = HashMap::new();
+    for location in references(symbol, workspace, true) {
+        let dir = location
+            .uri
+            .to_file_path()
+            .ok()
+            .and_then(|p| p.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| origin_dir.clone());
+        let text = relative_path(&dir, &new_path).to_string_lossy().replace('\\', "/");
+        edits
+            .entry(location.uri)
+            .or_default()
+            .push(OneOf::Left(TextEdit::new(location.range, text)));
+    }
+    for (uri, edits) in edits {
+        operations.push(DocumentChangeOperation::Edit(TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+            edits,
+        }));
+    }
+    Ok(WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Operations(operations)),
+        ..Default::default()
+    })
+}
+
+struct Collector<'a> {
+    uri: &'a Url,
+    source: &'a str,
+    rope: Rope,
+    base_dir: Option<PathBuf>,
+    occurrences: Vec<Occurrence>,
+}
+
+impl<'a> Collector<'a> {
+    fn collect_suite(&mut self, suite: Node) {
+        let Some(suite_name) = suite.child_by_field_name("name").map(|n| self.text(n).to_string())
+        else {
+            return;
+        };
+        let Some(body) = children_of_kind(suite, "suite_body").into_iter().next() else { return };
+
+        let mut fixtures = HashSet::new();
+        let mut helpers: HashSet<(Lang, String)> = HashSet::new();
+        let mut definition_offsets = HashSet::new();
+        for item in named_children(body) {
+            match item.kind() {
+                "fixture" | "benchmark" => {
+                    let Some(name) = item.child_by_field_name("name") else { continue };
+                    let kind = if item.kind() == "fixture" {
+                        fixtures.insert(self.text(name).to_string());
+                        SymbolKind::Fixture
+                    } else {
+                        SymbolKind::Benchmark
+                    };
+                    self.push(kind, Some(&suite_name), self.text(name), name.start_byte(), true);
+                }
+                "setup_block" => {
+                    let Some(lang) = language_of(item, self.source) else { continue };
+                    for code in descendants_of_kind(item, "embedded_code") {
+                        if code.parent().and_then(|p| p.parent()).map(|p| p.kind()) !=
+                            Some("helpers_section")
+                        {
+                            continue;
+                        }
+                        for (offset, name) in helper_definitions(lang, self.text(code)) {
+                            let start = code.start_byte() + offset;
+                            helpers.insert((lang, name.to_string()));
+                            definition_offsets.insert(start);
+                            self.push(
+                                SymbolKind::Helper(lang),
+                                Some(&suite_name),
+                                name,
+                                start,
+                                true,
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Uses inside embedded code
+        let mut code_nodes = descendants_of_kind(body, "embedded_code");
+        code_nodes.extend(descendants_of_kind(body, "inline_code"));
+        for code in code_nodes {
+            let Some(lang) = enclosing_language(code, self.source) else { continue };
+            for (offset, name) in code_identifiers(lang, self.text(code)) {
+                let start = code.start_byte() + offset;
+                if definition_offsets.contains(&start) {
+                    continue;
+                }
+                let kind = if helpers.contains(&(lang, name.to_string())) {
+                    SymbolKind::Helper(lang)
+                } else if fixtures.contains(name) {
+                    SymbolKind::Fixture
+                } else {
+                    SymbolKind::Identifier(lang)
+                };
+                self.push(kind, Some(&suite_name), name, start, false);
+            }
+        }
+
+        // Benchmarks named by chart directives
+        for param in descendants_of_kind(body, "chart_param") {
+            let Some(param_name) = param.child_by_field_name("name") else { continue };
+            if !BENCHMARK_CHART_PARAMS.contains(&self.text(param_name)) {
+                continue;
+            }
+            for content in descendants_of_kind(param, "string_content") {
+                let name = self.text(content);
+                self.push(
+                    SymbolKind::Benchmark,
+                    Some(&suite_name),
+                    name,
+                    content.start_byte(),
+                    false,
+                );
+            }
+        }
+    }
+
+    fn collect_file_refs(&mut self, root: Node) {
+        for file_ref in descendants_of_kind(root, "file_ref") {
+            let Some(content) = descendants_of_kind(file_ref, "string_content").into_iter().next()
+            else {
+                continue;
+            };
+            let raw = Path::new(self.text(content));
+            let resolved = match &self.base_dir {
+                Some(base) if raw.is_relative() => normalize(&base.join(raw)),
+                _ => normalize(raw),
+            };
+            let name = resolved.to_string_lossy().to_string();
+            let range = self.range(content.start_byte(), content.end_byte());
+            self.occurrences.push(Occurrence {
+                symbol: Symbol { kind: SymbolKind::File, suite: None, name },
+                location: Location::new(self.uri.clone(), range),
+                is_definition: false,
+            });
+        }
+    }
+
+    fn push(&mut self, kind: SymbolKind, suite: Option<&str>, name: &str, start: usize, def: bool) {
+        let range = self.range(start, start + name.len());
+        self.occurrences.push(Occurrence {
+            symbol: Symbol { kind, suite: suite.map(str::to_string), name: name.to_string() },
+            location: Location::new(self.uri.clone(), range),
+            is_definition: def,
+        });
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        node.utf8_text(self.source.as_bytes()).unwrap_or("")
+    }
+
+    fn range(&self, start: usize, end: usize) -> Range {
+        Range::new(self.position(start), self.position(end))
+    }
+
+    /// LSP position of a byte offset; the column counts UTF-16 code units
+    fn position(&self, byte: usize) -> Position {
+        let char_idx = self.rope.byte_to_char(byte.min(self.rope.len_bytes()));
+        let line = self.rope.char_to_line(char_idx);
+        let line_start = self.rope.char_to_utf16_cu(self.rope.line_to_char(line));
+        Position::new(line as u32, (self.rope.char_to_utf16_cu(char_idx) - line_start) as u32)
+    }
+}
+
+/// Top-level helper functions declared in a `helpers { ... }` block: `(byte offset, name)`.
+/// Only declarations at the block's outermost indentation count, so nested functions and
+/// methods are not mistaken for helpers.
+fn helper_definitions(lang: Lang, code: &str) -> Vec<(usize, &str)> {
+    static PATTERNS: Lazy<HashMap<Lang, Regex>> = Lazy::new(|| {
+        [
+            (Lang::Go, r"^func\s+([A-Za-z_]\w*)\s*[\[(]"),
+            (
+                Lang::TypeScript,
+                r"^(?:export\s+)?(?:(?:async\s+)?function\s*\*?\s*([A-Za-z_$][\w$]*)|(?:const|let)\s+([A-Za-z_$][\w$]*)\s*=\s*(?:async\s*)?(?:\([^)]*\)|[A-Za-z_$][\w$]*)\s*(?::[^=]*)?=>)",
+            ),
+            (Lang::Rust, r"^(?:pub(?:\([^)]*\))?\s+)?(?:const\s+)?(?:async\s+)?(?:unsafe\s+)?fn\s+([A-Za-z_]\w*)"),
+            (Lang::Python, r"^(?:async\s+)?def\s+([A-Za-z_]\w*)"),
+            (Lang::Zig, r"^(?:pub\s+)?(?:inline\s+)?fn\s+([A-Za-z_]\w*)"),
+            (
+                Lang::C,
+                r"^(?:(?:static|inline|extern|const|unsigned|signed|struct)\s+)*[A-Za-z_]\w*[\s\*]+([A-Za-z_]\w*)\s*\(",
+            ),
+            (
+                Lang::CSharp,
+                r"^(?:(?:public|private|internal|protected|static|async|unsafe|override|virtual)\s+)*[A-Za-z_][\w<>\[\],\.\? ]*\s+([A-Za-z_]\w*)\s*(?:<[^>]*>)?\s*\(",
+            ),
+        ]
+        .into_iter()
+        .map(|(lang, pattern)| (lang, Regex::new(pattern).unwrap()))
+        .collect()
+    });
+    const NOT_NAMES: &[&str] = &["if", "for", "while", "switch", "return", "new", "else", "sizeof"];
+
+    let Some(pattern) = PATTERNS.get(&lang) else { return Vec::new() };
+    let indent_of = |line: &str| line.len() - line.trim_start().len();
+    let Some(outer) = code.lines().filter(|l| !l.trim().is_empty()).map(indent_of).min() else {
+        return Vec::new();
+    };
+
+    let mut found = Vec::new();
+    let mut offset = 0;
+    for line in code.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        if line.trim().is_empty() || indent_of(line) != outer {
+            continue;
+        }
+        let trimmed = &line[outer..];
+        let Some(caps) = pattern.captures(trimmed) else { continue };
+        let Some(name) = caps.iter().skip(1).flatten().next() else { continue };
+        if NOT_NAMES.contains(&name.as_str()) {
+            continue;
+        }
+        found.push((line_start + outer + name.start(), name.as_str()));
+    }
+    found
+}
+
+/// Identifier tokens in `lang` code: `(byte offset, name)`. String and character literals,
+/// comments, Rust lifetimes and member accesses (`x.name`) are skipped; TypeScript template
+/// literals are followed into their `${...}` interpolations.
+fn code_identifiers(lang: Lang, code: &str) -> Vec<(usize, &str)> {
+    let mut found = Vec::new();
+    // Brace depth within each open `${...}` interpolation
+    let mut interpolations: Vec<usize> = Vec::new();
+    let mut i = 0;
+    while let Some(c) = code[i..].chars().next() {
+        let rest = &code[i..];
+        if lang == Lang::TypeScript && (c == '`' || (c == '}' && interpolations.last() == Some(&0)))
+        {
+            if c == '}' {
+                interpolations.pop();
+            }
+            let (len, opens) = template_text_len(&rest[1..]);
+            i += 1 + len;
+            if opens {
+                interpolations.push(0);
+            }
+            continue;
+        }
+        if let Some(len) = skipped_len(lang, rest) {
+            i += len;
+            continue;
+        }
+        if let Some(depth) = interpolations.last_mut() {
+            match c {
+                '{' => *depth += 1,
+                '}' => *depth -= 1,
+                _ => {}
+            }
+        }
+        if c.is_ascii_alphanumeric() || c == '_' {
+            let len = rest
+                .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+                .unwrap_or(rest.len());
+            // Digits start number literals (`0x1f`), not identifiers
+            if !c.is_ascii_digit() && !code[..i].ends_with('.') {
+                found.push((i, &rest[..len]));
+            }
+            i += len;
+            continue;
+        }
+        i += c.len_utf8();
+    }
+    found
+}
+
+/// Length of the TypeScript template text at the start of `text` (just after a backtick or the
+/// `}` closing an interpolation), and whether it ends by opening an interpolation
+fn template_text_len(text: &str) -> (usize, bool) {
+    let mut chars = text.char_indices();
+    while let Some((j, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '`' => return (j + 1, false),
+            '$' if text[j..].starts_with("${") => return (j + 2, true),
+            _ => {}
+        }
+    }
+    (text.len(), false)
+}
+
+/// Length of the comment or literal `s` starts with, if any
+fn skipped_len(lang: Lang, s: &str) -> Option<usize> {
+    static RUST_RAW: Lazy<Regex> = Lazy::new(|| Regex::new(r##"^b?r(#*)""##).unwrap());
+    static RUST_CHAR: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^b?'(?:\\(?:u\{[0-9A-Fa-f]+\}|x[0-9A-Fa-f]{2}|.)|[^\\'\n])'").unwrap()
+    });
+    static RUST_LIFETIME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^'[A-Za-z_]\w*").unwrap());
+    static PYTHON_STRING: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r#"^[rRbBuUfF]{0,2}('''|"""|'|")"#).unwrap());
+    static CSHARP_VERBATIM: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^(?:\$@|@\$|@)""#).unwrap());
+
+    let line_end = s.find('\n').unwrap_or(s.len());
+    let comment = if lang == Lang::Python { "#" } else { "//" };
+    if s.starts_with(comment) || (lang == Lang::Zig && s.starts_with("\\\\")) {
+        return Some(line_end);
+    }
+    if !matches!(lang, Lang::Python | Lang::Zig) && s.starts_with("/*") {
+        return Some(s[2..].find("*/").map_or(s.len(), |j| j + 4));
+    }
+    match lang {
+        Lang::Rust => {
+            if let Some(caps) = RUST_RAW.captures(s) {
+                let close = format!("\"{}", &caps[1]);
+                return Some(literal_len(s, caps[0].len(), &close, false, true));
+            }
+            if let Some(m) = RUST_CHAR.find(s).or_else(|| RUST_LIFETIME.find(s)) {
+                return Some(m.end());
+            }
+            let start = usize::from(s.starts_with("b\""));
+            if s[start..].starts_with('"') {
+                return Some(literal_len(s, start + 1, "\"", true, true));
+            }
+            return None;
+        }
+        Lang::Python => {
+            let caps = PYTHON_STRING.captures(s)?;
+            let quote = &caps[1];
+            return Some(literal_len(s, caps[0].len(), quote, true, quote.len() == 3));
+        }
+        Lang::CSharp => {
+            if let Some(m) = CSHARP_VERBATIM.find(s) {
+                // A doubled `""` inside reads as two adjacent literals, which skips the same text
+                return Some(literal_len(s, m.end(), "\"", false, true));
+            }
+        }
+        Lang::Go if s.starts_with('`') => return Some(literal_len(s, 1, "`", false, true)),
+        _ => {}
+    }
+    let quote = s.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    Some(literal_len(s, 1, quote.encode_utf8(&mut [0; 4]), true, false))
+}
+
+/// Length of the literal in `s` whose body starts at byte `from` and ends with `close`.
+/// Backslash escapes apply when `escapes` is set; an unterminated literal runs to the end of the
+/// line, or to the end of `s` when `multiline` is set.
+fn literal_len(s: &str, from: usize, close: &str, escapes: bool, multiline: bool) -> usize {
+    let mut chars = s[from..].char_indices();
+    while let Some((j, c)) = chars.next() {
+        let at = from + j;
+        if s[at..].starts_with(close) {
+            return at + close.len();
+        }
+        match c {
+            '\\' if escapes => {
+                chars.next();
+            }
+            '\n' if !multiline => return at,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// Language of a node carrying a `language` field (setup blocks, implementations, hooks)
+fn language_of(node: Node, source: &str) -> Option<Lang> {
+    let tag = node.child_by_field_name("language")?;
+    Lang::from_str(tag.utf8_text(source.as_bytes()).ok()?)
+}
+
+/// Language of the nearest enclosing setup block, implementation or hook
+fn enclosing_language(node: Node, source: &str) -> Option<Lang> {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if matches!(n.kind(), "setup_block" | "language_implementation" | "hook_flat") {
+            return language_of(n, source);
+        }
+        current = n.parent();
+    }
+    None
+}
+
+fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor).collect()
+}
+
+fn children_of_kind<'a>(node: Node<'a>, kind: &str) -> Vec<Node<'a>> {
+    named_children(node).into_iter().filter(|n| n.kind() == kind).collect()
+}
+
+fn descendants_of_kind<'a>(node: Node<'a>, kind: &str) -> Vec<Node<'a>> {
+    let mut found = Vec::new();
+    let mut stack = vec![node];
+    while let Some(n) = stack.pop() {
+        if n.kind() == kind {
+            found.push(n);
+            continue;
+        }
+        let mut children = named_children(n);
+        children.reverse();
+        stack.extend(children);
+    }
+    found
+}
+
+/// Resolve `.` and `..` components without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push("..");
+                }
+            }
+            other => out.push(other.as_os_str()),
+        }
+    }
+    out
+}
+
+/// `target` relative to `dir` (both absolute and normalized)
+fn relative_path(dir: &Path, target: &Path) -> PathBuf {
+    let dir: Vec<_> = dir.components().collect();
+    let target: Vec<_> = target.components().collect();
+    let common = dir.iter().zip(&target).take_while(|(a, b)| a == b).count();
+    let mut out = PathBuf::new();
+    for _ in common..dir.len() {
+        out.push("..");
+    }
+    for component in &target[common..] {
+        out.push(component.as_os_str());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_syntax::IncrementalParser;
+
+    const SOURCE: &str = r#"suite hashing {
+    setup go {
+        helpers {
+            func hashGo(data []byte) []byte {
+                return inner(data)
+            }
+        }
+    }
+
+    setup ts {
+        helpers {
+            function hashTs(data: Uint8Array) { return data }
+        }
+    }
+
+    fixture payload {
+        hex: @file("fixtures/payload.hex")
+    }
+
+    bench short {
+        go: hashGo(payload)
+        ts: hashTs(payload)
+    }
+
+    bench long {
+        go: hashGo(payload.Clone())
+    }
+
+    after {
+        charting.drawTable(includeBenchmarks: ["short", "long"])
+    }
+}
+"#;
+
+    fn occurrences(source: &str) -> Vec<Occurrence> {
+        let tree = IncrementalParser::new().parse(source, None);
+        collect_occurrences(&uri(), source, &tree)
+    }
+
+    fn uri() -> Url {
+        Url::parse("file:///work/bench/hash.bench").unwrap()
+    }
+
+    fn symbol(kind: SymbolKind, name: &str) -> Symbol {
+        Symbol { kind, suite: Some("hashing".to_string()), name: name.to_string() }
+    }
+
+    #[test]
+    fn test_collects_definitions_and_references() {
+        let occs = occurrences(SOURCE);
+
+        let payload = symbol(SymbolKind::Fixture, "payload");
+        let defs = definitions(&payload, &occs);
+        assert_eq!(defs.len(), 1);
+        assert_eq!(defs[0].range.start, Position::new(15, 12));
+        assert_eq!(references(&payload, &occs, false).len(), 3);
+
+        let hash_go = symbol(SymbolKind::Helper(Lang::Go), "hashGo");
+        assert_eq!(definitions(&hash_go, &occs)[0].range.start, Position::new(3, 17));
+        assert_eq!(references(&hash_go, &occs, true).len(), 3);
+        // Helpers are per language: ts code never references the Go helper
+        assert!(references(&hash_go, &occs, false).iter().all(|l| l.range.start.line != 21));
+
+        let short = symbol(SymbolKind::Benchmark, "short");
+        let refs = references(&short, &occs, false);
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].range.start, Position::new(29, 48));
+
+        let at = occurrence_at(&occs, Position::new(16, 25)).unwrap();
+        assert_eq!(at.symbol.kind, SymbolKind::File);
+        assert_eq!(at.symbol.name, "/work/bench/fixtures/payload.hex");
+    }
+
+    #[test]
+    fn test_rename_rewrites_every_occurrence_and_rejects_clashes() {
+        let occs = occurrences(SOURCE);
+        let payload = symbol(SymbolKind::Fixture, "payload");
+
+        let edit = rename(&payload, &occs, "data32", &uri()).unwrap();
+        let changes = edit.changes.unwrap();
+        let edits = &changes[&uri()];
+        assert_eq!(edits.len(), 4);
+        assert!(edits.iter().all(|e| e.new_text == "data32"));
+
+        assert!(rename(&payload, &occs, "hashGo", &uri()).unwrap_err().contains("already exists"));
+        assert!(rename(&payload, &occs, "2fast", &uri()).is_err());
+        let short = symbol(SymbolKind::Benchmark, "short");
+        assert!(rename(&short, &occs, "long", &uri()).is_err());
+    }
+
+    #[test]
+    fn test_rename_skips_strings_and_comments_and_rejects_names_in_use() {
+        let source = r#"suite hashing {
+    fixture payload {
+        hex: "00"
+    }
+
+    bench short {
+        go: check(payload, "payload") // payload
+        ts: `${payload}-payload`
+        rust: {
+            let size = payload.len(); // payload
+            'payload: loop { break 'payload; }
+        }
+    }
+}
+"#;
+        let occs = occurrences(source);
+        let payload = symbol(SymbolKind::Fixture, "payload");
+        let mut lines: Vec<u32> =
+            references(&payload, &occs, false).iter().map(|l| l.range.start.line).collect();
+        lines.sort();
+        assert_eq!(lines, [6, 7, 9]);
+
+        assert!(rename(&payload, &occs, "size", &uri()).unwrap_err().contains("already used"));
+        assert!(rename(&payload, &occs, "check", &uri()).is_err());
+        assert!(rename(&payload, &occs, "input", &uri()).is_ok());
+        // Plain identifiers are not navigable
+        assert!(occurrence_at(&occs, Position::new(6, 13)).is_none());
+    }
+
+    #[test]
+    fn test_positions_count_utf16_code_units() {
+        let source = r#"suite hashing {
+    fixture data {
+        hex: "00"
+    }
+
+    bench show {
+        go: fmt.Println("→", data)
+        ts: log("𝄞", data)
+    }
+}
+"#;
+        let occs = occurrences(source);
+        let data = symbol(SymbolKind::Fixture, "data");
+
+        let edit = rename(&data, &occs, "payload", &uri()).unwrap();
+        let changes = edit.changes.unwrap();
+        let mut ranges: Vec<Range> = changes[&uri()].iter().map(|e| e.range).collect();
+        ranges.sort_by_key(|r| (r.start.line, r.start.character));
+        assert_eq!(
+            ranges,
+            [
+                Range::new(Position::new(1, 12), Position::new(1, 16)),
+                Range::new(Position::new(6, 29), Position::new(6, 33)),
+                Range::new(Position::new(7, 22), Position::new(7, 26)),
+            ]
+        );
+        assert_eq!(occurrence_at(&occs, Position::new(6, 31)).unwrap().symbol, data);
+        assert_eq!(occurrence_at(&occs, Position::new(7, 24)).unwrap().symbol, data);
+    }
+
+    #[test]
+    fn test_code_identifiers_per_language() {
+        let names = |lang, code| -> Vec<&str> {
+            code_identifiers(lang, code).into_iter().map(|(_, name)| name).collect()
+        };
+        assert_eq!(names(Lang::Python, "f(a, '''b''', r\"c\\\"\") # d"), ["f", "a"]);
+        assert_eq!(names(Lang::Rust, "g(r#\"x\"#, b'y', '\\'', x.z) /* w */"), ["g", "x"]);
+        assert_eq!(names(Lang::Go, "h(`a`, 'b', 0x1f)"), ["h"]);
+        assert_eq!(names(Lang::CSharp, "k(@\"a\"\"b\", $\"c\")"), ["k"]);
+        assert_eq!(names(Lang::TypeScript, "`a${ {b: c}.d }e${f}`"), ["b", "c", "f"]);
+        assert_eq!(names(Lang::Zig, "\\\\ a\nb"), ["b"]);
+    }
+
+    #[test]
+    fn test_helper_definitions_only_at_outer_indentation() {
+        let code = "\n    def outer(x):\n        def inner(y):\n            return y\n        return inner(x)\n";
+        let found = helper_definitions(Lang::Python, code);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, "outer");
+        assert_eq!(&code[found[0].0..found[0].0 + 5], "outer");
+    }
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            relative_path(Path::new("/work/bench/evm"), Path::new("/work/bench/fixtures/a.hex")),
+            PathBuf::from("../fixtures/a.hex")
+        );
+        assert_eq!(normalize(Path::new("/work/./bench/../x")), PathBuf::from("/work/x"));
+    }
+}
@@ -0,0 +1,310 @@
+//! Inlay hints with the last run's results
+//!
+//! The nearest `out/results.json` above a `.bench` file (where `poly-bench run` saves its
+//! results) is loaded through the reporter's results loader and cached until the file's
+//! modification time changes. Each `bench` declaration gets the winner and speedup, each
+//! language implementation line its time (or bytes) per op. A result whose `source_hash` no
+//! longer matches the benchmark's current fingerprint is marked stale.
+
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use dashmap::DashMap;
+use poly_bench_dsl::SuiteType;
+use poly_bench_executor::{
+    watch::{EnvFingerprints, Fingerprints},
+    BenchmarkResult, BenchmarkResults,
+};
+use poly_bench_syntax::{PartialBenchmark, Span};
+use tower_lsp::lsp_types::*;
+
+use crate::{
+    bench_runner::{format_measurement, is_expansion_of, result_summary, winner_summary},
+    document::Document,
+};
+
+/// Results file written by `poly-bench run`, relative to the directory it ran in
+pub const RESULTS_FILE: &str = "out/results.json";
+
+/// Loaded results files and per-document benchmark fingerprints
+#[derive(Default)]
+pub struct ResultHints {
+    results: DashMap<PathBuf, (SystemTime, Arc<BenchmarkResults>)>,
+    fingerprints: DashMap<Url, (i32, Option<Arc<Fingerprints>>)>,
+}
+
+impl ResultHints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget loaded results (after a results file changed on disk)
+    pub fn invalidate(&self) {
+        self.results.clear();
+    }
+
+    /// Forget a closed document's fingerprints
+    pub fn remove_document(&self, uri: &Url) {
+        self.fingerprints.remove(uri);
+    }
+
+    /// Hints for the part of `doc` within `range`
+    pub fn get_inlay_hints(&self, doc: &Document, range: Range) -> Vec<InlayHint> {
+        let Ok(bench_path) = doc.uri.to_file_path() else { return Vec::new() };
+        let Some((results_path, results)) = self.results_for(&bench_path) else {
+            return Vec::new();
+        };
+        if !ran_file(&results, &bench_path, &results_path) {
+            return Vec::new();
+        }
+        let fingerprints = self.fingerprints(doc, &bench_path);
+        let in_range = |position: &Position| range.start <= *position && *position <= range.end;
+
+        let mut hints = Vec::new();
+        for suite in doc.partial_ast.valid_suites() {
+            let Some(suite_results) = results.suites.iter().find(|s| s.name == suite.name) else {
+                continue;
+            };
+            let is_memory = suite_results.suite_type == SuiteType::Memory;
+            for bench in suite.benchmarks.iter().filter_map(|b| b.as_valid()) {
+                let matches: Vec<&BenchmarkResult> = suite_results
+                    .benchmarks
+                    .iter()
+                    .filter(|r| is_expansion_of(&r.name, &r.params, &bench.name))
+                    .collect();
+                if matches.is_empty() {
+                    continue;
+                }
+                let stale = fingerprints.as_deref().is_some_and(|current| {
+                    matches.iter().any(|r| {
+                        r.source_hash.as_deref().is_some_and(|hash| {
+                            current.get(&suite.name, &r.name).is_some_and(|now| now != hash)
+                        })
+                    })
+                });
+
+                let position = line_end(doc, &bench.span);
+                if in_range(&position) {
+                    hints.push(declaration_hint(position, bench, &matches, is_memory, stale));
+                }
+                for (lang, block) in &bench.implementations {
+                    let Some(block) = block.as_valid() else { continue };
+                    let position = line_end(doc, &block.span);
+                    let lang = poly_bench_traits::syntax_lang_to_dsl(*lang);
+                    let values: Vec<String> = matches
+                        .iter()
+                        .filter_map(|r| {
+                            let m = r.measurements.get(&lang)?;
+                            let mut value = format_measurement(m, is_memory);
+                            if winner_summary(r, is_memory).is_some_and(|(w, _)| w == Some(lang)) {
+                                value.push_str(" ★");
+                            }
+                            Some(labelled(&bench.name, r, value))
+                        })
+                        .collect();
+                    if !values.is_empty() && in_range(&position) {
+                        hints.push(hint(position, values.join(" · "), None));
+                    }
+                }
+            }
+        }
+        hints
+    }
+
+    /// The nearest results file above `bench_path`, reloaded when it changed on disk
+    fn results_for(&self, bench_path: &Path) -> Option<(PathBuf, Arc<BenchmarkResults>)> {
+        let path = bench_path
+            .ancestors()
+            .skip(1)
+            .map(|dir| dir.join(RESULTS_FILE))
+            .find(|p| p.is_file())?;
+        let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+        if let Some(entry) = self.results.get(&path) {
+            if entry.0 == modified {
+                return Some((path, entry.1.clone()));
+            }
+        }
+        let results = match poly_bench_reporter::results_file::load_file(&path) {
+            Ok(results) => Arc::new(results),
+            Err(e) => {
+                tracing::debug!("[results] {}", e);
+                return None;
+            }
+        };
+        self.results.insert(path.clone(), (modified, results.clone()));
+        Some((path, results))
+    }
+
+    /// Benchmark fingerprints of the document as it is now; `None` while it does not lower
+    fn fingerprints(&self, doc: &Document, bench_path: &Path) -> Option<Arc<Fingerprints>> {
+        if let Some(entry) = self.fingerprints.get(&doc.uri) {
+            if entry.0 == doc.version {
+                return entry.1.clone();
+            }
+        }
+        let filename = bench_path.file_name().and_then(|s| s.to_str()).unwrap_or("unknown");
+        let fingerprints = poly_bench_dsl::parse(&doc.source_text(), filename)
+            .ok()
+            .and_then(|ast| poly_bench_ir::lower(&ast, bench_path.parent()).ok())
+            .map(|ir| Arc::new(Fingerprints::of(&ir, &EnvFingerprints::default())));
+        self.fingerprints.insert(doc.uri.clone(), (doc.version, fingerprints.clone()));
+        fingerprints
+    }
+}
+
+/// Whether the results include `bench_path`. Paths in the run environment are relative to the
+/// directory the run started in (the parent of `out/`); results without one are assumed to match.
+fn ran_file(results: &BenchmarkResults, bench_path: &Path, results_path: &Path) -> bool {
+    let Some(env) = results.environment.as_ref().filter(|env| !env.bench_files.is_empty()) else {
+        return true;
+    };
+    let run_dir = results_path.parent().and_then(Path::parent);
+    env.bench_files.iter().any(|file| {
+        let path = Path::new(&file.path);
+        if path.is_absolute() {
+            return path == bench_path;
+        }
+        let relative: PathBuf = path.components().filter(|c| *c != Component::CurDir).collect();
+        run_dir.is_some_and(|dir| dir.join(&relative) == bench_path) ||
+            bench_path.ends_with(&relative)
+    })
+}
+
+fn declaration_hint(
+    position: Position,
+    bench: &PartialBenchmark,
+    matches: &[&BenchmarkResult],
+    is_memory: bool,
+    stale: bool,
+) -> InlayHint {
+    let winners: Vec<String> = matches
+        .iter()
+        .filter_map(|r| winner_summary(r, is_memory).map(|(_, w)| labelled(&bench.name, r, w)))
+        .collect();
+    let mut label = if winners.is_empty() { "last run".to_string() } else { winners.join(" · ") };
+    let mut tooltip: Vec<String> =
+        matches.iter().map(|r| labelled(&bench.name, r, result_summary(r, is_memory))).collect();
+    if stale {
+        label.push_str(" (stale)");
+        tooltip.push("The benchmark changed since these results were measured".to_string());
+    }
+    hint(position, label, Some(tooltip.join("\n")))
+}
+
+/// Prefix a value with the parameter suffix of a matrix expansion, e.g. `n100: …`
+fn labelled(bench: &str, result: &BenchmarkResult, value: String) -> String {
+    match result.name.strip_prefix(bench).and_then(|rest| rest.strip_prefix('_')) {
+        Some(suffix) => format!("{}: {}", suffix, value),
+        None => value,
+    }
+}
+
+fn hint(position: Position, label: String, tooltip: Option<String>) -> InlayHint {
+    InlayHint {
+        position,
+        label: InlayHintLabel::String(label),
+        kind: None,
+        text_edits: None,
+        tooltip: tooltip.map(InlayHintTooltip::String),
+        padding_left: Some(true),
+        padding_right: None,
+        data: None,
+    }
+}
+
+/// End of the first line of `span`
+fn line_end(doc: &Document, span: &Span) -> Position {
+    let line = doc.source.get_line(span.start_line).map(|l| l.to_string()).unwrap_or_default();
+    Position::new(span.start_line as u32, line.trim_end().len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::{BenchmarkKind, Lang};
+    use poly_bench_executor::SuiteResults;
+    use poly_bench_runtime::Measurement;
+    use std::collections::HashMap;
+
+    const SOURCE: &str = r#"declare suite sorting performance timeBased sameDataset: false {
+    bench sortInts {
+        go: sortInts(data)
+        ts: sortInts(data)
+    }
+}
+"#;
+
+    fn results(source_hash: Option<String>) -> BenchmarkResults {
+        let measurements = HashMap::from([
+            (Lang::Go, Measurement::from_aggregate(1000, 1_200_000)),
+            (Lang::TypeScript, Measurement::from_aggregate(1000, 2_400_000)),
+        ]);
+        let result = BenchmarkResult::new(
+            "sortInts".to_string(),
+            "sorting_sortInts".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "strict".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .with_source_hash(source_hash);
+        let suite =
+            SuiteResults::new("sorting".to_string(), None, SuiteType::Performance, vec![result]);
+        BenchmarkResults::new(vec![suite])
+    }
+
+    fn hints_for(source_hash: Option<String>) -> Vec<InlayHint> {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("out")).unwrap();
+        let json = serde_json::to_string(&results(source_hash)).unwrap();
+        std::fs::write(dir.path().join(RESULTS_FILE), json).unwrap();
+
+        let bench_path = dir.path().join("sorting.bench");
+        std::fs::write(&bench_path, SOURCE).unwrap();
+        let doc = Document::new(Url::from_file_path(&bench_path).unwrap(), SOURCE.to_string(), 1);
+        let all = Range::new(Position::new(0, 0), Position::new(10, 0));
+        ResultHints::new().get_inlay_hints(&doc, all)
+    }
+
+    fn label(hint: &InlayHint) -> &str {
+        match &hint.label {
+            InlayHintLabel::String(s) => s,
+            InlayHintLabel::LabelParts(_) => "",
+        }
+    }
+
+    #[test]
+    fn test_hints_on_declaration_and_implementation_lines() {
+        let hints = hints_for(None);
+        let mut labels: Vec<(Position, &str)> =
+            hints.iter().map(|h| (h.position, label(h))).collect();
+        labels.sort_by_key(|(p, _)| *p);
+        assert_eq!(
+            labels,
+            [
+                (Position::new(1, 20), "Go 2.00x faster"),
+                (Position::new(2, 26), "1.20 µs/op ★"),
+                (Position::new(3, 26), "2.40 µs/op"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_benchmark_is_marked_stale() {
+        assert_eq!(label(&hints_for(Some("old".to_string()))[0]), "Go 2.00x faster (stale)");
+
+        let ast = poly_bench_dsl::parse(SOURCE, "sorting.bench").unwrap();
+        let ir = poly_bench_ir::lower(&ast, None).unwrap();
+        let current = Fingerprints::of(&ir, &EnvFingerprints::default());
+        let hash = current.get("sorting", "sortInts").map(String::from);
+        assert_eq!(label(&hints_for(hash)[0]), "Go 2.00x faster");
+    }
+}
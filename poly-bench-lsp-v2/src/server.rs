@@ -3,7 +3,11 @@
 //! This module implements the Language Server Protocol handlers
 //! using tower-lsp.
 
-use std::path::Path;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
 
 use poly_bench_dsl::Lang as DslLang;
 use poly_bench_project::get_detector;
@@ -11,13 +15,25 @@ use poly_bench_runtime::supported_languages;
 use poly_bench_syntax::Lang as SyntaxLang;
 
 use crate::{
+    bench_runner::{
+        finding_diagnostics, run_target, RunFinding, RunProgress, RunTarget, COMPILE_CHECK_COMMAND,
+        RUN_COMMAND, RUN_SUPPORTED,
+    },
+    code_actions::get_code_actions,
+    code_lens::get_code_lenses,
     diagnostics::compute_diagnostics,
     document::Document,
     embedded::EmbeddedConfig,
     embedded_diagnostics::check_embedded_code,
+    embedded_requests::{
+        get_embedded_completion, get_embedded_definition, get_embedded_references,
+        get_embedded_signature_help,
+    },
     formatter::format_document,
     hover::get_hover,
     hover_cache::invalidate_document_cache,
+    navigation::{self, Occurrence},
+    result_hints::ResultHints,
     semantic_tokens::{get_semantic_tokens, LEGEND},
     virtual_files::VirtualFileManagers,
 };
@@ -25,9 +41,9 @@ use crate::{
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
 use poly_bench_stdlib::VALID_MODULES;
-use poly_bench_syntax::Node as AstNode;
+use poly_bench_syntax::{IncrementalParser, Node as AstNode};
 use tower_lsp::{jsonrpc::Result, lsp_types::*, Client, LanguageServer};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// The poly-bench language server
 pub struct PolyBenchLanguageServer {
@@ -41,6 +57,14 @@ pub struct PolyBenchLanguageServer {
     virtual_file_managers: VirtualFileManagers,
     /// Workspace root path
     workspace_root: parking_lot::RwLock<Option<String>>,
+    /// Results and compile errors from runs started via code lenses
+    run_findings: DashMap<Url, Vec<RunFinding>>,
+    /// Only one benchmark run at a time; runs compete for the CPU
+    run_in_progress: AtomicBool,
+    /// Counter for `$/progress` tokens
+    progress_tokens: AtomicU64,
+    /// Last-run results shown as inlay hints
+    result_hints: ResultHints,
 }
 
 impl PolyBenchLanguageServer {
@@ -52,6 +76,10 @@ impl PolyBenchLanguageServer {
             embedded_config: parking_lot::RwLock::new(EmbeddedConfig::default()),
             virtual_file_managers: VirtualFileManagers::new(),
             workspace_root: parking_lot::RwLock::new(None),
+            run_findings: DashMap::new(),
+            run_in_progress: AtomicBool::new(false),
+            progress_tokens: AtomicU64::new(0),
+            result_hints: ResultHints::new(),
         }
     }
 
@@ -76,6 +104,10 @@ impl PolyBenchLanguageServer {
                 diagnostics.extend(embedded_diags);
             }
 
+            if let Some(findings) = self.run_findings.get(uri) {
+                diagnostics.extend(finding_diagnostics(&doc, &findings));
+            }
+
             self.client.publish_diagnostics(uri.clone(), diagnostics, Some(doc.version)).await;
         }
     }
@@ -83,9 +115,9 @@ impl PolyBenchLanguageServer {
     /// Detect embedded language configuration from workspace
     fn detect_embedded_config(&self, workspace_root: &str) {
         let mut config = EmbeddedConfig::default();
-        for dsl_lang in supported_languages() {
+        for dsl_lang in &supported_languages() {
+            let Some(syntax_lang) = to_syntax_lang(*dsl_lang) else { continue };
             if let Some(root) = find_module_root(*dsl_lang, workspace_root) {
-                let syntax_lang = to_syntax_lang(*dsl_lang);
                 info!("Detected {} at: {}", syntax_lang.as_str(), root);
                 config.set_module_root(syntax_lang, root);
             }
@@ -102,9 +134,9 @@ impl PolyBenchLanguageServer {
             if let Some(parent) = path.parent() {
                 let parent_str = parent.to_string_lossy();
                 let mut config = self.embedded_config.write();
-                for dsl_lang in supported_languages() {
+                for dsl_lang in &supported_languages() {
+                    let Some(syntax_lang) = to_syntax_lang(*dsl_lang) else { continue };
                     if let Some(root) = find_module_root(*dsl_lang, &parent_str) {
-                        let syntax_lang = to_syntax_lang(*dsl_lang);
                         info!("Detected {} at: {}", syntax_lang.as_str(), root);
                         config.set_module_root(syntax_lang, root);
                     }
@@ -112,6 +144,175 @@ impl PolyBenchLanguageServer {
             }
         }
     }
+
+    /// Register the external runtimes and variants of the project containing `uri` so their
+    /// code blocks parse like they do for `poly-bench run`
+    fn register_project_runtimes(&self, uri: &Url) {
+        let Some(root) =
+            uri.to_file_path().ok().and_then(|path| poly_bench_project::find_project_root(&path))
+        else {
+            return;
+        };
+        if let Err(e) = crate::bench_runner::register_project_runtimes(&root) {
+            warn!("Failed to register runtimes of {}: {}", root.display(), e);
+        }
+    }
+
+    /// Run `target` on a dedicated thread, reporting its stages as `$/progress`
+    async fn run_with_progress(
+        &self,
+        target: &RunTarget,
+        compile_only: bool,
+    ) -> std::result::Result<crate::bench_runner::RunOutcome, String> {
+        // Run what the editor shows, saved or not
+        let source = match self.documents.get(&target.uri) {
+            Some(doc) => doc.source_text(),
+            None => {
+                let path = target.uri.to_file_path().map_err(|_| "not a file".to_string())?;
+                std::fs::read_to_string(path).map_err(|e| e.to_string())?
+            }
+        };
+
+        let n = self.progress_tokens.fetch_add(1, Ordering::Relaxed);
+        let token = NumberOrString::String(format!("polybench-run-{}", n));
+        let progress = self
+            .client
+            .send_request::<request::WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+            .is_ok();
+        let title = if compile_only { "Compile check" } else { "Run" };
+        if progress {
+            self.send_progress(
+                &token,
+                WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                    title: format!("{} {}", title, target.label()),
+                    cancellable: Some(false),
+                    message: None,
+                    percentage: Some(0),
+                }),
+            )
+            .await;
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        let thread_target = target.clone();
+        std::thread::spawn(move || {
+            let outcome = run_target(&thread_target, &source, compile_only, move |stage| {
+                let _ = tx.send(stage);
+            });
+            let _ = done_tx.send(outcome);
+        });
+
+        while let Some(stage) = rx.recv().await {
+            if !progress {
+                continue;
+            }
+            let (message, percentage) = match stage {
+                RunProgress::Compiling => ("compile validation".to_string(), 0),
+                RunProgress::Running { index, total, name } => (
+                    format!("{} ({}/{})", name, index + 1, total),
+                    (index * 100 / total.max(1)) as u32,
+                ),
+            };
+            self.send_progress(
+                &token,
+                WorkDoneProgress::Report(WorkDoneProgressReport {
+                    cancellable: Some(false),
+                    message: Some(message),
+                    percentage: Some(percentage),
+                }),
+            )
+            .await;
+        }
+        let outcome = done_rx.await.unwrap_or_else(|_| Err("benchmark run panicked".to_string()));
+
+        if progress {
+            let message = match &outcome {
+                Ok(outcome) => outcome.summary.clone(),
+                Err(e) => e.clone(),
+            };
+            self.send_progress(
+                &token,
+                WorkDoneProgress::End(WorkDoneProgressEnd { message: Some(message) }),
+            )
+            .await;
+        }
+        outcome
+    }
+
+    async fn send_progress(&self, token: &NumberOrString, progress: WorkDoneProgress) {
+        self.client
+            .send_notification::<notification::Progress>(ProgressParams {
+                token: token.clone(),
+                value: ProgressParamsValue::WorkDone(progress),
+            })
+            .await;
+    }
+
+    /// Run an embedded-language request against an open document with a fresh embedded config
+    fn with_embedded<T>(
+        &self,
+        uri: &Url,
+        request: impl FnOnce(&Document, &EmbeddedConfig) -> Option<T>,
+    ) -> Option<T> {
+        let doc = self.documents.get(uri)?;
+        self.update_config_for_document(uri);
+        let config = self.embedded_config.read().clone();
+        request(&doc, &config)
+    }
+
+    /// Symbol occurrences in one open document
+    fn document_occurrences(&self, uri: &Url) -> Vec<Occurrence> {
+        self.documents
+            .get(uri)
+            .map(|doc| navigation::collect_occurrences(uri, &doc.source_text(), &doc.tree))
+            .unwrap_or_default()
+    }
+
+    /// Symbol occurrences across every `.bench` file of the project containing `uri` (or the
+    /// workspace), preferring the unsaved contents of open documents
+    fn workspace_occurrences(&self, uri: &Url) -> Vec<Occurrence> {
+        let root = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| poly_bench_project::find_project_root(&path))
+            .or_else(|| self.workspace_root.read().as_ref().map(PathBuf::from));
+
+        let mut occurrences = Vec::new();
+        let mut seen = HashSet::new();
+        for entry in self.documents.iter() {
+            // Suites are only unique within a project: open documents elsewhere are unrelated
+            let in_project = entry.key() == uri ||
+                root.as_ref().is_some_and(|root| {
+                    entry.key().to_file_path().is_ok_and(|path| path.starts_with(root))
+                });
+            if !in_project {
+                continue;
+            }
+            seen.insert(entry.key().clone());
+            occurrences.extend(navigation::collect_occurrences(
+                entry.key(),
+                &entry.source_text(),
+                &entry.tree,
+            ));
+        }
+        let Some(root) = root else { return occurrences };
+        let mut parser = IncrementalParser::new();
+        for path in find_bench_files(&root) {
+            let Ok(file_uri) = Url::from_file_path(&path) else { continue };
+            if seen.contains(&file_uri) {
+                continue;
+            }
+            let Ok(source) = std::fs::read_to_string(&path) else { continue };
+            parser.reset();
+            let tree = parser.parse(&source, None);
+            occurrences.extend(navigation::collect_occurrences(&file_uri, &source, &tree));
+        }
+        occurrences
+    }
 }
 
 #[tower_lsp::async_trait]
@@ -156,7 +357,35 @@ impl LanguageServer for PolyBenchLanguageServer {
                     trigger_characters: Some(completion_trigger_characters()),
                     ..Default::default()
                 }),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    ..Default::default()
+                }),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![
+                            CodeActionKind::QUICKFIX,
+                            CodeActionKind::REFACTOR_EXTRACT,
+                        ]),
+                        ..Default::default()
+                    },
+                )),
+                code_lens_provider: Some(CodeLensOptions { resolve_provider: Some(false) }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: [RUN_COMMAND, COMPILE_CHECK_COMMAND]
+                        .into_iter()
+                        .filter(|command| RUN_SUPPORTED || *command != RUN_COMMAND)
+                        .map(String::from)
+                        .collect(),
+                    ..Default::default()
+                }),
                 definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 ..Default::default()
             },
             server_info: Some(ServerInfo {
@@ -183,6 +412,7 @@ impl LanguageServer for PolyBenchLanguageServer {
 
         debug!("Document opened: {}", uri);
 
+        self.register_project_runtimes(&uri);
         // Update embedded config if needed
         self.update_config_for_document(&uri);
 
@@ -225,6 +455,7 @@ impl LanguageServer for PolyBenchLanguageServer {
         // Clean up virtual files and cache
         self.virtual_file_managers.remove_all(uri.as_str());
         invalidate_document_cache(&uri);
+        self.result_hints.remove_document(&uri);
 
         self.documents.remove(&uri);
 
@@ -232,11 +463,25 @@ impl LanguageServer for PolyBenchLanguageServer {
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let mut sources_changed = false;
         for change in &params.changes {
             let path = change.uri.path();
             let filename = Path::new(path).file_name().and_then(|s| s.to_str()).unwrap_or("");
 
+            // A new `poly-bench run` finished; only the inlay hints depend on it
+            if filename == "results.json" {
+                info!("Detected {} change, refreshing result hints", path);
+                self.result_hints.invalidate();
+                let _ = self.client.inlay_hint_refresh().await;
+                continue;
+            }
+            sources_changed = true;
+
             match filename {
+                "polybench.toml" => {
+                    info!("Detected {} change, registering project runtimes", path);
+                    self.register_project_runtimes(&change.uri);
+                }
                 "package.json" | "package-lock.json" | ".package-lock.json" => {
                     info!("Detected {} change, clearing TypeScript caches", filename);
                     self.virtual_file_managers.clear_caches_for_lang(DslLang::TypeScript);
@@ -265,6 +510,10 @@ impl LanguageServer for PolyBenchLanguageServer {
             }
         }
 
+        if !sources_changed {
+            return;
+        }
+
         // Re-run diagnostics for all open documents to pick up new modules
         for entry in self.documents.iter() {
             let uri = entry.key().clone();
@@ -328,6 +577,13 @@ impl LanguageServer for PolyBenchLanguageServer {
             CompletionResponse::List(CompletionList { is_incomplete: true, items })
         };
 
+        // Inside embedded code, the language's own server knows best
+        if let Some(response) = self.with_embedded(&uri, |doc, config| {
+            get_embedded_completion(doc, position, config, &uri, &self.virtual_file_managers)
+        }) {
+            return Ok(Some(response));
+        }
+
         if let Some(doc) = self.documents.get(&uri) {
             // Get the line text before cursor for context detection
             let line_text = get_line_text_before_cursor(&doc.source, position);
@@ -575,15 +831,178 @@ impl LanguageServer for PolyBenchLanguageServer {
 
     async fn goto_definition(
         &self,
-        _params: GotoDefinitionParams,
+        params: GotoDefinitionParams,
     ) -> Result<Option<GotoDefinitionResponse>> {
-        Ok(None)
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let occurrences = self.document_occurrences(&uri);
+        let Some(occurrence) = navigation::occurrence_at(&occurrences, position) else {
+            return Ok(self.with_embedded(&uri, |doc, config| {
+                get_embedded_definition(doc, position, config, &uri, &self.virtual_file_managers)
+            }));
+        };
+        let mut locations =
+            navigation::definitions(&occurrence.symbol, &self.workspace_occurrences(&uri));
+        Ok(match locations.len() {
+            0 => None,
+            1 => locations.pop().map(GotoDefinitionResponse::Scalar),
+            _ => Some(GotoDefinitionResponse::Array(locations)),
+        })
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let occurrences = self.document_occurrences(&uri);
+        let Some(occurrence) = navigation::occurrence_at(&occurrences, position) else {
+            return Ok(self.with_embedded(&uri, |doc, config| {
+                get_embedded_references(
+                    doc,
+                    position,
+                    config,
+                    &uri,
+                    &self.virtual_file_managers,
+                    params.context.include_declaration,
+                )
+            }));
+        };
+        Ok(Some(navigation::references(
+            &occurrence.symbol,
+            &self.workspace_occurrences(&uri),
+            params.context.include_declaration,
+        )))
+    }
+
+    async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        Ok(self.with_embedded(&uri, |doc, config| {
+            get_embedded_signature_help(doc, position, config, &uri, &self.virtual_file_managers)
+        }))
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        Ok(self
+            .documents
+            .get(&params.text_document.uri)
+            .map(|doc| self.result_hints.get_inlay_hints(&doc, params.range)))
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        Ok(self
+            .documents
+            .get(&params.text_document.uri)
+            .map(|doc| get_code_actions(&doc, params.range, &params.context.diagnostics)))
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        Ok(self.documents.get(&params.text_document.uri).map(|doc| get_code_lenses(&doc)))
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        let compile_only = match params.command.as_str() {
+            RUN_COMMAND if RUN_SUPPORTED => false,
+            COMPILE_CHECK_COMMAND => true,
+            other => {
+                return Err(tower_lsp::jsonrpc::Error::invalid_params(format!(
+                    "unknown command `{}`",
+                    other
+                )))
+            }
+        };
+        let target: RunTarget = params
+            .arguments
+            .into_iter()
+            .next()
+            .and_then(|arg| serde_json::from_value(arg).ok())
+            .ok_or_else(|| {
+                tower_lsp::jsonrpc::Error::invalid_params("expected a run target argument")
+            })?;
+
+        if self.run_in_progress.swap(true, Ordering::SeqCst) {
+            self.client
+                .show_message(MessageType::WARNING, "A benchmark run is already in progress")
+                .await;
+            return Ok(None);
+        }
+        let outcome = self.run_with_progress(&target, compile_only).await;
+        self.run_in_progress.store(false, Ordering::SeqCst);
+
+        match outcome {
+            Ok(outcome) => {
+                let mut findings = self.run_findings.entry(target.uri.clone()).or_default();
+                findings.retain(|f| !target.covers(f));
+                findings.extend(outcome.findings);
+                drop(findings);
+                self.publish_diagnostics(&target.uri).await;
+                self.client.show_message(MessageType::INFO, &outcome.summary).await;
+                Ok(Some(serde_json::Value::String(outcome.summary)))
+            }
+            Err(e) => {
+                let message = format!("{}: {}", target.label(), e);
+                self.client.show_message(MessageType::ERROR, &message).await;
+                Ok(None)
+            }
+        }
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let occurrences = self.document_occurrences(&params.text_document.uri);
+        Ok(navigation::occurrence_at(&occurrences, params.position)
+            .map(|o| PrepareRenameResponse::Range(o.location.range)))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+
+        let occurrences = self.document_occurrences(&uri);
+        let Some(occurrence) = navigation::occurrence_at(&occurrences, position) else {
+            return Ok(None);
+        };
+        let workspace = self.workspace_occurrences(&uri);
+        navigation::rename(&occurrence.symbol, &workspace, &params.new_name, &uri)
+            .map(Some)
+            .map_err(tower_lsp::jsonrpc::Error::invalid_params)
     }
 }
 
-/// Convert poly_bench_dsl::Lang to poly_bench_syntax::Lang
-fn to_syntax_lang(l: poly_bench_dsl::Lang) -> SyntaxLang {
-    match l {
+/// `.bench` files under `root`, skipping hidden directories and dependency/build output
+fn find_bench_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "node_modules" && name != "target" {
+                    dirs.push(path);
+                }
+            } else if path.extension().is_some_and(|ext| ext == "bench") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Convert poly_bench_dsl::Lang to poly_bench_syntax::Lang (external runtimes have no
+/// embedded language support, and variants share their base language's)
+fn to_syntax_lang(l: poly_bench_dsl::Lang) -> Option<SyntaxLang> {
+    Some(match l {
         poly_bench_dsl::Lang::Go => SyntaxLang::Go,
         poly_bench_dsl::Lang::TypeScript => SyntaxLang::TypeScript,
         poly_bench_dsl::Lang::Rust => SyntaxLang::Rust,
@@ -591,7 +1010,8 @@ fn to_syntax_lang(l: poly_bench_dsl::Lang) -> SyntaxLang {
         poly_bench_dsl::Lang::C => SyntaxLang::C,
         poly_bench_dsl::Lang::CSharp => SyntaxLang::CSharp,
         poly_bench_dsl::Lang::Zig => SyntaxLang::Zig,
-    }
+        poly_bench_dsl::Lang::External(_) | poly_bench_dsl::Lang::Variant(_) => return None,
+    })
 }
 
 /// Find module root for a language using project detectors and poly-bench runtime layout
@@ -917,9 +1337,14 @@ fn is_suite_param_value_context(line_text: &str) -> bool {
             "baseline" |
             "targetTime" |
             "sink" |
+            "counters" |
+            "equivalent" |
             "outlierDetection" |
             "cvThreshold" |
             "count" |
+            "ciTarget" |
+            "maxRuns" |
+            "maxTime" |
             "memory" |
             "fairness" |
             "fairnessSeed" |
@@ -1810,6 +2235,22 @@ fn get_suite_body_completions() -> Vec<CompletionItem> {
             detail: Some("Use sink/black-box pattern to prevent dead code elimination".to_string()),
             ..Default::default()
         },
+        CompletionItem {
+            label: "counters".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("counters: true".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Collect hardware performance counters via perf_event_open".to_string()),
+            ..Default::default()
+        },
+        CompletionItem {
+            label: "equivalent".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("equivalent: true".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Fail when languages return different values".to_string()),
+            ..Default::default()
+        },
         // Statistical settings
         CompletionItem {
             label: "outlierDetection".to_string(),
@@ -1839,6 +2280,30 @@ fn get_suite_body_completions() -> Vec<CompletionItem> {
             ),
             ..Default::default()
         },
+        CompletionItem {
+            label: "ciTarget".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("ciTarget: 2".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Add runs until the 95% CI is within this % of the median".to_string()),
+            ..Default::default()
+        },
+        CompletionItem {
+            label: "maxRuns".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("maxRuns: 30".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Run budget for ciTarget".to_string()),
+            ..Default::default()
+        },
+        CompletionItem {
+            label: "maxTime".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("maxTime: 60s".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Time budget for ciTarget".to_string()),
+            ..Default::default()
+        },
         CompletionItem {
             label: "fairness".to_string(),
             kind: Some(CompletionItemKind::PROPERTY),
@@ -2049,6 +2514,14 @@ fn get_fixture_body_completions() -> Vec<CompletionItem> {
             detail: Some("Fixture data from file".to_string()),
             ..Default::default()
         },
+        CompletionItem {
+            label: "data @random".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("data: @random(bytes: ${1:1024}, seed: ${2:42})".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some("Deterministic random fixture data".to_string()),
+            ..Default::default()
+        },
         CompletionItem {
             label: "encoding".to_string(),
             kind: Some(CompletionItemKind::PROPERTY),
@@ -2073,6 +2546,16 @@ fn get_fixture_body_completions() -> Vec<CompletionItem> {
             detail: Some("Structured fixture selector".to_string()),
             ..Default::default()
         },
+        CompletionItem {
+            label: "external".to_string(),
+            kind: Some(CompletionItemKind::PROPERTY),
+            insert_text: Some("external: ${1|true,false|}".to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            detail: Some(
+                "Load fixture data from a file at startup instead of inlining it".to_string(),
+            ),
+            ..Default::default()
+        },
         CompletionItem {
             label: "go".to_string(),
             kind: Some(CompletionItemKind::KEYWORD),
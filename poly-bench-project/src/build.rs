@@ -73,7 +73,7 @@ pub fn build_project(options: &BuildOptions) -> Result<()> {
 pub fn build_project_at(project_root: &Path, options: &BuildOptions) -> Result<()> {
     let manifest = crate::load_manifest(project_root)?;
 
-    for lang in poly_bench_runtime::supported_languages() {
+    for lang in &poly_bench_runtime::supported_languages() {
         if manifest.has_runtime(*lang) {
             build_runtime_env_for_lang(*lang, project_root, &manifest, options)?;
         }
@@ -109,6 +109,10 @@ fn build_runtime_env_for_lang(
         }
         Lang::CSharp => build_csharp_env(project_root, manifest.csharp.as_ref().unwrap(), options),
         Lang::Zig => build_zig_env(project_root, manifest.zig.as_ref().unwrap(), options),
+        // External runtime hosts manage their own environment
+        Lang::External(_) => Ok(()),
+        // Variants reuse their base language's environment
+        Lang::Variant(_) => Ok(()),
     }
 }
 
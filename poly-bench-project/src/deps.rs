@@ -1697,7 +1697,7 @@ pub fn install_all() -> Result<()> {
 
     let manifest = crate::load_manifest(&project_root)?;
 
-    for lang in poly_bench_runtime::supported_languages() {
+    for lang in &poly_bench_runtime::supported_languages() {
         if manifest.has_runtime(*lang) {
             install_runtime_deps_for_lang(*lang, &project_root, &manifest)?;
         }
@@ -1728,6 +1728,7 @@ fn install_runtime_deps_for_lang(
         }
         Lang::CSharp => install_csharp_deps(project_root, manifest.csharp.as_ref().unwrap()),
         Lang::Zig => install_zig_deps(project_root, manifest.zig.as_ref().unwrap()),
+        Lang::External(_) | Lang::Variant(_) => Ok(()),
     }
 }
 
@@ -125,7 +125,7 @@ pub fn init_project(options: &InitOptions) -> Result<PathBuf> {
     }
 
     // Create runtime-env dirs and language-specific env files (keeps root uncluttered)
-    for lang in poly_bench_runtime::supported_languages() {
+    for lang in &poly_bench_runtime::supported_languages() {
         if manifest.has_runtime(*lang) {
             init_runtime_env_for_lang(
                 *lang,
@@ -448,6 +448,9 @@ fn init_runtime_env_for_lang(
                     .map_err(|e| miette::miette!("Failed to write main.zig: {}", e))?;
             }
         }
+        // External runtimes and variants are declared by hand under [runtimes.<name>] and
+        // [variants.<name>]
+        Lang::External(_) | Lang::Variant(_) => {}
     }
 
     Ok(())
@@ -47,6 +47,7 @@ pub fn is_runtime_env_root(path: &Path) -> bool {
 
 /// Validate that a path is a project root for a language using its detector markers.
 pub fn is_valid_project_root_for_lang(path: &Path, lang: poly_bench_dsl::Lang) -> bool {
+    let lang = lang.base();
     if !path.is_dir() {
         return false;
     }
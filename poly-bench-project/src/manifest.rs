@@ -1,10 +1,14 @@
 //! Manifest parsing and serialization for polybench.toml
 
 use miette::Result;
-use poly_bench_dsl::Lang;
+use poly_bench_dsl::{
+    lint::{LintConfig, LintLevel},
+    Lang,
+};
+use poly_bench_runtime::{ExternalRuntimeSpec, RuntimeVariantSpec};
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     path::Path,
 };
 
@@ -49,6 +53,23 @@ pub struct Manifest {
     /// Output configuration
     #[serde(default)]
     pub output: OutputConfig,
+
+    /// External runtimes (`[runtimes.<name>]`), driven over the stdio runtime protocol
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub runtimes: BTreeMap<String, ExternalRuntimeSpec>,
+
+    /// Runtime variants (`[variants.<name>]`): a built-in language with its own toolchain,
+    /// flags and environment, measured as a separate contestant
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variants: BTreeMap<String, RuntimeVariantSpec>,
+
+    /// Lint severity overrides (`[lint]`), keyed by rule ID
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lint: BTreeMap<String, LintLevel>,
+
+    /// Noise control for benchmark runs (`[isolation]`, Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation: Option<IsolationConfig>,
 }
 
 /// Project metadata
@@ -285,6 +306,28 @@ pub struct RustDependencyDetail {
     pub optional: Option<bool>,
 }
 
+/// Noise control applied while benchmarks run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IsolationConfig {
+    /// CPUs to pin benchmark processes to, e.g. `"2-3"` or `"2,4,6"` (default: the
+    /// highest-numbered allowed CPU)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<String>,
+
+    /// Nice value to run at; negative values need `CAP_SYS_NICE` and are skipped otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nice: Option<i32>,
+
+    /// Refuse to run when the pre-run stability checks find a noisy system
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Machine-wide checks that also fail a strict run, from `"aslr"` and `"turbo"`; both only
+    /// warn otherwise
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strict_checks: Vec<String>,
+}
+
 /// Output configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OutputConfig {
@@ -299,6 +342,11 @@ pub struct OutputConfig {
     /// Whether to auto-save benchmark results to JSON
     #[serde(default = "default_auto_save")]
     pub auto_save_results: bool,
+
+    /// Runs kept in `.polybench/history/`; older ones are pruned after each run, 0 disables
+    /// recording
+    #[serde(default = "default_history_limit")]
+    pub history_limit: usize,
 }
 
 fn default_output_dir() -> String {
@@ -309,12 +357,17 @@ fn default_auto_save() -> bool {
     true
 }
 
+fn default_history_limit() -> usize {
+    50
+}
+
 impl Default for OutputConfig {
     fn default() -> Self {
         Self {
             report_formats: Vec::new(),
             output_dir: default_output_dir(),
             auto_save_results: default_auto_save(),
+            history_limit: default_history_limit(),
         }
     }
 }
@@ -379,9 +432,18 @@ impl Manifest {
                 None
             },
             output: OutputConfig::default(),
+            runtimes: BTreeMap::new(),
+            variants: BTreeMap::new(),
+            lint: BTreeMap::new(),
+            isolation: None,
         }
     }
 
+    /// Lint configuration built from the `[lint]` overrides
+    pub fn lint_config(&self) -> LintConfig {
+        LintConfig::new(self.lint.clone())
+    }
+
     /// Check if a runtime is enabled for the given language
     pub fn has_runtime(&self, lang: Lang) -> bool {
         match lang {
@@ -392,6 +454,8 @@ impl Manifest {
             Lang::C => self.c.is_some(),
             Lang::CSharp => self.csharp.is_some(),
             Lang::Zig => self.zig.is_some(),
+            Lang::External(_) => self.runtimes.contains_key(lang.as_str()),
+            Lang::Variant(_) => self.variants.contains_key(lang.as_str()),
         }
     }
 
@@ -684,4 +748,29 @@ mod tests {
         assert!(manifest.has_runtime(Lang::CSharp));
         assert_eq!(manifest.csharp.as_ref().unwrap().target_framework, "net8.0".to_string());
     }
+
+    #[test]
+    fn test_manifest_lint_overrides() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+[project]
+name = "lints"
+
+[lint]
+unused-fixture = "allow"
+hook-without-impl = "error"
+"#,
+        )
+        .unwrap();
+        let config = manifest.lint_config();
+        assert_eq!(config.level("unused-fixture"), LintLevel::Allow);
+        assert_eq!(config.level("hook-without-impl"), LintLevel::Error);
+        assert_eq!(config.level("empty-suite"), LintLevel::Warn);
+
+        let err = toml::from_str::<Manifest>(
+            "[project]\nname = \"x\"\n[lint]\nunused-fixture = \"loud\"\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unknown variant"), "{}", err);
+    }
 }
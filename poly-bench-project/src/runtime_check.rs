@@ -6,7 +6,10 @@
 use crate::toolchain::{minimum_version, Version};
 use miette::miette;
 use poly_bench_dsl::Lang;
-use std::{path::PathBuf, process::Command};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 /// Returns the binary name(s) to check for a language.
 /// For languages with alternatives (e.g. python3/python), we check in order.
@@ -19,21 +22,33 @@ pub fn required_binary(lang: Lang) -> &'static [&'static str] {
         Lang::C => &["clang"],
         Lang::CSharp => &["dotnet"],
         Lang::Zig => &["zig"],
+        // The host command comes from polybench.toml; see find_binary_path
+        Lang::External(_) => &[],
+        Lang::Variant(variant) => required_binary(variant.base()),
     }
 }
 
 /// Returns true if the language runtime is installed (binary found on PATH).
 pub fn is_lang_installed(lang: Lang) -> bool {
-    for bin in required_binary(lang) {
-        if which::which(bin).is_ok() {
-            return true;
-        }
-    }
-    false
+    find_binary_path(lang).is_some()
 }
 
 /// Returns the path to the installed binary, if found.
 pub fn find_binary_path(lang: Lang) -> Option<PathBuf> {
+    if lang.is_external() {
+        let program = poly_bench_runtime::external::external_program(lang)?;
+        return if program.components().count() > 1 {
+            program.exists().then_some(program)
+        } else {
+            which::which(program).ok()
+        };
+    }
+    if lang.is_variant() {
+        return match poly_bench_runtime::variant::variant_binary(lang) {
+            Some(binary) => binary.exists().then_some(binary),
+            None => find_binary_path(lang.base()),
+        };
+    }
     for bin in required_binary(lang) {
         if let Ok(path) = which::which(bin) {
             return Some(path);
@@ -61,6 +76,8 @@ pub fn get_installed_version(lang: Lang) -> Option<Version> {
         Lang::C => Command::new("clang").arg("--version").output().ok()?,
         Lang::CSharp => Command::new("dotnet").arg("--version").output().ok()?,
         Lang::Zig => Command::new("zig").arg("version").output().ok()?,
+        Lang::External(_) => return None,
+        Lang::Variant(variant) => return get_installed_version(variant.base()),
     };
 
     if !output.status.success() {
@@ -71,6 +88,26 @@ pub fn get_installed_version(lang: Lang) -> Option<Version> {
     parse_version(lang, &stdout)
 }
 
+/// First line of the version command's output for `binary`, a toolchain binary of `lang`,
+/// e.g. `go version go1.24.0 linux/amd64` or `Python 3.12.0`.
+pub fn version_output(lang: Lang, binary: &Path) -> Option<String> {
+    let args: &[&str] = match lang.base() {
+        Lang::Go | Lang::Zig => &["version"],
+        Lang::TypeScript => &["-v"],
+        Lang::Rust | Lang::Python | Lang::C | Lang::CSharp => &["--version"],
+        Lang::External(_) | Lang::Variant(_) => return None,
+    };
+    let output = Command::new(binary).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Older Python versions print the version to stderr
+    [output.stdout, output.stderr].iter().find_map(|bytes| {
+        let text = String::from_utf8_lossy(bytes);
+        text.lines().map(str::trim).find(|line| !line.is_empty()).map(str::to_string)
+    })
+}
+
 /// Parse version string from command output.
 fn parse_version(lang: Lang, output: &str) -> Option<Version> {
     let version_str = match lang {
@@ -98,6 +135,8 @@ fn parse_version(lang: Lang, output: &str) -> Option<Version> {
         Lang::CSharp => output.trim(),
         // 0.15.2
         Lang::Zig => output.trim(),
+        Lang::External(_) => return None,
+        Lang::Variant(variant) => return parse_version(variant.base(), output),
     };
 
     parse_semver(version_str)
@@ -175,6 +214,12 @@ pub fn install_hint(lang: Lang) -> String {
         Lang::C => platform_clang_hint(),
         Lang::CSharp => "Install .NET: https://dotnet.microsoft.com/download".to_string(),
         Lang::Zig => "Install Zig: https://ziglang.org/download/".to_string(),
+        Lang::External(_) => {
+            format!("Check the command under [runtimes.{}] in polybench.toml", lang.as_str())
+        }
+        Lang::Variant(_) => {
+            format!("Check the toolchain under [variants.{}] in polybench.toml", lang.as_str())
+        }
     }
 }
 
@@ -18,7 +18,7 @@ use std::{env, fs, io::Read, path::PathBuf, process::Command};
 
 /// Returns true if poly-bench can auto-install this language.
 pub fn can_auto_install(lang: Lang) -> bool {
-    lang != Lang::C
+    lang != Lang::C && !lang.is_external()
 }
 
 /// Install a language runtime to the Polybench toolchain directory.
@@ -41,7 +41,9 @@ pub fn install_toolchain(lang: Lang) -> Result<PathBuf> {
         Lang::Python => install_python_toolchain(),
         Lang::Zig => install_zig_toolchain(),
         Lang::CSharp => install_dotnet_toolchain(),
-        Lang::C => Err(runtime_check::not_installed_error(lang)),
+        Lang::C | Lang::External(_) | Lang::Variant(_) => {
+            Err(runtime_check::not_installed_error(lang))
+        }
     };
 
     match &result {
@@ -64,6 +64,8 @@ fn generate_shim_source(lang: Lang) -> String {
         Lang::Rust => "cargo",
         Lang::CSharp => "dotnet",
         Lang::C => panic!("C has no shim"),
+        Lang::External(_) => panic!("external runtimes have no shim"),
+        Lang::Variant(_) => panic!("runtime variants have no shim"),
     };
 
     format!(
@@ -118,6 +120,8 @@ fn generate_shim_source_windows(lang: Lang) -> String {
         Lang::Rust => "cargo",
         Lang::CSharp => "dotnet",
         Lang::C => panic!("C has no shim"),
+        Lang::External(_) => panic!("external runtimes have no shim"),
+        Lang::Variant(_) => panic!("runtime variants have no shim"),
     };
 
     format!(
@@ -173,6 +177,9 @@ pub fn create_shim(lang: Lang, toolchain_binary: &PathBuf) -> Result<PathBuf> {
     if lang == Lang::C {
         return Err(miette!("C does not use shims"));
     }
+    if lang.is_external() {
+        return Err(miette!("External runtimes do not use shims"));
+    }
 
     let shim_binary_path = shim_path(lang)?;
     let shim_directory = shim_dir()?;
@@ -229,6 +236,9 @@ pub fn create_shell_shim(lang: Lang, toolchain_binary: &PathBuf) -> Result<PathB
     if lang == Lang::C {
         return Err(miette!("C does not use shims"));
     }
+    if lang.is_external() {
+        return Err(miette!("External runtimes do not use shims"));
+    }
 
     let shim_binary_path = shim_path(lang)?;
     let shim_directory = shim_dir()?;
@@ -270,7 +280,7 @@ exec "{}" "$@"
 pub fn create_global_symlink(lang: Lang) -> Result<Option<PathBuf>> {
     use std::os::unix::fs::symlink;
 
-    if lang == Lang::C {
+    if lang == Lang::C || lang.is_external() {
         return Ok(None);
     }
 
@@ -67,6 +67,8 @@ pub fn pinned_version(lang: Lang) -> &'static str {
         Lang::CSharp => pinned::DOTNET,
         Lang::Rust => pinned::RUST,
         Lang::C => panic!("C has no pinned version - manual install required"),
+        Lang::External(_) => panic!("external runtimes have no pinned version"),
+        Lang::Variant(variant) => pinned_version(variant.base()),
     }
 }
 
@@ -80,6 +82,8 @@ pub fn minimum_version(lang: Lang) -> Version {
         Lang::CSharp => Version::from_tuple(minimum::DOTNET),
         Lang::Rust => Version::from_tuple(minimum::RUST),
         Lang::C => Version::new(0, 0, 0), // C accepts any clang version
+        Lang::External(_) => Version::new(0, 0, 0),
+        Lang::Variant(variant) => minimum_version(variant.base()),
     }
 }
 
@@ -181,6 +185,8 @@ pub fn toolchain_binary_path(lang: Lang, version: &str) -> Result<PathBuf> {
         Lang::Rust => base.join("bin").join("cargo"),
         Lang::CSharp => base.join("dotnet"),
         Lang::C => panic!("C has no toolchain binary"),
+        Lang::External(_) => panic!("external runtimes have no toolchain binary"),
+        Lang::Variant(variant) => return toolchain_binary_path(variant.base(), version),
     })
 }
 
@@ -207,6 +213,8 @@ pub fn shim_path(lang: Lang) -> Result<PathBuf> {
         Lang::Rust => "cargo",
         Lang::CSharp => "dotnet",
         Lang::C => panic!("C has no shim"),
+        Lang::External(_) => panic!("external runtimes have no shim"),
+        Lang::Variant(_) => panic!("runtime variants have no shim"),
     };
     let name = if cfg!(windows) { format!("{}.exe", binary_name) } else { binary_name.to_string() };
     shim_dir().map(|d| d.join(name))
@@ -242,6 +250,8 @@ pub fn global_symlink_path(lang: Lang) -> Result<PathBuf> {
         Lang::Rust => "cargo",
         Lang::CSharp => "dotnet",
         Lang::C => panic!("C has no global symlink"),
+        Lang::External(_) => panic!("external runtimes have no global symlink"),
+        Lang::Variant(_) => panic!("runtime variants have no global symlink"),
     };
     global_bin_dir().map(|d| d.join(binary_name))
 }
@@ -315,6 +315,8 @@ mod tests {
                 filtered_nanos_per_op: None,
                 timed_out: None,
                 run_nanos_per_op: None,
+                counters: None,
+                validation_error: None,
             },
         );
         measurements.insert(
@@ -355,6 +357,8 @@ mod tests {
                 filtered_nanos_per_op: None,
                 timed_out: None,
                 run_nanos_per_op: None,
+                counters: None,
+                validation_error: None,
             },
         );
 
@@ -490,6 +494,8 @@ mod tests {
                     filtered_nanos_per_op: None,
                     timed_out: None,
                     run_nanos_per_op: None,
+                    counters: None,
+                    validation_error: None,
                 },
             );
             measurements.insert(
@@ -530,6 +536,8 @@ mod tests {
                     filtered_nanos_per_op: None,
                     timed_out: None,
                     run_nanos_per_op: None,
+                    counters: None,
+                    validation_error: None,
                 },
             );
             benchmarks.push(BenchmarkResult::new(
@@ -4,9 +4,8 @@ use poly_bench_ir::ChartDirectiveIR;
 use poly_bench_runtime::measurement::Measurement;
 
 use super::{
-    axis_label_for_scale, compute_ci_bounds, derive_y_scale_params, escape_xml,
-    extract_numeric_value, filter_benchmarks, generate_y_ticks, inverse_transform_y, lang_color,
-    make_y_to_px,
+    axis_label_for_scale, benchmark_x_value, compute_ci_bounds, derive_y_scale_params, escape_xml,
+    filter_benchmarks, generate_y_ticks, inverse_transform_y, lang_color, make_y_to_px,
     regression::{self, SelectedModel},
     sort_benchmarks, split_gap_bounds, y_upper_with_headroom, YAxisScale,
 };
@@ -112,7 +111,7 @@ pub fn generate(
     let x_values: Vec<f64> = filtered
         .iter()
         .enumerate()
-        .map(|(i, b)| extract_numeric_value(&b.name).map(|n| n as f64).unwrap_or((i + 1) as f64))
+        .map(|(i, b)| benchmark_x_value(b).unwrap_or((i + 1) as f64))
         .collect();
     let x_min = *x_values.first().unwrap_or(&1.0);
     let x_max = *x_values.last().unwrap_or(&x_min);
@@ -990,6 +989,8 @@ mod tests {
                 filtered_nanos_per_op: None,
                 timed_out: None,
                 run_nanos_per_op: None,
+                counters: None,
+                validation_error: None,
             },
         );
         measurements.insert(
@@ -1030,6 +1031,8 @@ mod tests {
                 filtered_nanos_per_op: None,
                 timed_out: None,
                 run_nanos_per_op: None,
+                counters: None,
+                validation_error: None,
             },
         );
 
@@ -290,7 +290,7 @@ pub(crate) fn svg_gradient_defs() -> String {
         lang_color, lang_gradient_end, lang_gradient_id, supported_languages,
     };
     let mut s = String::new();
-    for lang in supported_languages() {
+    for lang in &supported_languages() {
         s.push_str(&format!(
             "  <linearGradient id=\"{}\" x1=\"0\" y1=\"0\" x2=\"1\" y2=\"0\">\n\
                 <stop offset=\"0%\" stop-color=\"{}\" stop-opacity=\"0.95\"/>\n\
@@ -551,6 +551,14 @@ pub fn extract_numeric_value(name: &str) -> Option<i64> {
     }
 }
 
+/// X-axis value for a benchmark in trend charts.
+///
+/// Prefers the first numeric parameter binding from a parameterized benchmark and falls back
+/// to the number embedded in the benchmark name (e.g. `sortN_1000` -> 1000).
+pub fn benchmark_x_value(bench: &BenchmarkResult) -> Option<f64> {
+    bench.param_x_value().or_else(|| extract_numeric_value(&bench.name).map(|n| n as f64))
+}
+
 /// Sort benchmarks based on directive parameters
 pub fn sort_benchmarks(benchmarks: &mut [&BenchmarkResult], directive: &ChartDirectiveIR) {
     // Default to "natural" sorting which handles numeric values in names correctly
@@ -613,12 +621,14 @@ pub fn sort_benchmarks(benchmarks: &mut [&BenchmarkResult], directive: &ChartDir
             "name" => a.name.cmp(&b.name),
             // "natural" is the default - sorts by numeric value in name, then alphabetically
             "natural" | _ => {
-                let num_a = extract_numeric_value(&a.name);
-                let num_b = extract_numeric_value(&b.name);
+                let num_a = benchmark_x_value(a);
+                let num_b = benchmark_x_value(b);
 
                 match (num_a, num_b) {
                     // Both have numeric values - sort by number
-                    (Some(na), Some(nb)) => na.cmp(&nb),
+                    (Some(na), Some(nb)) => {
+                        na.partial_cmp(&nb).unwrap_or(std::cmp::Ordering::Equal)
+                    }
                     // Only one has numeric - numeric comes first
                     (Some(_), None) => std::cmp::Ordering::Less,
                     (None, Some(_)) => std::cmp::Ordering::Greater,
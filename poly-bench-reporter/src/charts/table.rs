@@ -30,7 +30,7 @@ pub fn generate(
 
     // Build column structure: (label, lang_opt, width) - lang_opt is Some(lang) for lang columns
     let mut columns: Vec<(&'static str, Option<Lang>, i32)> = vec![("Benchmark", None, 150)];
-    for lang in supported_languages() {
+    for lang in &supported_languages() {
         if filtered.iter().any(|b| b.measurements.contains_key(lang)) {
             columns.push((lang_label(*lang), Some(*lang), MIN_COL_WIDTH));
         }
@@ -114,7 +114,7 @@ pub fn generate(
         };
 
         let mut values: Vec<(Lang, f64)> = Vec::new();
-        for lang in supported_languages() {
+        for lang in &supported_languages() {
             if let Some(v) = bench.measurements.get(lang).and_then(primary_value) {
                 values.push((*lang, v));
             }
@@ -3,8 +3,15 @@
 use colored::Colorize;
 use miette::Result;
 use poly_bench_dsl::{BenchmarkKind, Lang};
-use poly_bench_executor::{comparison::BenchmarkResult, BenchmarkResults, SuiteResults};
-use poly_bench_runtime::{lang_label, measurement::Measurement, supported_languages};
+use poly_bench_executor::{
+    comparison::BenchmarkResult, BenchmarkResults, RegressionReport, RegressionStatistic,
+    RegressionVerdict, SuiteResults,
+};
+use poly_bench_runtime::{
+    lang_label,
+    measurement::{HardwareCounters, Measurement, Significance},
+    memory_metric_note, supported_languages,
+};
 use std::collections::HashMap;
 
 /// Benchmark configuration for display
@@ -88,6 +95,97 @@ fn format_ops_per_sec(ops: f64) -> String {
 const ASYNC_WARN_MIN_SUCCESS_RATIO: f64 = 0.95;
 const ASYNC_WARN_MAX_RATIO_SPREAD: f64 = 0.05;
 
+/// Format a per-op event count compactly (e.g. `1.24k`, `3.10M`)
+fn format_count(v: f64) -> String {
+    if v >= 1e9 {
+        format!("{:.2}G", v / 1e9)
+    } else if v >= 1e6 {
+        format!("{:.2}M", v / 1e6)
+    } else if v >= 1e3 {
+        format!("{:.2}k", v / 1e3)
+    } else if v >= 10.0 {
+        format!("{:.0}", v)
+    } else {
+        format!("{:.2}", v)
+    }
+}
+
+/// One-line summary of hardware counters, or the reason they are unavailable
+fn counter_summary(c: &HardwareCounters) -> String {
+    if !c.has_values() {
+        return format!("unavailable ({})", c.unavailable.as_deref().unwrap_or("no counters read"));
+    }
+    let mut parts = Vec::new();
+    if let Some(v) = c.instructions_per_op {
+        parts.push(format!("{} instr/op", format_count(v)));
+    }
+    if let Some(v) = c.cycles_per_op {
+        parts.push(format!("{} cycles/op", format_count(v)));
+    }
+    if let Some(ipc) = c.ipc() {
+        parts.push(format!("IPC {:.2}", ipc));
+    }
+    if let Some(v) = c.cache_misses_per_op {
+        parts.push(format!("{} cache-miss/op", format_count(v)));
+    }
+    if let Some(v) = c.branch_misses_per_op {
+        parts.push(format!("{} branch-miss/op", format_count(v)));
+    }
+    parts.join(" · ")
+}
+
+/// Equivalence status lines for a benchmark marked `equivalent: true`: one per mismatch, or
+/// a single pass line
+fn equivalence_lines(bench: &BenchmarkResult) -> Vec<(bool, String)> {
+    let Some(check) = &bench.equivalence else { return Vec::new() };
+    let mut lines: Vec<(bool, String)> =
+        check.mismatches.iter().map(|m| (false, format!("outputs differ: {}", m.diff))).collect();
+    if check.passed && check.compared() >= 2 {
+        let langs: Vec<&str> = supported_languages()
+            .iter()
+            .filter(|l| check.outputs.contains_key(l))
+            .map(|l| l.as_str())
+            .collect();
+        lines.push((true, format!("outputs equivalent ({})", langs.join(", "))));
+    }
+    if !check.unchecked.is_empty() {
+        let langs: Vec<&str> = check.unchecked.iter().map(|l| l.as_str()).collect();
+        lines.push((true, format!("output not captured: {}", langs.join(", "))));
+    }
+    lines
+}
+
+fn print_equivalence(bench: &BenchmarkResult) {
+    for (ok, line) in equivalence_lines(bench) {
+        if ok {
+            println!("   {}", format!("  {}", line).dimmed());
+        } else {
+            println!("   {} {}", "✗".red(), line.red());
+        }
+    }
+}
+
+/// Failed `validate` expressions, one line per language
+fn print_validation(bench: &BenchmarkResult) {
+    for lang in &supported_languages() {
+        if let Some(error) = bench.measurements.get(lang).and_then(|m| m.validation_error.as_ref())
+        {
+            println!("   {} {}", "✗".red(), format!("{}: {}", lang_short_name(*lang), error).red());
+        }
+    }
+}
+
+/// Runs taken by a benchmark with a `ciTarget`; highlighted when the target was missed
+fn print_adaptive(bench: &BenchmarkResult) {
+    let Some(outcome) = &bench.adaptive else { return };
+    let line = format!("adaptive: {}", outcome.summary());
+    if outcome.target_met {
+        println!("   {}", format!("  {}", line).dimmed());
+    } else {
+        println!("   {} {}", "!".yellow(), line.yellow());
+    }
+}
+
 fn lang_short_name(lang: Lang) -> &'static str {
     lang_label(lang)
 }
@@ -102,6 +200,8 @@ fn lang_colorize(s: &str, lang: Lang) -> colored::ColoredString {
         Lang::C => s.blue(),
         Lang::CSharp => s.magenta(),
         Lang::Zig => s.bright_yellow(),
+        Lang::External(_) => s.white(),
+        Lang::Variant(variant) => lang_colorize(s, variant.base()),
     }
 }
 
@@ -164,7 +264,8 @@ pub fn report_with_options(results: &BenchmarkResults, options: &ReportOptions)
         let has_config = config.iterations.is_some() ||
             config.warmup.is_some() ||
             config.timeout_ms.is_some() ||
-            config.order.is_some();
+            config.order.is_some() ||
+            results.environment.is_some();
 
         if has_config {
             println!("{}", "CONFIG".bold().underline());
@@ -184,6 +285,11 @@ pub fn report_with_options(results: &BenchmarkResults, options: &ReportOptions)
             if let Some(ref ord) = config.order {
                 println!("  {:<20} {}", "Execution Order:", ord);
             }
+            if let Some(ref environment) = results.environment {
+                for (label, value) in environment.summary_rows() {
+                    println!("  {:<20} {}", format!("{}:", label), value.dimmed());
+                }
+            }
             println!();
         }
     }
@@ -213,6 +319,17 @@ pub fn report_with_options(results: &BenchmarkResults, options: &ReportOptions)
         print_suite_with_options(suite, options);
     }
 
+    if !results.skipped.is_empty() {
+        println!(" {} {}", "○".dimmed(), format!("{} skipped", results.skipped.len()).bold());
+        for skipped in &results.skipped {
+            println!(
+                "   {}",
+                format!("· {}.{} ({})", skipped.suite, skipped.name, skipped.reason).dimmed()
+            );
+        }
+        println!();
+    }
+
     let wins_parts: Vec<String> = supported_languages()
         .iter()
         .map(|lang| {
@@ -229,19 +346,57 @@ pub fn report_with_options(results: &BenchmarkResults, options: &ReportOptions)
         summary.ties,
         summary.geo_mean_speedup
     );
+    let equivalence_failures = results.equivalence_failures();
+    if !equivalence_failures.is_empty() {
+        let names: Vec<&str> = equivalence_failures.iter().map(|b| b.full_name.as_str()).collect();
+        println!(
+            "   {} {}",
+            "✗".red(),
+            format!(
+                "{} benchmark(s) failed the equivalence check: {}",
+                names.len(),
+                names.join(", ")
+            )
+            .red()
+        );
+    }
+    let validation_failures = results.validation_failures();
+    if !validation_failures.is_empty() {
+        let names: Vec<&str> = validation_failures.iter().map(|b| b.full_name.as_str()).collect();
+        println!(
+            "   {} {}",
+            "✗".red(),
+            format!("{} benchmark(s) failed validation: {}", names.len(), names.join(", ")).red()
+        );
+    }
+    if summary.parallel_benchmarks > 0 {
+        println!(
+            "   {}",
+            format!(
+                "{} benchmarks from parallel suites excluded from the summary",
+                summary.parallel_benchmarks
+            )
+            .dimmed()
+        );
+    }
     println!();
 
     Ok(())
 }
 
 fn print_suite_with_options(suite: &SuiteResults, options: &ReportOptions) {
-    let icon = "✓";
+    let icon = if suite.benchmarks.iter().any(|b| b.equivalence_failed() || b.validation_failed()) {
+        "✗".red()
+    } else {
+        "✓".green()
+    };
 
     // Suite header
+    let mode = if suite.is_parallel() { " [parallel]" } else { "" };
     if let Some(ref desc) = suite.description {
-        println!(" {} {} {}", icon.green(), suite.name.bold(), desc.dimmed());
+        println!(" {} {}{} {}", icon, suite.name.bold(), mode.yellow(), desc.dimmed());
     } else {
-        println!(" {} {}", icon.green(), suite.name.bold());
+        println!(" {} {}{}", icon, suite.name.bold(), mode.yellow());
     }
 
     // Distribution stats table (vitest/tinybench style)
@@ -354,7 +509,7 @@ fn print_distribution_table(
     };
 
     let mut best_worst: HashMap<Lang, (Option<&str>, Option<&str>)> = HashMap::new();
-    for lang in supported_languages() {
+    for lang in &supported_languages() {
         let best = benchmarks
             .iter()
             .filter_map(|b| b.measurements.get(lang).map(|m| (b.name.as_str(), metric_val(m))))
@@ -390,7 +545,7 @@ fn print_distribution_table(
         if is_memory { (" lowest", " highest") } else { (" fastest", " slowest") };
 
     for bench in benchmarks {
-        for lang in supported_languages() {
+        for lang in &supported_languages() {
             let Some(m) = bench.measurements.get(lang) else { continue };
 
             let (lang_best, lang_worst) = best_worst.get(lang).copied().unwrap_or((None, None));
@@ -503,6 +658,20 @@ fn print_distribution_table(
             println!("   {}", "  mode: async-sequential".dimmed());
         }
 
+        for lang in &supported_languages() {
+            if let Some(counters) = bench.measurements.get(lang).and_then(|m| m.counters.as_ref()) {
+                println!(
+                    "   {}",
+                    format!("  counters {}: {}", lang_short_name(*lang), counter_summary(counters))
+                        .dimmed()
+                );
+            }
+        }
+
+        print_equivalence(bench);
+        print_validation(bench);
+        print_adaptive(bench);
+
         let primary = |m: &Measurement| -> f64 {
             if is_memory {
                 m.bytes_per_op.map(|b| b as f64).unwrap_or(f64::MAX)
@@ -512,7 +681,7 @@ fn print_distribution_table(
         };
 
         let mut times: Vec<(Lang, f64)> = Vec::new();
-        for lang in supported_languages() {
+        for lang in &supported_languages() {
             if let Some(v) = bench.measurements.get(lang).map(primary) {
                 times.push((*lang, v));
             }
@@ -521,17 +690,34 @@ fn print_distribution_table(
         if times.len() >= 2 {
             times.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
             let (fastest_lang, fastest_time) = times[0];
-            let (_, second_time) = times[1];
+            let (runner_up, second_time) = times[1];
             let speedup = second_time / fastest_time;
+            let significance = if is_memory {
+                None
+            } else {
+                Significance::between(
+                    &bench.measurements[&fastest_lang],
+                    &bench.measurements[&runner_up],
+                )
+            };
 
             let winner_str = if speedup < 1.05 {
                 "  → Similar performance".dimmed()
+            } else if let Some(test) = significance.as_ref().filter(|t| !t.significant) {
+                format!(
+                    "  → Inconclusive: {} is {:.2}x faster, but not significant ({})",
+                    lang_label(fastest_lang),
+                    speedup,
+                    test.describe()
+                )
+                .dimmed()
             } else {
                 let fastest_name = lang_label(fastest_lang);
+                let note = significance.map(|t| format!(" ({})", t.describe())).unwrap_or_default();
                 let msg = if is_memory {
                     format!("  → {} uses {:.2}x less memory", fastest_name, speedup)
                 } else {
-                    format!("  → {} is {:.2}x faster", fastest_name, speedup)
+                    format!("  → {} is {:.2}x faster{}", fastest_name, speedup, note)
                 };
                 lang_colorize(&msg, fastest_lang)
             };
@@ -576,6 +762,22 @@ fn print_distribution_table(
         // Add visual separation between benchmarks
         println!();
     }
+
+    if is_memory {
+        for (lang, note) in memory_metric_notes(benchmarks) {
+            println!("   {}", format!("bytes/op {}: {}", lang_short_name(lang), note).dimmed());
+        }
+    }
+}
+
+/// Languages in `benchmarks` whose memory numbers are not cumulative bytes and allocation counts
+/// per op, with how they are measured instead
+fn memory_metric_notes(benchmarks: &[BenchmarkResult]) -> Vec<(Lang, &'static str)> {
+    supported_languages()
+        .into_iter()
+        .filter(|lang| benchmarks.iter().any(|b| b.measurements.contains_key(lang)))
+        .filter_map(|lang| memory_metric_note(lang).map(|note| (lang, note)))
+        .collect()
 }
 
 /// Print compact table (legacy format)
@@ -630,7 +832,8 @@ fn print_compact_table(
                 poly_bench_runtime::measurement::ComparisonWinner::Second => {
                     result_str.cyan().to_string()
                 }
-                poly_bench_runtime::measurement::ComparisonWinner::Tie => {
+                poly_bench_runtime::measurement::ComparisonWinner::Tie |
+                poly_bench_runtime::measurement::ComparisonWinner::Inconclusive => {
                     result_str.dimmed().to_string()
                 }
             }
@@ -672,15 +875,109 @@ fn print_compact_table(
                 result_colored
             );
         }
+        print_equivalence(bench);
+        print_adaptive(bench);
+    }
+}
+
+/// Print a baseline vs candidate comparison produced by `poly-bench compare`
+pub fn report_regressions(report: &RegressionReport) -> Result<()> {
+    println!();
+    println!("{}", "─".repeat(78));
+    println!("  COMPARE  {} → {}", report.baseline_label, report.candidate_label);
+    println!("{}", "─".repeat(78));
+    println!();
+    println!(
+        "   {:<40} {:>12} {:>12} {:>9} {:>17}",
+        "name".dimmed(),
+        "baseline".dimmed(),
+        "candidate".dimmed(),
+        "change".dimmed(),
+        "95% CI".dimmed()
+    );
+
+    for entry in &report.entries {
+        let name = format!("· {}: {}.{}", lang_label(entry.lang), entry.suite, entry.benchmark);
+        let format_value = |v: f64| {
+            if entry.is_memory {
+                Measurement::format_bytes(v as u64)
+            } else {
+                Measurement::format_duration(v)
+            }
+        };
+        let ci = match (entry.ci_95_lower, entry.ci_95_upper) {
+            (Some(lo), Some(hi)) => format!("[{:.3}x, {:.3}x]", lo, hi),
+            _ => "-".to_string(),
+        };
+        let change = format!("{:+.1}%", entry.change_percent());
+        let change = match entry.verdict {
+            RegressionVerdict::Regression => change.red().bold(),
+            RegressionVerdict::Improvement => change.green().bold(),
+            RegressionVerdict::Unchanged => change.dimmed(),
+        };
+        println!(
+            "   {:<40} {:>12} {:>12} {:>9} {:>17}",
+            lang_colorize(&name, entry.lang),
+            format_value(entry.baseline),
+            format_value(entry.candidate),
+            change,
+            ci.dimmed()
+        );
+    }
+    println!();
+    if report.entries.iter().any(|e| e.statistic == RegressionStatistic::Median) {
+        println!(
+            "   {}",
+            "Values are medians where a 95% CI is shown (the statistic the change compares), \
+             means otherwise"
+                .dimmed()
+        );
+        println!();
     }
+
+    for name in &report.missing_in_candidate {
+        println!("   {} {} (only in baseline)", "-".yellow(), name);
+    }
+    for name in &report.new_in_candidate {
+        println!("   {} {} (only in candidate)", "+".cyan(), name);
+    }
+    for name in &report.mode_mismatch {
+        println!("   {} {} (parallel vs isolated run, not compared)", "~".yellow(), name);
+    }
+    if !report.missing_in_candidate.is_empty() ||
+        !report.new_in_candidate.is_empty() ||
+        !report.mode_mismatch.is_empty()
+    {
+        println!();
+    }
+
+    let regressions = report.regressions().count();
+    let improvements = report.improvements().count();
+    let summary = format!(
+        "   Summary: {} regressions | {} improvements | {} unchanged (threshold {}%)",
+        regressions,
+        improvements,
+        report.entries.len() - regressions - improvements,
+        report.threshold_percent
+    );
+    if regressions > 0 {
+        println!("{}", summary.red());
+    } else {
+        println!("{}", summary);
+    }
+    println!();
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::async_reliability_warnings;
+    use super::{
+        async_reliability_warnings, counter_summary, equivalence_lines, memory_metric_notes,
+    };
     use poly_bench_dsl::{BenchmarkKind, Lang};
     use poly_bench_executor::comparison::BenchmarkResult;
-    use poly_bench_runtime::measurement::Measurement;
+    use poly_bench_runtime::measurement::{HardwareCounters, Measurement};
     use std::collections::HashMap;
 
     #[test]
@@ -715,6 +1012,33 @@ mod tests {
         assert!(warnings.iter().any(|w| w.contains("spread")));
     }
 
+    #[test]
+    fn test_memory_metric_notes_cover_languages_without_counts() {
+        let mut measurements = HashMap::new();
+        measurements
+            .insert(Lang::Go, Measurement::from_aggregate(100, 100_000).with_memory(64, Some(1)));
+        measurements
+            .insert(Lang::Python, Measurement::from_aggregate(100, 90_000).with_memory(80, None));
+        let bench = BenchmarkResult::new(
+            "alloc".to_string(),
+            "suite_alloc".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            poly_bench_dsl::SuiteType::Memory,
+            "strict".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let notes = memory_metric_notes(&[bench]);
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].0, Lang::Python);
+        assert!(notes[0].1.contains("no allocation counts"));
+    }
+
     #[test]
     fn test_async_reliability_warnings_empty_for_sync_benchmarks() {
         let bench = BenchmarkResult::new(
@@ -733,4 +1057,63 @@ mod tests {
 
         assert!(async_reliability_warnings(&bench).is_empty());
     }
+
+    #[test]
+    fn test_counter_summary_values_and_unavailable() {
+        let counters = HardwareCounters {
+            instructions_per_op: Some(2400.0),
+            cycles_per_op: Some(1200.0),
+            cache_misses_per_op: Some(0.5),
+            branch_misses_per_op: None,
+            unavailable: None,
+        };
+        assert_eq!(
+            counter_summary(&counters),
+            "2.40k instr/op · 1.20k cycles/op · IPC 2.00 · 0.50 cache-miss/op"
+        );
+
+        let denied = HardwareCounters {
+            unavailable: Some("perf_event_open(instructions): EACCES".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(counter_summary(&denied), "unavailable (perf_event_open(instructions): EACCES)");
+    }
+
+    #[test]
+    fn test_equivalence_lines_report_mismatch() {
+        let output = |raw: &str| {
+            let mut m = Measurement::from_aggregate(100, 100_000);
+            m.raw_result = Some(raw.to_string());
+            m
+        };
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, output("[1,2,3]"));
+        measurements.insert(Lang::Rust, output("[1, 2, 4]"));
+        measurements.insert(Lang::C, output("sink"));
+
+        let bench = BenchmarkResult::new(
+            "sort".to_string(),
+            "suite_sort".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            poly_bench_dsl::SuiteType::Performance,
+            "strict".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+        assert!(equivalence_lines(&bench).is_empty());
+
+        let bench = bench.with_equivalence_check();
+        assert!(bench.equivalence_failed());
+        assert_eq!(
+            equivalence_lines(&bench),
+            vec![
+                (false, "outputs differ: rust vs go at [2]: 4 vs 3".to_string()),
+                (true, "output not captured: c".to_string()),
+            ]
+        );
+    }
 }
@@ -0,0 +1,186 @@
+//! GitHub check-run output
+//!
+//! Produces the JSON body for the Checks API (`POST /repos/{owner}/{repo}/check-runs`, with
+//! `head_sha` added by the caller). Failures are the ones the JUnit report marks as
+//! `<failure>` (timeouts, failed runs, failed validations, equivalence mismatches); the
+//! conclusion is `failure` when any exist, `neutral` when only warnings do, else `success`.
+//! The full markdown report goes in `output.text`.
+
+use crate::{junit, markdown};
+use miette::Result;
+use poly_bench_executor::BenchmarkResults;
+use poly_bench_runtime::{measurement::DEFAULT_CV_THRESHOLD, supported_languages};
+use serde_json::json;
+
+/// Check name shown in the GitHub UI
+const CHECK_NAME: &str = "poly-bench";
+
+/// GitHub rejects `output.summary` and `output.text` longer than this
+const MAX_OUTPUT_CHARS: usize = 65_535;
+
+/// Generate a GitHub check-run JSON body from benchmark results
+pub fn report(results: &BenchmarkResults) -> Result<String> {
+    let mut failures = Vec::new();
+    let mut warnings = Vec::new();
+
+    for suite in &results.suites {
+        for bench in &suite.benchmarks {
+            let name = format!("{}.{}", suite.name, bench.name);
+            let threshold = bench.cv_threshold.unwrap_or(DEFAULT_CV_THRESHOLD);
+            for lang in &supported_languages() {
+                let Some(m) = bench.measurements.get(lang) else {
+                    continue;
+                };
+                if let Some((_, message, detail)) = junit::measurement_failure(m) {
+                    failures.push(format!("`{}` ({}): {} — {}", name, lang, message, detail));
+                }
+                for warning in junit::warnings(m, threshold) {
+                    warnings.push(format!("`{}` ({}): {}", name, lang, warning));
+                }
+            }
+            if let Some(check) = bench.equivalence.as_ref() {
+                for mismatch in &check.mismatches {
+                    failures.push(format!("`{}`: outputs differ: {}", name, mismatch.diff));
+                }
+            }
+        }
+    }
+
+    let conclusion = if !failures.is_empty() {
+        "failure"
+    } else if !warnings.is_empty() {
+        "neutral"
+    } else {
+        "success"
+    };
+    let total = results.summary.total_benchmarks;
+    let title = match (failures.len(), warnings.len()) {
+        (0, 0) => format!("{} benchmark(s) passed", total),
+        (0, w) => format!("{} benchmark(s), {} warning(s)", total, w),
+        (f, w) => format!("{} benchmark(s), {} failure(s), {} warning(s)", total, f, w),
+    };
+
+    let mut summary = format!("**{}**\n\n{}\n", title, results.summary.winner_description);
+    if !failures.is_empty() {
+        summary.push_str("\n### Failures\n\n");
+        for failure in &failures {
+            summary.push_str(&format!("- ❌ {}\n", failure));
+        }
+    }
+    if !warnings.is_empty() {
+        summary.push_str("\n### Warnings\n\n");
+        for warning in &warnings {
+            summary.push_str(&format!("- ⚠️ {}\n", warning));
+        }
+    }
+
+    let body = json!({
+        "name": CHECK_NAME,
+        "status": "completed",
+        "conclusion": conclusion,
+        "output": {
+            "title": title,
+            "summary": truncate(&summary),
+            "text": truncate(&markdown::report(results)?),
+        },
+    });
+    serde_json::to_string_pretty(&body)
+        .map_err(|e| miette::miette!("Failed to serialize check run: {}", e))
+}
+
+/// Cut `text` to GitHub's output limit on a character boundary
+fn truncate(text: &str) -> String {
+    const NOTE: &str = "\n\n… (truncated)";
+    if text.chars().count() <= MAX_OUTPUT_CHARS {
+        return text.to_string();
+    }
+    let keep = MAX_OUTPUT_CHARS - NOTE.chars().count();
+    let mut out: String = text.chars().take(keep).collect();
+    out.push_str(NOTE);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::{BenchmarkKind, Lang, SuiteType};
+    use poly_bench_executor::{BenchmarkResult, SuiteResults};
+    use poly_bench_runtime::measurement::Measurement;
+    use std::collections::HashMap;
+
+    fn results(go: Measurement) -> BenchmarkResults {
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, go);
+        let bench = BenchmarkResult::new(
+            "sort".to_string(),
+            "algo_sort".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "legacy".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+        BenchmarkResults::new(vec![SuiteResults::new(
+            "algo".to_string(),
+            None,
+            SuiteType::Performance,
+            vec![bench],
+        )])
+    }
+
+    fn check_run(results: &BenchmarkResults) -> serde_json::Value {
+        serde_json::from_str(&report(results).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_check_run_success() {
+        let mut m = Measurement::from_samples(vec![100, 101], 2);
+        m.is_stable = Some(true);
+        let run = check_run(&results(m));
+
+        assert_eq!(run["name"], "poly-bench");
+        assert_eq!(run["status"], "completed");
+        assert_eq!(run["conclusion"], "success");
+        assert_eq!(run["output"]["title"], "1 benchmark(s) passed");
+        assert!(run["output"]["text"].as_str().unwrap().starts_with("# Benchmark Report"));
+    }
+
+    #[test]
+    fn test_check_run_failure_lists_validation() {
+        let mut m = Measurement::from_samples(vec![100, 101], 2);
+        m.record_validation(Some(&"result == 3".to_string()), Some(false));
+        let run = check_run(&results(m));
+
+        assert_eq!(run["conclusion"], "failure");
+        let summary = run["output"]["summary"].as_str().unwrap();
+        assert!(summary.contains(
+            "- ❌ `algo.sort` (go): validation failed — validate `result == 3` returned false"
+        ));
+    }
+
+    #[test]
+    fn test_check_run_unstable_is_neutral() {
+        let mut m = Measurement::from_samples(vec![100, 101], 2);
+        m.is_stable = Some(false);
+        m.cv_percent = Some(9.0);
+        let run = check_run(&results(m));
+
+        assert_eq!(run["conclusion"], "neutral");
+        assert!(run["output"]["summary"]
+            .as_str()
+            .unwrap()
+            .contains("unstable result: cv 9.0% exceeds 5%"));
+    }
+
+    #[test]
+    fn test_truncate_respects_limit() {
+        let long = "é".repeat(MAX_OUTPUT_CHARS + 10);
+        let cut = truncate(&long);
+        assert_eq!(cut.chars().count(), MAX_OUTPUT_CHARS);
+        assert!(cut.ends_with("(truncated)"));
+    }
+}
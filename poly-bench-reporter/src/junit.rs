@@ -0,0 +1,434 @@
+//! JUnit XML report generator
+//!
+//! Each suite becomes a `<testsuite>` and each benchmark/language pair a `<testcase>`
+//! (`classname` = `suite.bench`, `name` = language). Timeouts, failed async runs, failed
+//! `validate` expressions and outputs that differ from the reference language
+//! (`equivalent: true`) are reported as `<failure>`s, unstable results and partial async
+//! errors as warnings in
+//! `<system-out>`, and timing data as `<property>` elements.
+
+use miette::Result;
+use poly_bench_dsl::{Lang, SuiteType};
+use poly_bench_executor::{BenchmarkResult, BenchmarkResults, CompileError, SuiteResults};
+use poly_bench_runtime::{
+    measurement::{Measurement, DEFAULT_CV_THRESHOLD},
+    supported_languages,
+};
+use std::fmt::Write;
+
+/// Generate a JUnit XML report from benchmark results
+pub fn report(results: &BenchmarkResults) -> Result<String> {
+    let mut suites_xml = String::new();
+    let (mut tests, mut failures, mut skipped) = (0, 0, 0);
+
+    for suite in &results.suites {
+        let suite_report = suite_xml(suite);
+        tests += suite_report.tests;
+        failures += suite_report.failures;
+        suites_xml.push_str(&suite_report.xml);
+    }
+
+    if !results.skipped.is_empty() {
+        let mut cases = String::new();
+        for bench in &results.skipped {
+            write!(
+                cases,
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"0\">\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                escape(&format!("{}.{}", bench.suite, bench.name)),
+                escape(&bench.name),
+                escape(&bench.reason)
+            )
+            .unwrap();
+        }
+        skipped = results.skipped.len();
+        tests += skipped;
+        write!(
+            suites_xml,
+            "  <testsuite name=\"filtered\" tests=\"{}\" failures=\"0\" errors=\"0\" skipped=\"{}\" time=\"0\">\n{}  </testsuite>\n",
+            skipped, skipped, cases
+        )
+        .unwrap();
+    }
+
+    Ok(document(tests, failures, skipped, &suites_xml))
+}
+
+/// Generate a JUnit XML report for benchmarks that failed pre-run compile validation.
+///
+/// Every affected benchmark/language pair becomes a failed testcase so CI dashboards show the
+/// failure even though no benchmark ran.
+pub fn report_compile_errors(errors: &[CompileError]) -> Result<String> {
+    let mut cases = String::new();
+    let mut tests = 0;
+    for err in errors {
+        let message = err.message.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("");
+        for bench in &err.benchmarks {
+            tests += 1;
+            write!(
+                cases,
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"0\">\n      <failure type=\"compile\" message=\"{}\">{}</failure>\n    </testcase>\n",
+                escape(bench),
+                escape(err.lang.as_str()),
+                escape(&format!("{} compile error: {}", err.source, message)),
+                escape(&err.message)
+            )
+            .unwrap();
+        }
+    }
+
+    let suite = format!(
+        "  <testsuite name=\"compile-validation\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"0\">\n{}  </testsuite>\n",
+        tests, tests, cases
+    );
+    Ok(document(tests, tests, 0, &suite))
+}
+
+/// Rendered `<testsuite>` with its counts
+struct SuiteXml {
+    xml: String,
+    tests: usize,
+    failures: usize,
+}
+
+fn suite_xml(suite: &SuiteResults) -> SuiteXml {
+    let mut cases = String::new();
+    let (mut tests, mut failures) = (0, 0);
+    let mut total_secs = 0.0;
+
+    for bench in &suite.benchmarks {
+        for lang in &supported_languages() {
+            let Some(m) = bench.measurements.get(lang) else {
+                continue;
+            };
+            tests += 1;
+            let secs = m.total_nanos as f64 / 1e9;
+            total_secs += secs;
+
+            let failure = failure_xml(m).or_else(|| equivalence_failure_xml(bench, *lang));
+            if failure.is_some() {
+                failures += 1;
+            }
+            let warnings = warnings(m, bench.cv_threshold.unwrap_or(DEFAULT_CV_THRESHOLD));
+
+            writeln!(
+                cases,
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\">",
+                escape(&format!("{}.{}", suite.name, bench.name)),
+                escape(lang.as_str()),
+                secs
+            )
+            .unwrap();
+            cases.push_str("      <properties>\n");
+            for (name, value) in properties(bench, m, suite.suite_type) {
+                writeln!(
+                    cases,
+                    "        <property name=\"{}\" value=\"{}\"/>",
+                    name,
+                    escape(&value)
+                )
+                .unwrap();
+            }
+            cases.push_str("      </properties>\n");
+            if let Some(failure) = failure {
+                cases.push_str(&failure);
+            }
+            if !warnings.is_empty() {
+                let text: Vec<String> =
+                    warnings.iter().map(|w| format!("WARNING: {}", w)).collect();
+                writeln!(cases, "      <system-out>{}</system-out>", escape(&text.join("\n")))
+                    .unwrap();
+            }
+            cases.push_str("    </testcase>\n");
+        }
+    }
+
+    let xml = format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.6}\">\n{}  </testsuite>\n",
+        escape(&suite.name),
+        tests,
+        failures,
+        total_secs,
+        cases
+    );
+    SuiteXml { xml, tests, failures }
+}
+
+/// Failure kind, message and detail for a timed-out, failed or invalid measurement
+pub(crate) fn measurement_failure(m: &Measurement) -> Option<(&'static str, &'static str, String)> {
+    let detail = m.async_error_samples.as_ref().and_then(|s| s.first()).cloned();
+    let (kind, message, detail) = if m.timed_out == Some(true) {
+        ("timeout", "benchmark timed out", detail)
+    } else if m.estimator_source.as_deref() == Some("async-error") {
+        ("error", "benchmark run failed", detail)
+    } else if let Some(error) = &m.validation_error {
+        ("validation", "validation failed", Some(error.clone()))
+    } else {
+        return None;
+    };
+    Some((kind, message, detail.unwrap_or_else(|| message.to_string())))
+}
+
+/// `<failure>` element for timed-out, failed or invalid measurements
+fn failure_xml(m: &Measurement) -> Option<String> {
+    let (kind, message, detail) = measurement_failure(m)?;
+    Some(format!(
+        "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+        kind,
+        message,
+        escape(&detail)
+    ))
+}
+
+/// `<failure>` element for a language whose output differs from the reference language
+fn equivalence_failure_xml(bench: &BenchmarkResult, lang: Lang) -> Option<String> {
+    let check = bench.equivalence.as_ref()?;
+    let mismatch = check.mismatches.iter().find(|m| m.lang == lang)?;
+    let expected = check.reference.and_then(|r| check.outputs.get(&r));
+    let actual = check.outputs.get(&lang);
+    Some(format!(
+        "      <failure type=\"equivalence\" message=\"{}\">expected: {}\nactual: {}</failure>\n",
+        escape(&format!("outputs differ: {}", mismatch.diff)),
+        escape(expected.map(String::as_str).unwrap_or("-")),
+        escape(actual.map(String::as_str).unwrap_or("-"))
+    ))
+}
+
+/// Non-fatal problems worth surfacing on an otherwise passing testcase
+pub(crate) fn warnings(m: &Measurement, cv_threshold: f64) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if m.is_stable == Some(false) {
+        warnings.push(format!(
+            "unstable result: cv {:.1}% exceeds {}%",
+            m.cv_percent.unwrap_or(0.0),
+            cv_threshold
+        ));
+    }
+    if m.timed_out != Some(true) && m.estimator_source.as_deref() != Some("async-error") {
+        if let Some(errors) = m.async_error_count.filter(|&n| n > 0) {
+            warnings.push(format!("{} async iterations failed", errors));
+        }
+    }
+    warnings
+}
+
+fn properties(
+    bench: &BenchmarkResult,
+    m: &Measurement,
+    suite_type: SuiteType,
+) -> Vec<(&'static str, String)> {
+    let mut props = vec![
+        ("comparison_mode", bench.comparison_mode.clone()),
+        ("iterations", m.iterations.to_string()),
+        ("nanos_per_op", format!("{:.3}", m.nanos_per_op)),
+        ("ops_per_sec", format!("{:.3}", m.ops_per_sec)),
+    ];
+    let optional = [
+        ("samples", m.samples.map(|v| v.to_string())),
+        ("min_nanos", m.min_nanos.map(|v| v.to_string())),
+        ("max_nanos", m.max_nanos.map(|v| v.to_string())),
+        ("p50_nanos", m.p50_nanos.map(|v| v.to_string())),
+        ("p99_nanos", m.p99_nanos.map(|v| v.to_string())),
+        ("cv_percent", m.cv_percent.map(|v| format!("{:.3}", v))),
+        ("rme_percent", m.rme_percent.map(|v| format!("{:.3}", v))),
+        ("ci_95_lower", m.ci_95_lower.map(|v| format!("{:.3}", v))),
+        ("ci_95_upper", m.ci_95_upper.map(|v| format!("{:.3}", v))),
+        ("run_count", m.run_count.map(|v| v.to_string())),
+        ("warmup_nanos", m.warmup_nanos.map(|v| v.to_string())),
+        ("bytes_per_op", m.bytes_per_op.map(|v| v.to_string())),
+        ("allocs_per_op", m.allocs_per_op.map(|v| v.to_string())),
+    ];
+    props.extend(optional.into_iter().filter_map(|(name, value)| value.map(|v| (name, v))));
+    if suite_type == SuiteType::Memory {
+        props.push(("suite_type", "memory".to_string()));
+    }
+    for param in &bench.params {
+        props.push(("param", format!("{}={}", param.name, param.value)));
+    }
+    props
+}
+
+fn document(tests: usize, failures: usize, skipped: usize, body: &str) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"poly-bench\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">\n{}</testsuites>\n",
+        tests, failures, skipped, body
+    )
+}
+
+/// Escape text for use in XML attributes and character data
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than whitespace are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poly_bench_dsl::{BenchmarkKind, Lang};
+    use poly_bench_executor::{ErrorSource, SuiteResults};
+    use poly_bench_ir::SkippedBenchmark;
+    use std::collections::HashMap;
+
+    fn results() -> BenchmarkResults {
+        let mut go = Measurement::from_samples(vec![100, 102, 98, 101], 4);
+        go.is_stable = Some(false);
+        go.cv_percent = Some(12.5);
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, go);
+        measurements.insert(Lang::TypeScript, Measurement::timeout_marker());
+        let bench = BenchmarkResult::new(
+            "a<b".to_string(),
+            "hash_a<b".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "legacy".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+        BenchmarkResults::new(vec![SuiteResults::new(
+            "hash".to_string(),
+            None,
+            SuiteType::Performance,
+            vec![bench],
+        )])
+        .with_skipped(vec![SkippedBenchmark {
+            suite: "hash".to_string(),
+            name: "slow".to_string(),
+            reason: "excluded by --exclude-tag slow".to_string(),
+        }])
+    }
+
+    #[test]
+    fn test_junit_testcases_failures_and_properties() {
+        let xml = report(&results()).unwrap();
+
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains(
+            "<testsuites name=\"poly-bench\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"hash.a&lt;b\" name=\"go\""));
+        assert!(xml.contains("<property name=\"nanos_per_op\" value=\""));
+        assert!(xml.contains("<property name=\"comparison_mode\" value=\"legacy\"/>"));
+        assert!(xml.contains("WARNING: unstable result: cv 12.5% exceeds 5%"));
+        assert!(xml.contains("<failure type=\"timeout\""));
+        assert!(xml.contains("<skipped message=\"excluded by --exclude-tag slow\"/>"));
+    }
+
+    #[test]
+    fn test_junit_equivalence_mismatch_is_failure() {
+        let output = |raw: &str| {
+            let mut m = Measurement::from_samples(vec![100, 101], 2);
+            m.raw_result = Some(raw.to_string());
+            m
+        };
+        let mut measurements = HashMap::new();
+        measurements.insert(Lang::Go, output("\"0xdead\""));
+        measurements.insert(Lang::Python, output("[222, 172]"));
+        let bench = BenchmarkResult::new(
+            "digest".to_string(),
+            "hash_digest".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "legacy".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .with_equivalence_check();
+        let results = BenchmarkResults::new(vec![SuiteResults::new(
+            "hash".to_string(),
+            None,
+            SuiteType::Performance,
+            vec![bench],
+        )]);
+        let xml = report(&results).unwrap();
+
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains(
+            "<failure type=\"equivalence\" message=\"outputs differ: python vs go at [1]: 172 vs 173\">expected: [222,173]\nactual: [222,172]</failure>"
+        ));
+    }
+
+    fn single(lang: Lang, m: Measurement) -> BenchmarkResult {
+        let mut measurements = HashMap::new();
+        measurements.insert(lang, m);
+        BenchmarkResult::new(
+            "sort".to_string(),
+            "algo_sort".to_string(),
+            BenchmarkKind::Sync,
+            None,
+            measurements,
+            SuiteType::Performance,
+            "legacy".to_string(),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn suite_of(bench: BenchmarkResult) -> BenchmarkResults {
+        BenchmarkResults::new(vec![SuiteResults::new(
+            "algo".to_string(),
+            None,
+            SuiteType::Performance,
+            vec![bench],
+        )])
+    }
+
+    #[test]
+    fn test_junit_validation_failure() {
+        let mut m = Measurement::from_samples(vec![100, 101], 2);
+        m.record_validation(Some(&"result == 44".to_string()), Some(false));
+        let xml = report(&suite_of(single(Lang::Python, m))).unwrap();
+
+        assert!(xml.contains("tests=\"1\" failures=\"1\""));
+        assert!(xml.contains(
+            "<failure type=\"validation\" message=\"validation failed\">validate `result == 44` returned false</failure>"
+        ));
+    }
+
+    #[test]
+    fn test_junit_unstable_warning_uses_suite_threshold() {
+        let mut m = Measurement::from_samples(vec![100, 102, 98, 101], 4);
+        m.cv_percent = Some(12.5);
+        let xml = report(&suite_of(single(Lang::Go, m).with_cv_threshold(2.5))).unwrap();
+
+        assert!(xml.contains("WARNING: unstable result: cv 12.5% exceeds 2.5%"));
+    }
+
+    #[test]
+    fn test_junit_compile_errors_are_failures() {
+        let errors = vec![CompileError {
+            benchmarks: vec!["hash_keccak".to_string(), "hash_sha".to_string()],
+            lang: Lang::Rust,
+            message: "error[E0425]: cannot find value `x`\n --> src/main.rs:3".to_string(),
+            source: ErrorSource::Setup,
+        }];
+        let xml = report_compile_errors(&errors).unwrap();
+
+        assert!(xml.contains("tests=\"2\" failures=\"2\""));
+        assert!(
+            xml.contains("message=\"setup compile error: error[E0425]: cannot find value `x`\"")
+        );
+        assert_eq!(xml.matches("<testcase ").count(), 2);
+    }
+}
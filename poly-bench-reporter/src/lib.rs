@@ -3,7 +3,10 @@
 pub mod chart_executor;
 pub mod charts;
 pub mod console;
+pub mod github;
 pub mod json;
+pub mod junit;
 pub mod markdown;
+pub mod results_file;
 pub mod svg;
 pub use chart_executor::{execute_chart_directives, GeneratedChart};
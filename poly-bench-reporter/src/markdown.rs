@@ -2,9 +2,11 @@
 
 use miette::Result;
 use poly_bench_dsl::Lang;
-use poly_bench_executor::BenchmarkResults;
+use poly_bench_executor::{BenchmarkResults, SuiteResults};
 use poly_bench_runtime::{
-    lang_full_name, lang_icon, measurement::Measurement, supported_languages,
+    lang_full_name, lang_icon,
+    measurement::{Measurement, Significance},
+    supported_languages,
 };
 
 /// Generate markdown report
@@ -15,6 +17,15 @@ pub fn report(results: &BenchmarkResults) -> Result<String> {
     md.push_str("# Benchmark Report\n\n");
     md.push_str(&format!("Generated: {}\n\n", chrono_lite()));
 
+    if let Some(ref environment) = results.environment {
+        md.push_str("<details>\n<summary>Environment</summary>\n\n");
+        md.push_str("| | |\n|---|---|\n");
+        for (label, value) in environment.summary_rows() {
+            md.push_str(&format!("| {} | {} |\n", label, value.replace('|', "\\|")));
+        }
+        md.push_str("\n</details>\n\n");
+    }
+
     // Overall Summary
     md.push_str("## Overall Summary\n\n");
 
@@ -30,19 +41,34 @@ pub fn report(results: &BenchmarkResults) -> Result<String> {
     md.push_str("|--------|-------|\n");
     md.push_str(&format!("| Total Suites | {} |\n", summary.total_suites));
     md.push_str(&format!("| Total Benchmarks | {} |\n", summary.total_benchmarks));
-    for lang in supported_languages() {
+    if summary.parallel_benchmarks > 0 {
+        md.push_str(&format!(
+            "| Parallel Benchmarks (excluded) | {} |\n",
+            summary.parallel_benchmarks
+        ));
+    }
+    let equivalence_failures = results.equivalence_failures().len();
+    if equivalence_failures > 0 {
+        md.push_str(&format!("| ❌ Equivalence Failures | {} |\n", equivalence_failures));
+    }
+    let validation_failures = results.validation_failures().len();
+    if validation_failures > 0 {
+        md.push_str(&format!("| ❌ Validation Failures | {} |\n", validation_failures));
+    }
+    let compared = summary.total_benchmarks - summary.parallel_benchmarks;
+    for lang in &supported_languages() {
         let wins = summary.lang_wins.get(lang).copied().unwrap_or(0) as usize;
         md.push_str(&format!(
             "| {} Wins | {} ({}%) |\n",
             lang_full_name(*lang),
             wins,
-            (wins * 100) / summary.total_benchmarks.max(1)
+            (wins * 100) / compared.max(1)
         ));
     }
     md.push_str(&format!(
         "| Ties | {} ({}%) |\n",
         summary.ties,
-        (summary.ties * 100) / summary.total_benchmarks.max(1)
+        (summary.ties * 100) / compared.max(1)
     ));
     md.push_str(&format!("| Geometric Mean Speedup | {:.2}x |\n\n", summary.geo_mean_speedup));
 
@@ -60,6 +86,12 @@ pub fn report(results: &BenchmarkResults) -> Result<String> {
         if let Some(ref desc) = suite.description {
             md.push_str(&format!("_{}_\n\n", desc));
         }
+        if suite.is_parallel() {
+            md.push_str(
+                "> Measured with `order: parallel`: runtimes ran concurrently, so timings \
+                 include contention and are not comparable with isolated suites.\n\n",
+            );
+        }
 
         // Determine which languages are present in this suite
         let present_langs: Vec<Lang> = supported_languages()
@@ -103,19 +135,33 @@ pub fn report(results: &BenchmarkResults) -> Result<String> {
             times.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
             let result_str = if times.len() >= 2 {
                 let (best_lang, best_time) = times[0];
-                let (_, second_time) = times[1];
+                let (runner_up, second_time) = times[1];
                 let speedup = second_time / best_time.max(1e-9);
                 let icon = lang_icon(best_lang);
+                let significance = Significance::between(
+                    &bench.measurements[&best_lang],
+                    &bench.measurements[&runner_up],
+                );
                 if speedup < 1.05 {
                     "⚪ Similar".to_string()
+                } else if let Some(test) = significance.as_ref().filter(|t| !t.significant) {
+                    format!(
+                        "❔ Inconclusive ({} {:.2}x faster, {})",
+                        lang_full_name(best_lang),
+                        speedup,
+                        test.describe()
+                    )
                 } else {
-                    format!("{} {} {:.2}x faster", icon, lang_full_name(best_lang), speedup)
+                    let note =
+                        significance.map(|t| format!(" ({})", t.describe())).unwrap_or_default();
+                    format!("{} {} {:.2}x faster{}", icon, lang_full_name(best_lang), speedup, note)
                 }
             } else if let Some(ref cmp) = bench.comparison {
                 let icon = match cmp.winner {
                     poly_bench_runtime::measurement::ComparisonWinner::First => "🟢",
                     poly_bench_runtime::measurement::ComparisonWinner::Second => "🔵",
                     poly_bench_runtime::measurement::ComparisonWinner::Tie => "⚪",
+                    poly_bench_runtime::measurement::ComparisonWinner::Inconclusive => "❔",
                 };
                 format!("{} {}", icon, cmp.speedup_description())
             } else {
@@ -128,19 +174,120 @@ pub fn report(results: &BenchmarkResults) -> Result<String> {
         }
 
         md.push_str("\n");
+        md.push_str(&counters_table(suite));
+        md.push_str(&equivalence_table(suite));
+        md.push_str(&adaptive_table(suite));
     }
 
     // Legend
     md.push_str("## Legend\n\n");
-    for lang in supported_languages() {
+    for lang in &supported_languages() {
         md.push_str(&format!("- {} {} faster\n", lang_icon(*lang), lang_full_name(*lang)));
     }
     md.push_str("- ⚪ Similar (within 5%)\n");
+    md.push_str("- ❔ Inconclusive (more than 5% apart, but not statistically significant)\n");
+    md.push_str("- p = two-sided Mann-Whitney U p-value on per-operation samples\n");
+    md.push_str(
+        "- paired CI = bootstrap 95% CI of per-run time ratios, used instead of p when both \
+         sides ran the same number of runs (count ≥ 5); significant when it excludes 1.0\n",
+    );
+    md.push_str("- effect = Cliff's delta magnitude\n");
     md.push_str("- ns/op = nanoseconds per operation (lower is better)\n");
 
     Ok(md)
 }
 
+/// Hardware counter table for a suite (empty when no benchmark collected counters)
+fn counters_table(suite: &SuiteResults) -> String {
+    let fmt = |v: Option<f64>| v.map(|v| format!("{:.2}", v)).unwrap_or_else(|| "-".to_string());
+    let mut rows = String::new();
+    for bench in &suite.benchmarks {
+        for lang in &supported_languages() {
+            let Some(c) = bench.measurements.get(lang).and_then(|m| m.counters.as_ref()) else {
+                continue;
+            };
+            let note = c.unavailable.as_deref().unwrap_or("");
+            rows.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                bench.name,
+                lang_full_name(*lang),
+                fmt(c.instructions_per_op),
+                fmt(c.cycles_per_op),
+                fmt(c.ipc()),
+                fmt(c.cache_misses_per_op),
+                fmt(c.branch_misses_per_op),
+                note
+            ));
+        }
+    }
+    if rows.is_empty() {
+        return rows;
+    }
+    format!(
+        "#### Hardware counters\n\n| Benchmark | Language | instr/op | cycles/op | IPC | cache misses/op | branch misses/op | Note |\n|-----------|----------|----------|-----------|-----|-----------------|------------------|------|\n{}\n",
+        rows
+    )
+}
+
+/// Output mismatches for benchmarks marked `equivalent: true` (empty when all outputs agree)
+fn equivalence_table(suite: &SuiteResults) -> String {
+    let mut rows = String::new();
+    for bench in &suite.benchmarks {
+        let Some(check) = &bench.equivalence else { continue };
+        for mismatch in &check.mismatches {
+            let output = check.outputs.get(&mismatch.lang).map(String::as_str).unwrap_or("-");
+            rows.push_str(&format!(
+                "| {} | {} | {} | `{}` |\n",
+                bench.name,
+                lang_full_name(mismatch.lang),
+                mismatch.diff.replace('|', "\\|"),
+                output.replace('|', "\\|").replace('`', "'")
+            ));
+        }
+    }
+    if rows.is_empty() {
+        return rows;
+    }
+    format!(
+        "#### ❌ Output equivalence failures\n\n| Benchmark | Language | Difference | Output |\n|-----------|----------|------------|--------|\n{}\n",
+        rows
+    )
+}
+
+/// Runs taken per language by benchmarks with a `ciTarget` (empty when none set one)
+fn adaptive_table(suite: &SuiteResults) -> String {
+    let mut rows = String::new();
+    for bench in &suite.benchmarks {
+        let Some(outcome) = &bench.adaptive else { continue };
+        for lang in &supported_languages() {
+            let Some(runs) = outcome.runs.get(lang) else { continue };
+            let ci = outcome
+                .ci_percent
+                .get(lang)
+                .map(|ci| format!("±{:.2}%", ci))
+                .unwrap_or_else(|| "-".to_string());
+            let met =
+                outcome.ci_percent.get(lang).is_some_and(|ci| *ci <= outcome.target_ci_percent);
+            rows.push_str(&format!(
+                "| {} | {} | {} | {} | ±{}% | {} |\n",
+                bench.name,
+                lang_full_name(*lang),
+                runs,
+                ci,
+                outcome.target_ci_percent,
+                if met { "✅" } else { "⚠️" }
+            ));
+        }
+    }
+    if rows.is_empty() {
+        return rows;
+    }
+    format!(
+        "#### Adaptive runs\n\n| Benchmark | Language | Runs | 95% CI | Target | Met |\n|-----------|----------|------|--------|--------|-----|\n{}\n",
+        rows
+    )
+}
+
 /// Simple timestamp without chrono dependency
 fn chrono_lite() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
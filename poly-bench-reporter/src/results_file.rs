@@ -0,0 +1,50 @@
+//! `results.json` schema and loading
+//!
+//! Loading and upgrading older results lives in [`poly_bench_executor::results_file`] so the run
+//! history can share it; it is re-exported here. The published JSON Schema for the current
+//! version lives at `docs/public/schemas/results.schema.json` and is generated by
+//! [`json_schema`].
+
+use poly_bench_executor::{BenchmarkResults, RESULTS_SCHEMA_VERSION};
+use serde_json::Value;
+
+pub use poly_bench_executor::results_file::{load, load_file, upgrade};
+
+/// Where the schema for the current version is published
+pub const SCHEMA_URL: &str = "https://polybench.evm-tooling.tools/schemas/results.schema.json";
+
+/// JSON Schema of the current results format, generated from the Rust types
+pub fn json_schema() -> String {
+    let mut schema =
+        serde_json::to_value(schemars::schema_for!(BenchmarkResults)).expect("schema serializes");
+    schema["$id"] = Value::from(SCHEMA_URL);
+    schema["title"] =
+        Value::from(format!("poly-bench results (schema version {})", RESULTS_SCHEMA_VERSION));
+    let mut json = serde_json::to_string_pretty(&schema).expect("schema serializes");
+    json.push('\n');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Regenerate with `POLYBENCH_UPDATE_SCHEMA=1 cargo test -p poly-bench-reporter`
+    #[test]
+    fn test_published_schema_is_current() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../docs/public/schemas/results.schema.json");
+        let generated = json_schema();
+        if std::env::var_os("POLYBENCH_UPDATE_SCHEMA").is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &generated).unwrap();
+        }
+        let published = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            published == generated,
+            "{} is out of date; rerun with POLYBENCH_UPDATE_SCHEMA=1",
+            path.display()
+        );
+    }
+}
@@ -132,7 +132,8 @@ pub fn generate_speedup_chart(results: &BenchmarkResults) -> Result<String> {
             let fill = match cmp.winner {
                 poly_bench_runtime::measurement::ComparisonWinner::First => "url(#goGrad)",
                 poly_bench_runtime::measurement::ComparisonWinner::Second => "url(#tsGrad)",
-                poly_bench_runtime::measurement::ComparisonWinner::Tie => "#9CA3AF",
+                poly_bench_runtime::measurement::ComparisonWinner::Tie |
+                poly_bench_runtime::measurement::ComparisonWinner::Inconclusive => "#9CA3AF",
             };
 
             // Label
@@ -156,7 +157,8 @@ pub fn generate_speedup_chart(results: &BenchmarkResults) -> Result<String> {
             let val_color = match cmp.winner {
                 poly_bench_runtime::measurement::ComparisonWinner::First => "#0E7490",
                 poly_bench_runtime::measurement::ComparisonWinner::Second => "#1E40AF",
-                poly_bench_runtime::measurement::ComparisonWinner::Tie => "#6B7280",
+                poly_bench_runtime::measurement::ComparisonWinner::Tie |
+                poly_bench_runtime::measurement::ComparisonWinner::Inconclusive => "#6B7280",
             };
 
             svg.push_str(&format!(
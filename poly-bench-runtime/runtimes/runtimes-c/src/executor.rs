@@ -3,21 +3,41 @@
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{AsyncSamplingPolicy, BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{
+    ArtifactCache, ArtifactKey, CounterTotals, Measurement, Runtime, RuntimeConfig, RuntimeFactory,
+    VariantToolchain,
+};
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
     process::Stdio,
 };
 
+/// Environment read by clang, pkg-config and the vcpkg/CMake build, keyed into cached binaries
+const BUILD_ENV: &[&str] = &[
+    "CC",
+    "CFLAGS",
+    "CPPFLAGS",
+    "LDFLAGS",
+    "CPATH",
+    "C_INCLUDE_PATH",
+    "LIBRARY_PATH",
+    "PKG_CONFIG_PATH",
+    "VCPKG_ROOT",
+];
+
 pub struct CRuntime {
     clang_binary: PathBuf,
     project_root: Option<PathBuf>,
     anvil_rpc_url: Option<String>,
     cached_binary: Option<(PathBuf, u64)>,
     last_precompile_nanos: Option<u64>,
+    /// Persistent cache of built binaries shared across runs
+    artifacts: ArtifactCache,
+    /// Compiler, extra clang flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 struct CCmakeDep {
@@ -28,7 +48,13 @@ struct CCmakeDep {
 
 impl CRuntime {
     pub fn new() -> Result<Self> {
-        let (clang_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::C)
+        Self::with_toolchain(VariantToolchain::default())
+    }
+
+    /// Create a C runtime that compiles with a runtime variant's compiler and flags
+    pub fn with_toolchain(toolchain: VariantToolchain) -> Result<Self> {
+        let (clang_binary, _) = toolchain
+            .resolve_binary(Lang::C)
             .map_err(|_| miette!("clang not found in PATH. Please install clang/LLVM."))?;
         Ok(Self {
             clang_binary,
@@ -36,6 +62,8 @@ impl CRuntime {
             anvil_rpc_url: None,
             cached_binary: None,
             last_precompile_nanos: None,
+            artifacts: ArtifactCache::from_env(),
+            toolchain,
         })
     }
 
@@ -373,20 +401,28 @@ add_executable({} {})
             .map_err(|e| miette!("Failed to write CMakeLists.txt: {}", e))?;
 
         let toolchain = Self::resolve_vcpkg_toolchain()?;
+        let build_dir = self.toolchain.artifact_name("build");
         let mut args: Vec<String> = vec![
             "-S".to_string(),
             ".".to_string(),
             "-B".to_string(),
-            "build".to_string(),
+            build_dir.clone(),
             format!("-DCMAKE_TOOLCHAIN_FILE={}", toolchain.display()),
             "-DCMAKE_BUILD_TYPE=Release".to_string(),
         ];
         if let Some(triplet) = Self::vcpkg_macos_triplet() {
             args.push(format!("-DVCPKG_TARGET_TRIPLET={}", triplet));
         }
+        if self.toolchain.binary.is_some() {
+            args.push(format!("-DCMAKE_C_COMPILER={}", self.clang_binary.display()));
+        }
+        if !self.toolchain.flags.is_empty() {
+            args.push(format!("-DCMAKE_C_FLAGS={}", self.toolchain.flags.join(" ")));
+        }
 
         let output = std::process::Command::new("cmake")
             .args(&args)
+            .envs(&self.toolchain.env)
             .current_dir(work_dir)
             .output()
             .map_err(|e| miette!("Failed to run cmake: {}", e))?;
@@ -405,7 +441,8 @@ add_executable({} {})
         }
 
         let build_output = std::process::Command::new("cmake")
-            .args(["--build", "build", "--config", "Release"])
+            .args(["--build", build_dir.as_str(), "--config", "Release"])
+            .envs(&self.toolchain.env)
             .current_dir(work_dir)
             .output()
             .map_err(|e| miette!("Failed to run cmake --build: {}", e))?;
@@ -418,7 +455,7 @@ add_executable({} {})
             ));
         }
 
-        let binary_path = work_dir.join("build").join(output_name);
+        let binary_path = work_dir.join(&build_dir).join(output_name);
         if !binary_path.exists() {
             return Err(miette!(
                 "C build succeeded but binary not found at {}",
@@ -495,11 +532,14 @@ add_executable({} {})
             }
         }
 
+        // Variant flags come after the defaults so they can override -O3 or -std
+        clang_args.extend(self.toolchain.flags.iter().cloned());
         clang_args.push(source_path.to_string_lossy().to_string());
         clang_args.push("-o".to_string());
         clang_args.push(binary_path.to_string_lossy().to_string());
         let output = std::process::Command::new(&self.clang_binary)
             .args(&clang_args)
+            .envs(&self.toolchain.env)
             .current_dir(work_dir)
             .output()
             .map_err(|e| miette!("Failed to run clang: {}", e))?;
@@ -521,6 +561,55 @@ add_executable({} {})
 
         Ok(binary_path)
     }
+
+    /// Directories searched for `#include "…"`: the source's own directory, then `-I` flags
+    fn include_dirs(&self, work_dir: &Path) -> Vec<PathBuf> {
+        let mut dirs = vec![work_dir.to_path_buf()];
+        let mut flags = self.toolchain.flags.iter();
+        while let Some(flag) = flags.next() {
+            let dir = match flag.strip_prefix("-I") {
+                Some("") => flags.next().map(String::as_str),
+                Some(dir) => Some(dir),
+                None => None,
+            };
+            if let Some(dir) = dir {
+                dirs.push(work_dir.join(dir));
+            }
+        }
+        dirs
+    }
+
+    /// Build the benchmark binary, reusing a cached binary built from the same inputs
+    fn build_runner(&self, work_dir: &Path, source: &str) -> Result<PathBuf> {
+        let (standard, deps) = self.resolve_c_config_for_work_dir(work_dir)?;
+        let mut deps: Vec<String> = self
+            .filter_deps_for_source(deps, source)
+            .into_iter()
+            .map(|(name, version)| format!("{}@{}", name, version))
+            .collect();
+        deps.sort();
+        let key = ArtifactKey::new(Lang::C, source)
+            .toolchain(&self.clang_binary, &["--version"])
+            .variant(&self.toolchain)
+            .extra("std", &standard)
+            .extra("deps", &deps.join(","))
+            .ambient_env(BUILD_ENV)
+            .file(&work_dir.join("vcpkg.json"));
+        let key = local_headers(source, &self.include_dirs(work_dir))
+            .iter()
+            .fold(key, |key, header| key.extra("header", &header.to_string_lossy()).file(header));
+        let output_name = self.toolchain.artifact_name("polybench_runner");
+        if let Some(cached) = self.artifacts.lookup(&key, &output_name) {
+            return Ok(cached);
+        }
+        let binary_path = self.write_source_and_build(
+            work_dir,
+            source,
+            &self.toolchain.artifact_name("bench_standalone.c"),
+            &output_name,
+        )?;
+        Ok(self.artifacts.store(&key, &binary_path).unwrap_or(binary_path))
+    }
 }
 
 impl Default for CRuntime {
@@ -542,7 +631,7 @@ impl RuntimeFactory for CRuntimeFactory {
     }
 
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
-        let mut rt = CRuntime::new()?;
+        let mut rt = CRuntime::with_toolchain(config.toolchain())?;
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::C));
         Ok(Box::new(rt))
     }
@@ -567,7 +656,8 @@ impl Runtime for CRuntime {
     }
 
     async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
-        poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::C)
+        self.toolchain
+            .resolve_binary(Lang::C)
             .map_err(|_| miette!("clang not found in PATH. Please install clang/LLVM."))?;
         Ok(())
     }
@@ -581,7 +671,8 @@ impl Runtime for CRuntime {
         let work_dir = self.resolve_work_dir()?;
         let safe_name = spec.full_name.replace('.', "_").replace('/', "_");
         let source_name = format!("bench_check_{}.c", safe_name);
-        self.write_source_and_build(&work_dir, &source, &source_name, "polybench_check")?;
+        let output_name = self.toolchain.artifact_name("polybench_check");
+        self.write_source_and_build(&work_dir, &source, &source_name, &output_name)?;
         Ok(())
     }
 
@@ -598,12 +689,7 @@ impl Runtime for CRuntime {
 
         let pc_start = std::time::Instant::now();
         let work_dir = self.resolve_work_dir()?;
-        let binary_path = self.write_source_and_build(
-            &work_dir,
-            &source,
-            "bench_standalone.c",
-            "polybench_runner",
-        )?;
+        let binary_path = self.build_runner(&work_dir, &source)?;
         self.cached_binary = Some((binary_path, source_hash));
         self.last_precompile_nanos = Some(pc_start.elapsed().as_nanos() as u64);
         Ok(())
@@ -622,28 +708,20 @@ impl Runtime for CRuntime {
             if cached_hash == source_hash && binary_path.exists() {
                 binary_path.clone()
             } else {
-                let built = self.write_source_and_build(
-                    &work_dir,
-                    &source,
-                    "bench_standalone.c",
-                    "polybench_runner",
-                )?;
+                let built = self.build_runner(&work_dir, &source)?;
                 self.cached_binary = Some((built.clone(), source_hash));
                 built
             }
         } else {
-            let built = self.write_source_and_build(
-                &work_dir,
-                &source,
-                "bench_standalone.c",
-                "polybench_runner",
-            )?;
+            let built = self.build_runner(&work_dir, &source)?;
             self.cached_binary = Some((built.clone(), source_hash));
             built
         };
 
         let mut cmd = tokio::process::Command::new(&binary_path);
-        cmd.current_dir(&work_dir)
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
+        cmd.envs(&self.toolchain.env)
+            .current_dir(&work_dir)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
@@ -677,7 +755,7 @@ impl Runtime for CRuntime {
 
         let run_wall_nanos = run_start.elapsed().as_nanos() as u64;
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut m = parse_benchmark_result(&stdout, spec.outlier_detection, spec.cv_threshold)?;
+        let mut m = parse_benchmark_result(&stdout, spec)?;
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -698,6 +776,14 @@ fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String
         if let Some(fixture) = suite.get_fixture(fixture_name) {
             if let Some(fixture_impl) = fixture.implementations.get(&Lang::C) {
                 out.push_str(&format!("{}void* {} = {};\n", indent, fixture_name, fixture_impl));
+            } else if let Some(path) = fixture.external_file_literal(Lang::C) {
+                // Mapped once on first use; `<name>_len` holds the size
+                out.push_str(&format!(
+                    "{indent}static const unsigned char* {name} = NULL;\n{indent}static size_t {name}_len = 0;\n{indent}if (!{name}) {name} = __polybench_load_fixture({path}, &{name}_len);\n",
+                    indent = indent,
+                    name = fixture_name,
+                    path = path
+                ));
             } else if !fixture.data.is_empty() {
                 let bytes: Vec<String> =
                     fixture.data.iter().map(|b| format!("0x{:02x}", b)).collect();
@@ -713,12 +799,81 @@ fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String
     out
 }
 
+fn emit_fixture_loader() -> &'static str {
+    // Maps external fixture files read-only; falls back to reading them on Windows
+    r#"#if defined(_WIN32)
+static const unsigned char* __polybench_load_fixture(const char* path, size_t* len) {
+    FILE* f = fopen(path, "rb");
+    if (!f) { perror(path); exit(1); }
+    fseek(f, 0, SEEK_END);
+    *len = (size_t)ftell(f);
+    fseek(f, 0, SEEK_SET);
+    unsigned char* data = (unsigned char*)malloc(*len ? *len : 1);
+    if (!data || fread(data, 1, *len, f) != *len) { perror(path); exit(1); }
+    fclose(f);
+    return data;
+}
+#else
+#include <fcntl.h>
+#include <sys/mman.h>
+#include <sys/stat.h>
+#include <unistd.h>
+
+static const unsigned char* __polybench_load_fixture(const char* path, size_t* len) {
+    struct stat st;
+    int fd = open(path, O_RDONLY);
+    if (fd < 0 || fstat(fd, &st) != 0) { perror(path); exit(1); }
+    *len = (size_t)st.st_size;
+    void* data = mmap(NULL, *len ? *len : 1, PROT_READ, MAP_PRIVATE, fd, 0);
+    close(fd);
+    if (data == MAP_FAILED) { perror(path); exit(1); }
+    return (const unsigned char*)data;
+}
+#endif
+
+"#
+}
+
+/// Project headers reachable from `source` through `#include "…"`, searched in `dirs` and
+/// followed transitively (relative to the including header first)
+fn local_headers(source: &str, dirs: &[PathBuf]) -> Vec<PathBuf> {
+    fn quoted_includes(source: &str) -> impl Iterator<Item = &str> {
+        source.lines().filter_map(|line| {
+            let rest = line.trim_start().strip_prefix('#')?.trim_start();
+            let rest = rest.strip_prefix("include")?.trim_start().strip_prefix('"')?;
+            rest.split_once('"').map(|(name, _)| name)
+        })
+    }
+
+    let mut found: Vec<PathBuf> = Vec::new();
+    let mut pending: Vec<(String, Option<PathBuf>)> =
+        quoted_includes(source).map(|name| (name.to_string(), None)).collect();
+    while let Some((name, including_dir)) = pending.pop() {
+        let Some(path) =
+            including_dir.iter().chain(dirs).map(|dir| dir.join(&name)).find(|path| path.is_file())
+        else {
+            continue;
+        };
+        if found.contains(&path) {
+            continue;
+        }
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            let parent = path.parent().map(Path::to_path_buf);
+            pending.extend(quoted_includes(&contents).map(|n| (n.to_string(), parent.clone())));
+        }
+        found.push(path);
+    }
+    found.sort();
+    found
+}
+
 fn emit_memory_helpers() -> String {
-    // Use a tracking allocator that wraps malloc and counts total bytes allocated.
-    // This is cumulative (never decreases) like Go's runtime.MemStats.TotalAlloc.
+    // Use a tracking allocator that wraps malloc and counts total bytes and allocations.
+    // Both are cumulative (never decrease) like Go's runtime.MemStats.TotalAlloc and Mallocs.
     // On macOS, we use malloc_size() to track the actual size of allocations.
     // On other platforms, we prepend each allocation with the size.
     r#"static uint64_t __polybench_total_allocated = 0;
+static uint64_t __polybench_alloc_count = 0;
 
 #if defined(__APPLE__)
 #include <malloc/malloc.h>
@@ -727,6 +882,7 @@ void* __polybench_malloc(size_t size) {
     void* ptr = malloc(size);
     if (ptr) {
         __polybench_total_allocated += malloc_size(ptr);
+        __polybench_alloc_count++;
     }
     return ptr;
 }
@@ -735,6 +891,7 @@ void* __polybench_calloc(size_t count, size_t size) {
     void* ptr = calloc(count, size);
     if (ptr) {
         __polybench_total_allocated += malloc_size(ptr);
+        __polybench_alloc_count++;
     }
     return ptr;
 }
@@ -746,6 +903,7 @@ void* __polybench_realloc(void* old_ptr, size_t size) {
         size_t new_size = malloc_size(ptr);
         if (new_size > old_size) {
             __polybench_total_allocated += (new_size - old_size);
+            __polybench_alloc_count++;
         }
     }
     return ptr;
@@ -759,16 +917,19 @@ void __polybench_free(void* ptr) {
 
 void* __polybench_malloc(size_t size) {
     __polybench_total_allocated += size;
+    __polybench_alloc_count++;
     return malloc(size);
 }
 
 void* __polybench_calloc(size_t count, size_t size) {
     __polybench_total_allocated += count * size;
+    __polybench_alloc_count++;
     return calloc(count, size);
 }
 
 void* __polybench_realloc(void* old_ptr, size_t size) {
     __polybench_total_allocated += size;
+    __polybench_alloc_count++;
     return realloc(old_ptr, size);
 }
 
@@ -790,6 +951,79 @@ static uint64_t __polybench_get_total_allocated(void) {
     .to_string()
 }
 
+fn emit_counter_helpers() -> String {
+    // Opens instructions, cycles, cache-miss and branch-miss counters for the calling thread
+    // via perf_event_open; counters the kernel refuses are skipped and the first failure is
+    // reported as "error" so the run still succeeds.
+    r#"#if defined(__linux__)
+#include <errno.h>
+#include <linux/perf_event.h>
+#include <sys/ioctl.h>
+#include <sys/syscall.h>
+#include <unistd.h>
+
+static const char* __polybench_counter_names[4] = {"instructions", "cycles", "cache_misses", "branch_misses"};
+static int __polybench_counter_fds[4] = {-1, -1, -1, -1};
+static char __polybench_counter_error[160];
+
+static void __polybench_counters_start(void) {
+    const uint64_t configs[4] = {PERF_COUNT_HW_INSTRUCTIONS, PERF_COUNT_HW_CPU_CYCLES, PERF_COUNT_HW_CACHE_MISSES, PERF_COUNT_HW_BRANCH_MISSES};
+    for (int i = 0; i < 4; i++) {
+        struct perf_event_attr attr;
+        memset(&attr, 0, sizeof(attr));
+        attr.type = PERF_TYPE_HARDWARE;
+        attr.size = sizeof(attr);
+        attr.config = configs[i];
+        attr.disabled = 1;
+        attr.exclude_kernel = 1;
+        attr.exclude_hv = 1;
+        int fd = (int)syscall(__NR_perf_event_open, &attr, 0, -1, -1, 0);
+        if (fd < 0 && !__polybench_counter_error[0]) {
+            snprintf(__polybench_counter_error, sizeof(__polybench_counter_error), "perf_event_open(%s): %s", __polybench_counter_names[i], strerror(errno));
+        }
+        __polybench_counter_fds[i] = fd;
+    }
+    for (int i = 0; i < 4; i++) {
+        if (__polybench_counter_fds[i] < 0) continue;
+        ioctl(__polybench_counter_fds[i], PERF_EVENT_IOC_RESET, 0);
+        ioctl(__polybench_counter_fds[i], PERF_EVENT_IOC_ENABLE, 0);
+    }
+}
+
+static void __polybench_counters_stop(void) {
+    for (int i = 0; i < 4; i++) {
+        if (__polybench_counter_fds[i] >= 0) ioctl(__polybench_counter_fds[i], PERF_EVENT_IOC_DISABLE, 0);
+    }
+    size_t cap = sizeof(__polybench_counters_json);
+    size_t len = (size_t)snprintf(__polybench_counters_json, cap, "\"counters\":{");
+    int first = 1;
+    for (int i = 0; i < 4; i++) {
+        int fd = __polybench_counter_fds[i];
+        if (fd < 0) continue;
+        uint64_t value = 0;
+        if (read(fd, &value, sizeof(value)) == (ssize_t)sizeof(value) && len < cap) {
+            len += (size_t)snprintf(__polybench_counters_json + len, cap - len, "%s\"%s\":%llu", first ? "" : ",", __polybench_counter_names[i], (unsigned long long)value);
+            first = 0;
+        }
+        close(fd);
+    }
+    if (__polybench_counter_error[0] && len < cap) {
+        len += (size_t)snprintf(__polybench_counters_json + len, cap - len, "%s\"error\":\"%s\"", first ? "" : ",", __polybench_counter_error);
+    }
+    if (len < cap) snprintf(__polybench_counters_json + len, cap - len, "},");
+}
+#else
+static void __polybench_counters_start(void) {}
+
+static void __polybench_counters_stop(void) {
+    snprintf(__polybench_counters_json, sizeof(__polybench_counters_json), "\"counters\":{\"error\":\"hardware counters require Linux perf_event_open\"},");
+}
+#endif
+
+"#
+    .to_string()
+}
+
 fn emit_hook(code: Option<&String>, indent: &str) -> String {
     code.map(|c| {
         CRuntime::normalize_indent(c)
@@ -841,13 +1075,23 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
         src.push('\n');
     }
 
-    src.push_str("static void* __polybench_sink;\n\n");
+    src.push_str("static void* __polybench_sink;\n");
+    // Filled with `"counters":{...},` by the counter helpers; empty unless `counters: true`
+    src.push_str("static char __polybench_counters_json[512];\n");
+    // `"validationPassed":...,` once `validate` has run; empty without a validation
+    src.push_str("static const char* __polybench_validation_json = \"\";\n\n");
     src.push_str(
         "static uint64_t __polybench_nanos_now(void) {\n    struct timespec ts;\n    clock_gettime(CLOCK_MONOTONIC, &ts);\n    return (uint64_t)ts.tv_sec * 1000000000ULL + (uint64_t)ts.tv_nsec;\n}\n\n",
     );
     if spec.memory {
         src.push_str(&emit_memory_helpers());
     }
+    if spec.counters {
+        src.push_str(&emit_counter_helpers());
+    }
+    if suite.uses_external_fixture(spec, Lang::C) {
+        src.push_str(emit_fixture_loader());
+    }
 
     let stdlib_code = stdlib::get_stdlib_code(&suite.stdlib_imports, &crate::C_STDLIB);
     if !stdlib_code.is_empty() {
@@ -879,6 +1123,13 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
         }
     }
 
+    // Benchmark parameters, visible to hooks in main as well as the benchmark body
+    let params = param_declarations(&spec.params, Lang::C, "");
+    if !params.is_empty() {
+        src.push_str(&params);
+        src.push('\n');
+    }
+
     src.push_str("static void* __polybench_bench(void) {\n");
     src.push_str(&emit_fixtures(spec, suite, "    "));
     for line in impl_code.lines() {
@@ -892,6 +1143,19 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
     src.push_str("    return NULL;\n");
     src.push_str("}\n\n");
 
+    // Validation: the implementation is evaluated once more as an expression (bound to `result`)
+    let validation = spec.validations.get(&Lang::C);
+    if let Some(check) = validation {
+        src.push_str("static int __polybench_validate(void) {\n");
+        src.push_str(&emit_fixtures(spec, suite, "    "));
+        src.push_str(&format!(
+            "    __auto_type result = ({});\n",
+            impl_code.trim().trim_end_matches(';')
+        ));
+        src.push_str(&format!("    return ({}) ? 1 : 0;\n", check.trim()));
+        src.push_str("}\n\n");
+    }
+
     if check_only {
         src.push_str("int main(void) { return 0; }\n");
         return Ok(src);
@@ -937,17 +1201,29 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
     if use_memory {
         // Reset the tracking allocator counter before the benchmark
         src.push_str("    __polybench_total_allocated = 0;\n");
+        src.push_str("    __polybench_alloc_count = 0;\n");
         src.push_str("    uint64_t __mem_before = __polybench_get_total_allocated();\n");
     }
 
+    let validation_call = if validation.is_some() {
+        "    __polybench_validation_json = __polybench_validate() ? \"\\\"validationPassed\\\":true,\" : \"\\\"validationPassed\\\":false,\";\n"
+    } else {
+        ""
+    };
     let sample_cap = spec.async_sample_cap;
     let each_hook_c = emit_hook(spec.each_hooks.get(&Lang::C), "            ");
+    let (counters_start, counters_stop) = if spec.counters {
+        ("    __polybench_counters_start();\n", "    __polybench_counters_stop();\n")
+    } else {
+        ("", "")
+    };
 
     if is_async {
         // Async-sequential: one completed call per iteration, no batching
         let target_nanos = (spec.target_time_ms as f64) * 1_000_000.0;
         let is_fixed_cap = matches!(spec.async_sampling_policy, AsyncSamplingPolicy::FixedCap);
 
+        src.push_str(counters_start);
         src.push_str(&format!(
             "    double targetNs = {:.0};\n    uint64_t sampleCap = {};\n    uint64_t totalIterations = 0;\n    double totalNs = 0.0;\n    size_t sampleCount = 0;\n    double* samples = (double*)malloc((size_t)sampleCap * sizeof(double));\n    if (!samples) return 1;\n    uint64_t successfulCount = 0;\n    uint64_t errorCount = 0;\n\n",
             target_nanos, sample_cap
@@ -970,18 +1246,21 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
             ));
         }
 
+        src.push_str(counters_stop);
         src.push_str(
             "    double nanosPerOp = totalNs / (double)(totalIterations ? totalIterations : 1);\n    double opsPerSec = 1000000000.0 / (nanosPerOp > 0.0 ? nanosPerOp : 1.0);\n",
         );
         if use_memory {
             src.push_str("    uint64_t __mem_after = __polybench_get_total_allocated();\n");
             src.push_str("    uint64_t __bytes_per_op = totalIterations ? (__mem_after - __mem_before) / totalIterations : 0;\n");
+            src.push_str("    uint64_t __allocs_per_op = totalIterations ? __polybench_alloc_count / totalIterations : 0;\n");
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::C), "    "));
         if use_memory {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"samples\\\":[\", (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"allocsPerOp\\\":%llu,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op, (unsigned long long)__allocs_per_op);\n");
         } else {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
         }
         src.push_str("    for (size_t i = 0; i < sampleCount; i++) { if (i) printf(\",\"); printf(\"%.0f\", samples[i]); }\n");
         src.push_str("    printf(\"],\\\"successfulCount\\\":%llu,\\\"errorCount\\\":%llu,\\\"successfulResults\\\":[],\\\"errorSamples\\\":[]\", (unsigned long long)successfulCount, (unsigned long long)errorCount);\n");
@@ -990,6 +1269,7 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
         );
         src.push_str("    printf(\"}\\n\");\n    free(samples);\n");
     } else if spec.mode == BenchMode::Auto {
+        src.push_str(counters_start);
         src.push_str(&format!(
             "    double targetNs = {:.0};\n    uint64_t totalIterations = 0;\n    double totalNs = 0.0;\n    uint64_t batch = 1;\n    size_t sampleCap = 4096;\n    size_t sampleCount = 0;\n    double* samples = (double*)malloc(sampleCap * sizeof(double));\n    if (!samples) return 1;\n    while (totalNs < targetNs) {{\n        uint64_t t0 = __polybench_nanos_now();\n        for (uint64_t i = 0; i < batch; i++) {{\n",
             (spec.target_time_ms as f64) * 1_000_000.0
@@ -1012,18 +1292,21 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
             "            double remaining = targetNs - totalNs;\n            uint64_t nextBatch = (uint64_t)fmax(1.0, (batch * remaining / elapsedNs) * 1.1);\n            batch = nextBatch;\n",
         );
         src.push_str("        } else {\n            batch *= 2;\n        }\n    }\n");
+        src.push_str(counters_stop);
         src.push_str(
             "    double nanosPerOp = totalNs / (double)(totalIterations ? totalIterations : 1);\n    double opsPerSec = 1000000000.0 / (nanosPerOp > 0.0 ? nanosPerOp : 1.0);\n",
         );
         if use_memory {
             src.push_str("    uint64_t __mem_after = __polybench_get_total_allocated();\n");
             src.push_str("    uint64_t __bytes_per_op = totalIterations ? (__mem_after - __mem_before) / totalIterations : 0;\n");
+            src.push_str("    uint64_t __allocs_per_op = totalIterations ? __polybench_alloc_count / totalIterations : 0;\n");
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::C), "    "));
         if use_memory {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"samples\\\":[\", (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"allocsPerOp\\\":%llu,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op, (unsigned long long)__allocs_per_op);\n");
         } else {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)totalIterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
         }
         src.push_str("    for (size_t i = 0; i < sampleCount; i++) {\n");
         src.push_str(
@@ -1032,6 +1315,7 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
         src.push_str("    if (__polybench_sink) {\n        printf(\"],\\\"rawResult\\\":\\\"sink\\\"}\");\n    } else {\n        printf(\"]}\");\n    }\n");
         src.push_str("    printf(\"\\n\");\n    free(samples);\n");
     } else {
+        src.push_str(counters_start);
         src.push_str(&format!(
             "    uint64_t iterations = {};\n    double* samples = (double*)malloc((size_t)iterations * sizeof(double));\n    if (!samples) return 1;\n    for (uint64_t i = 0; i < iterations; i++) {{\n        uint64_t t0 = __polybench_nanos_now();\n",
             spec.iterations
@@ -1045,18 +1329,21 @@ fn generate_c_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool) ->
         src.push_str("        uint64_t t1 = __polybench_nanos_now();\n");
         src.push_str("        samples[i] = (double)(t1 - t0);\n");
         src.push_str("    }\n");
+        src.push_str(counters_stop);
         src.push_str(
             "    double totalNs = 0.0;\n    for (uint64_t i = 0; i < iterations; i++) totalNs += samples[i];\n    double nanosPerOp = totalNs / (double)(iterations ? iterations : 1);\n    double opsPerSec = 1000000000.0 / (nanosPerOp > 0.0 ? nanosPerOp : 1.0);\n",
         );
         if use_memory {
             src.push_str("    uint64_t __mem_after = __polybench_get_total_allocated();\n");
             src.push_str("    uint64_t __bytes_per_op = iterations ? (__mem_after - __mem_before) / iterations : 0;\n");
+            src.push_str("    uint64_t __allocs_per_op = iterations ? __polybench_alloc_count / iterations : 0;\n");
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::C), "    "));
         if use_memory {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"samples\\\":[\", (unsigned long long)iterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"bytesPerOp\\\":%llu,\\\"allocsPerOp\\\":%llu,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)iterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec, (unsigned long long)__bytes_per_op, (unsigned long long)__allocs_per_op);\n");
         } else {
-            src.push_str("    printf(\"{\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", (unsigned long long)iterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
+            src.push_str("    printf(\"{%s%s\\\"iterations\\\":%llu,\\\"totalNanos\\\":%.0f,\\\"warmupNanos\\\":%llu,\\\"nanosPerOp\\\":%.6f,\\\"opsPerSec\\\":%.6f,\\\"samples\\\":[\", __polybench_counters_json, __polybench_validation_json, (unsigned long long)iterations, totalNs, (unsigned long long)__warmup_nanos, nanosPerOp, opsPerSec);\n");
         }
         src.push_str("    for (uint64_t i = 0; i < iterations; i++) {\n");
         src.push_str(
@@ -1093,6 +1380,10 @@ struct BenchResultJson {
     successful_results: Vec<String>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    counters: Option<CounterTotals>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -1116,7 +1407,7 @@ impl BenchResultJson {
             m.warmup_nanos = Some(w as u64);
         }
         if let Some(bytes) = self.bytes_per_op {
-            m = m.with_allocs(bytes, self.allocs_per_op.unwrap_or(0));
+            m = m.with_memory(bytes, self.allocs_per_op);
         }
         if let Some(sc) = self.successful_count {
             m.async_success_count = Some(sc);
@@ -1127,15 +1418,12 @@ impl BenchResultJson {
         if !self.error_samples.is_empty() {
             m.async_error_samples = Some(self.error_samples);
         }
+        m.counters = self.counters.map(|c| c.per_op(self.iterations));
         m
     }
 }
 
-fn parse_benchmark_result(
-    stdout: &str,
-    outlier_detection: bool,
-    cv_threshold: f64,
-) -> Result<Measurement> {
+fn parse_benchmark_result(stdout: &str, spec: &BenchmarkSpec) -> Result<Measurement> {
     let json_line = stdout
         .lines()
         .filter(|l| !l.trim().is_empty())
@@ -1143,5 +1431,65 @@ fn parse_benchmark_result(
         .ok_or_else(|| miette!("No benchmark output from C runtime"))?;
     let result: BenchResultJson = serde_json::from_str(json_line)
         .map_err(|e| miette!("Failed to parse C benchmark output: {}\n{}", e, stdout))?;
-    Ok(result.into_measurement(outlier_detection, cv_threshold))
+    let validation_passed = result.validation_passed;
+    let mut m = result.into_measurement(spec.outlier_detection, spec.cv_threshold);
+    m.record_validation(spec.validations.get(&Lang::C), validation_passed);
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validated_spec() -> (BenchmarkSpec, SuiteIR) {
+        let suite = SuiteIR::new("snap".to_string());
+        let mut spec = BenchmarkSpec::new("add".to_string(), "snap", 10, 5, 0);
+        spec.implementations.insert(Lang::C, "1 + 2".to_string());
+        spec.validations.insert(Lang::C, "result == 3".to_string());
+        (spec, suite)
+    }
+
+    #[test]
+    fn test_validation_runs_after_timing() {
+        let (spec, suite) = validated_spec();
+        let src = generate_c_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("__auto_type result = (1 + 2);"));
+        assert!(src.contains("result == 3"));
+        assert!(src.contains("validationPassed"));
+    }
+
+    #[test]
+    fn test_no_validation_without_expression() {
+        let (mut spec, suite) = validated_spec();
+        spec.validations.clear();
+        let src = generate_c_source(&spec, &suite, false).unwrap();
+        assert!(!src.contains("__polybench_validate()"));
+    }
+
+    #[test]
+    fn test_memory_reports_bytes_and_allocs() {
+        let (mut spec, suite) = validated_spec();
+        spec.memory = true;
+        let src = generate_c_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("#define malloc(size) __polybench_malloc(size)"));
+        assert!(src.contains("__polybench_alloc_count = 0;"));
+        assert!(src.contains("__polybench_alloc_count / totalIterations"));
+        assert!(src.contains("\\\"allocsPerOp\\\":%llu"));
+    }
+
+    #[test]
+    fn test_local_headers_follow_quoted_includes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().to_path_buf();
+        std::fs::create_dir_all(root.join("include/detail")).unwrap();
+        std::fs::write(root.join("include/util.h"), "#include \"detail/impl.h\"\n").unwrap();
+        std::fs::write(root.join("include/detail/impl.h"), "int helper(void);\n").unwrap();
+        let source = "#include <stdio.h>\n#include \"util.h\"\n#include \"missing.h\"\n";
+
+        assert!(local_headers(source, std::slice::from_ref(&root)).is_empty());
+        assert_eq!(
+            local_headers(source, &[root.clone(), root.join("include")]),
+            vec![root.join("include/detail/impl.h"), root.join("include/util.h")]
+        );
+    }
 }
@@ -4,10 +4,12 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static C_BUILTINS: &[&str] = &[
     "printf", "fprintf", "snprintf", "puts", "malloc", "calloc", "realloc", "free", "memcpy",
     "memset", "memcmp", "strlen", "strcmp", "strncmp", "atoi", "atol", "strtol", "strtod",
     "sizeof", // C built-in operator, not a function
+    "if", "for", "while", "switch", "return", "_Alignof", "defined",
 ];
 
 pub(crate) struct CHelperFunctionExtractor;
@@ -3,8 +3,11 @@
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{AsyncSamplingPolicy, BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
-use poly_bench_traits::{Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
+use poly_bench_traits::{
+    ArtifactCache, ArtifactKey, Measurement, Runtime, RuntimeConfig, RuntimeFactory,
+    VariantToolchain,
+};
 use regex::Regex;
 use std::{
     path::{Path, PathBuf},
@@ -12,6 +15,11 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
+/// Environment read by `dotnet build` and NuGet (MSBuild also sees every variable as a
+/// property, but these are the ones that conventionally change builds), keyed into cached
+/// builds
+const BUILD_ENV: &[&str] = &["DOTNET_*", "MSBUILD*", "NUGET_*", "Configuration", "Platform"];
+
 pub struct CSharpRuntime {
     dotnet_binary: PathBuf,
     project_root: Option<PathBuf>,
@@ -20,11 +28,20 @@ pub struct CSharpRuntime {
     cached_binary: Option<(PathBuf, u64)>,
     /// Duration of last precompile in nanoseconds (for accurate reporting)
     last_precompile_nanos: Option<u64>,
+    /// Persistent cache of build outputs shared across runs
+    artifacts: ArtifactCache,
+    /// dotnet binary, `dotnet build` flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl CSharpRuntime {
     pub fn new() -> Result<Self> {
-        let (dotnet_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::CSharp)
+        Self::with_toolchain(VariantToolchain::default())
+    }
+
+    /// Create a C# runtime that builds and runs with a runtime variant's toolchain
+    pub fn with_toolchain(toolchain: VariantToolchain) -> Result<Self> {
+        let (dotnet_binary, _) = toolchain.resolve_binary(Lang::CSharp)
             .map_err(|_| miette!("dotnet not found. Install via 'poly-bench add-runtime csharp' or ensure dotnet is in PATH"))?;
         Ok(Self {
             dotnet_binary,
@@ -32,6 +49,8 @@ impl CSharpRuntime {
             anvil_rpc_url: None,
             cached_binary: None,
             last_precompile_nanos: None,
+            artifacts: ArtifactCache::from_env(),
+            toolchain,
         })
     }
 
@@ -183,7 +202,7 @@ impl CSharpRuntime {
         let _ = std::fs::remove_dir_all(work_dir.join(".polybench-build"));
     }
 
-    /// Build the DLL and cache it for reuse
+    /// Build the DLL, or reuse the output of an identical earlier build, and cache it for reuse
     async fn build_dll(
         &mut self,
         _spec: &BenchmarkSpec,
@@ -195,6 +214,20 @@ impl CSharpRuntime {
         let project_path = self.ensure_project_files(work_dir)?;
         let target_framework = Self::parse_target_framework(&project_path)?;
         Self::ensure_global_json(work_dir, &target_framework)?;
+
+        let key = ArtifactKey::new(Lang::CSharp, source)
+            .toolchain(&self.dotnet_binary, &["--version"])
+            .variant(&self.toolchain)
+            .ambient_env(BUILD_ENV)
+            .file(&project_path)
+            .file(&work_dir.join("global.json"))
+            .file(&work_dir.join("packages.lock.json"));
+        if let Some(output_dir) = self.artifacts.lookup(&key, &target_framework) {
+            let dll_path = output_dir.join("polybench.dll");
+            self.cached_binary = Some((dll_path.clone(), source_hash));
+            return Ok(dll_path);
+        }
+
         Self::cleanup_local_build_artifacts(work_dir);
         let build_root = Self::unique_build_root(work_dir)?;
         let obj_path = build_root.join("obj");
@@ -216,6 +249,8 @@ impl CSharpRuntime {
                 &format!("-p:BaseOutputPath={}/", bin_path.to_string_lossy()),
                 project_path.to_string_lossy().as_ref(),
             ])
+            .args(&self.toolchain.flags)
+            .envs(&self.toolchain.env)
             .current_dir(work_dir)
             .output()
             .await
@@ -237,6 +272,13 @@ impl CSharpRuntime {
             ));
         }
 
+        // The whole output directory is cached: the DLL needs its deps.json and runtimeconfig
+        let dll_path =
+            match self.artifacts.store(&key, &bin_path.join("Release").join(&target_framework)) {
+                Ok(output_dir) => output_dir.join("polybench.dll"),
+                Err(_) => dll_path,
+            };
+
         // Cache the DLL path and source hash for reuse
         self.cached_binary = Some((dll_path.clone(), source_hash));
 
@@ -263,7 +305,7 @@ impl RuntimeFactory for CSharpRuntimeFactory {
     }
 
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
-        let mut rt = CSharpRuntime::new()?;
+        let mut rt = CSharpRuntime::with_toolchain(config.toolchain())?;
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::CSharp));
         Ok(Box::new(rt))
     }
@@ -288,7 +330,7 @@ impl Runtime for CSharpRuntime {
     }
 
     async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
-        poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::CSharp)
+        self.toolchain.resolve_binary(Lang::CSharp)
             .map_err(|_| miette!("dotnet not found. Install via 'poly-bench add-runtime csharp' or ensure dotnet is in PATH"))?;
         Ok(())
     }
@@ -323,6 +365,8 @@ impl Runtime for CSharpRuntime {
                 &format!("-p:BaseOutputPath={}/", bin_path.to_string_lossy()),
                 project_path.to_string_lossy().as_ref(),
             ])
+            .args(&self.toolchain.flags)
+            .envs(&self.toolchain.env)
             .current_dir(&work_dir)
             .output()
             .await
@@ -351,55 +395,8 @@ impl Runtime for CSharpRuntime {
         }
 
         let pc_start = std::time::Instant::now();
-
         let work_dir = self.resolve_work_dir()?;
-        let project_path = self.ensure_project_files(&work_dir)?;
-        let target_framework = Self::parse_target_framework(&project_path)?;
-        Self::ensure_global_json(&work_dir, &target_framework)?;
-        Self::cleanup_local_build_artifacts(&work_dir);
-        let build_root = Self::unique_build_root(&work_dir)?;
-        let obj_path = build_root.join("obj");
-        let bin_path = build_root.join("bin");
-
-        std::fs::write(work_dir.join("Program.cs"), &source)
-            .map_err(|e| miette!("Failed to write Program.cs: {}", e))?;
-
-        let build = tokio::process::Command::new(&self.dotnet_binary)
-            .args([
-                "build",
-                "-nologo",
-                "-c",
-                "Release",
-                "-f",
-                &target_framework,
-                "-p:UseAppHost=false",
-                &format!("-p:BaseIntermediateOutputPath={}/", obj_path.to_string_lossy()),
-                &format!("-p:BaseOutputPath={}/", bin_path.to_string_lossy()),
-                project_path.to_string_lossy().as_ref(),
-            ])
-            .current_dir(&work_dir)
-            .output()
-            .await
-            .map_err(|e| miette!("Failed to run dotnet build: {}", e))?;
-
-        if !build.status.success() {
-            return Err(miette!(
-                "C# build failed:\n{}\n{}",
-                String::from_utf8_lossy(&build.stdout),
-                String::from_utf8_lossy(&build.stderr)
-            ));
-        }
-
-        let dll_path = bin_path.join("Release").join(&target_framework).join("polybench.dll");
-        if !dll_path.exists() {
-            return Err(miette!(
-                "C# build succeeded but output DLL not found at {}",
-                dll_path.display()
-            ));
-        }
-
-        // Cache the DLL path and source hash for reuse
-        self.cached_binary = Some((dll_path, source_hash));
+        self.build_dll(spec, suite, &work_dir, &source, source_hash).await?;
         self.last_precompile_nanos = Some(pc_start.elapsed().as_nanos() as u64);
 
         Ok(())
@@ -429,7 +426,9 @@ impl Runtime for CSharpRuntime {
         };
 
         let mut cmd = tokio::process::Command::new(&self.dotnet_binary);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
         cmd.arg(dll_path.to_string_lossy().as_ref())
+            .envs(&self.toolchain.env)
             .current_dir(&work_dir)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
@@ -467,7 +466,7 @@ impl Runtime for CSharpRuntime {
 
         let run_wall_nanos = run_start.elapsed().as_nanos() as u64;
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut m = parse_benchmark_result(&stdout, spec.outlier_detection, spec.cv_threshold)?;
+        let mut m = parse_benchmark_result(&stdout, spec)?;
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -502,6 +501,12 @@ fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String
         if let Some(fixture) = suite.get_fixture(fixture_name) {
             if let Some(fixture_impl) = fixture.implementations.get(&Lang::CSharp) {
                 out.push_str(&format!("{}var {} = {};\n", indent, fixture_name, fixture_impl));
+            } else if fixture.external_file.is_some() {
+                // Read once by the static field from `emit_external_fixture_fields`
+                out.push_str(&format!(
+                    "{}byte[] {} = __polybench_fixture_{};\n",
+                    indent, fixture_name, fixture_name
+                ));
             } else if !fixture.data.is_empty() {
                 let bytes: Vec<String> =
                     fixture.data.iter().map(|b| format!("0x{:02x}", b)).collect();
@@ -517,6 +522,26 @@ fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String
     out
 }
 
+/// Static fields holding external fixtures, read once when the class initializes
+fn emit_external_fixture_fields(spec: &BenchmarkSpec, suite: &SuiteIR) -> String {
+    let mut out = String::new();
+    for fixture in spec.fixture_refs.iter().filter_map(|name| suite.get_fixture(name)) {
+        if fixture.implementations.contains_key(&Lang::CSharp) {
+            continue;
+        }
+        if let Some(path) = fixture.external_file_literal(Lang::CSharp) {
+            out.push_str(&format!(
+                "    static readonly byte[] __polybench_fixture_{} = System.IO.File.ReadAllBytes({});\n",
+                fixture.name, path
+            ));
+        }
+    }
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
 fn generate_csharp_source(
     spec: &BenchmarkSpec,
     suite: &SuiteIR,
@@ -546,6 +571,20 @@ fn generate_csharp_source(
     // Use a non-Program entry type to avoid conflicts with compiler-generated Program.
     src.push_str("public static class PolybenchProgram {\n");
     src.push_str("    static object? __polybench_sink;\n\n");
+    // `rawResult` is emitted as a JSON string; byte arrays become integer arrays (System.Text.Json
+    // would otherwise write base64) so they compare equal to other languages' bytes
+    src.push_str("    static string? __polybench_raw_result(object? value) {\n");
+    src.push_str(
+        "        if (value is byte[] bytes) value = Array.ConvertAll(bytes, b => (int)b);\n",
+    );
+    src.push_str("        return value == null ? null : JsonSerializer.Serialize(value);\n");
+    src.push_str("    }\n\n");
+    src.push_str(&emit_external_fixture_fields(spec, suite));
+    let params = param_declarations(&spec.params, Lang::CSharp, "    ");
+    if !params.is_empty() {
+        src.push_str(&params);
+        src.push('\n');
+    }
 
     if let Some(declarations) = suite.declarations.get(&Lang::CSharp) {
         if !declarations.trim().is_empty() {
@@ -585,6 +624,35 @@ fn generate_csharp_source(
     src.push_str("        return null;\n");
     src.push_str("    }\n\n");
 
+    // Validation: the implementation is evaluated once more as an expression (bound to `result`)
+    let validation = spec.validations.get(&Lang::CSharp);
+    if let Some(check) = validation {
+        if is_async {
+            src.push_str("    static async Task<bool> __polybench_validate() {\n");
+        } else {
+            src.push_str("    static bool __polybench_validate() {\n");
+        }
+        src.push_str(&emit_fixtures(spec, suite, "        "));
+        src.push_str(&format!(
+            "        var result = {}({});\n",
+            if is_async { "await " } else { "" },
+            impl_code.trim().trim_end_matches(';')
+        ));
+        src.push_str(&format!("        return {};\n", check.trim()));
+        src.push_str("    }\n\n");
+    }
+    let (validation_call, validation_entry) = match (validation, is_async) {
+        (None, _) => ("", ""),
+        (Some(_), true) => (
+            "        bool validationPassed = await __polybench_validate();\n",
+            "            [\"validationPassed\"] = validationPassed,\n",
+        ),
+        (Some(_), false) => (
+            "        bool validationPassed = __polybench_validate();\n",
+            "            [\"validationPassed\"] = validationPassed,\n",
+        ),
+    };
+
     if check_only {
         if is_async {
             src.push_str("    public static async Task Main() { }\n}\n");
@@ -645,11 +713,16 @@ fn generate_csharp_source(
     src.push_str(&CSharpRuntime::emit_hook(spec.before_hooks.get(&Lang::CSharp), "        "));
 
     let use_memory = spec.memory;
+    // Cumulative allocation counters (like Go's TotalAlloc) never decrease, so they capture
+    // allocations freed during the benchmark. Sync benchmarks run on one thread, so the exact
+    // per-thread counter is used; async continuations may hop threads and need the process total.
+    let allocated_bytes = if is_async {
+        "GC.GetTotalAllocatedBytes(true)"
+    } else {
+        "GC.GetAllocatedBytesForCurrentThread()"
+    };
     if use_memory {
-        // Use GC.GetTotalAllocatedBytes() for cumulative allocation tracking (like Go's TotalAlloc)
-        // This never decreases, so it captures allocations even if they're freed during the
-        // benchmark
-        src.push_str("        long memBefore = GC.GetTotalAllocatedBytes(true);\n");
+        src.push_str(&format!("        long memBefore = {};\n", allocated_bytes));
     }
 
     let sample_cap = spec.async_sample_cap;
@@ -689,9 +762,10 @@ fn generate_csharp_source(
         src.push_str("        double nanosPerOp = totalNs / Math.Max(1, totalIterations);\n");
         src.push_str("        double opsPerSec = 1_000_000_000.0 / Math.Max(1, nanosPerOp);\n");
         if use_memory {
-            src.push_str("        long memAfter = GC.GetTotalAllocatedBytes(true);\n");
+            src.push_str(&format!("        long memAfter = {};\n", allocated_bytes));
             src.push_str("        long bytesPerOp = Math.Max(0, (memAfter - memBefore) / Math.Max(1, totalIterations));\n");
         }
+        src.push_str(validation_call);
         src.push_str("        var result = new Dictionary<string, object?> {\n");
         src.push_str("            [\"iterations\"] = totalIterations,\n");
         src.push_str("            [\"totalNanos\"] = totalNs,\n");
@@ -702,12 +776,15 @@ fn generate_csharp_source(
             src.push_str("            [\"bytesPerOp\"] = bytesPerOp,\n");
         }
         src.push_str("            [\"samples\"] = samples,\n");
+        src.push_str(validation_entry);
         src.push_str("            [\"successfulCount\"] = successfulCount,\n");
         src.push_str("            [\"errorCount\"] = errorCount,\n");
         src.push_str("            [\"successfulResults\"] = successfulResults,\n");
         src.push_str("            [\"errorSamples\"] = errorSamples,\n");
         if spec.use_sink {
-            src.push_str("            [\"rawResult\"] = __polybench_sink,\n");
+            src.push_str(
+                "            [\"rawResult\"] = __polybench_raw_result(__polybench_sink),\n",
+            );
         }
         src.push_str("        };\n");
         src.push_str(&CSharpRuntime::emit_hook(spec.after_hooks.get(&Lang::CSharp), "        "));
@@ -740,9 +817,10 @@ fn generate_csharp_source(
         src.push_str("        double nanosPerOp = totalNs / Math.Max(1, totalIterations);\n");
         src.push_str("        double opsPerSec = 1_000_000_000.0 / Math.Max(1, nanosPerOp);\n");
         if use_memory {
-            src.push_str("        long memAfter = GC.GetTotalAllocatedBytes(true);\n");
+            src.push_str(&format!("        long memAfter = {};\n", allocated_bytes));
             src.push_str("        long bytesPerOp = Math.Max(0, (memAfter - memBefore) / Math.Max(1, totalIterations));\n");
         }
+        src.push_str(validation_call);
         src.push_str("        var result = new Dictionary<string, object?> {\n");
         src.push_str("            [\"iterations\"] = totalIterations,\n");
         src.push_str("            [\"totalNanos\"] = totalNs,\n");
@@ -753,8 +831,11 @@ fn generate_csharp_source(
             src.push_str("            [\"bytesPerOp\"] = bytesPerOp,\n");
         }
         src.push_str("            [\"samples\"] = samples,\n");
+        src.push_str(validation_entry);
         if spec.use_sink {
-            src.push_str("            [\"rawResult\"] = __polybench_sink,\n");
+            src.push_str(
+                "            [\"rawResult\"] = __polybench_raw_result(__polybench_sink),\n",
+            );
         }
         src.push_str("        };\n");
         src.push_str(&CSharpRuntime::emit_hook(spec.after_hooks.get(&Lang::CSharp), "        "));
@@ -779,9 +860,10 @@ fn generate_csharp_source(
         src.push_str("        double nanosPerOp = totalNs / Math.Max(1, iterations);\n");
         src.push_str("        double opsPerSec = 1_000_000_000.0 / Math.Max(1, nanosPerOp);\n");
         if use_memory {
-            src.push_str("        long memAfter = GC.GetTotalAllocatedBytes(true);\n");
+            src.push_str(&format!("        long memAfter = {};\n", allocated_bytes));
             src.push_str("        long bytesPerOp = Math.Max(0, (memAfter - memBefore) / Math.Max(1, iterations));\n");
         }
+        src.push_str(validation_call);
         src.push_str("        var result = new Dictionary<string, object?> {\n");
         src.push_str("            [\"iterations\"] = iterations,\n");
         src.push_str("            [\"totalNanos\"] = totalNs,\n");
@@ -792,8 +874,11 @@ fn generate_csharp_source(
             src.push_str("            [\"bytesPerOp\"] = bytesPerOp,\n");
         }
         src.push_str("            [\"samples\"] = samples,\n");
+        src.push_str(validation_entry);
         if spec.use_sink {
-            src.push_str("            [\"rawResult\"] = __polybench_sink,\n");
+            src.push_str(
+                "            [\"rawResult\"] = __polybench_raw_result(__polybench_sink),\n",
+            );
         }
         src.push_str("        };\n");
         src.push_str(&CSharpRuntime::emit_hook(spec.after_hooks.get(&Lang::CSharp), "        "));
@@ -826,6 +911,8 @@ struct BenchResultJson {
     successful_results: Vec<String>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -849,7 +936,7 @@ impl BenchResultJson {
             m.warmup_nanos = Some(w as u64);
         }
         if let Some(bytes) = self.bytes_per_op {
-            m = m.with_allocs(bytes, self.allocs_per_op.unwrap_or(0));
+            m = m.with_memory(bytes, self.allocs_per_op);
         }
         if let Some(sc) = self.successful_count {
             m.async_success_count = Some(sc);
@@ -864,11 +951,7 @@ impl BenchResultJson {
     }
 }
 
-fn parse_benchmark_result(
-    stdout: &str,
-    outlier_detection: bool,
-    cv_threshold: f64,
-) -> Result<Measurement> {
+fn parse_benchmark_result(stdout: &str, spec: &BenchmarkSpec) -> Result<Measurement> {
     let json_line = stdout
         .lines()
         .filter(|l| !l.trim().is_empty())
@@ -876,5 +959,48 @@ fn parse_benchmark_result(
         .ok_or_else(|| miette!("No benchmark output from C# runtime"))?;
     let result: BenchResultJson = serde_json::from_str(json_line)
         .map_err(|e| miette!("Failed to parse C# benchmark output: {}\n{}", e, stdout))?;
-    Ok(result.into_measurement(outlier_detection, cv_threshold))
+    let validation_passed = result.validation_passed;
+    let mut m = result.into_measurement(spec.outlier_detection, spec.cv_threshold);
+    m.record_validation(spec.validations.get(&Lang::CSharp), validation_passed);
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validated_spec() -> (BenchmarkSpec, SuiteIR) {
+        let suite = SuiteIR::new("snap".to_string());
+        let mut spec = BenchmarkSpec::new("add".to_string(), "snap", 10, 5, 0);
+        spec.implementations.insert(Lang::CSharp, "1 + 2".to_string());
+        spec.validations.insert(Lang::CSharp, "result == 3".to_string());
+        (spec, suite)
+    }
+
+    #[test]
+    fn test_validation_runs_after_timing() {
+        let (spec, suite) = validated_spec();
+        let src = generate_csharp_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("var result = (1 + 2);"));
+        assert!(src.contains("result == 3"));
+        assert!(src.contains("validationPassed"));
+    }
+
+    #[test]
+    fn test_no_validation_without_expression() {
+        let (mut spec, suite) = validated_spec();
+        spec.validations.clear();
+        let src = generate_csharp_source(&spec, &suite, false).unwrap();
+        assert!(!src.contains("__polybench_validate()"));
+    }
+
+    #[test]
+    fn test_memory_reports_cumulative_bytes_without_counts() {
+        let (mut spec, suite) = validated_spec();
+        spec.memory = true;
+        let src = generate_csharp_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("GC.GetAllocatedBytesForCurrentThread()"));
+        assert!(src.contains("[\"bytesPerOp\"] = bytesPerOp"));
+        assert!(!src.contains("allocsPerOp"));
+    }
 }
@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static CSHARP_BUILTINS: &[&str] = &[
     "Console",
     "Math",
@@ -23,6 +24,23 @@ static CSHARP_BUILTINS: &[&str] = &[
     "HashSet",
     "Span",
     "ReadOnlySpan",
+    "if",
+    "for",
+    "foreach",
+    "while",
+    "switch",
+    "return",
+    "catch",
+    "using",
+    "lock",
+    "typeof",
+    "nameof",
+    "sizeof",
+    "default",
+    "checked",
+    "unchecked",
+    "fixed",
+    "when",
 ];
 
 pub(crate) struct CSharpHelperFunctionExtractor;
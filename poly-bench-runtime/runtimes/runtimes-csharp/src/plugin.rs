@@ -39,6 +39,10 @@ impl RuntimePlugin for CSharpPlugin {
         csharp_lang_display()
     }
 
+    fn memory_metric_note(&self) -> Option<&'static str> {
+        Some("cumulative bytes (GC.GetAllocatedBytesForCurrentThread); no allocation counts")
+    }
+
     fn project_root_detector(&self) -> Option<&'static dyn ProjectRootDetector> {
         Some(&CSHARP_DETECTOR)
     }
@@ -2,12 +2,12 @@
 
 use miette::{miette, Result};
 use poly_bench_dsl::{BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
 use poly_bench_stdlib as stdlib;
 
 use super::shared::{
     self, generate_bench_call, generate_suite_code, CollectedImports, SinkMemoryDecls,
-    BENCH_RESULT_STRUCT,
+    BENCH_RESULT_STRUCT, RAW_RESULT_HELPER,
 };
 
 /// Generate Go plugin source code from IR
@@ -27,6 +27,13 @@ pub fn generate(ir: &BenchmarkIR) -> Result<String> {
         }
     }
 
+    let uses_external_fixture = ir.suites.iter().flat_map(|suite| &suite.fixtures).any(|fixture| {
+        fixture.external_file.is_some() && !fixture.implementations.contains_key(&Lang::Go)
+    });
+    if uses_external_fixture {
+        user_imports.extend(shared::EXTERNAL_FIXTURE_IMPORTS);
+    }
+
     // Collect stdlib imports
     let stdlib_imports = stdlib::get_stdlib_imports(&ir.stdlib_imports, &crate::GO_STDLIB);
 
@@ -52,6 +59,8 @@ pub fn generate(ir: &BenchmarkIR) -> Result<String> {
     // BenchResult type with plugin exports
     code.push_str("// BenchResult holds the benchmark measurement results\n");
     code.push_str(BENCH_RESULT_STRUCT);
+    code.push('\n');
+    code.push_str(RAW_RESULT_HELPER);
     code.push_str("\n// Export symbols for plugin loading\nvar (\n\tRunBenchmark  = runBenchmark\n\tListBenchmarks = listBenchmarks\n)\n\n");
 
     // Generate code for each suite
@@ -155,7 +164,13 @@ fn generate_fixture(code: &mut String, fixture: &FixtureIR) -> Result<()> {
         if let Some(ref desc) = fixture.description {
             code.push_str(&format!("// {}\n", desc));
         }
-        code.push_str(&format!("var {} = {}\n\n", fixture.name, fixture.as_go_bytes()));
+        match fixture.external_file_literal(Lang::Go) {
+            Some(path) => {
+                code.push_str(&shared::external_fixture_var(&fixture.name, &path));
+                code.push('\n');
+            }
+            None => code.push_str(&format!("var {} = {}\n\n", fixture.name, fixture.as_go_bytes())),
+        }
     }
     Ok(())
 }
@@ -176,11 +191,12 @@ fn generate_benchmark(code: &mut String, bench: &BenchmarkSpec, _suite: &SuiteIR
     let bench_call = generate_bench_call(impl_code, bench.use_sink);
 
     // Generate hook code
-    let before_hook = bench
-        .before_hooks
-        .get(&Lang::Go)
-        .map(|h| format!("\t// Before hook\n\t{}\n\n", h.trim()))
-        .unwrap_or_default();
+    let before_hook = param_declarations(&bench.params, Lang::Go, "\t") +
+        &bench
+            .before_hooks
+            .get(&Lang::Go)
+            .map(|h| format!("\t// Before hook\n\t{}\n\n", h.trim()))
+            .unwrap_or_default();
     let after_hook = bench
         .after_hooks
         .get(&Lang::Go)
@@ -218,11 +234,7 @@ fn generate_benchmark(code: &mut String, bench: &BenchmarkSpec, _suite: &SuiteIR
 
 	nanosPerOp := float64(totalNanos) / float64(totalIterations)
 	opsPerSec := 1e9 / nanosPerOp
-	rawResultBytes, _ := json.Marshal(__sink)
-	rawResult := ""
-	if string(rawResultBytes) != "null" {{
-		rawResult = string(rawResultBytes)
-	}}
+	rawResult := polybenchRawResult(__sink)
 	
 {}
 	return BenchResult{{
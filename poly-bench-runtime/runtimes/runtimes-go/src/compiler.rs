@@ -20,7 +20,11 @@ impl GoCompiler {
     pub fn new() -> Result<Self> {
         let (go_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Go)
             .map_err(|_| miette!("Go compiler not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH"))?;
+        Self::with_binary(go_binary)
+    }
 
+    /// Create a Go compiler using a specific go binary (runtime variants)
+    pub fn with_binary(go_binary: PathBuf) -> Result<Self> {
         let temp_dir =
             TempDir::new().map_err(|e| miette!("Failed to create temp directory: {}", e))?;
 
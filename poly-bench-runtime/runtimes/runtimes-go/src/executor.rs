@@ -5,16 +5,40 @@ use async_trait::async_trait;
 use libloading::{Library, Symbol};
 use miette::{miette, Result};
 use poly_bench_dsl::{BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{ErrorMapper, Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{
+    ArtifactCache, ArtifactKey, CounterTotals, ErrorMapper, Measurement, Runtime, RuntimeConfig,
+    RuntimeFactory, VariantToolchain,
+};
 use std::{collections::HashSet, path::PathBuf, process::Stdio};
 
 use super::shared::{
-    self, generate_bench_call, generate_fixtures_for_spec, generate_suite_code, SinkMemoryDecls,
-    BENCH_RESULT_STRUCT,
+    self, generate_bench_call, generate_fixtures_for_spec, generate_suite_code, CounterDecls,
+    SinkMemoryDecls, BENCH_RESULT_STRUCT,
 };
 
+/// Extensions of module sources `go build` compiles into the harness
+pub const SOURCE_EXTENSIONS: &[&str] = &["go", "s", "c", "h"];
+
+/// Environment read by `go build`, keyed into cached binaries
+const BUILD_ENV: &[&str] = &[
+    "GOFLAGS",
+    "GOOS",
+    "GOARCH",
+    "GOAMD64",
+    "GOARM",
+    "GOARM64",
+    "GO386",
+    "GOEXPERIMENT",
+    "GOTOOLCHAIN",
+    "GOWORK",
+    "CGO_*",
+    "CC",
+    "CXX",
+    "PKG_CONFIG_PATH",
+];
+
 /// Go runtime using plugin system
 pub struct GoRuntime {
     /// Compiled plugin library
@@ -31,6 +55,10 @@ pub struct GoRuntime {
     cached_binary: Option<(PathBuf, u64)>,
     /// Duration of last precompile in nanoseconds (for accurate reporting)
     last_precompile_nanos: Option<u64>,
+    /// Persistent cache of built binaries shared across runs
+    artifacts: ArtifactCache,
+    /// Toolchain, build flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl GoRuntime {
@@ -43,6 +71,8 @@ impl GoRuntime {
             anvil_rpc_url: None,
             cached_binary: None,
             last_precompile_nanos: None,
+            artifacts: ArtifactCache::from_env(),
+            toolchain: VariantToolchain::default(),
         }
     }
 
@@ -78,6 +108,7 @@ impl RuntimeFactory for GoRuntimeFactory {
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
         let mut rt = GoRuntime::new();
         rt.set_module_root(config.get_root(Lang::Go));
+        rt.toolchain = config.toolchain();
         Ok(Box::new(rt))
     }
 }
@@ -98,7 +129,10 @@ impl Runtime for GoRuntime {
 
     async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
         // Go plugins only work on Linux, so we'll use subprocess execution on all platforms
-        let compiler = GoCompiler::new()?;
+        let (go_binary, _) = self.toolchain.resolve_binary(Lang::Go).map_err(|_| {
+            miette!("Go compiler not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH")
+        })?;
+        let compiler = GoCompiler::with_binary(go_binary)?;
         self.compiler = Some(compiler);
         Ok(())
     }
@@ -136,19 +170,19 @@ impl Runtime for GoRuntime {
             (src_path, compiler.temp_path().to_path_buf())
         };
 
-        std::fs::write(&src_path, &source)
+        std::fs::write(&src_path, source)
             .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
 
-        let (go_binary, _) =
-            poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Go).map_err(|_| {
-                miette!(
-                    "Go not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH"
-                )
-            })?;
+        let (go_binary, _) = self.toolchain.resolve_binary(Lang::Go).map_err(|_| {
+            miette!("Go not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH")
+        })?;
 
         // Use 'go build' to compile without running
         let output = tokio::process::Command::new(&go_binary)
-            .args(["build", "-o", "/dev/null", src_path.to_str().unwrap()])
+            .arg("build")
+            .args(&self.toolchain.flags)
+            .args(["-o", "/dev/null", src_path.to_str().unwrap()])
+            .envs(&self.toolchain.env)
             .current_dir(&working_dir)
             .output()
             .await
@@ -179,56 +213,7 @@ impl Runtime for GoRuntime {
 
         let pc_start = std::time::Instant::now();
 
-        // Need to compile - set up directories and source
-        let (src_path, working_dir) = if let Some(ref module_root) = self.module_root {
-            let is_runtime_env = module_root.as_os_str().to_string_lossy().contains("runtime-env");
-            let src_path = if is_runtime_env {
-                module_root.join("bench_standalone.go")
-            } else {
-                let bench_dir = module_root.join(".polybench");
-                std::fs::create_dir_all(&bench_dir)
-                    .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
-                bench_dir.join("bench_standalone.go")
-            };
-            (src_path, module_root.clone())
-        } else {
-            let compiler =
-                self.compiler.as_ref().ok_or_else(|| miette!("Compiler not initialized"))?;
-
-            let src_path = compiler.temp_path().join("bench_standalone.go");
-            (src_path, compiler.temp_path().to_path_buf())
-        };
-
-        std::fs::write(&src_path, &source)
-            .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
-
-        let (go_binary, _) =
-            poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Go).map_err(|_| {
-                miette!(
-                    "Go not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH"
-                )
-            })?;
-
-        // Build the binary (separate from running)
-        let binary_path = working_dir.join("polybench_runner");
-        let build_output = tokio::process::Command::new(&go_binary)
-            .args(["build", "-o", binary_path.to_str().unwrap(), src_path.to_str().unwrap()])
-            .current_dir(&working_dir)
-            .output()
-            .await
-            .map_err(|e| miette!("Failed to build Go benchmark: {}", e))?;
-
-        if !build_output.status.success() {
-            let stderr = String::from_utf8_lossy(&build_output.stderr);
-            return Err(miette!("Go benchmark build failed:\n{}", stderr));
-        }
-
-        if !binary_path.exists() {
-            return Err(miette!(
-                "Compiled binary not found at expected path: {}",
-                binary_path.display()
-            ));
-        }
+        let binary_path = self.build_binary(&source).await?;
 
         // Cache the binary path and source hash for reuse
         self.cached_binary = Some((binary_path, source_hash));
@@ -291,9 +276,80 @@ impl GoRuntime {
         }
     }
 
+    /// Write the harness source and build it, reusing a cached binary built from the same
+    /// inputs
+    async fn build_binary(&self, source: &str) -> Result<PathBuf> {
+        let (src_path, working_dir) = if let Some(ref module_root) = self.module_root {
+            let is_runtime_env = module_root.as_os_str().to_string_lossy().contains("runtime-env");
+            let src_path = if is_runtime_env {
+                module_root.join(self.toolchain.artifact_name("bench_standalone.go"))
+            } else {
+                let bench_dir = module_root.join(".polybench");
+                std::fs::create_dir_all(&bench_dir)
+                    .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
+                bench_dir.join(self.toolchain.artifact_name("bench_standalone.go"))
+            };
+            (src_path, module_root.clone())
+        } else {
+            let compiler =
+                self.compiler.as_ref().ok_or_else(|| miette!("Compiler not initialized"))?;
+
+            let src_path =
+                compiler.temp_path().join(self.toolchain.artifact_name("bench_standalone.go"));
+            (src_path, compiler.temp_path().to_path_buf())
+        };
+
+        std::fs::write(&src_path, source)
+            .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
+
+        let (go_binary, _) = self.toolchain.resolve_binary(Lang::Go).map_err(|_| {
+            miette!("Go not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH")
+        })?;
+
+        let key = ArtifactKey::new(Lang::Go, source)
+            .toolchain(&go_binary, &["version"])
+            .variant(&self.toolchain)
+            .ambient_env(BUILD_ENV)
+            .file(&working_dir.join("go.mod"))
+            .file(&working_dir.join("go.sum"))
+            .source_tree(&working_dir, SOURCE_EXTENSIONS);
+        let binary_name = self.toolchain.artifact_name("polybench_runner");
+        if let Some(cached) = self.artifacts.lookup(&key, &binary_name) {
+            return Ok(cached);
+        }
+
+        // Build the binary (separate from running)
+        let binary_path = working_dir.join(&binary_name);
+        let build_output = tokio::process::Command::new(&go_binary)
+            .arg("build")
+            .args(&self.toolchain.flags)
+            .args(["-o", binary_path.to_str().unwrap(), src_path.to_str().unwrap()])
+            .envs(&self.toolchain.env)
+            .current_dir(&working_dir)
+            .output()
+            .await
+            .map_err(|e| miette!("Failed to build Go benchmark: {}", e))?;
+
+        if !build_output.status.success() {
+            let stderr = String::from_utf8_lossy(&build_output.stderr);
+            return Err(miette!("Go benchmark build failed:\n{}", stderr));
+        }
+
+        if !binary_path.exists() {
+            return Err(miette!(
+                "Compiled binary not found at expected path: {}",
+                binary_path.display()
+            ));
+        }
+
+        Ok(self.artifacts.store(&key, &binary_path).unwrap_or(binary_path))
+    }
+
     /// Run a pre-compiled binary and parse the result
     async fn run_binary(&self, binary_path: &PathBuf, spec: &BenchmarkSpec) -> Result<Measurement> {
         let mut cmd = tokio::process::Command::new(binary_path);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
+        cmd.envs(&self.toolchain.env);
 
         if let Some(ref url) = self.anvil_rpc_url {
             cmd.env("ANVIL_RPC_URL", url);
@@ -336,7 +392,9 @@ impl GoRuntime {
             .ok_or_else(|| miette!("No output from benchmark"))?;
         let result: BenchResultJson = serde_json::from_str(json_line)
             .map_err(|e| miette!("Failed to parse benchmark result: {}\nOutput: {}", e, stdout))?;
+        let validation_passed = result.validation_passed;
         let mut m = result.into_measurement_with_options(spec.outlier_detection, spec.cv_threshold);
+        m.record_validation(spec.validations.get(&Lang::Go), validation_passed);
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -365,56 +423,7 @@ impl GoRuntime {
             }
         }
 
-        // Need to compile - set up directories and source
-        let (src_path, working_dir) = if let Some(ref module_root) = self.module_root {
-            let is_runtime_env = module_root.as_os_str().to_string_lossy().contains("runtime-env");
-            let src_path = if is_runtime_env {
-                module_root.join("bench_standalone.go")
-            } else {
-                let bench_dir = module_root.join(".polybench");
-                std::fs::create_dir_all(&bench_dir)
-                    .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
-                bench_dir.join("bench_standalone.go")
-            };
-            (src_path, module_root.clone())
-        } else {
-            let compiler =
-                self.compiler.as_ref().ok_or_else(|| miette!("Compiler not initialized"))?;
-
-            let src_path = compiler.temp_path().join("bench_standalone.go");
-            (src_path, compiler.temp_path().to_path_buf())
-        };
-
-        std::fs::write(&src_path, &source)
-            .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
-
-        let (go_binary, _) =
-            poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Go).map_err(|_| {
-                miette!(
-                    "Go not found. Install via 'poly-bench add-runtime go' or ensure go is in PATH"
-                )
-            })?;
-
-        // Build the binary (separate from running)
-        let binary_path = working_dir.join("polybench_runner");
-        let build_output = tokio::process::Command::new(&go_binary)
-            .args(["build", "-o", binary_path.to_str().unwrap(), src_path.to_str().unwrap()])
-            .current_dir(&working_dir)
-            .output()
-            .await
-            .map_err(|e| miette!("Failed to build Go benchmark: {}", e))?;
-
-        if !build_output.status.success() {
-            let stderr = String::from_utf8_lossy(&build_output.stderr);
-            return Err(miette!("Go benchmark build failed:\n{}", stderr));
-        }
-
-        if !binary_path.exists() {
-            return Err(miette!(
-                "Compiled binary not found at expected path: {}",
-                binary_path.display()
-            ));
-        }
+        let binary_path = self.build_binary(&source).await?;
 
         // Cache the binary path and source hash for reuse
         self.cached_binary = Some((binary_path.clone(), source_hash));
@@ -449,6 +458,10 @@ struct BenchResultJson {
     error_count: Option<u64>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    counters: Option<CounterTotals>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -484,6 +497,7 @@ impl BenchResultJson {
         if let Some(w) = self.warmup_nanos {
             m.warmup_nanos = Some(w);
         }
+        m.counters = self.counters.map(|c| c.per_op(self.iterations));
 
         m
     }
@@ -510,6 +524,12 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
     if spec.use_sink || spec.memory {
         all_imports.insert("\"runtime\"");
     }
+    if spec.counters {
+        all_imports.extend(shared::PERF_COUNTERS_IMPORTS);
+    }
+    if suite.uses_external_fixture(spec, Lang::Go) {
+        all_imports.extend(shared::EXTERNAL_FIXTURE_IMPORTS);
+    }
     if let Some(user_imports) = suite.imports.get(&Lang::Go) {
         for import_spec in user_imports {
             all_imports.insert(import_spec);
@@ -530,6 +550,12 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
     // BenchResult type
     code.push_str(BENCH_RESULT_STRUCT);
     code.push('\n');
+    code.push_str(shared::RAW_RESULT_HELPER);
+    code.push('\n');
+    if spec.counters {
+        code.push_str(shared::PERF_COUNTERS_HELPER);
+        code.push('\n');
+    }
 
     // Inject stdlib code
     let stdlib_code = stdlib::get_stdlib_code(&suite.stdlib_imports, &crate::GO_STDLIB);
@@ -544,8 +570,14 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
     // Fixtures
     code.push_str(&generate_fixtures_for_spec(spec, suite, Lang::Go));
 
+    // Benchmark parameters
+    code.push_str(&param_declarations(&spec.params, Lang::Go, ""));
+
     // Get shared declarations
-    let decls = SinkMemoryDecls::from_spec(spec);
+    let harness = HarnessDecls {
+        sink_memory: SinkMemoryDecls::from_spec(spec),
+        counters: CounterDecls::from_spec(spec),
+    };
     let bench_call = generate_bench_call(impl_code, spec.use_sink);
     let before_hook = spec.before_hooks.get(&Lang::Go);
     let after_hook = spec.after_hooks.get(&Lang::Go);
@@ -556,7 +588,7 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
         BenchMode::Auto => generate_auto_main(
             &mut code,
             spec,
-            &decls,
+            &harness,
             &bench_call,
             before_hook,
             after_hook,
@@ -565,7 +597,7 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
         BenchMode::Fixed => generate_fixed_main(
             &mut code,
             spec,
-            &decls,
+            &harness,
             &bench_call,
             before_hook,
             after_hook,
@@ -574,7 +606,10 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
     }
 
     // Memory profiling after measurement
-    code.push_str(decls.memory_after);
+    code.push_str(harness.sink_memory.memory_after);
+
+    let validation = spec.validations.get(&Lang::Go);
+    code.push_str(&shared::generate_validation(impl_code, validation));
 
     // After hook
     if let Some(after) = after_hook {
@@ -586,23 +621,34 @@ fn generate_standalone_benchmark(spec: &BenchmarkSpec, suite: &SuiteIR) -> Resul
 
     // Result calculation and output
     let iter_var = if spec.mode == BenchMode::Auto { "totalIterations" } else { "iterations" };
-    let memory_result = SinkMemoryDecls::memory_result_fields(spec.memory, iter_var);
+    let mut memory_result = SinkMemoryDecls::memory_result_fields(spec.memory, iter_var) +
+        harness.counters.result_field;
+    if validation.is_some() {
+        memory_result.push_str(shared::VALIDATION_RESULT_FIELD);
+    }
     code.push_str(&shared::generate_result_return(iter_var, &memory_result, true));
     code.push_str("}\n");
 
     Ok(code)
 }
 
+/// Statements the main function splices around its warmup and measurement loops
+struct HarnessDecls {
+    sink_memory: SinkMemoryDecls,
+    counters: CounterDecls,
+}
+
 /// Generate auto-calibration main function
 fn generate_auto_main(
     code: &mut String,
     spec: &BenchmarkSpec,
-    decls: &SinkMemoryDecls,
+    harness: &HarnessDecls,
     bench_call: &str,
     before_hook: Option<&String>,
     _after_hook: Option<&String>,
     each_hook: Option<&String>,
 ) {
+    let HarnessDecls { sink_memory: decls, counters } = harness;
     let is_async = spec.kind == BenchmarkKind::Async;
     // Note: targetNanos is declared inside generate_auto_mode_loop, not here
     code.push_str(&format!(
@@ -646,6 +692,7 @@ func main() {{
     ));
 
     // Auto-calibration loop
+    code.push_str(counters.start);
     if is_async {
         code.push_str(&format!(
             "\n{}",
@@ -669,6 +716,7 @@ func main() {{
             )
         ));
     }
+    code.push_str(counters.stop);
 
     // No post-sample collection for sync (rely on batch aggregate, like Go testing.B)
     if !is_async {
@@ -680,12 +728,13 @@ func main() {{
 fn generate_fixed_main(
     code: &mut String,
     spec: &BenchmarkSpec,
-    decls: &SinkMemoryDecls,
+    harness: &HarnessDecls,
     bench_call: &str,
     before_hook: Option<&String>,
     _after_hook: Option<&String>,
     each_hook: Option<&String>,
 ) {
+    let HarnessDecls { sink_memory: decls, counters } = harness;
     code.push_str(&format!(
         r#"
 func main() {{
@@ -729,8 +778,10 @@ func main() {{
     ));
 
     // Fixed measurement loop
+    code.push_str(counters.start);
     code.push_str(&format!(
         "\n{}",
         shared::generate_fixed_mode_loop(bench_call, decls.sink_keepalive, each_hook, "iterations")
     ));
+    code.push_str(counters.stop);
 }
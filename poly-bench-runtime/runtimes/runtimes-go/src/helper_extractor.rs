@@ -4,11 +4,12 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static GO_BUILTINS: &[&str] = &[
     "len", "make", "append", "copy", "delete", "panic", "recover", "print", "println", "close",
     "cap", "new", "real", "imag", "complex", "error", "string", "int", "int8", "int16", "int32",
     "int64", "uint", "uint8", "uint16", "uint32", "uint64", "float32", "float64", "bool", "byte",
-    "rune",
+    "rune", "if", "for", "switch", "return", "func", "go", "defer",
 ];
 
 pub(crate) struct GoHelperFunctionExtractor;
@@ -22,9 +22,137 @@ pub const BENCH_RESULT_STRUCT: &str = r#"type BenchResult struct {
 	SuccessfulCount uint64 `json:"successful_count,omitempty"`
 	ErrorCount uint64 `json:"error_count,omitempty"`
 	ErrorSamples []string `json:"error_samples,omitempty"`
+	Counters map[string]interface{} `json:"counters,omitempty"`
+	ValidationPassed *bool `json:"validation_passed,omitempty"`
 }
 "#;
 
+/// Serializes the last benchmark result for `raw_result`. Byte slices are written as integer
+/// arrays (not base64) so they compare equal to other languages' byte arrays under
+/// `equivalent: true`.
+pub const RAW_RESULT_HELPER: &str = r#"func polybenchRawResult(v interface{}) string {
+	if b, ok := v.([]byte); ok {
+		ints := make([]int, len(b))
+		for i, x := range b {
+			ints[i] = int(x)
+		}
+		v = ints
+	}
+	out, err := json.Marshal(v)
+	if err != nil || string(out) == "null" {
+		return ""
+	}
+	return string(out)
+}
+"#;
+
+/// Hardware counter helper (`counters: true`): opens instructions, cycles, cache-miss and
+/// branch-miss counters for the benchmarking thread via `perf_event_open` and reports totals
+/// (or the reason they are unavailable) in the result's `counters` object.
+#[cfg(target_os = "linux")]
+pub const PERF_COUNTERS_HELPER: &str = r#"type polybenchPerfEventAttr struct {
+	Type         uint32
+	Size         uint32
+	Config       uint64
+	SamplePeriod uint64
+	SampleType   uint64
+	ReadFormat   uint64
+	Flags        uint64
+	WakeupEvents uint32
+	BpType       uint32
+	Config1      uint64
+}
+
+type polybenchCounters struct {
+	fds   []int
+	names []string
+	err   string
+}
+
+func polybenchCountersStart() *polybenchCounters {
+	runtime.LockOSThread()
+	c := &polybenchCounters{}
+	events := []struct {
+		name   string
+		config uint64
+	}{{"instructions", 1}, {"cycles", 0}, {"cache_misses", 3}, {"branch_misses", 5}}
+	for _, ev := range events {
+		// PERF_TYPE_HARDWARE, disabled | exclude_kernel | exclude_hv
+		attr := polybenchPerfEventAttr{Size: uint32(unsafe.Sizeof(polybenchPerfEventAttr{})), Config: ev.config, Flags: 0x61}
+		fd, _, errno := syscall.Syscall6(syscall.SYS_PERF_EVENT_OPEN, uintptr(unsafe.Pointer(&attr)), 0, ^uintptr(0), ^uintptr(0), 0, 0)
+		if errno != 0 {
+			if c.err == "" {
+				c.err = "perf_event_open(" + ev.name + "): " + errno.Error()
+			}
+			continue
+		}
+		c.fds = append(c.fds, int(fd))
+		c.names = append(c.names, ev.name)
+	}
+	for _, fd := range c.fds {
+		syscall.Syscall(syscall.SYS_IOCTL, uintptr(fd), 0x2403, 0) // PERF_EVENT_IOC_RESET
+		syscall.Syscall(syscall.SYS_IOCTL, uintptr(fd), 0x2400, 0) // PERF_EVENT_IOC_ENABLE
+	}
+	return c
+}
+
+func (c *polybenchCounters) stop() map[string]interface{} {
+	for _, fd := range c.fds {
+		syscall.Syscall(syscall.SYS_IOCTL, uintptr(fd), 0x2401, 0) // PERF_EVENT_IOC_DISABLE
+	}
+	out := map[string]interface{}{}
+	for i, fd := range c.fds {
+		var buf [8]byte
+		if n, err := syscall.Read(fd, buf[:]); err == nil && n == 8 {
+			out[c.names[i]] = *(*uint64)(unsafe.Pointer(&buf[0]))
+		}
+		syscall.Close(fd)
+	}
+	if c.err != "" {
+		out["error"] = c.err
+	}
+	runtime.UnlockOSThread()
+	return out
+}
+"#;
+
+/// Hardware counter helper stub for platforms without `perf_event_open`
+#[cfg(not(target_os = "linux"))]
+pub const PERF_COUNTERS_HELPER: &str = r#"type polybenchCounters struct{}
+
+func polybenchCountersStart() *polybenchCounters { return &polybenchCounters{} }
+
+func (c *polybenchCounters) stop() map[string]interface{} {
+	return map[string]interface{}{"error": "hardware counters require Linux perf_event_open"}
+}
+"#;
+
+/// Imports needed by [`PERF_COUNTERS_HELPER`]
+pub const PERF_COUNTERS_IMPORTS: &[&str] =
+    if cfg!(target_os = "linux") { &["\"runtime\"", "\"syscall\"", "\"unsafe\""] } else { &[] };
+
+/// Start/stop statements wrapping the timed region and the matching BenchResult field
+pub struct CounterDecls {
+    pub start: &'static str,
+    pub stop: &'static str,
+    pub result_field: &'static str,
+}
+
+impl CounterDecls {
+    /// Create counter statements based on benchmark spec (empty unless `counters: true`)
+    pub fn from_spec(spec: &BenchmarkSpec) -> Self {
+        if spec.counters {
+            Self {
+                start: "\n\t__counters := polybenchCountersStart()\n",
+                stop: "\t__counterTotals := __counters.stop()\n",
+                result_field: "\t\tCounters: __counterTotals,\n",
+            }
+        } else {
+            Self { start: "", stop: "", result_field: "" }
+        }
+    }
+}
+
 /// Collected imports for code generation
 pub struct CollectedImports<'a> {
     pub all_imports: HashSet<&'a str>,
@@ -565,6 +693,8 @@ pub fn generate_fixtures_for_spec(spec: &BenchmarkSpec, suite: &SuiteIR, lang: L
                 } else {
                     code.push_str(&format!("var {} = {}\n", fixture_name, fixture_impl));
                 }
+            } else if let Some(path) = fixture.external_file_literal(lang) {
+                code.push_str(&external_fixture_var(fixture_name, &path));
             } else if !fixture.data.is_empty() {
                 code.push_str(&format!("var {} = {}\n", fixture_name, fixture.as_go_bytes()));
             }
@@ -578,6 +708,77 @@ pub fn generate_fixtures_for_spec(spec: &BenchmarkSpec, suite: &SuiteIR, lang: L
     code
 }
 
+/// Imports [`external_fixture_var`] needs
+pub const EXTERNAL_FIXTURE_IMPORTS: &[&str] =
+    if cfg!(unix) { &["\"os\"", "\"syscall\""] } else { &["\"os\""] };
+
+/// Package-level variable that maps an external fixture file at startup (reads it on Windows).
+/// The mapping is private and writable, so the fixture is an ordinary mutable `[]byte`.
+pub fn external_fixture_var(name: &str, path: &str) -> String {
+    if !cfg!(unix) {
+        return format!(
+            "var {} = func() []byte {{\n\tdata, err := os.ReadFile({})\n\tif err != nil {{\n\t\tpanic(err)\n\t}}\n\treturn data\n}}()\n",
+            name, path
+        );
+    }
+    format!(
+        r#"var {} = func() []byte {{
+	f, err := os.Open({})
+	if err != nil {{
+		panic(err)
+	}}
+	defer f.Close()
+	info, err := f.Stat()
+	if err != nil {{
+		panic(err)
+	}}
+	data, err := syscall.Mmap(int(f.Fd()), 0, int(info.Size()), syscall.PROT_READ|syscall.PROT_WRITE, syscall.MAP_PRIVATE)
+	if err != nil {{
+		panic(err)
+	}}
+	return data
+}}()
+"#,
+        name, path
+    )
+}
+
+/// Validation (`validate`): calls the implementation once more after the timed region and
+/// evaluates the expression with that value bound to `result`. Empty without a validation;
+/// otherwise pair it with [`VALIDATION_RESULT_FIELD`].
+pub fn generate_validation(impl_code: &str, validation: Option<&String>) -> String {
+    let Some(check) = validation else {
+        return String::new();
+    };
+    let mut code = String::from(
+        "
+	// Validation (outside the timed region)
+",
+    );
+    code.push_str(
+        "	validationPassed := func() bool {
+",
+    );
+    code.push_str(&format!(
+        "		result := {}
+",
+        impl_code.trim()
+    ));
+    code.push_str(&format!(
+        "		return {}
+",
+        check.trim()
+    ));
+    code.push_str(
+        "	}()
+",
+    );
+    code
+}
+
+/// `BenchResult` field reporting the outcome of [`generate_validation`]
+pub const VALIDATION_RESULT_FIELD: &str = "\t\tValidationPassed: &validationPassed,\n";
+
 /// Generate result calculation and return
 pub fn generate_result_return(
     iter_var: &str,
@@ -600,11 +801,7 @@ pub fn generate_result_return(
         r#"
 	nanosPerOp := float64(totalNanos) / float64({iter_var})
 	opsPerSec := 1e9 / nanosPerOp
-	rawResultBytes, _ := json.Marshal(__sink)
-	rawResult := ""
-	if string(rawResultBytes) != "null" {{
-		rawResult = string(rawResultBytes)
-	}}
+	rawResult := polybenchRawResult(__sink)
 	if successfulResults == nil {{
 		successfulResults = []string{{}}
 	}}
@@ -644,6 +841,15 @@ mod tests {
         assert!(BENCH_RESULT_STRUCT.contains("error_samples"));
     }
 
+    #[test]
+    fn test_generate_validation_binds_result() {
+        assert!(generate_validation("sha256SumGo(data)", None).is_empty());
+        let check = "len(result) == 32".to_string();
+        let code = generate_validation("sha256SumGo(data)", Some(&check));
+        assert!(code.contains("validationPassed := func() bool {"));
+        assert!(code.contains("\t\tresult := sha256SumGo(data)\n\t\treturn len(result) == 32\n"));
+    }
+
     #[test]
     fn test_generate_bench_call() {
         // Void-like (Ident()): wraps in closure to avoid "used as value" errors
@@ -659,6 +865,17 @@ mod tests {
         assert_eq!(generate_bench_call("foo()", false), "foo()");
     }
 
+    #[test]
+    fn test_counter_decls_only_when_enabled() {
+        let mut spec = BenchmarkSpec::new("b".to_string(), "s", 100, 0, 0);
+        assert!(CounterDecls::from_spec(&spec).start.is_empty());
+        spec.counters = true;
+        let decls = CounterDecls::from_spec(&spec);
+        assert!(decls.start.contains("polybenchCountersStart()"));
+        assert!(decls.result_field.contains("__counterTotals"));
+        assert!(PERF_COUNTERS_HELPER.contains("func polybenchCountersStart()"));
+    }
+
     #[test]
     fn test_memory_result_fields() {
         let result = SinkMemoryDecls::memory_result_fields(true, "iterations");
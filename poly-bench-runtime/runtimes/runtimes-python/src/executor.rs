@@ -6,12 +6,15 @@
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{AsyncSamplingPolicy, BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{Measurement, Runtime, RuntimeConfig, RuntimeFactory, VariantToolchain};
 use std::{path::PathBuf, process::Stdio};
 use tempfile::TempDir;
 
+/// Calls traced by the post-timing memory pass (`memory: true`)
+const MEMORY_PASS_OPS: u64 = 1000;
+
 /// Python runtime using subprocess execution
 pub struct PythonRuntime {
     temp_dir: Option<TempDir>,
@@ -21,11 +24,18 @@ pub struct PythonRuntime {
     cached_script: Option<(PathBuf, PathBuf, u64)>,
     /// Duration of last precompile in nanoseconds (for accurate reporting)
     last_precompile_nanos: Option<u64>,
+    /// Interpreter, interpreter flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl PythonRuntime {
     pub fn new() -> Result<Self> {
-        let (python_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Python)
+        Self::with_toolchain(VariantToolchain::default())
+    }
+
+    /// Create a Python runtime that runs scripts with a runtime variant's interpreter
+    pub fn with_toolchain(toolchain: VariantToolchain) -> Result<Self> {
+        let (python_binary, _) = toolchain.resolve_binary(Lang::Python)
             .map_err(|_| miette!("Python not found. Install via 'poly-bench add-runtime python' or ensure python3 is in PATH"))?;
 
         Ok(Self {
@@ -35,6 +45,7 @@ impl PythonRuntime {
             anvil_rpc_url: None,
             cached_script: None,
             last_precompile_nanos: None,
+            toolchain,
         })
     }
 
@@ -42,8 +53,9 @@ impl PythonRuntime {
         self.project_root = path;
 
         // When using poly-bench runtime-env, prefer the venv's Python so we use the
-        // same interpreter that has the installed dependencies (from pip install).
-        if let Some(ref project_root) = self.project_root {
+        // same interpreter that has the installed dependencies (from pip install). A variant's
+        // explicit interpreter always wins.
+        if let (Some(ref project_root), None) = (&self.project_root, &self.toolchain.binary) {
             let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
             if is_runtime_env {
                 let venv_python = project_root.join(".venv").join("bin").join("python");
@@ -73,21 +85,22 @@ impl PythonRuntime {
         script: &str,
         source_hash: u64,
     ) -> Result<(PathBuf, PathBuf)> {
+        let script_name = self.toolchain.artifact_name("bench.py");
         let (script_path, working_dir) = if let Some(ref project_root) = self.project_root {
             let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
             let script_path = if is_runtime_env {
-                project_root.join("bench.py")
+                project_root.join(&script_name)
             } else {
                 let bench_dir = project_root.join(".polybench");
                 std::fs::create_dir_all(&bench_dir)
                     .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
-                bench_dir.join("bench.py")
+                bench_dir.join(&script_name)
             };
             (script_path, project_root.clone())
         } else {
             let temp_dir =
                 self.temp_dir.as_ref().ok_or_else(|| miette!("Runtime not initialized"))?;
-            let script_path = temp_dir.path().join("bench.py");
+            let script_path = temp_dir.path().join(&script_name);
             (script_path, temp_dir.path().to_path_buf())
         };
 
@@ -119,7 +132,7 @@ impl RuntimeFactory for PythonRuntimeFactory {
         "Python Runtime"
     }
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
-        let mut rt = PythonRuntime::new()?;
+        let mut rt = PythonRuntime::with_toolchain(config.toolchain())?;
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::Python));
         Ok(Box::new(rt))
     }
@@ -195,8 +208,10 @@ impl Runtime for PythonRuntime {
         })?;
 
         let output = tokio::process::Command::new(&self.python_binary)
+            .args(&self.toolchain.flags)
             .args(["-m", "py_compile", script_path.to_str().unwrap()])
             .current_dir(&working_dir)
+            .envs(&self.toolchain.env)
             .output()
             .await
             .map_err(|e| miette!("Failed to run Python check: {}", e))?;
@@ -226,8 +241,10 @@ impl Runtime for PythonRuntime {
         // Compile to bytecode (.pyc) - this is what Python does on first import
         // Using py_compile ensures syntax is valid and generates cached bytecode
         let output = tokio::process::Command::new(&self.python_binary)
+            .args(&self.toolchain.flags)
             .args(["-m", "py_compile", script_path.to_str().unwrap()])
             .current_dir(&working_dir)
+            .envs(&self.toolchain.env)
             .output()
             .await
             .map_err(|e| miette!("Failed to run Python bytecode compilation: {}", e))?;
@@ -261,7 +278,11 @@ impl Runtime for PythonRuntime {
             };
 
         let mut cmd = tokio::process::Command::new(&self.python_binary);
-        cmd.arg(&script_path).current_dir(&working_dir);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
+        cmd.args(&self.toolchain.flags)
+            .arg(&script_path)
+            .current_dir(&working_dir)
+            .envs(&self.toolchain.env);
 
         if let Some(ref url) = self.anvil_rpc_url {
             cmd.env("ANVIL_RPC_URL", url);
@@ -307,7 +328,7 @@ impl Runtime for PythonRuntime {
 
         let run_wall_nanos = run_start.elapsed().as_nanos() as u64;
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut m = parse_benchmark_result(&stdout, spec.outlier_detection, spec.cv_threshold)?;
+        let mut m = parse_benchmark_result(&stdout, spec)?;
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -386,6 +407,7 @@ fn generate_python_check_source(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result
         }
     }
 
+    script.push_str(&param_declarations(&spec.params, Lang::Python, ""));
     script.push_str(&normalize_python_indent(impl_code));
     script.push('\n');
 
@@ -424,6 +446,11 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
 
     if use_sink {
         script.push_str("__polybench_sink = None\n\n");
+        // Bytes serialize as integer arrays so they compare equal to other languages' bytes
+        script.push_str("def __polybench_json_default(o):\n");
+        script.push_str("    if isinstance(o, (bytes, bytearray, memoryview)):\n");
+        script.push_str("        return list(bytes(o))\n");
+        script.push_str("    return str(o)\n\n");
     }
 
     if let Some(user_imports) = suite.imports.get(&Lang::Python) {
@@ -467,6 +494,11 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         if let Some(fixture) = suite.get_fixture(fixture_name) {
             if let Some(fixture_impl) = fixture.implementations.get(&Lang::Python) {
                 script.push_str(&format!("{} = {}\n", fixture_name, fixture_impl));
+            } else if let Some(path) = fixture.external_file_literal(Lang::Python) {
+                script.push_str(&format!(
+                    "with open({}, \"rb\") as __polybench_fixture_file:\n    {} = __polybench_fixture_file.read()\n",
+                    path, fixture_name
+                ));
             } else if !fixture.data.is_empty() {
                 let bytes: Vec<String> =
                     fixture.data.iter().map(|b| format!("0x{:02x}", b)).collect();
@@ -474,6 +506,7 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
             }
         }
     }
+    script.push_str(&param_declarations(&spec.params, Lang::Python, ""));
     script.push('\n');
 
     let use_auto_mode = spec.mode == BenchMode::Auto;
@@ -511,12 +544,6 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
     } else {
         script.push_str("def __polybench_run():\n");
     }
-    if use_memory {
-        // tracemalloc tracks current memory, not cumulative allocations
-        // We'll track peak memory per batch to estimate allocations
-        script.push_str("    tracemalloc.start()\n");
-        script.push_str("    total_allocated = 0\n");
-    }
     script.push_str("    samples = []\n");
     script.push_str("    warmup_nanos = 0\n\n");
     // Warmup (warmup_time_ms takes precedence over warmup_iterations)
@@ -570,9 +597,6 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
     let is_fixed_cap = matches!(spec.async_sampling_policy, AsyncSamplingPolicy::FixedCap);
 
     if is_async {
-        if use_memory {
-            script.push_str("    total_allocated = 0\n");
-        }
         script.push_str(&format!("    target_ns = {:.0}\n", target_nanos));
         script.push_str(&format!("    sample_cap = {}\n", sample_cap));
         script.push_str("    total_iterations = 0\n");
@@ -599,7 +623,7 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         if use_sink {
             script.push_str("            if len(successful_results) < sample_cap:\n");
             script.push_str("                try:\n");
-            script.push_str("                    r = json.dumps(__polybench_sink, default=str)\n");
+            script.push_str("                    r = json.dumps(__polybench_sink, default=__polybench_json_default)\n");
             script.push_str("                    if r != 'null': successful_results.append(r)\n");
             script.push_str("                except: pass\n");
         }
@@ -627,10 +651,6 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         script.push_str("    total_ns = 0.0\n");
         script.push_str("    samples = []\n");
         script.push_str("    while total_ns < target_ns:\n");
-        if use_memory {
-            // Reset tracemalloc peak before each batch to track allocations
-            script.push_str("        tracemalloc.reset_peak()\n");
-        }
         script.push_str("        batch_start = time.perf_counter_ns()\n");
         script.push_str("        for _ in range(batch_size):\n");
         script.push_str(&each_hook_code_inner);
@@ -640,11 +660,6 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
             script.push_str("            __polybench_bench()\n");
         }
         script.push_str("        batch_elapsed = time.perf_counter_ns() - batch_start\n");
-        if use_memory {
-            // Add peak memory from this batch to total (approximates cumulative allocations)
-            script.push_str("        _, batch_peak = tracemalloc.get_traced_memory()\n");
-            script.push_str("        total_allocated += batch_peak\n");
-        }
         script.push_str("        total_iterations += batch_size\n");
         script.push_str("        total_ns += batch_elapsed\n");
         script.push_str("        samples.append(batch_elapsed / batch_size)\n");
@@ -670,9 +685,6 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         script.push_str("    iterations = total_iterations\n");
     } else {
         script.push_str(&format!("    for _ in range({}):\n", iterations));
-        if use_memory {
-            script.push_str("        tracemalloc.reset_peak()\n");
-        }
         script.push_str("        start = time.perf_counter_ns()\n");
         script.push_str(&each_hook_code);
         if use_sink {
@@ -681,18 +693,44 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
             script.push_str("        __polybench_bench()\n");
         }
         script.push_str("        samples.append(time.perf_counter_ns() - start)\n");
-        if use_memory {
-            script.push_str("        _, iter_peak = tracemalloc.get_traced_memory()\n");
-            script.push_str("        total_allocated += iter_peak\n");
-        }
         script.push_str("    total_nanos = sum(samples)\n");
         script.push_str(&format!("    iterations = {}\n", iterations));
     }
 
     if use_memory {
+        // tracemalloc slows every allocation, so memory is measured in a separate pass after
+        // timing: the peak traced bytes above the pre-call baseline, averaged over the pass
+        script.push_str("\n    gc.collect()\n");
+        script.push_str("    tracemalloc.start()\n");
+        script.push_str(&format!("    mem_ops = max(1, min(iterations, {}))\n", MEMORY_PASS_OPS));
+        script.push_str("    total_allocated = 0\n");
+        script.push_str("    for _ in range(mem_ops):\n");
+        script.push_str(&each_hook_code);
+        script.push_str("        mem_before, _ = tracemalloc.get_traced_memory()\n");
+        script.push_str("        tracemalloc.reset_peak()\n");
+        if is_async {
+            script.push_str("        __polybench_mem_sink = await __polybench_bench()\n");
+        } else {
+            script.push_str("        __polybench_mem_sink = __polybench_bench()\n");
+        }
+        script.push_str("        _, mem_peak = tracemalloc.get_traced_memory()\n");
+        script.push_str("        total_allocated += max(0, mem_peak - mem_before)\n");
+        script.push_str("        del __polybench_mem_sink\n");
         script.push_str("    tracemalloc.stop()\n");
     }
 
+    // Validation: one more call outside the timed region, with its value bound to `result`
+    let validation = spec.validations.get(&Lang::Python);
+    if let Some(check) = validation {
+        script.push_str("\n    # Validation\n");
+        if is_async {
+            script.push_str("    result = await __polybench_bench()\n");
+        } else {
+            script.push_str("    result = __polybench_bench()\n");
+        }
+        script.push_str(&format!("    validation_passed = bool({})\n", check.trim()));
+    }
+
     if let Some(after) = after_hook {
         script.push_str("\n    # After hook\n");
         for line in after.trim().lines() {
@@ -713,11 +751,13 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
     script.push_str("        \"opsPerSec\": ops_per_sec,\n");
     script.push_str("        \"samples\": samples\n");
     if use_memory {
-        // total_allocated is cumulative peak memory from all batches/iterations
-        script.push_str("        , \"bytesPerOp\": int(total_allocated / iterations)\n");
+        script.push_str("        , \"bytesPerOp\": int(total_allocated / mem_ops)\n");
     }
     if use_sink {
-        script.push_str("        , \"rawResult\": json.dumps(__polybench_sink, default=str)\n");
+        script.push_str("        , \"rawResult\": json.dumps(__polybench_sink, default=__polybench_json_default)\n");
+    }
+    if validation.is_some() {
+        script.push_str("        , \"validationPassed\": validation_passed\n");
     }
     if is_async {
         script.push_str("        , \"successfulCount\": successful_count\n");
@@ -761,6 +801,8 @@ struct BenchResultJson {
     successful_results: Vec<String>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -784,7 +826,7 @@ impl BenchResultJson {
         };
 
         if let Some(bytes) = self.bytes_per_op {
-            m = m.with_allocs(bytes, self.allocs_per_op.unwrap_or(0));
+            m = m.with_memory(bytes, self.allocs_per_op);
         }
         if let Some(raw) = self.raw_result {
             m.raw_result = Some(raw);
@@ -806,11 +848,7 @@ impl BenchResultJson {
     }
 }
 
-fn parse_benchmark_result(
-    stdout: &str,
-    outlier_detection: bool,
-    cv_threshold: f64,
-) -> Result<Measurement> {
+fn parse_benchmark_result(stdout: &str, spec: &BenchmarkSpec) -> Result<Measurement> {
     let json_line = stdout
         .lines()
         .filter(|l| !l.trim().is_empty())
@@ -820,5 +858,51 @@ fn parse_benchmark_result(
     let result: BenchResultJson = serde_json::from_str(json_line)
         .map_err(|e| miette!("Failed to parse benchmark result: {}\nOutput: {}", e, stdout))?;
 
-    Ok(result.into_measurement_with_options(outlier_detection, cv_threshold))
+    let validation_passed = result.validation_passed;
+    let mut m = result.into_measurement_with_options(spec.outlier_detection, spec.cv_threshold);
+    m.record_validation(spec.validations.get(&Lang::Python), validation_passed);
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_spec() -> (BenchmarkSpec, SuiteIR) {
+        let suite = SuiteIR::new("snap".to_string());
+        let mut spec = BenchmarkSpec::new("alloc".to_string(), "snap", 10, 5, 0);
+        spec.implementations.insert(Lang::Python, "[0] * 64".to_string());
+        spec.memory = true;
+        (spec, suite)
+    }
+
+    #[test]
+    fn test_memory_pass_traces_peak_bytes_after_timing() {
+        let (spec, suite) = memory_spec();
+        let script = generate_standalone_script(&spec, &suite).unwrap();
+        let timing = script.find("total_nanos = ").unwrap();
+        let tracing = script.find("tracemalloc.start()").unwrap();
+        assert!(timing < tracing);
+        assert!(script.contains(&format!("min(iterations, {})", MEMORY_PASS_OPS)));
+        assert!(script.contains("\"bytesPerOp\": int(total_allocated / mem_ops)"));
+        assert!(!script.contains("allocsPerOp"));
+    }
+
+    #[test]
+    fn test_no_memory_pass_without_memory() {
+        let (mut spec, suite) = memory_spec();
+        spec.memory = false;
+        let script = generate_standalone_script(&spec, &suite).unwrap();
+        assert!(!script.contains("tracemalloc.start()"));
+        assert!(!script.contains("bytesPerOp"));
+    }
+
+    #[test]
+    fn test_parse_memory_result_has_no_alloc_count() {
+        let (spec, _) = memory_spec();
+        let stdout = r#"{"iterations": 10, "totalNanos": 1000.0, "warmupNanos": 0.0, "nanosPerOp": 100.0, "opsPerSec": 1e7, "samples": [], "bytesPerOp": 576}"#;
+        let m = parse_benchmark_result(stdout, &spec).unwrap();
+        assert_eq!(m.bytes_per_op, Some(576));
+        assert_eq!(m.allocs_per_op, None);
+    }
 }
@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static PYTHON_BUILTINS: &[&str] = &[
     "print",
     "len",
@@ -55,6 +56,22 @@ static PYTHON_BUILTINS: &[&str] = &[
     "staticmethod",
     "classmethod",
     "property",
+    "if",
+    "elif",
+    "while",
+    "for",
+    "in",
+    "not",
+    "and",
+    "or",
+    "is",
+    "return",
+    "assert",
+    "del",
+    "yield",
+    "await",
+    "with",
+    "except",
 ];
 
 pub(crate) struct PythonHelperFunctionExtractor;
@@ -38,6 +38,10 @@ impl RuntimePlugin for PythonPlugin {
         python_lang_display()
     }
 
+    fn memory_metric_note(&self) -> Option<&'static str> {
+        Some("peak traced bytes per call (tracemalloc, at most 1000 calls after timing); no allocation counts")
+    }
+
     fn project_root_detector(&self) -> Option<&'static dyn ProjectRootDetector> {
         Some(&PYTHON_DETECTOR)
     }
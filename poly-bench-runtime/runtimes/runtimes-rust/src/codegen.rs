@@ -2,7 +2,7 @@
 
 use miette::{miette, Result};
 use poly_bench_dsl::{BenchMode, Lang};
-use poly_bench_ir::{BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
 use poly_bench_stdlib as stdlib;
 
 use super::shared::{
@@ -145,11 +145,20 @@ fn generate_fixture(code: &mut String, fixture: &FixtureIR) -> Result<()> {
         if let Some(ref desc) = fixture.description {
             code.push_str(&format!("// {}\n", desc));
         }
-        code.push_str(&format!(
-            "const {}: &[u8] = &[{}];\n\n",
-            fixture.name.to_uppercase(),
-            fixture.as_rust_bytes()
-        ));
+        match fixture.external_file_literal(Lang::Rust) {
+            // Embedded from the shared file at compile time: still a `&[u8]` constant, without a
+            // multi-megabyte literal in the source
+            Some(path) => code.push_str(&format!(
+                "const {}: &[u8] = include_bytes!({});\n\n",
+                fixture.name.to_uppercase(),
+                path
+            )),
+            None => code.push_str(&format!(
+                "const {}: &[u8] = &[{}];\n\n",
+                fixture.name.to_uppercase(),
+                fixture.as_rust_bytes()
+            )),
+        }
     }
     Ok(())
 }
@@ -172,11 +181,12 @@ fn generate_benchmark(code: &mut String, bench: &BenchmarkSpec, _suite: &SuiteIR
     let bench_call = generate_bench_call(impl_code, bench.use_sink);
 
     // Generate hook code
-    let before_hook = bench
-        .before_hooks
-        .get(&Lang::Rust)
-        .map(|h| format!("    // Before hook\n    {}\n\n", h.trim()))
-        .unwrap_or_default();
+    let before_hook = param_declarations(&bench.params, Lang::Rust, "    ") +
+        &bench
+            .before_hooks
+            .get(&Lang::Rust)
+            .map(|h| format!("    // Before hook\n    {}\n\n", h.trim()))
+            .unwrap_or_default();
     let after_hook = bench
         .after_hooks
         .get(&Lang::Rust)
@@ -184,8 +194,9 @@ fn generate_benchmark(code: &mut String, bench: &BenchmarkSpec, _suite: &SuiteIR
         .unwrap_or_default();
     let each_hook = bench.each_hooks.get(&Lang::Rust);
 
-    // Memory result fields
-    let memory_result = SinkMemoryDecls::memory_result_fields(bench.memory);
+    // Memory result fields (hardware counters are only collected by the standalone executor)
+    let memory_result =
+        SinkMemoryDecls::memory_result_fields(bench.memory) + "        counters: None,\n";
 
     match bench.mode {
         BenchMode::Auto => {
@@ -4,13 +4,18 @@ use crate::error_mapping::RustErrorMapper;
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{BenchMode, BenchmarkKind, Lang, SuiteType};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{ErrorMapper, Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{
+    ArtifactCache, ArtifactKey, CounterTotals, ErrorMapper, Measurement, Runtime, RuntimeConfig,
+    RuntimeFactory, VariantToolchain,
+};
+use regex::Regex;
 use std::{
     collections::HashSet,
     path::{Path, PathBuf},
     process::Stdio,
+    sync::LazyLock,
     time::Instant,
 };
 
@@ -64,9 +69,25 @@ fn cleanup_lsp_virtual_files(project_root: &Path) {
 
 use super::shared::{
     self, generate_bench_call, generate_fixtures_for_spec, generate_init_code, generate_suite_code,
-    SinkMemoryDecls, BENCH_RESULT_STRUCT,
+    CounterDecls, SinkMemoryDecls, BENCH_RESULT_STRUCT,
 };
 
+/// Extensions of path-dependency crate sources the harness build compiles in
+pub const SOURCE_EXTENSIONS: &[&str] = &["rs", "toml"];
+
+/// Environment read by cargo, rustc and build scripts, keyed into cached binaries
+const BUILD_ENV: &[&str] = &[
+    "RUSTFLAGS",
+    "RUSTC",
+    "RUSTC_WRAPPER",
+    "RUSTUP_TOOLCHAIN",
+    "CARGO_*",
+    "CC",
+    "CFLAGS",
+    "CXX",
+    "CXXFLAGS",
+];
+
 /// Rust runtime using cargo subprocess
 pub struct RustRuntime {
     /// Rust project root directory (where Cargo.toml exists)
@@ -77,6 +98,10 @@ pub struct RustRuntime {
     cached_binary: Option<(PathBuf, u64)>,
     /// Duration of last precompile in nanoseconds (for accurate reporting)
     last_precompile_nanos: Option<u64>,
+    /// Persistent cache of built binaries shared across runs
+    artifacts: ArtifactCache,
+    /// Toolchain, RUSTFLAGS and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl RustRuntime {
@@ -86,6 +111,8 @@ impl RustRuntime {
             anvil_rpc_url: None,
             cached_binary: None,
             last_precompile_nanos: None,
+            artifacts: ArtifactCache::from_env(),
+            toolchain: VariantToolchain::default(),
         }
     }
 
@@ -121,6 +148,7 @@ impl RuntimeFactory for RustRuntimeFactory {
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
         let mut rt = RustRuntime::new();
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::Rust));
+        rt.toolchain = config.toolchain();
         Ok(Box::new(rt))
     }
 }
@@ -141,7 +169,7 @@ impl Runtime for RustRuntime {
 
     async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
         // Verify rustc/cargo is available
-        poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Rust)
+        self.toolchain.resolve_binary(Lang::Rust)
             .map_err(|_| miette!("Cargo not found. Install via 'poly-bench add-runtime rust' or ensure cargo is in PATH"))?;
         Ok(())
     }
@@ -273,22 +301,20 @@ impl Runtime for RustRuntime {
             (temp_dir, true)
         };
 
-        let src_dir = work_dir.join("src");
-        std::fs::create_dir_all(&src_dir)
-            .map_err(|e| miette!("Failed to create src directory: {}", e))?;
-
-        // Write source as main.rs
-        let main_path = src_dir.join("main.rs");
+        let main_path = self.source_path(&work_dir);
+        if let Some(src_dir) = main_path.parent() {
+            std::fs::create_dir_all(src_dir)
+                .map_err(|e| miette!("Failed to create src directory: {}", e))?;
+        }
         std::fs::write(&main_path, &source)
-            .map_err(|e| miette!("Failed to write main.rs: {}", e))?;
+            .map_err(|e| miette!("Failed to write {}: {}", main_path.display(), e))?;
 
-        let (cargo_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Rust)
+        let (cargo_binary, _) = self.toolchain.resolve_binary(Lang::Rust)
             .map_err(|_| miette!("Cargo not found. Install via 'poly-bench add-runtime rust' or ensure cargo is in PATH"))?;
 
         // Use 'cargo check' for fast compilation checking without codegen
-        let output = tokio::process::Command::new(&cargo_binary)
-            .args(["check", "--release", "--quiet"])
-            .current_dir(&work_dir)
+        let output = self
+            .cargo_command(&cargo_binary, "check", &work_dir)
             .output()
             .await
             .map_err(|e| miette!("Failed to compile Rust benchmark: {}", e))?;
@@ -323,109 +349,7 @@ impl Runtime for RustRuntime {
 
         let pc_start = Instant::now();
 
-        // Need to compile - set up directories and source
-        let (src_path, working_dir) = if let Some(ref project_root) = self.project_root {
-            let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
-            let is_polybench_project =
-                is_runtime_env || project_root.join("polybench.toml").exists();
-
-            // Clean up any LSP virtual files that might interfere with cargo build
-            if is_runtime_env {
-                cleanup_lsp_virtual_files(project_root);
-            }
-
-            if is_runtime_env {
-                let src_path = project_root.join("src").join("main.rs");
-                // Ensure src directory exists
-                if let Some(parent) = src_path.parent() {
-                    std::fs::create_dir_all(parent)
-                        .map_err(|e| miette!("Failed to create src directory: {}", e))?;
-                }
-                // Runtime-env Cargo.toml may not have alloc_tracker; add it for memory suites
-                let cargo_path = project_root.join("Cargo.toml");
-                if cargo_path.exists() {
-                    ensure_alloc_tracker_in_cargo_toml(&cargo_path, spec)?;
-                }
-                (src_path, project_root.clone())
-            } else if is_polybench_project {
-                let bench_dir = project_root.join(".polybench").join("runtime-env").join("rust");
-                std::fs::create_dir_all(&bench_dir).map_err(|e| {
-                    miette!("Failed to create .polybench/runtime-env/rust directory: {}", e)
-                })?;
-                let src_path = bench_dir.join("src").join("main.rs");
-                // Ensure src directory exists
-                if let Some(parent) = src_path.parent() {
-                    std::fs::create_dir_all(parent)
-                        .map_err(|e| miette!("Failed to create src directory: {}", e))?;
-                }
-                // Ensure Cargo.toml exists (create minimal one if missing)
-                let cargo_path = bench_dir.join("Cargo.toml");
-                if !cargo_path.exists() {
-                    let cargo_toml = generate_cargo_toml(suite);
-                    std::fs::write(&cargo_path, cargo_toml)
-                        .map_err(|e| miette!("Failed to write Cargo.toml: {}", e))?;
-                }
-                (src_path, bench_dir)
-            } else {
-                // Not a poly-bench project: use temp directory
-                let temp_dir = std::env::temp_dir().join("polybench-rust");
-                std::fs::create_dir_all(&temp_dir)
-                    .map_err(|e| miette!("Failed to create temp directory: {}", e))?;
-                let src_dir = temp_dir.join("src");
-                std::fs::create_dir_all(&src_dir)
-                    .map_err(|e| miette!("Failed to create src directory: {}", e))?;
-                let cargo_toml = generate_minimal_cargo_toml(spec);
-                std::fs::write(temp_dir.join("Cargo.toml"), cargo_toml)
-                    .map_err(|e| miette!("Failed to write Cargo.toml: {}", e))?;
-                (src_dir.join("main.rs"), temp_dir)
-            }
-        } else {
-            // Create temp directory for standalone execution
-            let temp_dir = std::env::temp_dir().join("polybench-rust");
-            std::fs::create_dir_all(&temp_dir)
-                .map_err(|e| miette!("Failed to create temp directory: {}", e))?;
-
-            let src_dir = temp_dir.join("src");
-            std::fs::create_dir_all(&src_dir)
-                .map_err(|e| miette!("Failed to create src directory: {}", e))?;
-
-            // Create minimal Cargo.toml
-            let cargo_toml = generate_minimal_cargo_toml(spec);
-            std::fs::write(temp_dir.join("Cargo.toml"), cargo_toml)
-                .map_err(|e| miette!("Failed to write Cargo.toml: {}", e))?;
-
-            (src_dir.join("main.rs"), temp_dir)
-        };
-
-        std::fs::write(&src_path, &source)
-            .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
-
-        let (cargo_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Rust)
-            .map_err(|_| miette!("Cargo not found. Install via 'poly-bench add-runtime rust' or ensure cargo is in PATH"))?;
-
-        // Build the binary
-        let build_output = tokio::process::Command::new(&cargo_binary)
-            .args(["build", "--release", "--quiet"])
-            .current_dir(&working_dir)
-            .output()
-            .await
-            .map_err(|e| miette!("Failed to build Rust benchmark: {}", e))?;
-
-        if !build_output.status.success() {
-            let stderr = String::from_utf8_lossy(&build_output.stderr);
-            return Err(miette!("Rust benchmark build failed:\n{}", stderr));
-        }
-
-        // Binary name matches the package name in Cargo.toml
-        let binary_name = "polybench_runner";
-        let binary_path = working_dir.join("target").join("release").join(binary_name);
-
-        if !binary_path.exists() {
-            return Err(miette!(
-                "Compiled binary not found at expected path: {}",
-                binary_path.display()
-            ));
-        }
+        let binary_path = self.build_binary(spec, suite, &source).await?;
 
         // Cache the binary path and source hash for reuse
         self.cached_binary = Some((binary_path, source_hash));
@@ -477,7 +401,78 @@ impl RustRuntime {
             }
         }
 
-        // Need to compile - set up directories and source
+        let binary_path = self.build_binary(spec, suite, &source).await?;
+
+        // Cache the binary path and source hash for reuse
+        self.cached_binary = Some((binary_path.clone(), source_hash));
+
+        // Run the binary
+        self.run_binary(&binary_path, spec).await
+    }
+
+    /// Cargo invocation for `subcommand` in release mode, limited to this runtime's binary.
+    /// Variants build into their own target directory, with their flags appended to
+    /// `RUSTFLAGS`.
+    fn cargo_command(
+        &self,
+        cargo_binary: &Path,
+        subcommand: &str,
+        working_dir: &Path,
+    ) -> tokio::process::Command {
+        let mut cmd = tokio::process::Command::new(cargo_binary);
+        cmd.args([subcommand, "--release", "--quiet", "--bin"])
+            .arg(self.bin_name())
+            .current_dir(working_dir)
+            .envs(&self.toolchain.env);
+        if !self.toolchain.name.is_empty() {
+            cmd.arg("--target-dir").arg(self.target_dir(working_dir));
+        }
+        if !self.toolchain.flags.is_empty() {
+            let mut rustflags = self.toolchain.env.get("RUSTFLAGS").cloned().unwrap_or_default();
+            for flag in &self.toolchain.flags {
+                if !rustflags.is_empty() {
+                    rustflags.push(' ');
+                }
+                rustflags.push_str(flag);
+            }
+            cmd.env("RUSTFLAGS", rustflags);
+        }
+        cmd
+    }
+
+    fn target_dir(&self, working_dir: &Path) -> PathBuf {
+        working_dir.join(self.toolchain.artifact_name("target"))
+    }
+
+    /// Binary target for the harness: the package's `src/main.rs` binary for the base language,
+    /// `src/bin/polybench_runner-<variant>.rs` for a variant
+    fn bin_name(&self) -> String {
+        self.toolchain.artifact_name("polybench_runner")
+    }
+
+    /// Harness source path in `working_dir`. Variants get their own binary target so parallel
+    /// workers sharing a crate never build each other's source.
+    fn source_path(&self, working_dir: &Path) -> PathBuf {
+        if self.toolchain.name.is_empty() {
+            working_dir.join("src").join("main.rs")
+        } else {
+            working_dir.join("src").join("bin").join(format!("{}.rs", self.bin_name()))
+        }
+    }
+
+    /// Compiled benchmark binary
+    fn release_binary(&self, working_dir: &Path) -> PathBuf {
+        self.target_dir(working_dir).join("release").join(self.bin_name())
+    }
+
+    /// Write the harness source and build it, reusing a cached binary built from the same
+    /// inputs
+    async fn build_binary(
+        &self,
+        spec: &BenchmarkSpec,
+        suite: &SuiteIR,
+        source: &str,
+    ) -> Result<PathBuf> {
         let (src_path, working_dir) = if let Some(ref project_root) = self.project_root {
             let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
             let is_polybench_project =
@@ -489,7 +484,7 @@ impl RustRuntime {
             }
 
             if is_runtime_env {
-                let src_path = project_root.join("src").join("main.rs");
+                let src_path = self.source_path(project_root);
                 // Ensure src directory exists
                 if let Some(parent) = src_path.parent() {
                     std::fs::create_dir_all(parent)
@@ -506,7 +501,7 @@ impl RustRuntime {
                 std::fs::create_dir_all(&bench_dir).map_err(|e| {
                     miette!("Failed to create .polybench/runtime-env/rust directory: {}", e)
                 })?;
-                let src_path = bench_dir.join("src").join("main.rs");
+                let src_path = self.source_path(&bench_dir);
                 // Ensure src directory exists
                 if let Some(parent) = src_path.parent() {
                     std::fs::create_dir_all(parent)
@@ -523,44 +518,57 @@ impl RustRuntime {
             } else {
                 // Not a poly-bench project: use temp directory
                 let temp_dir = std::env::temp_dir().join("polybench-rust");
-                std::fs::create_dir_all(&temp_dir)
-                    .map_err(|e| miette!("Failed to create temp directory: {}", e))?;
-                let src_dir = temp_dir.join("src");
-                std::fs::create_dir_all(&src_dir)
-                    .map_err(|e| miette!("Failed to create src directory: {}", e))?;
+                let src_path = self.source_path(&temp_dir);
+                if let Some(parent) = src_path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .map_err(|e| miette!("Failed to create src directory: {}", e))?;
+                }
                 let cargo_toml = generate_minimal_cargo_toml(spec);
                 std::fs::write(temp_dir.join("Cargo.toml"), cargo_toml)
                     .map_err(|e| miette!("Failed to write Cargo.toml: {}", e))?;
-                (src_dir.join("main.rs"), temp_dir)
+                (src_path, temp_dir)
             }
         } else {
             // Create temp directory for standalone execution
             let temp_dir = std::env::temp_dir().join("polybench-rust");
-            std::fs::create_dir_all(&temp_dir)
-                .map_err(|e| miette!("Failed to create temp directory: {}", e))?;
-
-            let src_dir = temp_dir.join("src");
-            std::fs::create_dir_all(&src_dir)
-                .map_err(|e| miette!("Failed to create src directory: {}", e))?;
+            let src_path = self.source_path(&temp_dir);
+            if let Some(parent) = src_path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| miette!("Failed to create src directory: {}", e))?;
+            }
 
             // Create minimal Cargo.toml
             let cargo_toml = generate_minimal_cargo_toml(spec);
             std::fs::write(temp_dir.join("Cargo.toml"), cargo_toml)
                 .map_err(|e| miette!("Failed to write Cargo.toml: {}", e))?;
 
-            (src_dir.join("main.rs"), temp_dir)
+            (src_path, temp_dir)
         };
 
-        std::fs::write(&src_path, &source)
+        std::fs::write(&src_path, source)
             .map_err(|e| miette!("Failed to write benchmark source: {}", e))?;
 
-        let (cargo_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Rust)
+        let (cargo_binary, _) = self.toolchain.resolve_binary(Lang::Rust)
             .map_err(|_| miette!("Cargo not found. Install via 'poly-bench add-runtime rust' or ensure cargo is in PATH"))?;
 
-        // Build the binary (separate from running)
-        let build_output = tokio::process::Command::new(&cargo_binary)
-            .args(["build", "--release", "--quiet"])
-            .current_dir(&working_dir)
+        let artifact_key = || {
+            let key = ArtifactKey::new(Lang::Rust, source)
+                .toolchain(&cargo_binary, &["--version"])
+                .variant(&self.toolchain)
+                .ambient_env(BUILD_ENV)
+                .file(&working_dir.join("Cargo.toml"))
+                .file(&working_dir.join("Cargo.lock"));
+            path_dependencies(&working_dir)
+                .iter()
+                .fold(key, |key, dir| key.source_tree(dir, SOURCE_EXTENSIONS))
+        };
+        if let Some(cached) = self.artifacts.lookup(&artifact_key(), "polybench_runner") {
+            return Ok(cached);
+        }
+
+        // Build the binary
+        let build_output = self
+            .cargo_command(&cargo_binary, "build", &working_dir)
             .output()
             .await
             .map_err(|e| miette!("Failed to build Rust benchmark: {}", e))?;
@@ -570,9 +578,7 @@ impl RustRuntime {
             return Err(miette!("Rust benchmark build failed:\n{}", stderr));
         }
 
-        // Binary name matches the package name in Cargo.toml
-        let binary_name = "polybench_runner";
-        let binary_path = working_dir.join("target").join("release").join(binary_name);
+        let binary_path = self.release_binary(&working_dir);
 
         if !binary_path.exists() {
             return Err(miette!(
@@ -581,16 +587,15 @@ impl RustRuntime {
             ));
         }
 
-        // Cache the binary path and source hash for reuse
-        self.cached_binary = Some((binary_path.clone(), source_hash));
-
-        // Run the binary
-        self.run_binary(&binary_path, spec).await
+        // Keyed after the build, which writes Cargo.lock on first use
+        Ok(self.artifacts.store(&artifact_key(), &binary_path).unwrap_or(binary_path))
     }
 
     /// Run a pre-compiled binary and parse the result
     async fn run_binary(&self, binary_path: &PathBuf, spec: &BenchmarkSpec) -> Result<Measurement> {
         let mut cmd = tokio::process::Command::new(binary_path);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
+        cmd.envs(&self.toolchain.env);
 
         if let Some(ref url) = self.anvil_rpc_url {
             cmd.env("ANVIL_RPC_URL", url);
@@ -628,7 +633,9 @@ impl RustRuntime {
         let stdout = String::from_utf8_lossy(&output.stdout);
         let result: BenchResultJson = serde_json::from_str(&stdout)
             .map_err(|e| miette!("Failed to parse benchmark result: {}\nOutput: {}", e, stdout))?;
+        let validation_passed = result.validation_passed;
         let mut m = result.into_measurement_with_options(spec.outlier_detection, spec.cv_threshold);
+        m.record_validation(spec.validations.get(&Lang::Rust), validation_passed);
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -640,6 +647,32 @@ impl RustRuntime {
     }
 }
 
+/// Crates reachable through `path = "…"` dependencies of the crate at `crate_dir`, followed
+/// transitively
+pub fn path_dependencies(crate_dir: &Path) -> Vec<PathBuf> {
+    static PATH_DEP: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"\bpath\s*=\s*"([^"]+)""#).unwrap());
+
+    let mut found: Vec<PathBuf> = Vec::new();
+    let mut pending = vec![crate_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(manifest) = std::fs::read_to_string(dir.join("Cargo.toml")) else {
+            continue;
+        };
+        for capture in PATH_DEP.captures_iter(&manifest) {
+            let Ok(dep) = dir.join(&capture[1]).canonicalize() else {
+                continue;
+            };
+            if dep.join("Cargo.toml").is_file() && !found.contains(&dep) {
+                found.push(dep.clone());
+                pending.push(dep);
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
 /// JSON format for benchmark results from Rust
 #[derive(Debug, serde::Deserialize)]
 struct BenchResultJson {
@@ -665,6 +698,10 @@ struct BenchResultJson {
     error_count: Option<u64>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    counters: Option<CounterTotals>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -708,6 +745,7 @@ impl BenchResultJson {
         if let Some(w) = self.warmup_nanos {
             m.warmup_nanos = Some(w);
         }
+        m.counters = self.counters.map(|c| c.per_op(self.iterations));
 
         m
     }
@@ -869,6 +907,14 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
     // BenchResult type
     code.push_str(BENCH_RESULT_STRUCT);
     code.push('\n');
+    if spec.counters {
+        code.push_str(shared::PERF_COUNTERS_HELPER);
+        code.push('\n');
+    }
+    if suite.uses_external_fixture(spec, Lang::Rust) {
+        code.push_str(shared::EXTERNAL_FIXTURE_HELPER);
+        code.push('\n');
+    }
 
     // Inject stdlib code
     let stdlib_code = stdlib::get_stdlib_code(&suite.stdlib_imports, &crate::RUST_STDLIB);
@@ -882,7 +928,21 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
 
     // Get shared declarations
     let decls = SinkMemoryDecls::from_spec(spec);
-    let bench_call = generate_bench_call(impl_code, spec.use_sink);
+    let counters = CounterDecls::from_spec(spec);
+    // Check if this is an async benchmark
+    let is_async = spec.kind == BenchmarkKind::Async;
+    // `equivalent: true` keeps the value of each call so the last one can be reported
+    let capture_last = spec.equivalent && !is_async;
+    let bench_call = if capture_last {
+        let value = if spec.use_sink {
+            format!("std::hint::black_box({})", impl_code)
+        } else {
+            impl_code.to_string()
+        };
+        format!("__polybench_last = Some({})", value)
+    } else {
+        generate_bench_call(impl_code, spec.use_sink)
+    };
     let before_hook = spec.before_hooks.get(&Lang::Rust);
     let after_hook = spec.after_hooks.get(&Lang::Rust);
     let each_hook = spec.each_hooks.get(&Lang::Rust);
@@ -896,10 +956,16 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
     // Fixtures
     code.push_str(&generate_fixtures_for_spec(spec, suite, Lang::Rust));
 
+    // Benchmark parameters
+    code.push_str(&param_declarations(&spec.params, Lang::Rust, "    "));
+
     // Variable declarations
     code.push_str(decls.sink_decl);
     code.push_str(decls.memory_decl);
     code.push_str("    let mut warmup_nanos: Option<u64> = None;\n");
+    if capture_last {
+        code.push_str("    let mut __polybench_last = None;\n");
+    }
 
     // Before hook
     if let Some(before) = before_hook {
@@ -911,9 +977,6 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
 
     code.push_str(decls.memory_before);
 
-    // Check if this is an async benchmark
-    let is_async = spec.kind == BenchmarkKind::Async;
-
     // Generate based on mode and async status
     match spec.mode {
         BenchMode::Auto => {
@@ -931,6 +994,7 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
                 ));
 
                 // Async loop based on policy
+                code.push_str(counters.start);
                 code.push_str(&shared::generate_async_loop_by_policy(
                     spec.async_sampling_policy,
                     &bench_call,
@@ -939,6 +1003,7 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
                     spec.target_time_ms,
                     spec.async_sample_cap,
                 ));
+                code.push_str(counters.stop);
             } else {
                 // Sync + Auto: standard auto-calibration
                 code.push_str(&shared::generate_warmup_loop(
@@ -949,12 +1014,14 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
                     spec.warmup_time_ms,
                 ));
 
+                code.push_str(counters.start);
                 code.push_str(&shared::generate_auto_mode_loop(
                     &bench_call,
                     decls.sink_keepalive,
                     each_hook,
                     spec.target_time_ms,
                 ));
+                code.push_str(counters.stop);
 
                 // No post-sample collection for sync (rely on batch aggregate, like Go testing.B)
                 code.push_str("    let samples: Vec<u64> = vec![];\n");
@@ -982,6 +1049,7 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
                 spec.warmup_time_ms,
             ));
 
+            code.push_str(counters.start);
             if is_async {
                 // Async Fixed mode: use async loop with error tracking (matching TypeScript)
                 code.push_str(&shared::generate_async_fixed_mode_loop(
@@ -1003,11 +1071,23 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
                 // Use iterations as total for result
                 code.push_str("    let total_iterations = iterations;\n");
             }
+            code.push_str(counters.stop);
         }
     }
 
     code.push_str(decls.memory_after);
 
+    // `equivalent: true` reports the value of the last timed call as JSON
+    if !is_async {
+        code.push_str(if capture_last {
+            "    let __polybench_raw_result: Option<String> = __polybench_last\n        .as_ref()\n        .and_then(|v| serde_json::to_string(v).ok())\n        .filter(|json| json != \"null\");\n"
+        } else {
+            "    let __polybench_raw_result: Option<String> = None;\n"
+        });
+    }
+
+    code.push_str(&shared::generate_validation(impl_code, spec.validations.get(&Lang::Rust)));
+
     // After hook
     if let Some(after) = after_hook {
         code.push_str("\n    // After hook\n");
@@ -1019,7 +1099,7 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
     // Result calculation and output
     // Use async result return for all async benchmarks (both Auto and Fixed modes now have error
     // tracking)
-    let memory_result = SinkMemoryDecls::memory_result_fields(spec.memory);
+    let memory_result = SinkMemoryDecls::memory_result_fields(spec.memory) + counters.result_field;
     if is_async {
         code.push_str(&shared::generate_async_result_return("total_iterations", &memory_result));
     } else {
@@ -1030,3 +1110,74 @@ static ALLOCATOR: alloc_tracker::Allocator<std::alloc::System> = alloc_tracker::
 
     Ok(code)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(equivalent: bool) -> (BenchmarkSpec, SuiteIR) {
+        let suite = SuiteIR::new("snap".to_string());
+        let mut spec = BenchmarkSpec::new("bytes".to_string(), "snap", 10, 5, 0);
+        spec.mode = BenchMode::Fixed;
+        spec.equivalent = equivalent;
+        spec.implementations.insert(Lang::Rust, "vec![1u8, 2, 3]".to_string());
+        (spec, suite)
+    }
+
+    #[test]
+    fn test_equivalent_reports_last_timed_value_as_json() {
+        let (spec, suite) = spec(true);
+        let src = generate_standalone_benchmark(&spec, &suite).unwrap();
+        assert!(src.contains("let mut __polybench_last = None;"));
+        assert!(src.contains("__polybench_last = Some(std::hint::black_box(vec![1u8, 2, 3]))"));
+        assert!(src.contains("serde_json::to_string(v)"));
+        // The implementation only runs inside the warmup and timed loops
+        assert_eq!(src.matches("vec![1u8, 2, 3]").count(), 2);
+    }
+
+    #[test]
+    fn test_no_capture_without_equivalent() {
+        let (spec, suite) = spec(false);
+        let src = generate_standalone_benchmark(&spec, &suite).unwrap();
+        assert!(!src.contains("__polybench_last"));
+        assert!(src.contains("let __polybench_raw_result: Option<String> = None;"));
+    }
+
+    #[test]
+    fn test_variants_build_their_own_binary_target() {
+        let dir = Path::new("/work");
+        let base = RustRuntime::new();
+        assert_eq!(base.source_path(dir), dir.join("src").join("main.rs"));
+        assert_eq!(base.release_binary(dir), dir.join("target/release/polybench_runner"));
+
+        let mut nightly = RustRuntime::new();
+        nightly.toolchain.name = "nightly".to_string();
+        assert_eq!(
+            nightly.source_path(dir),
+            dir.join("src").join("bin").join("polybench_runner-nightly.rs")
+        );
+        assert_eq!(
+            nightly.release_binary(dir),
+            dir.join("target-nightly/release/polybench_runner-nightly")
+        );
+    }
+
+    #[test]
+    fn test_path_dependencies_are_followed_transitively() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        for (name, manifest) in [
+            ("runner", "[dependencies]\nlib_a = { path = \"../lib_a\" }\nserde = \"1\"\n"),
+            ("lib_a", "[dependencies]\nlib_b = { path = \"../lib_b\" }\n"),
+            ("lib_b", "[package]\nname = \"lib_b\"\n"),
+        ] {
+            std::fs::create_dir_all(root.join(name)).unwrap();
+            std::fs::write(root.join(name).join("Cargo.toml"), manifest).unwrap();
+        }
+
+        assert_eq!(
+            path_dependencies(&root.join("runner")),
+            vec![root.join("lib_a"), root.join("lib_b")]
+        );
+    }
+}
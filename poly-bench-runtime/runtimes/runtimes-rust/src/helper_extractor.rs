@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static RUST_BUILTINS: &[&str] = &[
     "vec",
     "println",
@@ -30,6 +31,13 @@ static RUST_BUILTINS: &[&str] = &[
     "HashSet",
     "BTreeMap",
     "BTreeSet",
+    "if",
+    "while",
+    "for",
+    "match",
+    "return",
+    "in",
+    "as",
 ];
 
 pub(crate) struct RustHelperFunctionExtractor;
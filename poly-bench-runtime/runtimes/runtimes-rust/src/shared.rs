@@ -31,6 +31,166 @@ struct BenchResult {
     error_count: Option<u64>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     error_samples: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counters: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation_passed: Option<bool>,
+}
+"#;
+
+/// Hardware counter helper (`counters: true`): opens instructions, cycles, cache-miss and
+/// branch-miss counters for the benchmarking thread via `perf_event_open` and reports totals
+/// (or the reason they are unavailable) in the result's `counters` object.
+#[cfg(target_os = "linux")]
+pub const PERF_COUNTERS_HELPER: &str = r#"mod polybench_counters {
+    use std::os::raw::{c_long, c_ulong};
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    unsafe extern "C" {
+        fn syscall(num: c_long, ...) -> c_long;
+        fn ioctl(fd: i32, request: c_ulong, ...) -> i32;
+        fn read(fd: i32, buf: *mut u8, count: usize) -> isize;
+        fn close(fd: i32) -> i32;
+    }
+
+    // `perf_event_open` syscall numbers; other architectures (whose numbers or ioctl
+    // encodings differ) report counters as unsupported
+    #[cfg(target_arch = "x86_64")]
+    const SYS_PERF_EVENT_OPEN: Option<c_long> = Some(298);
+    #[cfg(target_arch = "x86")]
+    const SYS_PERF_EVENT_OPEN: Option<c_long> = Some(336);
+    #[cfg(target_arch = "arm")]
+    const SYS_PERF_EVENT_OPEN: Option<c_long> = Some(364);
+    #[cfg(any(target_arch = "aarch64", target_arch = "riscv64", target_arch = "loongarch64"))]
+    const SYS_PERF_EVENT_OPEN: Option<c_long> = Some(241);
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        target_arch = "x86",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "loongarch64"
+    )))]
+    const SYS_PERF_EVENT_OPEN: Option<c_long> = None;
+
+    pub struct Counters {
+        fds: Vec<(&'static str, i32)>,
+        error: Option<String>,
+    }
+
+    pub fn start() -> Counters {
+        let mut counters = Counters { fds: Vec::new(), error: None };
+        let Some(sys_perf_event_open) = SYS_PERF_EVENT_OPEN else {
+            counters.error = Some(format!("hardware counters are not supported on {}", std::env::consts::ARCH));
+            return counters;
+        };
+        let events = [("instructions", 1u64), ("cycles", 0), ("cache_misses", 3), ("branch_misses", 5)];
+        for (name, config) in events {
+            // PERF_TYPE_HARDWARE, disabled | exclude_kernel | exclude_hv
+            let attr = PerfEventAttr {
+                size: std::mem::size_of::<PerfEventAttr>() as u32,
+                config,
+                flags: 0x61,
+                ..Default::default()
+            };
+            let fd = unsafe { syscall(sys_perf_event_open, &attr as *const PerfEventAttr, 0i32, -1i32, -1i32, 0 as c_ulong) };
+            if fd < 0 {
+                if counters.error.is_none() {
+                    counters.error = Some(format!("perf_event_open({}): {}", name, std::io::Error::last_os_error()));
+                }
+                continue;
+            }
+            counters.fds.push((name, fd as i32));
+        }
+        for &(_, fd) in &counters.fds {
+            unsafe {
+                ioctl(fd, 0x2403); // PERF_EVENT_IOC_RESET
+                ioctl(fd, 0x2400); // PERF_EVENT_IOC_ENABLE
+            }
+        }
+        counters
+    }
+
+    impl Counters {
+        pub fn stop(self) -> serde_json::Value {
+            for &(_, fd) in &self.fds {
+                unsafe { ioctl(fd, 0x2401) }; // PERF_EVENT_IOC_DISABLE
+            }
+            let mut out = serde_json::Map::new();
+            for &(name, fd) in &self.fds {
+                let mut buf = [0u8; 8];
+                if unsafe { read(fd, buf.as_mut_ptr(), 8) } == 8 {
+                    out.insert(name.to_string(), u64::from_ne_bytes(buf).into());
+                }
+                unsafe { close(fd) };
+            }
+            if let Some(error) = self.error {
+                out.insert("error".to_string(), error.into());
+            }
+            serde_json::Value::Object(out)
+        }
+    }
+}
+"#;
+
+/// Hardware counter helper stub for platforms without `perf_event_open`
+#[cfg(not(target_os = "linux"))]
+pub const PERF_COUNTERS_HELPER: &str = r#"mod polybench_counters {
+    pub struct Counters;
+
+    pub fn start() -> Counters {
+        Counters
+    }
+
+    impl Counters {
+        pub fn stop(self) -> serde_json::Value {
+            serde_json::json!({ "error": "hardware counters require Linux perf_event_open" })
+        }
+    }
+}
+"#;
+
+/// `__polybench_map_fixture(path)`: maps an external fixture file read-only for the life of the
+/// process and returns its bytes (reads and leaks them where `mmap` is unavailable)
+pub const EXTERNAL_FIXTURE_HELPER: &str = r#"#[cfg(unix)]
+fn __polybench_map_fixture(path: &str) -> &'static [u8] {
+    use std::os::fd::AsRawFd;
+
+    unsafe extern "C" {
+        fn mmap(addr: *mut u8, len: usize, prot: i32, flags: i32, fd: i32, offset: isize) -> *mut u8;
+    }
+    const PROT_READ: i32 = 1;
+    const MAP_PRIVATE: i32 = 2;
+
+    let file = std::fs::File::open(path).expect("failed to open fixture");
+    let len = file.metadata().expect("failed to stat fixture").len() as usize;
+    // SAFETY: a private read-only mapping that is never unmapped, so the slice stays valid
+    unsafe {
+        let data = mmap(std::ptr::null_mut(), len, PROT_READ, MAP_PRIVATE, file.as_raw_fd(), 0);
+        if data as isize == -1 {
+            panic!("failed to map fixture {}: {}", path, std::io::Error::last_os_error());
+        }
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
+#[cfg(not(unix))]
+fn __polybench_map_fixture(path: &str) -> &'static [u8] {
+    std::fs::read(path).expect("failed to read fixture").leak()
 }
 "#;
 
@@ -151,6 +311,28 @@ impl SinkMemoryDecls {
     }
 }
 
+/// Start/stop statements wrapping the timed region and the matching BenchResult field
+pub struct CounterDecls {
+    pub start: &'static str,
+    pub stop: &'static str,
+    pub result_field: &'static str,
+}
+
+impl CounterDecls {
+    /// Create counter statements based on benchmark spec (no-ops unless `counters: true`)
+    pub fn from_spec(spec: &BenchmarkSpec) -> Self {
+        if spec.counters {
+            Self {
+                start: "    let __counters = polybench_counters::start();\n",
+                stop: "    let __counter_totals = __counters.stop();\n",
+                result_field: "        counters: Some(__counter_totals),\n",
+            }
+        } else {
+            Self { start: "", stop: "", result_field: "        counters: None,\n" }
+        }
+    }
+}
+
 /// Generate the benchmark call expression
 /// Uses std::hint::black_box to prevent the compiler from optimizing away the result
 /// without incurring heap allocation overhead
@@ -569,6 +751,20 @@ pub fn generate_async_loop_by_policy(
     }
 }
 
+/// Validation (`validate`): calls the implementation once more after the timed region and
+/// evaluates the expression with that value bound to `result`. Declares
+/// `__polybench_validation_passed`, which the result returns expect in scope.
+pub fn generate_validation(impl_code: &str, validation: Option<&String>) -> String {
+    let Some(check) = validation else {
+        return "    let __polybench_validation_passed: Option<bool> = None;\n".to_string();
+    };
+    format!(
+        "\n    // Validation (outside the timed region)\n    let __polybench_validation_passed: Option<bool> = {{\n        let result = {};\n        Some({})\n    }};\n",
+        impl_code.trim(),
+        check.trim()
+    )
+}
+
 /// Generate result calculation and output for async benchmarks
 pub fn generate_async_result_return(iter_var: &str, memory_result: &str) -> String {
     format!(
@@ -588,6 +784,7 @@ pub fn generate_async_result_return(iter_var: &str, memory_result: &str) -> Stri
         successful_count: Some(successful_count),
         error_count: Some(error_count),
         error_samples,
+        validation_passed: __polybench_validation_passed,
     }};
     
     println!("{{}}", serde_json::to_string(&result).unwrap());
@@ -651,6 +848,12 @@ pub fn generate_fixtures_for_spec(spec: &BenchmarkSpec, suite: &SuiteIR, lang: L
         if let Some(fixture) = suite.get_fixture(fixture_name) {
             if let Some(fixture_impl) = fixture.implementations.get(&lang) {
                 code.push_str(&format!("    let {} = {};\n", fixture_name, fixture_impl));
+            } else if let Some(path) = fixture.external_file_literal(lang) {
+                // Needs EXTERNAL_FIXTURE_HELPER
+                code.push_str(&format!(
+                    "    let {}: &[u8] = __polybench_map_fixture({});\n",
+                    fixture_name, path
+                ));
             } else if !fixture.data.is_empty() {
                 code.push_str(&format!(
                     "    let {}: Vec<u8> = vec![{}];\n",
@@ -668,7 +871,8 @@ pub fn generate_fixtures_for_spec(spec: &BenchmarkSpec, suite: &SuiteIR, lang: L
     code
 }
 
-/// Generate result calculation and output
+/// Generate result calculation and output (expects `__polybench_raw_result` and
+/// `__polybench_validation_passed` in scope)
 pub fn generate_result_return(iter_var: &str, memory_result: &str) -> String {
     format!(
         r#"
@@ -682,11 +886,12 @@ pub fn generate_result_return(iter_var: &str, memory_result: &str) -> String {
         nanos_per_op,
         ops_per_sec,
 {memory_result}        samples,
-        raw_result: None,
+        raw_result: __polybench_raw_result,
         successful_results: Vec::new(),
         successful_count: None,
         error_count: None,
         error_samples: Vec::new(),
+        validation_passed: __polybench_validation_passed,
     }};
     
     println!("{{}}", serde_json::to_string(&result).unwrap());
@@ -714,6 +919,19 @@ mod tests {
         assert_eq!(generate_bench_call("foo()", false), "let _ = foo()");
     }
 
+    #[test]
+    fn test_counter_decls_only_when_enabled() {
+        let mut spec = BenchmarkSpec::new("b".to_string(), "s", 100, 0, 0);
+        let decls = CounterDecls::from_spec(&spec);
+        assert!(decls.start.is_empty());
+        assert_eq!(decls.result_field.trim(), "counters: None,");
+        spec.counters = true;
+        let decls = CounterDecls::from_spec(&spec);
+        assert!(decls.start.contains("polybench_counters::start()"));
+        assert!(decls.result_field.contains("__counter_totals"));
+        assert!(PERF_COUNTERS_HELPER.contains("mod polybench_counters"));
+    }
+
     #[test]
     fn test_memory_result_fields() {
         let result = SinkMemoryDecls::memory_result_fields(true);
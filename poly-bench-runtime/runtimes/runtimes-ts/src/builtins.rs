@@ -27,6 +27,10 @@ pub const BENCH_HARNESS_PERF: &str = r#"
     function normalizeRawResult(value) {
         if (value === undefined) return null;
         if (typeof value === 'bigint') return value.toString();
+        // Typed arrays (e.g. Uint8Array) become plain arrays so bytes compare across languages
+        if (ArrayBuffer.isView(value) && !(value instanceof DataView)) {
+            return Array.from(value, (v) => typeof v === 'bigint' ? v.toString() : v);
+        }
         try {
             return JSON.parse(JSON.stringify(value, (_, v) => typeof v === 'bigint' ? v.toString() : v));
         } catch (_) {
@@ -558,8 +562,8 @@ pub const BENCH_HARNESS_PERF: &str = r#"
 })(globalThis);
 "#;
 
-/// Memory harness: uses V8 total_allocated_bytes (GC-insensitive) and forces GC before measurement.
-/// Use with Node --expose-gc for memory benchmarks.
+/// Memory harness: uses V8 total_allocated_bytes (GC-insensitive) when available, else samples
+/// heap growth across single calls after a forced GC. Use with Node --expose-gc.
 pub const BENCH_HARNESS_MEMORY: &str = r#"
 // Benchmark harness for poly-bench (memory path)
 (function(globalThis) {
@@ -582,40 +586,76 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
 
     // GC before measurement when available (Node --expose-gc)
     const forceGC = () => { if (typeof global.gc === 'function') global.gc(); };
-    // Use V8 total_allocated_bytes (GC-insensitive cumulative) when available, else fallbacks
+    // Calls measured by the forced-GC fallback (each call runs after a full GC)
+    const MEMORY_SAMPLE_OPS = 100;
+    // V8 total_allocated_bytes (Node 25+) is cumulative and GC-insensitive
     const getMemorySnapshot = () => {
         const stats = v8 && v8.getHeapStatistics ? v8.getHeapStatistics() : null;
-        // total_allocated_bytes (Node 25+) is the best cumulative metric - never decreases
         const totalAllocatedBytes = stats && typeof stats.total_allocated_bytes === 'number' ? stats.total_allocated_bytes : 0;
-        const totalHeap = stats ? stats.total_heap_size : 0;
-        const malloced = stats ? (stats.malloced_memory || 0) : 0;
-        const heapUsed = (typeof process !== 'undefined' && process.memoryUsage) ? process.memoryUsage().heapUsed : 0;
-        return { totalAllocatedBytes, totalHeap, malloced, heapUsed };
+        return { totalAllocatedBytes };
+    };
+    // JS heap plus off-heap ArrayBuffer backing stores
+    const usedHeapSize = () => {
+        if (!v8 || !v8.getHeapStatistics) return 0;
+        const stats = v8.getHeapStatistics();
+        return stats.used_heap_size + (stats.external_memory || 0);
     };
+    // Bytes per op from cumulative snapshots, or undefined when the runtime lacks the counter
     const bytesPerOpFromSnapshots = (before, after, iters) => {
-        // Prefer total_allocated_bytes (cumulative, GC-insensitive)
         if (before.totalAllocatedBytes > 0 && after.totalAllocatedBytes > 0) {
-            const delta = (after.totalAllocatedBytes - before.totalAllocatedBytes) / iters;
-            if (delta > 0) return Math.round(delta);
-        }
-        // Fallback: malloced_memory (cumulative C++ allocations)
-        if (before.malloced > 0 && after.malloced > 0) {
-            const delta = (after.malloced - before.malloced) / iters;
-            if (delta > 0) return Math.round(delta);
-        }
-        // Fallback: total_heap_size (cumulative JS heap growth)
-        if (before.totalHeap > 0 && after.totalHeap > 0) {
-            const delta = (after.totalHeap - before.totalHeap) / iters;
-            if (delta > 0) return Math.round(delta);
-        }
-        // Last resort: heapUsed delta (can be negative after GC, so clamp)
-        const heapDelta = Math.max(0, (after.heapUsed - before.heapUsed)) / iters;
-        return heapDelta > 0 ? Math.round(heapDelta) : 0;
+            return Math.max(0, Math.round((after.totalAllocatedBytes - before.totalAllocatedBytes) / iters));
+        }
+        return undefined;
+    };
+    const median = (values) => {
+        const sorted = values.slice().sort((a, b) => a - b);
+        return sorted.length ? sorted[Math.floor(sorted.length / 2)] : 0;
     };
+    // Fallback outside the timed loop: force a full GC before each sampled call so the heap
+    // growth across the call is what it allocated. The median is used (early calls still warm up
+    // inline caches) and the cost of reading heap statistics is subtracted.
+    function measureBytesPerOp(fn, eachHook, iters) {
+        const ops = Math.max(1, Math.min(iters, MEMORY_SAMPLE_OPS));
+        const deltas = [], overhead = [];
+        for (let i = 0; i < ops; i++) {
+            forceGC();
+            let before = usedHeapSize();
+            overhead.push(usedHeapSize() - before);
+            if (eachHook) eachHook();
+            forceGC();
+            before = usedHeapSize();
+            globalThis.__polybench_mem_sink = fn();
+            deltas.push(usedHeapSize() - before);
+            globalThis.__polybench_mem_sink = undefined;
+        }
+        return Math.max(0, median(deltas) - median(overhead));
+    }
+    async function measureBytesPerOpAsync(fn, eachHook, iters) {
+        const ops = Math.max(1, Math.min(iters, MEMORY_SAMPLE_OPS));
+        const deltas = [], overhead = [];
+        for (let i = 0; i < ops; i++) {
+            try {
+                forceGC();
+                let before = usedHeapSize();
+                overhead.push(usedHeapSize() - before);
+                if (eachHook) await eachHook();
+                forceGC();
+                before = usedHeapSize();
+                globalThis.__polybench_mem_sink = await fn();
+                deltas.push(usedHeapSize() - before);
+            } catch (_) {}
+            globalThis.__polybench_mem_sink = undefined;
+        }
+        return Math.max(0, median(deltas) - median(overhead));
+    }
 
     function normalizeRawResult(value) {
         if (value === undefined) return null;
         if (typeof value === 'bigint') return value.toString();
+        // Typed arrays (e.g. Uint8Array) become plain arrays so bytes compare across languages
+        if (ArrayBuffer.isView(value) && !(value instanceof DataView)) {
+            return Array.from(value, (v) => typeof v === 'bigint' ? v.toString() : v);
+        }
         try {
             return JSON.parse(JSON.stringify(value, (_, v) => typeof v === 'bigint' ? v.toString() : v));
         } catch (_) {
@@ -660,7 +700,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
             totalNanos += samples[i];
         }
         const memAfter = getMemorySnapshot();
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations) ?? measureBytesPerOp(fn, null, iterations);
         return { iterations, totalNanos, nanosPerOp: totalNanos / iterations, opsPerSec: 1e9 / (totalNanos / iterations), bytesPerOp, samples, rawResult: normalizeRawResult(lastResult) };
     }
 
@@ -700,7 +740,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
             } else { iterations *= 10; }
         }
         const memAfter = getMemorySnapshot();
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, totalIterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, totalIterations) ?? measureBytesPerOp(fn, null, totalIterations);
         const nanosPerOp = totalNanos / totalIterations;
         return { iterations: totalIterations, totalNanos, nanosPerOp, opsPerSec: 1e9 / nanosPerOp, bytesPerOp, samples, rawResult: normalizeRawResult(lastResult) };
     }
@@ -753,7 +793,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
             totalNanos += elapsed;
         }
         const memAfter = getMemorySnapshot();
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations) ?? await measureBytesPerOpAsync(fn, null, iterations);
         return { iterations, totalNanos, nanosPerOp: totalNanos / iterations, opsPerSec: 1e9 / (totalNanos / iterations), bytesPerOp, samples, rawResult: normalizeRawResult(lastResult), successfulResults, successfulCount, errorCount, errorSamples };
     }
 
@@ -802,7 +842,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
         }
         const memAfter = getMemorySnapshot();
         const denomIterations = totalIterations > 0 ? totalIterations : 1;
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, denomIterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, denomIterations) ?? await measureBytesPerOpAsync(fn, null, denomIterations);
         return { iterations: totalIterations, totalNanos, nanosPerOp: totalNanos / denomIterations, opsPerSec: 1e9 / (totalNanos / denomIterations), bytesPerOp, samples, rawResult: normalizeRawResult(lastResult), successfulResults, successfulCount, errorCount, errorSamples };
     }
 
@@ -827,7 +867,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
             totalNanos += samples[i];
         }
         const memAfter = getMemorySnapshot();
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations) ?? measureBytesPerOp(fn, eachHook, iterations);
         return { iterations, totalNanos, nanosPerOp: totalNanos / iterations, opsPerSec: 1e9 / (totalNanos / iterations), bytesPerOp, samples, rawResult: normalizeRawResult(lastResult) };
     }
 
@@ -867,7 +907,7 @@ pub const BENCH_HARNESS_MEMORY: &str = r#"
             totalNanos += elapsed;
         }
         const memAfter = getMemorySnapshot();
-        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations);
+        const bytesPerOp = bytesPerOpFromSnapshots(memBefore, memAfter, iterations) ?? await measureBytesPerOpAsync(fn, eachHook, iterations);
         return { iterations, totalNanos, nanosPerOp: totalNanos / iterations, opsPerSec: 1e9 / (totalNanos / iterations), bytesPerOp, samples, rawResult: normalizeRawResult(lastResult), successfulResults, successfulCount, errorCount, errorSamples };
     }
 
@@ -910,6 +950,17 @@ const {name}_hex = "{hex_prefix}{hex_data}";
     )
 }
 
+/// Generate code that reads an external fixture file at startup (needs `require` in scope)
+pub fn generate_external_fixture_code(name: &str, path_literal: &str) -> String {
+    format!(
+        r#"const {name} = new Uint8Array(require('node:fs').readFileSync({path}));
+const {name}_hex = '0x' + Buffer.from({name}).toString('hex');
+"#,
+        name = name,
+        path = path_literal
+    )
+}
+
 /// Generate benchmark wrapper code
 pub fn generate_benchmark_code(
     _name: &str,
@@ -962,6 +1013,8 @@ mod tests {
     fn test_memory_harness_uses_total_allocated_bytes() {
         assert!(BENCH_HARNESS_MEMORY.contains("total_allocated_bytes"));
         assert!(BENCH_HARNESS_MEMORY.contains("global.gc"));
+        assert!(BENCH_HARNESS_MEMORY.contains("?? measureBytesPerOp(fn, eachHook, iterations)"));
+        assert!(BENCH_HARNESS_MEMORY.contains("used_heap_size"));
         assert!(BENCH_HARNESS_MEMORY.contains("node:v8"));
     }
 }
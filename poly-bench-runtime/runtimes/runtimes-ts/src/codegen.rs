@@ -2,9 +2,11 @@
 
 use miette::{miette, Result};
 use poly_bench_dsl::{BenchMode, Lang};
-use poly_bench_ir::{BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkIR, BenchmarkSpec, FixtureIR, SuiteIR};
 use poly_bench_stdlib as stdlib;
 
+use crate::builtins;
+
 /// Generate TypeScript benchmark code from IR
 pub fn generate(ir: &BenchmarkIR) -> Result<String> {
     let mut code = String::new();
@@ -497,6 +499,9 @@ fn generate_fixture(code: &mut String, fixture: &FixtureIR) -> Result<()> {
     // Check if there's a TS-specific implementation
     if let Some(impl_code) = fixture.implementations.get(&Lang::TypeScript) {
         code.push_str(&format!("const {} = {};\n\n", fixture.name, impl_code));
+    } else if let Some(path) = fixture.external_file_literal(Lang::TypeScript) {
+        code.push_str(&builtins::generate_external_fixture_code(&fixture.name, &path));
+        code.push('\n');
     } else if !fixture.data.is_empty() {
         // Use hex data - provide as both Uint8Array and hex string
         code.push_str(&format!("const {} = {};\n", fixture.name, fixture.as_js_uint8array()));
@@ -530,11 +535,14 @@ fn generate_benchmark(code: &mut String, bench: &BenchmarkSpec) -> Result<()> {
     let each_hook = bench.each_hooks.get(&Lang::TypeScript);
 
     // Generate before/after wrapper if needed
-    let has_before_or_after = before_hook.is_some() || after_hook.is_some();
-
-    // Generate hook code
-    let before_code =
-        before_hook.map(|h| format!("    // Before hook\n    {}\n", h.trim())).unwrap_or_default();
+    let has_before_or_after =
+        before_hook.is_some() || after_hook.is_some() || !bench.params.is_empty();
+
+    // Generate hook code (parameters are declared ahead of the before hook)
+    let before_code = param_declarations(&bench.params, Lang::TypeScript, "    ") +
+        &before_hook
+            .map(|h| format!("    // Before hook\n    {}\n", h.trim()))
+            .unwrap_or_default();
     let after_code =
         after_hook.map(|h| format!("\n    // After hook\n    {}", h.trim())).unwrap_or_default();
 
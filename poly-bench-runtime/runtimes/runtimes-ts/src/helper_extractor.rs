@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static TS_BUILTINS: &[&str] = &[
     "console",
     "Math",
@@ -35,6 +36,18 @@ static TS_BUILTINS: &[&str] = &[
     "fetch",
     "require",
     "import",
+    "if",
+    "for",
+    "while",
+    "switch",
+    "return",
+    "catch",
+    "typeof",
+    "function",
+    "await",
+    "yield",
+    "void",
+    "delete",
 ];
 
 pub(crate) struct TsHelperFunctionExtractor;
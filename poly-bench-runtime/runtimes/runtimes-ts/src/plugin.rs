@@ -38,6 +38,10 @@ impl RuntimePlugin for TsPlugin {
         ts_lang_display()
     }
 
+    fn memory_metric_note(&self) -> Option<&'static str> {
+        Some("median V8 heap growth per call after a forced GC; no allocation counts")
+    }
+
     fn project_root_detector(&self) -> Option<&'static dyn ProjectRootDetector> {
         Some(&TS_DETECTOR)
     }
@@ -8,9 +8,11 @@ use crate::{builtins, codegen, error_mapping::TsErrorMapper, transpiler};
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{AsyncSamplingPolicy, BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{ErrorMapper, Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{
+    ErrorMapper, Measurement, Runtime, RuntimeConfig, RuntimeFactory, VariantToolchain,
+};
 use std::{path::PathBuf, process::Stdio, time::Instant};
 use tempfile::TempDir;
 
@@ -30,11 +32,18 @@ pub struct JsRuntime {
     cached_script: Option<(PathBuf, PathBuf, u64)>,
     /// Duration of last precompile in nanoseconds (for accurate reporting)
     last_precompile_nanos: Option<u64>,
+    /// JavaScript runtime binary, flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl JsRuntime {
     pub fn new() -> Result<Self> {
-        let (node_binary, _) = poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::TypeScript)
+        Self::with_toolchain(VariantToolchain::default())
+    }
+
+    /// Create a runtime that runs benchmarks with a runtime variant's binary (e.g. `bun`)
+    pub fn with_toolchain(toolchain: VariantToolchain) -> Result<Self> {
+        let (node_binary, _) = toolchain.resolve_binary(Lang::TypeScript)
             .map_err(|_| miette!("Node.js not found. Install via 'poly-bench add-runtime ts' or ensure node is in PATH"))?;
 
         Ok(Self {
@@ -45,6 +54,7 @@ impl JsRuntime {
             anvil_rpc_url: None,
             cached_script: None,
             last_precompile_nanos: None,
+            toolchain,
         })
     }
 
@@ -77,20 +87,21 @@ impl JsRuntime {
     ) -> Result<(PathBuf, PathBuf)> {
         let (script_path, working_dir) = if let Some(ref project_root) = self.project_root {
             let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
+            let script_name = self.toolchain.artifact_name("bench.mjs");
             let script_path = if is_runtime_env {
-                project_root.join("bench.mjs")
+                project_root.join(script_name)
             } else {
                 let bench_dir = project_root.join(".polybench");
                 std::fs::create_dir_all(&bench_dir)
                     .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
-                bench_dir.join("bench.mjs")
+                bench_dir.join(script_name)
             };
             (script_path, project_root.clone())
         } else {
             let temp_dir =
                 self.temp_dir.as_ref().ok_or_else(|| miette!("Runtime not initialized"))?;
 
-            let script_path = temp_dir.path().join("bench.js");
+            let script_path = temp_dir.path().join(self.toolchain.artifact_name("bench.js"));
             (script_path, temp_dir.path().to_path_buf())
         };
 
@@ -123,7 +134,7 @@ impl RuntimeFactory for JsRuntimeFactory {
         "JavaScript/TypeScript Runtime"
     }
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
-        let mut rt = JsRuntime::new()?;
+        let mut rt = JsRuntime::with_toolchain(config.toolchain())?;
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::TypeScript));
         Ok(Box::new(rt))
     }
@@ -260,6 +271,7 @@ impl Runtime for JsRuntime {
             let output = tokio::process::Command::new(&self.node_binary)
                 .args(["--check", js_path.to_str().unwrap()])
                 .current_dir(&working_dir)
+                .envs(&self.toolchain.env)
                 .output()
                 .await
                 .map_err(|e| miette!("Failed to check JavaScript syntax: {}", e))?;
@@ -292,20 +304,21 @@ impl Runtime for JsRuntime {
         // Determine where to write the script
         let (script_path, working_dir) = if let Some(ref project_root) = self.project_root {
             let is_runtime_env = project_root.as_os_str().to_string_lossy().contains("runtime-env");
+            let script_name = self.toolchain.artifact_name("bench.mjs");
             let script_path = if is_runtime_env {
-                project_root.join("bench.mjs")
+                project_root.join(script_name)
             } else {
                 let bench_dir = project_root.join(".polybench");
                 std::fs::create_dir_all(&bench_dir)
                     .map_err(|e| miette!("Failed to create .polybench directory: {}", e))?;
-                bench_dir.join("bench.mjs")
+                bench_dir.join(script_name)
             };
             (script_path, project_root.clone())
         } else {
             let temp_dir =
                 self.temp_dir.as_ref().ok_or_else(|| miette!("Runtime not initialized"))?;
 
-            let script_path = temp_dir.path().join("bench.js");
+            let script_path = temp_dir.path().join(self.toolchain.artifact_name("bench.js"));
             (script_path, temp_dir.path().to_path_buf())
         };
 
@@ -316,6 +329,7 @@ impl Runtime for JsRuntime {
         let check_output = tokio::process::Command::new(&self.node_binary)
             .args(["--check", script_path.to_str().unwrap()])
             .current_dir(&working_dir)
+            .envs(&self.toolchain.env)
             .output()
             .await
             .map_err(|e| miette!("Failed to check TypeScript benchmark: {}", e))?;
@@ -358,10 +372,14 @@ impl Runtime for JsRuntime {
 
         // Run with Node.js from the working directory (which has node_modules)
         let mut cmd = tokio::process::Command::new(&self.node_binary);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
         if spec.memory {
             cmd.arg("--expose-gc");
         }
-        cmd.arg(&script_path).current_dir(&working_dir);
+        cmd.args(&self.toolchain.flags)
+            .arg(&script_path)
+            .current_dir(&working_dir)
+            .envs(&self.toolchain.env);
 
         // Pass Anvil RPC URL if available
         if let Some(ref url) = self.anvil_rpc_url {
@@ -395,7 +413,7 @@ impl Runtime for JsRuntime {
 
         let run_wall_nanos = run_start.elapsed().as_nanos() as u64;
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut m = parse_benchmark_result(&stdout, spec.outlier_detection, spec.cv_threshold)?;
+        let mut m = parse_benchmark_result(&stdout, spec)?;
         // spawn = wall - warmup - exec (remainder after subtracting in-process phases)
         if let Some(warmup) = m.warmup_nanos {
             let exec = m.total_nanos;
@@ -441,8 +459,9 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         }
     }
 
-    // ESM: require is undefined; memory harness needs node:v8. Add createRequire so require works.
-    if spec.memory {
+    // ESM: require is undefined; memory harness needs node:v8 and external fixtures need node:fs.
+    // Add createRequire so require works.
+    if spec.memory || suite.uses_external_fixture(spec, Lang::TypeScript) {
         script.push_str("import { createRequire } from 'node:module';\nconst require = createRequire(import.meta.url);\n\n");
     }
 
@@ -509,11 +528,14 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
                 } else {
                     script.push_str(&format!("const {} = {};\n", fixture_name, stripped));
                 }
+            } else if let Some(path) = fixture.external_file_literal(Lang::TypeScript) {
+                script.push_str(&builtins::generate_external_fixture_code(fixture_name, &path));
             } else if !fixture.data.is_empty() {
                 script.push_str(&builtins::generate_fixture_code(fixture_name, &fixture.as_hex()));
             }
         }
     }
+    script.push_str(&param_declarations(&spec.params, Lang::TypeScript, ""));
     script.push_str("\n");
 
     // Phase 3: Get lifecycle hooks
@@ -658,6 +680,20 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
         }
     }
 
+    // Validation: one more call outside the timed region, with its value bound to `result`
+    if let Some(check) = spec.validations.get(&Lang::TypeScript) {
+        script.push_str("\n// Validation\n{\n");
+        script.push_str(&format!(
+            "    const result = {}({});\n",
+            if impl_is_async { "await " } else { "" },
+            impl_code
+        ));
+        script.push_str(&format!(
+            "    __result.validationPassed = Boolean({});\n}}\n",
+            strip_typescript_syntax(check.trim())
+        ));
+    }
+
     // Phase 3: After hook (runs once after benchmark)
     if let Some(after) = after_hook {
         script.push_str("\n// After hook\n");
@@ -675,11 +711,7 @@ fn generate_standalone_script(spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<S
 }
 
 /// Parse benchmark result JSON from stdout
-fn parse_benchmark_result(
-    stdout: &str,
-    outlier_detection: bool,
-    cv_threshold: f64,
-) -> Result<Measurement> {
+fn parse_benchmark_result(stdout: &str, spec: &BenchmarkSpec) -> Result<Measurement> {
     // Find the JSON line (last non-empty line)
     let json_line = stdout
         .lines()
@@ -690,7 +722,10 @@ fn parse_benchmark_result(
     let result: BenchResultJson = serde_json::from_str(json_line)
         .map_err(|e| miette!("Failed to parse benchmark result: {}\nOutput: {}", e, stdout))?;
 
-    Ok(result.into_measurement_with_options(outlier_detection, cv_threshold))
+    let validation_passed = result.validation_passed;
+    let mut m = result.into_measurement_with_options(spec.outlier_detection, spec.cv_threshold);
+    m.record_validation(spec.validations.get(&Lang::TypeScript), validation_passed);
+    Ok(m)
 }
 
 pub fn extract_runtime_error_reason(raw: &str) -> String {
@@ -762,7 +797,7 @@ struct BenchResultJson {
     ops_per_sec: f64,
     #[serde(default)]
     samples: Vec<f64>,
-    /// Bytes allocated per operation (from process.memoryUsage().heapUsed delta)
+    /// Bytes allocated per operation (V8 heap statistics)
     #[serde(default)]
     bytes_per_op: Option<f64>,
     #[serde(default)]
@@ -775,6 +810,8 @@ struct BenchResultJson {
     error_count: Option<u64>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -808,11 +845,10 @@ impl BenchResultJson {
             m.async_error_samples = Some(self.error_samples);
         }
 
-        // Apply memory stats from JS (total_allocated_bytes or heapUsed fallback); allocs_per_op
-        // not available in Node
+        // Apply memory stats from JS (total_allocated_bytes or the forced-GC heap fallback);
+        // V8 does not count allocations, so allocs_per_op stays unset
         if let Some(bytes) = self.bytes_per_op {
-            let bytes_u64 = bytes.max(0.0).round() as u64;
-            m = m.with_allocs(bytes_u64, 0);
+            m = m.with_memory(bytes.max(0.0).round() as u64, None);
         }
 
         m
@@ -893,6 +929,9 @@ fn generate_typescript_check_source(spec: &BenchmarkSpec, suite: &SuiteIR) -> Re
                 } else {
                     script.push_str(&format!("const {} = {};\n", fixture_name, fixture_impl));
                 }
+            } else if fixture.external_file.is_some() {
+                // Only the type matters for checking; the bytes are read at run time
+                script.push_str(&format!("const {} = new Uint8Array(0);\n", fixture_name));
             } else if !fixture.data.is_empty() {
                 script.push_str(&format!(
                     "const {} = new Uint8Array([{}]);\n",
@@ -902,6 +941,7 @@ fn generate_typescript_check_source(spec: &BenchmarkSpec, suite: &SuiteIR) -> Re
             }
         }
     }
+    script.push_str(&param_declarations(&spec.params, Lang::TypeScript, ""));
 
     // Add the benchmark implementation wrapped in a function
     let is_async = spec.kind == BenchmarkKind::Async || impl_code.contains("await ");
@@ -3,9 +3,12 @@
 use async_trait::async_trait;
 use miette::{miette, Result};
 use poly_bench_dsl::{AsyncSamplingPolicy, BenchMode, BenchmarkKind, Lang};
-use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_ir::{param_declarations, BenchmarkSpec, SuiteIR};
 use poly_bench_stdlib as stdlib;
-use poly_bench_traits::{Measurement, Runtime, RuntimeConfig, RuntimeFactory};
+use poly_bench_traits::{
+    ArtifactCache, ArtifactKey, CounterTotals, Measurement, Runtime, RuntimeConfig, RuntimeFactory,
+    VariantToolchain,
+};
 use std::{
     collections::{HashMap, HashSet},
     path::{Path, PathBuf},
@@ -13,23 +16,37 @@ use std::{
     time::{Duration, Instant},
 };
 
+/// Environment read by `zig build-exe`, keyed into cached binaries
+/// Extensions of project sources the harness build imports
+pub const SOURCE_EXTENSIONS: &[&str] = &["zig"];
+
+const BUILD_ENV: &[&str] = &["ZIG_LIB_DIR", "ZIG_LOCAL_CACHE_DIR", "CC", "CFLAGS"];
+
 pub struct ZigRuntime {
     zig_binary: PathBuf,
     project_root: Option<PathBuf>,
     anvil_rpc_url: Option<String>,
     cached_binary: Option<(PathBuf, u64)>,
     last_precompile_nanos: Option<u64>,
+    /// Persistent cache of built binaries shared across runs
+    artifacts: ArtifactCache,
+    /// zig binary, `zig build-exe` flags and environment of a runtime variant
+    toolchain: VariantToolchain,
 }
 
 impl ZigRuntime {
     pub fn new() -> Result<Self> {
+        Self::with_toolchain(VariantToolchain::default())
+    }
+
+    /// Create a Zig runtime that builds with a runtime variant's toolchain
+    pub fn with_toolchain(toolchain: VariantToolchain) -> Result<Self> {
         // Use Polybench-managed toolchain if available, otherwise fall back to system PATH
-        let (zig_binary, _is_managed) =
-            poly_bench_traits::resolve_binary(poly_bench_dsl::Lang::Zig).map_err(|_| {
-                miette!(
+        let (zig_binary, _is_managed) = toolchain.resolve_binary(Lang::Zig).map_err(|_| {
+            miette!(
                 "zig not found. Install via 'poly-bench add-runtime zig' or ensure zig is in PATH"
             )
-            })?;
+        })?;
 
         Ok(Self {
             zig_binary,
@@ -37,6 +54,8 @@ impl ZigRuntime {
             anvil_rpc_url: None,
             cached_binary: None,
             last_precompile_nanos: None,
+            artifacts: ArtifactCache::from_env(),
+            toolchain,
         })
     }
 
@@ -396,6 +415,27 @@ impl ZigRuntime {
         Ok(used)
     }
 
+    /// Build the benchmark binary, reusing a cached binary built from the same inputs
+    fn build_runner(&self, work_dir: &Path, source: &str) -> Result<PathBuf> {
+        let key = ArtifactKey::new(Lang::Zig, source)
+            .toolchain(&self.zig_binary, &["version"])
+            .variant(&self.toolchain)
+            .ambient_env(BUILD_ENV)
+            .file(&work_dir.join("build.zig.zon"))
+            .source_tree(work_dir, SOURCE_EXTENSIONS);
+        let output_name = self.toolchain.artifact_name("polybench_runner");
+        if let Some(cached) = self.artifacts.lookup(&key, &output_name) {
+            return Ok(cached);
+        }
+        let binary_path = self.write_source_and_build(
+            work_dir,
+            source,
+            &self.toolchain.artifact_name("bench_standalone.zig"),
+            &output_name,
+        )?;
+        Ok(self.artifacts.store(&key, &binary_path).unwrap_or(binary_path))
+    }
+
     fn write_source_and_build(
         &self,
         work_dir: &Path,
@@ -452,6 +492,7 @@ impl ZigRuntime {
         if link_libc {
             cmd.arg("-lc");
         }
+        cmd.args(&self.toolchain.flags).envs(&self.toolchain.env);
         let output =
             cmd.current_dir(work_dir).output().map_err(|e| miette!("Failed to run zig: {}", e))?;
 
@@ -555,6 +596,7 @@ impl ZigRuntime {
         let mut child = std::process::Command::new(&self.zig_binary)
             .args(["build", "-Doptimize=ReleaseFast"])
             .current_dir(work_dir)
+            .envs(&self.toolchain.env)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
@@ -668,7 +710,7 @@ impl RuntimeFactory for ZigRuntimeFactory {
     }
 
     fn create(&self, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
-        let mut rt = ZigRuntime::new()?;
+        let mut rt = ZigRuntime::with_toolchain(config.toolchain())?;
         rt.set_project_root(config.get_root(poly_bench_dsl::Lang::Zig));
         Ok(Box::new(rt))
     }
@@ -693,7 +735,7 @@ impl Runtime for ZigRuntime {
     }
 
     async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
-        which::which("zig").map_err(|_| miette!("zig not found in PATH"))?;
+        self.toolchain.resolve_binary(Lang::Zig).map_err(|_| miette!("zig not found in PATH"))?;
         Ok(())
     }
 
@@ -706,7 +748,8 @@ impl Runtime for ZigRuntime {
         let work_dir = self.resolve_work_dir()?;
         let safe_name = spec.full_name.replace('.', "_").replace('/', "_");
         let source_name = format!("bench_check_{}.zig", safe_name);
-        self.write_source_and_build(&work_dir, &source, &source_name, "polybench_check")?;
+        let output_name = self.toolchain.artifact_name("polybench_check");
+        self.write_source_and_build(&work_dir, &source, &source_name, &output_name)?;
         Ok(())
     }
 
@@ -723,12 +766,7 @@ impl Runtime for ZigRuntime {
 
         let pc_start = std::time::Instant::now();
         let work_dir = self.resolve_work_dir()?;
-        let binary_path = self.write_source_and_build(
-            &work_dir,
-            &source,
-            "bench_standalone.zig",
-            "polybench_runner",
-        )?;
+        let binary_path = self.build_runner(&work_dir, &source)?;
         self.cached_binary = Some((binary_path, source_hash));
         self.last_precompile_nanos = Some(pc_start.elapsed().as_nanos() as u64);
         Ok(())
@@ -747,28 +785,20 @@ impl Runtime for ZigRuntime {
             if cached_hash == source_hash && binary_path.exists() {
                 binary_path.clone()
             } else {
-                let built = self.write_source_and_build(
-                    &work_dir,
-                    &source,
-                    "bench_standalone.zig",
-                    "polybench_runner",
-                )?;
+                let built = self.build_runner(&work_dir, &source)?;
                 self.cached_binary = Some((built.clone(), source_hash));
                 built
             }
         } else {
-            let built = self.write_source_and_build(
-                &work_dir,
-                &source,
-                "bench_standalone.zig",
-                "polybench_runner",
-            )?;
+            let built = self.build_runner(&work_dir, &source)?;
             self.cached_binary = Some((built.clone(), source_hash));
             built
         };
 
         let mut cmd = tokio::process::Command::new(&binary_path);
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
         cmd.current_dir(&work_dir)
+            .envs(&self.toolchain.env)
             .stdin(Stdio::null())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
@@ -805,7 +835,7 @@ impl Runtime for ZigRuntime {
 
         let run_wall_nanos = run_start.elapsed().as_nanos() as u64;
         let stdout = String::from_utf8_lossy(&output.stdout);
-        let mut m = parse_benchmark_result(&stdout, spec.outlier_detection, spec.cv_threshold)?;
+        let mut m = parse_benchmark_result(&stdout, spec)?;
         let spawn = if let Some(w) = m.warmup_nanos {
             run_wall_nanos.saturating_sub(w).saturating_sub(m.total_nanos)
         } else {
@@ -820,12 +850,43 @@ impl Runtime for ZigRuntime {
     }
 }
 
+/// Statements for the top of main that map external fixture files (read them on Windows). The
+/// mapping is private and writable, so fixtures stay ordinary `[]u8` slices.
+fn emit_external_fixture_loads(spec: &BenchmarkSpec, suite: &SuiteIR) -> String {
+    let mut out = String::new();
+    for fixture in spec.fixture_refs.iter().filter_map(|name| suite.get_fixture(name)) {
+        if fixture.implementations.contains_key(&Lang::Zig) {
+            continue;
+        }
+        let Some(path) = fixture.external_file_literal(Lang::Zig) else { continue };
+        if cfg!(unix) {
+            out.push_str(&format!(
+                "    {{\n        const __file = try std.fs.cwd().openFile({path}, .{{}});\n        defer __file.close();\n        {name} = try std.posix.mmap(null, {len}, std.posix.PROT.READ | std.posix.PROT.WRITE, .{{ .TYPE = .PRIVATE }}, __file.handle, 0);\n    }}\n",
+                path = path,
+                name = fixture.name,
+                len = fixture.data.len()
+            ));
+        } else {
+            out.push_str(&format!(
+                "    {} = try std.fs.cwd().readFileAlloc(std.heap.page_allocator, {}, {});\n",
+                fixture.name,
+                path,
+                fixture.data.len()
+            ));
+        }
+    }
+    out
+}
+
 fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String {
     let mut out = String::new();
     for fixture_name in &spec.fixture_refs {
         if let Some(fixture) = suite.get_fixture(fixture_name) {
             if let Some(fixture_impl) = fixture.implementations.get(&Lang::Zig) {
                 out.push_str(&format!("{}{} = {};\n", indent, fixture_name, fixture_impl));
+            } else if fixture.external_file.is_some() {
+                // Filled at the start of main by `emit_external_fixture_loads`
+                out.push_str(&format!("{}var {}: []u8 = undefined;\n", indent, fixture_name));
             } else if !fixture.data.is_empty() {
                 let bytes: Vec<String> =
                     fixture.data.iter().map(|b| format!("0x{:02x}", b)).collect();
@@ -842,8 +903,78 @@ fn emit_fixtures(spec: &BenchmarkSpec, suite: &SuiteIR, indent: &str) -> String
     out
 }
 
+/// Counting allocator exposed to benchmark code as `polybench_allocator`. Allocations made through
+/// it are counted exactly; the VTable shape differs between Zig 0.13 (`u8` log2 alignment, no
+/// `remap`) and 0.14+ (`std.mem.Alignment`, `remap`).
+fn emit_counting_allocator() -> String {
+    r#"const __PolybenchCountingAllocator = struct {
+    child: std.mem.Allocator,
+    bytes: u64 = 0,
+    count: u64 = 0,
+
+    const Alignment = if (@hasDecl(std.mem, "Alignment")) std.mem.Alignment else u8;
+
+    fn alloc(ctx: *anyopaque, len: usize, alignment: Alignment, ret_addr: usize) ?[*]u8 {
+        const self: *__PolybenchCountingAllocator = @ptrCast(@alignCast(ctx));
+        const ptr = self.child.rawAlloc(len, alignment, ret_addr) orelse return null;
+        self.bytes += len;
+        self.count += 1;
+        return ptr;
+    }
+
+    fn resize(ctx: *anyopaque, memory: []u8, alignment: Alignment, new_len: usize, ret_addr: usize) bool {
+        const self: *__PolybenchCountingAllocator = @ptrCast(@alignCast(ctx));
+        if (!self.child.rawResize(memory, alignment, new_len, ret_addr)) return false;
+        if (new_len > memory.len) self.bytes += new_len - memory.len;
+        return true;
+    }
+
+    fn remap(ctx: *anyopaque, memory: []u8, alignment: Alignment, new_len: usize, ret_addr: usize) ?[*]u8 {
+        const self: *__PolybenchCountingAllocator = @ptrCast(@alignCast(ctx));
+        const ptr = self.child.rawRemap(memory, alignment, new_len, ret_addr) orelse return null;
+        if (new_len > memory.len) self.bytes += new_len - memory.len;
+        return ptr;
+    }
+
+    fn free(ctx: *anyopaque, memory: []u8, alignment: Alignment, ret_addr: usize) void {
+        const self: *__PolybenchCountingAllocator = @ptrCast(@alignCast(ctx));
+        self.child.rawFree(memory, alignment, ret_addr);
+    }
+
+    const vtable: std.mem.Allocator.VTable = blk: {
+        var vt: std.mem.Allocator.VTable = undefined;
+        vt.alloc = alloc;
+        vt.resize = resize;
+        vt.free = free;
+        if (@hasField(std.mem.Allocator.VTable, "remap")) vt.remap = remap;
+        break :blk vt;
+    };
+};
+
+var __polybench_counting = __PolybenchCountingAllocator{
+    .child = if (@import("builtin").link_libc) std.heap.c_allocator else std.heap.page_allocator,
+};
+const polybench_allocator = std.mem.Allocator{
+    .ptr = &__polybench_counting,
+    .vtable = &__PolybenchCountingAllocator.vtable,
+};
+
+"#
+    .to_string()
+}
+
+/// Bytes per op from `polybench_allocator` when the benchmark allocated through it, else from heap
+/// snapshot deltas; sets `__polybench_allocs_json` to `"allocsPerOp":N,` when allocations were
+/// counted
+fn emit_bytes_per_op(iterations: &str) -> String {
+    format!(
+        "    const __iters_nonzero = if ({it} > 0) {it} else @as(u64, 1);\n    const __bytes_per_op = if (__polybench_counting.count > 0) __polybench_counting.bytes / __iters_nonzero else __total_allocated / __iters_nonzero;\n    var __allocs_buf: [48]u8 = undefined;\n    const __polybench_allocs_json: []const u8 = if (__polybench_counting.count > 0) (std.fmt.bufPrint(&__allocs_buf, \"\\\"allocsPerOp\\\":{{}},\", .{{__polybench_counting.count / __iters_nonzero}}) catch \"\") else \"\";\n",
+        it = iterations
+    )
+}
+
 fn emit_memory_helpers() -> String {
-    // Simple memory tracking using OS-level heap stats
+    // Fallback for code that allocates outside `polybench_allocator`: OS-level heap stats.
     // Note: This tracks current heap usage, not cumulative allocations.
     // If allocations are freed within each benchmark iteration, this may show 0.
     r#"fn __polybench_mem_snapshot() u64 {
@@ -880,6 +1011,91 @@ fn emit_memory_helpers() -> String {
     .to_string()
 }
 
+fn emit_counter_helpers() -> String {
+    // Opens instructions, cycles, cache-miss and branch-miss counters for the calling thread
+    // via perf_event_open; counters the kernel refuses are skipped and the first failure is
+    // reported as "error" so the run still succeeds.
+    r#"const __PolybenchPerfEventAttr = extern struct {
+    kind: u32,
+    size: u32,
+    config: u64,
+    sample_period: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events: u32,
+    bp_type: u32,
+    config1: u64,
+};
+
+const __polybench_counter_names = [_][]const u8{ "instructions", "cycles", "cache_misses", "branch_misses" };
+var __polybench_counter_fds = [_]i32{ -1, -1, -1, -1 };
+var __polybench_counter_error: []const u8 = "";
+var __polybench_counter_error_buf: [160]u8 = undefined;
+var __polybench_counters_buf: [512]u8 = undefined;
+
+fn __polybench_counters_start() void {
+    const builtin = @import("builtin");
+    if (builtin.os.tag == .linux) {
+        const linux = std.os.linux;
+        const configs = [_]u64{ 1, 0, 3, 5 };
+        const minus_one: usize = @bitCast(@as(isize, -1));
+        for (configs, 0..) |config, i| {
+            var attr = std.mem.zeroes(__PolybenchPerfEventAttr);
+            attr.size = @sizeOf(__PolybenchPerfEventAttr);
+            attr.config = config;
+            attr.flags = 0x61; // disabled | exclude_kernel | exclude_hv
+            const rc = linux.syscall5(.perf_event_open, @intFromPtr(&attr), 0, minus_one, minus_one, 0);
+            const err = linux.E.init(rc);
+            if (err != .SUCCESS) {
+                if (__polybench_counter_error.len == 0) {
+                    __polybench_counter_error = std.fmt.bufPrint(&__polybench_counter_error_buf, "perf_event_open({s}): {s}", .{ __polybench_counter_names[i], @tagName(err) }) catch "perf_event_open failed";
+                }
+                continue;
+            }
+            __polybench_counter_fds[i] = @intCast(rc);
+        }
+        for (__polybench_counter_fds) |fd| {
+            if (fd < 0) continue;
+            _ = linux.ioctl(fd, 0x2403, 0); // PERF_EVENT_IOC_RESET
+            _ = linux.ioctl(fd, 0x2400, 0); // PERF_EVENT_IOC_ENABLE
+        }
+    }
+}
+
+fn __polybench_counters_stop() void {
+    const builtin = @import("builtin");
+    if (builtin.os.tag == .linux) {
+        const linux = std.os.linux;
+        for (__polybench_counter_fds) |fd| {
+            if (fd >= 0) _ = linux.ioctl(fd, 0x2401, 0); // PERF_EVENT_IOC_DISABLE
+        }
+        const buf = &__polybench_counters_buf;
+        var len: usize = (std.fmt.bufPrint(buf[0..], "\"counters\":{{", .{}) catch return).len;
+        var first = true;
+        for (__polybench_counter_fds, 0..) |fd, i| {
+            if (fd < 0) continue;
+            var value: u64 = 0;
+            if (linux.read(fd, @ptrCast(&value), @sizeOf(u64)) == @sizeOf(u64)) {
+                len += (std.fmt.bufPrint(buf[len..], "{s}\"{s}\":{d}", .{ if (first) "" else ",", __polybench_counter_names[i], value }) catch return).len;
+                first = false;
+            }
+            _ = linux.close(fd);
+        }
+        if (__polybench_counter_error.len > 0) {
+            len += (std.fmt.bufPrint(buf[len..], "{s}\"error\":\"{s}\"", .{ if (first) "" else ",", __polybench_counter_error }) catch return).len;
+        }
+        len += (std.fmt.bufPrint(buf[len..], "}},", .{}) catch return).len;
+        __polybench_counters_json = buf[0..len];
+    } else {
+        __polybench_counters_json = "\"counters\":{\"error\":\"hardware counters require Linux perf_event_open\"},";
+    }
+}
+
+"#
+    .to_string()
+}
+
 fn emit_hook(code: Option<&String>, indent: &str) -> String {
     code.map(|c| {
         ZigRuntime::normalize_indent(c)
@@ -919,7 +1135,12 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         src.push('\n');
     }
 
-    src.push_str("var __polybench_sink: ?*const anyopaque = null;\n\n");
+    src.push_str("var __polybench_sink: ?*const anyopaque = null;\n");
+    src.push_str(&emit_counting_allocator());
+    // Set to `"counters":{...},` by the counter helpers; empty unless `counters: true`
+    src.push_str("var __polybench_counters_json: []const u8 = \"\";\n");
+    // `"validationPassed":...,` once `validate` has run; empty without a validation
+    src.push_str("var __polybench_validation_json: []const u8 = \"\";\n\n");
 
     let stdlib_code = stdlib::get_stdlib_code(&suite.stdlib_imports, &crate::ZIG_STDLIB);
     if !stdlib_code.is_empty() {
@@ -954,6 +1175,7 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
     // Emit fixtures at module level so we can use doNotOptimizeAway (prevents optimizer from
     // eliminating benchmark)
     src.push_str(&emit_fixtures(spec, suite, ""));
+    src.push_str(&param_declarations(&spec.params, Lang::Zig, ""));
     src.push_str("\n");
     src.push_str("fn __polybench_bench() void {\n");
     // Wrap impl code to discard any return value (Zig 0.15+ is strict about unused values)
@@ -972,9 +1194,25 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         src.push_str(";\n");
     }
     src.push_str("}\n\n");
+    // Validation: the implementation is evaluated once more as an expression (bound to `result`)
+    let validation = spec.validations.get(&Lang::Zig);
+    if let Some(check) = validation {
+        src.push_str("fn __polybench_validate() bool {\n");
+        src.push_str(&format!("    const result = {};\n", impl_trimmed.trim_end_matches(';')));
+        src.push_str(&format!("    return {};\n", check.trim()));
+        src.push_str("}\n\n");
+    }
+    let validation_call = if validation.is_some() {
+        "    __polybench_validation_json = if (__polybench_validate()) \"\\\"validationPassed\\\":true,\" else \"\\\"validationPassed\\\":false,\";\n"
+    } else {
+        ""
+    };
     if spec.memory {
         src.push_str(&emit_memory_helpers());
     }
+    if spec.counters {
+        src.push_str(&emit_counter_helpers());
+    }
 
     if check_only {
         src.push_str("pub fn main() void {}\n");
@@ -990,6 +1228,7 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         "    const __ver_0_15 = std.SemanticVersion{ .major = 0, .minor = 15, .patch = 0 };\n",
     );
     src.push_str("    const __is_zig_13_or_14 = comptime (__zig_ver.order(__ver_0_13) != .lt and __zig_ver.order(__ver_0_15) == .lt);\n");
+    src.push_str(&emit_external_fixture_loads(spec, suite));
     if suite.init_code.get(&Lang::Zig).is_some() {
         src.push_str("    __polybench_init();\n");
     }
@@ -1028,14 +1267,22 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         // Track memory using snapshots - accumulate positive deltas
         src.push_str("    var __total_allocated: u64 = 0;\n");
         src.push_str("    var __mem_last = __polybench_mem_snapshot();\n");
+        // Only count allocations made by the timed loop
+        src.push_str("    __polybench_counting.bytes = 0;\n    __polybench_counting.count = 0;\n");
     }
 
     let sample_cap = spec.async_sample_cap;
     let is_fixed_cap = matches!(spec.async_sampling_policy, AsyncSamplingPolicy::FixedCap);
+    let (counters_start, counters_stop) = if spec.counters {
+        ("    __polybench_counters_start();\n", "    __polybench_counters_stop();\n")
+    } else {
+        ("", "")
+    };
 
     if is_async {
         // Async-sequential: one completed call per iteration, no batching
         let target_ns = (spec.target_time_ms as f64) * 1_000_000.0;
+        src.push_str(counters_start);
         if is_fixed_cap {
             src.push_str(&format!(
                 "    const __allocator = std.heap.page_allocator;\n    const sample_cap = {};\n    var total_iterations: u64 = 0;\n    var total_ns: f64 = 0;\n    var successful_count: u64 = 0;\n    const error_count: u64 = 0;\n    var samples = std.ArrayList(f64).initCapacity(__allocator, @intCast(sample_cap)) catch return;\n    defer if (__is_zig_13_or_14) samples.deinit() else samples.deinit(__allocator);\n",
@@ -1058,12 +1305,14 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
                 emit_hook(spec.each_hooks.get(&Lang::Zig), "        ")
             ));
         }
+        src.push_str(counters_stop);
         src.push_str(
             "    const nanos_per_op = total_ns / @as(f64, @floatFromInt(if (total_iterations > 0) total_iterations else 1));\n    const ops_per_sec = 1000000000.0 / (if (nanos_per_op > 0) nanos_per_op else 1.0);\n",
         );
         if use_memory {
-            src.push_str("    const __bytes_per_op = if (total_iterations > 0) __total_allocated / total_iterations else @as(u64, 0);\n");
+            src.push_str(&emit_bytes_per_op("total_iterations"));
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::Zig), "    "));
         src.push_str("    if (__is_zig_13_or_14) {\n");
         src.push_str(
@@ -1071,9 +1320,9 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         );
         src.push_str("        const stdout = __stdout_writer.writer();\n");
         if use_memory {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples.items, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1088,9 +1337,9 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         );
         src.push_str("        const stdout = &__stdout_writer.interface;\n");
         if use_memory {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples.items, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1100,6 +1349,7 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         src.push_str("        stdout.flush() catch {};\n");
         src.push_str("    }\n");
     } else if spec.mode == BenchMode::Auto {
+        src.push_str(counters_start);
         src.push_str(&format!(
             "    const __allocator = std.heap.page_allocator;\n    const target_ns = {:.0};\n    var total_iterations: u64 = 0;\n    var total_ns: f64 = 0;\n    var batch: u64 = 100;\n    var samples = std.ArrayList(f64).initCapacity(__allocator, 16) catch return;\n    defer if (__is_zig_13_or_14) samples.deinit() else samples.deinit(__allocator);\n    while (total_ns < target_ns) {{\n        const t0 = std.time.Instant.now() catch return;\n        for (0..batch) |_| {{\n",
             (spec.target_time_ms as f64) * 1_000_000.0
@@ -1129,12 +1379,14 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         src.push_str(
             "        } else {\n            batch = @min(batch * 2, 1000000);\n        }\n    }\n",
         );
+        src.push_str(counters_stop);
         src.push_str(
             "    const nanos_per_op = total_ns / @as(f64, @floatFromInt(if (total_iterations > 0) total_iterations else 1));\n    const ops_per_sec = 1000000000.0 / (if (nanos_per_op > 0) nanos_per_op else 1.0);\n",
         );
         if use_memory {
-            src.push_str("    const __bytes_per_op = if (total_iterations > 0) __total_allocated / total_iterations else @as(u64, 0);\n");
+            src.push_str(&emit_bytes_per_op("total_iterations"));
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::Zig), "    "));
         if use_memory {
             src.push_str("    if (__is_zig_13_or_14) {\n");
@@ -1142,14 +1394,14 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
                 "        var __stdout_writer = std.io.bufferedWriter(std.io.getStdOut().writer());\n",
             );
             src.push_str("        const stdout = __stdout_writer.writer();\n");
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
             src.push_str("    if (__is_zig_13_or_14) {\n");
             src.push_str(
                 "        var __stdout_writer = std.io.bufferedWriter(std.io.getStdOut().writer());\n",
             );
             src.push_str("        const stdout = __stdout_writer.writer();\n");
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples.items, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1164,9 +1416,9 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         );
         src.push_str("        const stdout = &__stdout_writer.interface;\n");
         if use_memory {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, total_iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples.items, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1176,6 +1428,7 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         src.push_str("        stdout.flush() catch {};\n");
         src.push_str("    }\n");
     } else {
+        src.push_str(counters_start);
         src.push_str(&format!(
             "    const iterations: u64 = {};\n    var samples: [{}]f64 = undefined;\n    for (0..iterations) |i| {{\n        const t0 = std.time.Instant.now() catch return;\n",
             spec.iterations, spec.iterations
@@ -1191,12 +1444,14 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
             src.push_str("        __mem_last = __mem_now;\n");
         }
         src.push_str("    }\n");
+        src.push_str(counters_stop);
         src.push_str(
             "    var total_ns: f64 = 0;\n    for (samples) |s| total_ns += s;\n    const nanos_per_op = total_ns / @as(f64, @floatFromInt(if (iterations > 0) iterations else 1));\n    const ops_per_sec = 1000000000.0 / (if (nanos_per_op > 0) nanos_per_op else 1.0);\n",
         );
         if use_memory {
-            src.push_str("    const __bytes_per_op = if (iterations > 0) __total_allocated / iterations else @as(u64, 0);\n");
+            src.push_str(&emit_bytes_per_op("iterations"));
         }
+        src.push_str(validation_call);
         src.push_str(&emit_hook(spec.after_hooks.get(&Lang::Zig), "    "));
         if use_memory {
             src.push_str("    if (__is_zig_13_or_14) {\n");
@@ -1204,14 +1459,14 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
                 "        var __stdout_writer = std.io.bufferedWriter(std.io.getStdOut().writer());\n",
             );
             src.push_str("        const stdout = __stdout_writer.writer();\n");
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
             src.push_str("    if (__is_zig_13_or_14) {\n");
             src.push_str(
                 "        var __stdout_writer = std.io.bufferedWriter(std.io.getStdOut().writer());\n",
             );
             src.push_str("        const stdout = __stdout_writer.writer();\n");
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1226,9 +1481,9 @@ fn generate_zig_source(spec: &BenchmarkSpec, suite: &SuiteIR, check_only: bool)
         );
         src.push_str("        const stdout = &__stdout_writer.interface;\n");
         if use_memory {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"bytesPerOp\\\":{},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, __polybench_allocs_json, iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec, __bytes_per_op });\n");
         } else {
-            src.push_str("        try stdout.print(\"{{\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
+            src.push_str("        try stdout.print(\"{{{s}{s}\\\"iterations\\\":{},\\\"totalNanos\\\":{d:.0},\\\"warmupNanos\\\":{},\\\"nanosPerOp\\\":{d:.6},\\\"opsPerSec\\\":{d:.6},\\\"samples\\\":[\", .{ __polybench_counters_json, __polybench_validation_json, iterations, total_ns, __warmup_nanos, nanos_per_op, ops_per_sec });\n");
         }
         src.push_str("        for (samples, 0..) |s, i| {\n");
         src.push_str("            if (i > 0) _ = stdout.writeAll(\",\") catch {};\n");
@@ -1266,6 +1521,10 @@ struct BenchResultJson {
     successful_results: Vec<String>,
     #[serde(default)]
     error_samples: Vec<String>,
+    #[serde(default)]
+    counters: Option<CounterTotals>,
+    #[serde(default)]
+    validation_passed: Option<bool>,
 }
 
 impl BenchResultJson {
@@ -1289,7 +1548,7 @@ impl BenchResultJson {
             m.warmup_nanos = Some(w as u64);
         }
         if let Some(bytes) = self.bytes_per_op {
-            m = m.with_allocs(bytes, self.allocs_per_op.unwrap_or(0));
+            m = m.with_memory(bytes, self.allocs_per_op);
         }
         if let Some(sc) = self.successful_count {
             m.async_success_count = Some(sc);
@@ -1300,15 +1559,12 @@ impl BenchResultJson {
         if !self.error_samples.is_empty() {
             m.async_error_samples = Some(self.error_samples);
         }
+        m.counters = self.counters.map(|c| c.per_op(self.iterations));
         m
     }
 }
 
-fn parse_benchmark_result(
-    stdout: &str,
-    outlier_detection: bool,
-    cv_threshold: f64,
-) -> Result<Measurement> {
+fn parse_benchmark_result(stdout: &str, spec: &BenchmarkSpec) -> Result<Measurement> {
     let json_line = stdout
         .lines()
         .filter(|l| !l.trim().is_empty())
@@ -1316,5 +1572,49 @@ fn parse_benchmark_result(
         .ok_or_else(|| miette!("No benchmark output from Zig runtime"))?;
     let result: BenchResultJson = serde_json::from_str(json_line)
         .map_err(|e| miette!("Failed to parse Zig benchmark output: {}\n{}", e, stdout))?;
-    Ok(result.into_measurement(outlier_detection, cv_threshold))
+    let validation_passed = result.validation_passed;
+    let mut m = result.into_measurement(spec.outlier_detection, spec.cv_threshold);
+    m.record_validation(spec.validations.get(&Lang::Zig), validation_passed);
+    Ok(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validated_spec() -> (BenchmarkSpec, SuiteIR) {
+        let suite = SuiteIR::new("snap".to_string());
+        let mut spec = BenchmarkSpec::new("add".to_string(), "snap", 10, 5, 0);
+        spec.implementations.insert(Lang::Zig, "1 + 2".to_string());
+        spec.validations.insert(Lang::Zig, "result == 3".to_string());
+        (spec, suite)
+    }
+
+    #[test]
+    fn test_validation_runs_after_timing() {
+        let (spec, suite) = validated_spec();
+        let src = generate_zig_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("const result = 1 + 2;"));
+        assert!(src.contains("result == 3"));
+        assert!(src.contains("validationPassed"));
+    }
+
+    #[test]
+    fn test_no_validation_without_expression() {
+        let (mut spec, suite) = validated_spec();
+        spec.validations.clear();
+        let src = generate_zig_source(&spec, &suite, false).unwrap();
+        assert!(!src.contains("__polybench_validate()"));
+    }
+
+    #[test]
+    fn test_memory_reports_counting_allocator_allocs() {
+        let (mut spec, suite) = validated_spec();
+        spec.memory = true;
+        let src = generate_zig_source(&spec, &suite, false).unwrap();
+        assert!(src.contains("polybench_allocator"));
+        assert!(src.contains("__polybench_counting.count / __iters_nonzero"));
+        assert!(src.contains("__polybench_allocs_json"));
+        assert!(src.contains("\\\"bytesPerOp\\\":{}"));
+    }
 }
@@ -4,6 +4,7 @@ use std::collections::HashSet;
 
 use poly_bench_traits::HelperFunctionExtractor;
 
+/// Builtins, plus the keywords that can be followed by `(`, skipped by undefined-function
 static ZIG_BUILTINS: &[&str] = &[
     "@import",
     "@ptrCast",
@@ -18,6 +19,17 @@ static ZIG_BUILTINS: &[&str] = &[
     "std.posix",
     "std.ArrayList",
     "std.heap",
+    "if",
+    "for",
+    "while",
+    "switch",
+    "return",
+    "catch",
+    "orelse",
+    "try",
+    "defer",
+    "errdefer",
+    "comptime",
 ];
 
 pub(crate) struct ZigHelperFunctionExtractor;
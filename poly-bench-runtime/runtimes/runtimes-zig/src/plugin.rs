@@ -38,6 +38,10 @@ impl RuntimePlugin for ZigPlugin {
         zig_lang_display()
     }
 
+    fn memory_metric_note(&self) -> Option<&'static str> {
+        Some("allocation counts cover polybench_allocator only")
+    }
+
     fn project_root_detector(&self) -> Option<&'static dyn ProjectRootDetector> {
         Some(&ZIG_DETECTOR)
     }
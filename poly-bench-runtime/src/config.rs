@@ -1,3 +1,3 @@
 //! Runtime configuration - re-export from poly-bench-traits
 
-pub use poly_bench_traits::RuntimeConfig;
+pub use poly_bench_traits::{RuntimeConfig, VariantToolchain};
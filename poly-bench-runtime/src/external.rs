@@ -0,0 +1,704 @@
+//! External runtimes driven over a JSON-over-stdio protocol
+//!
+//! Languages without a built-in runtime (Java, Ruby, ...) can be declared in `polybench.toml`:
+//!
+//! ```toml
+//! [runtimes.java]
+//! command = ["java", "-cp", "tools/host", "PolybenchHost"]
+//! template = "tools/Bench.java.tmpl"
+//! extension = "java"
+//!
+//! [runtimes.java.result]
+//! total_nanos = "/timing/total"
+//! ```
+//!
+//! For every benchmark the adapter renders `template` (see [`render_source`] for the
+//! placeholders), writes it under `.polybench/runtime-env/<name>/` and asks a long-lived host
+//! process (`command`) to compile or run it. Requests and responses are single-line JSON
+//! objects on the host's stdin/stdout:
+//!
+//! - `{"id":1,"type":"initialize","protocol":1,"lang":"java","workDir":"..."}`
+//! - `{"id":2,"type":"compile","benchmark":"...","source":"...","sourcePath":"..."}`
+//! - `{"id":3,"type":"run", ...compile fields, "iterations":..., "mode":"auto", ...}`; fixture data
+//!   is sent as hex in `fixtures`, except large ones, whose paths are in `fixtureFiles`
+//! - `{"id":4,"type":"shutdown"}`
+//!
+//! Each request is answered by `{"id":N,"ok":true,"result":{...}}` or
+//! `{"id":N,"ok":false,"error":"..."}`; other stdout lines are ignored. `run` results are read
+//! through the runtime's [`ResultSchema`], which defaults to the JSON shape the built-in
+//! harnesses print (`iterations`, `totalNanos`, `samples`, ...). Host stderr is written to
+//! `host.log` in the work directory.
+
+use crate::lang_display::intern;
+use async_trait::async_trait;
+use miette::{miette, Result};
+use once_cell::sync::Lazy;
+use poly_bench_dsl::{BenchmarkKind, ExternalLang, Lang};
+use poly_bench_ir::{BenchmarkSpec, SuiteIR};
+use poly_bench_traits::{LangDisplayInfo, Measurement, Runtime, RuntimeConfig};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::{Arc, RwLock},
+    time::Instant,
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    process::{Child, ChildStdin, ChildStdout, Command},
+    sync::Mutex,
+};
+
+/// Version of the stdio protocol sent in the `initialize` request
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// An external runtime declared under `[runtimes.<name>]` in `polybench.toml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalRuntimeSpec {
+    /// Host program and arguments; relative paths resolve against the project root
+    pub command: Vec<String>,
+    /// Source template rendered for each benchmark (relative to the project root)
+    pub template: PathBuf,
+    /// File extension of rendered sources
+    #[serde(default = "default_extension")]
+    pub extension: String,
+    /// Display label in reports (defaults to the runtime name)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Hex color for charts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Where to find measurement fields in `run` results
+    #[serde(default)]
+    pub result: ResultSchema,
+}
+
+fn default_extension() -> String {
+    "txt".to_string()
+}
+
+/// JSON pointers (RFC 6901) locating measurement fields in a `run` result.
+///
+/// `iterations` and `total_nanos` are required; the rest are read when present.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultSchema {
+    pub iterations: String,
+    pub total_nanos: String,
+    /// Array of per-sample nanoseconds
+    pub samples: String,
+    pub warmup_nanos: String,
+    pub bytes_per_op: String,
+    pub allocs_per_op: String,
+    /// Stringified return value of the benchmark body
+    pub raw_result: String,
+}
+
+impl Default for ResultSchema {
+    fn default() -> Self {
+        Self {
+            iterations: "/iterations".to_string(),
+            total_nanos: "/totalNanos".to_string(),
+            samples: "/samples".to_string(),
+            warmup_nanos: "/warmupNanos".to_string(),
+            bytes_per_op: "/bytesPerOp".to_string(),
+            allocs_per_op: "/allocsPerOp".to_string(),
+            raw_result: "/rawResult".to_string(),
+        }
+    }
+}
+
+impl ResultSchema {
+    /// Convert a `run` result into a measurement
+    pub fn measurement(
+        &self,
+        result: &Value,
+        outlier_detection: bool,
+        cv_threshold: f64,
+    ) -> Result<Measurement> {
+        let iterations = self
+            .number(result, &self.iterations)
+            .ok_or_else(|| miette!("run result has no iterations at '{}'", self.iterations))?;
+        let total_nanos = self
+            .number(result, &self.total_nanos)
+            .ok_or_else(|| miette!("run result has no total nanos at '{}'", self.total_nanos))?;
+        if iterations == 0 {
+            return Err(miette!("run result reports zero iterations"));
+        }
+
+        let samples: Vec<u64> = result
+            .pointer(&self.samples)
+            .and_then(Value::as_array)
+            .map(|s| s.iter().filter_map(Value::as_f64).map(|n| n as u64).collect())
+            .unwrap_or_default();
+        let mut m = if samples.is_empty() {
+            Measurement::from_aggregate(iterations, total_nanos)
+        } else {
+            Measurement::from_aggregate_with_sample_stats(
+                iterations,
+                total_nanos,
+                samples,
+                outlier_detection,
+                cv_threshold,
+            )
+        };
+
+        if let Some(bytes) = self.number(result, &self.bytes_per_op) {
+            m = m.with_memory(bytes, self.number(result, &self.allocs_per_op));
+        }
+        m.warmup_nanos = self.number(result, &self.warmup_nanos);
+        m.raw_result = result.pointer(&self.raw_result).map(|v| match v {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        });
+        Ok(m)
+    }
+
+    fn number(&self, result: &Value, pointer: &str) -> Option<u64> {
+        result.pointer(pointer).and_then(Value::as_f64).map(|n| n as u64)
+    }
+}
+
+/// A registered external runtime, resolved against its project
+struct ExternalRuntimeEntry {
+    spec: ExternalRuntimeSpec,
+    /// Template contents, read at registration
+    template: String,
+    /// Project root the runtime was declared in
+    project_root: PathBuf,
+    display: LangDisplayInfo,
+}
+
+static RUNTIMES: Lazy<RwLock<HashMap<Lang, Arc<ExternalRuntimeEntry>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register the external runtimes declared in a project's manifest, replacing those of any
+/// previously registered project.
+///
+/// Must run before `.bench` files are parsed so their language blocks are recognized. Returns
+/// the registered languages.
+pub fn register_external_runtimes(
+    project_root: &Path,
+    specs: &BTreeMap<String, ExternalRuntimeSpec>,
+) -> Result<Vec<Lang>> {
+    RUNTIMES.write().unwrap_or_else(|e| e.into_inner()).clear();
+    ExternalLang::clear();
+    let mut langs = Vec::new();
+    for (name, spec) in specs {
+        if name.is_empty() ||
+            !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(miette!("Invalid runtime name '{}': use letters, digits, '_' or '-'", name));
+        }
+        if Lang::from_str(name).is_some_and(|l| !l.is_external()) {
+            return Err(miette!(
+                "[runtimes.{}] conflicts with the built-in {} runtime",
+                name,
+                Lang::from_str(name).unwrap().grammar_display_name()
+            ));
+        }
+        if spec.command.is_empty() {
+            return Err(miette!("[runtimes.{}] command must not be empty", name));
+        }
+        let template_path = project_root.join(&spec.template);
+        let template = std::fs::read_to_string(&template_path).map_err(|e| {
+            miette!(
+                "[runtimes.{}] failed to read template {}: {}",
+                name,
+                template_path.display(),
+                e
+            )
+        })?;
+
+        let lang = ExternalLang::register(name);
+        let label = intern(spec.label.clone().unwrap_or_else(|| lang.as_str().to_string()));
+        let display = LangDisplayInfo::new(
+            label,
+            label,
+            intern(spec.color.clone().unwrap_or_else(|| "#9CA3AF".to_string())),
+            intern(format!("{}Grad", lang.as_str())),
+            "#4B5563",
+            "white",
+        );
+        let entry = ExternalRuntimeEntry {
+            spec: spec.clone(),
+            template,
+            project_root: project_root.to_path_buf(),
+            display,
+        };
+        RUNTIMES.write().unwrap_or_else(|e| e.into_inner()).insert(lang, Arc::new(entry));
+        langs.push(lang);
+    }
+    Ok(langs)
+}
+
+impl ExternalRuntimeEntry {
+    fn program(&self) -> PathBuf {
+        let program = Path::new(&self.spec.command[0]);
+        if program.components().count() > 1 {
+            self.project_root.join(program)
+        } else {
+            program.to_path_buf()
+        }
+    }
+}
+
+/// External languages that have a registered runtime, in registration order
+pub fn external_languages() -> Vec<Lang> {
+    let runtimes = RUNTIMES.read().unwrap_or_else(|e| e.into_inner());
+    ExternalLang::registered().into_iter().filter(|l| runtimes.contains_key(l)).collect()
+}
+
+/// Host program of a registered external runtime (resolved against its project root when it
+/// is a relative path)
+pub fn external_program(lang: Lang) -> Option<PathBuf> {
+    let runtimes = RUNTIMES.read().unwrap_or_else(|e| e.into_inner());
+    runtimes.get(&lang).map(|e| e.program())
+}
+
+/// Display info for a registered external runtime
+pub fn external_display(lang: Lang) -> Option<LangDisplayInfo> {
+    RUNTIMES.read().unwrap_or_else(|e| e.into_inner()).get(&lang).map(|e| e.display)
+}
+
+/// Create the runtime for a registered external language
+pub fn create_external_runtime(lang: Lang, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
+    let entry = RUNTIMES.read().unwrap_or_else(|e| e.into_inner()).get(&lang).cloned().ok_or_else(
+        || miette!("No runtime configured for '{}'; declare it under [runtimes.{}]", lang, lang),
+    )?;
+    let work_dir = config
+        .get_root(lang)
+        .unwrap_or_else(|| entry.project_root.join(".polybench/runtime-env").join(lang.as_str()));
+    Ok(Box::new(ExternalRuntime {
+        lang,
+        entry,
+        work_dir,
+        host: Mutex::new(None),
+        anvil_rpc_url: None,
+        last_precompile_nanos: None,
+    }))
+}
+
+/// Render a runtime's source template for one benchmark.
+///
+/// Placeholders are written `{{name}}`:
+/// - `bench`, `before`, `after`, `each`: benchmark body and lifecycle hooks
+/// - `imports`, `declarations`, `init`, `helpers`, `fixtures`: suite-level code for the language
+/// - `name`, `full_name`, `suite`, `iterations`, `warmup_iterations`, `target_time_ms`, `mode`,
+///   `sink`, `memory`, `async`: benchmark settings
+///
+/// Unknown placeholders are an error so typos surface at compile validation.
+pub fn render_source(
+    template: &str,
+    lang: Lang,
+    spec: &BenchmarkSpec,
+    suite: &SuiteIR,
+) -> Result<String> {
+    let code = |map: &HashMap<Lang, String>| map.get(&lang).cloned().unwrap_or_default();
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| miette!("Unclosed '{{{{' in runtime template"))?;
+        let value = match after[..end].trim() {
+            "bench" => spec
+                .get_impl(lang)
+                .ok_or_else(|| miette!("No {} implementation for benchmark {}", lang, spec.name))?
+                .to_string(),
+            "before" => code(&spec.before_hooks),
+            "after" => code(&spec.after_hooks),
+            "each" => code(&spec.each_hooks),
+            "imports" => suite.imports.get(&lang).map(|i| i.join("\n")).unwrap_or_default(),
+            "declarations" => code(&suite.declarations),
+            "init" => code(&suite.init_code),
+            "helpers" => code(&suite.helpers),
+            "fixtures" => suite
+                .fixtures
+                .iter()
+                .filter_map(|f| f.implementations.get(&lang).cloned())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "name" => spec.name.clone(),
+            "full_name" => spec.full_name.clone(),
+            "suite" => suite.name.clone(),
+            "iterations" => spec.iterations.to_string(),
+            "warmup_iterations" => spec.warmup_iterations.to_string(),
+            "target_time_ms" => spec.target_time_ms.to_string(),
+            "mode" => spec.mode.as_str().to_string(),
+            "sink" => spec.use_sink.to_string(),
+            "memory" => spec.memory.to_string(),
+            "async" => (spec.kind == BenchmarkKind::Async).to_string(),
+            other => {
+                return Err(miette!("Unknown placeholder '{{{{{}}}}}' in runtime template", other))
+            }
+        };
+        out.push_str(&value);
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// A running host process
+struct Host {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+    next_id: u64,
+    /// A request was sent but its response never read (the caller was cancelled, e.g. by a
+    /// timeout); the host may still be busy, so it is restarted before the next request.
+    in_flight: bool,
+}
+
+/// Runtime adapter for an external host process
+pub struct ExternalRuntime {
+    lang: Lang,
+    entry: Arc<ExternalRuntimeEntry>,
+    work_dir: PathBuf,
+    host: Mutex<Option<Host>>,
+    anvil_rpc_url: Option<String>,
+    last_precompile_nanos: Option<u64>,
+}
+
+impl ExternalRuntime {
+    fn source_path(&self, spec: &BenchmarkSpec) -> PathBuf {
+        self.work_dir.join(format!("{}.{}", spec.full_name, self.entry.spec.extension))
+    }
+
+    /// Render and write the benchmark source, returning the request fields describing it
+    fn write_source(&self, spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<Value> {
+        let source = render_source(&self.entry.template, self.lang, spec, suite)?;
+        std::fs::create_dir_all(&self.work_dir)
+            .map_err(|e| miette!("Failed to create {}: {}", self.work_dir.display(), e))?;
+        let path = self.source_path(spec);
+        std::fs::write(&path, &source)
+            .map_err(|e| miette!("Failed to write {}: {}", path.display(), e))?;
+        Ok(json!({
+            "benchmark": spec.full_name,
+            "source": source,
+            "sourcePath": path,
+        }))
+    }
+
+    fn log_path(&self) -> PathBuf {
+        self.work_dir.join("host.log")
+    }
+
+    /// Last lines of the host's stderr log, for error messages
+    fn log_tail(&self) -> String {
+        let log = std::fs::read_to_string(self.log_path()).unwrap_or_default();
+        let lines: Vec<&str> = log.lines().collect();
+        lines[lines.len().saturating_sub(20)..].join("\n")
+    }
+
+    async fn spawn_host(&self) -> Result<Host> {
+        std::fs::create_dir_all(&self.work_dir)
+            .map_err(|e| miette!("Failed to create {}: {}", self.work_dir.display(), e))?;
+        let log = std::fs::File::create(self.log_path())
+            .map_err(|e| miette!("Failed to create host log: {}", e))?;
+
+        let program = self.entry.program();
+        let mut cmd = Command::new(&program);
+        // The host runs the benchmarks in-process, so it gets the benchmark isolation
+        poly_bench_traits::isolate_benchmark_command(cmd.as_std_mut());
+        cmd.args(&self.entry.spec.command[1..])
+            .current_dir(&self.entry.project_root)
+            .env("POLYBENCH_WORK_DIR", &self.work_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(log)
+            .kill_on_drop(true);
+        if let Some(ref url) = self.anvil_rpc_url {
+            cmd.env("ANVIL_RPC_URL", url);
+        }
+        let mut child = cmd.spawn().map_err(|e| {
+            miette!("Failed to start {} runtime host '{}': {}", self.lang, program.display(), e)
+        })?;
+        let stdin = child.stdin.take().ok_or_else(|| miette!("host stdin unavailable"))?;
+        let stdout = child.stdout.take().ok_or_else(|| miette!("host stdout unavailable"))?;
+        let mut host = Host {
+            child,
+            stdin,
+            stdout: BufReader::new(stdout).lines(),
+            next_id: 1,
+            in_flight: false,
+        };
+
+        self.exchange(
+            &mut host,
+            json!({
+                "type": "initialize",
+                "protocol": PROTOCOL_VERSION,
+                "lang": self.lang.as_str(),
+                "workDir": self.work_dir,
+            }),
+        )
+        .await?;
+        Ok(host)
+    }
+
+    /// Send a request, starting (or restarting) the host as needed, and return its `result`
+    async fn request(&self, request: Value) -> Result<Value> {
+        let mut guard = self.host.lock().await;
+        if guard.as_ref().is_some_and(|h| h.in_flight) {
+            *guard = None;
+        }
+        if guard.is_none() {
+            *guard = Some(self.spawn_host().await?);
+        }
+        let host = guard.as_mut().expect("host started above");
+        let result = self.exchange(host, request).await;
+        if result.is_err() && !matches!(host.child.try_wait(), Ok(None)) {
+            *guard = None;
+        }
+        result
+    }
+
+    async fn exchange(&self, host: &mut Host, mut request: Value) -> Result<Value> {
+        let id = host.next_id;
+        host.next_id += 1;
+        request["id"] = json!(id);
+        let kind = request["type"].as_str().unwrap_or("request").to_string();
+
+        let mut line = request.to_string();
+        line.push('\n');
+        host.in_flight = true;
+        host.stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| self.host_error(&format!("failed to send {} request: {}", kind, e)))?;
+        host.stdin.flush().await.map_err(|e| self.host_error(&e.to_string()))?;
+
+        loop {
+            let line = host
+                .stdout
+                .next_line()
+                .await
+                .map_err(|e| self.host_error(&e.to_string()))?
+                .ok_or_else(|| self.host_error(&format!("host exited during {}", kind)))?;
+            let Ok(response) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            if response.get("id").and_then(Value::as_u64) != Some(id) {
+                continue;
+            }
+            host.in_flight = false;
+            if response.get("ok").and_then(Value::as_bool) == Some(true) {
+                return Ok(response.get("result").cloned().unwrap_or(Value::Null));
+            }
+            let error = response.get("error").and_then(Value::as_str).unwrap_or("unknown error");
+            return Err(miette!("{} {} failed:\n{}", self.lang, kind, error));
+        }
+    }
+
+    fn host_error(&self, message: &str) -> miette::Report {
+        let tail = self.log_tail();
+        if tail.is_empty() {
+            miette!("{} runtime host: {}", self.lang, message)
+        } else {
+            miette!("{} runtime host: {}\n{}", self.lang, message, tail)
+        }
+    }
+}
+
+#[async_trait]
+impl Runtime for ExternalRuntime {
+    fn name(&self) -> &'static str {
+        "External Runtime"
+    }
+
+    fn lang(&self) -> Lang {
+        self.lang
+    }
+
+    fn set_anvil_rpc_url(&mut self, url: String) {
+        self.anvil_rpc_url = Some(url);
+    }
+
+    fn last_precompile_nanos(&self) -> Option<u64> {
+        self.last_precompile_nanos
+    }
+
+    async fn initialize(&mut self, _suite: &SuiteIR) -> Result<()> {
+        let mut guard = self.host.lock().await;
+        if guard.is_none() {
+            *guard = Some(self.spawn_host().await?);
+        }
+        Ok(())
+    }
+
+    fn generate_check_source(&self, spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<String> {
+        render_source(&self.entry.template, self.lang, spec, suite)
+    }
+
+    async fn compile_check(&self, spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<()> {
+        let mut request = self.write_source(spec, suite)?;
+        request["type"] = json!("compile");
+        self.request(request).await.map(|_| ())
+    }
+
+    async fn precompile(&mut self, spec: &BenchmarkSpec, suite: &SuiteIR) -> Result<()> {
+        let start = Instant::now();
+        let mut request = self.write_source(spec, suite)?;
+        request["type"] = json!("compile");
+        self.request(request).await?;
+        self.last_precompile_nanos = Some(start.elapsed().as_nanos() as u64);
+        Ok(())
+    }
+
+    async fn run_benchmark(
+        &mut self,
+        spec: &BenchmarkSpec,
+        suite: &SuiteIR,
+    ) -> Result<Measurement> {
+        let mut request = self.write_source(spec, suite)?;
+        let (files, inline): (Vec<_>, Vec<_>) =
+            suite.fixtures.iter().partition(|f| f.external_file.is_some());
+        let fixtures: serde_json::Map<String, Value> =
+            inline.iter().map(|f| (f.name.clone(), json!(f.as_hex()))).collect();
+        let fixture_files: serde_json::Map<String, Value> = files
+            .iter()
+            .filter_map(|f| Some((f.name.clone(), json!(f.external_file.as_ref()?))))
+            .collect();
+        let fields = json!({
+            "type": "run",
+            "iterations": spec.iterations,
+            "warmupIterations": spec.warmup_iterations,
+            "warmupTimeMs": spec.warmup_time_ms,
+            "mode": spec.mode.as_str(),
+            "targetTimeMs": spec.target_time_ms,
+            "timeoutMs": spec.timeout,
+            "sink": spec.use_sink,
+            "memory": spec.memory,
+            "async": spec.kind == BenchmarkKind::Async,
+            "fixtures": fixtures,
+            "fixtureFiles": fixture_files,
+        });
+        for (key, value) in fields.as_object().expect("object literal") {
+            request[key] = value.clone();
+        }
+
+        let start = Instant::now();
+        let result = self.request(request).await?;
+        let wall_nanos = start.elapsed().as_nanos() as u64;
+        let mut m = self
+            .entry
+            .spec
+            .result
+            .measurement(&result, spec.outlier_detection, spec.cv_threshold)
+            .map_err(|e| miette!("{} benchmark {}: {}", self.lang, spec.full_name, e))?;
+        m.spawn_nanos = Some(
+            wall_nanos.saturating_sub(m.total_nanos).saturating_sub(m.warmup_nanos.unwrap_or(0)),
+        );
+        Ok(m)
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        let mut guard = self.host.lock().await;
+        if let Some(mut host) = guard.take() {
+            if !host.in_flight {
+                let _ = self.exchange(&mut host, json!({ "type": "shutdown" })).await;
+            }
+            let _ = host.child.kill().await;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_source_substitutes_placeholders() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let lang = ExternalLang::register("ruby-render-test");
+        let mut suite = SuiteIR::new("hash".to_string());
+        suite.imports.insert(lang, vec!["require 'digest'".to_string()]);
+        let mut spec = BenchmarkSpec::new("sha".to_string(), "hash", 100, 10, 0);
+        spec.implementations.insert(lang, "Digest::SHA256.digest(DATA)".to_string());
+
+        let source = render_source(
+            "{{imports}}\n# {{ full_name }} x{{iterations}}\nbench { {{bench}} }{{each}}",
+            lang,
+            &spec,
+            &suite,
+        )
+        .unwrap();
+        assert_eq!(
+            source,
+            "require 'digest'\n# hash_sha x100\nbench { Digest::SHA256.digest(DATA) }"
+        );
+
+        let err = render_source("{{bogus}}", lang, &spec, &suite).unwrap_err();
+        assert!(err.to_string().contains("Unknown placeholder '{{bogus}}'"));
+    }
+
+    #[test]
+    fn test_external_lang_serde_roundtrip() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let lang = ExternalLang::register("ruby-serde-test");
+        let json = serde_json::to_string(&vec![Lang::Go, lang]).unwrap();
+        assert_eq!(json, r#"["Go","ruby-serde-test"]"#);
+        let back: Vec<Lang> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, vec![Lang::Go, lang]);
+        // Unregistered names are rejected rather than interned
+        let err = serde_json::from_str::<Lang>(r#""crystal-serde-test""#).unwrap_err();
+        assert!(err.to_string().contains("unknown language 'crystal-serde-test'"));
+        assert!(ExternalLang::lookup("crystal-serde-test").is_none());
+    }
+
+    #[test]
+    fn test_reregistering_replaces_previous_project_runtimes() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let spec: ExternalRuntimeSpec = serde_json::from_value(
+            json!({"command": ["ruby"], "template": "Cargo.toml", "label": "Ruby"}),
+        )
+        .unwrap();
+        let register = |name: &str| {
+            register_external_runtimes(root, &BTreeMap::from([(name.to_string(), spec.clone())]))
+                .unwrap()[0]
+        };
+
+        let first = register("ruby-first-test");
+        assert_eq!(external_languages(), vec![first]);
+        let second = register("ruby-second-test");
+        assert_eq!(external_languages(), vec![second]);
+        assert_eq!(Lang::from_str("ruby-first-test"), None);
+        assert!(external_display(first).is_none());
+
+        // Registering the first project again reuses its interned name and display strings
+        let label = external_display(second).unwrap().label;
+        assert_eq!(register("ruby-first-test"), first);
+        assert!(std::ptr::eq(external_display(first).unwrap().label, label));
+    }
+
+    #[test]
+    fn test_result_schema_default_and_custom_pointers() {
+        let m = ResultSchema::default()
+            .measurement(
+                &json!({"iterations": 4, "totalNanos": 400.0, "samples": [90, 100, 110, 100]}),
+                false,
+                5.0,
+            )
+            .unwrap();
+        assert_eq!(m.nanos_per_op, 100.0);
+        assert_eq!(m.samples, Some(4));
+
+        let schema = ResultSchema {
+            iterations: "/n".to_string(),
+            total_nanos: "/timing/total".to_string(),
+            ..ResultSchema::default()
+        };
+        let m = schema
+            .measurement(&json!({"n": 10, "timing": {"total": 50}, "rawResult": 7}), true, 5.0)
+            .unwrap();
+        assert_eq!(m.nanos_per_op, 5.0);
+        assert_eq!(m.raw_result.as_deref(), Some("7"));
+        assert!(schema.measurement(&json!({"n": 10}), true, 5.0).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! Dispatches to runtime plugins for language-specific display info.
 
+use once_cell::sync::Lazy;
 use poly_bench_dsl::Lang;
 use poly_bench_traits::LangDisplayInfo;
 use runtimes_c::C_PLUGIN;
@@ -11,10 +12,15 @@ use runtimes_python::PYTHON_PLUGIN;
 use runtimes_rust::RUST_PLUGIN;
 use runtimes_ts::TS_PLUGIN;
 use runtimes_zig::ZIG_PLUGIN;
+use std::{collections::HashSet, sync::Mutex};
 
 static PLUGINS: &[&dyn poly_bench_traits::RuntimePlugin] =
     &[&GO_PLUGIN, &TS_PLUGIN, &RUST_PLUGIN, &PYTHON_PLUGIN, &C_PLUGIN, &CSHARP_PLUGIN, &ZIG_PLUGIN];
 
+/// Display strings of external runtimes and variants; projects are re-registered whenever they
+/// are loaded, so each distinct string is leaked only once
+static INTERNED: Lazy<Mutex<HashSet<&'static str>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
 const FALLBACK: LangDisplayInfo =
     LangDisplayInfo::new("Unknown", "Unknown", "#9CA3AF", "goGrad", "#0891B2", "white");
 
@@ -26,9 +32,34 @@ pub fn lang_icon(lang: Lang) -> &'static str {
 /// Get display info for a language.
 /// Returns fallback for unsupported languages.
 pub fn lang_display(lang: Lang) -> LangDisplayInfo {
+    if lang.is_external() {
+        return crate::external::external_display(lang).unwrap_or(LangDisplayInfo {
+            label: lang.as_str(),
+            full_name: lang.as_str(),
+            ..FALLBACK
+        });
+    }
+    if lang.is_variant() {
+        return crate::variant::variant_display(lang).unwrap_or(LangDisplayInfo {
+            label: lang.as_str(),
+            full_name: lang.as_str(),
+            ..lang_display(lang.base())
+        });
+    }
     PLUGINS.iter().find(|p| p.lang() == lang).map(|p| p.lang_display()).unwrap_or(FALLBACK)
 }
 
+/// Intern a display string declared in `polybench.toml`
+pub(crate) fn intern(s: String) -> &'static str {
+    let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(&existing) = interned.get(s.as_str()) {
+        return existing;
+    }
+    let s: &'static str = Box::leak(s.into_boxed_str());
+    interned.insert(s);
+    s
+}
+
 /// Short label for console (alias for lang_display(lang).label)
 pub fn lang_label(lang: Lang) -> &'static str {
     lang_display(lang).label
@@ -4,14 +4,19 @@
 
 pub mod config;
 pub mod error_mapping;
+pub mod external;
 /// Re-export Go runtime from runtimes-go for backward compatibility
 pub mod go {
-    pub use runtimes_go::{codegen, GoRuntime};
+    pub use runtimes_go::{codegen, executor::SOURCE_EXTENSIONS, GoRuntime};
 }
 /// Re-export TypeScript runtime from runtimes-ts for backward compatibility
 /// Re-export Rust runtime from runtimes-rust for backward compatibility
 pub mod rust {
-    pub use runtimes_rust::{codegen, RustRuntime};
+    pub use runtimes_rust::{
+        codegen,
+        executor::{path_dependencies, SOURCE_EXTENSIONS},
+        RustRuntime,
+    };
 }
 pub mod js {
     pub use runtimes_ts::{
@@ -34,13 +39,15 @@ pub mod csharp {
 }
 /// Re-export Zig runtime from runtimes-zig for backward compatibility
 pub mod zig {
-    pub use runtimes_zig::ZigRuntime;
+    pub use runtimes_zig::{executor::SOURCE_EXTENSIONS, ZigRuntime};
 }
 pub mod registry;
 pub mod traits;
+pub mod variant;
 
 pub use config::RuntimeConfig;
 pub use error_mapping::get_error_mapper;
+pub use external::{register_external_runtimes, ExternalRuntimeSpec};
 pub use js::{extract_generated_snippet, extract_runtime_error_reason};
 pub use lang_display::{
     lang_color, lang_display, lang_full_name, lang_gradient_end, lang_gradient_id, lang_icon,
@@ -48,13 +55,20 @@ pub use lang_display::{
 };
 pub use measurement::Measurement;
 pub use poly_bench_traits::{
-    detect_from_markers, ErrorMapper, LangDisplayInfo, LineMapping, LineMappings,
-    ProjectRootDetector, RuntimeFactory,
+    benchmark_isolation, detect_from_markers, set_benchmark_isolation, source_files, ArtifactCache,
+    ArtifactCacheStats, ArtifactKey, BenchmarkIsolation, ErrorMapper, LangDisplayInfo, LineMapping,
+    LineMappings, ProjectRootDetector, RuntimeFactory, VariantToolchain,
 };
 pub use registry::{
     create_runtime, create_runtimes, create_runtimes_arc, get_detector,
     get_embedded_diagnostic_provider, get_embedded_diagnostic_setup, get_embedded_hover_provider,
     get_embedded_lsp_client, get_helper_function_extractor, get_virtual_file_builder,
-    init_embedded_lsp_client, init_import_extractors, supported_languages,
+    init_embedded_lsp_client, init_helper_function_extractors, init_import_extractors,
+    memory_metric_note, supported_languages,
 };
 pub use traits::Runtime;
+pub use variant::{register_runtime_variants, RuntimeVariantSpec};
+
+/// Serializes tests that register or clear external runtimes and variants
+#[cfg(test)]
+pub(crate) static TEST_REGISTRY: std::sync::Mutex<()> = std::sync::Mutex::new(());
@@ -1,3 +1,7 @@
 //! Measurement types - re-export from poly-bench-traits
 
-pub use poly_bench_traits::{Comparison, ComparisonWinner, Measurement, DEFAULT_CV_THRESHOLD};
+pub use poly_bench_traits::{
+    Comparison, ComparisonWinner, CounterTotals, EffectMagnitude, HardwareCounters, Measurement,
+    Significance, SignificanceMethod, DEFAULT_CV_THRESHOLD, MIN_SIGNIFICANCE_SAMPLES,
+    SIGNIFICANCE_ALPHA,
+};
@@ -1,9 +1,11 @@
 //! Runtime registry for pluggable language runtimes
 //!
 //! Plugins register themselves via the `PLUGINS` distributed slice in
-//! poly-bench-traits. No manual registration needed here.
+//! poly-bench-traits. No manual registration needed here. External runtimes and runtime
+//! variants declared in `polybench.toml` are registered at startup via [`crate::external`] and
+//! [`crate::variant`].
 
-use crate::config::RuntimeConfig;
+use crate::{config::RuntimeConfig, external, variant};
 use miette::{miette, Result};
 use poly_bench_dsl::Lang;
 use poly_bench_traits::{
@@ -14,6 +16,12 @@ use std::{collections::HashMap, sync::Arc};
 
 /// Create a runtime for the given language
 pub fn create_runtime(lang: Lang, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
+    if lang.is_external() {
+        return external::create_external_runtime(lang, config);
+    }
+    if lang.is_variant() {
+        return variant::create_variant_runtime(lang, config);
+    }
     for plugin in PLUGINS {
         if plugin.lang() == lang {
             return plugin.runtime_factory().create(config);
@@ -70,6 +78,13 @@ pub fn get_embedded_lsp_client(lang: Lang) -> Option<Arc<dyn EmbeddedLspClient>>
     PLUGINS.iter().find(|p| p.lang() == lang).and_then(|p| p.embedded_lsp_client_get())
 }
 
+/// How memory suites measure `lang`, if not cumulative bytes and allocation counts per op.
+/// Variants measure like their base language.
+pub fn memory_metric_note(lang: Lang) -> Option<&'static str> {
+    let base = lang.base();
+    PLUGINS.iter().find(|p| p.lang() == base).and_then(|p| p.memory_metric_note())
+}
+
 /// Initialize import extractors for poly-bench-ir.
 /// Must be called at application startup before any ir::lower.
 pub fn init_import_extractors() {
@@ -77,11 +92,25 @@ pub fn init_import_extractors() {
     poly_bench_ir::set_import_extractors(extractors);
 }
 
-/// Get all supported languages (derived from registered plugins)
-pub fn supported_languages() -> &'static [Lang] {
-    use once_cell::sync::Lazy;
-    static LANGS: Lazy<Vec<Lang>> = Lazy::new(|| PLUGINS.iter().map(|p| p.lang()).collect());
-    LANGS.as_slice()
+/// Initialize helper function extractors for poly-bench-dsl's undefined-function check.
+/// Must be called at application startup before any validation.
+pub fn init_helper_function_extractors() {
+    let extractors: Vec<_> = PLUGINS
+        .iter()
+        .filter_map(|p| p.helper_function_extractor().map(|e| (p.lang(), e)))
+        .collect();
+    poly_bench_dsl::helper_extractor::set_helper_function_extractors(extractors);
+}
+
+/// Get all supported languages (registered plugins, then registered external runtimes, then
+/// runtime variants)
+pub fn supported_languages() -> Vec<Lang> {
+    PLUGINS
+        .iter()
+        .map(|p| p.lang())
+        .chain(external::external_languages())
+        .chain(variant::variant_languages())
+        .collect()
 }
 
 /// Build a map of runtimes for the requested languages (owned, for scheduler).
@@ -0,0 +1,317 @@
+//! Runtime variants: one language measured under several toolchains or flag sets
+//!
+//! A variant reuses the implementations written for its base language but runs them with its
+//! own toolchain binary, flags and environment, and is scheduled, compared and charted as a
+//! separate contestant:
+//!
+//! ```toml
+//! [variants.bun]
+//! lang = "ts"
+//! path = "bun"
+//!
+//! [variants.go-1-22]
+//! lang = "go"
+//! version = "1.22.0"
+//!
+//! [variants.rust-native]
+//! lang = "rust"
+//! env = { RUSTFLAGS = "-C target-cpu=native" }
+//! label = "Rust (native)"
+//! ```
+//!
+//! `flags` are compiler flags for compiled languages (`go build`, `clang`, `zig build-exe`,
+//! `dotnet build`, and `RUSTFLAGS` for Rust) and interpreter flags for TypeScript and Python.
+
+use crate::{
+    config::RuntimeConfig,
+    lang_display::{intern, lang_display},
+    registry::create_runtime,
+};
+use miette::{miette, Result};
+use once_cell::sync::Lazy;
+use poly_bench_dsl::{Lang, RuntimeVariant};
+use poly_bench_traits::{toolchain::toolchain_binary_path, LangDisplayInfo, Runtime};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+pub use poly_bench_traits::VariantToolchain;
+
+/// A runtime variant declared under `[variants.<name>]` in `polybench.toml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeVariantSpec {
+    /// Base language whose implementations the variant runs (`go`, `ts`, `rust`, ...)
+    pub lang: String,
+    /// Toolchain binary; a bare name is looked up on PATH, other relative paths resolve against
+    /// the project root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Version of a Polybench-managed toolchain to use when `path` is not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Compiler or interpreter flags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flags: Vec<String>,
+    /// Environment for toolchain and benchmark processes
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Display label in reports (defaults to the variant name)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Hex color for charts (defaults to a shade of the base language's color)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+struct VariantEntry {
+    toolchain: VariantToolchain,
+    display: LangDisplayInfo,
+}
+
+static VARIANTS: Lazy<RwLock<HashMap<Lang, Arc<VariantEntry>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register the runtime variants declared in a project's manifest, replacing those of any
+/// previously registered project.
+///
+/// Must run after external runtimes are registered (variant names may not shadow them) and
+/// before `--lang` or `baseline:` values are parsed. Returns the registered variants.
+pub fn register_runtime_variants(
+    project_root: &Path,
+    specs: &BTreeMap<String, RuntimeVariantSpec>,
+) -> Result<Vec<Lang>> {
+    VARIANTS.write().unwrap_or_else(|e| e.into_inner()).clear();
+    RuntimeVariant::clear();
+    let mut langs = Vec::new();
+    for (name, spec) in specs {
+        if name.is_empty() ||
+            !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(miette!("Invalid variant name '{}': use letters, digits, '_' or '-'", name));
+        }
+        if let Some(existing) = Lang::from_str(name) {
+            return Err(miette!(
+                "[variants.{}] conflicts with the {} runtime",
+                name,
+                existing.grammar_display_name()
+            ));
+        }
+        let base =
+            Lang::from_str(&spec.lang).filter(|l| Lang::ALL.contains(l)).ok_or_else(|| {
+                miette!(
+                    "[variants.{}] lang '{}' is not a built-in language ({})",
+                    name,
+                    spec.lang,
+                    Lang::ALL.iter().map(|l| l.as_str()).collect::<Vec<_>>().join(", ")
+                )
+            })?;
+        let binary = match (&spec.path, &spec.version) {
+            (Some(path), _) => Some(resolve_path(project_root, path)),
+            (None, Some(version)) => {
+                let binary = toolchain_binary_path(base, version).ok_or_else(|| {
+                    miette!("[variants.{}] {} has no managed toolchains; set path", name, base)
+                })?;
+                if !binary.exists() {
+                    return Err(miette!(
+                        "[variants.{}] {} {} is not installed (expected {})",
+                        name,
+                        base.grammar_display_name(),
+                        version,
+                        binary.display()
+                    ));
+                }
+                Some(binary)
+            }
+            (None, None) => None,
+        };
+
+        let lang = RuntimeVariant::register(name, base).ok_or_else(|| {
+            miette!(
+                "[variants.{}] is already registered as a variant of {}",
+                name,
+                RuntimeVariant::lookup(name).map_or(base, |v| v.base()).grammar_display_name()
+            )
+        })?;
+        let base_display = lang_display(base);
+        let label = intern(spec.label.clone().unwrap_or_else(|| lang.as_str().to_string()));
+        let (color, gradient_end) = match spec.color {
+            Some(ref color) => (intern(color.clone()), intern(color.clone())),
+            // Reverse the base gradient so the variant is told apart from its base in charts
+            None => (base_display.gradient_end, base_display.color),
+        };
+        let display = LangDisplayInfo {
+            label,
+            full_name: label,
+            color,
+            gradient_id: intern(format!("{}Grad", lang.as_str())),
+            gradient_end,
+            ..base_display
+        };
+        let toolchain = VariantToolchain {
+            name: lang.as_str().to_string(),
+            binary,
+            flags: spec.flags.clone(),
+            env: spec.env.clone(),
+        };
+        VARIANTS
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(lang, Arc::new(VariantEntry { toolchain, display }));
+        langs.push(lang);
+    }
+    Ok(langs)
+}
+
+fn resolve_path(project_root: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else if path.components().count() > 1 {
+        project_root.join(path)
+    } else {
+        // Keep the bare name if it is not on PATH; spawning reports the error
+        which::which(path).unwrap_or_else(|_| path.to_path_buf())
+    }
+}
+
+/// Runtime variants that have a registered configuration, in registration order
+pub fn variant_languages() -> Vec<Lang> {
+    let variants = VARIANTS.read().unwrap_or_else(|e| e.into_inner());
+    RuntimeVariant::registered().into_iter().filter(|l| variants.contains_key(l)).collect()
+}
+
+/// Toolchain settings of a registered variant
+pub fn variant_toolchain(lang: Lang) -> Option<VariantToolchain> {
+    VARIANTS.read().unwrap_or_else(|e| e.into_inner()).get(&lang).map(|e| e.toolchain.clone())
+}
+
+/// Toolchain binary of a registered variant, if it overrides the base language's
+pub fn variant_binary(lang: Lang) -> Option<PathBuf> {
+    variant_toolchain(lang).and_then(|t| t.binary)
+}
+
+/// Display info for a registered variant
+pub fn variant_display(lang: Lang) -> Option<LangDisplayInfo> {
+    VARIANTS.read().unwrap_or_else(|e| e.into_inner()).get(&lang).map(|e| e.display)
+}
+
+/// Create the base language's runtime configured with a variant's toolchain
+pub fn create_variant_runtime(lang: Lang, config: &RuntimeConfig) -> Result<Box<dyn Runtime>> {
+    let toolchain = variant_toolchain(lang).ok_or_else(|| {
+        miette!(
+            "No runtime variant configured for '{}'; declare it under [variants.{}]",
+            lang,
+            lang
+        )
+    })?;
+    let base = lang.base();
+    let mut config = config.clone();
+    if let Some(root) = config.get_root(lang) {
+        config.set_root(base, Some(root));
+    }
+    config.toolchain = Some(toolchain);
+    create_runtime(base, &config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_variant_of_builtin_language() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let root = std::env::temp_dir();
+        let mut specs = BTreeMap::new();
+        specs.insert(
+            "rust-native-test".to_string(),
+            RuntimeVariantSpec {
+                lang: "rust".to_string(),
+                env: BTreeMap::from([(
+                    "RUSTFLAGS".to_string(),
+                    "-C target-cpu=native".to_string(),
+                )]),
+                label: Some("Rust (native)".to_string()),
+                ..Default::default()
+            },
+        );
+        let langs = register_runtime_variants(&root, &specs).unwrap();
+        let variant = langs[0];
+
+        assert_eq!(variant.base(), Lang::Rust);
+        assert_eq!(Lang::from_str("rust-native-test"), None);
+        assert_eq!(Lang::from_str_with_variants("rust-native-test"), Some(variant));
+        assert!(variant_languages().contains(&variant));
+        assert_eq!(lang_display(variant).label, "Rust (native)");
+
+        let toolchain = variant_toolchain(variant).unwrap();
+        assert_eq!(toolchain.binary, None);
+        assert_eq!(toolchain.env["RUSTFLAGS"], "-C target-cpu=native");
+        assert_eq!(
+            toolchain.artifact_name("polybench_runner"),
+            "polybench_runner-rust-native-test"
+        );
+    }
+
+    #[test]
+    fn test_register_variant_rejects_bad_base_and_conflicts() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let root = std::env::temp_dir();
+        let spec = |lang: &str| RuntimeVariantSpec { lang: lang.to_string(), ..Default::default() };
+
+        let err = register_runtime_variants(
+            &root,
+            &BTreeMap::from([("cobol-test".to_string(), spec("cobol"))]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not a built-in language"), "{}", err);
+
+        let err =
+            register_runtime_variants(&root, &BTreeMap::from([("go".to_string(), spec("go"))]))
+                .unwrap_err();
+        assert!(err.to_string().contains("conflicts with the Go runtime"), "{}", err);
+
+        // Names are case-insensitive, so these declare one variant with two bases
+        let err = register_runtime_variants(
+            &root,
+            &BTreeMap::from([
+                ("Fast-Dup-Test".to_string(), spec("rust")),
+                ("fast-dup-test".to_string(), spec("go")),
+            ]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("already registered as a variant of Rust"), "{}", err);
+    }
+
+    #[test]
+    fn test_reregistering_replaces_previous_project_variants() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let root = std::env::temp_dir();
+        let register = |name: &str, lang: &str| {
+            let spec = RuntimeVariantSpec { lang: lang.to_string(), ..Default::default() };
+            let specs = BTreeMap::from([(name.to_string(), spec)]);
+            register_runtime_variants(&root, &specs).unwrap()[0]
+        };
+
+        let old = register("old-project-test", "rust");
+        let new = register("new-project-test", "go");
+        assert_eq!(variant_languages(), vec![new]);
+        assert_eq!(Lang::from_str_with_variants("old-project-test"), None);
+        assert!(variant_toolchain(old).is_none());
+
+        // Another project may reuse the name for a different base
+        assert_eq!(register("old-project-test", "go"), old);
+        assert_eq!(old.base(), Lang::Go);
+    }
+
+    #[test]
+    fn test_external_runtimes_resolve_before_variants() {
+        let _registry = crate::TEST_REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+        let external = poly_bench_dsl::ExternalLang::register("shared-name-test");
+        RuntimeVariant::register("shared-name-test", Lang::Rust).unwrap();
+
+        assert_eq!(Lang::from_str_with_variants("shared-name-test"), Some(external));
+        assert_eq!(serde_json::from_str::<Lang>(r#""shared-name-test""#).unwrap(), external);
+    }
+}
@@ -462,6 +462,9 @@ fn convert_fixture_data_property(node: TsNode, source: &str) -> Option<FixtureDa
                     }
                 }
             }
+            "generator_call" => {
+                return Some(FixtureData::Generator(child.text(source).to_string()));
+            }
             _ => {}
         }
     }
@@ -526,6 +529,10 @@ fn convert_benchmark(node: TsNode, source: &str) -> Node<PartialBenchmark> {
     };
     let mut benchmark = PartialBenchmark::new(name, kind, span);
 
+    if let Some(params) = node.field("params") {
+        benchmark.params = convert_bench_params(params, source);
+    }
+
     // Find benchmark_body
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -541,6 +548,32 @@ fn convert_benchmark(node: TsNode, source: &str) -> Node<PartialBenchmark> {
     Node::Valid(benchmark)
 }
 
+fn convert_bench_params(node: TsNode, source: &str) -> Vec<BenchParam> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "bench_param" {
+            let span = Span::from_node(&child);
+            let name = child.field("name").map(|n| n.text(source).to_string()).unwrap_or_default();
+            let mut values = Vec::new();
+            if let Some(array) = child.field("values") {
+                let mut array_cursor = array.walk();
+                for value in array.named_children(&mut array_cursor) {
+                    if value.is_extra() {
+                        continue;
+                    }
+                    values.push(convert_value(value, source));
+                }
+            }
+
+            params.push(BenchParam { name, values, span });
+        }
+    }
+
+    params
+}
+
 fn convert_benchmark_body(node: TsNode, source: &str, benchmark: &mut PartialBenchmark) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -845,4 +878,29 @@ declare suite test performance timeBased sameDataset: true {
         let suite_gs = suite.global_setup.as_ref().unwrap().as_valid().unwrap();
         assert_eq!(suite_gs.statements.len(), 1, "Suite-level globalSetup should have 1 statement");
     }
+
+    #[test]
+    fn test_convert_bench_params() {
+        let source = r#"
+suite test {
+    bench sort(n in [100, 1000], ratio in [0.5], order in ["asc"], stable in [true]) {
+        go: sortInts(n)
+    }
+}
+"#;
+        let file = parse_source(source);
+        let suite = file.suites[0].as_valid().unwrap();
+        let bench = suite.benchmarks[0].as_valid().unwrap();
+
+        assert_eq!(bench.name, "sort");
+        let names: Vec<&str> = bench.params.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["n", "ratio", "order", "stable"]);
+        assert!(matches!(
+            bench.params[0].values[..],
+            [PropertyValue::Number(100), PropertyValue::Number(1000)]
+        ));
+        assert!(matches!(bench.params[1].values[..], [PropertyValue::Float(f)] if f == 0.5));
+        assert!(matches!(&bench.params[2].values[..], [PropertyValue::String(s)] if s == "asc"));
+        assert!(matches!(bench.params[3].values[..], [PropertyValue::Boolean(true)]));
+    }
 }
@@ -391,6 +391,8 @@ pub enum HexData {
 pub enum FixtureData {
     Inline(String),
     File(String),
+    /// Generator call source text, e.g. `@random(bytes: 1024, seed: 42)`
+    Generator(String),
 }
 
 /// A partial benchmark
@@ -399,6 +401,7 @@ pub struct PartialBenchmark {
     pub name: String,
     pub kind: BenchmarkKind,
     pub span: Span,
+    pub params: Vec<BenchParam>,
     pub properties: Vec<Node<Property>>,
     pub tags: Vec<String>,
     pub skip: HashMap<Lang, Node<CodeBlock>>,
@@ -416,6 +419,7 @@ impl PartialBenchmark {
             name,
             kind,
             span,
+            params: Vec::new(),
             properties: Vec::new(),
             tags: Vec::new(),
             skip: HashMap::new(),
@@ -429,6 +433,14 @@ impl PartialBenchmark {
     }
 }
 
+/// A parameter axis on a benchmark: `bench sort(n in [100, 1000])`
+#[derive(Debug, Clone)]
+pub struct BenchParam {
+    pub name: String,
+    pub values: Vec<PropertyValue>,
+    pub span: Span,
+}
+
 /// After block with charting directives
 #[derive(Debug, Clone)]
 pub struct AfterBlock {
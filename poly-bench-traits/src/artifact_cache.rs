@@ -0,0 +1,542 @@
+//! Persistent, content-addressed cache of compiled benchmark harnesses
+//!
+//! Runtimes key each build by everything that determines its output (generated source,
+//! toolchain version, flags, environment, dependency lockfiles and project-local sources the
+//! harness compiles in) and reuse the stored
+//! artifact on later runs instead of invoking the compiler. Entries live under
+//! `<polybench base dir>/cache/artifacts/<key>/`, are shared by all projects, and are evicted
+//! least-recently-used first once the cache exceeds its size limit.
+//!
+//! Environment:
+//! - `POLYBENCH_CACHE_DIR`: cache location
+//! - `POLYBENCH_CACHE_MAX_MB`: size limit in megabytes (default 2048)
+//! - `POLYBENCH_NO_ARTIFACT_CACHE`: disable the cache
+
+use crate::{config::VariantToolchain, toolchain::polybench_base_dir};
+use miette::{miette, Result};
+use poly_bench_dsl::Lang;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{LazyLock, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Marker file recording when an entry was last stored or reused
+const LAST_USED_FILE: &str = ".last-used";
+
+/// Directories [`ArtifactKey::source_tree`] never descends into: build output and installed
+/// dependencies (keyed through lockfiles instead)
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "zig-cache", "zig-out", "bin", "obj"];
+
+/// File name prefixes of harness sources poly-bench writes next to project code
+const GENERATED_PREFIXES: &[&str] = &["bench_standalone", "bench_check_", "_lsp_virtual"];
+
+/// Toolchain version strings by binary path, so each toolchain is queried once per process
+static TOOLCHAIN_VERSIONS: LazyLock<Mutex<HashMap<PathBuf, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Inputs that determine a build artifact; builds with equal keys are interchangeable
+#[derive(Clone)]
+pub struct ArtifactKey {
+    lang: Lang,
+    hasher: Sha256,
+}
+
+impl ArtifactKey {
+    /// Start a key from the generated harness source
+    pub fn new(lang: Lang, source: &str) -> Self {
+        let mut key = Self { lang, hasher: Sha256::new() };
+        key.field("source", source.as_bytes());
+        key
+    }
+
+    /// Include the version reported by `binary <version_args>`
+    pub fn toolchain(mut self, binary: &Path, version_args: &[&str]) -> Self {
+        let version = toolchain_version(binary, version_args);
+        self.field("toolchain", version.as_bytes());
+        self
+    }
+
+    /// Include a runtime variant's flags and environment
+    pub fn variant(mut self, toolchain: &VariantToolchain) -> Self {
+        self.field("flags", toolchain.flags.join("\0").as_bytes());
+        self.env(&toolchain.env)
+    }
+
+    /// Include environment variables that affect the build
+    pub fn env(mut self, env: &BTreeMap<String, String>) -> Self {
+        for (name, value) in env {
+            self.field("env", format!("{}={}", name, value).as_bytes());
+        }
+        self
+    }
+
+    /// Include the process environment variables named in `names` that are set; a name ending
+    /// in `*` matches every variable with that prefix (e.g. `CGO_*`)
+    pub fn ambient_env(mut self, names: &[&str]) -> Self {
+        let mut vars: Vec<(String, String)> = std::env::vars_os()
+            .filter_map(|(name, value)| {
+                let name = name.into_string().ok()?;
+                let matched = names.iter().any(|pattern| match pattern.strip_suffix('*') {
+                    Some(prefix) => name.starts_with(prefix),
+                    None => name == *pattern,
+                });
+                matched.then(|| (name, value.to_string_lossy().into_owned()))
+            })
+            .collect();
+        vars.sort();
+        for (name, value) in vars {
+            self.field("ambient-env", format!("{}={}", name, value).as_bytes());
+        }
+        self
+    }
+
+    /// Include every file under `root` with one of `extensions`, by relative path and contents.
+    /// Skips hidden entries, build output and dependency directories, and harness sources
+    /// poly-bench generates.
+    pub fn source_tree(mut self, root: &Path, extensions: &[&str]) -> Self {
+        for path in source_files(root, extensions) {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            self.field("tree-path", relative.to_string_lossy().as_bytes());
+            self.field("tree-file", &fs::read(&path).unwrap_or_default());
+        }
+        self
+    }
+
+    /// Include a file's contents (manifests and lockfiles); a missing file is keyed as absent
+    pub fn file(mut self, path: &Path) -> Self {
+        let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+        match fs::read(path) {
+            Ok(contents) => self.field(&name, &contents),
+            Err(_) => self.field(&name, b"<absent>"),
+        }
+        self
+    }
+
+    /// Include any other build input
+    pub fn extra(mut self, label: &str, value: &str) -> Self {
+        self.field(label, value.as_bytes());
+        self
+    }
+
+    fn field(&mut self, label: &str, value: &[u8]) {
+        self.hasher.update((label.len() as u64).to_le_bytes());
+        self.hasher.update(label.as_bytes());
+        self.hasher.update((value.len() as u64).to_le_bytes());
+        self.hasher.update(value);
+    }
+
+    /// Cache entry name: the language followed by the hex digest of all inputs
+    pub fn digest(&self) -> String {
+        let digest = self.hasher.clone().finalize();
+        format!("{}-{:x}", self.lang.as_str(), digest)
+    }
+}
+
+/// Files under `root` that [`ArtifactKey::source_tree`] keys on, sorted
+pub fn source_files(root: &Path, extensions: &[&str]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_sources(root, extensions, &mut files);
+    files.sort();
+    files
+}
+
+/// Version string of a toolchain binary, falling back to its size and modification time when
+/// the version command fails
+pub fn toolchain_version(binary: &Path, version_args: &[&str]) -> String {
+    let mut versions = TOOLCHAIN_VERSIONS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(version) = versions.get(binary) {
+        return version.clone();
+    }
+    let version = Command::new(binary)
+        .args(version_args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| {
+            format!(
+                "{}{}",
+                String::from_utf8_lossy(&o.stdout).trim(),
+                String::from_utf8_lossy(&o.stderr).trim()
+            )
+        })
+        .unwrap_or_else(|| {
+            let meta = fs::metadata(binary).ok();
+            let modified = meta
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_nanos())
+                .unwrap_or_default();
+            format!("{}:{}:{}", binary.display(), meta.map(|m| m.len()).unwrap_or(0), modified)
+        });
+    versions.insert(binary.to_path_buf(), version.clone());
+    version
+}
+
+/// On-disk artifact cache shared by all runtimes
+#[derive(Debug, Clone)]
+pub struct ArtifactCache {
+    /// Directory holding one subdirectory per entry
+    root: PathBuf,
+    /// Size limit enforced after each store
+    max_bytes: u64,
+    /// Whether caching is enabled
+    enabled: bool,
+}
+
+impl ArtifactCache {
+    /// Default size limit: 2 GiB
+    pub const DEFAULT_MAX_BYTES: u64 = 2 * 1024 * 1024 * 1024;
+
+    /// Create a cache rooted at `root`
+    pub fn new(root: PathBuf, enabled: bool) -> Self {
+        Self { root, max_bytes: Self::DEFAULT_MAX_BYTES, enabled }
+    }
+
+    /// Create a disabled cache (no-op)
+    pub fn disabled() -> Self {
+        Self::new(PathBuf::new(), false)
+    }
+
+    /// The cache at its default location, configured from the environment
+    pub fn from_env() -> Self {
+        let root = std::env::var_os("POLYBENCH_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| polybench_base_dir().map(|b| b.join("cache").join("artifacts")));
+        let Some(root) = root else {
+            return Self::disabled();
+        };
+        let enabled = std::env::var_os("POLYBENCH_NO_ARTIFACT_CACHE").is_none();
+        let mut cache = Self::new(root, enabled);
+        if let Some(mb) =
+            std::env::var("POLYBENCH_CACHE_MAX_MB").ok().and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            cache.max_bytes = mb.saturating_mul(1024 * 1024);
+        }
+        cache
+    }
+
+    /// Set the size limit
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Cache location
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Check if caching is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Path of the artifact `name` stored under `key`, if present
+    pub fn lookup(&self, key: &ArtifactKey, name: &str) -> Option<PathBuf> {
+        if !self.enabled {
+            return None;
+        }
+        let entry = self.root.join(key.digest());
+        let artifact = entry.join(name);
+        if !artifact.exists() {
+            return None;
+        }
+        let _ = fs::write(entry.join(LAST_USED_FILE), now_millis().to_string());
+        Some(artifact)
+    }
+
+    /// Copy a freshly built file or directory into the cache under `key` and return the cached
+    /// copy's path. Evicts old entries if the cache is over its size limit.
+    pub fn store(&self, key: &ArtifactKey, artifact: &Path) -> Result<PathBuf> {
+        if !self.enabled {
+            return Ok(artifact.to_path_buf());
+        }
+        let name = artifact
+            .file_name()
+            .ok_or_else(|| miette!("Artifact path has no file name: {}", artifact.display()))?;
+        let digest = key.digest();
+        let entry = self.root.join(&digest);
+        let staging = self.root.join(format!(".tmp-{}-{}", digest, std::process::id()));
+
+        let _ = fs::remove_dir_all(&staging);
+        fs::create_dir_all(&staging)
+            .map_err(|e| miette!("Failed to create artifact cache entry: {}", e))?;
+        copy_recursive(artifact, &staging.join(name)).map_err(|e| {
+            miette!("Failed to copy {} into artifact cache: {}", artifact.display(), e)
+        })?;
+        let _ = fs::write(staging.join(LAST_USED_FILE), now_millis().to_string());
+
+        // Another process may have stored the same key meanwhile; its copy is interchangeable
+        // and may be running, so it is kept rather than replaced
+        if entry.join(name).exists() {
+            let _ = fs::remove_dir_all(&staging);
+            let _ = fs::write(entry.join(LAST_USED_FILE), now_millis().to_string());
+            return Ok(entry.join(name));
+        }
+        // An entry without the artifact is incomplete: move it aside so the rename can land
+        // without deleting files another process may still hold open
+        if entry.exists() {
+            let stale = self.root.join(format!(".stale-{}-{}", digest, std::process::id()));
+            let _ = fs::rename(&entry, &stale);
+            let _ = fs::remove_dir_all(&stale);
+        }
+        if fs::rename(&staging, &entry).is_err() {
+            let _ = fs::remove_dir_all(&staging);
+            if !entry.join(name).exists() {
+                return Ok(artifact.to_path_buf());
+            }
+        }
+
+        self.evict(&digest);
+        Ok(entry.join(name))
+    }
+
+    /// Remove least-recently-used entries (other than `keep`) until the cache fits its limit
+    fn evict(&self, keep: &str) {
+        let mut entries = self.entries();
+        let mut total: u64 = entries.iter().map(|e| e.bytes).sum();
+        if total <= self.max_bytes {
+            return;
+        }
+        entries.sort_by_key(|e| e.last_used);
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if entry.name == keep {
+                continue;
+            }
+            if fs::remove_dir_all(self.root.join(&entry.name)).is_ok() {
+                total = total.saturating_sub(entry.bytes);
+            }
+        }
+    }
+
+    fn entries(&self) -> Vec<CachedArtifact> {
+        let Ok(dir) = fs::read_dir(&self.root) else {
+            return Vec::new();
+        };
+        dir.flatten()
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| {
+                let name = e.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') {
+                    return None;
+                }
+                let path = e.path();
+                let last_used = fs::read_to_string(path.join(LAST_USED_FILE))
+                    .ok()
+                    .and_then(|s| s.trim().parse().ok())
+                    .unwrap_or(0);
+                Some(CachedArtifact { bytes: dir_size(&path), name, last_used })
+            })
+            .collect()
+    }
+
+    /// Entry count and size, in total and per language
+    pub fn stats(&self) -> ArtifactCacheStats {
+        let mut stats = ArtifactCacheStats { max_bytes: self.max_bytes, ..Default::default() };
+        for entry in self.entries() {
+            let lang = entry.name.rsplit_once('-').map(|(l, _)| l).unwrap_or("").to_string();
+            let per_lang = stats.by_lang.entry(lang).or_default();
+            per_lang.0 += 1;
+            per_lang.1 += entry.bytes;
+            stats.entries += 1;
+            stats.total_bytes += entry.bytes;
+        }
+        stats
+    }
+
+    /// Remove every entry; returns the number of bytes freed
+    pub fn clear(&self) -> Result<u64> {
+        let freed = self.stats().total_bytes;
+        if self.root.exists() {
+            fs::remove_dir_all(&self.root)
+                .map_err(|e| miette!("Failed to clear artifact cache: {}", e))?;
+        }
+        Ok(freed)
+    }
+}
+
+struct CachedArtifact {
+    name: String,
+    bytes: u64,
+    last_used: u64,
+}
+
+/// Artifact cache statistics
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactCacheStats {
+    pub entries: usize,
+    pub total_bytes: u64,
+    pub max_bytes: u64,
+    /// Entry count and bytes per language
+    pub by_lang: BTreeMap<String, (usize, u64)>,
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+fn copy_recursive(from: &Path, to: &Path) -> std::io::Result<()> {
+    if from.is_dir() {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        // fs::copy keeps permissions, so executables stay executable
+        fs::copy(from, to).map(|_| ())
+    }
+}
+
+fn collect_sources(dir: &Path, extensions: &[&str], files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(t) if t.is_dir() && !SKIPPED_DIRS.contains(&name.as_str()) => {
+                collect_sources(&path, extensions, files);
+            }
+            Ok(t) if t.is_file() => {
+                let generated = GENERATED_PREFIXES.iter().any(|p| name.starts_with(p));
+                let wanted = path
+                    .extension()
+                    .is_some_and(|ext| extensions.iter().any(|e| ext == std::ffi::OsStr::new(e)));
+                if wanted && !generated {
+                    files.push(path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let Ok(dir) = fs::read_dir(path) else {
+        return 0;
+    };
+    dir.flatten()
+        .map(|e| match e.metadata() {
+            Ok(m) if m.is_dir() => dir_size(&e.path()),
+            Ok(m) => m.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_changes_with_every_input() {
+        let base = ArtifactKey::new(Lang::Go, "package main");
+        assert_eq!(base.digest(), ArtifactKey::new(Lang::Go, "package main").digest());
+        assert!(base.digest().starts_with("go-"));
+
+        let flags = VariantToolchain { flags: vec!["-race".to_string()], ..Default::default() };
+        assert_ne!(base.digest(), base.clone().variant(&flags).digest());
+        assert_ne!(base.digest(), base.clone().extra("mode", "memory").digest());
+        assert_ne!(base.digest(), ArtifactKey::new(Lang::Go, "package main2").digest());
+        assert_ne!(base.digest(), ArtifactKey::new(Lang::Zig, "package main").digest());
+    }
+
+    #[test]
+    fn test_key_covers_ambient_env() {
+        let base = ArtifactKey::new(Lang::Go, "package main");
+        std::env::set_var("POLYBENCH_TEST_KEY_FLAG", "a");
+        let with_a = base.clone().ambient_env(&["POLYBENCH_TEST_KEY_*"]).digest();
+        std::env::set_var("POLYBENCH_TEST_KEY_FLAG", "b");
+        let with_b = base.clone().ambient_env(&["POLYBENCH_TEST_KEY_*"]).digest();
+        std::env::remove_var("POLYBENCH_TEST_KEY_FLAG");
+        let unset = base.clone().ambient_env(&["POLYBENCH_TEST_KEY_*"]).digest();
+        assert_ne!(with_a, with_b);
+        assert_ne!(with_a, unset);
+        assert_eq!(unset, base.clone().ambient_env(&["POLYBENCH_TEST_KEY_*"]).digest());
+    }
+
+    #[test]
+    fn test_key_covers_local_sources_but_not_generated_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("pkg")).unwrap();
+        fs::create_dir_all(dir.path().join("target")).unwrap();
+        fs::write(dir.path().join("pkg/util.go"), "package pkg").unwrap();
+        let key = || ArtifactKey::new(Lang::Go, "package main").source_tree(dir.path(), &["go"]);
+        let before = key().digest();
+
+        // Generated harnesses, build output and other extensions do not change the key
+        fs::write(dir.path().join("bench_standalone.go"), "package main").unwrap();
+        fs::write(dir.path().join("target/out.go"), "package out").unwrap();
+        fs::write(dir.path().join("pkg/notes.txt"), "notes").unwrap();
+        assert_eq!(before, key().digest());
+
+        fs::write(dir.path().join("pkg/util.go"), "package pkg // changed").unwrap();
+        assert_ne!(before, key().digest());
+    }
+
+    #[test]
+    fn test_store_keeps_an_existing_entry() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ArtifactCache::new(dir.path().join("artifacts"), true);
+        let key = ArtifactKey::new(Lang::C, "int main() {}");
+
+        let built = dir.path().join("polybench_runner");
+        fs::write(&built, b"first").unwrap();
+        let cached = cache.store(&key, &built).unwrap();
+
+        fs::write(&built, b"second").unwrap();
+        assert_eq!(cache.store(&key, &built).unwrap(), cached);
+        assert_eq!(fs::read(&cached).unwrap(), b"first");
+    }
+
+    #[test]
+    fn test_store_lookup_evict_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ArtifactCache::new(dir.path().join("artifacts"), true).with_max_bytes(6);
+
+        let built = dir.path().join("polybench_runner");
+        fs::write(&built, b"binary").unwrap();
+        let first = ArtifactKey::new(Lang::C, "int main() {}");
+        assert_eq!(cache.lookup(&first, "polybench_runner"), None);
+
+        let cached = cache.store(&first, &built).unwrap();
+        assert_eq!(fs::read(&cached).unwrap(), b"binary");
+        assert_eq!(cache.lookup(&first, "polybench_runner"), Some(cached));
+
+        // A second entry pushes the cache over its limit and evicts the first
+        let second = ArtifactKey::new(Lang::C, "int main() { return 1; }");
+        cache.store(&second, &built).unwrap();
+        assert_eq!(cache.lookup(&first, "polybench_runner"), None);
+        assert!(cache.lookup(&second, "polybench_runner").is_some());
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.by_lang["c"], (1, stats.total_bytes));
+
+        assert_eq!(cache.clear().unwrap(), stats.total_bytes);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_disabled_cache_is_a_no_op() {
+        let cache = ArtifactCache::disabled();
+        let key = ArtifactKey::new(Lang::Rust, "fn main() {}");
+        assert_eq!(cache.store(&key, Path::new("/tmp/x")).unwrap(), PathBuf::from("/tmp/x"));
+        assert_eq!(cache.lookup(&key, "x"), None);
+    }
+}
@@ -1,13 +1,18 @@
 //! Runtime configuration for project roots
 
 use poly_bench_dsl::Lang;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+};
 
 /// Configuration passed to runtime factories when creating a runtime.
 #[derive(Debug, Clone, Default)]
 pub struct RuntimeConfig {
     /// Project roots per language (e.g. go.mod dir for Go, package.json dir for TypeScript)
     pub roots: HashMap<Lang, Option<PathBuf>>,
+    /// Toolchain overrides when the runtime is created for a runtime variant
+    pub toolchain: Option<VariantToolchain>,
 }
 
 impl RuntimeConfig {
@@ -20,4 +25,63 @@ impl RuntimeConfig {
     pub fn set_root(&mut self, lang: Lang, path: Option<PathBuf>) {
         self.roots.insert(lang, path);
     }
+
+    /// Toolchain settings for the runtime being created (empty for a base language)
+    pub fn toolchain(&self) -> VariantToolchain {
+        self.toolchain.clone().unwrap_or_default()
+    }
+}
+
+/// Toolchain, flags and environment of a runtime variant (`[variants.<name>]` in
+/// `polybench.toml`).
+///
+/// The default value describes the base language's own toolchain, so runtimes can apply it
+/// unconditionally.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantToolchain {
+    /// Variant name; empty for a base language
+    pub name: String,
+    /// Toolchain binary (`go`, `node`, `cargo`, ...) used instead of the resolved one
+    pub binary: Option<PathBuf>,
+    /// Compiler flags for compiled languages, interpreter flags for TypeScript and Python
+    pub flags: Vec<String>,
+    /// Environment for toolchain and benchmark processes
+    pub env: BTreeMap<String, String>,
+}
+
+impl VariantToolchain {
+    /// Toolchain binary for `lang`: the variant's binary, or the usual resolution order
+    pub fn resolve_binary(&self, lang: Lang) -> miette::Result<(PathBuf, bool)> {
+        match self.binary {
+            Some(ref binary) => Ok((binary.clone(), false)),
+            None => crate::toolchain::resolve_binary(lang),
+        }
+    }
+
+    /// File name for a build artifact, suffixed with the variant name so variants sharing a
+    /// project root do not overwrite each other's builds
+    pub fn artifact_name(&self, name: &str) -> String {
+        if self.name.is_empty() {
+            return name.to_string();
+        }
+        match name.rsplit_once('.') {
+            Some((stem, ext)) => format!("{}-{}.{}", stem, self.name, ext),
+            None => format!("{}-{}", name, self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_name_is_unchanged_for_base_language() {
+        let base = VariantToolchain::default();
+        assert_eq!(base.artifact_name("polybench_runner"), "polybench_runner");
+
+        let variant = VariantToolchain { name: "go-1.22".to_string(), ..Default::default() };
+        assert_eq!(variant.artifact_name("polybench_runner"), "polybench_runner-go-1.22");
+        assert_eq!(variant.artifact_name("bench_standalone.go"), "bench_standalone-go-1.22.go");
+    }
 }
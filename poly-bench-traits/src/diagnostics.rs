@@ -1,7 +1,9 @@
 //! Embedded diagnostic types and traits
 
 use std::sync::Arc;
-use tower_lsp::lsp_types::{DiagnosticSeverity, Hover};
+use tower_lsp::lsp_types::{
+    CompletionResponse, DiagnosticSeverity, GotoDefinitionResponse, Hover, Location, SignatureHelp,
+};
 
 use crate::virtual_file::VirtualFile;
 
@@ -29,6 +31,43 @@ pub trait EmbeddedLspClient: Send + Sync {
         Ok(Vec::new())
     }
     fn hover(&self, uri: &str, line: u32, character: u32) -> Result<Option<Hover>, String>;
+    fn completion(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<CompletionResponse>, String> {
+        let _ = (uri, line, character);
+        Ok(None)
+    }
+    fn definition(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<GotoDefinitionResponse>, String> {
+        let _ = (uri, line, character);
+        Ok(None)
+    }
+    fn signature_help(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<SignatureHelp>, String> {
+        let _ = (uri, line, character);
+        Ok(None)
+    }
+    fn references(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+        include_declaration: bool,
+    ) -> Result<Vec<Location>, String> {
+        let _ = (uri, line, character, include_declaration);
+        Ok(Vec::new())
+    }
 }
 
 /// Diagnostic from LSP client (simplified)
@@ -0,0 +1,125 @@
+//! Noise control for benchmark processes
+//!
+//! The executor registers the CPUs and nice value chosen for a run via
+//! [`set_benchmark_isolation`]. Runtimes pass every benchmark [`Command`] through
+//! [`isolate_benchmark_command`], which applies both in the child between fork and exec, so
+//! poly-bench itself and the toolchains it builds with keep running unpinned at normal priority.
+
+use std::{process::Command, sync::Mutex};
+
+/// CPU set and priority for benchmark processes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BenchmarkIsolation {
+    /// CPUs benchmark processes may run on; empty leaves affinity alone
+    pub cpus: Vec<usize>,
+    /// Nice value benchmark processes run at
+    pub nice: Option<i32>,
+}
+
+static ISOLATION: Mutex<Option<BenchmarkIsolation>> = Mutex::new(None);
+
+/// Register the isolation applied to benchmark processes spawned from now on.
+/// Called by the executor before running benchmarks; `None` turns it off.
+pub fn set_benchmark_isolation(isolation: Option<BenchmarkIsolation>) {
+    *ISOLATION.lock().unwrap() = isolation;
+}
+
+/// The isolation benchmark processes are spawned with, if any
+pub fn benchmark_isolation() -> Option<BenchmarkIsolation> {
+    ISOLATION.lock().unwrap().clone()
+}
+
+/// Pin and re-nice the process `cmd` spawns according to [`benchmark_isolation`].
+///
+/// A child whose inherited affinity already lies within the isolated CPUs keeps it, so a
+/// parallel worker pinned to one of them still gets its own CPU.
+#[cfg(target_os = "linux")]
+pub fn isolate_benchmark_command(cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+
+    let Some(isolation) = benchmark_isolation() else {
+        return;
+    };
+    // Everything the child needs is prepared here: only async-signal-safe calls are allowed
+    // between fork and exec
+    let mask = (!isolation.cpus.is_empty()).then(|| cpu_set(&isolation.cpus));
+    let nice = isolation.nice;
+    // SAFETY: the closure only issues the sched_getaffinity, sched_setaffinity and setpriority
+    // syscalls on values captured before the fork; it neither allocates nor takes locks.
+    unsafe {
+        cmd.pre_exec(move || apply_in_child(mask.as_ref(), nice));
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn isolate_benchmark_command(_cmd: &mut Command) {}
+
+#[cfg(target_os = "linux")]
+fn cpu_set(cpus: &[usize]) -> libc::cpu_set_t {
+    // SAFETY: cpu_set_t is plain data; all-zero is the empty set and CPU_SET only writes bits
+    // inside it.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        for &cpu in cpus {
+            libc::CPU_SET(cpu, &mut set);
+        }
+        set
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_in_child(mask: Option<&libc::cpu_set_t>, nice: Option<i32>) -> std::io::Result<()> {
+    let size = std::mem::size_of::<libc::cpu_set_t>();
+    // SAFETY: every cpu_set_t is plain data of `size` bytes and the syscalls write at most that
+    // much; setpriority takes plain integers and does not retain them.
+    unsafe {
+        if let Some(mask) = mask {
+            let mut current: libc::cpu_set_t = std::mem::zeroed();
+            let within = libc::sched_getaffinity(0, size, &mut current) == 0 &&
+                (0..libc::CPU_SETSIZE as usize)
+                    .all(|cpu| !libc::CPU_ISSET(cpu, &current) || libc::CPU_ISSET(cpu, mask));
+            if !within && libc::sched_setaffinity(0, size, mask) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+        }
+        if let Some(nice) = nice {
+            if libc::setpriority(libc::PRIO_PROCESS, 0, nice) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolated_child_runs_on_isolated_cpus() {
+        let mut allowed = cpu_set(&[]);
+        // SAFETY: see `apply_in_child`
+        let cpu = unsafe {
+            libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut allowed);
+            (0..libc::CPU_SETSIZE as usize).rfind(|&cpu| libc::CPU_ISSET(cpu, &allowed)).unwrap()
+        };
+        set_benchmark_isolation(Some(BenchmarkIsolation { cpus: vec![cpu], nice: Some(19) }));
+
+        let mut cmd = Command::new("sh");
+        cmd.args([
+            "-c",
+            "grep Cpus_allowed_list /proc/self/status; cut -d' ' -f19 /proc/self/stat",
+        ]);
+        isolate_benchmark_command(&mut cmd);
+        let output = cmd.output().unwrap();
+        set_benchmark_isolation(None);
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut lines = stdout.lines();
+        assert_eq!(lines.next().unwrap().split_whitespace().last(), Some(cpu.to_string().as_str()));
+        assert_eq!(lines.next(), Some("19"));
+        // The parent keeps its own priority
+        // SAFETY: getpriority takes plain integers.
+        assert_ne!(unsafe { libc::getpriority(libc::PRIO_PROCESS, 0) }, 19);
+    }
+}
@@ -16,8 +16,10 @@ pub mod virtual_file;
 pub mod virtual_file_core;
 
 // Runtime modules
+pub mod artifact_cache;
 pub mod config;
 pub mod error_mapping;
+pub mod isolation;
 pub mod lang_display;
 pub mod measurement;
 pub mod plugin;
@@ -41,10 +43,18 @@ pub use virtual_file::{SectionMapping, VirtualFile, VirtualFileBuilder, VirtualF
 pub use virtual_file_core::{VirtualFileBuilderCore, VirtualFileData};
 
 // Runtime re-exports
-pub use config::RuntimeConfig;
+pub use artifact_cache::{source_files, ArtifactCache, ArtifactCacheStats, ArtifactKey};
+pub use config::{RuntimeConfig, VariantToolchain};
 pub use error_mapping::{ErrorMapper, LineMapping, LineMappings};
+pub use isolation::{
+    benchmark_isolation, isolate_benchmark_command, set_benchmark_isolation, BenchmarkIsolation,
+};
 pub use lang_display::LangDisplayInfo;
-pub use measurement::{Comparison, ComparisonWinner, Measurement, DEFAULT_CV_THRESHOLD};
+pub use measurement::{
+    Comparison, ComparisonWinner, CounterTotals, EffectMagnitude, HardwareCounters, Measurement,
+    Significance, SignificanceMethod, DEFAULT_CV_THRESHOLD, MIN_SIGNIFICANCE_SAMPLES,
+    SIGNIFICANCE_ALPHA,
+};
 pub use plugin::{RuntimePlugin, PLUGINS};
 pub use project::{detect_from_markers, ProjectRootDetector};
 pub use stdlib_provider::StdlibProvider;
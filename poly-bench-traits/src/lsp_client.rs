@@ -15,7 +15,10 @@ use std::{
 
 use dashmap::DashMap;
 use serde_json::{json, Value};
-use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use tower_lsp::lsp_types::{
+    CompletionResponse, GotoDefinitionResponse, Hover, HoverContents, Location, MarkupContent,
+    MarkupKind, SignatureHelp,
+};
 
 use crate::diagnostics::{EmbeddedLspClient, LspDiagnostic};
 
@@ -430,7 +433,20 @@ impl<C: LspConfig> LspClient<C> {
                 "textDocument": {
                     "hover": {
                         "contentFormat": ["markdown", "plaintext"]
-                    }
+                    },
+                    "completion": {
+                        "completionItem": {
+                            "snippetSupport": true,
+                            "documentationFormat": ["markdown", "plaintext"]
+                        }
+                    },
+                    "signatureHelp": {
+                        "signatureInformation": {
+                            "documentationFormat": ["markdown", "plaintext"]
+                        }
+                    },
+                    "definition": { "linkSupport": false },
+                    "references": {}
                 }
             },
             "workspaceFolders": [{
@@ -549,6 +565,85 @@ impl<C: LspConfig> LspClient<C> {
         parse_hover_response(&result)
     }
 
+    /// Send a request whose params are a text document position and decode the result
+    fn position_request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        uri: &str,
+        line: u32,
+        character: u32,
+        extra: Value,
+    ) -> Result<Option<T>, String> {
+        if !self.initialized.load(Ordering::SeqCst) {
+            self.initialize()?;
+        }
+
+        let mut params = json!({
+            "textDocument": {
+                "uri": uri
+            },
+            "position": {
+                "line": line,
+                "character": character
+            }
+        });
+        if let (Some(params), Some(extra)) = (params.as_object_mut(), extra.as_object()) {
+            params.extend(extra.clone());
+        }
+
+        let result = self.send_request(method, params)?;
+        if result.is_null() {
+            return Ok(None);
+        }
+        serde_json::from_value(result)
+            .map(Some)
+            .map_err(|e| format!("Invalid {} response from {}: {}", method, C::SERVER_NAME, e))
+    }
+
+    /// Request completions at a position
+    pub fn completion(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<CompletionResponse>, String> {
+        self.position_request("textDocument/completion", uri, line, character, json!({}))
+    }
+
+    /// Request the definition of the symbol at a position
+    pub fn definition(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<GotoDefinitionResponse>, String> {
+        self.position_request("textDocument/definition", uri, line, character, json!({}))
+    }
+
+    /// Request signature help at a position
+    pub fn signature_help(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<SignatureHelp>, String> {
+        self.position_request("textDocument/signatureHelp", uri, line, character, json!({}))
+    }
+
+    /// Request references to the symbol at a position
+    pub fn references(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+        include_declaration: bool,
+    ) -> Result<Vec<Location>, String> {
+        let context = json!({ "context": { "includeDeclaration": include_declaration } });
+        Ok(self
+            .position_request("textDocument/references", uri, line, character, context)?
+            .unwrap_or_default())
+    }
+
     /// Request diagnostics for a document
     pub fn request_diagnostics(&self, uri: &str) -> Result<Vec<LspDiagnostic>, String> {
         if !self.initialized.load(Ordering::SeqCst) {
@@ -644,6 +739,43 @@ impl<C: LspConfig> EmbeddedLspClient for LspClient<C> {
     fn hover(&self, uri: &str, line: u32, character: u32) -> Result<Option<Hover>, String> {
         LspClient::<C>::hover(self, uri, line, character)
     }
+
+    fn completion(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<CompletionResponse>, String> {
+        LspClient::<C>::completion(self, uri, line, character)
+    }
+
+    fn definition(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<GotoDefinitionResponse>, String> {
+        LspClient::<C>::definition(self, uri, line, character)
+    }
+
+    fn signature_help(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+    ) -> Result<Option<SignatureHelp>, String> {
+        LspClient::<C>::signature_help(self, uri, line, character)
+    }
+
+    fn references(
+        &self,
+        uri: &str,
+        line: u32,
+        character: u32,
+        include_declaration: bool,
+    ) -> Result<Vec<Location>, String> {
+        LspClient::<C>::references(self, uri, line, character, include_declaration)
+    }
 }
 
 /// Parse a hover response from an LSP server
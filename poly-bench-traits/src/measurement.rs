@@ -1,9 +1,10 @@
 //! Unified measurement types for benchmark results
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// A single benchmark measurement result
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Measurement {
     /// Number of iterations executed
     pub iterations: u64,
@@ -35,9 +36,9 @@ pub struct Measurement {
     pub rme_percent: Option<f64>,
     /// Number of samples collected
     pub samples: Option<u64>,
-    /// Bytes allocated per operation (Go-specific)
+    /// Bytes allocated per operation (`memory: true`)
     pub bytes_per_op: Option<u64>,
-    /// Allocations per operation (Go-specific)
+    /// Allocations per operation, for runtimes that can count them (Go, Rust, Zig)
     pub allocs_per_op: Option<u64>,
     /// Raw sample times in nanoseconds (for detailed analysis)
     pub raw_samples: Option<Vec<u64>>,
@@ -89,6 +90,111 @@ pub struct Measurement {
     /// Run-level nanos/op values (strict fairness mode)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_nanos_per_op: Option<Vec<f64>>,
+    /// Hardware performance counters for the timed region (`counters: true`, Linux only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counters: Option<HardwareCounters>,
+    /// Why the benchmark's `validate` expression failed, if it did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_error: Option<String>,
+}
+
+/// Per-operation hardware counter readings from `perf_event_open`
+///
+/// Each counter is `None` when the kernel refused to open or schedule it; `unavailable` then
+/// carries the reason (e.g. `perf_event_paranoid` too restrictive, or not running on Linux).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HardwareCounters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions_per_op: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycles_per_op: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_misses_per_op: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch_misses_per_op: Option<f64>,
+    /// Why counters could not be collected, if they could not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unavailable: Option<String>,
+}
+
+impl HardwareCounters {
+    /// Instructions retired per cycle, when both counters are present
+    pub fn ipc(&self) -> Option<f64> {
+        match (self.instructions_per_op, self.cycles_per_op) {
+            (Some(i), Some(c)) if c > 0.0 => Some(i / c),
+            _ => None,
+        }
+    }
+
+    /// Whether at least one counter was read
+    pub fn has_values(&self) -> bool {
+        self.instructions_per_op.is_some() ||
+            self.cycles_per_op.is_some() ||
+            self.cache_misses_per_op.is_some() ||
+            self.branch_misses_per_op.is_some()
+    }
+
+    /// Average counters across runs; runs without readings are ignored
+    pub fn average<'a>(runs: impl IntoIterator<Item = &'a HardwareCounters>) -> Option<Self> {
+        let runs: Vec<&HardwareCounters> = runs.into_iter().collect();
+        if runs.is_empty() {
+            return None;
+        }
+        let mean = |f: fn(&HardwareCounters) -> Option<f64>| {
+            let values: Vec<f64> = runs.iter().filter_map(|c| f(c)).collect();
+            (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+        };
+        let averaged = Self {
+            instructions_per_op: mean(|c| c.instructions_per_op),
+            cycles_per_op: mean(|c| c.cycles_per_op),
+            cache_misses_per_op: mean(|c| c.cache_misses_per_op),
+            branch_misses_per_op: mean(|c| c.branch_misses_per_op),
+            unavailable: None,
+        };
+        if averaged.has_values() {
+            Some(averaged)
+        } else {
+            Some(Self { unavailable: runs.iter().find_map(|c| c.unavailable.clone()), ..averaged })
+        }
+    }
+}
+
+/// Raw counter totals as emitted by generated harnesses (`"counters": {...}` in result JSON)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CounterTotals {
+    #[serde(default)]
+    pub instructions: Option<u64>,
+    #[serde(default)]
+    pub cycles: Option<u64>,
+    #[serde(default)]
+    pub cache_misses: Option<u64>,
+    #[serde(default)]
+    pub branch_misses: Option<u64>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl CounterTotals {
+    /// Convert totals for the timed region into per-operation values
+    pub fn per_op(&self, iterations: u64) -> HardwareCounters {
+        let per_op =
+            |v: Option<u64>| v.filter(|_| iterations > 0).map(|v| v as f64 / iterations as f64);
+        let counters = HardwareCounters {
+            instructions_per_op: per_op(self.instructions),
+            cycles_per_op: per_op(self.cycles),
+            cache_misses_per_op: per_op(self.cache_misses),
+            branch_misses_per_op: per_op(self.branch_misses),
+            unavailable: self.error.clone().filter(|e| !e.is_empty()),
+        };
+        if counters.has_values() || counters.unavailable.is_some() {
+            counters
+        } else {
+            HardwareCounters {
+                unavailable: Some("no hardware counters reported".to_string()),
+                ..counters
+            }
+        }
+    }
 }
 
 /// Default CV threshold percentage (5%) - results with CV above this are considered unstable
@@ -208,6 +314,8 @@ impl Measurement {
             filtered_nanos_per_op: Some(nanos_per_op),
             timed_out: Some(false),
             run_nanos_per_op: None,
+            counters: None,
+            validation_error: None,
         }
     }
 
@@ -253,6 +361,8 @@ impl Measurement {
             filtered_nanos_per_op: Some(nanos_per_op),
             timed_out: Some(false),
             run_nanos_per_op: None,
+            counters: None,
+            validation_error: None,
         }
     }
 
@@ -328,6 +438,13 @@ impl Measurement {
         self
     }
 
+    /// Set memory data from a runtime that may not report allocation counts
+    pub fn with_memory(mut self, bytes_per_op: u64, allocs_per_op: Option<u64>) -> Self {
+        self.bytes_per_op = Some(bytes_per_op);
+        self.allocs_per_op = allocs_per_op;
+        self
+    }
+
     /// Format duration for display
     pub fn format_duration(nanos: f64) -> String {
         if nanos < 1_000.0 {
@@ -526,6 +643,8 @@ impl Measurement {
             filtered_nanos_per_op: Some(median),
             timed_out: Some(false),
             run_nanos_per_op: Some(run_nanos_per_op),
+            counters: HardwareCounters::average(runs.iter().filter_map(|r| r.counters.as_ref())),
+            validation_error: runs.iter().find_map(|r| r.validation_error.clone()),
         }
     }
 
@@ -676,6 +795,8 @@ impl Measurement {
             filtered_nanos_per_op: Some(median),
             timed_out: Some(false),
             run_nanos_per_op: Some(run_nanos_per_op),
+            counters: HardwareCounters::average(runs.iter().filter_map(|r| r.counters.as_ref())),
+            validation_error: runs.iter().find_map(|r| r.validation_error.clone()),
         }
     }
 
@@ -685,6 +806,16 @@ impl Measurement {
         self
     }
 
+    /// Record the outcome of a `validate` expression reported by a harness.
+    ///
+    /// `passed` is `None` when the harness did not evaluate one.
+    pub fn record_validation(&mut self, expression: Option<&String>, passed: Option<bool>) {
+        if let (Some(expression), Some(false)) = (expression, passed) {
+            self.validation_error =
+                Some(format!("validate `{}` returned false", expression.trim()));
+        }
+    }
+
     /// Create an explicit timeout marker measurement.
     pub fn timeout_marker() -> Self {
         Self {
@@ -723,6 +854,8 @@ impl Measurement {
             filtered_nanos_per_op: None,
             timed_out: Some(true),
             run_nanos_per_op: None,
+            counters: None,
+            validation_error: None,
         }
     }
 
@@ -753,6 +886,51 @@ impl Measurement {
         let (lo, hi) = bootstrap_ci_median(&ratios, 1000);
         Some((median, lo, hi))
     }
+
+    /// Median of run-level values with the same bootstrap 95% CI used by [`aggregate_runs`].
+    /// Returns (median, 95% CI lower, 95% CI upper).
+    ///
+    /// [`aggregate_runs`]: Measurement::aggregate_runs
+    pub fn median_ci(values: &[f64]) -> Option<(f64, f64, f64)> {
+        if values.len() < 2 {
+            return None;
+        }
+        let (lo, hi) = bootstrap_ci_median(values, 1000);
+        Some((median_f64(values), lo, hi))
+    }
+
+    /// Median of a timing distribution (0.0 when empty)
+    pub fn median_of(values: &[f64]) -> f64 {
+        median_f64(values)
+    }
+
+    /// Per-operation timing distribution used to compare independent result sets: run-level
+    /// nanos/op when multiple runs were aggregated, otherwise the raw samples.
+    pub fn timing_distribution(&self) -> Option<Vec<f64>> {
+        if let Some(runs) = self.run_nanos_per_op.as_ref().filter(|r| r.len() > 1) {
+            return Some(runs.clone());
+        }
+        self.raw_samples
+            .as_ref()
+            .filter(|s| s.len() > 1)
+            .map(|s| s.iter().map(|&v| v as f64).collect())
+    }
+
+    /// Build a bootstrap CI for the ratio of medians between two independent distributions
+    /// (e.g. the same benchmark measured on two commits).
+    /// Returns (median ratio candidate/baseline, 95% CI lower, 95% CI upper).
+    pub fn independent_ratio_ci(baseline: &[f64], candidate: &[f64]) -> Option<(f64, f64, f64)> {
+        if baseline.len() < 2 || candidate.len() < 2 {
+            return None;
+        }
+        let baseline_median = median_f64(baseline);
+        if baseline_median <= 0.0 {
+            return None;
+        }
+        let ratio = median_f64(candidate) / baseline_median;
+        let (lo, hi) = bootstrap_ci_median_ratio(baseline, candidate, 1000);
+        Some((ratio, lo, hi))
+    }
 }
 
 /// Calculate median of a vector of u64 values
@@ -769,6 +947,55 @@ fn median_of_options(mut values: Vec<u64>) -> Option<u64> {
     }
 }
 
+/// Median of an unsorted f64 slice (0.0 when empty)
+fn median_f64(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let n = sorted.len();
+    if n.is_multiple_of(2) {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0
+    } else {
+        sorted[n / 2]
+    }
+}
+
+/// Deterministic xorshift generator so bootstrap CIs are reproducible across runs
+struct BootstrapRng(u64);
+
+impl BootstrapRng {
+    fn new() -> Self {
+        Self(0xA5A5_1234_9E37_79B9)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Median of a resample (with replacement) of `values`
+    fn resampled_median(&mut self, values: &[f64]) -> f64 {
+        let n = values.len();
+        let sample: Vec<f64> = (0..n).map(|_| values[(self.next_u64() as usize) % n]).collect();
+        median_f64(&sample)
+    }
+}
+
+/// 2.5th/97.5th percentiles of a bootstrap distribution
+fn percentile_interval(mut estimates: Vec<f64>) -> (f64, f64) {
+    estimates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let iterations = estimates.len();
+    let lower_idx = ((iterations as f64) * 0.025).floor() as usize;
+    let upper_idx = ((iterations as f64) * 0.975).ceil() as usize;
+    let lower = estimates[lower_idx.min(iterations - 1)];
+    let upper = estimates[upper_idx.min(iterations - 1)];
+    (lower, upper)
+}
+
 /// Deterministic bootstrap CI for median estimator.
 fn bootstrap_ci_median(values: &[f64], iterations: usize) -> (f64, f64) {
     if values.is_empty() {
@@ -778,33 +1005,26 @@ fn bootstrap_ci_median(values: &[f64], iterations: usize) -> (f64, f64) {
         return (values[0], values[0]);
     }
 
-    let mut state: u64 = 0xA5A5_1234_9E37_79B9;
-    let mut next_u64 = || {
-        state ^= state >> 12;
-        state ^= state << 25;
-        state ^= state >> 27;
-        state.wrapping_mul(0x2545_F491_4F6C_DD1D)
-    };
+    let mut rng = BootstrapRng::new();
+    let medians = (0..iterations).map(|_| rng.resampled_median(values)).collect();
+    percentile_interval(medians)
+}
 
-    let mut medians = Vec::with_capacity(iterations);
-    let n = values.len();
-    for _ in 0..iterations {
-        let mut sample = Vec::with_capacity(n);
-        for _ in 0..n {
-            let idx = (next_u64() as usize) % n;
-            sample.push(values[idx]);
-        }
-        sample.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-        let m = if n % 2 == 0 { (sample[n / 2 - 1] + sample[n / 2]) / 2.0 } else { sample[n / 2] };
-        medians.push(m);
+/// Deterministic bootstrap CI for the ratio of medians of two independent samples
+/// (candidate / baseline), resampling each side separately.
+fn bootstrap_ci_median_ratio(baseline: &[f64], candidate: &[f64], iterations: usize) -> (f64, f64) {
+    let mut rng = BootstrapRng::new();
+    let ratios = (0..iterations)
+        .filter_map(|_| {
+            let base = rng.resampled_median(baseline);
+            let cand = rng.resampled_median(candidate);
+            (base > 0.0).then(|| cand / base)
+        })
+        .collect::<Vec<_>>();
+    if ratios.is_empty() {
+        return (0.0, 0.0);
     }
-    medians.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-
-    let lower_idx = ((iterations as f64) * 0.025).floor() as usize;
-    let upper_idx = ((iterations as f64) * 0.975).ceil() as usize;
-    let lower = medians[lower_idx.min(medians.len() - 1)];
-    let upper = medians[upper_idx.min(medians.len() - 1)];
-    (lower, upper)
+    percentile_interval(ratios)
 }
 
 /// Calculate percentile from sorted samples (integer percentile)
@@ -851,7 +1071,7 @@ fn remove_outliers_iqr(sorted: &[u64]) -> Vec<u64> {
 }
 
 /// Comparison between two measurements
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Comparison {
     /// The benchmark name
     pub name: String,
@@ -875,13 +1095,201 @@ pub struct Comparison {
     /// Optional robust paired 95% CI upper bound for ratio
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ratio_ci_95_upper: Option<f64>,
+    /// Hypothesis test on the timing distributions (performance suites with enough samples)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub significance: Option<Significance>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum ComparisonWinner {
     First,
     Second,
+    /// Within 5% of each other
     Tie,
+    /// Apart by more than 5%, but the difference is not statistically significant
+    Inconclusive,
+}
+
+/// Minimum observations per side before a significance test is attempted
+pub const MIN_SIGNIFICANCE_SAMPLES: usize = 5;
+
+/// Significance level used to call a comparison
+pub const SIGNIFICANCE_ALPHA: f64 = 0.05;
+
+/// Hypothesis test used to decide whether two measurements differ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SignificanceMethod {
+    /// Two-sided Mann-Whitney U test on per-operation samples (normal approximation with tie
+    /// and continuity correction)
+    MannWhitneyU,
+    /// Bootstrap 95% CI of paired run ratios; significant when it excludes 1.0
+    PairedRatioCi,
+}
+
+/// Size of a difference by Cliff's delta, using the Romano et al. thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum EffectMagnitude {
+    Negligible,
+    Small,
+    Medium,
+    Large,
+}
+
+impl EffectMagnitude {
+    fn from_delta(delta: f64) -> Self {
+        match delta.abs() {
+            d if d < 0.147 => Self::Negligible,
+            d if d < 0.33 => Self::Small,
+            d if d < 0.474 => Self::Medium,
+            _ => Self::Large,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Negligible => "negligible",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        }
+    }
+}
+
+/// Whether the difference between two timing distributions is statistically meaningful
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Significance {
+    /// Test that produced the verdict
+    pub method: SignificanceMethod,
+    /// Two-sided p-value (Mann-Whitney U only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub p_value: Option<f64>,
+    /// Cliff's delta in [-1, 1]; positive when the second measurement is faster
+    pub effect_size: f64,
+    /// Magnitude bucket of `effect_size`
+    pub magnitude: EffectMagnitude,
+    /// Whether the difference is significant at [`SIGNIFICANCE_ALPHA`]
+    pub significant: bool,
+}
+
+impl Significance {
+    /// Test two measurements. Paired run-level ratios are used when both sides were aggregated
+    /// from the same number of runs, otherwise the per-operation distributions are compared
+    /// with Mann-Whitney U, which is also the fallback when no paired ratio can be formed (every
+    /// second-run value is zero). Returns `None` when either side has fewer than
+    /// [`MIN_SIGNIFICANCE_SAMPLES`] observations.
+    pub fn between(first: &Measurement, second: &Measurement) -> Option<Self> {
+        if let (Some(a), Some(b)) = (&first.run_nanos_per_op, &second.run_nanos_per_op) {
+            if a.len() == b.len() && a.len() >= MIN_SIGNIFICANCE_SAMPLES {
+                if let Some((_, lo, hi)) = Measurement::paired_ratio_ci(a, b) {
+                    let effect_size = cliffs_delta(a, b);
+                    return Some(Self {
+                        method: SignificanceMethod::PairedRatioCi,
+                        p_value: None,
+                        effect_size,
+                        magnitude: EffectMagnitude::from_delta(effect_size),
+                        significant: lo > 1.0 || hi < 1.0,
+                    });
+                }
+            }
+        }
+        Self::mann_whitney(&first.timing_distribution()?, &second.timing_distribution()?)
+    }
+
+    /// Two-sided Mann-Whitney U test between two independent samples
+    pub fn mann_whitney(first: &[f64], second: &[f64]) -> Option<Self> {
+        let (n1, n2) = (first.len(), second.len());
+        if n1 < MIN_SIGNIFICANCE_SAMPLES || n2 < MIN_SIGNIFICANCE_SAMPLES {
+            return None;
+        }
+
+        // Rank the pooled sample, giving tied values their average rank
+        let mut pooled: Vec<(f64, bool)> =
+            first.iter().map(|&v| (v, true)).chain(second.iter().map(|&v| (v, false))).collect();
+        pooled.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let mut first_rank_sum = 0.0;
+        let mut tie_term = 0.0;
+        let mut i = 0;
+        while i < pooled.len() {
+            let mut j = i + 1;
+            while j < pooled.len() && pooled[j].0 == pooled[i].0 {
+                j += 1;
+            }
+            let tied = (j - i) as f64;
+            let rank = (i + j + 1) as f64 / 2.0;
+            first_rank_sum +=
+                rank * pooled[i..j].iter().filter(|(_, is_first)| *is_first).count() as f64;
+            tie_term += tied.powi(3) - tied;
+            i = j;
+        }
+
+        let (n1f, n2f) = (n1 as f64, n2 as f64);
+        let n = n1f + n2f;
+        // U counts pairs where the first side is slower (ties count half)
+        let u = first_rank_sum - n1f * (n1f + 1.0) / 2.0;
+        let mean = n1f * n2f / 2.0;
+        let variance = n1f * n2f / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+        let p_value = if variance > 0.0 {
+            let z = ((u - mean).abs() - 0.5).max(0.0) / variance.sqrt();
+            erfc(z / std::f64::consts::SQRT_2)
+        } else {
+            1.0
+        };
+        let effect_size = 2.0 * u / (n1f * n2f) - 1.0;
+
+        Some(Self {
+            method: SignificanceMethod::MannWhitneyU,
+            p_value: Some(p_value),
+            effect_size,
+            magnitude: EffectMagnitude::from_delta(effect_size),
+            significant: p_value < SIGNIFICANCE_ALPHA,
+        })
+    }
+
+    /// Short human-readable summary, e.g. `p<0.001, large effect`
+    pub fn describe(&self) -> String {
+        let evidence = match (self.method, self.p_value) {
+            (SignificanceMethod::MannWhitneyU, Some(p)) if p < 0.001 => "p<0.001".to_string(),
+            (SignificanceMethod::MannWhitneyU, Some(p)) => format!("p={:.3}", p),
+            _ if self.significant => "paired CI excludes 1.0".to_string(),
+            _ => "paired CI includes 1.0".to_string(),
+        };
+        format!("{}, {} effect", evidence, self.magnitude.label())
+    }
+}
+
+/// Cliff's delta: P(first > second) - P(first < second)
+fn cliffs_delta(first: &[f64], second: &[f64]) -> f64 {
+    if first.is_empty() || second.is_empty() {
+        return 0.0;
+    }
+    let mut score = 0i64;
+    for a in first {
+        for b in second {
+            score += (a > b) as i64 - (a < b) as i64;
+        }
+    }
+    score as f64 / (first.len() * second.len()) as f64
+}
+
+/// Complementary error function (Numerical Recipes `erfcc`, fractional error < 1.2e-7)
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23 +
+        t * (1.000_023_68 +
+            t * (0.374_091_96 +
+                t * (0.096_784_18 +
+                    t * (-0.186_288_06 +
+                        t * (0.278_868_07 +
+                            t * (-1.135_203_98 +
+                                t * (1.488_515_87 +
+                                    t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let r = t * poly.exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
 }
 
 impl Comparison {
@@ -941,15 +1349,32 @@ impl Comparison {
             winner,
             ratio_ci_95_lower: ratio_ci_95.map(|x| x.0),
             ratio_ci_95_upper: ratio_ci_95.map(|x| x.1),
+            significance: None,
         }
     }
 
+    /// Attach a significance test; a difference the test cannot confirm becomes
+    /// [`ComparisonWinner::Inconclusive`]
+    pub fn with_significance(mut self, significance: Option<Significance>) -> Self {
+        if let Some(test) = &significance {
+            if !test.significant && self.winner != ComparisonWinner::Tie {
+                self.winner = ComparisonWinner::Inconclusive;
+            }
+        }
+        self.significance = significance;
+        self
+    }
+
     /// Get a description of the speedup
     pub fn speedup_description(&self) -> String {
         match self.winner {
             ComparisonWinner::First => format!("{} {:.2}x faster", self.first_lang, self.speedup),
             ComparisonWinner::Second => format!("{} {:.2}x faster", self.second_lang, self.speedup),
             ComparisonWinner::Tie => "Similar performance".to_string(),
+            ComparisonWinner::Inconclusive => {
+                let faster = if self.ratio > 1.0 { &self.second_lang } else { &self.first_lang };
+                format!("Inconclusive ({} {:.2}x faster, not significant)", faster, self.speedup)
+            }
         }
     }
 }
@@ -978,10 +1403,116 @@ mod tests {
         assert!(hi >= median);
     }
 
+    #[test]
+    fn test_independent_ratio_ci_detects_slowdown() {
+        let baseline = vec![100.0, 102.0, 98.0, 101.0, 99.0, 100.0, 103.0, 97.0];
+        let candidate: Vec<f64> = baseline.iter().map(|v| v * 1.5).collect();
+        let (ratio, lo, hi) = Measurement::independent_ratio_ci(&baseline, &candidate).unwrap();
+        assert!((ratio - 1.5).abs() < 1e-9);
+        assert!(lo > 1.0, "CI lower bound {} should exclude 1.0", lo);
+        assert!(hi >= ratio);
+    }
+
+    #[test]
+    fn test_independent_ratio_ci_requires_distributions() {
+        assert!(Measurement::independent_ratio_ci(&[100.0], &[100.0, 101.0]).is_none());
+        let m = Measurement::from_aggregate(10, 1000);
+        assert!(m.timing_distribution().is_none());
+        let m = Measurement::from_samples(vec![10, 11, 12, 13], 4);
+        assert_eq!(m.timing_distribution().unwrap().len(), 4);
+    }
+
     #[test]
     fn test_timeout_marker_sets_timeout_flag() {
         let m = Measurement::timeout_marker();
         assert_eq!(m.timed_out, Some(true));
         assert_eq!(m.iterations, 0);
     }
+
+    #[test]
+    fn test_counter_totals_per_op_and_aggregation() {
+        let totals: CounterTotals = serde_json::from_str(
+            r#"{"instructions":4000,"cycles":2000,"branch_misses":10,"error":"perf_event_open(cache_misses): ENOENT"}"#,
+        )
+        .unwrap();
+        let counters = totals.per_op(100);
+        assert_eq!(counters.instructions_per_op, Some(40.0));
+        assert_eq!(counters.ipc(), Some(2.0));
+        assert_eq!(counters.cache_misses_per_op, None);
+        assert!(counters.unavailable.is_some());
+
+        let denied: CounterTotals = serde_json::from_str(r#"{"error":"EACCES"}"#).unwrap();
+        let denied = denied.per_op(100);
+        assert!(!denied.has_values());
+
+        let mut first = Measurement::from_aggregate(100, 1000);
+        first.counters = Some(counters);
+        let mut second = Measurement::from_aggregate(100, 1200);
+        second.counters =
+            Some(CounterTotals { instructions: Some(6000), ..Default::default() }.per_op(100));
+        let aggregated = Measurement::aggregate_runs(vec![first, second]);
+        let counters = aggregated.counters.unwrap();
+        assert_eq!(counters.instructions_per_op, Some(50.0));
+        assert_eq!(counters.cycles_per_op, Some(20.0));
+        assert_eq!(counters.unavailable, None);
+
+        let mut third = Measurement::from_aggregate(100, 1000);
+        third.counters = Some(denied);
+        let aggregated = Measurement::aggregate_runs(vec![third.clone(), third]);
+        assert_eq!(aggregated.counters.unwrap().unavailable.as_deref(), Some("EACCES"));
+    }
+
+    #[test]
+    fn test_mann_whitney_separates_shifted_samples() {
+        let first: Vec<f64> = (0..20).map(|i| 100.0 + (i % 5) as f64).collect();
+        let second: Vec<f64> = first.iter().map(|v| v * 2.0).collect();
+        let test = Significance::mann_whitney(&first, &second).unwrap();
+        assert!(test.significant);
+        assert!(test.p_value.unwrap() < 0.001);
+        assert_eq!(test.effect_size, -1.0);
+        assert_eq!(test.magnitude, EffectMagnitude::Large);
+    }
+
+    #[test]
+    fn test_mann_whitney_overlapping_samples_not_significant() {
+        let first = vec![100.0, 104.0, 98.0, 110.0, 95.0, 107.0, 101.0, 99.0];
+        let second = vec![103.0, 97.0, 108.0, 100.0, 96.0, 111.0, 102.0, 105.0];
+        let test = Significance::mann_whitney(&first, &second).unwrap();
+        assert!(!test.significant);
+        assert!(test.p_value.unwrap() > 0.5);
+        assert!(Significance::mann_whitney(&first[..3], &second).is_none());
+        // All-equal samples carry no evidence either way
+        let flat = Significance::mann_whitney(&[5.0; 6], &[5.0; 6]).unwrap();
+        assert_eq!(flat.p_value, Some(1.0));
+    }
+
+    #[test]
+    fn test_zero_second_runs_fall_back_to_mann_whitney() {
+        let mut first = Measurement::from_aggregate(100, 1000);
+        first.run_nanos_per_op = Some(vec![10.0, 11.0, 12.0, 10.5, 11.5, 12.5]);
+        let mut second = Measurement::from_aggregate(100, 0);
+        second.run_nanos_per_op = Some(vec![0.0; 6]);
+        let test = Significance::between(&first, &second).unwrap();
+        assert_eq!(test.method, SignificanceMethod::MannWhitneyU);
+        assert!(test.significant);
+    }
+
+    #[test]
+    fn test_comparison_without_significance_is_inconclusive() {
+        let first = Measurement::from_samples(vec![100, 130, 90, 120, 95, 125, 85, 115], 8);
+        let second = Measurement::from_samples(vec![115, 100, 140, 105, 130, 95, 145, 110], 8);
+        let test = Significance::between(&first, &second);
+        let cmp = Comparison::new(
+            "noisy".to_string(),
+            first,
+            "Go".to_string(),
+            second,
+            "Rust".to_string(),
+            None,
+        );
+        assert_eq!(cmp.winner, ComparisonWinner::First);
+        let cmp = cmp.with_significance(test);
+        assert_eq!(cmp.winner, ComparisonWinner::Inconclusive);
+        assert!(cmp.speedup_description().starts_with("Inconclusive (Go"));
+    }
 }
@@ -34,6 +34,12 @@ pub trait RuntimePlugin: Send + Sync {
     /// Display metadata (labels, colors, gradients)
     fn lang_display(&self) -> LangDisplayInfo;
 
+    /// How memory suites measure this language, when it differs from cumulative bytes and
+    /// allocation counts per op (as Go and Rust report). Shown under memory tables.
+    fn memory_metric_note(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Optional project root detector
     fn project_root_detector(&self) -> Option<&'static dyn ProjectRootDetector> {
         None
@@ -87,7 +87,9 @@ pub fn pinned_version(lang: Lang) -> &'static str {
         Lang::Python => pinned::PYTHON,
         Lang::CSharp => pinned::DOTNET,
         Lang::Rust => pinned::RUST,
-        Lang::C => "system", // C uses system clang
+        Lang::C => "system",             // C uses system clang
+        Lang::External(_) => "external", // command comes from polybench.toml
+        Lang::Variant(variant) => pinned_version(variant.base()),
     }
 }
 
@@ -99,6 +101,7 @@ pub fn toolchain_path(lang: Lang, version: &str) -> Option<PathBuf> {
 
 /// Returns the path to the binary within a toolchain installation.
 pub fn toolchain_binary_path(lang: Lang, version: &str) -> Option<PathBuf> {
+    let lang = lang.base();
     let base = toolchain_path(lang, version)?;
     Some(match lang {
         Lang::Go => base.join("go").join("bin").join("go"),
@@ -113,7 +116,9 @@ pub fn toolchain_binary_path(lang: Lang, version: &str) -> Option<PathBuf> {
         }
         Lang::Rust => base.join("bin").join("cargo"),
         Lang::CSharp => base.join("dotnet"),
-        Lang::C => return None, // C uses system clang
+        Lang::C => return None,           // C uses system clang
+        Lang::External(_) => return None, // command comes from polybench.toml
+        Lang::Variant(_) => unreachable!("variants resolve to their base language"),
     })
 }
 
@@ -131,6 +136,7 @@ pub fn is_polybench_toolchain_installed(lang: Lang) -> bool {
 ///
 /// Returns the path to the binary and whether it's from Polybench-managed toolchain.
 pub fn resolve_binary(lang: Lang) -> Result<(PathBuf, bool)> {
+    let lang = lang.base();
     let version = pinned_version(lang);
 
     // First, check if Polybench-managed toolchain exists
@@ -149,6 +155,13 @@ pub fn resolve_binary(lang: Lang) -> Result<(PathBuf, bool)> {
         Lang::Rust => "cargo",
         Lang::CSharp => "dotnet",
         Lang::C => "clang",
+        Lang::External(_) => {
+            return Err(miette::miette!(
+                "{} is an external runtime; its command is configured in polybench.toml",
+                lang
+            ))
+        }
+        Lang::Variant(_) => unreachable!("variants resolve to their base language"),
     };
 
     // For Python, try python3 first, then python